@@ -0,0 +1,124 @@
+//! Prometheus metrics for AI registry activity.
+//!
+//! Follows finalized blocks, decodes the `System::Events` of each one and counts the
+//! registry and inference events operators care about. The metrics are registered on
+//! the node's existing Prometheus registry, so they are served from the same endpoint
+//! as the rest of the node metrics.
+
+use codec::Decode;
+use futures::StreamExt;
+use sc_client_api::{BlockchainEvents, StorageProvider};
+use solochain_template_runtime::{Hash, RuntimeEvent};
+use sp_core::{hashing::twox_128, storage::StorageKey};
+use std::sync::Arc;
+use substrate_prometheus_endpoint::{register, Counter, Gauge, PrometheusError, Registry, U64};
+
+use crate::service::FullClient;
+
+type EventRecord = frame_system::EventRecord<RuntimeEvent, Hash>;
+
+/// Counters exported for the registry dashboards.
+#[derive(Clone)]
+pub struct RegistryMetrics {
+	models_registered: Counter<U64>,
+	ratings_submitted: Counter<U64>,
+	inferences_settled: Counter<U64>,
+	inference_fee_volume: Counter<U64>,
+	last_block: Gauge<U64>,
+}
+
+impl RegistryMetrics {
+	/// Register the registry metrics on `registry`.
+	pub fn register(registry: &Registry) -> Result<Self, PrometheusError> {
+		Ok(Self {
+			models_registered: register(
+				Counter::new(
+					"inferify_registry_models_registered_total",
+					"Models registered in finalized blocks",
+				)?,
+				registry,
+			)?,
+			ratings_submitted: register(
+				Counter::new(
+					"inferify_registry_ratings_submitted_total",
+					"Model ratings submitted in finalized blocks",
+				)?,
+				registry,
+			)?,
+			inferences_settled: register(
+				Counter::new(
+					"inferify_inference_requests_settled_total",
+					"Inference requests settled in finalized blocks",
+				)?,
+				registry,
+			)?,
+			inference_fee_volume: register(
+				Counter::new(
+					"inferify_inference_fee_volume_total",
+					"Fees paid to model owners for settled inference requests",
+				)?,
+				registry,
+			)?,
+			last_block: register(
+				Gauge::new(
+					"inferify_registry_metrics_last_block",
+					"Number of the last finalized block scanned for registry events",
+				)?,
+				registry,
+			)?,
+		})
+	}
+
+	fn note_event(&self, event: &RuntimeEvent) {
+		match event {
+			RuntimeEvent::AIRegistry(pallet_ai_registry::Event::ModelRegistered { .. }) => {
+				self.models_registered.inc()
+			}
+			RuntimeEvent::AIRegistry(pallet_ai_registry::Event::ModelRated { .. }) => {
+				self.ratings_submitted.inc()
+			}
+			RuntimeEvent::Inference(pallet_inference::Event::RequestSettled { amount, .. }) => {
+				self.inferences_settled.inc();
+				self.inference_fee_volume.inc_by(u64::try_from(*amount).unwrap_or(u64::MAX));
+			}
+			_ => {}
+		}
+	}
+}
+
+/// Storage key of `frame_system::Events`.
+fn events_key() -> StorageKey {
+	let mut key = twox_128(b"System").to_vec();
+	key.extend_from_slice(&twox_128(b"Events"));
+	StorageKey(key)
+}
+
+/// Follow finality and update `metrics` from the events of every finalized block.
+///
+/// Blocks finalized in a batch are only reported through their tip, so the implicitly
+/// finalized blocks in `tree_route` are scanned as well.
+pub async fn run(client: Arc<FullClient>, metrics: RegistryMetrics) {
+	let key = events_key();
+	let mut finality = client.finality_notification_stream();
+
+	while let Some(notification) = finality.next().await {
+		let hashes = notification.tree_route.iter().chain(core::iter::once(&notification.hash));
+		for hash in hashes {
+			let records = match client.storage(*hash, &key) {
+				Ok(Some(data)) => Vec::<EventRecord>::decode(&mut &data.0[..]),
+				Ok(None) => continue,
+				Err(e) => {
+					log::warn!(target: "registry-metrics", "Failed to read events at {hash:?}: {e}");
+					continue;
+				}
+			};
+			match records {
+				Ok(records) => records.iter().for_each(|record| metrics.note_event(&record.event)),
+				Err(e) => {
+					log::warn!(target: "registry-metrics", "Failed to decode events at {hash:?}: {e}");
+				}
+			}
+		}
+		metrics.last_block.set(notification.header.number.into());
+	}
+}
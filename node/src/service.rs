@@ -211,6 +211,15 @@ pub fn new_full<
 	let enable_grandpa = !config.disable_grandpa;
 	let prometheus_registry = config.prometheus_registry().cloned();
 
+	if let Some(registry) = prometheus_registry.as_ref() {
+		let metrics = crate::registry_metrics::RegistryMetrics::register(registry)?;
+		task_manager.spawn_handle().spawn(
+			"registry-metrics",
+			None,
+			crate::registry_metrics::run(client.clone(), metrics),
+		);
+	}
+
 	let rpc_extensions_builder = {
 		let client = client.clone();
 		let pool = transaction_pool.clone();
@@ -0,0 +1,99 @@
+//! Runtime API definitions for the AI registry pallet
+//!
+//! Exposes read-only queries over registry state to off-chain clients such as
+//! auditors and indexers.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+
+use alloc::vec::Vec;
+use codec::Codec;
+use pallet_ai_registry::{
+	DeveloperPage, ModelId, ModelStats, RatingRecord, RegistryStats, RevenueHistory,
+};
+
+sp_api::decl_runtime_apis! {
+	/// Queries over the AI model registry
+	#[api_version(2)]
+	pub trait AiRegistryApi<AccountId>
+	where
+		AccountId: Codec,
+	{
+		/// Rating left by `rater` on `model_id`, together with the inference
+		/// receipt it was backed by, if any
+		fn rating_provenance(model_id: ModelId, rater: AccountId) -> Option<RatingRecord>;
+
+		/// Model clients of the deprecated `model_id` should move to, if any
+		#[api_version(2)]
+		fn successor(model_id: ModelId) -> Option<ModelId>;
+
+		/// Inferences of `model_id` over the last `eras` eras, the current one
+		/// included, e.g. `1` for "trending this week"
+		#[api_version(3)]
+		fn recent_inferences(model_id: ModelId, eras: u32) -> u32;
+
+		/// Models owned by `owner`, in ascending ID order
+		#[api_version(4)]
+		fn models_of(owner: AccountId) -> Vec<ModelId>;
+
+		/// Up to `n` active models with the best average rating, best first, among
+		/// those rated often enough for era rewards
+		#[api_version(5)]
+		fn top_models_by_rating(n: u32) -> Vec<ModelId>;
+
+		/// Up to `n` active models with the most completed inferences, most used first
+		#[api_version(5)]
+		fn top_models_by_inferences(n: u32) -> Vec<ModelId>;
+
+		/// Up to `n` active models, most recently registered first
+		#[api_version(5)]
+		fn recently_registered(n: u32) -> Vec<ModelId>;
+
+		/// Models whose name starts with `prefix`, ignoring ASCII case, in ascending
+		/// ID order; prefixes shorter than the runtime's `NamePrefixLength` only find
+		/// models named exactly that
+		#[api_version(6)]
+		fn models_by_name_prefix(prefix: Vec<u8>) -> Vec<ModelId>;
+
+		/// Median of the ratings left on `model_id`, weighted like its average, if
+		/// it has any
+		#[api_version(7)]
+		fn median_rating(model_id: ModelId) -> Option<u8>;
+
+		/// Profile of `developer` with the models they own, for marketplace vendor
+		/// pages, if they set a profile
+		#[api_version(8)]
+		fn developer_page(developer: AccountId) -> Option<DeveloperPage>;
+	}
+
+	/// Aggregate statistics of the AI model registry, so explorers need not
+	/// aggregate events themselves
+	pub trait AiRegistryStatsApi {
+		/// Chain-wide model, inference and fee counters
+		fn global_stats() -> RegistryStats;
+
+		/// Usage, revenue and rating distribution of `model_id`, if it exists
+		fn model_stats(model_id: ModelId) -> Option<ModelStats>;
+
+		/// Inference fees settled on `model_id` over the last `eras` eras, the
+		/// current one included
+		#[api_version(2)]
+		fn recent_revenue(model_id: ModelId, eras: u32) -> u128;
+	}
+
+	/// Revenue analytics, so accounting tools can pull model and owner earnings
+	/// from a node instead of replaying fee events
+	pub trait AiRegistryRevenueApi<AccountId>
+	where
+		AccountId: Codec,
+	{
+		/// Fees settled on `model_id` in the current era, since registration and in
+		/// each of the last `UsageWindow` eras, if the model exists
+		fn model_revenue(model_id: ModelId) -> Option<RevenueHistory>;
+
+		/// The same totals over every model `owner` owns now, including fees settled
+		/// before they acquired it
+		fn owner_revenue(owner: AccountId) -> RevenueHistory;
+	}
+}
@@ -3,25 +3,200 @@
 #![cfg(feature = "runtime-benchmarks")]
 
 use super::*;
+use codec::Encode;
 use frame_benchmarking::v2::*;
-use frame_support::traits::Currency;
+use frame_support::{
+	traits::{Currency, EnsureOrigin, Get, Hooks, SortedMembers},
+	BoundedBTreeSet, BoundedVec,
+};
 use frame_system::RawOrigin;
-use sp_std::vec;
+use sp_runtime::{
+	traits::{CheckedDiv, Hash, One, SaturatedConversion, Saturating, Zero},
+	Perbill,
+};
+use sp_std::{boxed::Box, vec, vec::Vec};
+
+const MODEL_SIZE: u64 = 1_000;
+
+/// Fund the incentive program and count a verified inference of `model_id` towards it
+fn use_incentivised_model<T: Config>(model_id: ModelId) {
+	let budget = T::Currency::minimum_balance().saturating_mul(100u32.into());
+	T::Currency::make_free_balance_be(
+		&Pallet::<T>::incentive_pot(),
+		budget.saturating_add(T::Currency::minimum_balance()),
+	);
+	IncentiveFunds::<T>::put(budget);
+	IncentivePerEra::<T>::put(budget);
+	let origin = T::InferenceOrigin::try_successful_origin().unwrap();
+	Pallet::<T>::increment_inference_count(origin, model_id).unwrap();
+}
+
+/// Register `n` models averaging four stars and rank them among the top models
+fn fill_top_models<T: Config>(n: u32) -> Vec<ModelId> {
+	let ratings = T::RewardMinRatings::get().max(1);
+	(0..n)
+		.map(|i| {
+			let model_id = register_benchmark_model::<T>(&account("top", i, 0));
+			Models::<T>::mutate(model_id, |model| {
+				let model = model.as_mut().unwrap();
+				model.rating_count = ratings;
+				model.total_rating = 4 * u64::from(ratings);
+			});
+			RaterCounts::<T>::insert(model_id, ratings);
+			TopModels::<T>::mutate(|top| top.try_push((model_id, 400)).unwrap());
+			model_id
+		})
+		.collect()
+}
+
+/// Give `who` enough free balance to register a model of `MODEL_SIZE` bytes
+fn fund_account<T: Config>(who: &T::AccountId) {
+	let balance = Pallet::<T>::model_deposit(MODEL_SIZE * 2)
+		.saturating_add(T::RegistrationFee::get())
+		.saturating_add(T::Currency::minimum_balance());
+	T::Currency::make_free_balance_be(who, balance);
+}
+
+/// Register a model owned by `owner` and return its ID
+fn register_benchmark_model<T: Config>(owner: &T::AccountId) -> ModelId {
+	fund_account::<T>(owner);
+	let model_id = NextModelId::<T>::get();
+	let _ = Pallet::<T>::register_model(
+		RawOrigin::Signed(owner.clone()).into(),
+		Pallet::<T>::benchmark_cid(model_id),
+		Pallet::<T>::benchmark_name(model_id),
+		b"Description".to_vec(),
+		ModelType::Classification,
+		1000u32.into(),
+		License::Mit,
+		MODEL_SIZE,
+		vec![],
+	);
+	model_id
+}
+
+/// Require registrants to be allowlisted, with `who` on the allowlist
+fn require_allowlist<T: Config>(who: &T::AccountId) {
+	AllowlistRequired::<T>::put(true);
+	T::DeveloperAllowlist::add(who);
+}
+
+/// Put `model_id` back to awaiting approval of its registration
+fn set_pending<T: Config>(model_id: ModelId) {
+	let was_active = Models::<T>::mutate(model_id, |model| {
+		let model = model.as_mut().unwrap();
+		core::mem::replace(&mut model.status, ModelStatus::Pending) == ModelStatus::Active
+	});
+	if was_active {
+		GlobalStats::<T>::mutate(|stats| stats.active_models.saturating_dec());
+	}
+}
+
+/// Create a collection owned by `owner` holding all but `free` of
+/// `MaxModelsPerCollection` models
+fn create_full_collection<T: Config>(owner: &T::AccountId, free: u32) -> CollectionId {
+	fund_account::<T>(owner);
+	let collection_id = NextCollectionId::<T>::get();
+	let _ = Pallet::<T>::create_collection(RawOrigin::Signed(owner.clone()).into(), vec![]);
+	for _ in free..T::MaxModelsPerCollection::get() {
+		let model_id = register_benchmark_model::<T>(owner);
+		let _ = Pallet::<T>::add_to_collection(
+			RawOrigin::Signed(owner.clone()).into(),
+			collection_id,
+			model_id,
+		);
+	}
+	collection_id
+}
+
+/// Fill the bookmarks of `who` with all but `free` of `MaxBookmarks` models that
+/// don't exist
+fn fill_bookmarks<T: Config>(who: &T::AccountId, free: u32) {
+	let mut bookmarks = BoundedBTreeSet::new();
+	for model_id in free..T::MaxBookmarks::get() {
+		let _ = bookmarks.try_insert(ModelId::MAX - ModelId::from(model_id));
+	}
+	Bookmarks::<T>::insert(who, bookmarks);
+}
+
+/// Have all but `free` of `MaxWatchers` accounts watch `model_id`
+fn fill_watchers<T: Config>(model_id: ModelId, free: u32) {
+	let watchers =
+		(free..T::MaxWatchers::get()).map(|i| account("watcher", i, 0)).collect::<Vec<_>>();
+	Watchers::<T>::insert(model_id, WatchersOf::<T>::truncate_from(watchers));
+}
+
+/// Fill the update history of `model_id`, so the next update drops an entry
+fn fill_update_history<T: Config>(model_id: ModelId) {
+	let update =
+		MetadataUpdate { field: MetadataField::Price, updated_at: 0, previous: Default::default() };
+	let history = vec![update; T::MaxUpdateHistory::get() as usize];
+	UpdateHistory::<T>::insert(model_id, UpdateHistoryOf::<T>::truncate_from(history));
+}
+
+/// Register `count` datasets and return their IDs
+fn register_benchmark_datasets<T: Config>(count: u32) -> Vec<DatasetId> {
+	let curator: T::AccountId = account("curator", 0, 0);
+	let balance = T::RegistrationFee::get()
+		.saturating_mul((count + 1).into())
+		.saturating_add(T::Currency::minimum_balance());
+	T::Currency::make_free_balance_be(&curator, balance);
+	(0..count)
+		.map(|_| {
+			let dataset_id = NextDatasetId::<T>::get();
+			let _ = Pallet::<T>::register_dataset(
+				RawOrigin::Signed(curator.clone()).into(),
+				b"QmYwAPJzv5CZsnA625s3Xf2nemtYgPpHdWEz79ojWnPbdG".to_vec(),
+				b"Dataset".to_vec(),
+				License::Mit,
+			);
+			dataset_id
+		})
+		.collect()
+}
+
+/// `count` shares adding up to 100%, the first held by `owner`
+fn even_shares<T: Config>(owner: &T::AccountId, count: u32) -> Vec<(T::AccountId, Perbill)> {
+	let part = Perbill::from_rational(1, count);
+	(0..count)
+		.map(|i| {
+			let holder = if i == 0 { owner.clone() } else { account("holder", i, 0) };
+			let share = if i + 1 == count {
+				Perbill::from_parts(Perbill::one().deconstruct() - (count - 1) * part.deconstruct())
+			} else {
+				part
+			};
+			(holder, share)
+		})
+		.collect()
+}
+
+/// Split `model_id` of `owner` into `shares`
+fn fractionalize<T: Config>(
+	owner: &T::AccountId,
+	model_id: ModelId,
+	shares: Vec<(T::AccountId, Perbill)>,
+) {
+	let _ =
+		Pallet::<T>::fractionalize_model(RawOrigin::Signed(owner.clone()).into(), model_id, shares);
+}
 
 #[benchmarks]
 mod benchmarks {
 	use super::*;
 
 	#[benchmark]
-	fn register_model() {
+	fn register_model(d: Linear<0, { T::MaxDatasetsPerModel::get() }>) {
 		let caller: T::AccountId = whitelisted_caller();
+		let datasets = register_benchmark_datasets::<T>(d);
 		let ipfs_cid = b"QmYwAPJzv5CZsnA625s3Xf2nemtYgPpHdWEz79ojWnPbdG".to_vec();
 		let name = b"Benchmark Model".to_vec();
 		let description = b"A model used for benchmarking".to_vec();
-		
+
 		// Fund the caller
-		let min_balance = T::MinimumModelStake::get() + T::RegistrationFee::get();
-		T::Currency::make_free_balance_be(&caller, min_balance);
+		fund_account::<T>(&caller);
+		// Worst case: registrants must be allowlisted
+		require_allowlist::<T>(&caller);
 
 		#[extrinsic_call]
 		register_model(
@@ -30,92 +205,1057 @@ mod benchmarks {
 			name,
 			description,
 			ModelType::Classification,
-			1000u128,
+			1000u32.into(),
+			License::Mit,
+			MODEL_SIZE,
+			datasets,
 		);
 
 		assert!(Models::<T>::contains_key(0));
 	}
 
+	#[benchmark]
+	fn register_dataset() {
+		let caller: T::AccountId = whitelisted_caller();
+		fund_account::<T>(&caller);
+		let name = vec![b'x'; T::MaxNameLength::get() as usize];
+
+		#[extrinsic_call]
+		_(
+			RawOrigin::Signed(caller),
+			b"QmYwAPJzv5CZsnA625s3Xf2nemtYgPpHdWEz79ojWnPbdG".to_vec(),
+			name,
+			License::Mit,
+		);
+
+		assert!(Datasets::<T>::contains_key(0));
+	}
+
+	#[benchmark]
+	fn fork_model() {
+		let parent_id = register_benchmark_model::<T>(&account("parent", 0, 0));
+		let caller: T::AccountId = whitelisted_caller();
+		fund_account::<T>(&caller);
+		require_allowlist::<T>(&caller);
+		let parent_share = T::MaxParentShare::get();
+
+		#[extrinsic_call]
+		_(
+			RawOrigin::Signed(caller),
+			parent_id,
+			Pallet::<T>::benchmark_cid(parent_id + 1),
+			b"Benchmark Fork".to_vec(),
+			b"A fork used for benchmarking".to_vec(),
+			1000u32.into(),
+			License::Mit,
+			MODEL_SIZE,
+			parent_share,
+		);
+
+		let fork_id = parent_id + 1;
+		assert_eq!(Models::<T>::get(fork_id).and_then(|model| model.parent), Some(parent_id));
+		assert_eq!(ParentShare::<T>::get(fork_id), Some(parent_share));
+	}
+
+	#[benchmark]
+	fn deprecate_model() {
+		let caller: T::AccountId = whitelisted_caller();
+		let model_id = register_benchmark_model::<T>(&caller);
+		let successor = register_benchmark_model::<T>(&caller);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), model_id, Some(successor));
+
+		assert_eq!(Successors::<T>::get(model_id), Some(successor));
+	}
+
+	#[benchmark]
+	fn bookmark_model() {
+		let caller: T::AccountId = whitelisted_caller();
+		let model_id = register_benchmark_model::<T>(&caller);
+		fill_bookmarks::<T>(&caller, 1);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller.clone()), model_id);
+
+		assert!(Bookmarks::<T>::get(&caller).contains(&model_id));
+	}
+
+	#[benchmark]
+	fn unbookmark_model() {
+		let caller: T::AccountId = whitelisted_caller();
+		fill_bookmarks::<T>(&caller, 0);
+		let model_id = ModelId::MAX;
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller.clone()), model_id);
+
+		assert!(!Bookmarks::<T>::get(&caller).contains(&model_id));
+	}
+
+	#[benchmark]
+	fn watch_model() {
+		let model_id = register_benchmark_model::<T>(&account("owner", 0, 0));
+		fill_watchers::<T>(model_id, 1);
+		let caller: T::AccountId = whitelisted_caller();
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller.clone()), model_id);
+
+		assert!(Watchers::<T>::get(model_id).contains(&caller));
+	}
+
+	#[benchmark]
+	fn unwatch_model() {
+		let model_id = register_benchmark_model::<T>(&account("owner", 0, 0));
+		fill_watchers::<T>(model_id, 0);
+		let caller: T::AccountId = account("watcher", T::MaxWatchers::get() - 1, 0);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller.clone()), model_id);
+
+		assert!(!Watchers::<T>::get(model_id).contains(&caller));
+	}
+
+	#[benchmark]
+	fn claim_fee_rebate() {
+		let owner: T::AccountId = whitelisted_caller();
+		let model_id = register_benchmark_model::<T>(&owner);
+		let ratings = T::RebateMinRatings::get().max(1);
+		Models::<T>::mutate(model_id, |model| {
+			let model = model.as_mut().unwrap();
+			model.rating_count = ratings;
+			model.total_rating = 5 * u64::from(ratings);
+		});
+		RaterCounts::<T>::insert(model_id, ratings);
+		let pot = Pallet::<T>::fee_pot();
+		T::Currency::make_free_balance_be(
+			&pot,
+			T::RegistrationFee::get().saturating_add(T::Currency::minimum_balance()),
+		);
+		// Worst case: one era due, leaving part of the fee to rebate later
+		frame_system::Pallet::<T>::set_block_number(
+			frame_system::Pallet::<T>::block_number().saturating_add(T::RentEraLength::get()),
+		);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(owner), model_id);
+
+		assert!(RebatableFees::<T>::get(model_id) < T::RegistrationFee::get());
+	}
+
+	#[benchmark]
+	fn fund_incentives() -> Result<(), BenchmarkError> {
+		let origin =
+			T::IncentiveOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		let amount = T::Currency::minimum_balance().saturating_mul(100u32.into());
+		T::Currency::make_free_balance_be(
+			&T::IncentiveSource::get(),
+			amount.saturating_mul(2u32.into()),
+		);
+
+		#[extrinsic_call]
+		_(origin as T::RuntimeOrigin, amount, amount);
+
+		assert_eq!(IncentiveFunds::<T>::get(), amount);
+		Ok(())
+	}
+
+	#[benchmark]
+	fn claim_incentive() {
+		let owner: T::AccountId = whitelisted_caller();
+		let model_id = register_benchmark_model::<T>(&owner);
+		use_incentivised_model::<T>(model_id);
+		// Worst case: the model's usage of the last era is credited first
+		frame_system::Pallet::<T>::set_block_number(
+			frame_system::Pallet::<T>::block_number().saturating_add(T::RentEraLength::get()),
+		);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(owner), model_id);
+
+		assert!(!IncentiveUsage::<T>::contains_key(model_id));
+		assert!(!IncentiveEarnings::<T>::contains_key(model_id));
+	}
+
+	#[benchmark]
+	fn credit_era_rewards(n: Linear<0, { T::MaxRewardedModels::get() }>) {
+		let models = fill_top_models::<T>(n);
+		let era = T::RentEraLength::get();
+
+		#[block]
+		{
+			Pallet::<T>::on_initialize(era);
+		}
+
+		if !T::RewardPerEra::get().is_zero() {
+			for model_id in models {
+				assert!(EraRewards::<T>::contains_key(model_id));
+			}
+		}
+	}
+
+	#[benchmark]
+	fn enact_deactivations(n: Linear<0, { T::MaxDeactivationsPerBlock::get() }>) {
+		// Outside of era boundaries, where rewards are credited
+		let at = T::RentEraLength::get().saturating_add(One::one());
+		let models: Vec<ModelId> = (0..n)
+			.map(|i| {
+				let model_id = register_benchmark_model::<T>(&account("owner", i, 0));
+				ScheduledDeactivations::<T>::try_append(at, model_id).unwrap();
+				PendingDeactivations::<T>::insert(model_id, at);
+				model_id
+			})
+			.collect();
+
+		#[block]
+		{
+			Pallet::<T>::on_initialize(at);
+		}
+
+		for model_id in models {
+			assert_eq!(Models::<T>::get(model_id).unwrap().status, ModelStatus::Deactivated);
+		}
+	}
+
+	#[benchmark]
+	fn claim_reward() {
+		let owner: T::AccountId = whitelisted_caller();
+		let model_id = register_benchmark_model::<T>(&owner);
+		EraRewards::<T>::insert(model_id, T::Currency::minimum_balance());
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(owner), model_id);
+
+		assert!(!EraRewards::<T>::contains_key(model_id));
+	}
+
+	#[benchmark]
+	fn create_collection() {
+		let caller: T::AccountId = whitelisted_caller();
+		fund_account::<T>(&caller);
+		let name = vec![b'x'; T::MaxNameLength::get() as usize];
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), name);
+
+		assert!(Collections::<T>::contains_key(0));
+	}
+
+	#[benchmark]
+	fn add_to_collection() {
+		let caller: T::AccountId = whitelisted_caller();
+		let collection_id = create_full_collection::<T>(&caller, 1);
+		let model_id = register_benchmark_model::<T>(&caller);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), collection_id, model_id);
+
+		assert!(Collections::<T>::get(collection_id).unwrap().models.contains(&model_id));
+	}
+
+	#[benchmark]
+	fn remove_from_collection() {
+		let caller: T::AccountId = whitelisted_caller();
+		let collection_id = create_full_collection::<T>(&caller, 0);
+		let model_id = NextModelId::<T>::get() - 1;
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), collection_id, model_id);
+
+		assert!(!Collections::<T>::get(collection_id).unwrap().models.contains(&model_id));
+	}
+
 	#[benchmark]
 	fn update_model_metadata() {
 		let caller: T::AccountId = whitelisted_caller();
-		let ipfs_cid = b"QmYwAPJzv5CZsnA625s3Xf2nemtYgPpHdWEz79ojWnPbdG".to_vec();
-		
-		// Setup: register a model first
-		let min_balance = T::MinimumModelStake::get() + T::RegistrationFee::get();
-		T::Currency::make_free_balance_be(&caller, min_balance);
-		
-		let _ = Pallet::<T>::register_model(
-			RawOrigin::Signed(caller.clone()).into(),
-			ipfs_cid,
-			b"Model".to_vec(),
-			b"Description".to_vec(),
-			ModelType::Classification,
-			1000u128,
+		let model_id = register_benchmark_model::<T>(&caller);
+		fill_watchers::<T>(model_id, 0);
+		fill_update_history::<T>(model_id);
+		let description = vec![b'x'; T::MaxDescriptionLength::get() as usize];
+
+		#[extrinsic_call]
+		update_model_metadata(
+			RawOrigin::Signed(caller),
+			model_id,
+			Some(2000u32.into()),
+			Some(description),
+			Some(ModelStatus::Paused),
+			Some(License::Apache2),
 		);
 
+		let model = Models::<T>::get(model_id).unwrap();
+		assert_eq!(model.price, 2000u128);
+		assert_eq!(model.status, ModelStatus::Paused);
+		assert_eq!(model.license, License::Apache2);
+	}
+
+	#[benchmark]
+	fn update_model_price() {
+		let caller: T::AccountId = whitelisted_caller();
+		let model_id = register_benchmark_model::<T>(&caller);
+		fill_watchers::<T>(model_id, 0);
+		fill_update_history::<T>(model_id);
+
 		#[extrinsic_call]
 		update_model_metadata(
 			RawOrigin::Signed(caller),
-			0,
-			Some(2000u128),
+			model_id,
+			Some(2000u32.into()),
+			None,
 			None,
 			None,
 		);
 
-		let model = Models::<T>::get(0).unwrap();
+		let model = Models::<T>::get(model_id).unwrap();
 		assert_eq!(model.price, 2000u128);
 	}
 
+	#[benchmark]
+	fn rename_model() {
+		let caller: T::AccountId = whitelisted_caller();
+		let model_id = register_benchmark_model::<T>(&caller);
+		let name = vec![b'x'; T::MaxNameLength::get() as usize];
+		fill_update_history::<T>(model_id);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), model_id, name.clone(), Some(ModelType::Generative));
+
+		let model = Models::<T>::get(model_id).unwrap();
+		assert_eq!(model.name.into_inner(), name);
+		assert_eq!(model.model_type, ModelType::Generative);
+	}
+
+	#[benchmark]
+	fn lock_metadata() {
+		let caller: T::AccountId = whitelisted_caller();
+		let model_id = register_benchmark_model::<T>(&caller);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), model_id);
+
+		assert!(LockedMetadata::<T>::contains_key(model_id));
+	}
+
 	#[benchmark]
 	fn deactivate_model() {
 		let caller: T::AccountId = whitelisted_caller();
-		let ipfs_cid = b"QmYwAPJzv5CZsnA625s3Xf2nemtYgPpHdWEz79ojWnPbdG".to_vec();
-		
-		// Setup: register a model first
-		let min_balance = T::MinimumModelStake::get() + T::RegistrationFee::get();
-		T::Currency::make_free_balance_be(&caller, min_balance);
-		
-		let _ = Pallet::<T>::register_model(
-			RawOrigin::Signed(caller.clone()).into(),
-			ipfs_cid,
-			b"Model".to_vec(),
-			b"Description".to_vec(),
-			ModelType::Classification,
-			1000u128,
-		);
+		let model_id = register_benchmark_model::<T>(&caller);
+		// Worst case: a scheduled deactivation is brought forward
+		let at = frame_system::Pallet::<T>::block_number().saturating_add(One::one());
+		ScheduledDeactivations::<T>::try_append(at, model_id).unwrap();
+		PendingDeactivations::<T>::insert(model_id, at);
 
 		#[extrinsic_call]
-		deactivate_model(RawOrigin::Signed(caller), 0);
+		deactivate_model(RawOrigin::Signed(caller), model_id, None);
 
-		let model = Models::<T>::get(0).unwrap();
+		let model = Models::<T>::get(model_id).unwrap();
 		assert_eq!(model.status, ModelStatus::Deactivated);
 	}
 
+	#[benchmark]
+	fn reactivate_model() {
+		let caller: T::AccountId = whitelisted_caller();
+		let model_id = register_benchmark_model::<T>(&caller);
+		let _ =
+			Pallet::<T>::deactivate_model(RawOrigin::Signed(caller.clone()).into(), model_id, None);
+		fund_account::<T>(&caller);
+
+		// Worst case: the deposit of a deactivated model is reserved again
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), model_id);
+
+		assert_eq!(Models::<T>::get(model_id).unwrap().status, ModelStatus::Active);
+	}
+
 	#[benchmark]
 	fn rate_model() {
 		let owner: T::AccountId = whitelisted_caller();
 		let rater: T::AccountId = account("rater", 0, 0);
-		let ipfs_cid = b"QmYwAPJzv5CZsnA625s3Xf2nemtYgPpHdWEz79ojWnPbdG".to_vec();
-		
-		// Setup: register a model first
-		let min_balance = T::MinimumModelStake::get() + T::RegistrationFee::get();
-		T::Currency::make_free_balance_be(&owner, min_balance);
-		
-		let _ = Pallet::<T>::register_model(
+		let model_id = register_benchmark_model::<T>(&owner);
+		// Worst case: the model enters a full board of top models at the top
+		fill_top_models::<T>(T::MaxRewardedModels::get());
+		let ratings = T::RewardMinRatings::get();
+		Models::<T>::mutate(model_id, |model| {
+			let model = model.as_mut().unwrap();
+			model.rating_count = ratings;
+			model.total_rating = 5 * u64::from(ratings);
+		});
+		RaterCounts::<T>::insert(model_id, ratings);
+		// with a full window of recent ratings
+		let recent = vec![5; T::RatingWindow::get() as usize];
+		RecentRatings::<T>::insert(model_id, BoundedVec::truncate_from(recent));
+
+		// Worst case: a funded rater whose age is checked
+		T::Currency::make_free_balance_be(
+			&rater,
+			T::MinRaterBalance::get().max(T::Currency::minimum_balance()),
+		);
+		let now = frame_system::Pallet::<T>::block_number();
+		AccountCreated::<T>::insert(&rater, now);
+		frame_system::Pallet::<T>::set_block_number(now.saturating_add(T::MinRaterAge::get()));
+		// who used all but one rating of this era
+		let now = frame_system::Pallet::<T>::block_number();
+		if let Some(era) = now.checked_div(&T::RentEraLength::get()) {
+			let rated = (1..T::MaxRatingsPerEra::get()).map(|n| model_id + u64::from(n));
+			RatedInEra::<T>::insert(&rater, (era, BoundedVec::truncate_from(rated.collect())));
+		}
+		// with a paid inference behind the rating
+		T::InferenceReceipts::create_receipt(&rater, model_id);
+		// Worst case: the longest review CID
+		let review_cid = vec![b'b'; T::MaxCidLength::get() as usize];
+
+		#[extrinsic_call]
+		rate_model(RawOrigin::Signed(rater.clone()), model_id, 5, Some(review_cid));
+
+		let model = Models::<T>::get(model_id).unwrap();
+		assert_eq!(model.rating_count, ratings + 1);
+		assert_eq!(RaterCounts::<T>::get(model_id), ratings + 1);
+		assert!(RatingReviews::<T>::contains_key(model_id, &rater));
+	}
+
+	#[benchmark]
+	fn propose_size_correction() {
+		let caller: T::AccountId = whitelisted_caller();
+		let model_id = register_benchmark_model::<T>(&caller);
+
+		#[extrinsic_call]
+		propose_size_correction(RawOrigin::Signed(caller), model_id, MODEL_SIZE * 2);
+
+		assert_eq!(PendingSizeCorrections::<T>::get(model_id), Some(MODEL_SIZE * 2));
+	}
+
+	#[benchmark]
+	fn approve_size_correction() -> Result<(), BenchmarkError> {
+		let owner: T::AccountId = whitelisted_caller();
+		let model_id = register_benchmark_model::<T>(&owner);
+		PendingSizeCorrections::<T>::insert(model_id, MODEL_SIZE * 2);
+		let origin =
+			T::GovernanceOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+
+		#[extrinsic_call]
+		approve_size_correction(origin as T::RuntimeOrigin, model_id);
+
+		let model = Models::<T>::get(model_id).unwrap();
+		assert_eq!(model.size_bytes, MODEL_SIZE * 2);
+		Ok(())
+	}
+
+	#[benchmark]
+	fn extend_admin_sunset() -> Result<(), BenchmarkError> {
+		let new_sunset = AdminSunset::<T>::get() + 1u32.into();
+		let origin = T::SunsetExtensionOrigin::try_successful_origin()
+			.map_err(|_| BenchmarkError::Weightless)?;
+
+		#[extrinsic_call]
+		extend_admin_sunset(origin as T::RuntimeOrigin, new_sunset);
+
+		assert_eq!(AdminSunset::<T>::get(), new_sunset);
+		Ok(())
+	}
+
+	#[benchmark]
+	fn add_model_callback() {
+		let caller: T::AccountId = whitelisted_caller();
+		let model_id = register_benchmark_model::<T>(&caller);
+		let endpoint = CallbackEndpoint::Cid(
+			b"QmYwAPJzv5CZsnA625s3Xf2nemtYgPpHdWEz79ojWnPbdG".to_vec().try_into().unwrap(),
+		);
+
+		#[extrinsic_call]
+		add_model_callback(RawOrigin::Signed(caller), model_id, endpoint, callback_events::ALL);
+
+		assert_eq!(ModelCallbacks::<T>::get(model_id).len(), 1);
+	}
+
+	#[benchmark]
+	fn remove_model_callback() {
+		let caller: T::AccountId = whitelisted_caller();
+		let model_id = register_benchmark_model::<T>(&caller);
+		let _ = Pallet::<T>::add_model_callback(
+			RawOrigin::Signed(caller.clone()).into(),
+			model_id,
+			CallbackEndpoint::UrlHash(Default::default()),
+			callback_events::ALL,
+		);
+
+		#[extrinsic_call]
+		remove_model_callback(RawOrigin::Signed(caller), model_id, 0);
+
+		assert!(ModelCallbacks::<T>::get(model_id).is_empty());
+	}
+
+	#[benchmark]
+	fn export_models(n: Linear<1, { T::MaxExportBatch::get() }>) {
+		let caller: T::AccountId = whitelisted_caller();
+		let model_ids: Vec<ModelId> =
+			(0..n).map(|_| register_benchmark_model::<T>(&caller)).collect();
+
+		#[extrinsic_call]
+		export_models(RawOrigin::Signed(caller), model_ids);
+	}
+
+	#[benchmark]
+	fn import_model() {
+		let relayer: T::AccountId = whitelisted_caller();
+		// Same seed yields the same key, so the owner can be derived before signing
+		let (_, owner) = T::BenchmarkHelper::create_signature(b"//AiRegistry", &[]);
+		let packet = MigrationPacket::<T> {
+			version: MIGRATION_PACKET_VERSION,
+			source_chain: T::Hashing::hash(b"source-chain"),
+			source_model_id: 7,
+			owner: owner.clone(),
+			ipfs_cid: b"QmYwAPJzv5CZsnA625s3Xf2nemtYgPpHdWEz79ojWnPbdG"
+				.to_vec()
+				.try_into()
+				.unwrap(),
+			name: b"Model".to_vec().try_into().unwrap(),
+			description: b"Description".to_vec().try_into().unwrap(),
+			model_type: ModelType::Classification,
+			license: License::Mit,
+			size_bytes: MODEL_SIZE,
+			price: 1000,
+			created_at: 1,
+			exported_at: 2,
+		};
+		let (signature, _) = T::BenchmarkHelper::create_signature(
+			b"//AiRegistry",
+			&(MIGRATION_PACKET_CONTEXT, &packet).encode(),
+		);
+		fund_account::<T>(&owner);
+		require_allowlist::<T>(&owner);
+
+		#[extrinsic_call]
+		import_model(RawOrigin::Signed(relayer), packet, signature);
+
+		assert!(ImportedPackets::<T>::contains_key(T::Hashing::hash(b"source-chain"), 7));
+	}
+
+	#[benchmark]
+	fn collect_rent() {
+		let owner: T::AccountId = whitelisted_caller();
+		let model_id = register_benchmark_model::<T>(&owner);
+
+		// Worst case: idle long enough for the rent to exhaust the whole deposit
+		let deposit = Models::<T>::get(model_id).unwrap().deposit;
+		let rent: u128 = T::RentPerEra::get().saturated_into();
+		let eras = (deposit / rent.max(1)).saturating_add(1).saturated_into::<u32>();
+		let idle = T::RentEraLength::get()
+			.saturating_mul(eras.saturating_add(T::RentFreeEras::get()).into());
+		frame_system::Pallet::<T>::set_block_number(
+			frame_system::Pallet::<T>::block_number().saturating_add(idle),
+		);
+
+		#[extrinsic_call]
+		collect_rent(RawOrigin::Signed(owner), model_id);
+
+		assert!(RentExhausted::<T>::contains_key(model_id));
+	}
+
+	#[benchmark]
+	fn top_up_deposit() {
+		let owner: T::AccountId = whitelisted_caller();
+		let model_id = register_benchmark_model::<T>(&owner);
+		// Worst case: the top-up takes the model off the reaping queue
+		RentExhausted::<T>::insert(model_id, frame_system::Pallet::<T>::block_number());
+
+		#[extrinsic_call]
+		top_up_deposit(RawOrigin::Signed(owner), model_id, 1000u32.into());
+
+		assert!(!RentExhausted::<T>::contains_key(model_id));
+	}
+
+	#[benchmark]
+	fn reap_model(c: Linear<0, { T::MaxCollectionsPerModel::get() }>) {
+		let owner: T::AccountId = whitelisted_caller();
+		let model_id = register_benchmark_model::<T>(&owner);
+		use_incentivised_model::<T>(model_id);
+		let now = frame_system::Pallet::<T>::block_number();
+		RentExhausted::<T>::insert(model_id, now);
+		// Worst case: the model is in `c` collections and still has a deactivation and
+		// a price change scheduled
+		for _ in 0..c {
+			let collection_id = NextCollectionId::<T>::get();
+			let _ = Pallet::<T>::create_collection(RawOrigin::Signed(owner.clone()).into(), vec![]);
+			let _ = Pallet::<T>::add_to_collection(
+				RawOrigin::Signed(owner.clone()).into(),
+				collection_id,
+				model_id,
+			);
+		}
+		let at = now.saturating_add(One::one());
+		ScheduledDeactivations::<T>::try_append(at, model_id).unwrap();
+		PendingDeactivations::<T>::insert(model_id, at);
+		ScheduledPriceChanges::<T>::try_append(at, model_id).unwrap();
+		PendingPriceChanges::<T>::insert(model_id, (at, 0));
+
+		#[extrinsic_call]
+		reap_model(RawOrigin::Signed(owner), model_id);
+
+		assert!(!Models::<T>::contains_key(model_id));
+		assert!(!ModelCollections::<T>::contains_key(model_id));
+		assert!(!PendingPriceChanges::<T>::contains_key(model_id));
+	}
+
+	#[benchmark]
+	fn slash_model(r: Linear<0, { T::ReportThreshold::get() }>) -> Result<(), BenchmarkError> {
+		let owner: T::AccountId = whitelisted_caller();
+		let model_id = register_benchmark_model::<T>(&owner);
+		let now = frame_system::Pallet::<T>::block_number();
+		for i in 0..r {
+			let reporter: T::AccountId = account("reporter", i, 0);
+			ModelReports::<T>::insert(model_id, reporter, now);
+		}
+		ReportCounts::<T>::insert(model_id, r);
+		let origin =
+			T::SlashOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+
+		#[extrinsic_call]
+		slash_model(origin as T::RuntimeOrigin, model_id);
+
+		assert_eq!(Models::<T>::get(model_id).unwrap().status, ModelStatus::Deactivated);
+		assert_eq!(ModelReports::<T>::iter_prefix(model_id).count(), 0);
+		Ok(())
+	}
+
+	#[benchmark]
+	fn transfer_model() {
+		let owner: T::AccountId = whitelisted_caller();
+		let model_id = register_benchmark_model::<T>(&owner);
+		let dest: T::AccountId = account("dest", 0, 0);
+		fund_account::<T>(&dest);
+
+		#[extrinsic_call]
+		transfer_model(RawOrigin::Signed(owner), model_id, dest.clone());
+
+		assert_eq!(Models::<T>::get(model_id).unwrap().owner, dest);
+	}
+
+	#[benchmark]
+	fn sync_model_owner() -> Result<(), BenchmarkError> {
+		let owner: T::AccountId = whitelisted_caller();
+		let model_id = register_benchmark_model::<T>(&owner);
+		let holder: T::AccountId = account("holder", 0, 0);
+		fund_account::<T>(&holder);
+		// The NFT changes hands outside the registry, e.g. in a marketplace sale
+		T::ModelNfts::transfer(model_id, &holder).map_err(|_| BenchmarkError::Weightless)?;
+		if T::ModelNfts::owner(model_id).is_none() {
+			return Err(BenchmarkError::Weightless);
+		}
+
+		#[extrinsic_call]
+		sync_model_owner(RawOrigin::Signed(holder.clone()), model_id);
+
+		assert_eq!(Models::<T>::get(model_id).unwrap().owner, holder);
+		Ok(())
+	}
+
+	#[benchmark]
+	fn fractionalize_model(s: Linear<1, { T::MaxShareholders::get() }>) {
+		let owner: T::AccountId = whitelisted_caller();
+		let model_id = register_benchmark_model::<T>(&owner);
+		let shares = even_shares::<T>(&owner, s);
+
+		#[extrinsic_call]
+		fractionalize_model(RawOrigin::Signed(owner), model_id, shares);
+
+		assert_eq!(Shareholders::<T>::get(model_id).unwrap().len(), s as usize);
+	}
+
+	#[benchmark]
+	fn transfer_shares() {
+		let owner: T::AccountId = whitelisted_caller();
+		let model_id = register_benchmark_model::<T>(&owner);
+		fractionalize::<T>(&owner, model_id, even_shares::<T>(&owner, 2));
+		let dest: T::AccountId = account("dest", 0, 0);
+		let share = Perbill::from_percent(50);
+
+		// The sender gives up their whole share to a new shareholder
+		#[extrinsic_call]
+		transfer_shares(RawOrigin::Signed(owner), model_id, dest.clone(), share);
+
+		assert!(Shareholders::<T>::get(model_id).unwrap().contains(&(dest, share)));
+	}
+
+	#[benchmark]
+	fn propose_metadata_update() {
+		let owner: T::AccountId = whitelisted_caller();
+		let model_id = register_benchmark_model::<T>(&owner);
+		fractionalize::<T>(&owner, model_id, vec![(owner.clone(), Perbill::one())]);
+		let description = vec![b'x'; T::MaxDescriptionLength::get() as usize];
+
+		// The proposer's share alone is a majority, so the change is applied
+		#[extrinsic_call]
+		propose_metadata_update(
+			RawOrigin::Signed(owner),
+			model_id,
+			Some(2000u32.into()),
+			Some(description),
+			Some(ModelStatus::Paused),
+		);
+
+		assert_eq!(Models::<T>::get(model_id).unwrap().status, ModelStatus::Paused);
+	}
+
+	#[benchmark]
+	fn approve_metadata_update() {
+		let owner: T::AccountId = whitelisted_caller();
+		let model_id = register_benchmark_model::<T>(&owner);
+		let shares = even_shares::<T>(&owner, 2);
+		let approver = shares[1].0.clone();
+		fractionalize::<T>(&owner, model_id, shares);
+		let _ = Pallet::<T>::propose_metadata_update(
+			RawOrigin::Signed(owner.clone()).into(),
+			model_id,
+			Some(2000u32.into()),
+			Some(vec![b'x'; T::MaxDescriptionLength::get() as usize]),
+			Some(ModelStatus::Paused),
+		);
+
+		#[extrinsic_call]
+		approve_metadata_update(RawOrigin::Signed(approver), model_id, owner);
+
+		assert_eq!(Models::<T>::get(model_id).unwrap().status, ModelStatus::Paused);
+	}
+
+	#[benchmark]
+	fn cancel_metadata_update() {
+		let owner: T::AccountId = whitelisted_caller();
+		let model_id = register_benchmark_model::<T>(&owner);
+		fractionalize::<T>(&owner, model_id, even_shares::<T>(&owner, 2));
+		let _ = Pallet::<T>::propose_metadata_update(
+			RawOrigin::Signed(owner.clone()).into(),
+			model_id,
+			Some(2000u32.into()),
+			None,
+			None,
+		);
+
+		#[extrinsic_call]
+		cancel_metadata_update(RawOrigin::Signed(owner.clone()), model_id);
+
+		assert!(!MetadataProposals::<T>::contains_key(model_id, owner));
+	}
+
+	#[benchmark]
+	fn report_model() {
+		let owner: T::AccountId = whitelisted_caller();
+		let model_id = register_benchmark_model::<T>(&owner);
+		let reporter: T::AccountId = account("reporter", 0, 0);
+		fund_account::<T>(&reporter);
+		// Worst case: the reporter is old enough to be aged and suspends the model
+		let now = frame_system::Pallet::<T>::block_number();
+		frame_system::Pallet::<T>::set_block_number(now.saturating_add(T::MinRaterAge::get()));
+		ReportCounts::<T>::insert(model_id, T::ReportThreshold::get().saturating_sub(1));
+		// and its scheduled deactivation is cancelled
+		let at = frame_system::Pallet::<T>::block_number().saturating_add(One::one());
+		ScheduledDeactivations::<T>::try_append(at, model_id).unwrap();
+		PendingDeactivations::<T>::insert(model_id, at);
+
+		#[extrinsic_call]
+		report_model(RawOrigin::Signed(reporter.clone()), model_id);
+
+		assert!(ModelReports::<T>::contains_key(model_id, &reporter));
+		assert_eq!(Models::<T>::get(model_id).unwrap().status, ModelStatus::UnderReview);
+		assert!(!PendingDeactivations::<T>::contains_key(model_id));
+	}
+
+	#[benchmark]
+	fn dismiss_reports(
+		r: Linear<1, { T::ReportThreshold::get().max(1) }>,
+	) -> Result<(), BenchmarkError> {
+		let owner: T::AccountId = whitelisted_caller();
+		let model_id = register_benchmark_model::<T>(&owner);
+		let now = frame_system::Pallet::<T>::block_number();
+		for i in 0..r {
+			let reporter: T::AccountId = account("reporter", i, 0);
+			ModelReports::<T>::insert(model_id, reporter, now);
+		}
+		ReportCounts::<T>::insert(model_id, r);
+		UnderReview::<T>::insert(model_id, ModelStatus::Active);
+		Models::<T>::mutate(model_id, |model| {
+			model.as_mut().unwrap().status = ModelStatus::UnderReview
+		});
+		let origin =
+			T::SlashOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+
+		#[extrinsic_call]
+		dismiss_reports(origin as T::RuntimeOrigin, model_id);
+
+		assert_eq!(Models::<T>::get(model_id).unwrap().status, ModelStatus::Active);
+		assert_eq!(ModelReports::<T>::iter_prefix(model_id).count(), 0);
+		Ok(())
+	}
+
+	#[benchmark]
+	fn appeal_deactivation() -> Result<(), BenchmarkError> {
+		let owner: T::AccountId = whitelisted_caller();
+		let model_id = register_benchmark_model::<T>(&owner);
+		Pallet::<T>::do_slash(model_id).map_err(|_| BenchmarkError::Weightless)?;
+		fund_account::<T>(&owner);
+		T::Currency::make_free_balance_be(
+			&owner,
+			T::Currency::free_balance(&owner).saturating_add(T::AppealBond::get()),
+		);
+		let evidence_cid = vec![b'b'; T::MaxCidLength::get() as usize];
+
+		#[extrinsic_call]
+		appeal_deactivation(RawOrigin::Signed(owner), model_id, evidence_cid);
+
+		assert!(Appeals::<T>::contains_key(model_id));
+		Ok(())
+	}
+
+	#[benchmark]
+	fn resolve_appeal() -> Result<(), BenchmarkError> {
+		let owner: T::AccountId = whitelisted_caller();
+		let model_id = register_benchmark_model::<T>(&owner);
+		Pallet::<T>::do_slash(model_id).map_err(|_| BenchmarkError::Weightless)?;
+		fund_account::<T>(&owner);
+		T::Currency::make_free_balance_be(
+			&owner,
+			T::Currency::free_balance(&owner).saturating_add(T::AppealBond::get()),
+		);
+		Pallet::<T>::appeal_deactivation(
 			RawOrigin::Signed(owner).into(),
-			ipfs_cid,
-			b"Model".to_vec(),
-			b"Description".to_vec(),
-			ModelType::Classification,
-			1000u128,
+			model_id,
+			vec![b'b'; T::MaxCidLength::get() as usize],
+		)?;
+		let origin =
+			T::GovernanceOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+
+		// Worst case: the appeal is upheld and the model restored
+		#[extrinsic_call]
+		resolve_appeal(origin as T::RuntimeOrigin, model_id, true);
+
+		assert_eq!(Models::<T>::get(model_id).unwrap().status, ModelStatus::Active);
+		Ok(())
+	}
+
+	#[benchmark]
+	fn approve_registration() -> Result<(), BenchmarkError> {
+		let owner: T::AccountId = whitelisted_caller();
+		let model_id = register_benchmark_model::<T>(&owner);
+		set_pending::<T>(model_id);
+		let origin = T::RegistrationApprovalOrigin::try_successful_origin()
+			.map_err(|_| BenchmarkError::Weightless)?;
+
+		#[extrinsic_call]
+		_(origin as T::RuntimeOrigin, model_id);
+
+		assert_eq!(Models::<T>::get(model_id).unwrap().status, ModelStatus::Active);
+		Ok(())
+	}
+
+	#[benchmark]
+	fn reject_registration() -> Result<(), BenchmarkError> {
+		let owner: T::AccountId = whitelisted_caller();
+		let model_id = register_benchmark_model::<T>(&owner);
+		set_pending::<T>(model_id);
+		// Worst case: the owner also scheduled its deactivation
+		let at = frame_system::Pallet::<T>::block_number().saturating_add(One::one());
+		PendingDeactivations::<T>::insert(model_id, at);
+		ScheduledDeactivations::<T>::mutate(at, |models| models.try_push(model_id).unwrap());
+		let origin = T::RegistrationApprovalOrigin::try_successful_origin()
+			.map_err(|_| BenchmarkError::Weightless)?;
+
+		#[extrinsic_call]
+		_(origin as T::RuntimeOrigin, model_id);
+
+		assert_eq!(Models::<T>::get(model_id).unwrap().status, ModelStatus::Deactivated);
+		Ok(())
+	}
+
+	#[benchmark]
+	fn set_allowlist_required() -> Result<(), BenchmarkError> {
+		let origin =
+			T::GovernanceOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+
+		#[extrinsic_call]
+		_(origin as T::RuntimeOrigin, true);
+
+		assert!(AllowlistRequired::<T>::get());
+		Ok(())
+	}
+
+	#[benchmark]
+	fn set_developer_profile() {
+		let caller: T::AccountId = whitelisted_caller();
+		let cid = Some(vec![b'b'; T::MaxCidLength::get() as usize]);
+
+		#[extrinsic_call]
+		_(
+			RawOrigin::Signed(caller.clone()),
+			vec![b'a'; T::MaxNameLength::get() as usize],
+			cid.clone(),
+			cid,
+		);
+
+		assert!(DeveloperProfiles::<T>::contains_key(&caller));
+	}
+
+	#[benchmark]
+	fn clear_developer_profile() {
+		let caller: T::AccountId = whitelisted_caller();
+		let cid = Some(vec![b'b'; T::MaxCidLength::get() as usize]);
+		let _ = Pallet::<T>::set_developer_profile(
+			RawOrigin::Signed(caller.clone()).into(),
+			vec![b'a'; T::MaxNameLength::get() as usize],
+			cid.clone(),
+			cid,
 		);
 
 		#[extrinsic_call]
-		rate_model(RawOrigin::Signed(rater), 0, 5);
+		_(RawOrigin::Signed(caller.clone()));
+
+		assert!(!DeveloperProfiles::<T>::contains_key(&caller));
+	}
+
+	#[benchmark]
+	fn create_organization() {
+		let caller: T::AccountId = whitelisted_caller();
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller.clone()), vec![b'a'; T::MaxNameLength::get() as usize]);
+
+		assert_eq!(OrganizationMembers::<T>::get(0, &caller), Some(OrganizationRole::Admin));
+	}
+
+	#[benchmark]
+	fn set_organization_role() -> Result<(), BenchmarkError> {
+		let caller: T::AccountId = whitelisted_caller();
+		let member: T::AccountId = account("member", 0, 0);
+		Pallet::<T>::create_organization(RawOrigin::Signed(caller.clone()).into(), vec![])?;
+
+		// Worst case: a new member joins as admin
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), 0, member.clone(), Some(OrganizationRole::Admin));
+
+		assert_eq!(Organizations::<T>::get(0).unwrap().admins, 2);
+		Ok(())
+	}
+
+	#[benchmark]
+	fn as_organization() -> Result<(), BenchmarkError> {
+		let caller: T::AccountId = whitelisted_caller();
+		Pallet::<T>::create_organization(RawOrigin::Signed(caller.clone()).into(), vec![])?;
+		let call: <T as Config>::RuntimeCall = Call::<T>::set_developer_profile {
+			display_name: vec![],
+			website_cid: None,
+			contact_cid: None,
+		}
+		.into();
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), 0, Box::new(call));
+
+		assert!(DeveloperProfiles::<T>::contains_key(Pallet::<T>::organization_account(0)));
+		Ok(())
+	}
+
+	#[benchmark]
+	fn schedule_price_change() {
+		let caller: T::AccountId = whitelisted_caller();
+		let model_id = register_benchmark_model::<T>(&caller);
+		// Worst case: a change scheduled for another block is replaced
+		let now = frame_system::Pallet::<T>::block_number();
+		let _ = Pallet::<T>::schedule_price_change(
+			RawOrigin::Signed(caller.clone()).into(),
+			model_id,
+			1u32.into(),
+			now.saturating_add(One::one()),
+		);
+		let at = now.saturating_add(2u32.into());
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), model_id, 2u32.into(), at);
+
+		assert_eq!(PendingPriceChanges::<T>::get(model_id), Some((at, 2)));
+	}
+
+	#[benchmark]
+	fn cancel_price_change() {
+		let caller: T::AccountId = whitelisted_caller();
+		let model_id = register_benchmark_model::<T>(&caller);
+		let at = frame_system::Pallet::<T>::block_number().saturating_add(One::one());
+		let _ = Pallet::<T>::schedule_price_change(
+			RawOrigin::Signed(caller.clone()).into(),
+			model_id,
+			1u32.into(),
+			at,
+		);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), model_id);
+
+		assert!(!PendingPriceChanges::<T>::contains_key(model_id));
+	}
+
+	#[benchmark]
+	fn enact_price_changes(n: Linear<0, { T::MaxPriceChangesPerBlock::get() }>) {
+		// Outside of era boundaries, where rewards are credited
+		let at = T::RentEraLength::get().saturating_add(One::one());
+		let models: Vec<ModelId> = (0..n)
+			.map(|i| {
+				let owner = account("owner", i, 0);
+				let model_id = register_benchmark_model::<T>(&owner);
+				let _ = Pallet::<T>::schedule_price_change(
+					RawOrigin::Signed(owner).into(),
+					model_id,
+					1u32.into(),
+					at,
+				);
+				model_id
+			})
+			.collect();
+
+		#[block]
+		{
+			Pallet::<T>::on_initialize(at);
+		}
+
+		for model_id in models {
+			assert_eq!(Models::<T>::get(model_id).unwrap().price, 1);
+		}
+	}
+
+	#[benchmark]
+	fn deactivate_at() {
+		let caller: T::AccountId = whitelisted_caller();
+		let model_id = register_benchmark_model::<T>(&caller);
+		let successor = register_benchmark_model::<T>(&caller);
+		// Worst case: a deactivation scheduled for another block is replaced
+		let now = frame_system::Pallet::<T>::block_number();
+		let scheduled = now.saturating_add(One::one());
+		ScheduledDeactivations::<T>::try_append(scheduled, model_id).unwrap();
+		PendingDeactivations::<T>::insert(model_id, scheduled);
+		let at = now.saturating_add(T::MaxDeactivationGrace::get());
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), model_id, at, Some(successor));
+
+		assert_eq!(PendingDeactivations::<T>::get(model_id), Some(at));
+		assert_eq!(Successors::<T>::get(model_id), Some(successor));
+	}
+
+	#[benchmark]
+	fn announce_deactivations(n: Linear<0, { T::MaxDeactivationsPerBlock::get() }>) {
+		// Outside of era boundaries, where rewards are credited
+		let now = T::RentEraLength::get().saturating_add(One::one());
+		let at = now.saturating_add(T::DeactivationNotice::get());
+		for i in 0..n {
+			let model_id = register_benchmark_model::<T>(&account("owner", i, 0));
+			ScheduledDeactivations::<T>::try_append(at, model_id).unwrap();
+			PendingDeactivations::<T>::insert(model_id, at);
+			Successors::<T>::insert(model_id, model_id + 1);
+		}
+
+		#[block]
+		{
+			Pallet::<T>::on_initialize(now);
+		}
 
-		let model = Models::<T>::get(0).unwrap();
-		assert_eq!(model.rating_count, 1);
+		assert_eq!(ScheduledDeactivations::<T>::get(at).len() as u32, n);
 	}
 
 	impl_benchmark_test_suite!(Pallet, crate::mock::new_test_ext(), crate::mock::Test);
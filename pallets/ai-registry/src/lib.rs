@@ -1,272 +1,5705 @@
-//! # AI Registry Pallet - Working Version
+//! # AI Registry Pallet
 //!
-//! A simplified but fully functional version of the AI registry pallet
-//! that resolves Substrate trait bound compatibility issues by inlining types.
+//! A production-ready pallet for registering and managing AI models on-chain.
+//!
+//! ## Overview
+//!
+//! The AI Registry pallet enables AI developers to register their models with metadata
+//! stored on IPFS. It provides functionality for:
+//! - Registering new AI models with IPFS CID references
+//! - Updating model metadata (price, status, description)
+//! - Deactivating models
+//! - Rating models based on inference quality
+//! - Querying models by owner or ID
+//!
+//! ## Model Lifecycle
+//!
+//! 1. **Registration**: Developer calls `register_model` with IPFS CID and metadata
+//! 2. **Active**: Model is available for inference requests
+//! 3. **Updated**: Owner can update metadata via `update_model_metadata`
+//! 4. **Rated**: Users who purchased inference can rate via `rate_model`
+//! 5. **Deactivated**: Owner or governance can deactivate via `deactivate_model`
+//!
+//! ## Economic Model
+//!
+//! - Registration requires minimum stake and registration fee
+//! - Model owner sets inference price
+//! - Revenue shared between owner and validators
+//! - Owners can split a model into shares; inference revenue is then paid out by
+//!   share, and metadata changes need the approval of a majority of shares
+//! - Models remember their creator; sales through a marketplace pay them
+//!   `CreatorRoyalty` of the price
+//! - Forks of a model can pass up to `MaxParentShare` of their inference revenue
+//!   to the owner of the model they were forked from
+//! - Registration fees go into a fee pot; highly rated models get part of their
+//!   fee back from it every era
+//! - Governance can fund an incentive budget that is split every era among active
+//!   models by their verified inferences
+//! - Every era, newly minted rewards are split among the best-rated models by
+//!   reputation and can be claimed for their owners
+//!
+//! ## Security
+//!
+//! - Only model owner can update or deactivate
+//! - IPFS CID format validation; an artifact can be registered only once
+//! - Rating restricted to users who paid for inference
+//! - Inference counts only recorded by `InferenceOrigin`, the inference pallet
+//! - Input validation on all parameters
 
 #![cfg_attr(not(feature = "std"), no_std)]
+// `register_model` takes more arguments than clippy allows, and the call constructors
+// generated for it cannot be annotated individually
+#![allow(clippy::too_many_arguments)]
+// The call dispatch generated for calls returning `DispatchResultWithPostInfo`
+// converts their result into the type it already has
+#![allow(clippy::useless_conversion)]
 
 pub use pallet::*;
 
 #[cfg(test)]
-mod tests_new;
+mod mock;
+
+#[cfg(test)]
+mod tests;
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+
+pub mod weights;
+pub use weights::*;
+
+pub mod types;
+pub use types::*;
+
+pub mod origins;
+pub use origins::*;
+
+pub mod traits;
+pub use traits::*;
+
+pub mod migrations;
 
 #[frame_support::pallet]
 pub mod pallet {
-	use codec::{Decode, Encode, MaxEncodedLen};
+	use super::*;
 	use frame_support::{
+		dispatch::{extract_actual_weight, GetDispatchInfo, PostDispatchInfo},
 		pallet_prelude::*,
-		traits::{Currency, ExistenceRequirement},
+		traits::{
+			Contains, Currency, ExistenceRequirement, Imbalance, IsSubType, OnUnbalanced,
+			ReservableCurrency, SortedMembers, WithdrawReasons,
+		},
+		PalletId,
 	};
 	use frame_system::pallet_prelude::*;
-	use scale_info::TypeInfo;
-	use sp_runtime::RuntimeDebug;
-
-	/// Unique identifier for models
-	pub type ModelId = u64;
-
-	/// Type of AI model
-	#[derive(
-		Clone, Copy, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen,
-	)]
-	pub enum ModelType {
-		Classification,
-		Regression,
-		Generative,
-	}
+	use sp_runtime::{
+		traits::{
+			AccountIdConversion, CheckedSub, Dispatchable, Hash, IdentifyAccount,
+			SaturatedConversion, Saturating, Verify, Zero,
+		},
+		Perbill,
+	};
+	use sp_std::{boxed::Box, vec::Vec};
 
-	impl Default for ModelType {
-		fn default() -> Self {
-			ModelType::Classification
-		}
-	}
+	type BalanceOf<T> =
+		<<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
+	type NegativeImbalanceOf<T> = <<T as Config>::Currency as Currency<
+		<T as frame_system::Config>::AccountId,
+	>>::NegativeImbalance;
 
-	/// Status of a model
-	#[derive(
-		Clone, Copy, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen,
-	)]
-	pub enum ModelStatus {
-		Active,
-		Paused,
-		Deactivated,
-	}
+	/// The in-code storage version
+	///
+	/// Bump together with adding a migration in [`crate::migrations`].
+	pub const STORAGE_VERSION: StorageVersion = StorageVersion::new(10);
 
-	impl Default for ModelStatus {
-		fn default() -> Self {
-			ModelStatus::Active
-		}
-	}
+	/// Prefix of the off-chain index entries recording the events of a model
+	pub const MODEL_INDEX_PREFIX: &[u8] = b"ai-registry/model";
 
-	type BalanceOf<T> =
-		<<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
+	/// Prefix of the off-chain index entries listing the models of an owner
+	pub const OWNER_INDEX_PREFIX: &[u8] = b"ai-registry/owner";
 
 	#[pallet::pallet]
+	#[pallet::storage_version(STORAGE_VERSION)]
 	pub struct Pallet<T>(_);
 
+	/// Configuration trait for the AI Registry pallet
 	#[pallet::config]
 	pub trait Config: frame_system::Config {
+		/// The overarching event type
 		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
-		type Currency: Currency<Self::AccountId>;
 
+		/// The overarching call type, so organization members can act through their
+		/// organization's account
+		type RuntimeCall: Parameter
+			+ Dispatchable<
+				RuntimeOrigin = <Self as frame_system::Config>::RuntimeOrigin,
+				PostInfo = PostDispatchInfo,
+			> + GetDispatchInfo
+			+ From<Call<Self>>
+			+ IsSubType<Call<Self>>
+			+ IsType<<Self as frame_system::Config>::RuntimeCall>;
+
+		/// Weight information for extrinsics in this pallet
+		type WeightInfo: WeightInfo;
+
+		/// Currency type for handling payments and stakes
+		type Currency: Currency<Self::AccountId> + ReservableCurrency<Self::AccountId>;
+
+		/// Minimum stake required to register a model
 		#[pallet::constant]
 		type MinimumModelStake: Get<BalanceOf<Self>>;
 
+		/// Registration fee for new models
 		#[pallet::constant]
 		type RegistrationFee: Get<BalanceOf<Self>>;
+
+		/// Maximum length of IPFS CID
+		#[pallet::constant]
+		type MaxCidLength: Get<u32>;
+
+		/// Maximum length of model name
+		#[pallet::constant]
+		type MaxNameLength: Get<u32>;
+
+		/// Maximum length of model description
+		#[pallet::constant]
+		type MaxDescriptionLength: Get<u32>;
+
+		/// Additional deposit reserved per byte of declared model artifact size
+		#[pallet::constant]
+		type DepositPerByte: Get<BalanceOf<Self>>;
+
+		/// Origin allowed to register and update models, resolving to the owner
+		/// account; signed accounts, and e.g. accounts of other chains via XCM
+		type RegistrationOrigin: EnsureOrigin<Self::RuntimeOrigin, Success = Self::AccountId>;
+
+		/// Origin allowed to approve corrections to registry data
+		type GovernanceOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+		/// Block after which root (sudo) may no longer administer the registry,
+		/// unless the on-chain schedule has been extended
+		#[pallet::constant]
+		type AdminSunsetBlock: Get<BlockNumberFor<Self>>;
+
+		/// Origin allowed to postpone the admin sunset (e.g. a referendum track)
+		type SunsetExtensionOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+		/// Maximum number of off-chain callback descriptors per model
+		#[pallet::constant]
+		type MaxCallbacksPerModel: Get<u32>;
+
+		/// Source of inference receipts linked to ratings for provenance
+		type InferenceReceipts: InferenceReceipts<Self::AccountId>;
+
+		/// Whether rating a model requires an inference receipt for it from
+		/// `InferenceReceipts`
+		#[pallet::constant]
+		type RequireReceiptToRate: Get<bool>;
+
+		/// Influence of each rater on model ratings; `()` gives every rater one vote,
+		/// [`QuadraticRatingWeight`] weighs raters by the square root of their stake
+		type RatingWeight: RatingWeight<Self::AccountId>;
+
+		/// Blocks an account must exist for before it may rate; only accounts
+		/// created while the runtime reports new accounts to this pallet are aged
+		#[pallet::constant]
+		type MinRaterAge: Get<BlockNumberFor<Self>>;
+
+		/// Free balance an account must hold to rate, e.g. the existential deposit
+		#[pallet::constant]
+		type MinRaterBalance: Get<BalanceOf<Self>>;
+
+		/// Models an account can rate or re-rate per era of `RentEraLength` blocks
+		#[pallet::constant]
+		type MaxRatingsPerEra: Get<u32>;
+
+		/// Latest ratings kept per model to notice its rating collapsing
+		#[pallet::constant]
+		type RatingWindow: Get<u32>;
+
+		/// Ratings needed in the window before a model can be paused for them
+		#[pallet::constant]
+		type PauseMinRatings: Get<u32>;
+
+		/// Average stars over the window below which active models are paused;
+		/// zero never pauses
+		#[pallet::constant]
+		type PauseRatingFloor: Get<u8>;
+
+		/// Distinct accounts whose reports suspend a model until `SlashOrigin`
+		/// resolves them
+		#[pallet::constant]
+		type ReportThreshold: Get<u32>;
+
+		/// Bond reserved from owners appealing a forced deactivation, slashed to
+		/// `Slash` if the appeal is rejected
+		#[pallet::constant]
+		type AppealBond: Get<BalanceOf<Self>>;
+
+		/// Longest grace period an owner can give before their model's deactivation
+		/// takes effect
+		#[pallet::constant]
+		type MaxDeactivationGrace: Get<BlockNumberFor<Self>>;
+
+		/// Maximum number of deactivations taking effect in the same block
+		#[pallet::constant]
+		type MaxDeactivationsPerBlock: Get<u32>;
+
+		/// Blocks before a scheduled deactivation that `DeactivationUpcoming` is
+		/// emitted; zero emits no advance notice
+		#[pallet::constant]
+		type DeactivationNotice: Get<BlockNumberFor<Self>>;
+
+		/// Maximum number of scheduled price changes taking effect in the same block
+		#[pallet::constant]
+		type MaxPriceChangesPerBlock: Get<u32>;
+
+		/// Whether new registrations start `Pending` until `RegistrationApprovalOrigin`
+		/// approves them, for permissioned deployments
+		#[pallet::constant]
+		type RequireRegistrationApproval: Get<bool>;
+
+		/// Origin allowed to approve or reject pending registrations
+		type RegistrationApprovalOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+		/// Developers allowed to register models while `AllowlistRequired` is set, e.g. a
+		/// `pallet-membership` instance
+		type DeveloperAllowlist: SortedMembers<Self::AccountId>;
+
+		/// Maximum number of members of an organization
+		#[pallet::constant]
+		type MaxOrganizationMembers: Get<u32>;
+
+		/// Accounts allowed to rate at all, e.g. those with a positive identity
+		/// judgement; `Everything` lets any old enough and funded account rate
+		type VerifiedRaters: Contains<Self::AccountId>;
+
+		/// Origin allowed to record completed inferences, i.e. the inference pallet
+		type InferenceOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+		/// NFTs representing model ownership
+		type ModelNfts: ModelNfts<Self::AccountId>;
+
+		/// Hook called after a model is registered
+		type OnModelRegistered: OnModelRegistered<Self::AccountId>;
+
+		/// Hook called when a model's status changes, e.g. when it is paused or deactivated
+		type OnModelStatusChanged: OnModelStatusChanged;
+
+		/// Maximum number of shareholders of a fractionally owned model
+		#[pallet::constant]
+		type MaxShareholders: Get<u32>;
+
+		/// Share needed to propose metadata changes of a fractionally owned model
+		#[pallet::constant]
+		type ProposalShareThreshold: Get<Perbill>;
+
+		/// Part of a model's sale price paid to its creator
+		#[pallet::constant]
+		type CreatorRoyalty: Get<Perbill>;
+
+		/// Maximum number of training datasets a model can reference
+		#[pallet::constant]
+		type MaxDatasetsPerModel: Get<u32>;
+
+		/// Largest share of its inference revenue a fork can pass to its parent's owner
+		#[pallet::constant]
+		type MaxParentShare: Get<Perbill>;
+
+		/// Maximum number of models in a collection
+		#[pallet::constant]
+		type MaxModelsPerCollection: Get<u32>;
+
+		/// Maximum number of collections a model can be in
+		#[pallet::constant]
+		type MaxCollectionsPerModel: Get<u32>;
+
+		/// Maximum number of models an account can bookmark
+		#[pallet::constant]
+		type MaxBookmarks: Get<u32>;
+
+		/// Maximum number of models an account can own
+		#[pallet::constant]
+		type MaxModelsPerOwner: Get<u32>;
+
+		/// Maximum number of accounts watching a model
+		#[pallet::constant]
+		type MaxWatchers: Get<u32>;
+
+		/// Number of watchers listed per `WatchedModelChanged` event
+		#[pallet::constant]
+		type WatchersPerEvent: Get<u32>;
+
+		/// Signature owners use to sign migration packets
+		type OwnerSignature: Verify<Signer = Self::OwnerSigner> + Parameter;
+
+		/// Public key type behind [`Config::OwnerSignature`]
+		type OwnerSigner: IdentifyAccount<AccountId = Self::AccountId>;
+
+		/// Maximum number of models exported in a single call
+		#[pallet::constant]
+		type MaxExportBatch: Get<u32>;
+
+		/// Length of a rent era in blocks
+		#[pallet::constant]
+		type RentEraLength: Get<BlockNumberFor<Self>>;
+
+		/// Number of eras without inference activity before rent starts accruing
+		#[pallet::constant]
+		type RentFreeEras: Get<u32>;
+
+		/// Rent taken from an idle model's deposit per era; zero disables rent
+		#[pallet::constant]
+		type RentPerEra: Get<BalanceOf<Self>>;
+
+		/// Blocks between a model's deposit running out and it becoming reapable
+		#[pallet::constant]
+		type RentReapDelay: Get<BlockNumberFor<Self>>;
+
+		/// Origin allowed to slash models confirmed as fraudulent (e.g. curators or council)
+		type SlashOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+		/// Portion of a fraudulent model's deposit that is slashed
+		#[pallet::constant]
+		type SlashFraction: Get<Perbill>;
+
+		/// Destination of slashed deposits, typically the treasury
+		type Slash: OnUnbalanced<NegativeImbalanceOf<Self>>;
+
+		/// Derives the fee pot account registration fees are paid into
+		#[pallet::constant]
+		type PalletId: Get<PalletId>;
+
+		/// Average rating (1-5) a model needs for registration fee rebates
+		#[pallet::constant]
+		type RebateRatingThreshold: Get<u8>;
+
+		/// Accounts that must rate a model before it can get registration fee rebates
+		#[pallet::constant]
+		type RebateMinRatings: Get<u32>;
+
+		/// Part of the registration fee rebated per `RentEraLength`; zero disables rebates
+		#[pallet::constant]
+		type RebatePerEra: Get<Perbill>;
+
+		/// Origin allowed to fund the model incentive program (e.g. council)
+		type IncentiveOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+		/// Account incentive budgets are funded from, typically the treasury
+		type IncentiveSource: Get<Self::AccountId>;
+
+		/// Number of top models by reputation rewarded every `RentEraLength`
+		#[pallet::constant]
+		type MaxRewardedModels: Get<u32>;
+
+		/// Accounts that must rate a model before it counts for era rewards
+		#[pallet::constant]
+		type RewardMinRatings: Get<u32>;
+
+		/// Rewards minted per era for the top models; zero disables rewards
+		#[pallet::constant]
+		type RewardPerEra: Get<BalanceOf<Self>>;
+
+		/// Number of latest metadata updates kept per model
+		#[pallet::constant]
+		type MaxUpdateHistory: Get<u32>;
+
+		/// Number of recent eras of `RentEraLength` blocks whose inference counts and
+		/// revenue are kept per model
+		#[pallet::constant]
+		type UsageWindow: Get<u32>;
+
+		/// Number of models kept on the leaderboard of most used models
+		#[pallet::constant]
+		type LeaderboardSize: Get<u32>;
+
+		/// Number of leading name bytes models are indexed by for name searches
+		#[pallet::constant]
+		type NamePrefixLength: Get<u32>;
+
+		/// Helper to produce owner signatures in benchmarks
+		#[cfg(feature = "runtime-benchmarks")]
+		type BenchmarkHelper: BenchmarkHelper<Self::OwnerSignature, Self::AccountId>;
 	}
 
-	/// Simplified model metadata (non-generic to avoid MaxEncodedLen issues)
+	/// Storage for model metadata indexed by ModelId
+	#[pallet::storage]
+	pub type Models<T: Config> =
+		StorageMap<_, Blake2_128Concat, ModelId, ModelMetadata<T>, OptionQuery>;
+
+	/// Models owned by each account, so all of them are found with a single read
+	#[pallet::storage]
+	pub type OwnedModels<T: Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		T::AccountId,
+		BoundedBTreeSet<ModelId, T::MaxModelsPerOwner>,
+		ValueQuery,
+	>;
+
+	/// Latest rating per (model, rater), with the inference receipt backing it
+	#[pallet::storage]
+	pub type Ratings<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		ModelId,
+		Blake2_128Concat,
+		T::AccountId,
+		RatingRecord,
+		OptionQuery,
+	>;
+
+	/// Block each account was created in, for [`Config::MinRaterAge`]
+	///
+	/// Accounts created before the runtime reported new accounts count as old
+	/// enough.
+	#[pallet::storage]
+	pub type AccountCreated<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, BlockNumberFor<T>, OptionQuery>;
+
+	/// Models each account rated in the latest era it rated in, for
+	/// [`Config::MaxRatingsPerEra`]
+	#[pallet::storage]
+	pub type RatedInEra<T: Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		T::AccountId,
+		(BlockNumberFor<T>, BoundedVec<ModelId, T::MaxRatingsPerEra>),
+		OptionQuery,
+	>;
+
+	/// Stars of the latest `RatingWindow` ratings of each model, newest first
+	///
+	/// Cleared when the model is paused for them, so a reactivated model is judged
+	/// on new ratings only.
+	#[pallet::storage]
+	pub type RecentRatings<T: Config> =
+		StorageMap<_, Blake2_128Concat, ModelId, BoundedVec<u8, T::RatingWindow>, ValueQuery>;
+
+	/// Block each account reported each model in, until the reports are resolved
+	#[pallet::storage]
+	pub type ModelReports<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		ModelId,
+		Blake2_128Concat,
+		T::AccountId,
+		BlockNumberFor<T>,
+		OptionQuery,
+	>;
+
+	/// Number of unresolved reports of each model
+	#[pallet::storage]
+	pub type ReportCounts<T: Config> = StorageMap<_, Blake2_128Concat, ModelId, u32, ValueQuery>;
+
+	/// Models in [`ModelStatus::UnderReview`], with the status they had before
+	///
+	/// Curators restore that status when they dismiss the reports.
+	#[pallet::storage]
+	pub type UnderReview<T: Config> =
+		StorageMap<_, Blake2_128Concat, ModelId, ModelStatus, OptionQuery>;
+
+	/// Models deactivated by force that can still be appealed, with the status they
+	/// had before
+	#[pallet::storage]
+	pub type ForcedDeactivations<T: Config> =
+		StorageMap<_, Blake2_128Concat, ModelId, ModelStatus, OptionQuery>;
+
+	/// Open appeals against forced deactivations
+	#[pallet::storage]
+	pub type Appeals<T: Config> =
+		StorageMap<_, Blake2_128Concat, ModelId, DeactivationAppeal<T>, OptionQuery>;
+
+	/// Profiles developers show on marketplace vendor pages
+	#[pallet::storage]
+	pub type DeveloperProfiles<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, DeveloperProfile<T>, OptionQuery>;
+
+	/// Block each model with a scheduled deactivation is deactivated at
+	#[pallet::storage]
+	pub type PendingDeactivations<T: Config> =
+		StorageMap<_, Blake2_128Concat, ModelId, BlockNumberFor<T>, OptionQuery>;
+
+	/// Models to deactivate at the start of each block
 	#[pallet::storage]
-	pub type ModelOwner<T: Config> = StorageMap<_, Blake2_128Concat, ModelId, T::AccountId>;
+	pub type ScheduledDeactivations<T: Config> = StorageMap<
+		_,
+		Twox64Concat,
+		BlockNumberFor<T>,
+		BoundedVec<ModelId, T::MaxDeactivationsPerBlock>,
+		ValueQuery,
+	>;
 
+	/// Price each model with a scheduled price change switches to, and the block it
+	/// does so at
 	#[pallet::storage]
-	pub type ModelCID<T: Config> = StorageMap<_, Blake2_128Concat, ModelId, BoundedVec<u8, ConstU32<128>>>;
+	pub type PendingPriceChanges<T: Config> =
+		StorageMap<_, Blake2_128Concat, ModelId, (BlockNumberFor<T>, u128), OptionQuery>;
 
+	/// Models whose price changes at the start of each block
 	#[pallet::storage]
-	pub type ModelPrice<T: Config> = StorageMap<_, Blake2_128Concat, ModelId, u128>;
+	pub type ScheduledPriceChanges<T: Config> = StorageMap<
+		_,
+		Twox64Concat,
+		BlockNumberFor<T>,
+		BoundedVec<ModelId, T::MaxPriceChangesPerBlock>,
+		ValueQuery,
+	>;
 
+	/// Whether only members of [`Config::DeveloperAllowlist`] may register models
 	#[pallet::storage]
-	pub type ModelTypeStorage<T: Config> = StorageMap<_, Blake2_128Concat, ModelId, ModelType>;
+	pub type AllowlistRequired<T: Config> = StorageValue<_, bool, ValueQuery>;
 
+	/// Weight of every rating that doesn't count once, per [`Config::RatingWeight`]
 	#[pallet::storage]
-	pub type ModelStatusStorage<T: Config> = StorageMap<_, Blake2_128Concat, ModelId, ModelStatus>;
+	pub type RatingWeights<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		ModelId,
+		Blake2_128Concat,
+		T::AccountId,
+		u32,
+		OptionQuery,
+	>;
 
+	/// Number of accounts that rated each model, whatever their weight
 	#[pallet::storage]
-	pub type ModelRatingTotal<T: Config> = StorageMap<_, Blake2_128Concat, ModelId, u64, ValueQuery>;
+	pub type RaterCounts<T: Config> = StorageMap<_, Blake2_128Concat, ModelId, u32, ValueQuery>;
 
+	/// IPFS CID of the written review left with each rating that has one
 	#[pallet::storage]
-	pub type ModelRatingCount<T: Config> = StorageMap<_, Blake2_128Concat, ModelId, u32, ValueQuery>;
+	pub type RatingReviews<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		ModelId,
+		Blake2_128Concat,
+		T::AccountId,
+		BoundedVec<u8, T::MaxCidLength>,
+		OptionQuery,
+	>;
 
+	/// Weighted ratings of each model per number of stars, one to five, from which
+	/// its median rating is read
 	#[pallet::storage]
-	pub type ModelsByOwner<T: Config> =
-		StorageDoubleMap<_, Blake2_128Concat, T::AccountId, Blake2_128Concat, ModelId, (), OptionQuery>;
+	pub type RatingHistograms<T: Config> =
+		StorageMap<_, Blake2_128Concat, ModelId, [u32; 5], ValueQuery>;
 
+	/// Counter for generating unique model IDs
 	#[pallet::storage]
 	pub type NextModelId<T: Config> = StorageValue<_, ModelId, ValueQuery>;
 
-	#[pallet::event]
-	#[pallet::generate_deposit(pub(super) fn deposit_event)]
-	pub enum Event<T: Config> {
-		ModelRegistered { model_id: ModelId, owner: T::AccountId },
-		ModelUpdated { model_id: ModelId },
-		ModelDeactivated { model_id: ModelId },
-		ModelRated { model_id: ModelId, rating: u8 },
+	/// Default admin sunset taken from the runtime configuration
+	#[pallet::type_value]
+	pub fn DefaultAdminSunset<T: Config>() -> BlockNumberFor<T> {
+		T::AdminSunsetBlock::get()
 	}
 
-	#[pallet::error]
-	pub enum Error<T> {
-		InvalidIPFSCID,
-		InsufficientStake,
-		ModelNotFound,
-		UnauthorizedAccess,
-		InvalidRating,
-		InsufficientBalance,
-		ArithmeticOverflow,
-	}
+	/// Block after which root-originated registry administration is rejected
+	#[pallet::storage]
+	pub type AdminSunset<T: Config> =
+		StorageValue<_, BlockNumberFor<T>, ValueQuery, DefaultAdminSunset<T>>;
 
-	#[pallet::call]
-	impl<T: Config> Pallet<T> {
-		/// Register a new AI model (using u8 for model_type: 0=Classification, 1=Regression, 2=Generative)
-		#[pallet::call_index(0)]
-		#[pallet::weight(50_000_000)]
-		pub fn register_model(
-			origin: OriginFor<T>,
-			ipfs_cid: Vec<u8>,
-			model_type_u8: u8,
-			price: u128,
-		) -> DispatchResult {
-			let who = ensure_signed(origin)?;
+	/// Artifact size corrections proposed by owners, awaiting governance approval
+	#[pallet::storage]
+	pub type PendingSizeCorrections<T: Config> =
+		StorageMap<_, Blake2_128Concat, ModelId, u64, OptionQuery>;
 
-			// Validate model type
-			ensure!(model_type_u8 <= 2, Error::<T>::InvalidIPFSCID); // Reuse error for simplicity
-			let model_type = match model_type_u8 {
-				0 => ModelType::Classification,
-				1 => ModelType::Regression,
-				_ => ModelType::Generative,
-			};
+	/// Off-chain callback descriptors declared by model owners
+	#[pallet::storage]
+	pub type ModelCallbacks<T: Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		ModelId,
+		BoundedVec<CallbackDescriptorOf<T>, T::MaxCallbacksPerModel>,
+		ValueQuery,
+	>;
 
-			// Validate CID
-			let bounded_cid: BoundedVec<u8, ConstU32<128>> =
-				ipfs_cid.try_into().map_err(|_| Error::<T>::InvalidIPFSCID)?;
-			ensure!(Self::validate_ipfs_cid(&bounded_cid), Error::<T>::InvalidIPFSCID);
+	/// Models imported from another chain, keyed by their local ID
+	#[pallet::storage]
+	pub type ImportedFrom<T: Config> =
+		StorageMap<_, Blake2_128Concat, ModelId, ModelProvenance<T::Hash>, OptionQuery>;
 
-			// Check stake
-			let free_balance = T::Currency::free_balance(&who);
-			ensure!(free_balance >= T::MinimumModelStake::get(), Error::<T>::InsufficientStake);
+	/// Local ID of each imported (source chain, source model ID) pair, preventing replays
+	#[pallet::storage]
+	pub type ImportedPackets<T: Config> =
+		StorageDoubleMap<_, Identity, T::Hash, Blake2_128Concat, ModelId, ModelId, OptionQuery>;
 
-			// Charge fee
-			let fee = T::RegistrationFee::get();
-			ensure!(free_balance >= fee, Error::<T>::InsufficientBalance);
+	/// Block of the last registration or inference of each model
+	#[pallet::storage]
+	pub type LastActivity<T: Config> =
+		StorageMap<_, Blake2_128Concat, ModelId, BlockNumberFor<T>, ValueQuery>;
 
-			let _imbalance = T::Currency::withdraw(
-				&who,
-				fee,
-				frame_support::traits::WithdrawReasons::FEE,
-				ExistenceRequirement::KeepAlive,
-			)?;
+	/// Block up to which rent has been collected for each model
+	#[pallet::storage]
+	pub type RentChargedUntil<T: Config> =
+		StorageMap<_, Blake2_128Concat, ModelId, BlockNumberFor<T>, ValueQuery>;
 
-			// Get next ID
-			let model_id = NextModelId::<T>::get();
-			let next_id = model_id.checked_add(1).ok_or(Error::<T>::ArithmeticOverflow)?;
+	/// Models whose deposit was exhausted by rent, with the block they become reapable
+	#[pallet::storage]
+	pub type RentExhausted<T: Config> =
+		StorageMap<_, Blake2_128Concat, ModelId, BlockNumberFor<T>, OptionQuery>;
 
-			// Store model data
-			ModelOwner::<T>::insert(model_id, &who);
-			ModelCID::<T>::insert(model_id, bounded_cid);
-			ModelPrice::<T>::insert(model_id, price);
-			ModelTypeStorage::<T>::insert(model_id, model_type);
-			ModelStatusStorage::<T>::insert(model_id, ModelStatus::Active);
-			ModelsByOwner::<T>::insert(&who, model_id, ());
-			NextModelId::<T>::put(next_id);
+	/// Part of each model's registration fee that can still be rebated
+	#[pallet::storage]
+	pub type RebatableFees<T: Config> =
+		StorageMap<_, Blake2_128Concat, ModelId, BalanceOf<T>, ValueQuery>;
 
-			Self::deposit_event(Event::ModelRegistered { model_id, owner: who });
+	/// Block up to which registration fee rebates have been paid for each model
+	#[pallet::storage]
+	pub type RebatedUntil<T: Config> =
+		StorageMap<_, Blake2_128Concat, ModelId, BlockNumberFor<T>, ValueQuery>;
 
-			Ok(())
-		}
+	/// Incentive funds not yet assigned to an era
+	#[pallet::storage]
+	pub type IncentiveFunds<T: Config> = StorageValue<_, BalanceOf<T>, ValueQuery>;
 
-		/// Update model price
-		#[pallet::call_index(1)]
-		#[pallet::weight(30_000_000)]
-		pub fn update_model_price(
-			origin: OriginFor<T>,
-			model_id: ModelId,
-			new_price: u128,
-		) -> DispatchResult {
-			let who = ensure_signed(origin)?;
+	/// Incentive budget assigned to every era with verified usage, while funds last
+	#[pallet::storage]
+	pub type IncentivePerEra<T: Config> = StorageValue<_, BalanceOf<T>, ValueQuery>;
 
-			let owner = ModelOwner::<T>::get(model_id).ok_or(Error::<T>::ModelNotFound)?;
-			ensure!(owner == who, Error::<T>::UnauthorizedAccess);
+	/// Budget and total verified usage of each era with incentivised inferences
+	#[pallet::storage]
+	pub type EraIncentives<T: Config> =
+		StorageMap<_, Blake2_128Concat, BlockNumberFor<T>, EraIncentive<BalanceOf<T>>, OptionQuery>;
 
-			ModelPrice::<T>::insert(model_id, new_price);
+	/// Era and number of a model's latest verified inferences not yet credited
+	#[pallet::storage]
+	pub type IncentiveUsage<T: Config> =
+		StorageMap<_, Blake2_128Concat, ModelId, (BlockNumberFor<T>, u32), OptionQuery>;
 
-			Self::deposit_event(Event::ModelUpdated { model_id });
+	/// Incentives credited to each model and not yet claimed
+	#[pallet::storage]
+	pub type IncentiveEarnings<T: Config> =
+		StorageMap<_, Blake2_128Concat, ModelId, BalanceOf<T>, ValueQuery>;
 
-			Ok(())
-		}
+	/// Best-rated active models with their reputation, highest first
+	#[pallet::storage]
+	pub type TopModels<T: Config> =
+		StorageValue<_, BoundedVec<(ModelId, u32), T::MaxRewardedModels>, ValueQuery>;
 
-		/// Deactivate model
-		#[pallet::call_index(2)]
-		#[pallet::weight(25_000_000)]
-		pub fn deactivate_model(origin: OriginFor<T>, model_id: ModelId) -> DispatchResult {
-			let who = ensure_signed(origin)?;
+	/// Era rewards earned by each model and not yet claimed
+	#[pallet::storage]
+	pub type EraRewards<T: Config> =
+		StorageMap<_, Blake2_128Concat, ModelId, BalanceOf<T>, ValueQuery>;
 
-			let owner = ModelOwner::<T>::get(model_id).ok_or(Error::<T>::ModelNotFound)?;
-			ensure!(owner == who, Error::<T>::UnauthorizedAccess);
+	/// Shareholders of fractionally owned models; other models belong wholly to their owner
+	#[pallet::storage]
+	pub type Shareholders<T: Config> =
+		StorageMap<_, Blake2_128Concat, ModelId, ModelSharesOf<T>, OptionQuery>;
 
-			ModelStatusStorage::<T>::insert(model_id, ModelStatus::Deactivated);
+	/// Training datasets models can reference
+	#[pallet::storage]
+	pub type Datasets<T: Config> = StorageMap<_, Blake2_128Concat, DatasetId, DatasetInfo<T>>;
 
-			Self::deposit_event(Event::ModelDeactivated { model_id });
+	/// Counter for generating unique dataset IDs
+	#[pallet::storage]
+	pub type NextDatasetId<T: Config> = StorageValue<_, DatasetId, ValueQuery>;
 
-			Ok(())
-		}
+	/// Datasets each model was trained on, as declared at registration
+	#[pallet::storage]
+	pub type ModelDatasets<T: Config> =
+		StorageMap<_, Blake2_128Concat, ModelId, ModelDatasetsOf<T>, OptionQuery>;
 
-		/// Rate a model (1-5 stars)
-		#[pallet::call_index(3)]
-		#[pallet::weight(28_000_000)]
-		pub fn rate_model(origin: OriginFor<T>, model_id: ModelId, rating: u8) -> DispatchResult {
-			let _who = ensure_signed(origin)?;
+	/// Named groups of models, e.g. a vendor's model families
+	#[pallet::storage]
+	pub type Collections<T: Config> =
+		StorageMap<_, Blake2_128Concat, CollectionId, CollectionInfo<T>, OptionQuery>;
 
-			ensure!(rating >= 1 && rating <= 5, Error::<T>::InvalidRating);
-			ensure!(ModelOwner::<T>::contains_key(model_id), Error::<T>::ModelNotFound);
+	/// Counter for generating unique collection IDs
+	#[pallet::storage]
+	pub type NextCollectionId<T: Config> = StorageValue<_, CollectionId, ValueQuery>;
 
-			ModelRatingTotal::<T>::mutate(model_id, |total| {
-				*total = total.saturating_add(rating as u64);
-			});
+	/// Collections each model is in, so a reaped model can be taken out of them
+	#[pallet::storage]
+	pub type ModelCollections<T: Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		ModelId,
+		BoundedBTreeSet<CollectionId, T::MaxCollectionsPerModel>,
+		ValueQuery,
+	>;
 
-			ModelRatingCount::<T>::mutate(model_id, |count| {
-				*count = count.saturating_add(1);
-			});
+	/// Organizations owning models on behalf of their members
+	#[pallet::storage]
+	pub type Organizations<T: Config> =
+		StorageMap<_, Blake2_128Concat, OrganizationId, Organization<T>, OptionQuery>;
 
-			Self::deposit_event(Event::ModelRated { model_id, rating });
+	/// Role of each member of an organization
+	#[pallet::storage]
+	pub type OrganizationMembers<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		OrganizationId,
+		Blake2_128Concat,
+		T::AccountId,
+		OrganizationRole,
+		OptionQuery,
+	>;
 
-			Ok(())
-		}
-	}
+	/// Counter for generating unique organization IDs
+	#[pallet::storage]
+	pub type NextOrganizationId<T: Config> = StorageValue<_, OrganizationId, ValueQuery>;
 
-	impl<T: Config> Pallet<T> {
-		/// Validate IPFS CID format
-		fn validate_ipfs_cid(cid: &BoundedVec<u8, ConstU32<128>>) -> bool {
-			if cid.len() < 46 {
-				return false;
-			}
-			// CIDv0: starts with "Qm" and is 46 characters
-			if cid.len() == 46 && cid.starts_with(b"Qm") {
-				return true;
-			}
-			// CIDv1: starts with "b"
-			if cid.starts_with(b"b") || cid.starts_with(b"B") {
-				return true;
-			}
-			false
-		}
+	/// Replacement clients of a deprecated or retiring model should move to
+	#[pallet::storage]
+	pub type Successors<T: Config> = StorageMap<_, Blake2_128Concat, ModelId, ModelId, OptionQuery>;
 
-		/// Get average rating
-		pub fn get_average_rating(model_id: ModelId) -> Option<u8> {
-			let count = ModelRatingCount::<T>::get(model_id);
-			if count > 0 {
-				let total = ModelRatingTotal::<T>::get(model_id);
-				Some((total / count as u64) as u8)
+	/// Models each account saved for later
+	#[pallet::storage]
+	pub type Bookmarks<T: Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		T::AccountId,
+		BoundedBTreeSet<ModelId, T::MaxBookmarks>,
+		ValueQuery,
+	>;
+
+	/// Accounts notified when a model's price or status changes
+	#[pallet::storage]
+	pub type Watchers<T: Config> =
+		StorageMap<_, Blake2_128Concat, ModelId, WatchersOf<T>, ValueQuery>;
+
+	/// Share of a fork's inference revenue paid to the owner of its parent
+	#[pallet::storage]
+	pub type ParentShare<T: Config> =
+		StorageMap<_, Blake2_128Concat, ModelId, Perbill, OptionQuery>;
+
+	/// Account collecting a model's inference revenue in place of its owner and
+	/// shareholders, e.g. a lessee
+	#[pallet::storage]
+	pub type RevenueRecipient<T: Config> =
+		StorageMap<_, Blake2_128Concat, ModelId, T::AccountId, OptionQuery>;
+
+	/// Block at which each model's CID, name, description and license were frozen for good
+	#[pallet::storage]
+	pub type LockedMetadata<T: Config> =
+		StorageMap<_, Blake2_128Concat, ModelId, BlockNumberFor<T>, OptionQuery>;
+
+	/// Latest metadata updates of each model, newest first
+	#[pallet::storage]
+	pub type UpdateHistory<T: Config> =
+		StorageMap<_, Blake2_128Concat, ModelId, UpdateHistoryOf<T>, ValueQuery>;
+
+	/// Inferences of each model per era over the last `UsageWindow` eras, newest first
+	#[pallet::storage]
+	pub type RecentUsage<T: Config> =
+		StorageMap<_, Blake2_128Concat, ModelId, RecentUsageOf<T>, ValueQuery>;
+
+	/// Inference fees settled on each model
+	#[pallet::storage]
+	pub type ModelRevenue<T: Config> = StorageMap<_, Blake2_128Concat, ModelId, u128, ValueQuery>;
+
+	/// Inference fees settled on each model per era over the last `UsageWindow` eras,
+	/// newest first
+	#[pallet::storage]
+	pub type RecentRevenue<T: Config> =
+		StorageMap<_, Blake2_128Concat, ModelId, RecentRevenueOf<T>, ValueQuery>;
+
+	/// Models by the first `NamePrefixLength` bytes of their lowercase name
+	#[pallet::storage]
+	pub type NameIndex<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		NamePrefixOf<T>,
+		Blake2_128Concat,
+		ModelId,
+		(),
+		OptionQuery,
+	>;
+
+	/// Model registered with each artifact, keyed by the hash of its IPFS CID
+	#[pallet::storage]
+	pub type ModelsByCid<T: Config> = StorageMap<_, Identity, T::Hash, ModelId, OptionQuery>;
+
+	/// Model of each owner by the hash of its name, keeping an owner's model names
+	/// unique; unnamed models aren't indexed
+	#[pallet::storage]
+	pub type OwnerModelNames<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::AccountId,
+		Identity,
+		T::Hash,
+		ModelId,
+		OptionQuery,
+	>;
+
+	/// Most used models with their completed inferences, highest first
+	#[pallet::storage]
+	pub type MostUsedModels<T: Config> =
+		StorageValue<_, BoundedVec<(ModelId, u64), T::LeaderboardSize>, ValueQuery>;
+
+	/// Chain-wide model, inference and fee counters
+	#[pallet::storage]
+	pub type GlobalStats<T: Config> = StorageValue<_, RegistryStats, ValueQuery>;
+
+	/// Pending metadata changes of fractionally owned models, by proposer
+	#[pallet::storage]
+	pub type MetadataProposals<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		ModelId,
+		Blake2_128Concat,
+		T::AccountId,
+		MetadataProposal<T>,
+		OptionQuery,
+	>;
+
+	/// Genesis configuration for pre-registered models
+	#[pallet::genesis_config]
+	#[derive(frame_support::DefaultNoBound)]
+	pub struct GenesisConfig<T: Config> {
+		/// Models registered at genesis: (owner, ipfs_cid, model_type, price)
+		///
+		/// Genesis models reserve the deposit of a model of no declared size from their
+		/// owner, pay rent from genesis on like any other model, and are licensed as
+		/// `Proprietary` until their owner states otherwise.
+		pub models: Vec<(T::AccountId, Vec<u8>, ModelType, u128)>,
+	}
+
+	#[pallet::genesis_build]
+	impl<T: Config> BuildGenesisConfig for GenesisConfig<T> {
+		fn build(&self) {
+			for (owner, ipfs_cid, model_type, price) in &self.models {
+				let bounded_cid: BoundedVec<u8, T::MaxCidLength> =
+					ipfs_cid.clone().try_into().expect("genesis model CID exceeds MaxCidLength");
+				assert!(Pallet::<T>::validate_ipfs_cid(&bounded_cid), "invalid genesis model CID");
+				let cid_key = Pallet::<T>::cid_key(&bounded_cid);
+				assert!(!ModelsByCid::<T>::contains_key(cid_key), "duplicate genesis model CID");
+
+				let model_id = NextModelId::<T>::get();
+				let deposit = Pallet::<T>::model_deposit(0);
+				T::Currency::reserve(owner, deposit)
+					.expect("genesis model owner cannot reserve the model deposit");
+				let metadata = ModelMetadata {
+					owner: owner.clone(),
+					creator: owner.clone(),
+					parent: None,
+					ipfs_cid: bounded_cid,
+					name: Default::default(),
+					description: Default::default(),
+					model_type: *model_type,
+					license: License::Proprietary,
+					size_bytes: 0,
+					deposit: deposit.saturated_into(),
+					price: *price,
+					created_at: 0,
+					total_inferences: 0,
+					total_rating: 0,
+					rating_count: 0,
+					status: ModelStatus::Active,
+				};
+
+				NameIndex::<T>::insert(Pallet::<T>::name_prefix(&metadata.name), model_id, ());
+				ModelsByCid::<T>::insert(cid_key, model_id);
+				Models::<T>::insert(model_id, metadata);
+				OwnedModels::<T>::mutate(owner, |owned| {
+					owned.try_insert(model_id).expect("genesis owner holds too many models")
+				});
+				NextModelId::<T>::put(model_id.saturating_add(1));
+				LastActivity::<T>::insert(model_id, BlockNumberFor::<T>::zero());
+				T::ModelNfts::mint(model_id, owner).expect("failed to mint genesis model NFT");
+				GlobalStats::<T>::mutate(|stats| {
+					stats.total_models.saturating_inc();
+					stats.active_models.saturating_inc();
+				});
+				T::OnModelRegistered::on_model_registered(model_id, owner);
+			}
+		}
+	}
+
+	/// Events emitted by this pallet
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// A new model was registered
+		/// [model_id, owner, ipfs_cid]
+		ModelRegistered {
+			model_id: ModelId,
+			owner: T::AccountId,
+			ipfs_cid: BoundedVec<u8, T::MaxCidLength>,
+		},
+		/// Model metadata was updated
+		/// [model_id, owner]
+		ModelUpdated { model_id: ModelId, owner: T::AccountId },
+		/// Model was deactivated
+		/// [model_id, owner]
+		ModelDeactivated { model_id: ModelId, owner: T::AccountId },
+		/// Model was rated
+		/// [model_id, rater, rating, review_cid]
+		ModelRated {
+			model_id: ModelId,
+			rater: T::AccountId,
+			rating: u8,
+			review_cid: Option<BoundedVec<u8, T::MaxCidLength>>,
+		},
+		/// Owner proposed a correction of the declared artifact size
+		/// [model_id, size_bytes]
+		SizeCorrectionProposed { model_id: ModelId, size_bytes: u64 },
+		/// Governance applied a size correction and the deposit was rebalanced
+		/// [model_id, old_size, new_size, deposit]
+		ModelSizeCorrected { model_id: ModelId, old_size: u64, new_size: u64, deposit: u128 },
+		/// The admin sunset was postponed
+		/// [old_sunset, new_sunset]
+		AdminSunsetExtended { old_sunset: BlockNumberFor<T>, new_sunset: BlockNumberFor<T> },
+		/// A callback descriptor was added to a model
+		/// [model_id, descriptor]
+		CallbackAdded { model_id: ModelId, descriptor: CallbackDescriptorOf<T> },
+		/// A callback descriptor was removed from a model
+		/// [model_id, index]
+		CallbackRemoved { model_id: ModelId, index: u32 },
+		/// A model was exported as a migration packet for the owner to sign
+		/// [model_id, packet]
+		ModelExported { model_id: ModelId, packet: MigrationPacket<T> },
+		/// A model was imported from a signed migration packet
+		/// [model_id, owner, source_chain, source_model_id]
+		ModelImported {
+			model_id: ModelId,
+			owner: T::AccountId,
+			source_chain: T::Hash,
+			source_model_id: ModelId,
+		},
+		/// Rent was taken from an idle model's deposit
+		/// [model_id, amount]
+		RentCollected { model_id: ModelId, amount: u128 },
+		/// Rent exhausted a model's deposit; the model is paused until topped up
+		/// [model_id, reap_at]
+		ModelRentExhausted { model_id: ModelId, reap_at: BlockNumberFor<T> },
+		/// Owner added to a model's deposit
+		/// [model_id, amount, deposit]
+		DepositToppedUp { model_id: ModelId, amount: u128, deposit: u128 },
+		/// A model whose deposit ran out was removed from storage
+		/// [model_id, owner]
+		ModelReaped { model_id: ModelId, owner: T::AccountId },
+		/// A fraudulent model was deactivated and part of its deposit slashed
+		/// [model_id, owner, amount]
+		ModelSlashed { model_id: ModelId, owner: T::AccountId, amount: u128 },
+		/// A model and its deposit moved to a new owner
+		/// [model_id, from, to]
+		ModelTransferred { model_id: ModelId, from: T::AccountId, to: T::AccountId },
+		/// A model was split into shares
+		/// [model_id, shares]
+		ModelFractionalized { model_id: ModelId, shares: ModelSharesOf<T> },
+		/// Part of a model's shares changed hands
+		/// [model_id, from, to, share]
+		SharesTransferred {
+			model_id: ModelId,
+			from: T::AccountId,
+			to: T::AccountId,
+			share: Perbill,
+		},
+		/// A shareholder proposed a metadata change
+		/// [model_id, proposer]
+		MetadataChangeProposed { model_id: ModelId, proposer: T::AccountId },
+		/// A shareholder approved a metadata change
+		/// [model_id, proposer, approver]
+		MetadataChangeApproved { model_id: ModelId, proposer: T::AccountId, approver: T::AccountId },
+		/// A metadata change reached a majority of shares and was applied
+		/// [model_id, proposer]
+		MetadataChangeEnacted { model_id: ModelId, proposer: T::AccountId },
+		/// A proposer withdrew their metadata change
+		/// [model_id, proposer]
+		MetadataChangeCancelled { model_id: ModelId, proposer: T::AccountId },
+		/// A model was sold, paying a royalty to its creator
+		/// [model_id, seller, buyer, price, creator, royalty]
+		ModelSold {
+			model_id: ModelId,
+			seller: T::AccountId,
+			buyer: T::AccountId,
+			price: BalanceOf<T>,
+			creator: T::AccountId,
+			royalty: BalanceOf<T>,
+		},
+		/// A training dataset was registered
+		/// [dataset_id, owner, ipfs_cid]
+		DatasetRegistered {
+			dataset_id: DatasetId,
+			owner: T::AccountId,
+			ipfs_cid: BoundedVec<u8, T::MaxCidLength>,
+		},
+		/// A model was linked to the datasets it was trained on
+		/// [model_id, datasets]
+		ModelDatasetsLinked { model_id: ModelId, datasets: ModelDatasetsOf<T> },
+		/// A model was registered as a fork of another
+		/// [model_id, parent_id, owner, parent_share]
+		ModelForked {
+			model_id: ModelId,
+			parent_id: ModelId,
+			owner: T::AccountId,
+			parent_share: Perbill,
+		},
+		/// A model collection was created
+		/// [collection_id, owner, name]
+		CollectionCreated {
+			collection_id: CollectionId,
+			owner: T::AccountId,
+			name: BoundedVec<u8, T::MaxNameLength>,
+		},
+		/// A model was added to a collection
+		/// [collection_id, model_id]
+		ModelAddedToCollection { collection_id: CollectionId, model_id: ModelId },
+		/// A model was removed from a collection
+		/// [collection_id, model_id]
+		ModelRemovedFromCollection { collection_id: CollectionId, model_id: ModelId },
+		/// A model was deprecated, optionally in favour of a successor
+		/// [model_id, successor]
+		ModelDeprecated { model_id: ModelId, successor: Option<ModelId> },
+		/// An account bookmarked a model
+		/// [who, model_id]
+		ModelBookmarked { who: T::AccountId, model_id: ModelId },
+		/// An account removed a bookmark
+		/// [who, model_id]
+		ModelUnbookmarked { who: T::AccountId, model_id: ModelId },
+		/// An account started watching a model
+		/// [who, model_id]
+		ModelWatched { who: T::AccountId, model_id: ModelId },
+		/// An account stopped watching a model
+		/// [who, model_id]
+		ModelUnwatched { who: T::AccountId, model_id: ModelId },
+		/// A watched model changed; emitted once per `WatchersPerEvent` watchers
+		/// [model_id, change, watchers]
+		WatchedModelChanged { model_id: ModelId, change: ModelChange, watchers: WatchersOf<T> },
+		/// Part of a highly rated model's registration fee was paid back to its owner
+		/// [model_id, owner, amount]
+		FeeRebated { model_id: ModelId, owner: T::AccountId, amount: u128 },
+		/// The incentive program was funded
+		/// [amount, per_era]
+		IncentivesFunded { amount: u128, per_era: u128 },
+		/// A model's incentives were paid to its owner
+		/// [model_id, owner, amount]
+		IncentiveClaimed { model_id: ModelId, owner: T::AccountId, amount: u128 },
+		/// An era's rewards were credited to the top models
+		/// [models, amount]
+		EraRewardsCredited { models: u32, amount: u128 },
+		/// A model's era rewards were minted to its owner
+		/// [model_id, owner, amount]
+		RewardClaimed { model_id: ModelId, owner: T::AccountId, amount: u128 },
+		/// A model's name or type was corrected
+		/// [model_id, old_name, new_name, model_type]
+		ModelRenamed {
+			model_id: ModelId,
+			old_name: BoundedVec<u8, T::MaxNameLength>,
+			new_name: BoundedVec<u8, T::MaxNameLength>,
+			model_type: ModelType,
+		},
+		/// A model's CID, name and description were frozen for good
+		/// [model_id, owner]
+		MetadataLocked { model_id: ModelId, owner: T::AccountId },
+		/// A model's recent ratings fell below `PauseRatingFloor` and it was paused
+		/// until its owner or governance reactivates it
+		/// [model_id, average]
+		ModelPausedForRatings { model_id: ModelId, average: u8 },
+		/// An account reported a model
+		/// [model_id, reporter, reports]
+		ModelReported { model_id: ModelId, reporter: T::AccountId, reports: u32 },
+		/// Reports reached `ReportThreshold` and the model was put under review
+		/// [model_id, reports]
+		ModelSuspended { model_id: ModelId, reports: u32 },
+		/// Curators dismissed a model's reports and restored its status
+		/// [model_id, status]
+		ReportsDismissed { model_id: ModelId, status: ModelStatus },
+		/// An owner appealed the forced deactivation of their model
+		/// [model_id, owner, evidence_cid, bond]
+		AppealOpened {
+			model_id: ModelId,
+			owner: T::AccountId,
+			evidence_cid: BoundedVec<u8, T::MaxCidLength>,
+			bond: u128,
+		},
+		/// Governance upheld an appeal, restoring the model and refunding the bond
+		/// [model_id, status]
+		AppealUpheld { model_id: ModelId, status: ModelStatus },
+		/// Governance rejected an appeal and slashed its bond
+		/// [model_id, bond]
+		AppealRejected { model_id: ModelId, bond: u128 },
+		/// An owner scheduled their model's deactivation; it stays usable until then
+		/// [model_id, owner, at]
+		DeactivationScheduled { model_id: ModelId, owner: T::AccountId, at: BlockNumberFor<T> },
+		/// An owner made their model active again, cancelling any scheduled deactivation
+		/// [model_id, owner]
+		ModelReactivated { model_id: ModelId, owner: T::AccountId },
+		/// A pending registration was approved and the model is active
+		/// [model_id]
+		RegistrationApproved { model_id: ModelId },
+		/// A pending registration was rejected; the model is deactivated and its
+		/// deposit released
+		/// [model_id]
+		RegistrationRejected { model_id: ModelId },
+		/// Governance turned the developer allowlist on or off
+		/// [required]
+		AllowlistRequirementSet { required: bool },
+		/// A developer set or updated their profile
+		/// [developer]
+		DeveloperProfileSet { developer: T::AccountId },
+		/// A developer removed their profile
+		/// [developer]
+		DeveloperProfileCleared { developer: T::AccountId },
+		/// An organization was created with its creator as admin
+		/// [org_id, account, admin]
+		OrganizationCreated { org_id: OrganizationId, account: T::AccountId, admin: T::AccountId },
+		/// An organization member's role changed; `None` if they were removed
+		/// [org_id, member, role]
+		OrganizationRoleSet {
+			org_id: OrganizationId,
+			member: T::AccountId,
+			role: Option<OrganizationRole>,
+		},
+		/// An owner announced their model's price will change at block `at`
+		/// [model_id, price, at]
+		PriceChangeScheduled { model_id: ModelId, price: u128, at: BlockNumberFor<T> },
+		/// An owner withdrew their model's scheduled price change
+		/// [model_id]
+		PriceChangeCancelled { model_id: ModelId },
+		/// A model is deactivated in `DeactivationNotice` blocks; users should move to
+		/// its successor, if it has one
+		/// [model_id, at, successor]
+		DeactivationUpcoming {
+			model_id: ModelId,
+			at: BlockNumberFor<T>,
+			successor: Option<ModelId>,
+		},
+	}
+
+	/// Errors that can occur in this pallet
+	#[pallet::error]
+	pub enum Error<T> {
+		/// IPFS CID format is invalid
+		InvalidIPFSCID,
+		/// Insufficient stake for model registration
+		InsufficientStake,
+		/// Model not found
+		ModelNotFound,
+		/// Caller is not authorized for this operation
+		UnauthorizedAccess,
+		/// Model already exists with this ID
+		ModelAlreadyExists,
+		/// Rating value is invalid (must be 1-5)
+		InvalidRating,
+		/// Model name is too long
+		NameTooLong,
+		/// Model description is too long
+		DescriptionTooLong,
+		/// IPFS CID is too long
+		CidTooLong,
+		/// Model is not in active status
+		ModelNotActive,
+		/// Arithmetic overflow occurred
+		ArithmeticOverflow,
+		/// User has not purchased inference for this model
+		NotInferenceUser,
+		/// Insufficient balance for registration fee
+		InsufficientBalance,
+		/// IPFS CID of a custom license is invalid
+		InvalidLicenseCID,
+		/// Declared artifact size must be greater than zero
+		InvalidModelSize,
+		/// No size correction is pending for this model
+		NoPendingSizeCorrection,
+		/// The admin sunset can only be moved later
+		SunsetNotExtended,
+		/// Model already has the maximum number of callbacks
+		TooManyCallbacks,
+		/// Event mask is empty or contains unknown events
+		InvalidEventMask,
+		/// No callback exists at the given index
+		CallbackNotFound,
+		/// Too many models requested in one export
+		TooManyModelsToExport,
+		/// Migration packet version is not supported by this chain
+		UnsupportedPacketVersion,
+		/// Migration packet was exported from this chain
+		PacketFromThisChain,
+		/// Migration packet signature does not match its owner
+		InvalidPacketSignature,
+		/// Migration packet has already been imported
+		PacketAlreadyImported,
+		/// No full rent era is due for the model
+		NoRentDue,
+		/// Model's deposit was exhausted by rent; top it up first
+		RentExhausted,
+		/// Model is not queued for reaping
+		NotReapable,
+		/// Model's reap delay has not elapsed yet
+		ReapTooEarly,
+		/// Model's NFT changed hands; its holder must sync the ownership first
+		OwnershipNotSynced,
+		/// Model's NFT is held by its owner already
+		OwnershipAlreadySynced,
+		/// Model has no NFT
+		ModelNftNotFound,
+		/// Model is split into shares already
+		ModelAlreadyFractional,
+		/// Model is not split into shares
+		ModelNotFractional,
+		/// Shares are empty, zero, duplicated or do not add up to 100%
+		InvalidShares,
+		/// Too many shareholders or approvals for one model
+		TooManyShareholders,
+		/// Caller's share is too small for this operation
+		InsufficientShare,
+		/// Caller holds no share of the model
+		NotShareholder,
+		/// Model is fractionally owned; metadata changes need shareholder approval
+		FractionallyOwned,
+		/// Caller already has a pending proposal for this model
+		ProposalAlreadyPending,
+		/// No such pending proposal
+		ProposalNotFound,
+		/// Caller already approved this proposal
+		AlreadyApproved,
+		/// Referenced dataset doesn't exist
+		DatasetNotFound,
+		/// More than `MaxDatasetsPerModel` datasets referenced
+		TooManyDatasets,
+		/// A dataset was referenced twice
+		DuplicateDataset,
+		/// Fork would pass more than `MaxParentShare` of its revenue to its parent
+		ParentShareTooHigh,
+		/// Collection doesn't exist
+		CollectionNotFound,
+		/// Caller doesn't own the collection
+		NotCollectionOwner,
+		/// Collection already holds `MaxModelsPerCollection` models
+		CollectionFull,
+		/// Model is already in the collection
+		AlreadyInCollection,
+		/// Model isn't in the collection
+		NotInCollection,
+		/// Model is in `MaxCollectionsPerModel` collections already
+		TooManyCollections,
+		/// Successor doesn't exist, isn't active or is the deprecated model itself
+		InvalidSuccessor,
+		/// Account already bookmarked `MaxBookmarks` models
+		TooManyBookmarks,
+		/// Model is already bookmarked
+		AlreadyBookmarked,
+		/// Model isn't bookmarked
+		NotBookmarked,
+		/// Caller already watches the model
+		AlreadyWatching,
+		/// Caller doesn't watch the model
+		NotWatching,
+		/// Model already has `MaxWatchers` watchers
+		TooManyWatchers,
+		/// Account already owns `MaxModelsPerOwner` models
+		TooManyModels,
+		/// Model's ratings are too few or too low for a fee rebate
+		RatingTooLow,
+		/// Rebates are disabled, no full era passed or the fee was fully rebated
+		NoRebateDue,
+		/// The fee pot has no funds to pay rebates from
+		FeePotEmpty,
+		/// Model has no incentives credited yet
+		NoIncentiveDue,
+		/// The incentive pot has no funds to pay incentives from
+		IncentivePotEmpty,
+		/// Model has no era rewards to claim
+		NoRewardDue,
+		/// Model's metadata is locked and can no longer change
+		MetadataLocked,
+		/// A model with this IPFS CID is already registered
+		CidAlreadyRegistered,
+		/// Owner already has a model with this name
+		DuplicateModelName,
+		/// Account was created too recently to rate
+		RaterTooNew,
+		/// Account holds less than the minimum balance to rate
+		RaterBalanceTooLow,
+		/// Account is not among the verified raters
+		RaterNotVerified,
+		/// Account already rated `MaxRatingsPerEra` models this era
+		TooManyRatingsThisEra,
+		/// Account already reported this model
+		AlreadyReported,
+		/// Model is suspended until its reports are resolved
+		ModelUnderReview,
+		/// Model is not suspended by reports
+		NotUnderReview,
+		/// Model was not deactivated by force, or its deactivation was appealed already
+		NotAppealable,
+		/// Model has an open appeal already
+		AppealAlreadyOpen,
+		/// Model has no open appeal
+		AppealNotFound,
+		/// Grace period exceeds `MaxDeactivationGrace`
+		GracePeriodTooLong,
+		/// `MaxDeactivationsPerBlock` deactivations are scheduled for that block already
+		TooManyScheduledDeactivations,
+		/// Model cannot move from its status to the requested one
+		InvalidStatusTransition,
+		/// Model is active and not scheduled for deactivation
+		ModelAlreadyActive,
+		/// Model was deactivated by force; it can only come back through an appeal
+		DeactivatedByForce,
+		/// Model's registration awaits approval
+		AwaitingApproval,
+		/// Model's registration is not awaiting approval
+		NotPendingApproval,
+		/// Only allowlisted developers may register models
+		NotAllowlisted,
+		/// Developer has no profile
+		ProfileNotFound,
+		/// Organization doesn't exist
+		OrganizationNotFound,
+		/// Caller is not a member of the organization
+		NotOrganizationMember,
+		/// Caller's role in the organization doesn't allow this
+		InsufficientOrganizationRole,
+		/// Organization must keep at least one admin
+		LastOrganizationAdmin,
+		/// Organization has `MaxOrganizationMembers` members already
+		TooManyOrganizationMembers,
+		/// Price changes can only be scheduled for a later block
+		PriceChangeNotInFuture,
+		/// `MaxPriceChangesPerBlock` price changes are scheduled for that block already
+		TooManyScheduledPriceChanges,
+		/// Model has no scheduled price change
+		NoPriceChangeScheduled,
+		/// Deactivations can only be scheduled for a later block
+		DeactivationNotInFuture,
+	}
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		fn on_initialize(now: BlockNumberFor<T>) -> Weight {
+			let deactivated = Self::enact_deactivations(now);
+			let announced = Self::announce_deactivations(now);
+			let repriced = Self::enact_price_changes(now);
+			let weight = T::WeightInfo::enact_deactivations(deactivated)
+				.saturating_add(T::WeightInfo::announce_deactivations(announced))
+				.saturating_add(T::WeightInfo::enact_price_changes(repriced));
+
+			let era = T::RentEraLength::get();
+			if now.is_zero() || era.is_zero() || !(now % era).is_zero() {
+				return weight;
+			}
+			let models = Self::credit_era_rewards();
+			weight.saturating_add(T::WeightInfo::credit_era_rewards(models))
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn try_state(_n: BlockNumberFor<T>) -> Result<(), sp_runtime::TryRuntimeError> {
+			Self::do_try_state()
+		}
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Register a new AI model
+		///
+		/// # Arguments
+		/// * `origin` - The account registering the model, per `RegistrationOrigin`
+		/// * `ipfs_cid` - IPFS Content Identifier for the model
+		/// * `name` - Human-readable model name
+		/// * `description` - Model description
+		/// * `model_type` - Type of AI model (classification, regression, generative)
+		/// * `price` - Price for single inference in native tokens
+		/// * `license` - License governing downstream use of the model
+		/// * `size_bytes` - Declared size of the model artifact in bytes
+		/// * `datasets` - Registered datasets the model was trained on, if any
+		///
+		/// The reserved deposit is `MinimumModelStake + DepositPerByte * size_bytes`.
+		/// While `RequireRegistrationApproval` is set the model starts `Pending`.
+		///
+		/// # Errors
+		/// * `NotAllowlisted` - Allowlist is required and caller is not on it
+		/// * `InvalidIPFSCID` - CID format validation failed
+		/// * `CidAlreadyRegistered` - A model with this CID is already registered
+		/// * `DuplicateModelName` - Caller already has a model with this name
+		/// * `InvalidModelSize` - Declared size is zero
+		/// * `InsufficientStake` - Caller cannot reserve the required deposit
+		/// * `NameTooLong` - Name exceeds maximum length
+		/// * `DescriptionTooLong` - Description exceeds maximum length
+		/// * `InsufficientBalance` - Cannot pay registration fee
+		/// * `TooManyDatasets` - More than `MaxDatasetsPerModel` datasets
+		/// * `DatasetNotFound` - A referenced dataset doesn't exist
+		/// * `DuplicateDataset` - A dataset was referenced twice
+		///
+		/// # Events
+		/// * `ModelRegistered` - Model successfully registered
+		/// * `ModelDatasetsLinked` - If any datasets were referenced
+		#[pallet::call_index(0)]
+		#[pallet::weight(T::WeightInfo::register_model(datasets.len() as u32))]
+		#[allow(clippy::too_many_arguments)]
+		pub fn register_model(
+			origin: OriginFor<T>,
+			ipfs_cid: Vec<u8>,
+			name: Vec<u8>,
+			description: Vec<u8>,
+			model_type: ModelType,
+			price: BalanceOf<T>,
+			license: LicenseOf<T>,
+			size_bytes: u64,
+			datasets: Vec<DatasetId>,
+		) -> DispatchResult {
+			let who = T::RegistrationOrigin::ensure_origin(origin)?;
+
+			let datasets: ModelDatasetsOf<T> =
+				datasets.try_into().map_err(|_| Error::<T>::TooManyDatasets)?;
+			Self::ensure_datasets(&datasets)?;
+
+			// Validate IPFS CID length
+			let bounded_cid: BoundedVec<u8, T::MaxCidLength> =
+				ipfs_cid.try_into().map_err(|_| Error::<T>::CidTooLong)?;
+
+			// Validate name length
+			let bounded_name: BoundedVec<u8, T::MaxNameLength> =
+				name.try_into().map_err(|_| Error::<T>::NameTooLong)?;
+
+			// Validate description length
+			let bounded_description: BoundedVec<u8, T::MaxDescriptionLength> =
+				description.try_into().map_err(|_| Error::<T>::DescriptionTooLong)?;
+
+			let model_id = Self::do_register(
+				&who,
+				bounded_cid,
+				bounded_name,
+				bounded_description,
+				model_type,
+				price.saturated_into(),
+				license,
+				size_bytes,
+				None,
+			)?;
+
+			if !datasets.is_empty() {
+				ModelDatasets::<T>::insert(model_id, &datasets);
+				Self::deposit_event(Event::ModelDatasetsLinked { model_id, datasets });
+			}
+
+			Ok(())
+		}
+
+		/// Update model metadata
+		///
+		/// # Arguments
+		/// * `origin` - Must be the model owner, per `RegistrationOrigin`
+		/// * `model_id` - ID of the model to update
+		/// * `new_price` - Optional new price
+		/// * `new_description` - Optional new description
+		/// * `new_status` - Optional new status
+		/// * `new_license` - Optional new license, applying to future use of the model
+		///
+		/// # Errors
+		/// * `ModelNotFound` - Model doesn't exist
+		/// * `UnauthorizedAccess` - Caller is not the owner
+		/// * `OwnershipNotSynced` - Caller no longer holds the model's NFT
+		/// * `FractionallyOwned` - Model is split into shares; use `propose_metadata_update`
+		/// * `MetadataLocked` - Description or license changed on a model with locked metadata
+		/// * `InvalidLicenseCID` - Custom license CID is malformed
+		/// * `InvalidStatusTransition` - Status cannot be reached from the current one
+		/// * `ModelUnderReview` - Model awaits a curator decision on its reports
+		///
+		/// # Events
+		/// * `ModelUpdated` - Metadata successfully updated
+		///
+		/// Charged for a full update; price-only updates are refunded down to
+		/// `update_model_price`.
+		#[pallet::call_index(1)]
+		#[pallet::weight(T::WeightInfo::update_model_metadata())]
+		pub fn update_model_metadata(
+			origin: OriginFor<T>,
+			model_id: ModelId,
+			new_price: Option<BalanceOf<T>>,
+			new_description: Option<Vec<u8>>,
+			new_status: Option<ModelStatus>,
+			new_license: Option<LicenseOf<T>>,
+		) -> DispatchResultWithPostInfo {
+			let who = T::RegistrationOrigin::ensure_origin(origin)?;
+			let price_only =
+				new_description.is_none() && new_status.is_none() && new_license.is_none();
+
+			// Get model and verify ownership
+			Models::<T>::try_mutate(model_id, |maybe_model| -> DispatchResult {
+				let model = maybe_model.as_mut().ok_or(Error::<T>::ModelNotFound)?;
+				Self::ensure_model_owner(model_id, model, &who)?;
+				ensure!(!Shareholders::<T>::contains_key(model_id), Error::<T>::FractionallyOwned);
+
+				let new_description = Self::bound_description(new_description)?;
+				Self::apply_metadata_update(
+					model_id,
+					model,
+					new_price.map(|price| price.saturated_into()),
+					new_description,
+					new_status,
+					new_license,
+				)
+			})?;
+
+			Self::deposit_indexed_event(model_id, Event::ModelUpdated { model_id, owner: who });
+
+			// Without a description, status or license there was no lock, rent or status
+			// to check
+			Ok(price_only.then(T::WeightInfo::update_model_price).into())
+		}
+
+		/// Deactivate a model
+		///
+		/// With a grace period, the model stays usable for that many blocks so that
+		/// integrators relying on it have time to migrate. Deactivating again replaces
+		/// a scheduled deactivation.
+		///
+		/// # Arguments
+		/// * `origin` - Must be the model owner or governance
+		/// * `model_id` - ID of the model to deactivate
+		/// * `grace` - Blocks until the deactivation takes effect; none or zero deactivates
+		///   right away
+		///
+		/// # Errors
+		/// * `ModelNotFound` - Model doesn't exist
+		/// * `UnauthorizedAccess` - Caller is not the owner
+		/// * `OwnershipNotSynced` - Caller no longer holds the model's NFT
+		/// * `GracePeriodTooLong` - Grace period exceeds `MaxDeactivationGrace`
+		/// * `TooManyScheduledDeactivations` - That block is fully booked
+		/// * `ModelUnderReview` - Model awaits a curator decision on its reports
+		///
+		/// # Events
+		/// * `ModelDeactivated` - Model successfully deactivated
+		/// * `DeactivationScheduled` - Model will be deactivated after the grace period
+		#[pallet::call_index(2)]
+		#[pallet::weight(T::WeightInfo::deactivate_model())]
+		pub fn deactivate_model(
+			origin: OriginFor<T>,
+			model_id: ModelId,
+			grace: Option<BlockNumberFor<T>>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let grace = grace.filter(|grace| !grace.is_zero());
+			if let Some(grace) = grace {
+				ensure!(grace <= T::MaxDeactivationGrace::get(), Error::<T>::GracePeriodTooLong);
+			}
+
+			// Get model and verify ownership
+			Models::<T>::try_mutate(model_id, |maybe_model| -> DispatchResult {
+				let model = maybe_model.as_mut().ok_or(Error::<T>::ModelNotFound)?;
+				Self::ensure_model_owner(model_id, model, &who)?;
+				// Deactivating would release the deposit curators may still slash
+				ensure!(model.status != ModelStatus::UnderReview, Error::<T>::ModelUnderReview);
+
+				if grace.is_none() {
+					Self::do_deactivate(model_id, model);
+				}
+				Ok(())
+			})?;
+
+			Self::cancel_deactivation(model_id);
+
+			match grace {
+				Some(grace) => Self::schedule_deactivation(
+					model_id,
+					who,
+					frame_system::Pallet::<T>::block_number().saturating_add(grace),
+				)?,
+				None => Self::deposit_indexed_event(
+					model_id,
+					Event::ModelDeactivated { model_id, owner: who },
+				),
+			}
+
+			Ok(())
+		}
+
+		/// Rate a model
+		///
+		/// Rating again replaces the caller's previous rating. The rating is stored
+		/// together with the caller's inference receipt, if any, so auditors can
+		/// trace it back to a paid inference. It counts with the caller's current
+		/// `RatingWeight`. A written review can be linked by its IPFS CID; rating
+		/// again without one drops the previous review.
+		///
+		/// # Arguments
+		/// * `origin` - User who purchased inference
+		/// * `model_id` - ID of the model to rate
+		/// * `rating` - Rating value (1-5)
+		/// * `review_cid` - Optional IPFS CID of the review text
+		///
+		/// # Errors
+		/// * `ModelNotFound` - Model doesn't exist
+		/// * `InvalidRating` - Rating not in 1-5 range
+		/// * `RaterNotVerified` - Caller is not among the verified raters
+		/// * `RaterTooNew` - Caller's account is younger than `MinRaterAge`
+		/// * `RaterBalanceTooLow` - Caller holds less than `MinRaterBalance`
+		/// * `TooManyRatingsThisEra` - Caller rated `MaxRatingsPerEra` other models this era
+		/// * `CidTooLong` - Review CID exceeds max length
+		/// * `InvalidIPFSCID` - Review CID format is invalid
+		/// * `NotInferenceUser` - `RequireReceiptToRate` is set and the caller holds no
+		///   receipt for the model
+		///
+		/// # Events
+		/// * `ModelRated` - Model successfully rated
+		/// * `ModelPausedForRatings` - The model's recent ratings collapsed
+		#[pallet::call_index(3)]
+		#[pallet::weight(T::WeightInfo::rate_model())]
+		pub fn rate_model(
+			origin: OriginFor<T>,
+			model_id: ModelId,
+			rating: u8,
+			review_cid: Option<Vec<u8>>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			// Validate rating
+			ensure!((1..=5).contains(&rating), Error::<T>::InvalidRating);
+			let receipt = T::InferenceReceipts::receipt_for(&who, model_id);
+			ensure!(
+				receipt.is_some() || !T::RequireReceiptToRate::get(),
+				Error::<T>::NotInferenceUser
+			);
+			Self::ensure_eligible_rater(&who)?;
+			Self::use_rating_quota(&who, model_id)?;
+
+			// Validate the review CID like model CIDs
+			let review_cid = review_cid
+				.map(|cid| -> Result<BoundedVec<u8, T::MaxCidLength>, DispatchError> {
+					let cid: BoundedVec<u8, T::MaxCidLength> =
+						cid.try_into().map_err(|_| Error::<T>::CidTooLong)?;
+					ensure!(Self::validate_ipfs_cid(&cid), Error::<T>::InvalidIPFSCID);
+					Ok(cid)
+				})
+				.transpose()?;
+
+			let previous = Ratings::<T>::get(model_id, &who);
+			let weight = T::RatingWeight::rating_weight(&who, model_id).max(1);
+			let old_rating = previous.as_ref().map(|old| {
+				(old.rating.clamp(1, 5), RatingWeights::<T>::get(model_id, &who).unwrap_or(1))
+			});
+
+			// Update model rating
+			let collapsed = Models::<T>::try_mutate(model_id, |maybe_model| {
+				let model = maybe_model.as_mut().ok_or(Error::<T>::ModelNotFound)?;
+
+				// A rater's new rating replaces their previous one, with its weight
+				let (total, count) = match old_rating {
+					Some((old, old_weight)) => (
+						model.total_rating.saturating_sub(u64::from(old) * u64::from(old_weight)),
+						model.rating_count.saturating_sub(old_weight),
+					),
+					None => (model.total_rating, model.rating_count),
+				};
+				let new_total = total
+					.checked_add(u64::from(rating) * u64::from(weight))
+					.ok_or(Error::<T>::ArithmeticOverflow)?;
+				let new_count = count.checked_add(weight).ok_or(Error::<T>::ArithmeticOverflow)?;
+				if old_rating.is_none() {
+					RaterCounts::<T>::mutate(model_id, |raters| raters.saturating_inc());
+				}
+
+				model.total_rating = new_total;
+				model.rating_count = new_count;
+
+				let collapsed = Self::note_recent_rating(model_id, rating)
+					.filter(|_| model.status == ModelStatus::Active);
+				if collapsed.is_some() {
+					Self::set_status(model_id, model, ModelStatus::Paused);
+					RecentRatings::<T>::remove(model_id);
+				}
+				Self::rank_model(model_id, model);
+
+				Ok::<_, DispatchError>(collapsed)
+			})?;
+
+			let rated_at = frame_system::Pallet::<T>::block_number().saturated_into::<u64>();
+			Ratings::<T>::insert(model_id, &who, RatingRecord { rating, receipt, rated_at });
+			if weight == 1 {
+				RatingWeights::<T>::remove(model_id, &who);
+			} else {
+				RatingWeights::<T>::insert(model_id, &who, weight);
+			}
+			match &review_cid {
+				Some(cid) => RatingReviews::<T>::insert(model_id, &who, cid),
+				None => RatingReviews::<T>::remove(model_id, &who),
+			}
+			RatingHistograms::<T>::mutate(model_id, |histogram| {
+				if let Some((old, old_weight)) = old_rating {
+					histogram[usize::from(old - 1)].saturating_reduce(old_weight);
+				}
+				histogram[usize::from(rating - 1)].saturating_accrue(weight);
+			});
+
+			Self::deposit_indexed_event(
+				model_id,
+				Event::ModelRated { model_id, rater: who, rating, review_cid },
+			);
+			if let Some(average) = collapsed {
+				Self::deposit_indexed_event(
+					model_id,
+					Event::ModelPausedForRatings { model_id, average },
+				);
+			}
+
+			Ok(())
+		}
+
+		/// Propose a correction of a model's declared artifact size
+		///
+		/// Owners cannot change the size directly since it determines the reserved
+		/// deposit; the correction only takes effect once approved by governance.
+		///
+		/// # Arguments
+		/// * `origin` - Must be the model owner
+		/// * `model_id` - ID of the model to correct
+		/// * `size_bytes` - Corrected artifact size in bytes
+		///
+		/// # Errors
+		/// * `ModelNotFound` - Model doesn't exist
+		/// * `UnauthorizedAccess` - Caller is not the owner
+		/// * `OwnershipNotSynced` - Caller no longer holds the model's NFT
+		/// * `InvalidModelSize` - Size is zero
+		///
+		/// # Events
+		/// * `SizeCorrectionProposed` - Correction is pending approval
+		#[pallet::call_index(4)]
+		#[pallet::weight(T::WeightInfo::propose_size_correction())]
+		pub fn propose_size_correction(
+			origin: OriginFor<T>,
+			model_id: ModelId,
+			size_bytes: u64,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			ensure!(size_bytes > 0, Error::<T>::InvalidModelSize);
+			let model = Models::<T>::get(model_id).ok_or(Error::<T>::ModelNotFound)?;
+			Self::ensure_model_owner(model_id, &model, &who)?;
+
+			PendingSizeCorrections::<T>::insert(model_id, size_bytes);
+
+			Self::deposit_event(Event::SizeCorrectionProposed { model_id, size_bytes });
+
+			Ok(())
+		}
+
+		/// Approve a pending size correction and rebalance the reserved deposit
+		///
+		/// # Arguments
+		/// * `origin` - Must be `GovernanceOrigin`
+		/// * `model_id` - ID of the model with a pending correction
+		///
+		/// # Errors
+		/// * `NoPendingSizeCorrection` - Nothing was proposed for this model
+		/// * `ModelNotFound` - Model doesn't exist
+		/// * `InsufficientStake` - Owner cannot reserve the increased deposit
+		///
+		/// # Events
+		/// * `ModelSizeCorrected` - Size and deposit updated
+		#[pallet::call_index(5)]
+		#[pallet::weight(T::WeightInfo::approve_size_correction())]
+		pub fn approve_size_correction(origin: OriginFor<T>, model_id: ModelId) -> DispatchResult {
+			T::GovernanceOrigin::ensure_origin(origin)?;
+
+			let new_size = PendingSizeCorrections::<T>::take(model_id)
+				.ok_or(Error::<T>::NoPendingSizeCorrection)?;
+
+			let (old_size, deposit) =
+				Models::<T>::try_mutate(model_id, |maybe_model| -> Result<_, DispatchError> {
+					let model = maybe_model.as_mut().ok_or(Error::<T>::ModelNotFound)?;
+					let old_size = model.size_bytes;
+
+					// Deactivated models no longer hold a deposit
+					if model.status != ModelStatus::Deactivated {
+						let old_deposit: BalanceOf<T> = model.deposit.saturated_into();
+						let new_deposit = Self::model_deposit(new_size);
+						if new_deposit > old_deposit {
+							T::Currency::reserve(&model.owner, new_deposit - old_deposit)
+								.map_err(|_| Error::<T>::InsufficientStake)?;
+						} else {
+							T::Currency::unreserve(&model.owner, old_deposit - new_deposit);
+						}
+						model.deposit = new_deposit.saturated_into();
+					}
+					model.size_bytes = new_size;
+
+					Ok((old_size, model.deposit))
+				})?;
+
+			Self::deposit_event(Event::ModelSizeCorrected {
+				model_id,
+				old_size,
+				new_size,
+				deposit,
+			});
+
+			Ok(())
+		}
+
+		/// Postpone the block after which root may no longer administer the registry
+		///
+		/// The sunset can only move later, never earlier, and only through
+		/// `SunsetExtensionOrigin`, so the admin key cannot extend its own mandate.
+		///
+		/// # Arguments
+		/// * `origin` - Must be `SunsetExtensionOrigin`
+		/// * `new_sunset` - New sunset block, later than the current one
+		///
+		/// # Errors
+		/// * `SunsetNotExtended` - New sunset is not after the current one
+		///
+		/// # Events
+		/// * `AdminSunsetExtended` - Schedule updated
+		#[pallet::call_index(6)]
+		#[pallet::weight(T::WeightInfo::extend_admin_sunset())]
+		pub fn extend_admin_sunset(
+			origin: OriginFor<T>,
+			new_sunset: BlockNumberFor<T>,
+		) -> DispatchResult {
+			T::SunsetExtensionOrigin::ensure_origin(origin)?;
+
+			let old_sunset = AdminSunset::<T>::get();
+			ensure!(new_sunset > old_sunset, Error::<T>::SunsetNotExtended);
+
+			AdminSunset::<T>::put(new_sunset);
+
+			Self::deposit_event(Event::AdminSunsetExtended { old_sunset, new_sunset });
+
+			Ok(())
+		}
+
+		/// Register an off-chain callback descriptor for a model
+		///
+		/// Gateway operators watch these descriptors to learn where to deliver
+		/// notifications about the model.
+		///
+		/// # Arguments
+		/// * `origin` - Must be the model owner
+		/// * `model_id` - ID of the model
+		/// * `endpoint` - URL hash or IPFS CID describing the endpoint
+		/// * `event_mask` - Bitmask of [`callback_events`] to deliver
+		///
+		/// # Errors
+		/// * `ModelNotFound` - Model doesn't exist
+		/// * `UnauthorizedAccess` - Caller is not the owner
+		/// * `OwnershipNotSynced` - Caller no longer holds the model's NFT
+		/// * `InvalidEventMask` - Mask is empty or has unknown bits
+		/// * `InvalidIPFSCID` - CID endpoint is malformed
+		/// * `TooManyCallbacks` - `MaxCallbacksPerModel` reached
+		///
+		/// # Events
+		/// * `CallbackAdded` - Descriptor stored
+		#[pallet::call_index(7)]
+		#[pallet::weight(T::WeightInfo::add_model_callback())]
+		pub fn add_model_callback(
+			origin: OriginFor<T>,
+			model_id: ModelId,
+			endpoint: CallbackEndpoint<T::MaxCidLength>,
+			event_mask: u32,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			Self::ensure_owner(model_id, &who)?;
+			ensure!(
+				event_mask != 0 && event_mask & !callback_events::ALL == 0,
+				Error::<T>::InvalidEventMask
+			);
+			if let CallbackEndpoint::Cid(ref cid) = endpoint {
+				ensure!(Self::validate_ipfs_cid(cid), Error::<T>::InvalidIPFSCID);
+			}
+
+			let descriptor = CallbackDescriptor { endpoint, event_mask };
+			ModelCallbacks::<T>::try_mutate(model_id, |callbacks| {
+				callbacks.try_push(descriptor.clone()).map_err(|_| Error::<T>::TooManyCallbacks)
+			})?;
+
+			Self::deposit_event(Event::CallbackAdded { model_id, descriptor });
+
+			Ok(())
+		}
+
+		/// Remove an off-chain callback descriptor from a model
+		///
+		/// # Arguments
+		/// * `origin` - Must be the model owner
+		/// * `model_id` - ID of the model
+		/// * `index` - Position of the descriptor in the model's callback list
+		///
+		/// # Errors
+		/// * `ModelNotFound` - Model doesn't exist
+		/// * `UnauthorizedAccess` - Caller is not the owner
+		/// * `OwnershipNotSynced` - Caller no longer holds the model's NFT
+		/// * `CallbackNotFound` - No descriptor at `index`
+		///
+		/// # Events
+		/// * `CallbackRemoved` - Descriptor removed
+		#[pallet::call_index(8)]
+		#[pallet::weight(T::WeightInfo::remove_model_callback())]
+		pub fn remove_model_callback(
+			origin: OriginFor<T>,
+			model_id: ModelId,
+			index: u32,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			Self::ensure_owner(model_id, &who)?;
+			ModelCallbacks::<T>::try_mutate(model_id, |callbacks| -> DispatchResult {
+				ensure!((index as usize) < callbacks.len(), Error::<T>::CallbackNotFound);
+				callbacks.remove(index as usize);
+				Ok(())
+			})?;
+
+			Self::deposit_event(Event::CallbackRemoved { model_id, index });
+
+			Ok(())
+		}
+
+		/// Export models as migration packets
+		///
+		/// Emits one `ModelExported` event per model carrying the packet. The owner
+		/// signs each packet off-chain and submits it to `import_model` on the
+		/// destination chain. Exporting leaves the models untouched here.
+		///
+		/// # Arguments
+		/// * `origin` - Must own every exported model
+		/// * `model_ids` - Models to export, at most `MaxExportBatch`
+		///
+		/// # Errors
+		/// * `TooManyModelsToExport` - More than `MaxExportBatch` models requested
+		/// * `ModelNotFound` - A model doesn't exist
+		/// * `UnauthorizedAccess` - Caller does not own a model
+		/// * `OwnershipNotSynced` - Caller no longer holds a model's NFT
+		/// * `ModelNotActive` - A model has been deactivated
+		///
+		/// # Events
+		/// * `ModelExported` - For each exported model
+		#[pallet::call_index(9)]
+		#[pallet::weight(T::WeightInfo::export_models(model_ids.len() as u32))]
+		pub fn export_models(origin: OriginFor<T>, model_ids: Vec<ModelId>) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			ensure!(
+				model_ids.len() <= T::MaxExportBatch::get() as usize,
+				Error::<T>::TooManyModelsToExport
+			);
+
+			let source_chain = Self::chain_id();
+			let exported_at: u64 = frame_system::Pallet::<T>::block_number().saturated_into();
+
+			for model_id in model_ids {
+				let model = Models::<T>::get(model_id).ok_or(Error::<T>::ModelNotFound)?;
+				Self::ensure_model_owner(model_id, &model, &who)?;
+				ensure!(model.status != ModelStatus::Deactivated, Error::<T>::ModelNotActive);
+
+				let packet = MigrationPacket {
+					version: MIGRATION_PACKET_VERSION,
+					source_chain,
+					source_model_id: model_id,
+					owner: model.owner,
+					ipfs_cid: model.ipfs_cid,
+					name: model.name,
+					description: model.description,
+					model_type: model.model_type,
+					license: model.license,
+					size_bytes: model.size_bytes,
+					price: model.price,
+					created_at: model.created_at,
+					exported_at,
+				};
+
+				Self::deposit_event(Event::ModelExported { model_id, packet });
+			}
+
+			Ok(())
+		}
+
+		/// Import a model from a signed migration packet
+		///
+		/// Anyone may relay the packet; the registration fee and deposit are taken
+		/// from the packet owner, who authorised the import by signing it.
+		///
+		/// # Arguments
+		/// * `origin` - Any signed account relaying the packet
+		/// * `packet` - Packet emitted by `export_models` on the source chain
+		/// * `signature` - Owner's signature over the packet
+		///
+		/// # Errors
+		/// * `UnsupportedPacketVersion` - Unknown packet format
+		/// * `PacketFromThisChain` - Packet was exported from this chain
+		/// * `PacketAlreadyImported` - Packet was imported before
+		/// * `InvalidPacketSignature` - Signature is not the owner's
+		/// * Any error of `register_model`
+		///
+		/// # Events
+		/// * `ModelRegistered` - Model registered under a new local ID
+		/// * `ModelImported` - Provenance recorded
+		#[pallet::call_index(10)]
+		#[pallet::weight(T::WeightInfo::import_model())]
+		pub fn import_model(
+			origin: OriginFor<T>,
+			packet: MigrationPacket<T>,
+			signature: T::OwnerSignature,
+		) -> DispatchResult {
+			ensure_signed(origin)?;
+
+			ensure!(
+				packet.version == MIGRATION_PACKET_VERSION,
+				Error::<T>::UnsupportedPacketVersion
+			);
+			ensure!(packet.source_chain != Self::chain_id(), Error::<T>::PacketFromThisChain);
+			ensure!(
+				!ImportedPackets::<T>::contains_key(packet.source_chain, packet.source_model_id),
+				Error::<T>::PacketAlreadyImported
+			);
+
+			let payload = (MIGRATION_PACKET_CONTEXT, &packet).encode();
+			ensure!(
+				signature.verify(&payload[..], &packet.owner),
+				Error::<T>::InvalidPacketSignature
+			);
+
+			let model_id = Self::do_register(
+				&packet.owner,
+				packet.ipfs_cid,
+				packet.name,
+				packet.description,
+				packet.model_type,
+				packet.price,
+				packet.license,
+				packet.size_bytes,
+				None,
+			)?;
+
+			ImportedPackets::<T>::insert(packet.source_chain, packet.source_model_id, model_id);
+			ImportedFrom::<T>::insert(
+				model_id,
+				ModelProvenance {
+					source_chain: packet.source_chain,
+					source_model_id: packet.source_model_id,
+					created_at: packet.created_at,
+				},
+			);
+
+			Self::deposit_event(Event::ModelImported {
+				model_id,
+				owner: packet.owner,
+				source_chain: packet.source_chain,
+				source_model_id: packet.source_model_id,
+			});
+
+			Ok(())
+		}
+
+		/// Collect rent from a model without recent inference activity
+		///
+		/// Callable by anyone. Once a model has been idle for `RentFreeEras`, every
+		/// full era costs `RentPerEra` from its deposit. When the deposit runs out the
+		/// model is paused and becomes reapable after `RentReapDelay`.
+		///
+		/// # Arguments
+		/// * `origin` - Any signed account
+		/// * `model_id` - ID of the model to charge
+		///
+		/// # Errors
+		/// * `ModelNotFound` - Model doesn't exist
+		/// * `ModelNotActive` - Model is deactivated
+		/// * `RentExhausted` - Deposit already ran out
+		/// * `NoRentDue` - Rent is disabled or no full era is due
+		///
+		/// # Events
+		/// * `RentCollected` - Rent taken from the deposit
+		/// * `ModelRentExhausted` - Deposit ran out and the model was paused
+		#[pallet::call_index(11)]
+		#[pallet::weight(T::WeightInfo::collect_rent())]
+		pub fn collect_rent(origin: OriginFor<T>, model_id: ModelId) -> DispatchResult {
+			ensure_signed(origin)?;
+
+			ensure!(!RentExhausted::<T>::contains_key(model_id), Error::<T>::RentExhausted);
+
+			let now = frame_system::Pallet::<T>::block_number();
+			let era = T::RentEraLength::get();
+			let rent = T::RentPerEra::get();
+			ensure!(!era.is_zero() && !rent.is_zero(), Error::<T>::NoRentDue);
+
+			let rent_free = era.saturating_mul(T::RentFreeEras::get().into());
+			let rent_start = LastActivity::<T>::get(model_id).saturating_add(rent_free);
+			let charged_from = rent_start.max(RentChargedUntil::<T>::get(model_id));
+			let eras_due = now.saturating_sub(charged_from) / era;
+			ensure!(!eras_due.is_zero(), Error::<T>::NoRentDue);
+
+			let (charged, exhausted) =
+				Models::<T>::try_mutate(model_id, |maybe_model| -> Result<_, DispatchError> {
+					let model = maybe_model.as_mut().ok_or(Error::<T>::ModelNotFound)?;
+					ensure!(model.status != ModelStatus::Deactivated, Error::<T>::ModelNotActive);
+
+					let due = rent.saturating_mul(eras_due.saturated_into::<u32>().into());
+					let deposit: BalanceOf<T> = model.deposit.saturated_into();
+					let charged = due.min(deposit);
+
+					// Rent is burned from the reserved deposit
+					let _imbalance = T::Currency::slash_reserved(&model.owner, charged);
+					model.deposit = deposit.saturating_sub(charged).saturated_into();
+
+					// Models under review or awaiting approval keep their status until
+					// curators decide
+					let exhausted = model.deposit == 0;
+					if exhausted
+						&& !matches!(model.status, ModelStatus::UnderReview | ModelStatus::Pending)
+					{
+						Self::set_status(model_id, model, ModelStatus::Paused);
+					}
+
+					Ok((charged, exhausted))
+				})?;
+
+			RentChargedUntil::<T>::insert(
+				model_id,
+				charged_from.saturating_add(era.saturating_mul(eras_due)),
+			);
+			Self::deposit_event(Event::RentCollected {
+				model_id,
+				amount: charged.saturated_into(),
+			});
+
+			if exhausted {
+				let reap_at = now.saturating_add(T::RentReapDelay::get());
+				RentExhausted::<T>::insert(model_id, reap_at);
+				Self::deposit_event(Event::ModelRentExhausted { model_id, reap_at });
+			}
+
+			Ok(())
+		}
+
+		/// Add to a model's reserved deposit
+		///
+		/// Topping up a model whose deposit was exhausted by rent takes it off the
+		/// reaping queue and reactivates it.
+		///
+		/// # Arguments
+		/// * `origin` - Must be the model owner
+		/// * `model_id` - ID of the model
+		/// * `amount` - Additional balance to reserve
+		///
+		/// # Errors
+		/// * `ModelNotFound` - Model doesn't exist
+		/// * `UnauthorizedAccess` - Caller is not the owner
+		/// * `OwnershipNotSynced` - Caller no longer holds the model's NFT
+		/// * `ModelNotActive` - Model is deactivated
+		/// * `InsufficientStake` - Caller cannot reserve `amount`
+		///
+		/// # Events
+		/// * `DepositToppedUp` - Deposit increased
+		#[pallet::call_index(12)]
+		#[pallet::weight(T::WeightInfo::top_up_deposit())]
+		pub fn top_up_deposit(
+			origin: OriginFor<T>,
+			model_id: ModelId,
+			amount: BalanceOf<T>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let deposit =
+				Models::<T>::try_mutate(model_id, |maybe_model| -> Result<_, DispatchError> {
+					let model = maybe_model.as_mut().ok_or(Error::<T>::ModelNotFound)?;
+					Self::ensure_model_owner(model_id, model, &who)?;
+					ensure!(model.status != ModelStatus::Deactivated, Error::<T>::ModelNotActive);
+
+					T::Currency::reserve(&who, amount)
+						.map_err(|_| Error::<T>::InsufficientStake)?;
+					model.deposit = model.deposit.saturating_add(amount.saturated_into());
+
+					if !amount.is_zero()
+						&& RentExhausted::<T>::take(model_id).is_some()
+						&& !matches!(model.status, ModelStatus::UnderReview | ModelStatus::Pending)
+					{
+						Self::set_status(model_id, model, ModelStatus::Active);
+					}
+
+					Ok(model.deposit)
+				})?;
+
+			Self::deposit_event(Event::DepositToppedUp {
+				model_id,
+				amount: amount.saturated_into(),
+				deposit,
+			});
+
+			Ok(())
+		}
+
+		/// Remove a model whose deposit was exhausted by rent
+		///
+		/// Callable by anyone once `RentReapDelay` has passed since the deposit ran
+		/// out. Scheduled deactivations and price changes, reports, rating weights,
+		/// provenance and collection memberships go with it; ratings are kept as an
+		/// audit trail.
+		///
+		/// # Arguments
+		/// * `origin` - Any signed account
+		/// * `model_id` - ID of the model to reap
+		///
+		/// # Errors
+		/// * `NotReapable` - Model's deposit has not been exhausted by rent
+		/// * `ReapTooEarly` - Reap delay has not elapsed
+		///
+		/// # Events
+		/// * `ModelReaped` - Model removed
+		#[pallet::call_index(13)]
+		#[pallet::weight(T::WeightInfo::reap_model(T::MaxCollectionsPerModel::get()))]
+		pub fn reap_model(origin: OriginFor<T>, model_id: ModelId) -> DispatchResult {
+			ensure_signed(origin)?;
+
+			let reap_at = RentExhausted::<T>::get(model_id).ok_or(Error::<T>::NotReapable)?;
+			ensure!(frame_system::Pallet::<T>::block_number() >= reap_at, Error::<T>::ReapTooEarly);
+
+			let model = Models::<T>::take(model_id).ok_or(Error::<T>::ModelNotFound)?;
+			Self::remove_owned_model(&model.owner, model_id);
+			NameIndex::<T>::remove(Self::name_prefix(&model.name), model_id);
+			Self::unindex_name(&model.owner, &model.name, model_id);
+			// Models migrated in with a duplicate CID don't own its entry
+			ModelsByCid::<T>::mutate_exists(Self::cid_key(&model.ipfs_cid), |entry| {
+				if *entry == Some(model_id) {
+					*entry = None;
+				}
+			});
+			ModelCallbacks::<T>::remove(model_id);
+			PendingSizeCorrections::<T>::remove(model_id);
+			LastActivity::<T>::remove(model_id);
+			RentChargedUntil::<T>::remove(model_id);
+			RentExhausted::<T>::remove(model_id);
+			RebatableFees::<T>::remove(model_id);
+			RebatedUntil::<T>::remove(model_id);
+			Self::forfeit_incentive(model_id);
+			TopModels::<T>::mutate(|top| top.retain(|(id, _)| *id != model_id));
+			MostUsedModels::<T>::mutate(|top| top.retain(|(id, _)| *id != model_id));
+			EraRewards::<T>::remove(model_id);
+			Shareholders::<T>::remove(model_id);
+			RevenueRecipient::<T>::remove(model_id);
+			ModelDatasets::<T>::remove(model_id);
+			ParentShare::<T>::remove(model_id);
+			Successors::<T>::remove(model_id);
+			Watchers::<T>::remove(model_id);
+			LockedMetadata::<T>::remove(model_id);
+			UpdateHistory::<T>::remove(model_id);
+			RecentUsage::<T>::remove(model_id);
+			ModelRevenue::<T>::remove(model_id);
+			RecentRevenue::<T>::remove(model_id);
+			RatingHistograms::<T>::remove(model_id);
+			RecentRatings::<T>::remove(model_id);
+			let _ = MetadataProposals::<T>::clear_prefix(model_id, T::MaxShareholders::get(), None);
+			Self::purge_model(model_id);
+			T::ModelNfts::burn(model_id)?;
+			GlobalStats::<T>::mutate(|stats| {
+				stats.total_models.saturating_dec();
+				if model.status == ModelStatus::Active {
+					stats.active_models.saturating_dec();
+				}
+			});
+
+			Self::index_owner(&model.owner, model_id, false);
+			Self::deposit_indexed_event(
+				model_id,
+				Event::ModelReaped { model_id, owner: model.owner },
+			);
+
+			Ok(())
+		}
+
+		/// Slash a model confirmed as fraudulent (stolen weights, malware CID, ...)
+		///
+		/// Deactivates the model, sends `SlashFraction` of its deposit to `Slash` and
+		/// returns the rest to the owner, who may appeal with `appeal_deactivation`.
+		///
+		/// # Arguments
+		/// * `origin` - Must be `SlashOrigin`
+		/// * `model_id` - ID of the model to slash
+		///
+		/// # Errors
+		/// * `ModelNotFound` - Model doesn't exist
+		/// * `ModelNotActive` - Model is already deactivated
+		///
+		/// # Events
+		/// * `ModelSlashed` - Model deactivated and deposit slashed
+		#[pallet::call_index(14)]
+		#[pallet::weight(T::WeightInfo::slash_model(T::ReportThreshold::get()))]
+		pub fn slash_model(origin: OriginFor<T>, model_id: ModelId) -> DispatchResult {
+			T::SlashOrigin::ensure_origin(origin)?;
+
+			Self::do_slash(model_id)
+		}
+
+		/// Transfer a model and its NFT to another account
+		///
+		/// The new owner reserves the model's deposit and the current owner's is
+		/// released.
+		///
+		/// # Arguments
+		/// * `origin` - Must be the model owner
+		/// * `model_id` - ID of the model to transfer
+		/// * `dest` - New owner
+		///
+		/// # Errors
+		/// * `ModelNotFound` - Model doesn't exist
+		/// * `UnauthorizedAccess` - Caller is not the owner
+		/// * `OwnershipNotSynced` - Caller no longer holds the model's NFT
+		/// * `InsufficientStake` - `dest` cannot reserve the deposit
+		///
+		/// # Events
+		/// * `ModelTransferred` - Model moved to `dest`
+		#[pallet::call_index(15)]
+		#[pallet::weight(T::WeightInfo::transfer_model())]
+		pub fn transfer_model(
+			origin: OriginFor<T>,
+			model_id: ModelId,
+			dest: T::AccountId,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			Models::<T>::try_mutate(model_id, |maybe_model| -> DispatchResult {
+				let model = maybe_model.as_mut().ok_or(Error::<T>::ModelNotFound)?;
+				Self::ensure_model_owner(model_id, model, &who)?;
+				Self::do_transfer_ownership(model_id, model, &dest)?;
+				T::ModelNfts::transfer(model_id, &dest)
+			})
+		}
+
+		/// Hand a model to the current holder of its NFT
+		///
+		/// Callable by anyone once the NFT changed hands outside the registry, e.g.
+		/// when it was sold on an NFT marketplace. Until then the previous owner can
+		/// no longer manage the model. The holder reserves the model's deposit and
+		/// the previous owner's is released.
+		///
+		/// # Arguments
+		/// * `origin` - Any signed account
+		/// * `model_id` - ID of the model
+		///
+		/// # Errors
+		/// * `ModelNftNotFound` - Model has no NFT
+		/// * `ModelNotFound` - Model doesn't exist
+		/// * `OwnershipAlreadySynced` - The owner holds the NFT
+		/// * `InsufficientStake` - The holder cannot reserve the deposit
+		///
+		/// # Events
+		/// * `ModelTransferred` - Model moved to the NFT holder
+		#[pallet::call_index(16)]
+		#[pallet::weight(T::WeightInfo::sync_model_owner())]
+		pub fn sync_model_owner(origin: OriginFor<T>, model_id: ModelId) -> DispatchResult {
+			ensure_signed(origin)?;
+
+			let holder = T::ModelNfts::owner(model_id).ok_or(Error::<T>::ModelNftNotFound)?;
+			Models::<T>::try_mutate(model_id, |maybe_model| -> DispatchResult {
+				let model = maybe_model.as_mut().ok_or(Error::<T>::ModelNotFound)?;
+				ensure!(model.owner != holder, Error::<T>::OwnershipAlreadySynced);
+				Self::do_transfer_ownership(model_id, model, &holder)
+			})
+		}
+
+		/// Split a model into shares
+		///
+		/// Inference revenue of the model is paid out by share from then on, and
+		/// its metadata can only be changed through `propose_metadata_update`. The
+		/// owner keeps managing everything else, e.g. callbacks and the deposit.
+		///
+		/// # Arguments
+		/// * `origin` - Must be the model owner
+		/// * `model_id` - ID of the model
+		/// * `shares` - Shareholders and their shares, adding up to 100%
+		///
+		/// # Errors
+		/// * `ModelNotFound` - Model doesn't exist
+		/// * `UnauthorizedAccess` - Caller is not the owner
+		/// * `OwnershipNotSynced` - Caller no longer holds the model's NFT
+		/// * `ModelAlreadyFractional` - Model is split into shares already
+		/// * `InvalidShares` - Shares are malformed or do not add up to 100%
+		/// * `TooManyShareholders` - More than `MaxShareholders` shareholders
+		///
+		/// # Events
+		/// * `ModelFractionalized` - Model split into shares
+		#[pallet::call_index(17)]
+		#[pallet::weight(T::WeightInfo::fractionalize_model(shares.len() as u32))]
+		pub fn fractionalize_model(
+			origin: OriginFor<T>,
+			model_id: ModelId,
+			shares: Vec<(T::AccountId, Perbill)>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			Self::ensure_owner(model_id, &who)?;
+			ensure!(!Shareholders::<T>::contains_key(model_id), Error::<T>::ModelAlreadyFractional);
+
+			let shares: ModelSharesOf<T> =
+				shares.try_into().map_err(|_| Error::<T>::TooManyShareholders)?;
+			let mut total: u64 = 0;
+			for (i, (holder, share)) in shares.iter().enumerate() {
+				ensure!(!share.is_zero(), Error::<T>::InvalidShares);
+				ensure!(
+					!shares[..i].iter().any(|(other, _)| other == holder),
+					Error::<T>::InvalidShares
+				);
+				total = total.saturating_add(u64::from(share.deconstruct()));
+			}
+			ensure!(total == u64::from(Perbill::one().deconstruct()), Error::<T>::InvalidShares);
+
+			Shareholders::<T>::insert(model_id, &shares);
+
+			Self::deposit_event(Event::ModelFractionalized { model_id, shares });
+
+			Ok(())
+		}
+
+		/// Transfer part of the caller's share of a model
+		///
+		/// # Arguments
+		/// * `origin` - A shareholder of the model
+		/// * `model_id` - ID of the model
+		/// * `dest` - Receiver of the share
+		/// * `share` - Share to transfer
+		///
+		/// # Errors
+		/// * `ModelNotFractional` - Model is not split into shares
+		/// * `InvalidShares` - `share` is zero
+		/// * `InsufficientShare` - Caller holds less than `share`
+		/// * `TooManyShareholders` - `dest` would exceed `MaxShareholders`
+		///
+		/// # Events
+		/// * `SharesTransferred` - Share moved to `dest`
+		#[pallet::call_index(18)]
+		#[pallet::weight(T::WeightInfo::transfer_shares())]
+		pub fn transfer_shares(
+			origin: OriginFor<T>,
+			model_id: ModelId,
+			dest: T::AccountId,
+			share: Perbill,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			ensure!(!share.is_zero(), Error::<T>::InvalidShares);
+
+			Shareholders::<T>::try_mutate(model_id, |maybe_shares| -> DispatchResult {
+				let shares = maybe_shares.as_mut().ok_or(Error::<T>::ModelNotFractional)?;
+
+				let from = shares
+					.iter()
+					.position(|(holder, _)| *holder == who)
+					.ok_or(Error::<T>::InsufficientShare)?;
+				ensure!(shares[from].1 >= share, Error::<T>::InsufficientShare);
+				shares[from].1 = shares[from].1.saturating_sub(share);
+				if shares[from].1.is_zero() {
+					shares.remove(from);
+				}
+
+				match shares.iter_mut().find(|(holder, _)| *holder == dest) {
+					Some((_, held)) => *held = held.saturating_add(share),
+					None => shares
+						.try_push((dest.clone(), share))
+						.map_err(|_| Error::<T>::TooManyShareholders)?,
+				}
+
+				// Only shareholders above the threshold keep proposals open, bounding
+				// them by `MaxShareholders`
+				if Self::share_of(shares, &who) < T::ProposalShareThreshold::get() {
+					MetadataProposals::<T>::remove(model_id, &who);
+				}
+				Ok(())
+			})?;
+
+			Self::deposit_event(Event::SharesTransferred { model_id, from: who, to: dest, share });
+
+			Ok(())
+		}
+
+		/// Propose a metadata change of a fractionally owned model
+		///
+		/// The proposal counts as approved by the proposer and is applied as soon
+		/// as shareholders holding more than half of the shares approve it.
+		///
+		/// # Arguments
+		/// * `origin` - A shareholder holding at least `ProposalShareThreshold`
+		/// * `model_id` - ID of the model
+		/// * `new_price` - Optional new price
+		/// * `new_description` - Optional new description
+		/// * `new_status` - Optional new status
+		///
+		/// # Errors
+		/// * `ModelNotFractional` - Model is not split into shares
+		/// * `InsufficientShare` - Caller's share is below `ProposalShareThreshold`
+		/// * `ProposalAlreadyPending` - Caller has a pending proposal for the model
+		/// * `DescriptionTooLong` - Description exceeds maximum length
+		/// * `MetadataLocked` - Description changed on a model with locked metadata
+		/// * `InvalidStatusTransition` - Status cannot be reached from the current one
+		/// * `ModelUnderReview` - Model awaits a curator decision on its reports
+		///
+		/// # Events
+		/// * `MetadataChangeProposed` - Proposal recorded
+		/// * `MetadataChangeEnacted` - The proposer's share alone was a majority
+		#[pallet::call_index(19)]
+		#[pallet::weight(T::WeightInfo::propose_metadata_update())]
+		pub fn propose_metadata_update(
+			origin: OriginFor<T>,
+			model_id: ModelId,
+			new_price: Option<BalanceOf<T>>,
+			new_description: Option<Vec<u8>>,
+			new_status: Option<ModelStatus>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let shares = Shareholders::<T>::get(model_id).ok_or(Error::<T>::ModelNotFractional)?;
+			let share = Self::share_of(&shares, &who);
+			ensure!(
+				!share.is_zero() && share >= T::ProposalShareThreshold::get(),
+				Error::<T>::InsufficientShare
+			);
+			ensure!(
+				!MetadataProposals::<T>::contains_key(model_id, &who),
+				Error::<T>::ProposalAlreadyPending
+			);
+			ensure!(
+				new_description.is_none() || !LockedMetadata::<T>::contains_key(model_id),
+				Error::<T>::MetadataLocked
+			);
+
+			let proposal = MetadataProposal {
+				new_price: new_price.map(|price| price.saturated_into()),
+				new_description: Self::bound_description(new_description)?,
+				new_status,
+				approvals: BoundedVec::truncate_from(sp_std::vec![who.clone()]),
+			};
+
+			Self::deposit_event(Event::MetadataChangeProposed { model_id, proposer: who.clone() });
+
+			Self::enact_or_store(model_id, &who, proposal, &shares)
+		}
+
+		/// Approve a pending metadata change of a fractionally owned model
+		///
+		/// # Arguments
+		/// * `origin` - A shareholder of the model
+		/// * `model_id` - ID of the model
+		/// * `proposer` - Shareholder who proposed the change
+		///
+		/// # Errors
+		/// * `ModelNotFractional` - Model is not split into shares
+		/// * `NotShareholder` - Caller holds no share
+		/// * `ProposalNotFound` - `proposer` has no pending proposal
+		/// * `AlreadyApproved` - Caller approved the proposal already
+		/// * `MetadataLocked` - Proposal changes the description of a model whose
+		///   metadata was locked since
+		/// * `InvalidStatusTransition` - Proposed status cannot be reached from the current one
+		/// * `ModelUnderReview` - Model awaits a curator decision on its reports
+		///
+		/// # Events
+		/// * `MetadataChangeApproved` - Approval recorded
+		/// * `MetadataChangeEnacted` - Approvals reached a majority of shares
+		#[pallet::call_index(20)]
+		#[pallet::weight(T::WeightInfo::approve_metadata_update())]
+		pub fn approve_metadata_update(
+			origin: OriginFor<T>,
+			model_id: ModelId,
+			proposer: T::AccountId,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let shares = Shareholders::<T>::get(model_id).ok_or(Error::<T>::ModelNotFractional)?;
+			ensure!(!Self::share_of(&shares, &who).is_zero(), Error::<T>::NotShareholder);
+			let mut proposal = MetadataProposals::<T>::get(model_id, &proposer)
+				.ok_or(Error::<T>::ProposalNotFound)?;
+			ensure!(!proposal.approvals.contains(&who), Error::<T>::AlreadyApproved);
+			proposal
+				.approvals
+				.try_push(who.clone())
+				.map_err(|_| Error::<T>::TooManyShareholders)?;
+
+			Self::deposit_event(Event::MetadataChangeApproved {
+				model_id,
+				proposer: proposer.clone(),
+				approver: who,
+			});
+
+			Self::enact_or_store(model_id, &proposer, proposal, &shares)
+		}
+
+		/// Withdraw the caller's pending metadata change
+		///
+		/// # Arguments
+		/// * `origin` - The proposer
+		/// * `model_id` - ID of the model
+		///
+		/// # Errors
+		/// * `ProposalNotFound` - Caller has no pending proposal for the model
+		///
+		/// # Events
+		/// * `MetadataChangeCancelled` - Proposal removed
+		#[pallet::call_index(21)]
+		#[pallet::weight(T::WeightInfo::cancel_metadata_update())]
+		pub fn cancel_metadata_update(origin: OriginFor<T>, model_id: ModelId) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			MetadataProposals::<T>::take(model_id, &who).ok_or(Error::<T>::ProposalNotFound)?;
+
+			Self::deposit_event(Event::MetadataChangeCancelled { model_id, proposer: who });
+
+			Ok(())
+		}
+
+		/// Register a training dataset that models can reference
+		///
+		/// # Arguments
+		/// * `origin` - Any signed account, becoming the dataset's owner
+		/// * `ipfs_cid` - IPFS Content Identifier for the data
+		/// * `name` - Human-readable dataset name
+		/// * `license` - License governing use of the data
+		///
+		/// # Errors
+		/// * `CidTooLong` - CID exceeds maximum length
+		/// * `InvalidIPFSCID` - CID format validation failed
+		/// * `InvalidLicenseCID` - Custom license CID is malformed
+		/// * `NameTooLong` - Name exceeds maximum length
+		/// * `InsufficientBalance` - Cannot pay `RegistrationFee`
+		///
+		/// # Events
+		/// * `DatasetRegistered` - Dataset registered
+		#[pallet::call_index(22)]
+		#[pallet::weight(T::WeightInfo::register_dataset())]
+		pub fn register_dataset(
+			origin: OriginFor<T>,
+			ipfs_cid: Vec<u8>,
+			name: Vec<u8>,
+			license: LicenseOf<T>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let ipfs_cid: BoundedVec<u8, T::MaxCidLength> =
+				ipfs_cid.try_into().map_err(|_| Error::<T>::CidTooLong)?;
+			ensure!(Self::validate_ipfs_cid(&ipfs_cid), Error::<T>::InvalidIPFSCID);
+			if let License::Custom(ref license_cid) = license {
+				ensure!(Self::validate_ipfs_cid(license_cid), Error::<T>::InvalidLicenseCID);
+			}
+			let name: BoundedVec<u8, T::MaxNameLength> =
+				name.try_into().map_err(|_| Error::<T>::NameTooLong)?;
+
+			let dataset_id = NextDatasetId::<T>::get();
+			let next_id = dataset_id.checked_add(1).ok_or(Error::<T>::ArithmeticOverflow)?;
+
+			Self::charge_registration_fee(&who)?;
+
+			let created_at = frame_system::Pallet::<T>::block_number().saturated_into();
+			Datasets::<T>::insert(
+				dataset_id,
+				DatasetInfo {
+					owner: who.clone(),
+					ipfs_cid: ipfs_cid.clone(),
+					name,
+					license,
+					created_at,
+				},
+			);
+			NextDatasetId::<T>::put(next_id);
+
+			Self::deposit_event(Event::DatasetRegistered { dataset_id, owner: who, ipfs_cid });
+
+			Ok(())
+		}
+
+		/// Register a new model derived from an existing one
+		///
+		/// The fork records its parent and takes over its model type; everything else
+		/// is registered as with `register_model`. The forker may pass part of the
+		/// fork's inference revenue to whoever owns the parent at the time.
+		///
+		/// # Arguments
+		/// * `origin` - The account registering the fork, per `RegistrationOrigin`
+		/// * `parent_id` - ID of the model being forked
+		/// * `ipfs_cid` - IPFS Content Identifier for the fork
+		/// * `parent_share` - Share of the fork's inference revenue paid to the
+		///   parent's owner, at most `MaxParentShare`
+		///
+		/// # Errors
+		/// * `ModelNotFound` - Parent doesn't exist
+		/// * `ParentShareTooHigh` - `parent_share` exceeds `MaxParentShare`
+		/// * Any error of `register_model`
+		///
+		/// # Events
+		/// * `ModelRegistered` - Fork registered
+		/// * `ModelForked` - Fork linked to its parent
+		#[pallet::call_index(23)]
+		#[pallet::weight(T::WeightInfo::fork_model())]
+		pub fn fork_model(
+			origin: OriginFor<T>,
+			parent_id: ModelId,
+			ipfs_cid: Vec<u8>,
+			name: Vec<u8>,
+			description: Vec<u8>,
+			price: BalanceOf<T>,
+			license: LicenseOf<T>,
+			size_bytes: u64,
+			parent_share: Perbill,
+		) -> DispatchResult {
+			let who = T::RegistrationOrigin::ensure_origin(origin)?;
+
+			let parent = Models::<T>::get(parent_id).ok_or(Error::<T>::ModelNotFound)?;
+			ensure!(parent_share <= T::MaxParentShare::get(), Error::<T>::ParentShareTooHigh);
+
+			let bounded_cid: BoundedVec<u8, T::MaxCidLength> =
+				ipfs_cid.try_into().map_err(|_| Error::<T>::CidTooLong)?;
+			let bounded_name: BoundedVec<u8, T::MaxNameLength> =
+				name.try_into().map_err(|_| Error::<T>::NameTooLong)?;
+			let bounded_description: BoundedVec<u8, T::MaxDescriptionLength> =
+				description.try_into().map_err(|_| Error::<T>::DescriptionTooLong)?;
+
+			let model_id = Self::do_register(
+				&who,
+				bounded_cid,
+				bounded_name,
+				bounded_description,
+				parent.model_type,
+				price.saturated_into(),
+				license,
+				size_bytes,
+				Some(parent_id),
+			)?;
+
+			if !parent_share.is_zero() {
+				ParentShare::<T>::insert(model_id, parent_share);
+			}
+
+			Self::deposit_event(Event::ModelForked {
+				model_id,
+				parent_id,
+				owner: who,
+				parent_share,
+			});
+
+			Ok(())
+		}
+
+		/// Create an empty named collection of models
+		///
+		/// # Arguments
+		/// * `origin` - Any signed account, becoming the collection's owner
+		/// * `name` - Human-readable collection name
+		///
+		/// # Errors
+		/// * `NameTooLong` - Name exceeds maximum length
+		/// * `InsufficientBalance` - Cannot pay `RegistrationFee`
+		///
+		/// # Events
+		/// * `CollectionCreated` - Collection created
+		#[pallet::call_index(24)]
+		#[pallet::weight(T::WeightInfo::create_collection())]
+		pub fn create_collection(origin: OriginFor<T>, name: Vec<u8>) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let name: BoundedVec<u8, T::MaxNameLength> =
+				name.try_into().map_err(|_| Error::<T>::NameTooLong)?;
+
+			let collection_id = NextCollectionId::<T>::get();
+			let next_id = collection_id.checked_add(1).ok_or(Error::<T>::ArithmeticOverflow)?;
+
+			Self::charge_registration_fee(&who)?;
+
+			Collections::<T>::insert(
+				collection_id,
+				CollectionInfo {
+					owner: who.clone(),
+					name: name.clone(),
+					models: Default::default(),
+				},
+			);
+			NextCollectionId::<T>::put(next_id);
+
+			Self::deposit_event(Event::CollectionCreated { collection_id, owner: who, name });
+
+			Ok(())
+		}
+
+		/// Add one of the caller's models to their collection
+		///
+		/// # Arguments
+		/// * `origin` - Must own both the collection and the model
+		/// * `collection_id` - ID of the collection
+		/// * `model_id` - ID of the model to add
+		///
+		/// # Errors
+		/// * `CollectionNotFound` - Collection doesn't exist
+		/// * `NotCollectionOwner` - Caller doesn't own the collection
+		/// * `ModelNotFound` - Model doesn't exist
+		/// * `UnauthorizedAccess` - Caller doesn't own the model
+		/// * `OwnershipNotSynced` - Caller no longer holds the model's NFT
+		/// * `AlreadyInCollection` - Model is already in the collection
+		/// * `CollectionFull` - Collection holds `MaxModelsPerCollection` models
+		/// * `TooManyCollections` - Model is in `MaxCollectionsPerModel` collections
+		///
+		/// # Events
+		/// * `ModelAddedToCollection` - Model added
+		#[pallet::call_index(25)]
+		#[pallet::weight(T::WeightInfo::add_to_collection())]
+		pub fn add_to_collection(
+			origin: OriginFor<T>,
+			collection_id: CollectionId,
+			model_id: ModelId,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			Collections::<T>::try_mutate(collection_id, |maybe_collection| -> DispatchResult {
+				let collection = maybe_collection.as_mut().ok_or(Error::<T>::CollectionNotFound)?;
+				ensure!(collection.owner == who, Error::<T>::NotCollectionOwner);
+				Self::ensure_owner(model_id, &who)?;
+				ensure!(
+					collection
+						.models
+						.try_insert(model_id)
+						.map_err(|_| Error::<T>::CollectionFull)?,
+					Error::<T>::AlreadyInCollection
+				);
+				Ok(())
+			})?;
+			ModelCollections::<T>::try_mutate(model_id, |collections| {
+				collections.try_insert(collection_id).map_err(|_| Error::<T>::TooManyCollections)
+			})?;
+
+			Self::deposit_event(Event::ModelAddedToCollection { collection_id, model_id });
+
+			Ok(())
+		}
+
+		/// Remove a model from a collection
+		///
+		/// The collection's owner can remove any of its models; anyone can remove a
+		/// model the collection's owner no longer owns, e.g. after a sale.
+		///
+		/// # Arguments
+		/// * `origin` - The collection's owner, or any signed account for stale entries
+		/// * `collection_id` - ID of the collection
+		/// * `model_id` - ID of the model to remove
+		///
+		/// # Errors
+		/// * `CollectionNotFound` - Collection doesn't exist
+		/// * `NotInCollection` - Model isn't in the collection
+		/// * `NotCollectionOwner` - Caller doesn't own the collection and the entry
+		///   isn't stale
+		///
+		/// # Events
+		/// * `ModelRemovedFromCollection` - Model removed
+		#[pallet::call_index(26)]
+		#[pallet::weight(T::WeightInfo::remove_from_collection())]
+		pub fn remove_from_collection(
+			origin: OriginFor<T>,
+			collection_id: CollectionId,
+			model_id: ModelId,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			Collections::<T>::try_mutate(collection_id, |maybe_collection| -> DispatchResult {
+				let collection = maybe_collection.as_mut().ok_or(Error::<T>::CollectionNotFound)?;
+				ensure!(collection.models.contains(&model_id), Error::<T>::NotInCollection);
+				let stale =
+					Models::<T>::get(model_id).is_none_or(|model| model.owner != collection.owner);
+				ensure!(collection.owner == who || stale, Error::<T>::NotCollectionOwner);
+				collection.models.remove(&model_id);
+				Ok(())
+			})?;
+			ModelCollections::<T>::mutate_exists(model_id, |maybe_collections| {
+				if let Some(collections) = maybe_collections {
+					collections.remove(&collection_id);
+					if collections.is_empty() {
+						*maybe_collections = None;
+					}
+				}
+			});
+
+			Self::deposit_event(Event::ModelRemovedFromCollection { collection_id, model_id });
+
+			Ok(())
+		}
+
+		/// Deprecate a model, pointing its users at a successor
+		///
+		/// Clients can look the successor up in `Successors` and move over to it. The
+		/// pointer stays until the owners give the model a status other than
+		/// `Deprecated` through a metadata update.
+		///
+		/// # Arguments
+		/// * `origin` - Must be the model owner, per `RegistrationOrigin`
+		/// * `model_id` - ID of the model to deprecate
+		/// * `successor` - Active model replacing it, if any
+		///
+		/// # Errors
+		/// * `ModelNotFound` - Model doesn't exist
+		/// * `UnauthorizedAccess` - Caller is not the owner
+		/// * `OwnershipNotSynced` - Caller no longer holds the model's NFT
+		/// * `FractionallyOwned` - Model is split into shares; use `propose_metadata_update`
+		/// * `ModelNotActive` - Model is deactivated
+		/// * `InvalidSuccessor` - Successor doesn't exist, isn't active or is the model
+		///   itself
+		/// * `ModelUnderReview` - Model awaits a curator decision on its reports
+		/// * `AwaitingApproval` - Model's registration has not been approved yet
+		///
+		/// # Events
+		/// * `ModelDeprecated` - Model deprecated
+		#[pallet::call_index(27)]
+		#[pallet::weight(T::WeightInfo::deprecate_model())]
+		pub fn deprecate_model(
+			origin: OriginFor<T>,
+			model_id: ModelId,
+			successor: Option<ModelId>,
+		) -> DispatchResult {
+			let who = T::RegistrationOrigin::ensure_origin(origin)?;
+
+			if let Some(successor_id) = successor {
+				ensure!(successor_id != model_id, Error::<T>::InvalidSuccessor);
+				let successor =
+					Models::<T>::get(successor_id).ok_or(Error::<T>::InvalidSuccessor)?;
+				ensure!(successor.status == ModelStatus::Active, Error::<T>::InvalidSuccessor);
+			}
+
+			Models::<T>::try_mutate(model_id, |maybe_model| -> DispatchResult {
+				let model = maybe_model.as_mut().ok_or(Error::<T>::ModelNotFound)?;
+				Self::ensure_model_owner(model_id, model, &who)?;
+				ensure!(!Shareholders::<T>::contains_key(model_id), Error::<T>::FractionallyOwned);
+				ensure!(model.status != ModelStatus::Deactivated, Error::<T>::ModelNotActive);
+				ensure!(model.status != ModelStatus::UnderReview, Error::<T>::ModelUnderReview);
+				ensure!(model.status != ModelStatus::Pending, Error::<T>::AwaitingApproval);
+				Self::set_status(model_id, model, ModelStatus::Deprecated);
+				Ok(())
+			})?;
+			Successors::<T>::set(model_id, successor);
+
+			Self::deposit_indexed_event(model_id, Event::ModelDeprecated { model_id, successor });
+
+			Ok(())
+		}
+
+		/// Save a model to the caller's bookmarks
+		///
+		/// # Arguments
+		/// * `origin` - Any signed account
+		/// * `model_id` - ID of the model to bookmark
+		///
+		/// # Errors
+		/// * `ModelNotFound` - Model doesn't exist
+		/// * `AlreadyBookmarked` - Model is already bookmarked
+		/// * `TooManyBookmarks` - Caller already bookmarked `MaxBookmarks` models
+		///
+		/// # Events
+		/// * `ModelBookmarked` - Bookmark added
+		#[pallet::call_index(28)]
+		#[pallet::weight(T::WeightInfo::bookmark_model())]
+		pub fn bookmark_model(origin: OriginFor<T>, model_id: ModelId) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			ensure!(Models::<T>::contains_key(model_id), Error::<T>::ModelNotFound);
+			Bookmarks::<T>::try_mutate(&who, |bookmarks| -> DispatchResult {
+				ensure!(
+					bookmarks.try_insert(model_id).map_err(|_| Error::<T>::TooManyBookmarks)?,
+					Error::<T>::AlreadyBookmarked
+				);
+				Ok(())
+			})?;
+
+			Self::deposit_event(Event::ModelBookmarked { who, model_id });
+
+			Ok(())
+		}
+
+		/// Remove a model from the caller's bookmarks
+		///
+		/// Works for models that no longer exist, too.
+		///
+		/// # Arguments
+		/// * `origin` - Any signed account
+		/// * `model_id` - ID of the bookmarked model
+		///
+		/// # Errors
+		/// * `NotBookmarked` - Model isn't bookmarked
+		///
+		/// # Events
+		/// * `ModelUnbookmarked` - Bookmark removed
+		#[pallet::call_index(29)]
+		#[pallet::weight(T::WeightInfo::unbookmark_model())]
+		pub fn unbookmark_model(origin: OriginFor<T>, model_id: ModelId) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			Bookmarks::<T>::try_mutate_exists(&who, |maybe_bookmarks| -> DispatchResult {
+				let bookmarks = maybe_bookmarks.as_mut().ok_or(Error::<T>::NotBookmarked)?;
+				ensure!(bookmarks.remove(&model_id), Error::<T>::NotBookmarked);
+				if bookmarks.is_empty() {
+					*maybe_bookmarks = None;
+				}
+				Ok(())
+			})?;
+
+			Self::deposit_event(Event::ModelUnbookmarked { who, model_id });
+
+			Ok(())
+		}
+
+		/// Get notified of changes to a model's price or status
+		///
+		/// Every change emits `WatchedModelChanged` events listing the model's
+		/// watchers, for notification services to push to.
+		///
+		/// # Arguments
+		/// * `origin` - Any signed account
+		/// * `model_id` - ID of the model to watch
+		///
+		/// # Errors
+		/// * `ModelNotFound` - Model doesn't exist
+		/// * `AlreadyWatching` - Caller already watches the model
+		/// * `TooManyWatchers` - Model already has `MaxWatchers` watchers
+		///
+		/// # Events
+		/// * `ModelWatched` - Caller now watches the model
+		#[pallet::call_index(30)]
+		#[pallet::weight(T::WeightInfo::watch_model())]
+		pub fn watch_model(origin: OriginFor<T>, model_id: ModelId) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			ensure!(Models::<T>::contains_key(model_id), Error::<T>::ModelNotFound);
+			Watchers::<T>::try_mutate(model_id, |watchers| -> DispatchResult {
+				ensure!(!watchers.contains(&who), Error::<T>::AlreadyWatching);
+				watchers.try_push(who.clone()).map_err(|_| Error::<T>::TooManyWatchers)?;
+				Ok(())
+			})?;
+
+			Self::deposit_event(Event::ModelWatched { who, model_id });
+
+			Ok(())
+		}
+
+		/// Stop watching a model
+		///
+		/// # Arguments
+		/// * `origin` - Any signed account
+		/// * `model_id` - ID of the watched model
+		///
+		/// # Errors
+		/// * `NotWatching` - Caller doesn't watch the model
+		///
+		/// # Events
+		/// * `ModelUnwatched` - Caller no longer watches the model
+		#[pallet::call_index(31)]
+		#[pallet::weight(T::WeightInfo::unwatch_model())]
+		pub fn unwatch_model(origin: OriginFor<T>, model_id: ModelId) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			Watchers::<T>::try_mutate(model_id, |watchers| -> DispatchResult {
+				let index = watchers
+					.iter()
+					.position(|watcher| *watcher == who)
+					.ok_or(Error::<T>::NotWatching)?;
+				watchers.swap_remove(index);
+				Ok(())
+			})?;
+
+			Self::deposit_event(Event::ModelUnwatched { who, model_id });
+
+			Ok(())
+		}
+
+		/// Pay part of a highly rated model's registration fee back to its owner
+		///
+		/// Callable by anyone. Every full `RentEraLength` since the last rebate earns
+		/// `RebatePerEra` of the registration fee, paid from the fee pot, until the
+		/// whole fee is back. The model's average rating must be at least
+		/// `RebateRatingThreshold`, rated by `RebateMinRatings` accounts, when claiming. If
+		/// the pot runs short, it pays what it has and the rest of the due eras is
+		/// forfeited.
+		///
+		/// # Arguments
+		/// * `origin` - Any signed account
+		/// * `model_id` - ID of the model
+		///
+		/// # Errors
+		/// * `ModelNotFound` - Model doesn't exist
+		/// * `ModelNotActive` - Model is paused, deprecated or deactivated
+		/// * `RatingTooLow` - Model is not rated well enough
+		/// * `NoRebateDue` - Rebates are disabled, no full era is due or nothing is left
+		/// * `FeePotEmpty` - The fee pot cannot pay anything
+		///
+		/// # Events
+		/// * `FeeRebated` - Rebate paid to the owner
+		#[pallet::call_index(32)]
+		#[pallet::weight(T::WeightInfo::claim_fee_rebate())]
+		pub fn claim_fee_rebate(origin: OriginFor<T>, model_id: ModelId) -> DispatchResult {
+			ensure_signed(origin)?;
+
+			let model = Models::<T>::get(model_id).ok_or(Error::<T>::ModelNotFound)?;
+			ensure!(model.status == ModelStatus::Active, Error::<T>::ModelNotActive);
+			ensure!(Self::rated_for_rebate(model_id, &model), Error::<T>::RatingTooLow);
+
+			let now = frame_system::Pallet::<T>::block_number();
+			let era = T::RentEraLength::get();
+			let share = T::RebatePerEra::get();
+			let remaining = RebatableFees::<T>::get(model_id);
+			ensure!(
+				!era.is_zero() && !share.is_zero() && !remaining.is_zero(),
+				Error::<T>::NoRebateDue
+			);
+			let rebated_until = RebatedUntil::<T>::get(model_id);
+			let eras_due = now.saturating_sub(rebated_until) / era;
+			ensure!(!eras_due.is_zero(), Error::<T>::NoRebateDue);
+
+			let due = (share * T::RegistrationFee::get())
+				.saturating_mul(eras_due.saturated_into::<u32>().into());
+			let pot = Self::fee_pot();
+			let available =
+				T::Currency::free_balance(&pot).saturating_sub(T::Currency::minimum_balance());
+			let amount = due.min(remaining).min(available);
+			ensure!(!amount.is_zero(), Error::<T>::FeePotEmpty);
+
+			T::Currency::transfer(&pot, &model.owner, amount, ExistenceRequirement::KeepAlive)?;
+			if amount == remaining {
+				RebatableFees::<T>::remove(model_id);
+				RebatedUntil::<T>::remove(model_id);
+			} else {
+				RebatableFees::<T>::insert(model_id, remaining.saturating_sub(amount));
+				RebatedUntil::<T>::insert(
+					model_id,
+					rebated_until.saturating_add(era.saturating_mul(eras_due)),
+				);
+			}
+
+			Self::deposit_event(Event::FeeRebated {
+				model_id,
+				owner: model.owner,
+				amount: amount.saturated_into(),
+			});
+
+			Ok(())
+		}
+
+		/// Fund the model incentive program from `IncentiveSource`
+		///
+		/// Every era with verified inferences of active models gets `per_era` of the
+		/// funds, or what is left of them, split among those models by their number
+		/// of verified inferences. A zero `amount` only changes the per-era budget.
+		///
+		/// # Arguments
+		/// * `origin` - Must be `IncentiveOrigin`
+		/// * `amount` - Funds moved from `IncentiveSource` into the incentive pot
+		/// * `per_era` - Budget distributed per era
+		///
+		/// # Errors
+		/// * `InsufficientBalance` - `IncentiveSource` cannot pay `amount`
+		///
+		/// # Events
+		/// * `IncentivesFunded` - Funds and budget recorded
+		#[pallet::call_index(33)]
+		#[pallet::weight(T::WeightInfo::fund_incentives())]
+		pub fn fund_incentives(
+			origin: OriginFor<T>,
+			amount: BalanceOf<T>,
+			per_era: BalanceOf<T>,
+		) -> DispatchResult {
+			T::IncentiveOrigin::ensure_origin(origin)?;
+
+			if !amount.is_zero() {
+				T::Currency::transfer(
+					&T::IncentiveSource::get(),
+					&Self::incentive_pot(),
+					amount,
+					ExistenceRequirement::KeepAlive,
+				)
+				.map_err(|_| Error::<T>::InsufficientBalance)?;
+				IncentiveFunds::<T>::mutate(|funds| *funds = funds.saturating_add(amount));
+			}
+			IncentivePerEra::<T>::put(per_era);
+
+			Self::deposit_event(Event::IncentivesFunded {
+				amount: amount.saturated_into(),
+				per_era: per_era.saturated_into(),
+			});
+
+			Ok(())
+		}
+
+		/// Pay a model's incentives to its owner
+		///
+		/// Callable by anyone. Credits the model's share of every finished era it was
+		/// used in and pays everything credited. If the incentive pot runs short, it
+		/// pays what it has and the rest stays claimable.
+		///
+		/// # Arguments
+		/// * `origin` - Any signed account
+		/// * `model_id` - ID of the model
+		///
+		/// # Errors
+		/// * `ModelNotFound` - Model doesn't exist
+		/// * `NoIncentiveDue` - Nothing is credited to the model
+		/// * `IncentivePotEmpty` - The incentive pot cannot pay anything
+		///
+		/// # Events
+		/// * `IncentiveClaimed` - Incentives paid to the owner
+		#[pallet::call_index(34)]
+		#[pallet::weight(T::WeightInfo::claim_incentive())]
+		pub fn claim_incentive(origin: OriginFor<T>, model_id: ModelId) -> DispatchResult {
+			ensure_signed(origin)?;
+
+			let model = Models::<T>::get(model_id).ok_or(Error::<T>::ModelNotFound)?;
+			Self::credit_incentive(model_id);
+			let earned = IncentiveEarnings::<T>::get(model_id);
+			ensure!(!earned.is_zero(), Error::<T>::NoIncentiveDue);
+
+			let pot = Self::incentive_pot();
+			let available =
+				T::Currency::free_balance(&pot).saturating_sub(T::Currency::minimum_balance());
+			let amount = earned.min(available);
+			ensure!(!amount.is_zero(), Error::<T>::IncentivePotEmpty);
+
+			T::Currency::transfer(&pot, &model.owner, amount, ExistenceRequirement::KeepAlive)?;
+			IncentiveEarnings::<T>::mutate_exists(model_id, |earnings| {
+				*earnings = earned.checked_sub(&amount).filter(|left| !left.is_zero());
+			});
+
+			Self::deposit_event(Event::IncentiveClaimed {
+				model_id,
+				owner: model.owner,
+				amount: amount.saturated_into(),
+			});
+
+			Ok(())
+		}
+
+		/// Mint a model's era rewards to its owner
+		///
+		/// Callable by anyone. At the start of every `RentEraLength`, `RewardPerEra`
+		/// is split among the `MaxRewardedModels` active models with the best
+		/// reputation, in proportion to it; the rewards accrue until claimed.
+		///
+		/// # Arguments
+		/// * `origin` - Any signed account
+		/// * `model_id` - ID of the model
+		///
+		/// # Errors
+		/// * `ModelNotFound` - Model doesn't exist
+		/// * `NoRewardDue` - Model has no rewards to claim
+		///
+		/// # Events
+		/// * `RewardClaimed` - Rewards minted to the owner
+		#[pallet::call_index(35)]
+		#[pallet::weight(T::WeightInfo::claim_reward())]
+		pub fn claim_reward(origin: OriginFor<T>, model_id: ModelId) -> DispatchResult {
+			ensure_signed(origin)?;
+
+			let model = Models::<T>::get(model_id).ok_or(Error::<T>::ModelNotFound)?;
+			let amount = EraRewards::<T>::take(model_id);
+			ensure!(!amount.is_zero(), Error::<T>::NoRewardDue);
+
+			// Dropping the imbalance adds the minted rewards to the total issuance
+			drop(T::Currency::deposit_creating(&model.owner, amount));
+
+			Self::deposit_event(Event::RewardClaimed {
+				model_id,
+				owner: model.owner,
+				amount: amount.saturated_into(),
+			});
+
+			Ok(())
+		}
+
+		/// Correct a model's name and, optionally, its type
+		///
+		/// # Arguments
+		/// * `origin` - Model owner, per `RegistrationOrigin`
+		/// * `model_id` - ID of the model
+		/// * `new_name` - Name replacing the current one
+		/// * `new_model_type` - Type replacing the current one, if any
+		///
+		/// # Errors
+		/// * `NameTooLong` - Name exceeds maximum length
+		/// * `ModelNotFound` - Model doesn't exist
+		/// * `UnauthorizedAccess` - Caller is not the owner
+		/// * `OwnershipNotSynced` - Caller no longer holds the model's NFT
+		/// * `FractionallyOwned` - Model is split into shares
+		/// * `MetadataLocked` - Model's metadata is locked
+		/// * `DuplicateModelName` - Caller has another model with the new name
+		/// * `ModelUnderReview` - Model awaits a curator decision on its reports
+		///
+		/// # Events
+		/// * `ModelRenamed` - Name and type updated
+		#[pallet::call_index(36)]
+		#[pallet::weight(T::WeightInfo::rename_model())]
+		pub fn rename_model(
+			origin: OriginFor<T>,
+			model_id: ModelId,
+			new_name: Vec<u8>,
+			new_model_type: Option<ModelType>,
+		) -> DispatchResult {
+			let who = T::RegistrationOrigin::ensure_origin(origin)?;
+
+			let new_name: BoundedVec<u8, T::MaxNameLength> =
+				new_name.try_into().map_err(|_| Error::<T>::NameTooLong)?;
+
+			let (old_name, model_type) =
+				Models::<T>::try_mutate(model_id, |maybe_model| -> Result<_, DispatchError> {
+					let model = maybe_model.as_mut().ok_or(Error::<T>::ModelNotFound)?;
+					Self::ensure_model_owner(model_id, model, &who)?;
+					ensure!(
+						!Shareholders::<T>::contains_key(model_id),
+						Error::<T>::FractionallyOwned
+					);
+					ensure!(model.status != ModelStatus::UnderReview, Error::<T>::ModelUnderReview);
+					ensure!(
+						!LockedMetadata::<T>::contains_key(model_id),
+						Error::<T>::MetadataLocked
+					);
+					ensure!(
+						Self::model_named(&who, &new_name).is_none_or(|named| named == model_id),
+						Error::<T>::DuplicateModelName
+					);
+
+					if let Some(model_type) = new_model_type {
+						let old = core::mem::replace(&mut model.model_type, model_type);
+						if old != model_type {
+							Self::record_update(model_id, MetadataField::ModelType, &old);
+						}
+					}
+					let old_name = core::mem::replace(&mut model.name, new_name.clone());
+					if old_name != new_name {
+						Self::record_update(model_id, MetadataField::Name, &old_name);
+						NameIndex::<T>::remove(Self::name_prefix(&old_name), model_id);
+						NameIndex::<T>::insert(Self::name_prefix(&new_name), model_id, ());
+						Self::unindex_name(&who, &old_name, model_id);
+						Self::index_name(&who, &new_name, model_id);
+					}
+					Ok((old_name, model.model_type))
+				})?;
+
+			Self::deposit_indexed_event(
+				model_id,
+				Event::ModelRenamed { model_id, old_name, new_name, model_type },
+			);
+
+			Ok(())
+		}
+
+		/// Freeze a model's CID, name, description and license for good
+		///
+		/// Consumers can rely on a locked model never changing under them. Price and
+		/// status stay editable. There is no way to unlock.
+		///
+		/// # Arguments
+		/// * `origin` - Model owner, per `RegistrationOrigin`
+		/// * `model_id` - ID of the model
+		///
+		/// # Errors
+		/// * `ModelNotFound` - Model doesn't exist
+		/// * `UnauthorizedAccess` - Caller is not the owner
+		/// * `OwnershipNotSynced` - Caller no longer holds the model's NFT
+		/// * `FractionallyOwned` - Model is split into shares
+		/// * `MetadataLocked` - Model's metadata is locked already
+		///
+		/// # Events
+		/// * `MetadataLocked` - Metadata frozen
+		#[pallet::call_index(37)]
+		#[pallet::weight(T::WeightInfo::lock_metadata())]
+		pub fn lock_metadata(origin: OriginFor<T>, model_id: ModelId) -> DispatchResult {
+			let who = T::RegistrationOrigin::ensure_origin(origin)?;
+
+			let model = Models::<T>::get(model_id).ok_or(Error::<T>::ModelNotFound)?;
+			Self::ensure_model_owner(model_id, &model, &who)?;
+			ensure!(!Shareholders::<T>::contains_key(model_id), Error::<T>::FractionallyOwned);
+			ensure!(!LockedMetadata::<T>::contains_key(model_id), Error::<T>::MetadataLocked);
+
+			LockedMetadata::<T>::insert(model_id, frame_system::Pallet::<T>::block_number());
+
+			Self::deposit_event(Event::MetadataLocked { model_id, owner: who });
+
+			Ok(())
+		}
+
+		/// Report a model for malicious or infringing content
+		///
+		/// Once `ReportThreshold` distinct accounts reported it, the model is put
+		/// `UnderReview`: it cannot be used, its metadata is frozen and its scheduled
+		/// deactivation is cancelled until `SlashOrigin` slashes it or dismisses the
+		/// reports. Reporters must meet the same requirements as raters.
+		///
+		/// # Arguments
+		/// * `origin` - Any account eligible to rate
+		/// * `model_id` - ID of the model to report
+		///
+		/// # Errors
+		/// * `RaterNotVerified`, `RaterTooNew`, `RaterBalanceTooLow` - Caller may not report
+		/// * `ModelNotFound` - Model doesn't exist
+		/// * `ModelNotActive` - Model is deactivated
+		/// * `ModelUnderReview` - Model is suspended already
+		/// * `AlreadyReported` - Caller reported the model already
+		///
+		/// # Events
+		/// * `ModelReported` - Report recorded
+		/// * `ModelSuspended` - Reports reached the threshold
+		#[pallet::call_index(38)]
+		#[pallet::weight(T::WeightInfo::report_model())]
+		pub fn report_model(origin: OriginFor<T>, model_id: ModelId) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Self::ensure_eligible_rater(&who)?;
+			ensure!(!UnderReview::<T>::contains_key(model_id), Error::<T>::ModelUnderReview);
+			ensure!(!ModelReports::<T>::contains_key(model_id, &who), Error::<T>::AlreadyReported);
+
+			let reports = ReportCounts::<T>::get(model_id).saturating_add(1);
+			let suspended = Models::<T>::try_mutate(model_id, |maybe_model| {
+				let model = maybe_model.as_mut().ok_or(Error::<T>::ModelNotFound)?;
+				ensure!(model.status != ModelStatus::Deactivated, Error::<T>::ModelNotActive);
+
+				let suspended = reports >= T::ReportThreshold::get();
+				if suspended {
+					UnderReview::<T>::insert(model_id, model.status);
+					Self::set_status(model_id, model, ModelStatus::UnderReview);
+				}
+				Ok::<_, DispatchError>(suspended)
+			})?;
+			// A scheduled deactivation would let the owner walk away with the deposit
+			if suspended {
+				Self::cancel_deactivation(model_id);
+			}
+
+			ModelReports::<T>::insert(model_id, &who, frame_system::Pallet::<T>::block_number());
+			ReportCounts::<T>::insert(model_id, reports);
+
+			Self::deposit_indexed_event(
+				model_id,
+				Event::ModelReported { model_id, reporter: who, reports },
+			);
+			if suspended {
+				Self::deposit_indexed_event(model_id, Event::ModelSuspended { model_id, reports });
+			}
+
+			Ok(())
+		}
+
+		/// Dismiss the reports of a suspended model and restore its previous status
+		///
+		/// Upheld reports are resolved by slashing the model instead.
+		///
+		/// # Arguments
+		/// * `origin` - Must be `SlashOrigin`
+		/// * `model_id` - ID of the suspended model
+		///
+		/// # Errors
+		/// * `NotUnderReview` - Model is not suspended by reports
+		/// * `ModelNotFound` - Model doesn't exist
+		///
+		/// # Events
+		/// * `ReportsDismissed` - Reports cleared and status restored
+		#[pallet::call_index(39)]
+		#[pallet::weight(T::WeightInfo::dismiss_reports(T::ReportThreshold::get()))]
+		pub fn dismiss_reports(origin: OriginFor<T>, model_id: ModelId) -> DispatchResult {
+			T::SlashOrigin::ensure_origin(origin)?;
+
+			let previous = UnderReview::<T>::take(model_id).ok_or(Error::<T>::NotUnderReview)?;
+			let status = Models::<T>::try_mutate(model_id, |maybe_model| {
+				let model = maybe_model.as_mut().ok_or(Error::<T>::ModelNotFound)?;
+				// Models out of deposit stay paused until topped up
+				let status = if previous == ModelStatus::Active
+					&& RentExhausted::<T>::contains_key(model_id)
+				{
+					ModelStatus::Paused
+				} else {
+					previous
+				};
+				Self::set_status(model_id, model, status);
+				Ok::<_, DispatchError>(status)
+			})?;
+
+			Self::clear_reports(model_id);
+
+			Self::deposit_indexed_event(model_id, Event::ReportsDismissed { model_id, status });
+
+			Ok(())
+		}
+
+		/// Make a paused, deprecated or deactivated model active again
+		///
+		/// A deactivated model's deposit is reserved from the owner again. Calling this
+		/// for an active model cancels its scheduled deactivation.
+		///
+		/// # Arguments
+		/// * `origin` - Must be the model owner, per `RegistrationOrigin`
+		/// * `model_id` - ID of the model to reactivate
+		///
+		/// # Errors
+		/// * `ModelNotFound` - Model doesn't exist
+		/// * `UnauthorizedAccess` - Caller is not the owner
+		/// * `OwnershipNotSynced` - Caller no longer holds the model's NFT
+		/// * `FractionallyOwned` - Model is split into shares; use `propose_metadata_update`
+		/// * `ModelUnderReview` - Model is suspended until its reports are resolved
+		/// * `AwaitingApproval` - Model's registration has not been approved yet
+		/// * `ModelAlreadyActive` - Model is active and not scheduled for deactivation
+		/// * `RentExhausted` - Model's deposit ran out; top it up instead
+		/// * `DeactivatedByForce` - Model was slashed; use `appeal_deactivation`
+		/// * `InsufficientStake` - Caller cannot reserve the deposit again
+		///
+		/// # Events
+		/// * `ModelReactivated` - Model is active, or pending approval again
+		#[pallet::call_index(42)]
+		#[pallet::weight(T::WeightInfo::reactivate_model())]
+		pub fn reactivate_model(origin: OriginFor<T>, model_id: ModelId) -> DispatchResult {
+			let who = T::RegistrationOrigin::ensure_origin(origin)?;
+
+			Models::<T>::try_mutate(model_id, |maybe_model| -> DispatchResult {
+				let model = maybe_model.as_mut().ok_or(Error::<T>::ModelNotFound)?;
+				Self::ensure_model_owner(model_id, model, &who)?;
+				ensure!(!Shareholders::<T>::contains_key(model_id), Error::<T>::FractionallyOwned);
+
+				let mut status = ModelStatus::Active;
+				match model.status {
+					ModelStatus::UnderReview => Err(Error::<T>::ModelUnderReview)?,
+					ModelStatus::Pending => Err(Error::<T>::AwaitingApproval)?,
+					ModelStatus::Active => ensure!(
+						PendingDeactivations::<T>::contains_key(model_id),
+						Error::<T>::ModelAlreadyActive
+					),
+					ModelStatus::Paused | ModelStatus::Deprecated => ensure!(
+						!RentExhausted::<T>::contains_key(model_id),
+						Error::<T>::RentExhausted
+					),
+					ModelStatus::Deactivated => {
+						ensure!(
+							!ForcedDeactivations::<T>::contains_key(model_id),
+							Error::<T>::DeactivatedByForce
+						);
+						let deposit = Self::model_deposit(model.size_bytes);
+						T::Currency::reserve(&who, deposit)
+							.map_err(|_| Error::<T>::InsufficientStake)?;
+						model.deposit = deposit.saturated_into();
+						// The fresh deposit settles any rent the model still owed, and its
+						// rent-free eras start over
+						RentExhausted::<T>::remove(model_id);
+						LastActivity::<T>::insert(
+							model_id,
+							frame_system::Pallet::<T>::block_number(),
+						);
+						// Rejected registrations must not come back without approval
+						if T::RequireRegistrationApproval::get() {
+							status = ModelStatus::Pending;
+						}
+					}
+				}
+
+				Successors::<T>::remove(model_id);
+				Self::set_status(model_id, model, status);
+				Ok(())
+			})?;
+			Self::cancel_deactivation(model_id);
+
+			Self::deposit_indexed_event(model_id, Event::ModelReactivated { model_id, owner: who });
+
+			Ok(())
+		}
+
+		/// Appeal the forced deactivation of a model
+		///
+		/// Models slashed by `SlashOrigin` or dispute resolution can be appealed once,
+		/// with evidence linked by its IPFS CID. `AppealBond` is reserved until
+		/// governance resolves the appeal.
+		///
+		/// # Arguments
+		/// * `origin` - Must be the model owner
+		/// * `model_id` - ID of the deactivated model
+		/// * `evidence_cid` - IPFS CID of the evidence
+		///
+		/// # Errors
+		/// * `ModelNotFound` - Model doesn't exist
+		/// * `UnauthorizedAccess` - Caller is not the owner
+		/// * `OwnershipNotSynced` - Caller no longer holds the model's NFT
+		/// * `NotAppealable` - Model was not deactivated by force
+		/// * `AppealAlreadyOpen` - Model is being appealed already
+		/// * `CidTooLong` - Evidence CID exceeds max length
+		/// * `InvalidIPFSCID` - Evidence CID format is invalid
+		/// * `InsufficientBalance` - Caller cannot reserve `AppealBond`
+		///
+		/// # Events
+		/// * `AppealOpened` - Appeal awaits governance
+		#[pallet::call_index(40)]
+		#[pallet::weight(T::WeightInfo::appeal_deactivation())]
+		pub fn appeal_deactivation(
+			origin: OriginFor<T>,
+			model_id: ModelId,
+			evidence_cid: Vec<u8>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let model = Models::<T>::get(model_id).ok_or(Error::<T>::ModelNotFound)?;
+			Self::ensure_model_owner(model_id, &model, &who)?;
+			ensure!(ForcedDeactivations::<T>::contains_key(model_id), Error::<T>::NotAppealable);
+			ensure!(!Appeals::<T>::contains_key(model_id), Error::<T>::AppealAlreadyOpen);
+
+			let evidence_cid: BoundedVec<u8, T::MaxCidLength> =
+				evidence_cid.try_into().map_err(|_| Error::<T>::CidTooLong)?;
+			ensure!(Self::validate_ipfs_cid(&evidence_cid), Error::<T>::InvalidIPFSCID);
+
+			let bond = T::AppealBond::get();
+			T::Currency::reserve(&who, bond).map_err(|_| Error::<T>::InsufficientBalance)?;
+
+			let bond: u128 = bond.saturated_into();
+			Appeals::<T>::insert(
+				model_id,
+				DeactivationAppeal {
+					appellant: who.clone(),
+					evidence_cid: evidence_cid.clone(),
+					bond,
+					opened_at: frame_system::Pallet::<T>::block_number().saturated_into(),
+				},
+			);
+
+			Self::deposit_indexed_event(
+				model_id,
+				Event::AppealOpened { model_id, owner: who, evidence_cid, bond },
+			);
+
+			Ok(())
+		}
+
+		/// Resolve an appeal against a forced deactivation
+		///
+		/// An upheld appeal refunds the bond and restores the model's previous status,
+		/// reserving its deposit from the owner again; the slashed part of the old
+		/// deposit is not returned. A rejected appeal's bond is slashed to `Slash`.
+		/// Either way, the deactivation cannot be appealed again.
+		///
+		/// # Arguments
+		/// * `origin` - Must be `GovernanceOrigin`
+		/// * `model_id` - ID of the appealed model
+		/// * `upheld` - Whether the appeal succeeds
+		///
+		/// # Errors
+		/// * `AppealNotFound` - Model has no open appeal
+		/// * `ModelNotFound` - Model doesn't exist
+		/// * `InsufficientStake` - Owner cannot reserve the model's deposit again
+		///
+		/// # Events
+		/// * `AppealUpheld` - Model restored and bond refunded
+		/// * `AppealRejected` - Bond slashed
+		#[pallet::call_index(41)]
+		#[pallet::weight(T::WeightInfo::resolve_appeal())]
+		pub fn resolve_appeal(
+			origin: OriginFor<T>,
+			model_id: ModelId,
+			upheld: bool,
+		) -> DispatchResult {
+			T::GovernanceOrigin::ensure_origin(origin)?;
+
+			let appeal = Appeals::<T>::take(model_id).ok_or(Error::<T>::AppealNotFound)?;
+			let previous =
+				ForcedDeactivations::<T>::take(model_id).ok_or(Error::<T>::NotAppealable)?;
+			let bond: BalanceOf<T> = appeal.bond.saturated_into();
+
+			if !upheld {
+				let (imbalance, _) = T::Currency::slash_reserved(&appeal.appellant, bond);
+				T::Slash::on_unbalanced(imbalance);
+				Self::deposit_indexed_event(
+					model_id,
+					Event::AppealRejected { model_id, bond: appeal.bond },
+				);
+				return Ok(());
+			}
+
+			T::Currency::unreserve(&appeal.appellant, bond);
+			let status = Models::<T>::try_mutate(model_id, |maybe_model| {
+				let model = maybe_model.as_mut().ok_or(Error::<T>::ModelNotFound)?;
+				let deposit = Self::model_deposit(model.size_bytes);
+				T::Currency::reserve(&model.owner, deposit)
+					.map_err(|_| Error::<T>::InsufficientStake)?;
+				model.deposit = deposit.saturated_into();
+				Self::set_status(model_id, model, previous);
+				Ok::<_, DispatchError>(model.status)
+			})?;
+			// Rent-free eras start over from the restoration
+			LastActivity::<T>::insert(model_id, frame_system::Pallet::<T>::block_number());
+
+			Self::deposit_indexed_event(model_id, Event::AppealUpheld { model_id, status });
+
+			Ok(())
+		}
+
+		/// Approve a pending registration, making the model active
+		///
+		/// # Arguments
+		/// * `origin` - Must be `RegistrationApprovalOrigin`
+		/// * `model_id` - ID of the pending model
+		///
+		/// # Errors
+		/// * `ModelNotFound` - Model doesn't exist
+		/// * `NotPendingApproval` - Model's registration is not awaiting approval
+		///
+		/// # Events
+		/// * `RegistrationApproved` - Model is active
+		#[pallet::call_index(43)]
+		#[pallet::weight(T::WeightInfo::approve_registration())]
+		pub fn approve_registration(origin: OriginFor<T>, model_id: ModelId) -> DispatchResult {
+			T::RegistrationApprovalOrigin::ensure_origin(origin)?;
+
+			Models::<T>::try_mutate(model_id, |maybe_model| -> DispatchResult {
+				let model = maybe_model.as_mut().ok_or(Error::<T>::ModelNotFound)?;
+				ensure!(model.status == ModelStatus::Pending, Error::<T>::NotPendingApproval);
+				Self::set_status(model_id, model, ModelStatus::Active);
+				Ok(())
+			})?;
+
+			Self::deposit_indexed_event(model_id, Event::RegistrationApproved { model_id });
+
+			Ok(())
+		}
+
+		/// Reject a pending registration
+		///
+		/// The model is deactivated and its deposit released; the registration fee is
+		/// kept. While approval is required, reactivating it makes it pending again.
+		///
+		/// # Arguments
+		/// * `origin` - Must be `RegistrationApprovalOrigin`
+		/// * `model_id` - ID of the pending model
+		///
+		/// # Errors
+		/// * `ModelNotFound` - Model doesn't exist
+		/// * `NotPendingApproval` - Model's registration is not awaiting approval
+		///
+		/// # Events
+		/// * `RegistrationRejected` - Model is deactivated
+		#[pallet::call_index(44)]
+		#[pallet::weight(T::WeightInfo::reject_registration())]
+		pub fn reject_registration(origin: OriginFor<T>, model_id: ModelId) -> DispatchResult {
+			T::RegistrationApprovalOrigin::ensure_origin(origin)?;
+
+			Models::<T>::try_mutate(model_id, |maybe_model| -> DispatchResult {
+				let model = maybe_model.as_mut().ok_or(Error::<T>::ModelNotFound)?;
+				ensure!(model.status == ModelStatus::Pending, Error::<T>::NotPendingApproval);
+				Self::do_deactivate(model_id, model);
+				Ok(())
+			})?;
+			Self::cancel_deactivation(model_id);
+
+			Self::deposit_indexed_event(model_id, Event::RegistrationRejected { model_id });
+
+			Ok(())
+		}
+
+		/// Require, or stop requiring, model registrants to be on the developer allowlist
+		///
+		/// Registering, forking and importing models are all restricted while required.
+		/// Existing models are unaffected.
+		///
+		/// # Arguments
+		/// * `origin` - Must be `GovernanceOrigin`
+		/// * `required` - Whether registrants must be allowlisted
+		///
+		/// # Events
+		/// * `AllowlistRequirementSet` - Requirement updated
+		#[pallet::call_index(45)]
+		#[pallet::weight(T::WeightInfo::set_allowlist_required())]
+		pub fn set_allowlist_required(origin: OriginFor<T>, required: bool) -> DispatchResult {
+			T::GovernanceOrigin::ensure_origin(origin)?;
+
+			AllowlistRequired::<T>::put(required);
+			Self::deposit_event(Event::AllowlistRequirementSet { required });
+
+			Ok(())
+		}
+
+		/// Set or replace the caller's developer profile
+		///
+		/// The profile is returned with the caller's models by the `developer_page`
+		/// runtime API.
+		///
+		/// # Arguments
+		/// * `origin` - The developer
+		/// * `display_name` - Name shown for the developer
+		/// * `website_cid` - IPFS CID of the developer's website, if any
+		/// * `contact_cid` - IPFS CID of the developer's contact details, if any
+		///
+		/// # Errors
+		/// * `NameTooLong` - Name exceeds maximum length
+		/// * `CidTooLong` - A CID exceeds maximum length
+		/// * `InvalidIPFSCID` - A CID format is invalid
+		///
+		/// # Events
+		/// * `DeveloperProfileSet` - Profile stored
+		#[pallet::call_index(46)]
+		#[pallet::weight(T::WeightInfo::set_developer_profile())]
+		pub fn set_developer_profile(
+			origin: OriginFor<T>,
+			display_name: Vec<u8>,
+			website_cid: Option<Vec<u8>>,
+			contact_cid: Option<Vec<u8>>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let display_name: BoundedVec<u8, T::MaxNameLength> =
+				display_name.try_into().map_err(|_| Error::<T>::NameTooLong)?;
+			let to_cid = |cid: Vec<u8>| -> Result<BoundedVec<u8, T::MaxCidLength>, DispatchError> {
+				let cid = cid.try_into().map_err(|_| Error::<T>::CidTooLong)?;
+				ensure!(Self::validate_ipfs_cid(&cid), Error::<T>::InvalidIPFSCID);
+				Ok(cid)
+			};
+			let website_cid = website_cid.map(to_cid).transpose()?;
+			let contact_cid = contact_cid.map(to_cid).transpose()?;
+
+			DeveloperProfiles::<T>::insert(
+				&who,
+				DeveloperProfile { display_name, website_cid, contact_cid },
+			);
+			Self::deposit_event(Event::DeveloperProfileSet { developer: who });
+
+			Ok(())
+		}
+
+		/// Remove the caller's developer profile
+		///
+		/// # Arguments
+		/// * `origin` - The developer
+		///
+		/// # Errors
+		/// * `ProfileNotFound` - Caller has no profile
+		///
+		/// # Events
+		/// * `DeveloperProfileCleared` - Profile removed
+		#[pallet::call_index(47)]
+		#[pallet::weight(T::WeightInfo::clear_developer_profile())]
+		pub fn clear_developer_profile(origin: OriginFor<T>) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			DeveloperProfiles::<T>::take(&who).ok_or(Error::<T>::ProfileNotFound)?;
+			Self::deposit_event(Event::DeveloperProfileCleared { developer: who });
+
+			Ok(())
+		}
+
+		/// Create an organization, with the caller as its first admin
+		///
+		/// The organization gets a keyless account that owns its models and funds;
+		/// members act through it with `as_organization`.
+		///
+		/// # Arguments
+		/// * `origin` - The first admin
+		/// * `name` - Human-readable organization name
+		///
+		/// # Errors
+		/// * `NameTooLong` - Name exceeds maximum length
+		/// * `ArithmeticOverflow` - Organization IDs are exhausted
+		///
+		/// # Events
+		/// * `OrganizationCreated` - Organization created
+		#[pallet::call_index(48)]
+		#[pallet::weight(T::WeightInfo::create_organization())]
+		pub fn create_organization(origin: OriginFor<T>, name: Vec<u8>) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let name: BoundedVec<u8, T::MaxNameLength> =
+				name.try_into().map_err(|_| Error::<T>::NameTooLong)?;
+			let org_id = NextOrganizationId::<T>::get();
+			let next_id = org_id.checked_add(1).ok_or(Error::<T>::ArithmeticOverflow)?;
+
+			let account = Self::organization_account(org_id);
+			Organizations::<T>::insert(
+				org_id,
+				Organization { account: account.clone(), name, members: 1, admins: 1 },
+			);
+			OrganizationMembers::<T>::insert(org_id, &who, OrganizationRole::Admin);
+			NextOrganizationId::<T>::put(next_id);
+
+			Self::deposit_event(Event::OrganizationCreated { org_id, account, admin: who });
+
+			Ok(())
+		}
+
+		/// Add a member to an organization, change their role or remove them
+		///
+		/// Any member may remove themselves; everything else needs an admin. The last
+		/// admin cannot be removed or demoted.
+		///
+		/// # Arguments
+		/// * `origin` - An admin of the organization, or `member` leaving it
+		/// * `org_id` - ID of the organization
+		/// * `member` - Account whose role changes
+		/// * `role` - New role, or `None` to remove the member
+		///
+		/// # Errors
+		/// * `OrganizationNotFound` - Organization doesn't exist
+		/// * `NotOrganizationMember` - Caller is not a member
+		/// * `InsufficientOrganizationRole` - Caller is not an admin
+		/// * `TooManyOrganizationMembers` - Organization is full
+		/// * `LastOrganizationAdmin` - Change would leave the organization without admins
+		///
+		/// # Events
+		/// * `OrganizationRoleSet` - Role updated
+		#[pallet::call_index(49)]
+		#[pallet::weight(T::WeightInfo::set_organization_role())]
+		pub fn set_organization_role(
+			origin: OriginFor<T>,
+			org_id: OrganizationId,
+			member: T::AccountId,
+			role: Option<OrganizationRole>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			Organizations::<T>::try_mutate(org_id, |maybe_org| -> DispatchResult {
+				let org = maybe_org.as_mut().ok_or(Error::<T>::OrganizationNotFound)?;
+				let caller_role = OrganizationMembers::<T>::get(org_id, &who)
+					.ok_or(Error::<T>::NotOrganizationMember)?;
+				let leaving = who == member && role.is_none();
+				ensure!(
+					leaving || caller_role == OrganizationRole::Admin,
+					Error::<T>::InsufficientOrganizationRole
+				);
+
+				let old = OrganizationMembers::<T>::get(org_id, &member);
+				match (old, role) {
+					(None, Some(_)) => {
+						ensure!(
+							org.members < T::MaxOrganizationMembers::get(),
+							Error::<T>::TooManyOrganizationMembers
+						);
+						org.members.saturating_inc();
+					}
+					(Some(_), None) => org.members.saturating_dec(),
+					_ => {}
+				}
+				let was_admin = old == Some(OrganizationRole::Admin);
+				let is_admin = role == Some(OrganizationRole::Admin);
+				if was_admin && !is_admin {
+					ensure!(org.admins > 1, Error::<T>::LastOrganizationAdmin);
+					org.admins.saturating_dec();
+				} else if is_admin && !was_admin {
+					org.admins.saturating_inc();
+				}
+
+				OrganizationMembers::<T>::set(org_id, &member, role);
+				Ok(())
+			})?;
+
+			Self::deposit_event(Event::OrganizationRoleSet { org_id, member, role });
+
+			Ok(())
+		}
+
+		/// Dispatch `call` from an organization's account
+		///
+		/// Publishers may register models and update their metadata, datasets and
+		/// collections; billing members may pay rent, top up deposits and claim
+		/// rebates, incentives and rewards; admins may dispatch any call.
+		///
+		/// # Arguments
+		/// * `origin` - A member of the organization
+		/// * `org_id` - ID of the organization
+		/// * `call` - Call to dispatch as the organization
+		///
+		/// # Errors
+		/// * `OrganizationNotFound` - Organization doesn't exist
+		/// * `NotOrganizationMember` - Caller is not a member
+		/// * `InsufficientOrganizationRole` - Caller's role doesn't allow `call`
+		/// * Any error of `call`
+		#[pallet::call_index(50)]
+		#[pallet::weight({
+			let info = call.get_dispatch_info();
+			(T::WeightInfo::as_organization().saturating_add(info.call_weight), info.class)
+		})]
+		pub fn as_organization(
+			origin: OriginFor<T>,
+			org_id: OrganizationId,
+			call: Box<<T as Config>::RuntimeCall>,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+
+			let org = Organizations::<T>::get(org_id).ok_or(Error::<T>::OrganizationNotFound)?;
+			let role = OrganizationMembers::<T>::get(org_id, &who)
+				.ok_or(Error::<T>::NotOrganizationMember)?;
+			ensure!(
+				role.allows(Self::required_role(&call)),
+				Error::<T>::InsufficientOrganizationRole
+			);
+
+			let info = call.get_dispatch_info();
+			let result = call.dispatch(frame_system::RawOrigin::Signed(org.account).into());
+			let weight = T::WeightInfo::as_organization()
+				.saturating_add(extract_actual_weight(&result, &info));
+			result
+				.map_err(|mut err| {
+					err.post_info = Some(weight).into();
+					err
+				})
+				.map(|_| Some(weight).into())
+		}
+
+		/// Announce a change of a model's price at a later block
+		///
+		/// The new price is public in `PendingPriceChanges` until it takes effect, so
+		/// clients can anticipate it. Scheduling again replaces the announced change.
+		/// The change is dropped if the model is under review or fractionally owned
+		/// when it is due.
+		///
+		/// # Arguments
+		/// * `origin` - Must be the model owner, per `RegistrationOrigin`
+		/// * `model_id` - ID of the model to reprice
+		/// * `new_price` - Price per inference from `at_block` on
+		/// * `at_block` - Block the new price takes effect at
+		///
+		/// # Errors
+		/// * `PriceChangeNotInFuture` - `at_block` is not after the current block
+		/// * `ModelNotFound` - Model doesn't exist
+		/// * `UnauthorizedAccess` - Caller is not the owner
+		/// * `OwnershipNotSynced` - Caller no longer holds the model's NFT
+		/// * `FractionallyOwned` - Model is split into shares; use `propose_metadata_update`
+		/// * `ModelUnderReview` - Model awaits a curator decision on its reports
+		/// * `TooManyScheduledPriceChanges` - That block is fully booked
+		///
+		/// # Events
+		/// * `PriceChangeScheduled` - Price change announced
+		#[pallet::call_index(51)]
+		#[pallet::weight(T::WeightInfo::schedule_price_change())]
+		pub fn schedule_price_change(
+			origin: OriginFor<T>,
+			model_id: ModelId,
+			new_price: BalanceOf<T>,
+			at_block: BlockNumberFor<T>,
+		) -> DispatchResult {
+			let who = T::RegistrationOrigin::ensure_origin(origin)?;
+			ensure!(
+				at_block > frame_system::Pallet::<T>::block_number(),
+				Error::<T>::PriceChangeNotInFuture
+			);
+
+			let model = Models::<T>::get(model_id).ok_or(Error::<T>::ModelNotFound)?;
+			Self::ensure_model_owner(model_id, &model, &who)?;
+			ensure!(!Shareholders::<T>::contains_key(model_id), Error::<T>::FractionallyOwned);
+			ensure!(model.status != ModelStatus::UnderReview, Error::<T>::ModelUnderReview);
+
+			Self::drop_price_change(model_id);
+			ScheduledPriceChanges::<T>::try_append(at_block, model_id)
+				.map_err(|_| Error::<T>::TooManyScheduledPriceChanges)?;
+			let price: u128 = new_price.saturated_into();
+			PendingPriceChanges::<T>::insert(model_id, (at_block, price));
+
+			Self::deposit_indexed_event(
+				model_id,
+				Event::PriceChangeScheduled { model_id, price, at: at_block },
+			);
+			Ok(())
+		}
+
+		/// Withdraw a model's scheduled price change
+		///
+		/// # Arguments
+		/// * `origin` - Must be the model owner, per `RegistrationOrigin`
+		/// * `model_id` - ID of the model
+		///
+		/// # Errors
+		/// * `ModelNotFound` - Model doesn't exist
+		/// * `UnauthorizedAccess` - Caller is not the owner
+		/// * `OwnershipNotSynced` - Caller no longer holds the model's NFT
+		/// * `NoPriceChangeScheduled` - Model has no scheduled price change
+		///
+		/// # Events
+		/// * `PriceChangeCancelled` - Price change withdrawn
+		#[pallet::call_index(52)]
+		#[pallet::weight(T::WeightInfo::cancel_price_change())]
+		pub fn cancel_price_change(origin: OriginFor<T>, model_id: ModelId) -> DispatchResult {
+			let who = T::RegistrationOrigin::ensure_origin(origin)?;
+
+			let model = Models::<T>::get(model_id).ok_or(Error::<T>::ModelNotFound)?;
+			Self::ensure_model_owner(model_id, &model, &who)?;
+			ensure!(Self::drop_price_change(model_id), Error::<T>::NoPriceChangeScheduled);
+
+			Self::deposit_indexed_event(model_id, Event::PriceChangeCancelled { model_id });
+			Ok(())
+		}
+
+		/// Schedule a model's end of life at a given block
+		///
+		/// The model stays usable until `at`, when it is deactivated at the start of
+		/// the block. `DeactivationNotice` blocks before, `DeactivationUpcoming` reminds
+		/// its users. A successor, if named, is recorded in `Successors` right away,
+		/// so clients can move over before the model goes away. Scheduling again replaces the
+		/// scheduled deactivation; `reactivate_model` cancels it.
+		///
+		/// # Arguments
+		/// * `origin` - Must be the model owner
+		/// * `model_id` - ID of the model to deactivate
+		/// * `at` - Block the model is deactivated at, at most `MaxDeactivationGrace`
+		///   blocks away
+		/// * `successor` - Active model replacing it, if any
+		///
+		/// # Errors
+		/// * `DeactivationNotInFuture` - `at` is not after the current block
+		/// * `GracePeriodTooLong` - `at` is more than `MaxDeactivationGrace` blocks away
+		/// * `InvalidSuccessor` - Successor doesn't exist, isn't active or is the model
+		///   itself
+		/// * `ModelNotFound` - Model doesn't exist
+		/// * `UnauthorizedAccess` - Caller is not the owner
+		/// * `OwnershipNotSynced` - Caller no longer holds the model's NFT
+		/// * `ModelNotActive` - Model is deactivated already
+		/// * `ModelUnderReview` - Model awaits a curator decision on its reports
+		/// * `TooManyScheduledDeactivations` - That block is fully booked
+		///
+		/// # Events
+		/// * `DeactivationScheduled` - Model will be deactivated at `at`
+		#[pallet::call_index(53)]
+		#[pallet::weight(T::WeightInfo::deactivate_at())]
+		pub fn deactivate_at(
+			origin: OriginFor<T>,
+			model_id: ModelId,
+			at: BlockNumberFor<T>,
+			successor: Option<ModelId>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let now = frame_system::Pallet::<T>::block_number();
+			ensure!(at > now, Error::<T>::DeactivationNotInFuture);
+			ensure!(
+				at.saturating_sub(now) <= T::MaxDeactivationGrace::get(),
+				Error::<T>::GracePeriodTooLong
+			);
+			if let Some(successor_id) = successor {
+				ensure!(successor_id != model_id, Error::<T>::InvalidSuccessor);
+				let successor =
+					Models::<T>::get(successor_id).ok_or(Error::<T>::InvalidSuccessor)?;
+				ensure!(successor.status == ModelStatus::Active, Error::<T>::InvalidSuccessor);
+			}
+
+			let model = Models::<T>::get(model_id).ok_or(Error::<T>::ModelNotFound)?;
+			Self::ensure_model_owner(model_id, &model, &who)?;
+			ensure!(model.status != ModelStatus::Deactivated, Error::<T>::ModelNotActive);
+			// Deactivating would release the deposit curators may still slash
+			ensure!(model.status != ModelStatus::UnderReview, Error::<T>::ModelUnderReview);
+
+			Self::cancel_deactivation(model_id);
+			Self::schedule_deactivation(model_id, who, at)?;
+			if let Some(successor) = successor {
+				Successors::<T>::insert(model_id, successor);
+			}
+			Ok(())
+		}
+	}
+
+	impl<T: Config> Pallet<T> {
+		/// Validate IPFS CID format, see [`is_valid_ipfs_cid`]
+		fn validate_ipfs_cid(cid: &BoundedVec<u8, T::MaxCidLength>) -> bool {
+			is_valid_ipfs_cid(cid)
+		}
+
+		/// Reject throwaway accounts as raters
+		fn ensure_eligible_rater(who: &T::AccountId) -> DispatchResult {
+			ensure!(T::VerifiedRaters::contains(who), Error::<T>::RaterNotVerified);
+			if let Some(created) = AccountCreated::<T>::get(who) {
+				let age = frame_system::Pallet::<T>::block_number().saturating_sub(created);
+				ensure!(age >= T::MinRaterAge::get(), Error::<T>::RaterTooNew);
+			}
+			ensure!(
+				T::Currency::free_balance(who) >= T::MinRaterBalance::get(),
+				Error::<T>::RaterBalanceTooLow
+			);
+			Ok(())
+		}
+
+		/// Count `model_id` against the models `who` may rate this era; rating a model
+		/// again in the same era is free
+		fn use_rating_quota(who: &T::AccountId, model_id: ModelId) -> DispatchResult {
+			let Some(era) = Self::incentive_era() else { return Ok(()) };
+			RatedInEra::<T>::try_mutate(who, |entry| {
+				let (rated_era, models) = entry.get_or_insert_with(|| (era, Default::default()));
+				if *rated_era != era {
+					*rated_era = era;
+					models.clear();
+				}
+				if !models.contains(&model_id) {
+					models.try_push(model_id).map_err(|_| Error::<T>::TooManyRatingsThisEra)?;
+				}
+				Ok(())
+			})
+		}
+
+		/// Add `rating` to the recent ratings of `model_id`, returning their average
+		/// if it fell below `PauseRatingFloor` over at least `PauseMinRatings` ratings
+		fn note_recent_rating(model_id: ModelId, rating: u8) -> Option<u8> {
+			RecentRatings::<T>::mutate(model_id, |recent| {
+				// Only fails if no ratings are kept at all
+				let _ = recent.force_insert_keep_left(0, rating);
+				let count = recent.len() as u32;
+				let total: u32 = recent.iter().copied().map(u32::from).sum();
+				let floor = u32::from(T::PauseRatingFloor::get());
+				(count > 0 && count >= T::PauseMinRatings::get() && total < floor * count)
+					.then(|| (total / count) as u8)
+			})
+		}
+
+		/// Rating left by `rater` on `model_id` and the receipt linked to it
+		///
+		/// Backs the `rating_provenance` runtime API.
+		pub fn rating_provenance(model_id: ModelId, rater: &T::AccountId) -> Option<RatingRecord> {
+			Ratings::<T>::get(model_id, rater)
+		}
+
+		/// IPFS CID of the review `rater` left with their rating of `model_id`, if any
+		pub fn rating_review(
+			model_id: ModelId,
+			rater: &T::AccountId,
+		) -> Option<BoundedVec<u8, T::MaxCidLength>> {
+			RatingReviews::<T>::get(model_id, rater)
+		}
+
+		/// Model replacing the deprecated or retiring `model_id`, if its owner named one
+		///
+		/// Backs the `successor` runtime API.
+		pub fn successor(model_id: ModelId) -> Option<ModelId> {
+			Successors::<T>::get(model_id)
+		}
+
+		/// Inferences of `model_id` over the last `eras` eras, the current one included
+		///
+		/// Only the last `UsageWindow` eras are known. Backs the `recent_inferences`
+		/// runtime API.
+		pub fn recent_inferences(model_id: ModelId, eras: u32) -> u32 {
+			Self::sum_recent(&RecentUsage::<T>::get(model_id), eras)
+		}
+
+		/// Inference fees settled on `model_id` over the last `eras` eras, the current
+		/// one included
+		///
+		/// Only the last `UsageWindow` eras are known. Backs the `recent_revenue`
+		/// runtime API.
+		pub fn recent_revenue(model_id: ModelId, eras: u32) -> u128 {
+			Self::sum_recent(&RecentRevenue::<T>::get(model_id), eras)
+		}
+
+		/// Current-era, lifetime and per-era fees settled on `model_id`, if it exists
+		///
+		/// Backs the `model_revenue` runtime API.
+		pub fn model_revenue(model_id: ModelId) -> Option<RevenueHistory> {
+			if !Models::<T>::contains_key(model_id) {
+				return None;
+			}
+			let window = T::UsageWindow::get() as usize;
+			let mut eras = sp_std::vec![0u128; window];
+			if let Some(era) = Self::incentive_era() {
+				for (recent_era, fees) in RecentRevenue::<T>::get(model_id) {
+					let age: usize = era.saturating_sub(recent_era).saturated_into();
+					if let Some(slot) = eras.get_mut(age) {
+						*slot = slot.saturating_add(fees);
+					}
+				}
+			}
+			Some(RevenueHistory {
+				current_era: eras.first().copied().unwrap_or_default(),
+				lifetime: ModelRevenue::<T>::get(model_id),
+				eras,
+			})
+		}
+
+		/// Fees settled on the models `owner` owns now, era by era like
+		/// [`Self::model_revenue`]
+		///
+		/// Includes fees settled before `owner` acquired a model, so it is what their
+		/// models earn rather than what `owner` was paid. Backs the `owner_revenue`
+		/// runtime API.
+		pub fn owner_revenue(owner: &T::AccountId) -> RevenueHistory {
+			let window = T::UsageWindow::get() as usize;
+			let mut total =
+				RevenueHistory { eras: sp_std::vec![0u128; window], ..Default::default() };
+			for model in OwnedModels::<T>::get(owner).into_iter().filter_map(Self::model_revenue) {
+				total.current_era = total.current_era.saturating_add(model.current_era);
+				total.lifetime = total.lifetime.saturating_add(model.lifetime);
+				for (sum, fees) in total.eras.iter_mut().zip(model.eras) {
+					*sum = sum.saturating_add(fees);
+				}
+			}
+			total
+		}
+
+		/// Deposits reserved from `owner` for the models they own
+		///
+		/// Lets the runtime reward owners with stake in the registry, e.g. with lower
+		/// fees. Deactivated and rent-exhausted models hold no deposit.
+		pub fn model_stake(owner: &T::AccountId) -> BalanceOf<T> {
+			OwnedModels::<T>::get(owner)
+				.into_iter()
+				.filter_map(Models::<T>::get)
+				.fold(Zero::zero(), |stake: BalanceOf<T>, model| {
+					stake.saturating_add(model.deposit.saturated_into())
+				})
+		}
+
+		/// Up to `n` active models with the best reputation, best first
+		///
+		/// Backs the `top_models_by_rating` runtime API. Only models on the
+		/// `MaxRewardedModels` board are considered.
+		pub fn top_models_by_rating(n: u32) -> Vec<ModelId> {
+			Self::active_of(TopModels::<T>::get().into_iter().map(|(model_id, _)| model_id), n)
+		}
+
+		/// Up to `n` active models with the most completed inferences, most used first
+		///
+		/// Backs the `top_models_by_inferences` runtime API. Only models on the
+		/// `LeaderboardSize` board are considered.
+		pub fn top_models_by_inferences(n: u32) -> Vec<ModelId> {
+			Self::active_of(MostUsedModels::<T>::get().into_iter().map(|(model_id, _)| model_id), n)
+		}
+
+		/// Up to `n` active models, newest first
+		///
+		/// Backs the `recently_registered` runtime API. Model IDs are assigned in
+		/// registration order, so this walks down from the latest one past removed
+		/// and inactive models, and is meant for off-chain queries only.
+		pub fn recently_registered(n: u32) -> Vec<ModelId> {
+			Self::active_of((0..NextModelId::<T>::get()).rev(), n)
+		}
+
+		/// The first `n` of `models` that exist and are active
+		fn active_of(models: impl Iterator<Item = ModelId>, n: u32) -> Vec<ModelId> {
+			models
+				.filter(|model_id| {
+					Models::<T>::get(model_id)
+						.is_some_and(|model| model.status == ModelStatus::Active)
+				})
+				.take(n as usize)
+				.collect()
+		}
+
+		/// Key of `name` in the [`NameIndex`]: its first `NamePrefixLength` bytes,
+		/// lowercased
+		pub fn name_prefix(name: &[u8]) -> NamePrefixOf<T> {
+			let len = name.len().min(T::NamePrefixLength::get() as usize);
+			BoundedVec::truncate_from(name[..len].to_ascii_lowercase())
+		}
+
+		/// Key of `cid` in [`ModelsByCid`]
+		pub fn cid_key(cid: &[u8]) -> T::Hash {
+			T::Hashing::hash(cid)
+		}
+
+		/// Model registered with the artifact at `cid`, if any
+		pub fn model_by_cid(cid: &[u8]) -> Option<ModelId> {
+			ModelsByCid::<T>::get(Self::cid_key(cid))
+		}
+
+		/// A valid CIDv0 unique to `n`, for benchmarks registering several models
+		#[cfg(feature = "runtime-benchmarks")]
+		pub fn benchmark_cid(n: u64) -> Vec<u8> {
+			Self::numbered(b"QmYwAPJzv5CZsnA625s3Xf2nemtYgPpHdWEz79oj", n)
+		}
+
+		/// A model name unique to `n`, for benchmarks registering several models
+		#[cfg(feature = "runtime-benchmarks")]
+		pub fn benchmark_name(n: u64) -> Vec<u8> {
+			Self::numbered(b"Model ", n)
+		}
+
+		/// `prefix` followed by the last six decimal digits of `n`
+		#[cfg(feature = "runtime-benchmarks")]
+		fn numbered(prefix: &[u8], n: u64) -> Vec<u8> {
+			let mut numbered = prefix.to_vec();
+			numbered.extend((0..6).rev().map(|digit| b'0' + (n / 10u64.pow(digit) % 10) as u8));
+			numbered
+		}
+
+		/// Model of `owner` named `name`, if any; unnamed models are never found
+		pub fn model_named(owner: &T::AccountId, name: &[u8]) -> Option<ModelId> {
+			OwnerModelNames::<T>::get(owner, T::Hashing::hash(name))
+		}
+
+		/// Models whose name starts with `prefix`, ignoring ASCII case, in ascending
+		/// ID order
+		///
+		/// Backs the `models_by_name_prefix` runtime API. Only the index entry of
+		/// the prefix is read, so prefixes shorter than `NamePrefixLength` only find
+		/// models named exactly that.
+		pub fn models_by_name_prefix(prefix: &[u8]) -> Vec<ModelId> {
+			let prefix = prefix.to_ascii_lowercase();
+			let mut models: Vec<ModelId> =
+				NameIndex::<T>::iter_key_prefix(Self::name_prefix(&prefix))
+					.filter(|model_id| {
+						Models::<T>::get(model_id).is_some_and(|model| {
+							model.name.to_ascii_lowercase().starts_with(&prefix)
+						})
+					})
+					.collect();
+			models.sort_unstable();
+			models
+		}
+
+		/// Models owned by `owner`, in ascending ID order
+		///
+		/// Backs the `models_of` runtime API.
+		pub fn models_of(owner: &T::AccountId) -> Vec<ModelId> {
+			OwnedModels::<T>::get(owner).into_iter().collect()
+		}
+
+		/// Profile of `developer` with the models they own, if they set a profile
+		///
+		/// Backs the `developer_page` runtime API.
+		pub fn developer_page(developer: &T::AccountId) -> Option<DeveloperPage> {
+			let profile = DeveloperProfiles::<T>::get(developer)?;
+			Some(DeveloperPage {
+				display_name: profile.display_name.into_inner(),
+				website_cid: profile.website_cid.map(BoundedVec::into_inner),
+				contact_cid: profile.contact_cid.map(BoundedVec::into_inner),
+				models: Self::models_of(developer),
+			})
+		}
+
+		/// Chain-wide model, inference and fee counters
+		///
+		/// Backs the `global_stats` runtime API.
+		pub fn global_stats() -> RegistryStats {
+			GlobalStats::<T>::get()
+		}
+
+		/// Usage, revenue and rating distribution of `model_id`, if it exists
+		///
+		/// Backs the `model_stats` runtime API. Reads every rating of the model, so it
+		/// is meant for off-chain queries only.
+		pub fn model_stats(model_id: ModelId) -> Option<ModelStats> {
+			let model = Models::<T>::get(model_id)?;
+			let mut rating_distribution = [0u32; 5];
+			for record in Ratings::<T>::iter_prefix_values(model_id) {
+				let stars = usize::from(record.rating.clamp(1, 5));
+				rating_distribution[stars - 1].saturating_inc();
+			}
+			Some(ModelStats {
+				total_inferences: model.total_inferences,
+				era_inferences: Self::recent_inferences(model_id, 1),
+				revenue: ModelRevenue::<T>::get(model_id),
+				rating_count: model.rating_count,
+				rating_distribution,
+			})
+		}
+
+		/// Account registration fees are paid into and rebates paid from
+		pub fn fee_pot() -> T::AccountId {
+			T::PalletId::get().into_account_truncating()
+		}
+
+		/// Take the registration fee from `who` into the fee pot
+		fn charge_registration_fee(who: &T::AccountId) -> DispatchResult {
+			let fee = T::Currency::withdraw(
+				who,
+				T::RegistrationFee::get(),
+				WithdrawReasons::FEE,
+				ExistenceRequirement::KeepAlive,
+			)
+			.map_err(|_| Error::<T>::InsufficientBalance)?;
+			T::Currency::resolve_creating(&Self::fee_pot(), fee);
+			Ok(())
+		}
+
+		/// Keyless account of organization `org_id`
+		pub fn organization_account(org_id: OrganizationId) -> T::AccountId {
+			T::PalletId::get().into_sub_account_truncating((b"org", org_id))
+		}
+
+		/// Role an organization member needs to make `call` as the organization
+		fn required_role(call: &<T as Config>::RuntimeCall) -> OrganizationRole {
+			match call.is_sub_type() {
+				Some(
+					Call::register_model { .. }
+					| Call::update_model_metadata { .. }
+					| Call::rename_model { .. }
+					| Call::deprecate_model { .. }
+					| Call::propose_size_correction { .. }
+					| Call::add_model_callback { .. }
+					| Call::remove_model_callback { .. }
+					| Call::export_models { .. }
+					| Call::register_dataset { .. }
+					| Call::fork_model { .. }
+					| Call::create_collection { .. }
+					| Call::add_to_collection { .. }
+					| Call::remove_from_collection { .. }
+					| Call::set_developer_profile { .. }
+					| Call::schedule_price_change { .. }
+					| Call::cancel_price_change { .. },
+				) => OrganizationRole::Publisher,
+				Some(
+					Call::collect_rent { .. }
+					| Call::top_up_deposit { .. }
+					| Call::claim_fee_rebate { .. }
+					| Call::claim_incentive { .. }
+					| Call::claim_reward { .. },
+				) => OrganizationRole::Billing,
+				_ => OrganizationRole::Admin,
+			}
+		}
+
+		/// Account incentive budgets are paid into and incentives paid from
+		pub fn incentive_pot() -> T::AccountId {
+			T::PalletId::get().into_sub_account_truncating(b"incentives")
+		}
+
+		/// Current era of `RentEraLength` blocks, in which incentives and recent usage
+		/// are accounted, if eras are configured
+		fn incentive_era() -> Option<BlockNumberFor<T>> {
+			frame_system::Pallet::<T>::block_number().checked_div(&T::RentEraLength::get())
+		}
+
+		/// Add `amount` to the current era of `recent`, a per-era window newest first,
+		/// forgetting eras that fell out of the `UsageWindow`
+		fn accrue_recent<V: Saturating + Copy>(
+			recent: &mut BoundedVec<(BlockNumberFor<T>, V), T::UsageWindow>,
+			amount: V,
+		) {
+			let Some(era) = Self::incentive_era() else { return };
+			let window = T::UsageWindow::get();
+			match recent.get_mut(0) {
+				Some((latest, total)) if *latest == era => *total = total.saturating_add(amount),
+				// Only fails if no eras are kept at all
+				_ => drop(recent.force_insert_keep_left(0, (era, amount))),
+			}
+			recent.retain(|(recent_era, _)| recent_era.saturating_add(window.into()) > era);
+		}
+
+		/// Total of `recent`, a per-era window newest first, over the last `eras` eras
+		fn sum_recent<V: Saturating + Zero + Copy>(
+			recent: &[(BlockNumberFor<T>, V)],
+			eras: u32,
+		) -> V {
+			let Some(era) = Self::incentive_era() else { return Zero::zero() };
+			recent
+				.iter()
+				.take_while(|(recent_era, _)| recent_era.saturating_add(eras.into()) > era)
+				.fold(Zero::zero(), |total: V, (_, amount)| total.saturating_add(*amount))
+		}
+
+		/// Count an inference of `model_id` in the current era
+		fn record_recent_usage(model_id: ModelId) {
+			RecentUsage::<T>::mutate(model_id, |usage| Self::accrue_recent(usage, 1));
+		}
+
+		/// Count a verified inference of an active model towards its incentives
+		///
+		/// The era gets its budget with its first counted inference; eras without
+		/// funds left don't count usage.
+		fn record_usage(model_id: ModelId) {
+			let Some(era) = Self::incentive_era() else { return };
+			Self::credit_incentive(model_id);
+
+			let mut incentive = match EraIncentives::<T>::get(era) {
+				Some(incentive) => incentive,
+				None => {
+					let budget = IncentivePerEra::<T>::get().min(IncentiveFunds::<T>::get());
+					if budget.is_zero() {
+						return;
+					}
+					IncentiveFunds::<T>::mutate(|funds| *funds = funds.saturating_sub(budget));
+					EraIncentive { budget, usage: 0, models: 0 }
+				}
+			};
+			let count = match IncentiveUsage::<T>::get(model_id) {
+				Some((_, count)) => count,
+				None => {
+					incentive.models.saturating_inc();
+					0
+				}
+			};
+			incentive.usage.saturating_inc();
+			IncentiveUsage::<T>::insert(model_id, (era, count.saturating_add(1)));
+			EraIncentives::<T>::insert(era, incentive);
+		}
+
+		/// Credit a model's share of a finished era's budget to its earnings
+		fn credit_incentive(model_id: ModelId) {
+			let Some(current) = Self::incentive_era() else { return };
+			let Some((era, count)) = IncentiveUsage::<T>::get(model_id) else { return };
+			if era >= current {
+				return;
+			}
+			IncentiveUsage::<T>::remove(model_id);
+			let Some(mut incentive) = EraIncentives::<T>::get(era) else { return };
+
+			let share = Perbill::from_rational(count, incentive.usage) * incentive.budget;
+			IncentiveEarnings::<T>::mutate(model_id, |earnings| {
+				*earnings = earnings.saturating_add(share)
+			});
+			incentive.models.saturating_dec();
+			if incentive.models == 0 {
+				EraIncentives::<T>::remove(era);
+			} else {
+				EraIncentives::<T>::insert(era, incentive);
+			}
+		}
+
+		/// Drop a reaped model's incentives, returning its earnings to the program
+		fn forfeit_incentive(model_id: ModelId) {
+			Self::credit_incentive(model_id);
+			if let Some((era, _)) = IncentiveUsage::<T>::take(model_id) {
+				EraIncentives::<T>::mutate_exists(era, |maybe_incentive| {
+					if let Some(incentive) = maybe_incentive {
+						incentive.models.saturating_dec();
+						if incentive.models == 0 {
+							*maybe_incentive = None;
+						}
+					}
+				});
+			}
+			let earned = IncentiveEarnings::<T>::take(model_id);
+			IncentiveFunds::<T>::mutate(|funds| *funds = funds.saturating_add(earned));
+		}
+
+		/// Average rating of an active model in hundredths of a star, once
+		/// `RewardMinRatings` accounts rated it
+		pub fn reputation(model_id: ModelId, model: &ModelMetadata<T>) -> Option<u32> {
+			if model.status != ModelStatus::Active
+				|| model.rating_count == 0
+				|| RaterCounts::<T>::get(model_id) < T::RewardMinRatings::get()
+			{
+				return None;
+			}
+			let average = model.total_rating.saturating_mul(100) / u64::from(model.rating_count);
+			Some(average.saturated_into())
+		}
+
+		/// Move `model_id` to its place among the top models by reputation
+		///
+		/// Models are ranked as they are rated, so a model dropping out leaves its
+		/// place to the next model rated well enough.
+		fn rank_model(model_id: ModelId, model: &ModelMetadata<T>) {
+			TopModels::<T>::mutate(|top| {
+				top.retain(|(id, _)| *id != model_id);
+				if let Some(reputation) = Self::reputation(model_id, model) {
+					let at =
+						top.iter().position(|(_, other)| *other < reputation).unwrap_or(top.len());
+					// Ranked below a full board
+					let _ = top.force_insert_keep_left(at, (model_id, reputation));
+				}
+			});
+		}
+
+		/// Move `model_id` to its place among the most used models
+		///
+		/// Counts only grow, so the board only needs updating as inferences complete;
+		/// models with equal counts keep the order they reached them in.
+		fn rank_usage(model_id: ModelId, inferences: u64) {
+			MostUsedModels::<T>::mutate(|top| {
+				top.retain(|(id, _)| *id != model_id);
+				let at = top.iter().position(|(_, other)| *other < inferences).unwrap_or(top.len());
+				// Ranked below a full board
+				let _ = top.force_insert_keep_left(at, (model_id, inferences));
+			});
+		}
+
+		/// Split `RewardPerEra` among the top models still active, by reputation
+		///
+		/// Returns the number of models on the board.
+		fn credit_era_rewards() -> u32 {
+			let reward = T::RewardPerEra::get();
+			let mut top = TopModels::<T>::get();
+			let models = top.len() as u32;
+			if reward.is_zero() {
+				return models;
+			}
+			top.retain(|(model_id, _)| {
+				Models::<T>::get(model_id).is_some_and(|model| model.status == ModelStatus::Active)
+			});
+			let total =
+				top.iter().fold(0u32, |total, (_, reputation)| total.saturating_add(*reputation));
+			if total == 0 {
+				TopModels::<T>::put(top);
+				return models;
+			}
+
+			let mut credited = BalanceOf::<T>::zero();
+			for (model_id, reputation) in top.iter() {
+				let share = Perbill::from_rational(*reputation, total) * reward;
+				EraRewards::<T>::mutate(model_id, |rewards| {
+					*rewards = rewards.saturating_add(share)
+				});
+				credited = credited.saturating_add(share);
+			}
+			Self::deposit_event(Event::EraRewardsCredited {
+				models: top.len() as u32,
+				amount: credited.saturated_into(),
+			});
+			TopModels::<T>::put(top);
+			models
+		}
+
+		/// Whether enough accounts rated `model`, averaging at least
+		/// `RebateRatingThreshold`, for registration fee rebates
+		fn rated_for_rebate(model_id: ModelId, model: &ModelMetadata<T>) -> bool {
+			let threshold = u64::from(T::RebateRatingThreshold::get());
+			model.rating_count > 0
+				&& RaterCounts::<T>::get(model_id) >= T::RebateMinRatings::get()
+				&& model.total_rating >= threshold.saturating_mul(model.rating_count.into())
+		}
+
+		/// Validate, charge for and store a new model owned by `who`
+		///
+		/// Shared by `register_model`, `fork_model` and `import_model`; checks `who` is
+		/// allowlisted if required, takes the fee and deposit from `who` and emits
+		/// `ModelRegistered`.
+		#[allow(clippy::too_many_arguments)]
+		fn do_register(
+			who: &T::AccountId,
+			ipfs_cid: BoundedVec<u8, T::MaxCidLength>,
+			name: BoundedVec<u8, T::MaxNameLength>,
+			description: BoundedVec<u8, T::MaxDescriptionLength>,
+			model_type: ModelType,
+			price: u128,
+			license: LicenseOf<T>,
+			size_bytes: u64,
+			parent: Option<ModelId>,
+		) -> Result<ModelId, DispatchError> {
+			ensure!(
+				!AllowlistRequired::<T>::get() || T::DeveloperAllowlist::contains(who),
+				Error::<T>::NotAllowlisted
+			);
+			ensure!(Self::validate_ipfs_cid(&ipfs_cid), Error::<T>::InvalidIPFSCID);
+			let cid_key = Self::cid_key(&ipfs_cid);
+			ensure!(!ModelsByCid::<T>::contains_key(cid_key), Error::<T>::CidAlreadyRegistered);
+			ensure!(Self::model_named(who, &name).is_none(), Error::<T>::DuplicateModelName);
+
+			// Custom licenses must point at a well-formed IPFS document
+			if let License::Custom(ref license_cid) = license {
+				ensure!(Self::validate_ipfs_cid(license_cid), Error::<T>::InvalidLicenseCID);
+			}
+
+			ensure!(size_bytes > 0, Error::<T>::InvalidModelSize);
+
+			// Check the size-proportional deposit can be covered
+			let deposit = Self::model_deposit(size_bytes);
+			let free_balance = T::Currency::free_balance(who);
+			ensure!(free_balance >= deposit, Error::<T>::InsufficientStake);
+
+			// Charge registration fee
+			let fee = T::RegistrationFee::get();
+			ensure!(free_balance >= fee, Error::<T>::InsufficientBalance);
+
+			Self::charge_registration_fee(who)?;
+
+			// Reserve the registration deposit for the lifetime of the model
+			T::Currency::reserve(who, deposit).map_err(|_| Error::<T>::InsufficientStake)?;
+
+			// Get next model ID
+			let model_id = NextModelId::<T>::get();
+			let next_id = model_id.checked_add(1).ok_or(Error::<T>::ArithmeticOverflow)?;
+
+			// Create model metadata
+			let now = frame_system::Pallet::<T>::block_number();
+			let created_at_u64: u64 = now.saturated_into();
+			let metadata = ModelMetadata {
+				owner: who.clone(),
+				creator: who.clone(),
+				parent,
+				ipfs_cid: ipfs_cid.clone(),
+				name,
+				description,
+				model_type,
+				license,
+				size_bytes,
+				deposit: deposit.saturated_into(),
+				price,
+				created_at: created_at_u64,
+				total_inferences: 0,
+				total_rating: 0,
+				rating_count: 0,
+				status: if T::RequireRegistrationApproval::get() {
+					ModelStatus::Pending
+				} else {
+					ModelStatus::Active
+				},
+			};
+			let active = metadata.status == ModelStatus::Active;
+
+			// Store model
+			NameIndex::<T>::insert(Self::name_prefix(&metadata.name), model_id, ());
+			ModelsByCid::<T>::insert(cid_key, model_id);
+			Self::index_name(who, &metadata.name, model_id);
+			Models::<T>::insert(model_id, metadata);
+			Self::add_owned_model(who, model_id)?;
+			NextModelId::<T>::put(next_id);
+			LastActivity::<T>::insert(model_id, now);
+			if !fee.is_zero() {
+				RebatableFees::<T>::insert(model_id, fee);
+				RebatedUntil::<T>::insert(model_id, now);
+			}
+			T::ModelNfts::mint(model_id, who)?;
+			GlobalStats::<T>::mutate(|stats| {
+				stats.total_models.saturating_inc();
+				if active {
+					stats.active_models.saturating_inc();
+				}
+			});
+			T::OnModelRegistered::on_model_registered(model_id, who);
+			Self::index_owner(who, model_id, true);
+
+			// Emit event
+			Self::deposit_indexed_event(
+				model_id,
+				Event::ModelRegistered { model_id, owner: who.clone(), ipfs_cid },
+			);
+
+			Ok(model_id)
+		}
+
+		/// Ensure every dataset in `datasets` is registered and referenced once
+		fn ensure_datasets(datasets: &[DatasetId]) -> DispatchResult {
+			for (i, dataset_id) in datasets.iter().enumerate() {
+				ensure!(Datasets::<T>::contains_key(dataset_id), Error::<T>::DatasetNotFound);
+				ensure!(!datasets[..i].contains(dataset_id), Error::<T>::DuplicateDataset);
+			}
+			Ok(())
+		}
+
+		/// Genesis hash identifying this chain in migration packets
+		pub fn chain_id() -> T::Hash {
+			frame_system::Pallet::<T>::block_hash(BlockNumberFor::<T>::zero())
+		}
+
+		/// Check the storage invariants of the registry
+		///
+		/// Run after every block by `try-runtime` and after upgrades by
+		/// `on-runtime-upgrade`, so broken migrations surface before enactment.
+		#[cfg(any(feature = "try-runtime", test))]
+		pub fn do_try_state() -> Result<(), sp_runtime::TryRuntimeError> {
+			let next_id = NextModelId::<T>::get();
+			let (mut total, mut active) = (0u64, 0u64);
+			for (model_id, model) in Models::<T>::iter() {
+				total += 1;
+				if model.status == ModelStatus::Active {
+					active += 1;
+				}
+				if model.status == ModelStatus::UnderReview {
+					ensure!(
+						UnderReview::<T>::contains_key(model_id),
+						"model in UnderReview status without pending reports"
+					);
+				}
+				ensure!(model_id < next_id, "model ID not below NextModelId");
+				ensure!(
+					OwnedModels::<T>::get(&model.owner).contains(&model_id),
+					"model missing from OwnedModels"
+				);
+				if model.status == ModelStatus::Deactivated {
+					ensure!(model.deposit == 0, "deactivated model still holds a deposit");
+				}
+				if let Some(parent_id) = model.parent {
+					ensure!(parent_id < model_id, "model forked from a later model");
+				}
+			}
+
+			let stats = GlobalStats::<T>::get();
+			ensure!(stats.total_models == total, "GlobalStats model count out of sync");
+			ensure!(stats.active_models == active, "GlobalStats active model count out of sync");
+
+			for (owner, owned) in OwnedModels::<T>::iter() {
+				ensure!(!owned.is_empty(), "empty OwnedModels entry");
+				for model_id in owned {
+					let model =
+						Models::<T>::get(model_id).ok_or("OwnedModels entry without model")?;
+					ensure!(model.owner == owner, "OwnedModels entry for wrong owner");
+				}
+			}
+
+			for (model_id, _) in RentExhausted::<T>::iter() {
+				let model =
+					Models::<T>::get(model_id).ok_or("RentExhausted entry without model")?;
+				ensure!(model.deposit == 0, "rent-exhausted model still holds a deposit");
+			}
+
+			for model_id in ModelCallbacks::<T>::iter_keys() {
+				ensure!(Models::<T>::contains_key(model_id), "callbacks for missing model");
+			}
+
+			for (model_id, share) in ParentShare::<T>::iter() {
+				let model = Models::<T>::get(model_id).ok_or("parent share of missing model")?;
+				ensure!(model.parent.is_some(), "parent share of a model without parent");
+				ensure!(share <= T::MaxParentShare::get(), "parent share above MaxParentShare");
+			}
+
+			for model_id in Successors::<T>::iter_keys() {
+				ensure!(Models::<T>::contains_key(model_id), "successor of missing model");
+			}
+
+			for (prefix, model_id, ()) in NameIndex::<T>::iter() {
+				let model = Models::<T>::get(model_id).ok_or("NameIndex entry without model")?;
+				ensure!(Self::name_prefix(&model.name) == prefix, "NameIndex entry for wrong name");
+			}
+			ensure!(
+				NameIndex::<T>::iter_keys().count() as u64 == total,
+				"model missing from NameIndex"
+			);
+
+			for (cid_key, model_id) in ModelsByCid::<T>::iter() {
+				let model = Models::<T>::get(model_id).ok_or("ModelsByCid entry without model")?;
+				ensure!(
+					Self::cid_key(&model.ipfs_cid) == cid_key,
+					"ModelsByCid entry for wrong CID"
+				);
+			}
+
+			for (owner, name_key, model_id) in OwnerModelNames::<T>::iter() {
+				let model =
+					Models::<T>::get(model_id).ok_or("OwnerModelNames entry without model")?;
+				ensure!(model.owner == owner, "OwnerModelNames entry for wrong owner");
+				ensure!(
+					!model.name.is_empty() && T::Hashing::hash(&model.name) == name_key,
+					"OwnerModelNames entry for wrong name"
+				);
+			}
+
+			for model_id in UnderReview::<T>::iter_keys() {
+				let model = Models::<T>::get(model_id).ok_or("UnderReview entry without model")?;
+				ensure!(
+					model.status == ModelStatus::UnderReview,
+					"model with reports under review not in UnderReview status"
+				);
+			}
+			for (model_id, at) in PendingDeactivations::<T>::iter() {
+				ensure!(
+					ScheduledDeactivations::<T>::get(at).contains(&model_id),
+					"pending deactivation not scheduled"
+				);
+			}
+			for (at, models) in ScheduledDeactivations::<T>::iter() {
+				for model_id in models {
+					ensure!(
+						PendingDeactivations::<T>::get(model_id) == Some(at),
+						"scheduled deactivation not pending"
+					);
+				}
+			}
+			for (model_id, (at, _)) in PendingPriceChanges::<T>::iter() {
+				ensure!(
+					ScheduledPriceChanges::<T>::get(at).contains(&model_id),
+					"pending price change not scheduled"
+				);
+			}
+			for (at, models) in ScheduledPriceChanges::<T>::iter() {
+				for model_id in models {
+					ensure!(
+						PendingPriceChanges::<T>::get(model_id).is_some_and(|(due, _)| due == at),
+						"scheduled price change not pending"
+					);
+				}
+			}
+			for model_id in ForcedDeactivations::<T>::iter_keys() {
+				let model =
+					Models::<T>::get(model_id).ok_or("ForcedDeactivations entry without model")?;
+				ensure!(
+					model.status == ModelStatus::Deactivated,
+					"force-deactivated model not deactivated"
+				);
+			}
+			for model_id in Appeals::<T>::iter_keys() {
+				ensure!(
+					ForcedDeactivations::<T>::contains_key(model_id),
+					"appeal without forced deactivation"
+				);
+			}
+			for (model_id, reports) in ReportCounts::<T>::iter() {
+				ensure!(
+					ModelReports::<T>::iter_prefix(model_id).count() as u32 == reports,
+					"ReportCounts out of sync with ModelReports"
+				);
+			}
+
+			for (model_id, histogram) in RatingHistograms::<T>::iter() {
+				let model =
+					Models::<T>::get(model_id).ok_or("RatingHistograms entry without model")?;
+				ensure!(
+					histogram.iter().copied().map(u64::from).sum::<u64>()
+						<= u64::from(model.rating_count),
+					"RatingHistograms entry above rating count"
+				);
+			}
+
+			// Every rater counts at least once
+			for (model_id, raters) in RaterCounts::<T>::iter() {
+				let model = Models::<T>::get(model_id).ok_or("RaterCounts entry without model")?;
+				ensure!(raters <= model.rating_count, "RaterCounts entry above rating count");
+			}
+
+			let most_used = MostUsedModels::<T>::get();
+			for (model_id, inferences) in most_used.iter() {
+				let model = Models::<T>::get(model_id).ok_or("most used model missing")?;
+				ensure!(model.total_inferences == *inferences, "MostUsedModels count out of sync");
+			}
+			ensure!(
+				most_used.windows(2).all(|pair| pair[0].1 >= pair[1].1),
+				"MostUsedModels out of order"
+			);
+
+			for (model_id, collections) in ModelCollections::<T>::iter() {
+				for collection_id in collections {
+					ensure!(
+						Collections::<T>::get(collection_id)
+							.is_some_and(|collection| collection.models.contains(&model_id)),
+						"ModelCollections entry not in collection"
+					);
+				}
+			}
+
+			for (org_id, org) in Organizations::<T>::iter() {
+				let (mut members, mut admins) = (0u32, 0u32);
+				for (_, role) in OrganizationMembers::<T>::iter_prefix(org_id) {
+					members += 1;
+					if role == OrganizationRole::Admin {
+						admins += 1;
+					}
+				}
+				ensure!(org.members == members, "organization member count out of sync");
+				ensure!(org.admins == admins, "organization admin count out of sync");
+				ensure!(admins > 0, "organization without admins");
+			}
+
+			for (model_id, shares) in Shareholders::<T>::iter() {
+				ensure!(Models::<T>::contains_key(model_id), "shares of missing model");
+				let total =
+					shares.iter().map(|(_, share)| u64::from(share.deconstruct())).sum::<u64>();
+				ensure!(
+					total == u64::from(Perbill::one().deconstruct()),
+					"shares not adding up to 100%"
+				);
+			}
+
+			Ok(())
+		}
+
+		/// Deactivate `model_id`, slashing `SlashFraction` of its deposit to `Slash`
+		///
+		/// Used by `slash_model` and by other pallets (e.g. dispute resolution) once
+		/// a model has been found fraudulent.
+		pub fn do_slash(model_id: ModelId) -> DispatchResult {
+			let (owner, slashed, status) =
+				Models::<T>::try_mutate(model_id, |maybe_model| -> Result<_, DispatchError> {
+					let model = maybe_model.as_mut().ok_or(Error::<T>::ModelNotFound)?;
+					ensure!(model.status != ModelStatus::Deactivated, Error::<T>::ModelNotActive);
+					let status = model.status;
+
+					let deposit: BalanceOf<T> = model.deposit.saturated_into();
+					let (imbalance, _) = T::Currency::slash_reserved(
+						&model.owner,
+						T::SlashFraction::get() * deposit,
+					);
+					let slashed = imbalance.peek();
+					T::Slash::on_unbalanced(imbalance);
+					T::Currency::unreserve(&model.owner, deposit.saturating_sub(slashed));
+
+					Self::set_status(model_id, model, ModelStatus::Deactivated);
+					model.deposit = 0;
+
+					Ok((model.owner.clone(), slashed, status))
+				})?;
+
+			// A slashed model is no longer waiting to be reaped for unpaid rent, nor for
+			// curators to resolve its reports, which are spent. Its owner may appeal, to
+			// get back the status it had before it was suspended.
+			RentExhausted::<T>::remove(model_id);
+			Self::clear_reports(model_id);
+			let previous = UnderReview::<T>::take(model_id).unwrap_or(status);
+			ForcedDeactivations::<T>::insert(model_id, previous);
+
+			Self::deposit_event(Event::ModelSlashed {
+				model_id,
+				owner,
+				amount: slashed.saturated_into(),
+			});
+
+			Ok(())
+		}
+
+		/// Forget the reports filed against `model_id`, so it can be reported afresh
+		fn clear_reports(model_id: ModelId) {
+			let reports = ReportCounts::<T>::take(model_id);
+			let _ = ModelReports::<T>::clear_prefix(model_id, reports, None);
+		}
+
+		/// Drop what is still scheduled for, filed against or pointing at the removed
+		/// `model_id`
+		fn purge_model(model_id: ModelId) {
+			Self::cancel_deactivation(model_id);
+			Self::drop_price_change(model_id);
+			UnderReview::<T>::remove(model_id);
+			Self::clear_reports(model_id);
+			let raters = RaterCounts::<T>::take(model_id);
+			let _ = RatingWeights::<T>::clear_prefix(model_id, raters, None);
+			ImportedFrom::<T>::remove(model_id);
+			for collection_id in ModelCollections::<T>::take(model_id) {
+				Collections::<T>::mutate(collection_id, |maybe_collection| {
+					if let Some(collection) = maybe_collection {
+						collection.models.remove(&model_id);
+					}
+				});
+			}
+		}
+
+		/// Deactivate `model` and release its owner's deposit
+		fn do_deactivate(model_id: ModelId, model: &mut ModelMetadata<T>) {
+			Self::set_status(model_id, model, ModelStatus::Deactivated);
+			T::Currency::unreserve(&model.owner, model.deposit.saturated_into());
+			model.deposit = 0;
+		}
+
+		/// Schedule `owner`'s `model_id` for deactivation at `at`
+		fn schedule_deactivation(
+			model_id: ModelId,
+			owner: T::AccountId,
+			at: BlockNumberFor<T>,
+		) -> DispatchResult {
+			ScheduledDeactivations::<T>::try_append(at, model_id)
+				.map_err(|_| Error::<T>::TooManyScheduledDeactivations)?;
+			PendingDeactivations::<T>::insert(model_id, at);
+			Self::deposit_indexed_event(
+				model_id,
+				Event::DeactivationScheduled { model_id, owner, at },
+			);
+			Ok(())
+		}
+
+		/// Give notice of the deactivations scheduled `DeactivationNotice` blocks after
+		/// `now`, returning how many there are
+		fn announce_deactivations(now: BlockNumberFor<T>) -> u32 {
+			let notice = T::DeactivationNotice::get();
+			if notice.is_zero() {
+				return 0;
+			}
+			let at = now.saturating_add(notice);
+			let scheduled = ScheduledDeactivations::<T>::get(at);
+			for &model_id in &scheduled {
+				let successor = Successors::<T>::get(model_id);
+				Self::deposit_indexed_event(
+					model_id,
+					Event::DeactivationUpcoming { model_id, at, successor },
+				);
+			}
+			scheduled.len() as u32
+		}
+
+		/// Drop the scheduled deactivation of `model_id`, if any
+		fn cancel_deactivation(model_id: ModelId) {
+			if let Some(at) = PendingDeactivations::<T>::take(model_id) {
+				ScheduledDeactivations::<T>::mutate(at, |models| {
+					models.retain(|id| *id != model_id)
+				});
+			}
+		}
+
+		/// Deactivate the models scheduled for deactivation at `now`, returning how
+		/// many were scheduled
+		///
+		/// Models reaped or deactivated otherwise in the meantime are skipped.
+		fn enact_deactivations(now: BlockNumberFor<T>) -> u32 {
+			let scheduled = ScheduledDeactivations::<T>::take(now);
+			for &model_id in &scheduled {
+				PendingDeactivations::<T>::remove(model_id);
+				let owner = Models::<T>::mutate(model_id, |maybe_model| {
+					let model = maybe_model.as_mut()?;
+					if model.status == ModelStatus::Deactivated {
+						return None;
+					}
+					Self::do_deactivate(model_id, model);
+					Some(model.owner.clone())
+				});
+				if let Some(owner) = owner {
+					Self::deposit_indexed_event(
+						model_id,
+						Event::ModelDeactivated { model_id, owner },
+					);
+				}
+			}
+			scheduled.len() as u32
+		}
+
+		/// Drop the scheduled price change of `model_id`, returning whether there was one
+		fn drop_price_change(model_id: ModelId) -> bool {
+			let Some((at, _)) = PendingPriceChanges::<T>::take(model_id) else { return false };
+			ScheduledPriceChanges::<T>::mutate(at, |models| models.retain(|id| *id != model_id));
+			true
+		}
+
+		/// Apply the price changes scheduled for `now`, returning how many were
+		/// scheduled
+		///
+		/// Models removed, under review or fractionally owned in the meantime keep
+		/// their price.
+		fn enact_price_changes(now: BlockNumberFor<T>) -> u32 {
+			let scheduled = ScheduledPriceChanges::<T>::take(now);
+			for &model_id in &scheduled {
+				let Some((_, price)) = PendingPriceChanges::<T>::take(model_id) else {
+					continue;
+				};
+				if Shareholders::<T>::contains_key(model_id) {
+					continue;
+				}
+				let owner = Models::<T>::mutate(model_id, |maybe_model| {
+					let model = maybe_model.as_mut()?;
+					Self::apply_metadata_update(model_id, model, Some(price), None, None, None)
+						.ok()?;
+					Some(model.owner.clone())
+				});
+				if let Some(owner) = owner {
+					Self::deposit_indexed_event(model_id, Event::ModelUpdated { model_id, owner });
+				}
+			}
+			scheduled.len() as u32
+		}
+
+		/// Bound a new model description
+		fn bound_description(
+			description: Option<Vec<u8>>,
+		) -> Result<Option<BoundedVec<u8, T::MaxDescriptionLength>>, Error<T>> {
+			description
+				.map(|description| {
+					description.try_into().map_err(|_| Error::<T>::DescriptionTooLong)
+				})
+				.transpose()
+		}
+
+		/// Apply the given changes to `model`
+		fn apply_metadata_update(
+			model_id: ModelId,
+			model: &mut ModelMetadata<T>,
+			new_price: Option<u128>,
+			new_description: Option<BoundedVec<u8, T::MaxDescriptionLength>>,
+			new_status: Option<ModelStatus>,
+			new_license: Option<LicenseOf<T>>,
+		) -> DispatchResult {
+			ensure!(model.status != ModelStatus::UnderReview, Error::<T>::ModelUnderReview);
+
+			if let Some(price) = new_price {
+				let old = core::mem::replace(&mut model.price, price);
+				if old != price {
+					Self::record_update(model_id, MetadataField::Price, &old);
+					Self::notify_watchers(model_id, ModelChange::Price { old, new: price });
+				}
+			}
+
+			if let Some(description) = new_description {
+				ensure!(!LockedMetadata::<T>::contains_key(model_id), Error::<T>::MetadataLocked);
+				let old = core::mem::replace(&mut model.description, description);
+				if old != model.description {
+					Self::record_update(model_id, MetadataField::Description, &old);
+				}
+			}
+
+			if let Some(license) = new_license {
+				ensure!(!LockedMetadata::<T>::contains_key(model_id), Error::<T>::MetadataLocked);
+				if let License::Custom(ref license_cid) = license {
+					ensure!(Self::validate_ipfs_cid(license_cid), Error::<T>::InvalidLicenseCID);
+				}
+				let old = core::mem::replace(&mut model.license, license);
+				if old != model.license {
+					Self::record_update(model_id, MetadataField::License, &old);
+				}
+			}
+
+			if let Some(status) = new_status {
+				ensure!(
+					model.status.can_transition_to(status),
+					Error::<T>::InvalidStatusTransition
+				);
+				ensure!(
+					status != ModelStatus::Active || !RentExhausted::<T>::contains_key(model_id),
+					Error::<T>::RentExhausted
+				);
+				if status != ModelStatus::Deprecated {
+					Successors::<T>::remove(model_id);
+				}
+				if model.status != status {
+					Self::record_update(model_id, MetadataField::Status, &model.status);
+				}
+				Self::set_status(model_id, model, status);
+			}
+
+			Ok(())
+		}
+
+		/// Add a change of `field` from `previous` to the update history of `model_id`,
+		/// dropping the oldest entry once `MaxUpdateHistory` are kept
+		fn record_update(model_id: ModelId, field: MetadataField, previous: &impl Encode) {
+			let update = MetadataUpdate {
+				field,
+				updated_at: frame_system::Pallet::<T>::block_number().saturated_into(),
+				previous: sp_io::hashing::blake2_256(&previous.encode()).into(),
+			};
+			UpdateHistory::<T>::mutate(model_id, |history| {
+				// Only fails if no history is kept at all
+				let _ = history.force_insert_keep_left(0, update);
+			});
+		}
+
+		/// Give `model` a new status, notifying its watchers and `OnModelStatusChanged`
+		/// if it changed
+		fn set_status(model_id: ModelId, model: &mut ModelMetadata<T>, status: ModelStatus) {
+			let old = core::mem::replace(&mut model.status, status);
+			if old != status {
+				if old == ModelStatus::Active {
+					GlobalStats::<T>::mutate(|stats| stats.active_models.saturating_dec());
+				} else if status == ModelStatus::Active {
+					GlobalStats::<T>::mutate(|stats| stats.active_models.saturating_inc());
+				}
+				T::OnModelStatusChanged::on_model_status_changed(model_id, old, status);
+				Self::notify_watchers(model_id, ModelChange::Status { old, new: status });
+			}
+		}
+
+		/// Emit `WatchedModelChanged` for `change`, listing the watchers of `model_id`
+		/// `WatchersPerEvent` at a time
+		fn notify_watchers(model_id: ModelId, change: ModelChange) {
+			let watchers = Watchers::<T>::get(model_id);
+			let chunk_size = (T::WatchersPerEvent::get() as usize).max(1);
+			for chunk in watchers.chunks(chunk_size) {
+				Self::deposit_event(Event::WatchedModelChanged {
+					model_id,
+					change,
+					watchers: BoundedVec::truncate_from(chunk.to_vec()),
+				});
+			}
+		}
+
+		/// Share `who` holds in `shares`
+		fn share_of(shares: &ModelSharesOf<T>, who: &T::AccountId) -> Perbill {
+			shares
+				.iter()
+				.find(|(holder, _)| holder == who)
+				.map_or(Perbill::zero(), |(_, share)| *share)
+		}
+
+		/// Apply `proposal` if its approvers currently hold a majority of `shares`,
+		/// otherwise store it as pending
+		fn enact_or_store(
+			model_id: ModelId,
+			proposer: &T::AccountId,
+			proposal: MetadataProposal<T>,
+			shares: &ModelSharesOf<T>,
+		) -> DispatchResult {
+			let approved = proposal.approvals.iter().fold(Perbill::zero(), |total, approver| {
+				total.saturating_add(Self::share_of(shares, approver))
+			});
+			if approved <= Perbill::from_percent(50) {
+				MetadataProposals::<T>::insert(model_id, proposer, proposal);
+				return Ok(());
+			}
+
+			MetadataProposals::<T>::remove(model_id, proposer);
+			let owner = Models::<T>::try_mutate(model_id, |maybe_model| {
+				let model = maybe_model.as_mut().ok_or(Error::<T>::ModelNotFound)?;
+				Self::apply_metadata_update(
+					model_id,
+					model,
+					proposal.new_price,
+					proposal.new_description,
+					proposal.new_status,
+					None,
+				)
+				.map(|()| model.owner.clone())
+			})?;
+
+			Self::deposit_event(Event::MetadataChangeEnacted {
+				model_id,
+				proposer: proposer.clone(),
+			});
+			Self::deposit_indexed_event(model_id, Event::ModelUpdated { model_id, owner });
+			Ok(())
+		}
+
+		/// Accounts sharing the inference revenue of `model_id` and their shares,
+		/// adding up to 100%; empty if it all goes to the owner
+		pub fn revenue_shares(model_id: ModelId) -> Vec<(T::AccountId, Perbill)> {
+			let shares = if let Some(recipient) = RevenueRecipient::<T>::get(model_id) {
+				sp_std::vec![(recipient, Perbill::one())]
+			} else {
+				Shareholders::<T>::get(model_id)
+					.map(|shares| shares.into_inner())
+					.unwrap_or_default()
+			};
+
+			// Forks pass their parent share on to the parent's current owner
+			let Some((parent_owner, parent_share)) = Self::parent_royalty(model_id) else {
+				return shares;
+			};
+			let shares = if shares.is_empty() {
+				let owner = Models::<T>::get(model_id).map(|model| model.owner);
+				owner.map(|owner| sp_std::vec![(owner, Perbill::one())]).unwrap_or_default()
+			} else {
+				shares
+			};
+			let rest = sp_runtime::PerThing::left_from_one(parent_share);
+			core::iter::once((parent_owner, parent_share))
+				.chain(shares.into_iter().map(|(holder, share)| (holder, rest * share)))
+				.collect()
+		}
+
+		/// Owner of the parent of a fork and the share of the fork's revenue owed to
+		/// them, if the parent still exists
+		fn parent_royalty(model_id: ModelId) -> Option<(T::AccountId, Perbill)> {
+			let share = ParentShare::<T>::get(model_id)?;
+			let parent_id = Models::<T>::get(model_id)?.parent?;
+			Some((Models::<T>::get(parent_id)?.owner, share))
+		}
+
+		/// Send all inference revenue of `model_id` to `recipient` instead of its
+		/// owner and shareholders, or back to them if `None`
+		pub fn set_revenue_recipient(model_id: ModelId, recipient: Option<T::AccountId>) {
+			RevenueRecipient::<T>::set(model_id, recipient);
+		}
+
+		/// Ensure `who` owns `model_id`
+		fn ensure_owner(model_id: ModelId, who: &T::AccountId) -> DispatchResult {
+			let model = Models::<T>::get(model_id).ok_or(Error::<T>::ModelNotFound)?;
+			Self::ensure_model_owner(model_id, &model, who)
+		}
+
+		/// Ensure `who` owns `model` and still holds its NFT, if it has one
+		fn ensure_model_owner(
+			model_id: ModelId,
+			model: &ModelMetadata<T>,
+			who: &T::AccountId,
+		) -> DispatchResult {
+			ensure!(model.owner == *who, Error::<T>::UnauthorizedAccess);
+			ensure!(
+				T::ModelNfts::owner(model_id).is_none_or(|holder| holder == *who),
+				Error::<T>::OwnershipNotSynced
+			);
+			Ok(())
+		}
+
+		/// Sell `model_id` to `buyer` for `price`
+		///
+		/// The sale path of marketplaces: `CreatorRoyalty` of the price goes to the
+		/// model's creator and the rest to its owner, and the buyer takes over the
+		/// model, its NFT and its deposit. Callers check the sale was agreed on.
+		pub fn do_sell(
+			model_id: ModelId,
+			buyer: &T::AccountId,
+			price: BalanceOf<T>,
+		) -> DispatchResult {
+			Models::<T>::try_mutate(model_id, |maybe_model| -> DispatchResult {
+				let model = maybe_model.as_mut().ok_or(Error::<T>::ModelNotFound)?;
+				let seller = model.owner.clone();
+
+				let royalty = T::CreatorRoyalty::get() * price;
+				T::Currency::transfer(
+					buyer,
+					&model.creator,
+					royalty,
+					ExistenceRequirement::KeepAlive,
+				)?;
+				T::Currency::transfer(
+					buyer,
+					&seller,
+					price.saturating_sub(royalty),
+					ExistenceRequirement::KeepAlive,
+				)?;
+
+				Self::do_transfer_ownership(model_id, model, buyer)?;
+				T::ModelNfts::transfer(model_id, buyer)?;
+
+				Self::deposit_event(Event::ModelSold {
+					model_id,
+					seller,
+					buyer: buyer.clone(),
+					price,
+					creator: model.creator.clone(),
+					royalty,
+				});
+				Ok(())
+			})
+		}
+
+		/// Hand `model` to `to`, who reserves its deposit in place of the current owner
+		///
+		/// Transfers can't be refused over a name, so a model named like one `to`
+		/// already has stays out of [`OwnerModelNames`] until renamed.
+		fn do_transfer_ownership(
+			model_id: ModelId,
+			model: &mut ModelMetadata<T>,
+			to: &T::AccountId,
+		) -> DispatchResult {
+			let deposit: BalanceOf<T> = model.deposit.saturated_into();
+			T::Currency::reserve(to, deposit).map_err(|_| Error::<T>::InsufficientStake)?;
+			T::Currency::unreserve(&model.owner, deposit);
+
+			Self::remove_owned_model(&model.owner, model_id);
+			Self::add_owned_model(to, model_id)?;
+			Self::unindex_name(&model.owner, &model.name, model_id);
+			Self::index_name(to, &model.name, model_id);
+			let from = core::mem::replace(&mut model.owner, to.clone());
+			Self::index_owner(&from, model_id, false);
+			Self::index_owner(to, model_id, true);
+
+			Self::deposit_indexed_event(
+				model_id,
+				Event::ModelTransferred { model_id, from, to: to.clone() },
+			);
+			Ok(())
+		}
+
+		/// Off-chain index key of the event of `model_id` deposited as event number
+		/// `index` of `block`
+		pub fn model_index_key(model_id: ModelId, block: BlockNumberFor<T>, index: u32) -> Vec<u8> {
+			(MODEL_INDEX_PREFIX, model_id, block, index).encode()
+		}
+
+		/// Off-chain index key marking `model_id` as owned by `owner`
+		pub fn owner_index_key(owner: &T::AccountId, model_id: ModelId) -> Vec<u8> {
+			(OWNER_INDEX_PREFIX, owner, model_id).encode()
+		}
+
+		/// Deposit `event` and record it in the off-chain index under `model_id`, so
+		/// nodes with indexing enabled can serve the history of the model
+		fn deposit_indexed_event(model_id: ModelId, event: Event<T>) {
+			let key = Self::model_index_key(
+				model_id,
+				frame_system::Pallet::<T>::block_number(),
+				frame_system::Pallet::<T>::event_count(),
+			);
+			sp_io::offchain_index::set(&key, &event.encode());
+			Self::deposit_event(event);
+		}
+
+		/// Record `model_id` as owned by `owner`, up to `MaxModelsPerOwner` models
+		fn add_owned_model(owner: &T::AccountId, model_id: ModelId) -> DispatchResult {
+			OwnedModels::<T>::try_mutate(owner, |owned| {
+				owned.try_insert(model_id).map(drop).map_err(|_| Error::<T>::TooManyModels.into())
+			})
+		}
+
+		/// Record `name` as taken by `model_id` among the models of `owner`, unless
+		/// it's empty or already taken
+		fn index_name(owner: &T::AccountId, name: &[u8], model_id: ModelId) {
+			if !name.is_empty() {
+				OwnerModelNames::<T>::mutate(owner, T::Hashing::hash(name), |entry| {
+					entry.get_or_insert(model_id);
+				});
+			}
+		}
+
+		/// Free `name` among the models of `owner` if `model_id` holds it
+		fn unindex_name(owner: &T::AccountId, name: &[u8], model_id: ModelId) {
+			OwnerModelNames::<T>::mutate_exists(owner, T::Hashing::hash(name), |entry| {
+				if *entry == Some(model_id) {
+					*entry = None;
+				}
+			});
+		}
+
+		/// Forget `model_id` as owned by `owner`, dropping the entry once empty
+		fn remove_owned_model(owner: &T::AccountId, model_id: ModelId) {
+			OwnedModels::<T>::mutate_exists(owner, |maybe_owned| {
+				if let Some(owned) = maybe_owned {
+					owned.remove(&model_id);
+					if owned.is_empty() {
+						*maybe_owned = None;
+					}
+				}
+			});
+		}
+
+		/// Add or remove the off-chain index entry of `owner` for `model_id`; entries
+		/// hold the block the model was acquired at
+		fn index_owner(owner: &T::AccountId, model_id: ModelId, owned: bool) {
+			let key = Self::owner_index_key(owner, model_id);
+			if owned {
+				sp_io::offchain_index::set(
+					&key,
+					&frame_system::Pallet::<T>::block_number().encode(),
+				);
 			} else {
-				None
+				sp_io::offchain_index::clear(&key);
+			}
+		}
+
+		/// Whether the admin sunset block has been reached
+		pub fn admin_sunset_passed() -> bool {
+			frame_system::Pallet::<T>::block_number() >= AdminSunset::<T>::get()
+		}
+
+		/// Deposit reserved for a model artifact of the given size
+		pub fn model_deposit(size_bytes: u64) -> BalanceOf<T> {
+			let size: BalanceOf<T> = size_bytes.saturated_into();
+			T::MinimumModelStake::get()
+				.saturating_add(T::DepositPerByte::get().saturating_mul(size))
+		}
+
+		/// Get average rating for a model
+		pub fn get_average_rating(model_id: ModelId) -> Option<u8> {
+			Models::<T>::get(model_id).and_then(|model| {
+				if model.rating_count > 0 {
+					Some((model.total_rating / model.rating_count as u64) as u8)
+				} else {
+					None
+				}
+			})
+		}
+
+		/// Get median rating for a model, weighted like the average
+		///
+		/// Takes the lower of the two middle ratings when they differ. Unlike the
+		/// average, a burst of one-star ratings cannot move it further than the
+		/// middle rating. Ratings a legacy migration merged into the totals are not
+		/// known individually and not counted.
+		pub fn get_median_rating(model_id: ModelId) -> Option<u8> {
+			let histogram = RatingHistograms::<T>::get(model_id);
+			let count: u64 = histogram.iter().copied().map(u64::from).sum();
+			let mut seen = 0u64;
+			(1u8..=5).zip(histogram).find_map(|(stars, ratings)| {
+				seen = seen.saturating_add(u64::from(ratings));
+				(seen > 0 && seen.saturating_mul(2) >= count).then_some(stars)
+			})
+		}
+
+		/// Increment inference count for a model
+		/// Called by pallet-inference when inference is completed, with `InferenceOrigin`
+		pub fn increment_inference_count(
+			origin: OriginFor<T>,
+			model_id: ModelId,
+		) -> DispatchResult {
+			T::InferenceOrigin::ensure_origin(origin)?;
+			let (active, inferences) =
+				Models::<T>::try_mutate(model_id, |maybe_model| -> Result<_, DispatchError> {
+					let model = maybe_model.as_mut().ok_or(Error::<T>::ModelNotFound)?;
+					model.total_inferences = model
+						.total_inferences
+						.checked_add(1)
+						.ok_or(Error::<T>::ArithmeticOverflow)?;
+					LastActivity::<T>::insert(model_id, frame_system::Pallet::<T>::block_number());
+					Ok((model.status == ModelStatus::Active, model.total_inferences))
+				})?;
+			GlobalStats::<T>::mutate(|stats| stats.total_inferences.saturating_inc());
+			Self::rank_usage(model_id, inferences);
+			Self::record_recent_usage(model_id);
+			if active {
+				Self::record_usage(model_id);
 			}
+			Ok(())
+		}
+
+		/// Add a settled inference fee to the revenue of `model_id`, in total and in the
+		/// current era, and to the chain-wide fee volume
+		/// Called by pallet-inference when a request is settled, with `InferenceOrigin`
+		pub fn note_fee_volume(
+			origin: OriginFor<T>,
+			model_id: ModelId,
+			fee: u128,
+		) -> DispatchResult {
+			T::InferenceOrigin::ensure_origin(origin)?;
+			if Models::<T>::contains_key(model_id) {
+				ModelRevenue::<T>::mutate(model_id, |revenue| revenue.saturating_accrue(fee));
+				RecentRevenue::<T>::mutate(model_id, |revenue| Self::accrue_recent(revenue, fee));
+			}
+			GlobalStats::<T>::mutate(|stats| stats.fee_volume.saturating_accrue(fee));
+			Ok(())
 		}
 	}
 }
+
+/// Answers model queries from the registry's storage
+impl<T, Balance> AiRegistryInspect<T::AccountId, Balance> for Pallet<T>
+where
+	T: Config,
+	Balance: sp_runtime::traits::AtLeast32BitUnsigned,
+{
+	fn owner(model_id: ModelId) -> Option<T::AccountId> {
+		Models::<T>::get(model_id).map(|model| model.owner)
+	}
+
+	fn price(model_id: ModelId) -> Option<Balance> {
+		use sp_runtime::SaturatedConversion;
+
+		Models::<T>::get(model_id).map(|model| model.price.saturated_into())
+	}
+
+	fn is_active(model_id: ModelId) -> bool {
+		Models::<T>::get(model_id).is_some_and(|model| model.status == ModelStatus::Active)
+	}
+
+	fn model_type(model_id: ModelId) -> Option<ModelType> {
+		Models::<T>::get(model_id).map(|model| model.model_type)
+	}
+}
+
+/// Records when accounts are created, so fresh accounts can be kept from rating
+impl<T: Config> frame_support::traits::OnNewAccount<T::AccountId> for Pallet<T> {
+	fn on_new_account(who: &T::AccountId) {
+		AccountCreated::<T>::insert(who, frame_system::Pallet::<T>::block_number());
+	}
+}
+
+/// Forgets the creation block of reaped accounts
+impl<T: Config> frame_support::traits::OnKilledAccount<T::AccountId> for Pallet<T> {
+	fn on_killed_account(who: &T::AccountId) {
+		AccountCreated::<T>::remove(who);
+	}
+}
@@ -0,0 +1,166 @@
+//! Consolidates the legacy split-storage layout into a single `Models` map
+//!
+//! The first version of the pallet kept every field of a model in a map of its own
+//! (`ModelOwner`, `ModelCID`, `ModelPrice`, ...). Chains still on that layout are at
+//! storage version 0 with no `Models`; this migration builds the version 0 `Models`
+//! entries out of the split maps, so [`super::v1`] onwards carry them to the current
+//! layout. It must run before them.
+//!
+//! The legacy layout reserved no deposit and recorded no name, description, license
+//! or size, so models come in with a zero deposit and size, empty texts and a
+//! `Proprietary` license. `ModelsByOwner` and `NextModelId` kept their layout.
+
+use super::{
+	v1::{v0, OldModelMetadata},
+	v4::v3::ModelsByOwner,
+};
+use crate::{Config, License, ModelId, ModelStatus, ModelType, Pallet};
+use frame_support::{
+	pallet_prelude::*,
+	traits::{GetStorageVersion, OnRuntimeUpgrade},
+};
+#[cfg(feature = "try-runtime")]
+use sp_std::vec::Vec;
+
+/// Storage items in the legacy layout
+pub mod old {
+	use super::*;
+
+	/// Owner of each model
+	#[frame_support::storage_alias]
+	pub type ModelOwner<T: Config> = StorageMap<
+		Pallet<T>,
+		Blake2_128Concat,
+		ModelId,
+		<T as frame_system::Config>::AccountId,
+		OptionQuery,
+	>;
+
+	/// IPFS CID of each model
+	#[frame_support::storage_alias]
+	pub type ModelCID<T: Config> = StorageMap<
+		Pallet<T>,
+		Blake2_128Concat,
+		ModelId,
+		BoundedVec<u8, ConstU32<128>>,
+		OptionQuery,
+	>;
+
+	/// Price of each model
+	#[frame_support::storage_alias]
+	pub type ModelPrice<T: Config> =
+		StorageMap<Pallet<T>, Blake2_128Concat, ModelId, u128, OptionQuery>;
+
+	/// Type of each model
+	#[frame_support::storage_alias]
+	pub type ModelTypeStorage<T: Config> =
+		StorageMap<Pallet<T>, Blake2_128Concat, ModelId, ModelType, OptionQuery>;
+
+	/// Status of each model; the legacy statuses are a prefix of [`ModelStatus`]
+	#[frame_support::storage_alias]
+	pub type ModelStatusStorage<T: Config> =
+		StorageMap<Pallet<T>, Blake2_128Concat, ModelId, ModelStatus, OptionQuery>;
+
+	/// Sum of the ratings of each model
+	#[frame_support::storage_alias]
+	pub type ModelRatingTotal<T: Config> =
+		StorageMap<Pallet<T>, Blake2_128Concat, ModelId, u64, ValueQuery>;
+
+	/// Number of ratings of each model
+	#[frame_support::storage_alias]
+	pub type ModelRatingCount<T: Config> =
+		StorageMap<Pallet<T>, Blake2_128Concat, ModelId, u32, ValueQuery>;
+}
+
+/// Whether the chain is on the legacy layout: storage version 0 with models in the
+/// split maps
+fn on_legacy_layout<T: Config>() -> bool {
+	Pallet::<T>::on_chain_storage_version() == 0
+		&& old::ModelOwner::<T>::iter_keys().next().is_some()
+}
+
+/// Builds a version 0 `Models` entry for every model in the split maps and removes
+/// them
+///
+/// Only runs on the legacy layout, so it can stay in the runtime's migrations. Models
+/// without a CID fitting `MaxCidLength` cannot be represented and are dropped along
+/// with their `ModelsByOwner` entry.
+pub struct MigrateLegacyLayout<T>(PhantomData<T>);
+
+impl<T: Config> OnRuntimeUpgrade for MigrateLegacyLayout<T> {
+	fn on_runtime_upgrade() -> Weight {
+		if !on_legacy_layout::<T>() {
+			return T::DbWeight::get().reads(2);
+		}
+
+		let mut models = 0u64;
+		for (model_id, owner) in old::ModelOwner::<T>::drain() {
+			models = models.saturating_add(1);
+			let ipfs_cid = old::ModelCID::<T>::take(model_id)
+				.and_then(|cid| BoundedVec::try_from(cid.into_inner()).ok());
+			let price = old::ModelPrice::<T>::take(model_id);
+			let model_type = old::ModelTypeStorage::<T>::take(model_id);
+			let status = old::ModelStatusStorage::<T>::take(model_id);
+			let total_rating = old::ModelRatingTotal::<T>::take(model_id);
+			let rating_count = old::ModelRatingCount::<T>::take(model_id);
+
+			let Some(ipfs_cid) = ipfs_cid else {
+				ModelsByOwner::<T>::remove(&owner, model_id);
+				continue;
+			};
+			v0::Models::<T>::insert(
+				model_id,
+				OldModelMetadata {
+					owner,
+					ipfs_cid,
+					name: Default::default(),
+					description: Default::default(),
+					model_type: model_type.unwrap_or_default(),
+					license: License::Proprietary,
+					size_bytes: 0,
+					deposit: 0,
+					price: price.unwrap_or_default(),
+					created_at: 0,
+					total_inferences: 0,
+					total_rating,
+					rating_count,
+					status: status.unwrap_or_default(),
+				},
+			);
+		}
+		T::DbWeight::get()
+			.reads_writes(models.saturating_mul(7).saturating_add(2), models.saturating_mul(8))
+	}
+
+	#[cfg(feature = "try-runtime")]
+	fn pre_upgrade() -> Result<Vec<u8>, sp_runtime::TryRuntimeError> {
+		// Models expected in the consolidated map, if there is anything to migrate
+		let expected = on_legacy_layout::<T>().then(|| {
+			old::ModelOwner::<T>::iter_keys()
+				.filter(|model_id| {
+					old::ModelCID::<T>::get(model_id)
+						.is_some_and(|cid| cid.len() <= T::MaxCidLength::get() as usize)
+				})
+				.count() as u64
+		});
+		Ok(expected.encode())
+	}
+
+	#[cfg(feature = "try-runtime")]
+	fn post_upgrade(state: Vec<u8>) -> Result<(), sp_runtime::TryRuntimeError> {
+		let expected =
+			Option::<u64>::decode(&mut &state[..]).map_err(|_| "invalid pre-upgrade state")?;
+		let Some(expected) = expected else { return Ok(()) };
+		ensure!(old::ModelOwner::<T>::iter_keys().next().is_none(), "legacy models left behind");
+		ensure!(
+			v0::Models::<T>::iter_keys().count() as u64 == expected,
+			"models lost in migration"
+		);
+		for (owner, model_id, ()) in ModelsByOwner::<T>::iter() {
+			let model =
+				v0::Models::<T>::get(model_id).ok_or("ModelsByOwner entry without model")?;
+			ensure!(model.owner == owner, "ModelsByOwner entry for wrong owner");
+		}
+		Ok(())
+	}
+}
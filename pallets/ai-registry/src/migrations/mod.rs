@@ -0,0 +1,57 @@
+//! Storage migrations for the AI Registry pallet
+//!
+//! Every change to the layout of an existing storage item (most commonly
+//! [`crate::ModelMetadata`]) must ship with a migration:
+//!
+//! 1. Bump [`crate::pallet::STORAGE_VERSION`].
+//! 2. Add a `vN` module here that keeps the old layout behind
+//!    `#[frame_support::storage_alias]` and implements
+//!    [`frame_support::traits::UncheckedOnRuntimeUpgrade`] to translate it,
+//!    with `pre_upgrade`/`post_upgrade` checks behind the `try-runtime` feature.
+//! 3. Expose it as `pub type MigrateToVN<T> = VersionedMigrationOf<T, Inner<T>, N - 1, N>;`
+//!    and add it to the runtime's `Migrations` tuple.
+//!
+//! Wrapping migrations in [`VersionedMigrationOf`] guarantees they only run
+//! against the storage version they were written for and bump it afterwards,
+//! so a migration left in the runtime by mistake is a no-op.
+//!
+//! Migrations are dry-run against live state with `try-runtime`; besides their
+//! own checks, [`crate::Pallet::do_try_state`] runs once they are applied.
+
+use crate::Pallet;
+use frame_support::migrations::VersionedMigration;
+
+pub mod legacy;
+pub mod v1;
+pub mod v10;
+pub mod v2;
+pub mod v3;
+pub mod v4;
+pub mod v5;
+pub mod v6;
+pub mod v7;
+pub mod v8;
+pub mod v9;
+
+/// Runs `Inner` only if the on-chain storage version is `FROM`, then sets it to `TO`
+pub type VersionedMigrationOf<T, Inner, const FROM: u16, const TO: u16> =
+	VersionedMigration<FROM, TO, Inner, Pallet<T>, <T as frame_system::Config>::DbWeight>;
+
+/// Migrations of this pallet not yet applied by every known runtime
+///
+/// Runtimes add this to their `Migrations` tuple so new migrations are picked
+/// up without touching runtime code. Chains on the legacy split-storage layout are
+/// consolidated first.
+pub type Unreleased<T> = (
+	legacy::MigrateLegacyLayout<T>,
+	v1::MigrateToV1<T>,
+	v2::MigrateToV2<T>,
+	v3::MigrateToV3<T>,
+	v4::MigrateToV4<T>,
+	v5::MigrateToV5<T>,
+	v6::MigrateToV6<T>,
+	v7::MigrateToV7<T>,
+	v8::MigrateToV8<T>,
+	v9::MigrateToV9<T>,
+	v10::MigrateToV10<T>,
+);
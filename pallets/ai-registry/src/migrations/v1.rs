@@ -0,0 +1,126 @@
+//! Adds [`crate::ModelMetadata::creator`], set to the current owner of every model
+
+use super::VersionedMigrationOf;
+use crate::{Config, LicenseOf, ModelId, ModelStatus, ModelType, Pallet};
+use codec::{Decode, Encode, MaxEncodedLen};
+use frame_support::{pallet_prelude::*, traits::UncheckedOnRuntimeUpgrade};
+use scale_info::TypeInfo;
+#[cfg(feature = "try-runtime")]
+use sp_std::vec::Vec;
+
+/// [`crate::ModelMetadata`] as stored before the creator was recorded
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+#[scale_info(skip_type_params(T))]
+#[codec(mel_bound())]
+pub struct OldModelMetadata<T: Config> {
+	pub owner: T::AccountId,
+	pub ipfs_cid: BoundedVec<u8, T::MaxCidLength>,
+	pub name: BoundedVec<u8, T::MaxNameLength>,
+	pub description: BoundedVec<u8, T::MaxDescriptionLength>,
+	pub model_type: ModelType,
+	pub license: LicenseOf<T>,
+	pub size_bytes: u64,
+	pub deposit: u128,
+	pub price: u128,
+	pub created_at: u64,
+	pub total_inferences: u64,
+	pub total_rating: u64,
+	pub rating_count: u32,
+	pub status: ModelStatus,
+}
+
+/// [`crate::ModelMetadata`] as stored at version 1, before forks were recorded
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+#[scale_info(skip_type_params(T))]
+#[codec(mel_bound())]
+pub struct ModelMetadataV1<T: Config> {
+	pub owner: T::AccountId,
+	pub creator: T::AccountId,
+	pub ipfs_cid: BoundedVec<u8, T::MaxCidLength>,
+	pub name: BoundedVec<u8, T::MaxNameLength>,
+	pub description: BoundedVec<u8, T::MaxDescriptionLength>,
+	pub model_type: ModelType,
+	pub license: LicenseOf<T>,
+	pub size_bytes: u64,
+	pub deposit: u128,
+	pub price: u128,
+	pub created_at: u64,
+	pub total_inferences: u64,
+	pub total_rating: u64,
+	pub rating_count: u32,
+	pub status: ModelStatus,
+}
+
+/// Storage items in the old layout
+pub mod v0 {
+	use super::*;
+
+	/// Models before the creator was recorded
+	#[frame_support::storage_alias]
+	pub type Models<T: Config> =
+		StorageMap<Pallet<T>, Blake2_128Concat, ModelId, OldModelMetadata<T>, OptionQuery>;
+}
+
+/// Storage items in the layout this migration writes, translated further by later
+/// migrations
+pub mod new {
+	use super::*;
+
+	/// Models with their creator
+	#[frame_support::storage_alias]
+	pub type Models<T: Config> =
+		StorageMap<Pallet<T>, Blake2_128Concat, ModelId, ModelMetadataV1<T>, OptionQuery>;
+}
+
+/// Records the owner of every model as its creator
+pub struct InnerMigrateV0ToV1<T>(PhantomData<T>);
+
+impl<T: Config> UncheckedOnRuntimeUpgrade for InnerMigrateV0ToV1<T> {
+	fn on_runtime_upgrade() -> Weight {
+		let mut translated = 0u64;
+		new::Models::<T>::translate::<OldModelMetadata<T>, _>(|_, old| {
+			translated = translated.saturating_add(1);
+			Some(ModelMetadataV1 {
+				creator: old.owner.clone(),
+				owner: old.owner,
+				ipfs_cid: old.ipfs_cid,
+				name: old.name,
+				description: old.description,
+				model_type: old.model_type,
+				license: old.license,
+				size_bytes: old.size_bytes,
+				deposit: old.deposit,
+				price: old.price,
+				created_at: old.created_at,
+				total_inferences: old.total_inferences,
+				total_rating: old.total_rating,
+				rating_count: old.rating_count,
+				status: old.status,
+			})
+		});
+		T::DbWeight::get().reads_writes(translated, translated)
+	}
+
+	#[cfg(feature = "try-runtime")]
+	fn pre_upgrade() -> Result<Vec<u8>, sp_runtime::TryRuntimeError> {
+		// Every model must decode in the old layout
+		let count = v0::Models::<T>::iter_keys().count() as u64;
+		ensure!(v0::Models::<T>::iter_values().count() as u64 == count, "undecodable model");
+		Ok(count.encode())
+	}
+
+	#[cfg(feature = "try-runtime")]
+	fn post_upgrade(state: Vec<u8>) -> Result<(), sp_runtime::TryRuntimeError> {
+		let count = u64::decode(&mut &state[..]).map_err(|_| "invalid pre-upgrade state")?;
+		let mut migrated = 0u64;
+		for model in new::Models::<T>::iter_values() {
+			ensure!(model.creator == model.owner, "creator not set to owner");
+			migrated += 1;
+		}
+		ensure!(migrated == count, "models lost in migration");
+		Ok(())
+	}
+}
+
+/// Adds the creator of every model, run only at storage version 0
+pub type MigrateToV1<T> = VersionedMigrationOf<T, InnerMigrateV0ToV1<T>, 0, 1>;
@@ -0,0 +1,45 @@
+//! Builds [`crate::pallet::RaterCounts`] for models rated before it existed
+//!
+//! Ratings used to be counted only by weight, in the model's `rating_count`. Every
+//! account with an entry in [`crate::pallet::Ratings`] counts once.
+
+use super::VersionedMigrationOf;
+use crate::{
+	pallet::{RaterCounts, Ratings},
+	Config,
+};
+use frame_support::{pallet_prelude::*, traits::UncheckedOnRuntimeUpgrade};
+#[cfg(feature = "try-runtime")]
+use sp_std::vec::Vec;
+
+/// Counts the raters of every rated model
+pub struct InnerMigrateV9ToV10<T>(PhantomData<T>);
+
+impl<T: Config> UncheckedOnRuntimeUpgrade for InnerMigrateV9ToV10<T> {
+	fn on_runtime_upgrade() -> Weight {
+		let mut ratings = 0u64;
+		for (model_id, _) in Ratings::<T>::iter_keys() {
+			ratings = ratings.saturating_add(1);
+			RaterCounts::<T>::mutate(model_id, |raters| raters.saturating_inc());
+		}
+		T::DbWeight::get().reads_writes(ratings.saturating_mul(2), ratings)
+	}
+
+	#[cfg(feature = "try-runtime")]
+	fn pre_upgrade() -> Result<Vec<u8>, sp_runtime::TryRuntimeError> {
+		Ok((Ratings::<T>::iter_keys().count() as u64).encode())
+	}
+
+	#[cfg(feature = "try-runtime")]
+	fn post_upgrade(state: Vec<u8>) -> Result<(), sp_runtime::TryRuntimeError> {
+		let count = u64::decode(&mut &state[..]).map_err(|_| "invalid pre-upgrade state")?;
+		ensure!(
+			RaterCounts::<T>::iter_values().map(u64::from).sum::<u64>() == count,
+			"raters left out of RaterCounts"
+		);
+		Ok(())
+	}
+}
+
+/// Counts the raters of every model, run only at storage version 9
+pub type MigrateToV10<T> = VersionedMigrationOf<T, InnerMigrateV9ToV10<T>, 9, 10>;
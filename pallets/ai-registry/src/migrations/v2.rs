@@ -0,0 +1,61 @@
+//! Adds [`crate::ModelMetadata::parent`], empty for every existing model
+
+use super::{v1::ModelMetadataV1, VersionedMigrationOf};
+use crate::{pallet::Models, Config, ModelMetadata};
+use frame_support::{pallet_prelude::*, traits::UncheckedOnRuntimeUpgrade};
+#[cfg(feature = "try-runtime")]
+use {super::v1::new as v1, sp_std::vec::Vec};
+
+/// Marks every existing model as an original rather than a fork
+pub struct InnerMigrateV1ToV2<T>(PhantomData<T>);
+
+impl<T: Config> UncheckedOnRuntimeUpgrade for InnerMigrateV1ToV2<T> {
+	fn on_runtime_upgrade() -> Weight {
+		let mut translated = 0u64;
+		Models::<T>::translate::<ModelMetadataV1<T>, _>(|_, old| {
+			translated = translated.saturating_add(1);
+			Some(ModelMetadata {
+				owner: old.owner,
+				creator: old.creator,
+				parent: None,
+				ipfs_cid: old.ipfs_cid,
+				name: old.name,
+				description: old.description,
+				model_type: old.model_type,
+				license: old.license,
+				size_bytes: old.size_bytes,
+				deposit: old.deposit,
+				price: old.price,
+				created_at: old.created_at,
+				total_inferences: old.total_inferences,
+				total_rating: old.total_rating,
+				rating_count: old.rating_count,
+				status: old.status,
+			})
+		});
+		T::DbWeight::get().reads_writes(translated, translated)
+	}
+
+	#[cfg(feature = "try-runtime")]
+	fn pre_upgrade() -> Result<Vec<u8>, sp_runtime::TryRuntimeError> {
+		// Every model must decode in the old layout
+		let count = v1::Models::<T>::iter_keys().count() as u64;
+		ensure!(v1::Models::<T>::iter_values().count() as u64 == count, "undecodable model");
+		Ok(count.encode())
+	}
+
+	#[cfg(feature = "try-runtime")]
+	fn post_upgrade(state: Vec<u8>) -> Result<(), sp_runtime::TryRuntimeError> {
+		let count = u64::decode(&mut &state[..]).map_err(|_| "invalid pre-upgrade state")?;
+		let mut migrated = 0u64;
+		for model in Models::<T>::iter_values() {
+			ensure!(model.parent.is_none(), "existing model marked as a fork");
+			migrated += 1;
+		}
+		ensure!(migrated == count, "models lost in migration");
+		Ok(())
+	}
+}
+
+/// Adds the parent of every model, run only at storage version 1
+pub type MigrateToV2<T> = VersionedMigrationOf<T, InnerMigrateV1ToV2<T>, 1, 2>;
@@ -0,0 +1,47 @@
+//! Adds [`crate::pallet::GlobalStats`], counted from the models in storage
+//!
+//! Inferences of models reaped before the upgrade and fees settled before it are
+//! not recorded anywhere else, so the backfilled totals start without them.
+
+use super::VersionedMigrationOf;
+use crate::{
+	pallet::{GlobalStats, Models},
+	Config, ModelStatus, RegistryStats,
+};
+use frame_support::{pallet_prelude::*, traits::UncheckedOnRuntimeUpgrade};
+use sp_runtime::Saturating;
+#[cfg(feature = "try-runtime")]
+use sp_std::vec::Vec;
+
+/// Counts the registered models, the active ones and their inferences
+pub struct InnerMigrateV2ToV3<T>(PhantomData<T>);
+
+impl<T: Config> UncheckedOnRuntimeUpgrade for InnerMigrateV2ToV3<T> {
+	fn on_runtime_upgrade() -> Weight {
+		let mut stats = RegistryStats::default();
+		for model in Models::<T>::iter_values() {
+			stats.total_models.saturating_inc();
+			if model.status == ModelStatus::Active {
+				stats.active_models.saturating_inc();
+			}
+			stats.total_inferences.saturating_accrue(model.total_inferences);
+		}
+		GlobalStats::<T>::put(stats);
+		T::DbWeight::get().reads_writes(stats.total_models, 1)
+	}
+
+	#[cfg(feature = "try-runtime")]
+	fn pre_upgrade() -> Result<Vec<u8>, sp_runtime::TryRuntimeError> {
+		Ok((Models::<T>::iter_keys().count() as u64).encode())
+	}
+
+	#[cfg(feature = "try-runtime")]
+	fn post_upgrade(state: Vec<u8>) -> Result<(), sp_runtime::TryRuntimeError> {
+		let count = u64::decode(&mut &state[..]).map_err(|_| "invalid pre-upgrade state")?;
+		ensure!(GlobalStats::<T>::get().total_models == count, "models miscounted");
+		Ok(())
+	}
+}
+
+/// Backfills the chain-wide counters, run only at storage version 2
+pub type MigrateToV3<T> = VersionedMigrationOf<T, InnerMigrateV2ToV3<T>, 2, 3>;
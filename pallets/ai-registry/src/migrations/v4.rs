@@ -0,0 +1,69 @@
+//! Replaces the `ModelsByOwner` double map with [`crate::pallet::OwnedModels`]
+//!
+//! Listing an owner's models took a prefix iteration over `ModelsByOwner`; the
+//! bounded set takes a single read. Owners holding more than `MaxModelsPerOwner`
+//! models keep them, but not all are listed, so runtimes must pick a bound above the
+//! largest holding on chain, which `pre_upgrade` checks.
+
+use super::VersionedMigrationOf;
+use crate::{pallet::OwnedModels, Config, ModelId, Pallet};
+use frame_support::{pallet_prelude::*, traits::UncheckedOnRuntimeUpgrade};
+#[cfg(feature = "try-runtime")]
+use sp_std::{collections::btree_map::BTreeMap, vec::Vec};
+
+/// Storage items as of version 3
+pub mod v3 {
+	use super::*;
+
+	/// Double map for querying models by owner
+	#[frame_support::storage_alias]
+	pub type ModelsByOwner<T: Config> = StorageDoubleMap<
+		Pallet<T>,
+		Blake2_128Concat,
+		<T as frame_system::Config>::AccountId,
+		Blake2_128Concat,
+		ModelId,
+		(),
+		OptionQuery,
+	>;
+}
+
+/// Moves every `ModelsByOwner` entry into its owner's set
+pub struct InnerMigrateV3ToV4<T>(PhantomData<T>);
+
+impl<T: Config> UncheckedOnRuntimeUpgrade for InnerMigrateV3ToV4<T> {
+	fn on_runtime_upgrade() -> Weight {
+		let mut moved = 0u64;
+		for (owner, model_id, ()) in v3::ModelsByOwner::<T>::drain() {
+			moved = moved.saturating_add(1);
+			// Models beyond the bound stay with their owner, unlisted
+			let _ = OwnedModels::<T>::mutate(&owner, |owned| owned.try_insert(model_id));
+		}
+		T::DbWeight::get().reads_writes(moved.saturating_mul(2), moved.saturating_mul(2))
+	}
+
+	#[cfg(feature = "try-runtime")]
+	fn pre_upgrade() -> Result<Vec<u8>, sp_runtime::TryRuntimeError> {
+		let mut held = BTreeMap::<T::AccountId, u32>::new();
+		for owner in v3::ModelsByOwner::<T>::iter_keys().map(|(owner, _)| owner) {
+			*held.entry(owner).or_default() += 1;
+		}
+		ensure!(
+			held.values().all(|count| *count <= T::MaxModelsPerOwner::get()),
+			"an owner holds more than MaxModelsPerOwner models"
+		);
+		Ok((held.values().map(|count| u64::from(*count)).sum::<u64>()).encode())
+	}
+
+	#[cfg(feature = "try-runtime")]
+	fn post_upgrade(state: Vec<u8>) -> Result<(), sp_runtime::TryRuntimeError> {
+		let count = u64::decode(&mut &state[..]).map_err(|_| "invalid pre-upgrade state")?;
+		ensure!(v3::ModelsByOwner::<T>::iter_keys().next().is_none(), "ModelsByOwner left behind");
+		let listed: u64 = OwnedModels::<T>::iter_values().map(|owned| owned.len() as u64).sum();
+		ensure!(listed == count, "models lost in migration");
+		Ok(())
+	}
+}
+
+/// Lists the models of every owner in a single entry, run only at storage version 3
+pub type MigrateToV4<T> = VersionedMigrationOf<T, InnerMigrateV3ToV4<T>, 3, 4>;
@@ -0,0 +1,45 @@
+//! Builds the [`crate::pallet::NameIndex`] for models registered before it existed
+//!
+//! Only the layout of the index is new; models keep their layout, so this only
+//! reads them.
+
+use super::VersionedMigrationOf;
+use crate::{
+	pallet::{Models, NameIndex},
+	Config, Pallet,
+};
+use frame_support::{pallet_prelude::*, traits::UncheckedOnRuntimeUpgrade};
+#[cfg(feature = "try-runtime")]
+use sp_std::vec::Vec;
+
+/// Indexes every model by the prefix of its name
+pub struct InnerMigrateV4ToV5<T>(PhantomData<T>);
+
+impl<T: Config> UncheckedOnRuntimeUpgrade for InnerMigrateV4ToV5<T> {
+	fn on_runtime_upgrade() -> Weight {
+		let mut models = 0u64;
+		for (model_id, model) in Models::<T>::iter() {
+			models = models.saturating_add(1);
+			NameIndex::<T>::insert(Pallet::<T>::name_prefix(&model.name), model_id, ());
+		}
+		T::DbWeight::get().reads_writes(models, models)
+	}
+
+	#[cfg(feature = "try-runtime")]
+	fn pre_upgrade() -> Result<Vec<u8>, sp_runtime::TryRuntimeError> {
+		Ok((Models::<T>::iter_keys().count() as u64).encode())
+	}
+
+	#[cfg(feature = "try-runtime")]
+	fn post_upgrade(state: Vec<u8>) -> Result<(), sp_runtime::TryRuntimeError> {
+		let count = u64::decode(&mut &state[..]).map_err(|_| "invalid pre-upgrade state")?;
+		ensure!(
+			NameIndex::<T>::iter_keys().count() as u64 == count,
+			"models left out of NameIndex"
+		);
+		Ok(())
+	}
+}
+
+/// Indexes every model's name, run only at storage version 4
+pub type MigrateToV5<T> = VersionedMigrationOf<T, InnerMigrateV4ToV5<T>, 4, 5>;
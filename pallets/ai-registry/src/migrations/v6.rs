@@ -0,0 +1,52 @@
+//! Builds [`crate::pallet::ModelsByCid`] for models registered before it existed
+//!
+//! Chains may already hold several models with the same CID. The lowest model ID,
+//! the first to register the artifact, is indexed; the others keep running but no
+//! longer own their CID.
+
+use super::VersionedMigrationOf;
+use crate::{
+	pallet::{Models, ModelsByCid},
+	Config, Pallet,
+};
+use frame_support::{pallet_prelude::*, traits::UncheckedOnRuntimeUpgrade};
+#[cfg(feature = "try-runtime")]
+use sp_std::{collections::btree_set::BTreeSet, vec::Vec};
+
+/// Indexes every model by the hash of its CID
+pub struct InnerMigrateV5ToV6<T>(PhantomData<T>);
+
+impl<T: Config> UncheckedOnRuntimeUpgrade for InnerMigrateV5ToV6<T> {
+	fn on_runtime_upgrade() -> Weight {
+		let mut models = 0u64;
+		for (model_id, model) in Models::<T>::iter() {
+			models = models.saturating_add(1);
+			ModelsByCid::<T>::mutate(Pallet::<T>::cid_key(&model.ipfs_cid), |entry| {
+				// `Models` iterates in hash order, not ID order
+				if entry.is_none_or(|indexed| model_id < indexed) {
+					*entry = Some(model_id);
+				}
+			});
+		}
+		T::DbWeight::get().reads_writes(models.saturating_mul(2), models)
+	}
+
+	#[cfg(feature = "try-runtime")]
+	fn pre_upgrade() -> Result<Vec<u8>, sp_runtime::TryRuntimeError> {
+		let cids: BTreeSet<_> = Models::<T>::iter_values().map(|model| model.ipfs_cid).collect();
+		Ok((cids.len() as u64).encode())
+	}
+
+	#[cfg(feature = "try-runtime")]
+	fn post_upgrade(state: Vec<u8>) -> Result<(), sp_runtime::TryRuntimeError> {
+		let count = u64::decode(&mut &state[..]).map_err(|_| "invalid pre-upgrade state")?;
+		ensure!(
+			ModelsByCid::<T>::iter_keys().count() as u64 == count,
+			"CIDs left out of ModelsByCid"
+		);
+		Ok(())
+	}
+}
+
+/// Indexes every model's CID, run only at storage version 5
+pub type MigrateToV6<T> = VersionedMigrationOf<T, InnerMigrateV5ToV6<T>, 5, 6>;
@@ -0,0 +1,61 @@
+//! Builds [`crate::pallet::OwnerModelNames`] for models registered before it existed
+//!
+//! Owners may already hold several models of the same name. The lowest model ID is
+//! indexed; the others keep their name but no longer hold it, so renaming them to
+//! it fails until the indexed model is renamed or gone.
+
+use super::VersionedMigrationOf;
+use crate::{
+	pallet::{Models, OwnerModelNames},
+	Config,
+};
+use frame_support::{pallet_prelude::*, traits::UncheckedOnRuntimeUpgrade};
+use sp_runtime::traits::Hash;
+#[cfg(feature = "try-runtime")]
+use sp_std::{collections::btree_set::BTreeSet, vec::Vec};
+
+/// Indexes the name of every named model under its owner
+pub struct InnerMigrateV6ToV7<T>(PhantomData<T>);
+
+impl<T: Config> UncheckedOnRuntimeUpgrade for InnerMigrateV6ToV7<T> {
+	fn on_runtime_upgrade() -> Weight {
+		let mut models = 0u64;
+		let mut named = 0u64;
+		for (model_id, model) in Models::<T>::iter() {
+			models = models.saturating_add(1);
+			if model.name.is_empty() {
+				continue;
+			}
+			named = named.saturating_add(1);
+			OwnerModelNames::<T>::mutate(&model.owner, T::Hashing::hash(&model.name), |entry| {
+				// `Models` iterates in hash order, not ID order
+				if entry.is_none_or(|indexed| model_id < indexed) {
+					*entry = Some(model_id);
+				}
+			});
+		}
+		T::DbWeight::get().reads_writes(models.saturating_add(named), named)
+	}
+
+	#[cfg(feature = "try-runtime")]
+	fn pre_upgrade() -> Result<Vec<u8>, sp_runtime::TryRuntimeError> {
+		let names: BTreeSet<_> = Models::<T>::iter_values()
+			.filter(|model| !model.name.is_empty())
+			.map(|model| (model.owner, model.name))
+			.collect();
+		Ok((names.len() as u64).encode())
+	}
+
+	#[cfg(feature = "try-runtime")]
+	fn post_upgrade(state: Vec<u8>) -> Result<(), sp_runtime::TryRuntimeError> {
+		let count = u64::decode(&mut &state[..]).map_err(|_| "invalid pre-upgrade state")?;
+		ensure!(
+			OwnerModelNames::<T>::iter_keys().count() as u64 == count,
+			"names left out of OwnerModelNames"
+		);
+		Ok(())
+	}
+}
+
+/// Indexes the model names of every owner, run only at storage version 6
+pub type MigrateToV7<T> = VersionedMigrationOf<T, InnerMigrateV6ToV7<T>, 6, 7>;
@@ -0,0 +1,58 @@
+//! Builds [`crate::pallet::RatingHistograms`] from the ratings left before it existed
+//!
+//! Every rating is counted with the weight it was left with. Ratings of reaped
+//! models are skipped.
+
+use super::VersionedMigrationOf;
+use crate::{
+	pallet::{Models, RatingHistograms, RatingWeights, Ratings},
+	Config,
+};
+use frame_support::{pallet_prelude::*, traits::UncheckedOnRuntimeUpgrade};
+use sp_runtime::Saturating;
+#[cfg(feature = "try-runtime")]
+use sp_std::{collections::btree_set::BTreeSet, vec::Vec};
+
+/// Counts every rating into its model's histogram
+pub struct InnerMigrateV7ToV8<T>(PhantomData<T>);
+
+impl<T: Config> UncheckedOnRuntimeUpgrade for InnerMigrateV7ToV8<T> {
+	fn on_runtime_upgrade() -> Weight {
+		let mut ratings = 0u64;
+		let mut counted = 0u64;
+		for (model_id, rater, record) in Ratings::<T>::iter() {
+			ratings = ratings.saturating_add(1);
+			if !Models::<T>::contains_key(model_id) {
+				continue;
+			}
+			counted = counted.saturating_add(1);
+			let weight = RatingWeights::<T>::get(model_id, &rater).unwrap_or(1);
+			RatingHistograms::<T>::mutate(model_id, |histogram| {
+				histogram[usize::from(record.rating.clamp(1, 5) - 1)].saturating_accrue(weight);
+			});
+		}
+		T::DbWeight::get().reads_writes(ratings.saturating_add(counted.saturating_mul(3)), counted)
+	}
+
+	#[cfg(feature = "try-runtime")]
+	fn pre_upgrade() -> Result<Vec<u8>, sp_runtime::TryRuntimeError> {
+		let rated: BTreeSet<_> = Ratings::<T>::iter_keys()
+			.map(|(model_id, _)| model_id)
+			.filter(|model_id| Models::<T>::contains_key(model_id))
+			.collect();
+		Ok((rated.len() as u64).encode())
+	}
+
+	#[cfg(feature = "try-runtime")]
+	fn post_upgrade(state: Vec<u8>) -> Result<(), sp_runtime::TryRuntimeError> {
+		let count = u64::decode(&mut &state[..]).map_err(|_| "invalid pre-upgrade state")?;
+		ensure!(
+			RatingHistograms::<T>::iter_keys().count() as u64 == count,
+			"rated models left out of RatingHistograms"
+		);
+		Ok(())
+	}
+}
+
+/// Builds the rating histogram of every model, run only at storage version 7
+pub type MigrateToV8<T> = VersionedMigrationOf<T, InnerMigrateV7ToV8<T>, 7, 8>;
@@ -0,0 +1,65 @@
+//! Moves models suspended by reports from `Paused` to [`crate::ModelStatus::UnderReview`]
+//!
+//! Suspended models used to be paused, with their previous status kept in
+//! [`crate::pallet::UnderReview`]. Entries of models that left `Paused` since,
+//! e.g. because their owner deactivated them, are no longer under review and are
+//! dropped.
+
+use super::VersionedMigrationOf;
+use crate::{
+	pallet::{Models, UnderReview},
+	Config, ModelStatus,
+};
+use frame_support::{pallet_prelude::*, traits::UncheckedOnRuntimeUpgrade};
+#[cfg(feature = "try-runtime")]
+use sp_std::vec::Vec;
+
+/// Puts every suspended model under review
+pub struct InnerMigrateV8ToV9<T>(PhantomData<T>);
+
+impl<T: Config> UncheckedOnRuntimeUpgrade for InnerMigrateV8ToV9<T> {
+	fn on_runtime_upgrade() -> Weight {
+		let mut suspended = 0u64;
+		for model_id in UnderReview::<T>::iter_keys().collect::<sp_std::vec::Vec<_>>() {
+			suspended = suspended.saturating_add(1);
+			let paused = Models::<T>::mutate(model_id, |maybe_model| match maybe_model {
+				Some(model) if model.status == ModelStatus::Paused => {
+					model.status = ModelStatus::UnderReview;
+					true
+				}
+				_ => false,
+			});
+			if !paused {
+				UnderReview::<T>::remove(model_id);
+			}
+		}
+		T::DbWeight::get().reads_writes(suspended.saturating_mul(2), suspended)
+	}
+
+	#[cfg(feature = "try-runtime")]
+	fn pre_upgrade() -> Result<Vec<u8>, sp_runtime::TryRuntimeError> {
+		let paused = UnderReview::<T>::iter_keys()
+			.filter(|model_id| {
+				Models::<T>::get(model_id).is_some_and(|model| model.status == ModelStatus::Paused)
+			})
+			.count() as u64;
+		Ok(paused.encode())
+	}
+
+	#[cfg(feature = "try-runtime")]
+	fn post_upgrade(state: Vec<u8>) -> Result<(), sp_runtime::TryRuntimeError> {
+		let count = u64::decode(&mut &state[..]).map_err(|_| "invalid pre-upgrade state")?;
+		ensure!(
+			Models::<T>::iter_values()
+				.filter(|model| model.status == ModelStatus::UnderReview)
+				.count() as u64
+				== count,
+			"suspended models not moved under review"
+		);
+		ensure!(UnderReview::<T>::iter_keys().count() as u64 == count, "stale UnderReview entries");
+		Ok(())
+	}
+}
+
+/// Puts suspended models under review, run only at storage version 8
+pub type MigrateToV9<T> = VersionedMigrationOf<T, InnerMigrateV8ToV9<T>, 8, 9>;
@@ -1,11 +1,18 @@
 //! Mock runtime for AI Registry pallet tests
 
 use crate as pallet_ai_registry;
+use crate::{ModelId, ModelStatus};
 use frame_support::{
-	derive_impl, parameter_types,
-	traits::{ConstU128, ConstU32},
+	derive_impl, ensure, parameter_types,
+	storage::types::ValueQuery,
+	traits::{ConstU128, ConstU32, ConstU64, ConstU8, Contains, SortedMembers},
+	Blake2_128Concat, PalletId,
+};
+use frame_system::{EnsureRoot, EnsureSigned};
+use sp_runtime::{
+	testing::{TestSignature, UintAuthorityId},
+	BuildStorage, DispatchResult, Perbill,
 };
-use sp_runtime::BuildStorage;
 
 type Block = frame_system::mocking::MockBlock<Test>;
 
@@ -41,6 +48,8 @@ mod runtime {
 impl frame_system::Config for Test {
 	type Block = Block;
 	type AccountData = pallet_balances::AccountData<u128>;
+	type OnNewAccount = AIRegistry;
+	type OnKilledAccount = AIRegistry;
 }
 
 #[derive_impl(pallet_balances::config_preludes::TestDefaultConfig)]
@@ -56,10 +65,26 @@ parameter_types! {
 	pub const MaxCidLength: u32 = 128;
 	pub const MaxNameLength: u32 = 256;
 	pub const MaxDescriptionLength: u32 = 1024;
+	pub const DepositPerByte: u128 = 1;
+	pub const SlashFraction: Perbill = Perbill::from_percent(50);
+	pub const ProposalShareThreshold: Perbill = Perbill::from_percent(20);
+	pub const CreatorRoyalty: Perbill = Perbill::from_percent(10);
+	pub const MaxParentShare: Perbill = Perbill::from_percent(50);
+	pub const RegistryPalletId: PalletId = PalletId(*b"py/aireg");
+	pub const RebatePerEra: Perbill = Perbill::from_percent(10);
+	pub const IncentiveSource: u64 = 99;
+	pub static MinRaterAge: u64 = 0;
+	pub static MinRaterBalance: u128 = 0;
+	pub static UnverifiedRaters: Vec<u64> = vec![];
+	pub static PauseRatingFloor: u8 = 0;
+	pub static RequireRegistrationApproval: bool = false;
+	pub static RequireReceiptToRate: bool = false;
+	pub static Developers: Vec<u64> = vec![];
 }
 
 impl pallet_ai_registry::Config for Test {
 	type RuntimeEvent = RuntimeEvent;
+	type RuntimeCall = RuntimeCall;
 	type WeightInfo = ();
 	type Currency = Balances;
 	type MinimumModelStake = MinimumModelStake;
@@ -67,6 +92,182 @@ impl pallet_ai_registry::Config for Test {
 	type MaxCidLength = MaxCidLength;
 	type MaxNameLength = MaxNameLength;
 	type MaxDescriptionLength = MaxDescriptionLength;
+	type DepositPerByte = DepositPerByte;
+	type RegistrationOrigin = EnsureSigned<u64>;
+	type GovernanceOrigin = EnsureRoot<u64>;
+	type AdminSunsetBlock = ConstU64<100>;
+	type SunsetExtensionOrigin = EnsureRoot<u64>;
+	type MaxCallbacksPerModel = ConstU32<2>;
+	type InferenceReceipts = MockReceipts;
+	type RequireReceiptToRate = RequireReceiptToRate;
+	type RatingWeight = pallet_ai_registry::QuadraticRatingWeight<MockStakes, ConstU128<100>>;
+	type MinRaterAge = MinRaterAge;
+	type MinRaterBalance = MinRaterBalance;
+	type MaxRatingsPerEra = ConstU32<3>;
+	type RatingWindow = ConstU32<4>;
+	type PauseMinRatings = ConstU32<3>;
+	type PauseRatingFloor = PauseRatingFloor;
+	type ReportThreshold = ConstU32<2>;
+	type AppealBond = ConstU128<500>;
+	type MaxDeactivationGrace = ConstU64<100>;
+	type MaxDeactivationsPerBlock = ConstU32<2>;
+	type DeactivationNotice = ConstU64<5>;
+	type MaxPriceChangesPerBlock = ConstU32<2>;
+	type RequireRegistrationApproval = RequireRegistrationApproval;
+	type RegistrationApprovalOrigin = EnsureRoot<u64>;
+	type DeveloperAllowlist = MockDevelopers;
+	type MaxOrganizationMembers = ConstU32<3>;
+	type VerifiedRaters = MockVerifiedRaters;
+	type InferenceOrigin = EnsureRoot<u64>;
+	type ModelNfts = MockNfts;
+	type OnModelRegistered = MockHooks;
+	type OnModelStatusChanged = MockHooks;
+	type MaxShareholders = ConstU32<4>;
+	type ProposalShareThreshold = ProposalShareThreshold;
+	type CreatorRoyalty = CreatorRoyalty;
+	type MaxDatasetsPerModel = ConstU32<3>;
+	type MaxParentShare = MaxParentShare;
+	type MaxModelsPerCollection = ConstU32<3>;
+	type MaxCollectionsPerModel = ConstU32<2>;
+	type MaxBookmarks = ConstU32<3>;
+	type MaxModelsPerOwner = ConstU32<16>;
+	type MaxWatchers = ConstU32<3>;
+	type WatchersPerEvent = ConstU32<2>;
+	type OwnerSignature = TestSignature;
+	type OwnerSigner = UintAuthorityId;
+	type MaxExportBatch = ConstU32<3>;
+	type RentEraLength = ConstU64<10>;
+	type RentFreeEras = ConstU32<2>;
+	type RentPerEra = ConstU128<600>;
+	type RentReapDelay = ConstU64<5>;
+	type SlashOrigin = EnsureRoot<u64>;
+	type SlashFraction = SlashFraction;
+	type Slash = ();
+	type PalletId = RegistryPalletId;
+	type RebateRatingThreshold = ConstU8<4>;
+	type RebateMinRatings = ConstU32<2>;
+	type RebatePerEra = RebatePerEra;
+	type IncentiveOrigin = EnsureRoot<u64>;
+	type IncentiveSource = IncentiveSource;
+	type MaxRewardedModels = ConstU32<2>;
+	type RewardMinRatings = ConstU32<2>;
+	type RewardPerEra = ConstU128<90>;
+	type MaxUpdateHistory = ConstU32<3>;
+	type UsageWindow = ConstU32<3>;
+	type LeaderboardSize = ConstU32<3>;
+	type NamePrefixLength = ConstU32<3>;
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkHelper = MockBenchmarkHelper;
+}
+
+/// Account 2 holds receipt 42 for every model; nobody else has paid
+pub struct MockReceipts;
+impl pallet_ai_registry::InferenceReceipts<u64> for MockReceipts {
+	fn receipt_for(who: &u64, _model_id: pallet_ai_registry::ModelId) -> Option<u64> {
+		(*who == 2).then_some(42)
+	}
+
+	#[cfg(feature = "runtime-benchmarks")]
+	fn create_receipt(_who: &u64, _model_id: pallet_ai_registry::ModelId) {}
+}
+
+/// Every account but the [`UnverifiedRaters`]
+pub struct MockVerifiedRaters;
+impl Contains<u64> for MockVerifiedRaters {
+	fn contains(who: &u64) -> bool {
+		!UnverifiedRaters::get().contains(who)
+	}
+}
+
+/// The [`Developers`] on the allowlist
+pub struct MockDevelopers;
+impl SortedMembers<u64> for MockDevelopers {
+	fn sorted_members() -> Vec<u64> {
+		let mut developers = Developers::get();
+		developers.sort();
+		developers
+	}
+
+	#[cfg(feature = "runtime-benchmarks")]
+	fn add(who: &u64) {
+		Developers::mutate(|developers| developers.push(*who));
+	}
+}
+
+/// Stake of each rater, the same in every model
+#[frame_support::storage_alias]
+pub type RaterStakes = StorageMap<MockStakes, Blake2_128Concat, u64, u128, ValueQuery>;
+
+/// Rater stakes kept in [`RaterStakes`]
+pub struct MockStakes;
+impl pallet_ai_registry::RaterStake<u64> for MockStakes {
+	fn stake_of(who: &u64, _model_id: ModelId) -> u128 {
+		RaterStakes::get(who)
+	}
+}
+
+/// Holders of model NFTs
+#[frame_support::storage_alias]
+pub type NftOwners = StorageMap<MockNfts, Blake2_128Concat, ModelId, u64>;
+
+/// NFTs kept in [`NftOwners`]
+pub struct MockNfts;
+impl pallet_ai_registry::ModelNfts<u64> for MockNfts {
+	fn mint(model_id: ModelId, owner: &u64) -> DispatchResult {
+		ensure!(!NftOwners::contains_key(model_id), "NFT already minted");
+		NftOwners::insert(model_id, owner);
+		Ok(())
+	}
+
+	fn burn(model_id: ModelId) -> DispatchResult {
+		NftOwners::take(model_id).map(|_| ()).ok_or("Unknown NFT".into())
+	}
+
+	fn owner(model_id: ModelId) -> Option<u64> {
+		NftOwners::get(model_id)
+	}
+
+	fn transfer(model_id: ModelId, to: &u64) -> DispatchResult {
+		NftOwners::try_mutate(model_id, |owner| match owner {
+			Some(owner) => {
+				*owner = *to;
+				Ok(())
+			}
+			None => Err("Unknown NFT".into()),
+		})
+	}
+}
+
+/// Models reported to [`MockHooks`] as registered, with their owners
+#[frame_support::storage_alias]
+pub type RegisteredModels = StorageValue<MockHooks, Vec<(ModelId, u64)>, ValueQuery>;
+
+/// Status changes reported to [`MockHooks`]
+#[frame_support::storage_alias]
+pub type StatusChanges =
+	StorageValue<MockHooks, Vec<(ModelId, ModelStatus, ModelStatus)>, ValueQuery>;
+
+/// Registration and status hooks logging into [`RegisteredModels`] and [`StatusChanges`]
+pub struct MockHooks;
+impl pallet_ai_registry::OnModelRegistered<u64> for MockHooks {
+	fn on_model_registered(model_id: ModelId, owner: &u64) {
+		RegisteredModels::append((model_id, *owner));
+	}
+}
+
+impl pallet_ai_registry::OnModelStatusChanged for MockHooks {
+	fn on_model_status_changed(model_id: ModelId, old: ModelStatus, new: ModelStatus) {
+		StatusChanges::append((model_id, old, new));
+	}
+}
+
+#[cfg(feature = "runtime-benchmarks")]
+pub struct MockBenchmarkHelper;
+#[cfg(feature = "runtime-benchmarks")]
+impl pallet_ai_registry::BenchmarkHelper<TestSignature, u64> for MockBenchmarkHelper {
+	fn create_signature(_entropy: &[u8], msg: &[u8]) -> (TestSignature, u64) {
+		(TestSignature(1, msg.to_vec()), 1)
+	}
 }
 
 // Build genesis storage according to the mock runtime.
@@ -78,14 +279,19 @@ pub fn new_test_ext() -> sp_io::TestExternalities {
 			(1, 10000), // Account with sufficient balance
 			(2, 10000),
 			(3, 10000),
-			(4, 500),   // Account with insufficient balance
+			(4, 500),    // Account with insufficient balance
+			(99, 10000), // Incentive source
 		],
-		dev_accounts: vec![],
+		dev_accounts: None,
 	}
 	.assimilate_storage(&mut t)
 	.unwrap();
 
 	let mut ext = sp_io::TestExternalities::new(t);
-	ext.execute_with(|| System::set_block_number(1));
+	ext.execute_with(|| {
+		System::set_block_number(1);
+		// Stamped by the genesis build of a full runtime
+		crate::pallet::STORAGE_VERSION.put::<AIRegistry>();
+	});
 	ext
 }
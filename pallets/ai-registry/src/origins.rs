@@ -0,0 +1,34 @@
+//! Origin helpers for the AI Registry pallet
+
+use crate::{Config, Pallet};
+use core::marker::PhantomData;
+use frame_support::traits::EnsureOrigin;
+use frame_system::EnsureRoot;
+#[cfg(feature = "runtime-benchmarks")]
+use frame_system::RawOrigin;
+
+/// Ensures the origin is root, but only until the admin sunset block.
+///
+/// Runtimes combine this with their collective or referenda origin so that sudo
+/// can administer the registry during bootstrapping and governance takes over
+/// once [`crate::AdminSunset`] has passed.
+pub struct EnsureRootBeforeSunset<T>(PhantomData<T>);
+
+impl<T: Config> EnsureOrigin<T::RuntimeOrigin> for EnsureRootBeforeSunset<T> {
+	type Success = ();
+
+	fn try_origin(o: T::RuntimeOrigin) -> Result<Self::Success, T::RuntimeOrigin> {
+		if Pallet::<T>::admin_sunset_passed() {
+			return Err(o);
+		}
+		EnsureRoot::<T::AccountId>::try_origin(o)
+	}
+
+	#[cfg(feature = "runtime-benchmarks")]
+	fn try_successful_origin() -> Result<T::RuntimeOrigin, ()> {
+		if Pallet::<T>::admin_sunset_passed() {
+			return Err(());
+		}
+		Ok(RawOrigin::Root.into())
+	}
+}
@@ -1,11 +1,28 @@
 //! Unit tests for AI Registry pallet
 
 use crate::{
+	callback_events,
 	mock::*,
-	pallet::{Error, Event, Models, ModelsByOwner, NextModelId},
-	ModelStatus, ModelType,
+	pallet::{
+		AccountCreated, AdminSunset, Bookmarks, Collections, Datasets, EraIncentives, EraRewards,
+		Error, Event, ForcedDeactivations, GlobalStats, ImportedFrom, ImportedPackets,
+		IncentiveEarnings, IncentiveFunds, IncentiveUsage, LastActivity, LockedMetadata,
+		MetadataProposals, ModelCallbacks, ModelCollections, ModelDatasets, ModelReports, Models,
+		ModelsByCid, MostUsedModels, NameIndex, NextModelId, Organizations, OwnedModels,
+		OwnerModelNames, ParentShare, PendingDeactivations, PendingPriceChanges, RatedInEra,
+		RaterCounts, RatingHistograms, RatingReviews, RatingWeights, Ratings, RebatableFees,
+		RebatedUntil, RecentRatings, RecentRevenue, RecentUsage, RentExhausted, ReportCounts,
+		ScheduledDeactivations, ScheduledPriceChanges, Shareholders, Successors, TopModels,
+		UnderReview, UpdateHistory, Watchers,
+	},
+	AiRegistryInspect, CallbackDescriptor, CallbackEndpoint, DeveloperPage, License, LicenseOf,
+	MetadataField, MigrationPacket, ModelChange, ModelProvenance, ModelStats, ModelStatus,
+	ModelType, OrganizationRole, RatingRecord, RegistryStats, RevenueHistory, WeightInfo,
+	MIGRATION_PACKET_CONTEXT,
 };
-use frame_support::{assert_noop, assert_ok};
+use codec::Encode;
+use frame_support::{assert_noop, assert_ok, traits::Hooks};
+use sp_runtime::{testing::TestSignature, Perbill};
 
 #[test]
 fn register_model_works() {
@@ -22,7 +39,10 @@ fn register_model_works() {
 			name.clone(),
 			description,
 			ModelType::Classification,
-			500
+			500,
+			License::Mit,
+			1_000,
+			vec![]
 		));
 
 		// Check storage
@@ -30,12 +50,13 @@ fn register_model_works() {
 		assert_eq!(model.owner, 1);
 		assert_eq!(model.model_type, ModelType::Classification);
 		assert_eq!(model.price, 500);
+		assert_eq!(model.license, License::Mit);
 		assert_eq!(model.status, ModelStatus::Active);
 		assert_eq!(model.total_inferences, 0);
 		assert_eq!(model.rating_count, 0);
 
-		// Check double map
-		assert!(ModelsByOwner::<Test>::get(1, 0).is_some());
+		// Check owner's models
+		assert!(OwnedModels::<Test>::get(1).contains(&0));
 
 		// Check next ID incremented
 		assert_eq!(NextModelId::<Test>::get(), 1);
@@ -66,7 +87,10 @@ fn register_model_with_cidv1_works() {
 			name,
 			description,
 			ModelType::Generative,
-			1000
+			1000,
+			License::Mit,
+			1_000,
+			vec![]
 		));
 
 		assert_eq!(NextModelId::<Test>::get(), 1);
@@ -88,7 +112,10 @@ fn register_model_fails_with_invalid_cid() {
 				name,
 				description,
 				ModelType::Classification,
-				500
+				500,
+				License::Mit,
+				1_000,
+				vec![]
 			),
 			Error::<Test>::InvalidIPFSCID
 		);
@@ -110,13 +137,61 @@ fn register_model_fails_with_insufficient_balance() {
 				name,
 				description,
 				ModelType::Classification,
-				500
+				500,
+				License::Mit,
+				1_000,
+				vec![]
 			),
 			Error::<Test>::InsufficientStake
 		);
 	});
 }
 
+#[test]
+fn register_model_with_custom_license_works() {
+	new_test_ext().execute_with(|| {
+		let ipfs_cid = b"QmYwAPJzv5CZsnA625s3Xf2nemtYgPpHdWEz79ojWnPbdG".to_vec();
+		let license_cid = b"bafybeigdyrzt5sfp7udm7hu76uh7y26nf3efuylqabf3oclgtqy55fbzdi".to_vec();
+
+		assert_ok!(AIRegistry::register_model(
+			RuntimeOrigin::signed(1),
+			ipfs_cid,
+			b"Model".to_vec(),
+			b"Description".to_vec(),
+			ModelType::Generative,
+			500,
+			License::Custom(license_cid.clone().try_into().unwrap()),
+			1_000,
+			vec![]
+		));
+
+		let model = Models::<Test>::get(0).unwrap();
+		assert_eq!(model.license, License::Custom(license_cid.try_into().unwrap()));
+	});
+}
+
+#[test]
+fn register_model_fails_with_invalid_license_cid() {
+	new_test_ext().execute_with(|| {
+		let ipfs_cid = b"QmYwAPJzv5CZsnA625s3Xf2nemtYgPpHdWEz79ojWnPbdG".to_vec();
+
+		assert_noop!(
+			AIRegistry::register_model(
+				RuntimeOrigin::signed(1),
+				ipfs_cid,
+				b"Model".to_vec(),
+				b"Description".to_vec(),
+				ModelType::Generative,
+				500,
+				License::Custom(b"not-a-cid".to_vec().try_into().unwrap()),
+				1_000,
+				vec![]
+			),
+			Error::<Test>::InvalidLicenseCID
+		);
+	});
+}
+
 #[test]
 fn update_model_metadata_works() {
 	new_test_ext().execute_with(|| {
@@ -128,7 +203,10 @@ fn update_model_metadata_works() {
 			b"Model".to_vec(),
 			b"Description".to_vec(),
 			ModelType::Classification,
-			500
+			500,
+			License::Mit,
+			1_000,
+			vec![]
 		));
 
 		// Update price
@@ -137,6 +215,7 @@ fn update_model_metadata_works() {
 			0,
 			Some(1000),
 			None,
+			None,
 			None
 		));
 
@@ -150,6 +229,7 @@ fn update_model_metadata_works() {
 			0,
 			None,
 			Some(new_desc.clone()),
+			None,
 			None
 		));
 
@@ -162,7 +242,8 @@ fn update_model_metadata_works() {
 			0,
 			None,
 			None,
-			Some(ModelStatus::Paused)
+			Some(ModelStatus::Paused),
+			None
 		));
 
 		let model = Models::<Test>::get(0).unwrap();
@@ -181,22 +262,88 @@ fn update_model_fails_with_unauthorized_access() {
 			b"Model".to_vec(),
 			b"Description".to_vec(),
 			ModelType::Classification,
-			500
+			500,
+			License::Mit,
+			1_000,
+			vec![]
 		));
 
 		// Try to update with account 2
 		assert_noop!(
-			AIRegistry::update_model_metadata(RuntimeOrigin::signed(2), 0, Some(1000), None, None),
+			AIRegistry::update_model_metadata(
+				RuntimeOrigin::signed(2),
+				0,
+				Some(1000),
+				None,
+				None,
+				None
+			),
 			Error::<Test>::UnauthorizedAccess
 		);
 	});
 }
 
+#[test]
+fn owners_can_relicense_models() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		register_test_model(1);
+
+		assert_noop!(
+			AIRegistry::update_model_metadata(
+				RuntimeOrigin::signed(1),
+				0,
+				None,
+				None,
+				None,
+				Some(License::Custom(b"not-a-cid".to_vec().try_into().unwrap()))
+			),
+			Error::<Test>::InvalidLicenseCID
+		);
+		assert_ok!(AIRegistry::update_model_metadata(
+			RuntimeOrigin::signed(1),
+			0,
+			None,
+			None,
+			None,
+			Some(License::Apache2)
+		));
+		assert_eq!(Models::<Test>::get(0).unwrap().license, License::Apache2);
+		let history = UpdateHistory::<Test>::get(0);
+		assert_eq!(history[0].field, MetadataField::License);
+		assert_eq!(
+			history[0].previous,
+			sp_io::hashing::blake2_256(&LicenseOf::<Test>::Mit.encode()).into()
+		);
+
+		// Locked models keep their license
+		assert_ok!(AIRegistry::lock_metadata(RuntimeOrigin::signed(1), 0));
+		assert_noop!(
+			AIRegistry::update_model_metadata(
+				RuntimeOrigin::signed(1),
+				0,
+				None,
+				None,
+				None,
+				Some(License::OpenRail)
+			),
+			Error::<Test>::MetadataLocked
+		);
+	});
+}
+
 #[test]
 fn update_nonexistent_model_fails() {
 	new_test_ext().execute_with(|| {
 		assert_noop!(
-			AIRegistry::update_model_metadata(RuntimeOrigin::signed(1), 999, Some(1000), None, None),
+			AIRegistry::update_model_metadata(
+				RuntimeOrigin::signed(1),
+				999,
+				Some(1000),
+				None,
+				None,
+				None
+			),
 			Error::<Test>::ModelNotFound
 		);
 	});
@@ -213,11 +360,14 @@ fn deactivate_model_works() {
 			b"Model".to_vec(),
 			b"Description".to_vec(),
 			ModelType::Classification,
-			500
+			500,
+			License::Mit,
+			1_000,
+			vec![]
 		));
 
 		// Deactivate
-		assert_ok!(AIRegistry::deactivate_model(RuntimeOrigin::signed(1), 0));
+		assert_ok!(AIRegistry::deactivate_model(RuntimeOrigin::signed(1), 0, None));
 
 		let model = Models::<Test>::get(0).unwrap();
 		assert_eq!(model.status, ModelStatus::Deactivated);
@@ -238,12 +388,15 @@ fn deactivate_model_fails_unauthorized() {
 			b"Model".to_vec(),
 			b"Description".to_vec(),
 			ModelType::Classification,
-			500
+			500,
+			License::Mit,
+			1_000,
+			vec![]
 		));
 
 		// Try to deactivate with account 2
 		assert_noop!(
-			AIRegistry::deactivate_model(RuntimeOrigin::signed(2), 0),
+			AIRegistry::deactivate_model(RuntimeOrigin::signed(2), 0, None),
 			Error::<Test>::UnauthorizedAccess
 		);
 	});
@@ -260,18 +413,21 @@ fn rate_model_works() {
 			b"Model".to_vec(),
 			b"Description".to_vec(),
 			ModelType::Classification,
-			500
+			500,
+			License::Mit,
+			1_000,
+			vec![]
 		));
 
 		// Rate with 5 stars
-		assert_ok!(AIRegistry::rate_model(RuntimeOrigin::signed(2), 0, 5));
+		assert_ok!(AIRegistry::rate_model(RuntimeOrigin::signed(2), 0, 5, None));
 
 		let model = Models::<Test>::get(0).unwrap();
 		assert_eq!(model.total_rating, 5);
 		assert_eq!(model.rating_count, 1);
 
 		// Rate again with 3 stars
-		assert_ok!(AIRegistry::rate_model(RuntimeOrigin::signed(3), 0, 3));
+		assert_ok!(AIRegistry::rate_model(RuntimeOrigin::signed(3), 0, 3, None));
 
 		let model = Models::<Test>::get(0).unwrap();
 		assert_eq!(model.total_rating, 8);
@@ -294,18 +450,21 @@ fn rate_model_fails_with_invalid_rating() {
 			b"Model".to_vec(),
 			b"Description".to_vec(),
 			ModelType::Classification,
-			500
+			500,
+			License::Mit,
+			1_000,
+			vec![]
 		));
 
 		// Try to rate with 0 (invalid)
 		assert_noop!(
-			AIRegistry::rate_model(RuntimeOrigin::signed(2), 0, 0),
+			AIRegistry::rate_model(RuntimeOrigin::signed(2), 0, 0, None),
 			Error::<Test>::InvalidRating
 		);
 
 		// Try to rate with 6 (invalid)
 		assert_noop!(
-			AIRegistry::rate_model(RuntimeOrigin::signed(2), 0, 6),
+			AIRegistry::rate_model(RuntimeOrigin::signed(2), 0, 6, None),
 			Error::<Test>::InvalidRating
 		);
 	});
@@ -315,7 +474,7 @@ fn rate_model_fails_with_invalid_rating() {
 fn rate_nonexistent_model_fails() {
 	new_test_ext().execute_with(|| {
 		assert_noop!(
-			AIRegistry::rate_model(RuntimeOrigin::signed(1), 999, 5),
+			AIRegistry::rate_model(RuntimeOrigin::signed(1), 999, 5, None),
 			Error::<Test>::ModelNotFound
 		);
 	});
@@ -324,17 +483,18 @@ fn rate_nonexistent_model_fails() {
 #[test]
 fn multiple_models_registration_works() {
 	new_test_ext().execute_with(|| {
-		let ipfs_cid = b"QmYwAPJzv5CZsnA625s3Xf2nemtYgPpHdWEz79ojWnPbdG".to_vec();
-
 		// Register 3 models
 		for i in 0..3 {
 			assert_ok!(AIRegistry::register_model(
 				RuntimeOrigin::signed(1),
-				ipfs_cid.clone(),
+				cid(i),
 				format!("Model {}", i).as_bytes().to_vec(),
 				b"Description".to_vec(),
 				ModelType::Classification,
-				500 * (i as u128 + 1)
+				500 * (i as u128 + 1),
+				License::Mit,
+				1_000,
+				vec![]
 			));
 		}
 
@@ -347,9 +507,7 @@ fn multiple_models_registration_works() {
 		assert_eq!(NextModelId::<Test>::get(), 3);
 
 		// Check ownership mapping
-		assert!(ModelsByOwner::<Test>::get(1, 0).is_some());
-		assert!(ModelsByOwner::<Test>::get(1, 1).is_some());
-		assert!(ModelsByOwner::<Test>::get(1, 2).is_some());
+		assert_eq!(AIRegistry::models_of(&1), vec![0, 1, 2]);
 	});
 }
 
@@ -364,21 +522,30 @@ fn increment_inference_count_works() {
 			b"Model".to_vec(),
 			b"Description".to_vec(),
 			ModelType::Classification,
-			500
+			500,
+			License::Mit,
+			1_000,
+			vec![]
 		));
 
 		// Initial count should be 0
 		let model = Models::<Test>::get(0).unwrap();
 		assert_eq!(model.total_inferences, 0);
 
+		// Only `InferenceOrigin` can count inferences
+		assert_noop!(
+			AIRegistry::increment_inference_count(RuntimeOrigin::signed(1), 0),
+			sp_runtime::DispatchError::BadOrigin
+		);
+
 		// Increment count (simulating inference completion)
-		assert_ok!(AIRegistry::increment_inference_count(0));
+		assert_ok!(AIRegistry::increment_inference_count(RuntimeOrigin::root(), 0));
 
 		let model = Models::<Test>::get(0).unwrap();
 		assert_eq!(model.total_inferences, 1);
 
 		// Increment again
-		assert_ok!(AIRegistry::increment_inference_count(0));
+		assert_ok!(AIRegistry::increment_inference_count(RuntimeOrigin::root(), 0));
 
 		let model = Models::<Test>::get(0).unwrap();
 		assert_eq!(model.total_inferences, 2);
@@ -399,17 +566,3612 @@ fn get_average_rating_works() {
 			b"Model".to_vec(),
 			b"Description".to_vec(),
 			ModelType::Classification,
-			500
+			500,
+			License::Mit,
+			1_000,
+			vec![]
 		));
 
 		// No ratings yet
 		assert_eq!(AIRegistry::get_average_rating(0), None);
 
 		// Add some ratings
-		assert_ok!(AIRegistry::rate_model(RuntimeOrigin::signed(2), 0, 5));
+		assert_ok!(AIRegistry::rate_model(RuntimeOrigin::signed(2), 0, 5, None));
 		assert_eq!(AIRegistry::get_average_rating(0), Some(5));
 
-		assert_ok!(AIRegistry::rate_model(RuntimeOrigin::signed(3), 0, 3));
+		assert_ok!(AIRegistry::rate_model(RuntimeOrigin::signed(3), 0, 3, None));
 		assert_eq!(AIRegistry::get_average_rating(0), Some(4)); // (5+3)/2 = 4
 	});
 }
+
+#[test]
+fn register_model_reserves_size_proportional_deposit() {
+	new_test_ext().execute_with(|| {
+		let ipfs_cid = b"QmYwAPJzv5CZsnA625s3Xf2nemtYgPpHdWEz79ojWnPbdG".to_vec();
+		assert_ok!(AIRegistry::register_model(
+			RuntimeOrigin::signed(1),
+			ipfs_cid,
+			b"Model".to_vec(),
+			b"Description".to_vec(),
+			ModelType::Classification,
+			500,
+			License::Mit,
+			2_000,
+			vec![]
+		));
+
+		// Minimum stake (1000) + 1 per byte
+		let model = Models::<Test>::get(0).unwrap();
+		assert_eq!(model.size_bytes, 2_000);
+		assert_eq!(model.deposit, 3_000);
+		assert_eq!(Balances::reserved_balance(1), 3_000);
+		assert_eq!(Balances::free_balance(1), 10_000 - 100 - 3_000);
+
+		// Deactivation releases the deposit
+		assert_ok!(AIRegistry::deactivate_model(RuntimeOrigin::signed(1), 0, None));
+		assert_eq!(Balances::reserved_balance(1), 0);
+		assert_eq!(Models::<Test>::get(0).unwrap().deposit, 0);
+	});
+}
+
+#[test]
+fn register_model_fails_when_deposit_exceeds_balance() {
+	new_test_ext().execute_with(|| {
+		let ipfs_cid = b"QmYwAPJzv5CZsnA625s3Xf2nemtYgPpHdWEz79ojWnPbdG".to_vec();
+		assert_noop!(
+			AIRegistry::register_model(
+				RuntimeOrigin::signed(1),
+				ipfs_cid.clone(),
+				b"Model".to_vec(),
+				b"Description".to_vec(),
+				ModelType::Classification,
+				500,
+				License::Mit,
+				20_000,
+				vec![]
+			),
+			Error::<Test>::InsufficientStake
+		);
+		assert_noop!(
+			AIRegistry::register_model(
+				RuntimeOrigin::signed(1),
+				ipfs_cid,
+				b"Model".to_vec(),
+				b"Description".to_vec(),
+				ModelType::Classification,
+				500,
+				License::Mit,
+				0,
+				vec![]
+			),
+			Error::<Test>::InvalidModelSize
+		);
+	});
+}
+
+#[test]
+fn size_correction_requires_governance_approval() {
+	new_test_ext().execute_with(|| {
+		let ipfs_cid = b"QmYwAPJzv5CZsnA625s3Xf2nemtYgPpHdWEz79ojWnPbdG".to_vec();
+		assert_ok!(AIRegistry::register_model(
+			RuntimeOrigin::signed(1),
+			ipfs_cid,
+			b"Model".to_vec(),
+			b"Description".to_vec(),
+			ModelType::Classification,
+			500,
+			License::Mit,
+			1_000,
+			vec![]
+		));
+
+		// Only the owner may propose a correction
+		assert_noop!(
+			AIRegistry::propose_size_correction(RuntimeOrigin::signed(2), 0, 3_000),
+			Error::<Test>::UnauthorizedAccess
+		);
+		assert_ok!(AIRegistry::propose_size_correction(RuntimeOrigin::signed(1), 0, 3_000));
+
+		// Proposal alone does not change the model
+		assert_eq!(Models::<Test>::get(0).unwrap().size_bytes, 1_000);
+
+		// Only governance may approve
+		assert_noop!(
+			AIRegistry::approve_size_correction(RuntimeOrigin::signed(1), 0),
+			sp_runtime::DispatchError::BadOrigin
+		);
+		assert_ok!(AIRegistry::approve_size_correction(RuntimeOrigin::root(), 0));
+
+		let model = Models::<Test>::get(0).unwrap();
+		assert_eq!(model.size_bytes, 3_000);
+		assert_eq!(model.deposit, 4_000);
+		assert_eq!(Balances::reserved_balance(1), 4_000);
+		System::assert_has_event(
+			Event::ModelSizeCorrected {
+				model_id: 0,
+				old_size: 1_000,
+				new_size: 3_000,
+				deposit: 4_000,
+			}
+			.into(),
+		);
+
+		// Downward corrections release the difference
+		assert_ok!(AIRegistry::propose_size_correction(RuntimeOrigin::signed(1), 0, 500));
+		assert_ok!(AIRegistry::approve_size_correction(RuntimeOrigin::root(), 0));
+		assert_eq!(Balances::reserved_balance(1), 1_500);
+
+		assert_noop!(
+			AIRegistry::approve_size_correction(RuntimeOrigin::root(), 0),
+			Error::<Test>::NoPendingSizeCorrection
+		);
+	});
+}
+
+#[test]
+fn root_governance_expires_at_admin_sunset() {
+	new_test_ext().execute_with(|| {
+		use crate::EnsureRootBeforeSunset;
+		use frame_support::traits::EnsureOrigin;
+
+		assert_eq!(AdminSunset::<Test>::get(), 100);
+		assert!(EnsureRootBeforeSunset::<Test>::try_origin(RuntimeOrigin::root()).is_ok());
+		assert!(EnsureRootBeforeSunset::<Test>::try_origin(RuntimeOrigin::signed(1)).is_err());
+
+		System::set_block_number(100);
+		assert!(AIRegistry::admin_sunset_passed());
+		assert!(EnsureRootBeforeSunset::<Test>::try_origin(RuntimeOrigin::root()).is_err());
+	});
+}
+
+#[test]
+fn extend_admin_sunset_only_moves_later() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			AIRegistry::extend_admin_sunset(RuntimeOrigin::signed(1), 200),
+			sp_runtime::DispatchError::BadOrigin
+		);
+		assert_noop!(
+			AIRegistry::extend_admin_sunset(RuntimeOrigin::root(), 50),
+			Error::<Test>::SunsetNotExtended
+		);
+
+		assert_ok!(AIRegistry::extend_admin_sunset(RuntimeOrigin::root(), 200));
+		assert_eq!(AdminSunset::<Test>::get(), 200);
+		System::assert_has_event(
+			Event::AdminSunsetExtended { old_sunset: 100, new_sunset: 200 }.into(),
+		);
+
+		System::set_block_number(150);
+		assert!(!AIRegistry::admin_sunset_passed());
+	});
+}
+
+#[test]
+fn genesis_config_registers_models() {
+	use sp_runtime::BuildStorage;
+
+	let t = RuntimeGenesisConfig {
+		system: Default::default(),
+		balances: pallet_balances::GenesisConfig {
+			balances: vec![(1, 10_000), (2, 10_000)],
+			..Default::default()
+		},
+		ai_registry: crate::GenesisConfig {
+			models: vec![
+				(1, cid(0), ModelType::Generative, 250),
+				(2, cid(1), ModelType::Regression, 0),
+			],
+		},
+	}
+	.build_storage()
+	.unwrap();
+
+	sp_io::TestExternalities::new(t).execute_with(|| {
+		assert_eq!(NextModelId::<Test>::get(), 2);
+
+		let model = Models::<Test>::get(0).unwrap();
+		assert_eq!(model.owner, 1);
+		assert_eq!(model.model_type, ModelType::Generative);
+		assert_eq!(model.price, 250);
+		assert_eq!(model.status, ModelStatus::Active);
+		assert!(OwnedModels::<Test>::get(1).contains(&0));
+
+		assert_eq!(Models::<Test>::get(1).unwrap().owner, 2);
+		assert!(OwnedModels::<Test>::get(2).contains(&1));
+
+		// Genesis models reserve a deposit and pay rent from genesis like any other
+		assert_eq!(model.deposit, 1_000);
+		assert_eq!(Balances::reserved_balance(1), 1_000);
+		assert_eq!(LastActivity::<Test>::get(0), 0);
+		System::set_block_number(40);
+		assert_ok!(AIRegistry::collect_rent(RuntimeOrigin::signed(3), 0));
+		assert_eq!(Models::<Test>::get(0).unwrap().status, ModelStatus::Paused);
+		assert_eq!(Balances::reserved_balance(1), 0);
+		assert_noop!(
+			AIRegistry::reap_model(RuntimeOrigin::signed(3), 0),
+			Error::<Test>::ReapTooEarly
+		);
+		System::set_block_number(45);
+		assert_ok!(AIRegistry::reap_model(RuntimeOrigin::signed(3), 0));
+		assert!(!Models::<Test>::contains_key(0));
+		assert_eq!(NextModelId::<Test>::get(), 2);
+	});
+}
+
+#[test]
+fn model_callbacks_managed_by_owner() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let ipfs_cid = b"QmYwAPJzv5CZsnA625s3Xf2nemtYgPpHdWEz79ojWnPbdG".to_vec();
+		assert_ok!(AIRegistry::register_model(
+			RuntimeOrigin::signed(1),
+			ipfs_cid.clone(),
+			b"Model".to_vec(),
+			b"Description".to_vec(),
+			ModelType::Classification,
+			500,
+			License::Mit,
+			1_000,
+			vec![]
+		));
+
+		let url_hash = CallbackEndpoint::UrlHash([7u8; 32].into());
+		assert_noop!(
+			AIRegistry::add_model_callback(
+				RuntimeOrigin::signed(2),
+				0,
+				url_hash.clone(),
+				callback_events::ALL
+			),
+			Error::<Test>::UnauthorizedAccess
+		);
+		assert_noop!(
+			AIRegistry::add_model_callback(RuntimeOrigin::signed(1), 0, url_hash.clone(), 0),
+			Error::<Test>::InvalidEventMask
+		);
+		assert_noop!(
+			AIRegistry::add_model_callback(RuntimeOrigin::signed(1), 0, url_hash.clone(), 1 << 31),
+			Error::<Test>::InvalidEventMask
+		);
+		assert_noop!(
+			AIRegistry::add_model_callback(
+				RuntimeOrigin::signed(1),
+				0,
+				CallbackEndpoint::Cid(b"not-a-cid".to_vec().try_into().unwrap()),
+				callback_events::ALL
+			),
+			Error::<Test>::InvalidIPFSCID
+		);
+
+		assert_ok!(AIRegistry::add_model_callback(
+			RuntimeOrigin::signed(1),
+			0,
+			url_hash.clone(),
+			callback_events::MODEL_RATED
+		));
+		let cid_endpoint = CallbackEndpoint::Cid(ipfs_cid.try_into().unwrap());
+		assert_ok!(AIRegistry::add_model_callback(
+			RuntimeOrigin::signed(1),
+			0,
+			cid_endpoint.clone(),
+			callback_events::ALL
+		));
+		System::assert_last_event(
+			Event::CallbackAdded {
+				model_id: 0,
+				descriptor: CallbackDescriptor {
+					endpoint: cid_endpoint.clone(),
+					event_mask: callback_events::ALL,
+				},
+			}
+			.into(),
+		);
+
+		// Mock allows two callbacks per model
+		assert_noop!(
+			AIRegistry::add_model_callback(
+				RuntimeOrigin::signed(1),
+				0,
+				url_hash,
+				callback_events::ALL
+			),
+			Error::<Test>::TooManyCallbacks
+		);
+
+		assert_noop!(
+			AIRegistry::remove_model_callback(RuntimeOrigin::signed(1), 0, 2),
+			Error::<Test>::CallbackNotFound
+		);
+		assert_ok!(AIRegistry::remove_model_callback(RuntimeOrigin::signed(1), 0, 0));
+		System::assert_last_event(Event::CallbackRemoved { model_id: 0, index: 0 }.into());
+
+		let callbacks = ModelCallbacks::<Test>::get(0);
+		assert_eq!(callbacks.len(), 1);
+		assert_eq!(callbacks[0].endpoint, cid_endpoint);
+	});
+}
+
+#[test]
+fn rating_records_provenance_and_replaces_previous() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(5);
+		assert_ok!(AIRegistry::register_model(
+			RuntimeOrigin::signed(1),
+			b"QmYwAPJzv5CZsnA625s3Xf2nemtYgPpHdWEz79ojWnPbdG".to_vec(),
+			b"Model".to_vec(),
+			b"Description".to_vec(),
+			ModelType::Classification,
+			500,
+			License::Mit,
+			1_000,
+			vec![]
+		));
+
+		// Account 2 has a receipt in the mock, account 3 does not
+		assert_ok!(AIRegistry::rate_model(RuntimeOrigin::signed(2), 0, 4, None));
+		assert_ok!(AIRegistry::rate_model(RuntimeOrigin::signed(3), 0, 2, None));
+		assert_eq!(
+			AIRegistry::rating_provenance(0, &2),
+			Some(RatingRecord { rating: 4, receipt: Some(42), rated_at: 5 })
+		);
+		assert_eq!(Ratings::<Test>::get(0, 3).unwrap().receipt, None);
+		assert_eq!(AIRegistry::rating_provenance(0, &4), None);
+
+		// Rating again replaces the previous rating instead of adding one
+		System::set_block_number(6);
+		assert_ok!(AIRegistry::rate_model(RuntimeOrigin::signed(2), 0, 1, None));
+		let model = Models::<Test>::get(0).unwrap();
+		assert_eq!(model.rating_count, 2);
+		assert_eq!(model.total_rating, 3);
+		assert_eq!(AIRegistry::rating_provenance(0, &2).unwrap().rated_at, 6);
+	});
+}
+
+#[test]
+fn ratings_can_require_a_receipt() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		register_test_model(1);
+		RequireReceiptToRate::set(true);
+
+		// Account 3 holds no receipt in the mock
+		assert_noop!(
+			AIRegistry::rate_model(RuntimeOrigin::signed(3), 0, 5, None),
+			Error::<Test>::NotInferenceUser
+		);
+		assert_ok!(AIRegistry::rate_model(RuntimeOrigin::signed(2), 0, 5, None));
+		assert_eq!(Ratings::<Test>::get(0, 2).unwrap().receipt, Some(42));
+	});
+}
+
+#[test]
+fn fresh_unfunded_or_unverified_accounts_cannot_rate() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		register_test_model(1);
+		MinRaterAge::set(10);
+		MinRaterBalance::set(50);
+		UnverifiedRaters::set(vec![3]);
+
+		// Account 40 comes into existence at block 1
+		assert_ok!(Balances::transfer_allow_death(RuntimeOrigin::signed(1), 40, 100));
+		assert_eq!(AccountCreated::<Test>::get(40), Some(1));
+		assert_noop!(
+			AIRegistry::rate_model(RuntimeOrigin::signed(40), 0, 5, None),
+			Error::<Test>::RaterTooNew
+		);
+		System::set_block_number(11);
+		assert_ok!(AIRegistry::rate_model(RuntimeOrigin::signed(40), 0, 5, None));
+
+		// Accounts older than the tracking count as old enough, but must be funded
+		assert_eq!(AccountCreated::<Test>::get(41), None);
+		assert_noop!(
+			AIRegistry::rate_model(RuntimeOrigin::signed(41), 0, 5, None),
+			Error::<Test>::RaterBalanceTooLow
+		);
+		assert_noop!(
+			AIRegistry::rate_model(RuntimeOrigin::signed(3), 0, 5, None),
+			Error::<Test>::RaterNotVerified
+		);
+
+		// Reaped accounts start over when they come back
+		assert_ok!(Balances::transfer_all(RuntimeOrigin::signed(40), 1, false));
+		assert_eq!(AccountCreated::<Test>::get(40), None);
+		assert_ok!(Balances::transfer_allow_death(RuntimeOrigin::signed(1), 40, 100));
+		assert_eq!(AccountCreated::<Test>::get(40), Some(11));
+	});
+}
+
+#[test]
+fn accounts_rate_at_most_max_ratings_per_era() {
+	new_test_ext().execute_with(|| {
+		for owner in [1, 1, 1, 3, 3] {
+			register_test_model(owner);
+		}
+		for model_id in 0..3 {
+			assert_ok!(AIRegistry::rate_model(RuntimeOrigin::signed(2), model_id, 4, None));
+		}
+		assert_noop!(
+			AIRegistry::rate_model(RuntimeOrigin::signed(2), 3, 4, None),
+			Error::<Test>::TooManyRatingsThisEra
+		);
+		// Updating a rating of the era costs nothing more, nor do other accounts share it
+		assert_ok!(AIRegistry::rate_model(RuntimeOrigin::signed(2), 0, 5, None));
+		assert_ok!(AIRegistry::rate_model(RuntimeOrigin::signed(4), 3, 4, None));
+
+		// The next era of 10 blocks starts afresh
+		System::set_block_number(10);
+		assert_ok!(AIRegistry::rate_model(RuntimeOrigin::signed(2), 3, 4, None));
+		assert_ok!(AIRegistry::rate_model(RuntimeOrigin::signed(2), 4, 4, None));
+		assert_eq!(RatedInEra::<Test>::get(2).unwrap(), (1, vec![3, 4].try_into().unwrap()));
+	});
+}
+
+#[test]
+fn models_are_paused_when_their_ratings_collapse() {
+	new_test_ext().execute_with(|| {
+		register_test_model(1);
+		PauseRatingFloor::set(2);
+
+		// Averaging exactly the floor is fine
+		for rater in 10..13 {
+			assert_ok!(AIRegistry::rate_model(RuntimeOrigin::signed(rater), 0, 2, None));
+		}
+		assert_eq!(Models::<Test>::get(0).unwrap().status, ModelStatus::Active);
+
+		// The window keeps the latest four ratings
+		assert_ok!(AIRegistry::rate_model(RuntimeOrigin::signed(13), 0, 1, None));
+		System::assert_last_event(Event::ModelPausedForRatings { model_id: 0, average: 1 }.into());
+		assert_eq!(Models::<Test>::get(0).unwrap().status, ModelStatus::Paused);
+		assert_eq!(GlobalStats::<Test>::get().active_models, 0);
+		assert!(RecentRatings::<Test>::get(0).is_empty());
+
+		// Once reactivated the model needs three new ratings to be judged again
+		assert_ok!(AIRegistry::update_model_metadata(
+			RuntimeOrigin::signed(1),
+			0,
+			None,
+			None,
+			Some(ModelStatus::Active),
+			None
+		));
+		for rater in 14..16 {
+			assert_ok!(AIRegistry::rate_model(RuntimeOrigin::signed(rater), 0, 1, None));
+		}
+		assert_eq!(Models::<Test>::get(0).unwrap().status, ModelStatus::Active);
+		assert_eq!(RecentRatings::<Test>::get(0).to_vec(), vec![1, 1]);
+	});
+}
+
+#[test]
+fn reports_suspend_models_until_curators_resolve_them() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		register_test_model(1);
+		register_test_model(1);
+		assert_ok!(AIRegistry::deactivate_model(RuntimeOrigin::signed(1), 0, Some(10)));
+
+		assert_ok!(AIRegistry::report_model(RuntimeOrigin::signed(2), 0));
+		System::assert_last_event(
+			Event::ModelReported { model_id: 0, reporter: 2, reports: 1 }.into(),
+		);
+		assert_noop!(
+			AIRegistry::report_model(RuntimeOrigin::signed(2), 0),
+			Error::<Test>::AlreadyReported
+		);
+		assert_eq!(Models::<Test>::get(0).unwrap().status, ModelStatus::Active);
+
+		// The second distinct reporter puts the model under review, where it cannot be
+		// used, its metadata is frozen and the owner cannot walk away with the deposit
+		assert_ok!(AIRegistry::report_model(RuntimeOrigin::signed(3), 0));
+		System::assert_last_event(Event::ModelSuspended { model_id: 0, reports: 2 }.into());
+		assert_eq!(Models::<Test>::get(0).unwrap().status, ModelStatus::UnderReview);
+		assert_eq!(UnderReview::<Test>::get(0), Some(ModelStatus::Active));
+		assert!(!<AIRegistry as AiRegistryInspect<u64, u128>>::is_active(0));
+		assert!(!PendingDeactivations::<Test>::contains_key(0));
+		assert_noop!(
+			AIRegistry::report_model(RuntimeOrigin::signed(4), 0),
+			Error::<Test>::ModelUnderReview
+		);
+		assert_noop!(
+			AIRegistry::update_model_metadata(
+				RuntimeOrigin::signed(1),
+				0,
+				Some(1),
+				None,
+				None,
+				None
+			),
+			Error::<Test>::ModelUnderReview
+		);
+		assert_noop!(
+			AIRegistry::rename_model(RuntimeOrigin::signed(1), 0, b"Renamed".to_vec(), None),
+			Error::<Test>::ModelUnderReview
+		);
+		assert_noop!(
+			AIRegistry::deactivate_model(RuntimeOrigin::signed(1), 0, None),
+			Error::<Test>::ModelUnderReview
+		);
+		assert_noop!(
+			AIRegistry::reactivate_model(RuntimeOrigin::signed(1), 0),
+			Error::<Test>::ModelUnderReview
+		);
+
+		// Curators dismissing the reports restore the model
+		assert_noop!(
+			AIRegistry::dismiss_reports(RuntimeOrigin::signed(1), 0),
+			sp_runtime::DispatchError::BadOrigin
+		);
+		assert_ok!(AIRegistry::dismiss_reports(RuntimeOrigin::root(), 0));
+		System::assert_last_event(
+			Event::ReportsDismissed { model_id: 0, status: ModelStatus::Active }.into(),
+		);
+		assert_eq!(Models::<Test>::get(0).unwrap().status, ModelStatus::Active);
+		assert_eq!(ReportCounts::<Test>::get(0), 0);
+		assert_eq!(ModelReports::<Test>::iter_prefix(0).count(), 0);
+		assert_noop!(
+			AIRegistry::dismiss_reports(RuntimeOrigin::root(), 0),
+			Error::<Test>::NotUnderReview
+		);
+
+		// Upholding them means slashing the model
+		assert_ok!(AIRegistry::report_model(RuntimeOrigin::signed(2), 1));
+		assert_ok!(AIRegistry::report_model(RuntimeOrigin::signed(3), 1));
+		assert_ok!(AIRegistry::slash_model(RuntimeOrigin::root(), 1));
+		assert_eq!(Models::<Test>::get(1).unwrap().status, ModelStatus::Deactivated);
+		assert!(!UnderReview::<Test>::contains_key(1));
+		assert_eq!(ReportCounts::<Test>::get(1), 0);
+		assert_eq!(ModelReports::<Test>::iter_prefix(1).count(), 0);
+
+		// A model restored on appeal can be reported again from scratch
+		assert_ok!(AIRegistry::appeal_deactivation(RuntimeOrigin::signed(1), 1, cid(7)));
+		assert_ok!(AIRegistry::resolve_appeal(RuntimeOrigin::root(), 1, true));
+		assert_eq!(Models::<Test>::get(1).unwrap().status, ModelStatus::Active);
+		assert_ok!(AIRegistry::report_model(RuntimeOrigin::signed(2), 1));
+		System::assert_last_event(
+			Event::ModelReported { model_id: 1, reporter: 2, reports: 1 }.into(),
+		);
+		assert_eq!(Models::<Test>::get(1).unwrap().status, ModelStatus::Active);
+		assert_ok!(AIRegistry::do_try_state());
+	});
+}
+
+#[test]
+fn ratings_link_review_cids() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		register_test_model(1);
+
+		assert_noop!(
+			AIRegistry::rate_model(RuntimeOrigin::signed(2), 0, 4, Some(b"not a cid".to_vec())),
+			Error::<Test>::InvalidIPFSCID
+		);
+		assert_noop!(
+			AIRegistry::rate_model(RuntimeOrigin::signed(2), 0, 4, Some(vec![b'b'; 129])),
+			Error::<Test>::CidTooLong
+		);
+
+		assert_ok!(AIRegistry::rate_model(RuntimeOrigin::signed(2), 0, 4, Some(cid(100))));
+		assert_eq!(AIRegistry::rating_review(0, &2).unwrap().to_vec(), cid(100));
+		System::assert_last_event(
+			Event::ModelRated {
+				model_id: 0,
+				rater: 2,
+				rating: 4,
+				review_cid: Some(cid(100).try_into().unwrap()),
+			}
+			.into(),
+		);
+
+		// A new rating without a review drops the one written for the old rating
+		assert_ok!(AIRegistry::rate_model(RuntimeOrigin::signed(2), 0, 2, None));
+		assert!(!RatingReviews::<Test>::contains_key(0, 2));
+	});
+}
+
+#[test]
+fn quadratic_rating_weights_grow_with_the_square_root_of_stake() {
+	new_test_ext().execute_with(|| {
+		register_test_model(1);
+		// Stakes count in units of 100 in the mock
+		RaterStakes::insert(4, 900);
+		RaterStakes::insert(5, 10_000);
+
+		// Raters without stake still count once
+		assert_ok!(AIRegistry::rate_model(RuntimeOrigin::signed(3), 0, 1, None));
+		assert_ok!(AIRegistry::rate_model(RuntimeOrigin::signed(4), 0, 5, None));
+		assert_ok!(AIRegistry::rate_model(RuntimeOrigin::signed(5), 0, 2, None));
+		let model = Models::<Test>::get(0).unwrap();
+		assert_eq!((model.total_rating, model.rating_count), (1 + 5 * 3 + 2 * 10, 14));
+		assert_eq!(RatingWeights::<Test>::get(0, 3), None);
+		assert_eq!(RatingWeights::<Test>::get(0, 4), Some(3));
+
+		// Rating again replaces the rating with its old weight by one with the current
+		RaterStakes::insert(4, 1_600);
+		RaterStakes::insert(5, 50);
+		assert_ok!(AIRegistry::rate_model(RuntimeOrigin::signed(4), 0, 4, None));
+		assert_ok!(AIRegistry::rate_model(RuntimeOrigin::signed(5), 0, 2, None));
+		let model = Models::<Test>::get(0).unwrap();
+		assert_eq!((model.total_rating, model.rating_count), (1 + 4 * 4 + 2, 6));
+		assert_eq!(RatingWeights::<Test>::get(0, 4), Some(4));
+		assert_eq!(RatingWeights::<Test>::get(0, 5), None);
+		assert_eq!(AIRegistry::get_average_rating(0), Some(3));
+	});
+}
+
+#[test]
+fn rating_thresholds_count_raters_rather_than_weight() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		register_test_model(1);
+		// Stakes count in units of 100 in the mock
+		RaterStakes::insert(4, 900);
+
+		// One heavy rater weighs past both thresholds of two ratings alone
+		assert_ok!(AIRegistry::rate_model(RuntimeOrigin::signed(4), 0, 5, None));
+		assert_eq!(Models::<Test>::get(0).unwrap().rating_count, 3);
+		assert_eq!(RaterCounts::<Test>::get(0), 1);
+		assert!(TopModels::<Test>::get().is_empty());
+		assert_noop!(
+			AIRegistry::claim_fee_rebate(RuntimeOrigin::signed(2), 0),
+			Error::<Test>::RatingTooLow
+		);
+
+		// Rating again doesn't count the rater twice
+		assert_ok!(AIRegistry::rate_model(RuntimeOrigin::signed(4), 0, 4, None));
+		assert_eq!(RaterCounts::<Test>::get(0), 1);
+		assert!(TopModels::<Test>::get().is_empty());
+
+		assert_ok!(AIRegistry::rate_model(RuntimeOrigin::signed(3), 0, 5, None));
+		assert_eq!(RaterCounts::<Test>::get(0), 2);
+		assert_eq!(TopModels::<Test>::get().to_vec(), vec![(0, 425)]);
+		assert_noop!(
+			AIRegistry::claim_fee_rebate(RuntimeOrigin::signed(2), 0),
+			Error::<Test>::NoRebateDue
+		);
+	});
+}
+
+#[test]
+fn median_rating_withstands_review_bombing() {
+	new_test_ext().execute_with(|| {
+		register_test_model(1);
+		assert_eq!(AIRegistry::get_median_rating(0), None);
+
+		for rater in 10..15 {
+			assert_ok!(AIRegistry::rate_model(RuntimeOrigin::signed(rater), 0, 5, None));
+		}
+		for rater in 20..24 {
+			assert_ok!(AIRegistry::rate_model(RuntimeOrigin::signed(rater), 0, 1, None));
+		}
+		assert_eq!(RatingHistograms::<Test>::get(0), [4, 0, 0, 0, 5]);
+		// The bombing drags the average down but not the median
+		assert_eq!(AIRegistry::get_average_rating(0), Some(3));
+		assert_eq!(AIRegistry::get_median_rating(0), Some(5));
+
+		// With an even split the lower middle rating counts
+		assert_ok!(AIRegistry::rate_model(RuntimeOrigin::signed(24), 0, 1, None));
+		assert_eq!(AIRegistry::get_median_rating(0), Some(1));
+
+		// Rating again moves the rating to its new stars
+		assert_ok!(AIRegistry::rate_model(RuntimeOrigin::signed(24), 0, 4, None));
+		assert_eq!(RatingHistograms::<Test>::get(0), [4, 0, 0, 1, 5]);
+		assert_eq!(AIRegistry::get_median_rating(0), Some(4));
+
+		// Weighted raters count as often as their weight
+		RaterStakes::insert(30, 2_500);
+		assert_ok!(AIRegistry::rate_model(RuntimeOrigin::signed(30), 0, 2, None));
+		assert_eq!(RatingHistograms::<Test>::get(0), [4, 5, 0, 1, 5]);
+		assert_eq!(AIRegistry::get_median_rating(0), Some(2));
+		assert_ok!(AIRegistry::do_try_state());
+	});
+}
+
+#[test]
+fn versioned_migrations_run_once_from_matching_version() {
+	use crate::{migrations::VersionedMigrationOf, pallet::STORAGE_VERSION};
+	use frame_support::traits::{
+		GetStorageVersion, OnRuntimeUpgrade, StorageVersion, UncheckedOnRuntimeUpgrade,
+	};
+
+	struct BumpNextModelId;
+	impl UncheckedOnRuntimeUpgrade for BumpNextModelId {
+		fn on_runtime_upgrade() -> frame_support::weights::Weight {
+			NextModelId::<Test>::mutate(|id| *id += 1);
+			frame_support::weights::Weight::zero()
+		}
+	}
+
+	new_test_ext().execute_with(|| {
+		// Genesis stamps the in-code version
+		assert_eq!(AIRegistry::on_chain_storage_version(), STORAGE_VERSION);
+
+		let to = StorageVersion::new(10);
+		assert_eq!(STORAGE_VERSION, StorageVersion::new(9));
+		type ToV10 = VersionedMigrationOf<Test, BumpNextModelId, 9, 10>;
+
+		ToV10::on_runtime_upgrade();
+		assert_eq!(NextModelId::<Test>::get(), 1);
+		assert_eq!(AIRegistry::on_chain_storage_version(), to);
+
+		// Already migrated: the inner migration does not run again
+		ToV10::on_runtime_upgrade();
+		assert_eq!(NextModelId::<Test>::get(), 1);
+	});
+}
+
+/// Export model 0 owned by account 1 and return the emitted packet
+fn export_first_model() -> MigrationPacket<Test> {
+	assert_ok!(AIRegistry::register_model(
+		RuntimeOrigin::signed(1),
+		b"QmYwAPJzv5CZsnA625s3Xf2nemtYgPpHdWEz79ojWnPbdG".to_vec(),
+		b"Model".to_vec(),
+		b"Description".to_vec(),
+		ModelType::Generative,
+		500,
+		License::Apache2,
+		1_000,
+		vec![]
+	));
+	assert_ok!(AIRegistry::export_models(RuntimeOrigin::signed(1), vec![0]));
+
+	System::events()
+		.into_iter()
+		.find_map(|record| match record.event {
+			RuntimeEvent::AIRegistry(Event::ModelExported { packet, .. }) => Some(packet),
+			_ => None,
+		})
+		.expect("export emits a packet")
+}
+
+fn sign_packet(signer: u64, packet: &MigrationPacket<Test>) -> TestSignature {
+	TestSignature(signer, (MIGRATION_PACKET_CONTEXT, packet).encode())
+}
+
+#[test]
+fn export_models_checks_ownership_and_batch_size() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(3);
+		let packet = export_first_model();
+		assert_eq!(packet.source_chain, AIRegistry::chain_id());
+		assert_eq!(packet.source_model_id, 0);
+		assert_eq!(packet.owner, 1);
+		assert_eq!(packet.license, License::Apache2);
+		assert_eq!(packet.exported_at, 3);
+
+		// Exporting does not touch the source model
+		assert_eq!(Models::<Test>::get(0).unwrap().status, ModelStatus::Active);
+
+		assert_noop!(
+			AIRegistry::export_models(RuntimeOrigin::signed(2), vec![0]),
+			Error::<Test>::UnauthorizedAccess
+		);
+		assert_noop!(
+			AIRegistry::export_models(RuntimeOrigin::signed(1), vec![0, 0, 0, 0]),
+			Error::<Test>::TooManyModelsToExport
+		);
+		assert_ok!(AIRegistry::deactivate_model(RuntimeOrigin::signed(1), 0, None));
+		assert_noop!(
+			AIRegistry::export_models(RuntimeOrigin::signed(1), vec![0]),
+			Error::<Test>::ModelNotActive
+		);
+	});
+}
+
+#[test]
+fn import_model_from_signed_packet() {
+	let mut packet = new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		export_first_model()
+	});
+
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+
+		// Packets from this very chain are rejected
+		let local = packet.clone();
+		assert_noop!(
+			AIRegistry::import_model(
+				RuntimeOrigin::signed(3),
+				local.clone(),
+				sign_packet(1, &local)
+			),
+			Error::<Test>::PacketFromThisChain
+		);
+
+		packet.source_chain = [9u8; 32].into();
+
+		// Signature must come from the packet owner and cover the whole packet
+		assert_noop!(
+			AIRegistry::import_model(
+				RuntimeOrigin::signed(3),
+				packet.clone(),
+				sign_packet(2, &packet)
+			),
+			Error::<Test>::InvalidPacketSignature
+		);
+		let mut tampered = packet.clone();
+		tampered.price = 1;
+		assert_noop!(
+			AIRegistry::import_model(RuntimeOrigin::signed(3), tampered, sign_packet(1, &packet)),
+			Error::<Test>::InvalidPacketSignature
+		);
+		let mut future = packet.clone();
+		future.version = 2;
+		assert_noop!(
+			AIRegistry::import_model(
+				RuntimeOrigin::signed(3),
+				future.clone(),
+				sign_packet(1, &future)
+			),
+			Error::<Test>::UnsupportedPacketVersion
+		);
+
+		// A relayer submits; the owner pays fee and deposit
+		assert_ok!(AIRegistry::import_model(
+			RuntimeOrigin::signed(3),
+			packet.clone(),
+			sign_packet(1, &packet)
+		));
+		let model = Models::<Test>::get(0).unwrap();
+		assert_eq!(model.owner, 1);
+		assert_eq!(model.model_type, ModelType::Generative);
+		assert_eq!(model.license, License::Apache2);
+		assert_eq!(model.price, 500);
+		assert_eq!(Balances::reserved_balance(1), 2_000);
+		assert_eq!(Balances::free_balance(3), 10_000);
+		assert!(OwnedModels::<Test>::get(1).contains(&0));
+		assert_eq!(
+			ImportedFrom::<Test>::get(0),
+			Some(ModelProvenance {
+				source_chain: [9u8; 32].into(),
+				source_model_id: 0,
+				created_at: 1
+			})
+		);
+		assert_eq!(ImportedPackets::<Test>::get(sp_core::H256::from([9u8; 32]), 0), Some(0));
+		System::assert_last_event(
+			Event::ModelImported {
+				model_id: 0,
+				owner: 1,
+				source_chain: [9u8; 32].into(),
+				source_model_id: 0,
+			}
+			.into(),
+		);
+
+		// Replays are rejected
+		assert_noop!(
+			AIRegistry::import_model(
+				RuntimeOrigin::signed(3),
+				packet.clone(),
+				sign_packet(1, &packet)
+			),
+			Error::<Test>::PacketAlreadyImported
+		);
+	});
+}
+
+#[test]
+fn rent_accrues_on_idle_models_until_reaped() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(AIRegistry::register_model(
+			RuntimeOrigin::signed(1),
+			b"QmYwAPJzv5CZsnA625s3Xf2nemtYgPpHdWEz79ojWnPbdG".to_vec(),
+			b"Model".to_vec(),
+			b"Description".to_vec(),
+			ModelType::Classification,
+			500,
+			License::Mit,
+			1_000,
+			vec![]
+		));
+		assert_eq!(Balances::reserved_balance(1), 2_000);
+
+		// Two rent-free eras of 10 blocks, then 600 per era
+		System::set_block_number(30);
+		assert_noop!(
+			AIRegistry::collect_rent(RuntimeOrigin::signed(2), 0),
+			Error::<Test>::NoRentDue
+		);
+
+		// Inference activity restarts the rent-free period
+		System::set_block_number(11);
+		assert_ok!(AIRegistry::increment_inference_count(RuntimeOrigin::root(), 0));
+		assert_eq!(LastActivity::<Test>::get(0), 11);
+
+		System::set_block_number(51);
+		assert_ok!(AIRegistry::collect_rent(RuntimeOrigin::signed(2), 0));
+		System::assert_last_event(Event::RentCollected { model_id: 0, amount: 1_200 }.into());
+		assert_eq!(Models::<Test>::get(0).unwrap().deposit, 800);
+		assert_eq!(Balances::reserved_balance(1), 800);
+
+		// Already charged up to this era
+		assert_noop!(
+			AIRegistry::collect_rent(RuntimeOrigin::signed(2), 0),
+			Error::<Test>::NoRentDue
+		);
+
+		// Two more eras exhaust the remaining deposit and pause the model
+		System::set_block_number(71);
+		assert_ok!(AIRegistry::collect_rent(RuntimeOrigin::signed(2), 0));
+		System::assert_has_event(Event::RentCollected { model_id: 0, amount: 800 }.into());
+		System::assert_last_event(Event::ModelRentExhausted { model_id: 0, reap_at: 76 }.into());
+		let model = Models::<Test>::get(0).unwrap();
+		assert_eq!(model.deposit, 0);
+		assert_eq!(model.status, ModelStatus::Paused);
+		assert_noop!(
+			AIRegistry::update_model_metadata(
+				RuntimeOrigin::signed(1),
+				0,
+				None,
+				None,
+				Some(ModelStatus::Active),
+				None
+			),
+			Error::<Test>::RentExhausted
+		);
+
+		assert_noop!(
+			AIRegistry::reap_model(RuntimeOrigin::signed(2), 0),
+			Error::<Test>::ReapTooEarly
+		);
+
+		// What the model leaves scheduled, filed or listed goes with it
+		assert_ok!(AIRegistry::schedule_price_change(RuntimeOrigin::signed(1), 0, 700, 90));
+		ScheduledDeactivations::<Test>::try_append(90, 0).unwrap();
+		PendingDeactivations::<Test>::insert(0, 90);
+		ReportCounts::<Test>::insert(0, 1);
+		ModelReports::<Test>::insert(0, 3, 71);
+		RaterCounts::<Test>::insert(0, 1);
+		RatingWeights::<Test>::insert(0, 3, 2);
+		ImportedFrom::<Test>::insert(
+			0,
+			ModelProvenance { source_chain: [9u8; 32].into(), source_model_id: 0, created_at: 1 },
+		);
+		assert_ok!(AIRegistry::create_collection(RuntimeOrigin::signed(1), b"Family".to_vec()));
+		assert_ok!(AIRegistry::add_to_collection(RuntimeOrigin::signed(1), 0, 0));
+
+		System::set_block_number(76);
+		assert_ok!(AIRegistry::reap_model(RuntimeOrigin::signed(2), 0));
+		System::assert_last_event(Event::ModelReaped { model_id: 0, owner: 1 }.into());
+		assert!(!Models::<Test>::contains_key(0));
+		assert!(!OwnedModels::<Test>::get(1).contains(&0));
+		assert!(!NftOwners::contains_key(0));
+		assert!(!PendingPriceChanges::<Test>::contains_key(0));
+		assert!(ScheduledPriceChanges::<Test>::get(90).is_empty());
+		assert!(!PendingDeactivations::<Test>::contains_key(0));
+		assert!(ScheduledDeactivations::<Test>::get(90).is_empty());
+		assert!(!ReportCounts::<Test>::contains_key(0));
+		assert!(!ModelReports::<Test>::contains_key(0, 3));
+		assert!(!RaterCounts::<Test>::contains_key(0));
+		assert!(!RatingWeights::<Test>::contains_key(0, 3));
+		assert!(!ImportedFrom::<Test>::contains_key(0));
+		assert!(Collections::<Test>::get(0).unwrap().models.is_empty());
+		assert!(!ModelCollections::<Test>::contains_key(0));
+		// The artifact can be registered again
+		assert_eq!(
+			AIRegistry::model_by_cid(b"QmYwAPJzv5CZsnA625s3Xf2nemtYgPpHdWEz79ojWnPbdG"),
+			None
+		);
+		assert_noop!(
+			AIRegistry::reap_model(RuntimeOrigin::signed(2), 0),
+			Error::<Test>::NotReapable
+		);
+	});
+}
+
+/// CIDv0 of the `n`th test model
+fn cid(n: u64) -> Vec<u8> {
+	format!("QmYwAPJzv5CZsnA625s3Xf2nemtYgPpHdWEz79oj{n:06}").into_bytes()
+}
+
+/// Name of the `n`th test model
+fn name(n: u64) -> Vec<u8> {
+	format!("Model {n}").into_bytes()
+}
+
+/// Register a classification model owned by `owner`
+fn register_test_model(owner: u64) {
+	assert_ok!(AIRegistry::register_model(
+		RuntimeOrigin::signed(owner),
+		cid(NextModelId::<Test>::get()),
+		name(NextModelId::<Test>::get()),
+		b"Description".to_vec(),
+		ModelType::Classification,
+		500,
+		License::Mit,
+		1_000,
+		vec![]
+	));
+}
+
+#[test]
+fn highly_rated_models_get_fee_rebates() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		register_test_model(1);
+		assert_eq!(Balances::free_balance(AIRegistry::fee_pot()), 100);
+		assert_eq!(RebatableFees::<Test>::get(0), 100);
+
+		// Two ratings averaging four stars are needed
+		assert_noop!(
+			AIRegistry::claim_fee_rebate(RuntimeOrigin::signed(2), 0),
+			Error::<Test>::RatingTooLow
+		);
+		assert_ok!(AIRegistry::rate_model(RuntimeOrigin::signed(2), 0, 5, None));
+		assert_noop!(
+			AIRegistry::claim_fee_rebate(RuntimeOrigin::signed(2), 0),
+			Error::<Test>::RatingTooLow
+		);
+		assert_ok!(AIRegistry::rate_model(RuntimeOrigin::signed(3), 0, 3, None));
+		assert_noop!(
+			AIRegistry::claim_fee_rebate(RuntimeOrigin::signed(2), 0),
+			Error::<Test>::NoRebateDue
+		);
+
+		// 10% of the fee per era of 10 blocks
+		System::set_block_number(35);
+		let balance = Balances::free_balance(1);
+		assert_ok!(AIRegistry::claim_fee_rebate(RuntimeOrigin::signed(2), 0));
+		System::assert_last_event(Event::FeeRebated { model_id: 0, owner: 1, amount: 30 }.into());
+		assert_eq!(Balances::free_balance(1), balance + 30);
+		assert_eq!(RebatableFees::<Test>::get(0), 70);
+		assert_eq!(RebatedUntil::<Test>::get(0), 31);
+		assert_noop!(
+			AIRegistry::claim_fee_rebate(RuntimeOrigin::signed(2), 0),
+			Error::<Test>::NoRebateDue
+		);
+
+		// The pot keeps its existential deposit
+		System::set_block_number(200);
+		assert_ok!(AIRegistry::rate_model(RuntimeOrigin::signed(4), 0, 1, None));
+		assert_noop!(
+			AIRegistry::claim_fee_rebate(RuntimeOrigin::signed(2), 0),
+			Error::<Test>::RatingTooLow
+		);
+		assert_ok!(AIRegistry::rate_model(RuntimeOrigin::signed(4), 0, 5, None));
+		assert_ok!(AIRegistry::claim_fee_rebate(RuntimeOrigin::signed(2), 0));
+		System::assert_last_event(Event::FeeRebated { model_id: 0, owner: 1, amount: 69 }.into());
+		assert_eq!(Balances::free_balance(AIRegistry::fee_pot()), 1);
+		assert_noop!(
+			AIRegistry::claim_fee_rebate(RuntimeOrigin::signed(2), 0),
+			Error::<Test>::NoRebateDue
+		);
+
+		// The rest is paid once the pot holds more fees
+		System::set_block_number(210);
+		assert_noop!(
+			AIRegistry::claim_fee_rebate(RuntimeOrigin::signed(2), 0),
+			Error::<Test>::FeePotEmpty
+		);
+		register_test_model(3);
+		assert_ok!(AIRegistry::claim_fee_rebate(RuntimeOrigin::signed(2), 0));
+		System::assert_last_event(Event::FeeRebated { model_id: 0, owner: 1, amount: 1 }.into());
+		assert!(!RebatableFees::<Test>::contains_key(0));
+		assert!(!RebatedUntil::<Test>::contains_key(0));
+	});
+}
+
+#[test]
+fn top_up_rescues_exhausted_model() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(AIRegistry::register_model(
+			RuntimeOrigin::signed(1),
+			b"QmYwAPJzv5CZsnA625s3Xf2nemtYgPpHdWEz79ojWnPbdG".to_vec(),
+			b"Model".to_vec(),
+			b"Description".to_vec(),
+			ModelType::Classification,
+			500,
+			License::Mit,
+			1_000,
+			vec![]
+		));
+
+		System::set_block_number(100);
+		assert_ok!(AIRegistry::collect_rent(RuntimeOrigin::signed(2), 0));
+		assert!(RentExhausted::<Test>::contains_key(0));
+
+		assert_noop!(
+			AIRegistry::top_up_deposit(RuntimeOrigin::signed(2), 0, 1_000),
+			Error::<Test>::UnauthorizedAccess
+		);
+		assert_ok!(AIRegistry::top_up_deposit(RuntimeOrigin::signed(1), 0, 1_000));
+		System::assert_last_event(
+			Event::DepositToppedUp { model_id: 0, amount: 1_000, deposit: 1_000 }.into(),
+		);
+		assert!(!RentExhausted::<Test>::contains_key(0));
+		assert_eq!(Models::<Test>::get(0).unwrap().status, ModelStatus::Active);
+		assert_eq!(Balances::reserved_balance(1), 1_000);
+		assert_noop!(
+			AIRegistry::reap_model(RuntimeOrigin::signed(2), 0),
+			Error::<Test>::NotReapable
+		);
+	});
+}
+
+#[test]
+fn try_state_detects_inconsistent_storage() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(AIRegistry::register_model(
+			RuntimeOrigin::signed(1),
+			b"QmYwAPJzv5CZsnA625s3Xf2nemtYgPpHdWEz79ojWnPbdG".to_vec(),
+			b"Model".to_vec(),
+			b"Description".to_vec(),
+			ModelType::Classification,
+			500,
+			License::Mit,
+			1_000,
+			vec![]
+		));
+		assert_ok!(AIRegistry::deactivate_model(RuntimeOrigin::signed(1), 0, None));
+		assert_ok!(AIRegistry::do_try_state());
+
+		OwnedModels::<Test>::remove(1);
+		assert!(AIRegistry::do_try_state().is_err());
+
+		OwnedModels::<Test>::mutate(1, |owned| owned.try_insert(0).unwrap());
+		NextModelId::<Test>::put(0);
+		assert!(AIRegistry::do_try_state().is_err());
+	});
+}
+
+#[test]
+fn slash_model_burns_fraction_and_refunds_rest() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(AIRegistry::register_model(
+			RuntimeOrigin::signed(1),
+			b"QmYwAPJzv5CZsnA625s3Xf2nemtYgPpHdWEz79ojWnPbdG".to_vec(),
+			b"Model".to_vec(),
+			b"Description".to_vec(),
+			ModelType::Classification,
+			500,
+			License::Mit,
+			1_000,
+			vec![]
+		));
+		let issuance = Balances::total_issuance();
+
+		assert_noop!(
+			AIRegistry::slash_model(RuntimeOrigin::signed(1), 0),
+			sp_runtime::DispatchError::BadOrigin
+		);
+		assert_ok!(AIRegistry::slash_model(RuntimeOrigin::root(), 0));
+
+		// Half of the 2000 deposit is slashed, the rest returned
+		System::assert_last_event(
+			Event::ModelSlashed { model_id: 0, owner: 1, amount: 1_000 }.into(),
+		);
+		let model = Models::<Test>::get(0).unwrap();
+		assert_eq!(model.status, ModelStatus::Deactivated);
+		assert_eq!(model.deposit, 0);
+		assert_eq!(Balances::reserved_balance(1), 0);
+		assert_eq!(Balances::free_balance(1), 10_000 - 100 - 1_000);
+		assert_eq!(Balances::total_issuance(), issuance - 1_000);
+
+		assert_noop!(
+			AIRegistry::slash_model(RuntimeOrigin::root(), 0),
+			Error::<Test>::ModelNotActive
+		);
+	});
+}
+
+#[test]
+fn status_changes_follow_the_transition_table() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		register_test_model(1);
+		let update_status = |status| {
+			AIRegistry::update_model_metadata(
+				RuntimeOrigin::signed(1),
+				0,
+				None,
+				None,
+				Some(status),
+				None,
+			)
+		};
+
+		assert!(ModelStatus::Paused.can_transition_to(ModelStatus::Deprecated));
+		assert!(!ModelStatus::Deactivated.can_transition_to(ModelStatus::Paused));
+		assert_noop!(
+			AIRegistry::reactivate_model(RuntimeOrigin::signed(1), 0),
+			Error::<Test>::ModelAlreadyActive
+		);
+		// Deactivation must release the deposit, so metadata updates cannot deactivate
+		assert_noop!(
+			update_status(ModelStatus::Deactivated),
+			Error::<Test>::InvalidStatusTransition
+		);
+
+		assert_ok!(update_status(ModelStatus::Paused));
+		assert_ok!(AIRegistry::reactivate_model(RuntimeOrigin::signed(1), 0));
+		System::assert_last_event(Event::ModelReactivated { model_id: 0, owner: 1 }.into());
+		assert_eq!(Models::<Test>::get(0).unwrap().status, ModelStatus::Active);
+
+		// Deactivated models come back with their deposit reserved again
+		assert_ok!(AIRegistry::deactivate_model(RuntimeOrigin::signed(1), 0, None));
+		assert_eq!(Balances::reserved_balance(1), 0);
+		assert_noop!(update_status(ModelStatus::Active), Error::<Test>::InvalidStatusTransition);
+		assert_noop!(
+			AIRegistry::reactivate_model(RuntimeOrigin::signed(2), 0),
+			Error::<Test>::UnauthorizedAccess
+		);
+		assert_ok!(AIRegistry::reactivate_model(RuntimeOrigin::signed(1), 0));
+		let model = Models::<Test>::get(0).unwrap();
+		assert_eq!(model.status, ModelStatus::Active);
+		assert_eq!(model.deposit, 2_000);
+		assert_eq!(Balances::reserved_balance(1), 2_000);
+
+		// Reactivating cancels a scheduled deactivation
+		assert_ok!(AIRegistry::deactivate_model(RuntimeOrigin::signed(1), 0, Some(10)));
+		assert_ok!(AIRegistry::reactivate_model(RuntimeOrigin::signed(1), 0));
+		assert!(!PendingDeactivations::<Test>::contains_key(0));
+		assert!(ScheduledDeactivations::<Test>::get(11).is_empty());
+
+		// Slashed models can only come back through an appeal
+		assert_ok!(AIRegistry::slash_model(RuntimeOrigin::root(), 0));
+		assert_noop!(
+			AIRegistry::reactivate_model(RuntimeOrigin::signed(1), 0),
+			Error::<Test>::DeactivatedByForce
+		);
+		assert_ok!(AIRegistry::do_try_state());
+	});
+}
+
+#[test]
+fn registrations_can_require_approval() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		RequireRegistrationApproval::set(true);
+		register_test_model(1);
+		register_test_model(2);
+		assert_eq!(Models::<Test>::get(0).unwrap().status, ModelStatus::Pending);
+		assert_eq!(GlobalStats::<Test>::get().active_models, 0);
+		assert!(!<AIRegistry as AiRegistryInspect<u64, u128>>::is_active(0));
+
+		// Owners cannot route around the approval
+		assert_noop!(
+			AIRegistry::update_model_metadata(
+				RuntimeOrigin::signed(1),
+				0,
+				None,
+				None,
+				Some(ModelStatus::Active),
+				None
+			),
+			Error::<Test>::InvalidStatusTransition
+		);
+		assert_noop!(
+			AIRegistry::reactivate_model(RuntimeOrigin::signed(1), 0),
+			Error::<Test>::AwaitingApproval
+		);
+		assert_noop!(
+			AIRegistry::deprecate_model(RuntimeOrigin::signed(1), 0, None),
+			Error::<Test>::AwaitingApproval
+		);
+
+		assert_noop!(
+			AIRegistry::approve_registration(RuntimeOrigin::signed(1), 0),
+			sp_runtime::DispatchError::BadOrigin
+		);
+		assert_ok!(AIRegistry::approve_registration(RuntimeOrigin::root(), 0));
+		System::assert_last_event(Event::RegistrationApproved { model_id: 0 }.into());
+		assert_eq!(Models::<Test>::get(0).unwrap().status, ModelStatus::Active);
+		assert_eq!(GlobalStats::<Test>::get().active_models, 1);
+		assert_noop!(
+			AIRegistry::approve_registration(RuntimeOrigin::root(), 0),
+			Error::<Test>::NotPendingApproval
+		);
+
+		// Rejection releases the deposit but keeps the fee
+		assert_ok!(AIRegistry::reject_registration(RuntimeOrigin::root(), 1));
+		System::assert_last_event(Event::RegistrationRejected { model_id: 1 }.into());
+		assert_eq!(Models::<Test>::get(1).unwrap().status, ModelStatus::Deactivated);
+		assert_eq!(Balances::reserved_balance(2), 0);
+		assert_eq!(Balances::free_balance(2), 9_900);
+
+		// and the model needs approval again to come back
+		assert_ok!(AIRegistry::reactivate_model(RuntimeOrigin::signed(2), 1));
+		assert_eq!(Models::<Test>::get(1).unwrap().status, ModelStatus::Pending);
+		assert_eq!(GlobalStats::<Test>::get().active_models, 1);
+		assert_ok!(AIRegistry::do_try_state());
+	});
+}
+
+#[test]
+fn only_allowlisted_developers_register_when_required() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		register_test_model(1);
+
+		assert_noop!(
+			AIRegistry::set_allowlist_required(RuntimeOrigin::signed(1), true),
+			sp_runtime::DispatchError::BadOrigin
+		);
+		assert_ok!(AIRegistry::set_allowlist_required(RuntimeOrigin::root(), true));
+		System::assert_last_event(Event::AllowlistRequirementSet { required: true }.into());
+
+		let fork = |who| {
+			AIRegistry::fork_model(
+				RuntimeOrigin::signed(who),
+				0,
+				cid(NextModelId::<Test>::get()),
+				b"Fork".to_vec(),
+				b"Description".to_vec(),
+				800,
+				License::Mit,
+				2_000,
+				Perbill::zero(),
+			)
+		};
+		assert_noop!(fork(2), Error::<Test>::NotAllowlisted);
+		Developers::set(vec![3, 2]);
+		assert_ok!(fork(2));
+		register_test_model(3);
+		// Existing models are unaffected
+		assert_ok!(AIRegistry::update_model_metadata(
+			RuntimeOrigin::signed(1),
+			0,
+			Some(600),
+			None,
+			None,
+			None
+		));
+		assert_noop!(fork(1), Error::<Test>::NotAllowlisted);
+
+		assert_ok!(AIRegistry::set_allowlist_required(RuntimeOrigin::root(), false));
+		assert_ok!(fork(1));
+	});
+}
+
+#[test]
+fn developer_profiles_are_shown_with_their_models() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		register_test_model(1);
+		register_test_model(1);
+		assert_eq!(AIRegistry::developer_page(&1), None);
+
+		assert_noop!(
+			AIRegistry::set_developer_profile(
+				RuntimeOrigin::signed(1),
+				b"Acme AI".to_vec(),
+				Some(b"not-a-cid".to_vec()),
+				None
+			),
+			Error::<Test>::InvalidIPFSCID
+		);
+		assert_ok!(AIRegistry::set_developer_profile(
+			RuntimeOrigin::signed(1),
+			b"Acme AI".to_vec(),
+			Some(cid(100)),
+			None
+		));
+		System::assert_last_event(Event::DeveloperProfileSet { developer: 1 }.into());
+		assert_eq!(
+			AIRegistry::developer_page(&1),
+			Some(DeveloperPage {
+				display_name: b"Acme AI".to_vec(),
+				website_cid: Some(cid(100)),
+				contact_cid: None,
+				models: vec![0, 1],
+			})
+		);
+
+		assert_ok!(AIRegistry::clear_developer_profile(RuntimeOrigin::signed(1)));
+		System::assert_last_event(Event::DeveloperProfileCleared { developer: 1 }.into());
+		assert_eq!(AIRegistry::developer_page(&1), None);
+		assert_noop!(
+			AIRegistry::clear_developer_profile(RuntimeOrigin::signed(1)),
+			Error::<Test>::ProfileNotFound
+		);
+	});
+}
+
+#[test]
+fn organization_members_act_within_their_roles() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(AIRegistry::create_organization(RuntimeOrigin::signed(1), b"Acme".to_vec()));
+		let org = AIRegistry::organization_account(0);
+		System::assert_last_event(
+			Event::OrganizationCreated { org_id: 0, account: org, admin: 1 }.into(),
+		);
+		assert_ok!(Balances::transfer_allow_death(RuntimeOrigin::signed(1), org, 5_000));
+		assert_ok!(AIRegistry::set_organization_role(
+			RuntimeOrigin::signed(1),
+			0,
+			2,
+			Some(OrganizationRole::Publisher)
+		));
+		assert_ok!(AIRegistry::set_organization_role(
+			RuntimeOrigin::signed(1),
+			0,
+			3,
+			Some(OrganizationRole::Billing)
+		));
+		assert_noop!(
+			AIRegistry::set_organization_role(
+				RuntimeOrigin::signed(2),
+				0,
+				4,
+				Some(OrganizationRole::Publisher)
+			),
+			Error::<Test>::InsufficientOrganizationRole
+		);
+
+		let register = RuntimeCall::AIRegistry(crate::Call::register_model {
+			ipfs_cid: cid(0),
+			name: name(0),
+			description: b"Description".to_vec(),
+			model_type: ModelType::Classification,
+			price: 500,
+			license: License::Mit,
+			size_bytes: 1_000,
+			datasets: vec![],
+		});
+		let as_org = |who, call: RuntimeCall| {
+			AIRegistry::as_organization(RuntimeOrigin::signed(who), 0, Box::new(call))
+				.map_err(|err| err.error)
+		};
+		assert_noop!(as_org(4, register.clone()), Error::<Test>::NotOrganizationMember);
+		assert_noop!(as_org(3, register.clone()), Error::<Test>::InsufficientOrganizationRole);
+		assert_ok!(as_org(2, register));
+		assert_eq!(Models::<Test>::get(0).unwrap().owner, org);
+		assert_eq!(Balances::reserved_balance(org), 2_000);
+
+		// Billing members manage funds, only admins hand models over
+		let top_up =
+			RuntimeCall::AIRegistry(crate::Call::top_up_deposit { model_id: 0, amount: 100 });
+		assert_noop!(as_org(2, top_up.clone()), Error::<Test>::InsufficientOrganizationRole);
+		assert_ok!(as_org(3, top_up));
+		assert_eq!(Balances::reserved_balance(org), 2_100);
+		let transfer =
+			RuntimeCall::AIRegistry(crate::Call::transfer_model { model_id: 0, dest: 2 });
+		assert_noop!(as_org(2, transfer.clone()), Error::<Test>::InsufficientOrganizationRole);
+		assert_ok!(as_org(1, transfer));
+		assert_eq!(Models::<Test>::get(0).unwrap().owner, 2);
+
+		// Members can leave, but the last admin cannot
+		assert_noop!(
+			AIRegistry::set_organization_role(RuntimeOrigin::signed(1), 0, 1, None),
+			Error::<Test>::LastOrganizationAdmin
+		);
+		assert_ok!(AIRegistry::set_organization_role(RuntimeOrigin::signed(3), 0, 3, None));
+		System::assert_last_event(
+			Event::OrganizationRoleSet { org_id: 0, member: 3, role: None }.into(),
+		);
+		assert_eq!(Organizations::<Test>::get(0).unwrap().members, 2);
+		assert_ok!(AIRegistry::do_try_state());
+	});
+}
+
+#[test]
+fn deactivations_can_be_scheduled_with_a_grace_period() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		register_test_model(1);
+		register_test_model(2);
+		register_test_model(3);
+
+		assert_noop!(
+			AIRegistry::deactivate_model(RuntimeOrigin::signed(1), 0, Some(101)),
+			Error::<Test>::GracePeriodTooLong
+		);
+		assert_ok!(AIRegistry::deactivate_model(RuntimeOrigin::signed(1), 0, Some(10)));
+		System::assert_last_event(
+			Event::DeactivationScheduled { model_id: 0, owner: 1, at: 11 }.into(),
+		);
+		// The model stays usable during the grace period
+		assert_eq!(Models::<Test>::get(0).unwrap().status, ModelStatus::Active);
+		assert_eq!(Balances::reserved_balance(1), 2_000);
+
+		// Deactivating again replaces the schedule
+		assert_ok!(AIRegistry::deactivate_model(RuntimeOrigin::signed(1), 0, Some(20)));
+		assert!(ScheduledDeactivations::<Test>::get(11).is_empty());
+		assert_eq!(PendingDeactivations::<Test>::get(0), Some(21));
+		assert_ok!(AIRegistry::deactivate_model(RuntimeOrigin::signed(2), 1, Some(20)));
+		assert_noop!(
+			AIRegistry::deactivate_model(RuntimeOrigin::signed(3), 2, Some(20)),
+			Error::<Test>::TooManyScheduledDeactivations
+		);
+		assert_ok!(AIRegistry::do_try_state());
+
+		System::set_block_number(21);
+		AIRegistry::on_initialize(21);
+		System::assert_last_event(Event::ModelDeactivated { model_id: 1, owner: 2 }.into());
+		for model_id in [0, 1] {
+			assert_eq!(Models::<Test>::get(model_id).unwrap().status, ModelStatus::Deactivated);
+			assert!(!PendingDeactivations::<Test>::contains_key(model_id));
+		}
+		assert_eq!(Balances::reserved_balance(1), 0);
+		assert!(!ScheduledDeactivations::<Test>::contains_key(21));
+
+		// No grace period deactivates right away
+		assert_ok!(AIRegistry::deactivate_model(RuntimeOrigin::signed(3), 2, Some(0)));
+		assert_eq!(Models::<Test>::get(2).unwrap().status, ModelStatus::Deactivated);
+		assert_ok!(AIRegistry::do_try_state());
+	});
+}
+
+#[test]
+fn owners_can_appeal_forced_deactivations() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		register_test_model(1);
+		register_test_model(2);
+		let evidence = b"QmYwAPJzv5CZsnA625s3Xf2nemtYgPpHdWEz79ojWnPbdG".to_vec();
+
+		// Only forced deactivations can be appealed
+		assert_ok!(AIRegistry::deactivate_model(RuntimeOrigin::signed(2), 1, None));
+		assert_noop!(
+			AIRegistry::appeal_deactivation(RuntimeOrigin::signed(2), 1, evidence.clone()),
+			Error::<Test>::NotAppealable
+		);
+
+		assert_ok!(AIRegistry::slash_model(RuntimeOrigin::root(), 0));
+		assert_eq!(ForcedDeactivations::<Test>::get(0), Some(ModelStatus::Active));
+		assert_noop!(
+			AIRegistry::appeal_deactivation(RuntimeOrigin::signed(2), 0, evidence.clone()),
+			Error::<Test>::UnauthorizedAccess
+		);
+		assert_noop!(
+			AIRegistry::appeal_deactivation(RuntimeOrigin::signed(1), 0, b"not a cid".to_vec()),
+			Error::<Test>::InvalidIPFSCID
+		);
+		assert_ok!(AIRegistry::appeal_deactivation(RuntimeOrigin::signed(1), 0, evidence.clone()));
+		System::assert_last_event(
+			Event::AppealOpened {
+				model_id: 0,
+				owner: 1,
+				evidence_cid: evidence.clone().try_into().unwrap(),
+				bond: 500,
+			}
+			.into(),
+		);
+		assert_eq!(Balances::reserved_balance(1), 500);
+		assert_noop!(
+			AIRegistry::appeal_deactivation(RuntimeOrigin::signed(1), 0, evidence.clone()),
+			Error::<Test>::AppealAlreadyOpen
+		);
+
+		// Upholding the appeal refunds the bond and restores the model and its deposit
+		assert_noop!(
+			AIRegistry::resolve_appeal(RuntimeOrigin::signed(1), 0, true),
+			sp_runtime::DispatchError::BadOrigin
+		);
+		assert_ok!(AIRegistry::resolve_appeal(RuntimeOrigin::root(), 0, true));
+		System::assert_last_event(
+			Event::AppealUpheld { model_id: 0, status: ModelStatus::Active }.into(),
+		);
+		let model = Models::<Test>::get(0).unwrap();
+		assert_eq!(model.status, ModelStatus::Active);
+		assert_eq!(model.deposit, 2_000);
+		assert_eq!(Balances::reserved_balance(1), 2_000);
+		assert!(!ForcedDeactivations::<Test>::contains_key(0));
+		assert_noop!(
+			AIRegistry::resolve_appeal(RuntimeOrigin::root(), 0, true),
+			Error::<Test>::AppealNotFound
+		);
+		assert_ok!(AIRegistry::do_try_state());
+
+		// Rejecting it slashes the bond, and the deactivation cannot be appealed again
+		assert_ok!(AIRegistry::slash_model(RuntimeOrigin::root(), 0));
+		assert_ok!(AIRegistry::appeal_deactivation(RuntimeOrigin::signed(1), 0, evidence.clone()));
+		let issuance = Balances::total_issuance();
+		assert_ok!(AIRegistry::resolve_appeal(RuntimeOrigin::root(), 0, false));
+		System::assert_last_event(Event::AppealRejected { model_id: 0, bond: 500 }.into());
+		assert_eq!(Balances::total_issuance(), issuance - 500);
+		assert_eq!(Balances::reserved_balance(1), 0);
+		assert_eq!(Models::<Test>::get(0).unwrap().status, ModelStatus::Deactivated);
+		assert_noop!(
+			AIRegistry::appeal_deactivation(RuntimeOrigin::signed(1), 0, evidence),
+			Error::<Test>::NotAppealable
+		);
+		assert_ok!(AIRegistry::do_try_state());
+	});
+}
+
+#[test]
+fn transfer_model_moves_nft_and_deposit() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(AIRegistry::register_model(
+			RuntimeOrigin::signed(1),
+			b"QmYwAPJzv5CZsnA625s3Xf2nemtYgPpHdWEz79ojWnPbdG".to_vec(),
+			b"Model".to_vec(),
+			b"Description".to_vec(),
+			ModelType::Classification,
+			500,
+			License::Mit,
+			1_000,
+			vec![]
+		));
+		assert_eq!(NftOwners::get(0), Some(1));
+
+		assert_noop!(
+			AIRegistry::transfer_model(RuntimeOrigin::signed(2), 0, 3),
+			Error::<Test>::UnauthorizedAccess
+		);
+		// The new owner must cover the deposit
+		assert_noop!(
+			AIRegistry::transfer_model(RuntimeOrigin::signed(1), 0, 4),
+			Error::<Test>::InsufficientStake
+		);
+
+		assert_ok!(AIRegistry::transfer_model(RuntimeOrigin::signed(1), 0, 2));
+		System::assert_last_event(Event::ModelTransferred { model_id: 0, from: 1, to: 2 }.into());
+		assert_eq!(Models::<Test>::get(0).unwrap().owner, 2);
+		assert_eq!(NftOwners::get(0), Some(2));
+		assert!(!OwnedModels::<Test>::contains_key(1));
+		assert!(OwnedModels::<Test>::get(2).contains(&0));
+		assert_eq!(Balances::reserved_balance(1), 0);
+		assert_eq!(Balances::reserved_balance(2), 2_000);
+		assert_ok!(AIRegistry::do_try_state());
+	});
+}
+
+#[test]
+fn nft_holder_takes_over_model() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(AIRegistry::register_model(
+			RuntimeOrigin::signed(1),
+			b"QmYwAPJzv5CZsnA625s3Xf2nemtYgPpHdWEz79ojWnPbdG".to_vec(),
+			b"Model".to_vec(),
+			b"Description".to_vec(),
+			ModelType::Classification,
+			500,
+			License::Mit,
+			1_000,
+			vec![]
+		));
+		assert_noop!(
+			AIRegistry::sync_model_owner(RuntimeOrigin::signed(3), 0),
+			Error::<Test>::OwnershipAlreadySynced
+		);
+		assert_noop!(
+			AIRegistry::sync_model_owner(RuntimeOrigin::signed(3), 1),
+			Error::<Test>::ModelNftNotFound
+		);
+
+		// Sold on a marketplace to an account that cannot cover the deposit yet
+		NftOwners::insert(0, 4);
+		assert_noop!(
+			AIRegistry::update_model_metadata(
+				RuntimeOrigin::signed(1),
+				0,
+				Some(1),
+				None,
+				None,
+				None
+			),
+			Error::<Test>::OwnershipNotSynced
+		);
+		assert_noop!(
+			AIRegistry::sync_model_owner(RuntimeOrigin::signed(3), 0),
+			Error::<Test>::InsufficientStake
+		);
+
+		// Resold; anyone can hand the model to the holder
+		NftOwners::insert(0, 2);
+		assert_ok!(AIRegistry::sync_model_owner(RuntimeOrigin::signed(3), 0));
+		System::assert_last_event(Event::ModelTransferred { model_id: 0, from: 1, to: 2 }.into());
+		assert_eq!(Models::<Test>::get(0).unwrap().owner, 2);
+		assert_eq!(Balances::reserved_balance(1), 0);
+		assert_eq!(Balances::reserved_balance(2), 2_000);
+		assert_ok!(AIRegistry::update_model_metadata(
+			RuntimeOrigin::signed(2),
+			0,
+			Some(1),
+			None,
+			None,
+			None
+		));
+		assert_ok!(AIRegistry::do_try_state());
+	});
+}
+
+#[test]
+fn fractionalize_model_splits_shares() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(AIRegistry::register_model(
+			RuntimeOrigin::signed(1),
+			b"QmYwAPJzv5CZsnA625s3Xf2nemtYgPpHdWEz79ojWnPbdG".to_vec(),
+			b"Model".to_vec(),
+			b"Description".to_vec(),
+			ModelType::Classification,
+			500,
+			License::Mit,
+			1_000,
+			vec![]
+		));
+		let pct = Perbill::from_percent;
+
+		assert_noop!(
+			AIRegistry::fractionalize_model(RuntimeOrigin::signed(2), 0, vec![(2, pct(100))]),
+			Error::<Test>::UnauthorizedAccess
+		);
+		for shares in [
+			vec![],
+			vec![(1, pct(60)), (2, pct(30))],
+			vec![(1, pct(60)), (2, pct(40)), (3, pct(0))],
+			vec![(1, pct(60)), (1, pct(40))],
+		] {
+			assert_noop!(
+				AIRegistry::fractionalize_model(RuntimeOrigin::signed(1), 0, shares),
+				Error::<Test>::InvalidShares
+			);
+		}
+		assert_noop!(
+			AIRegistry::fractionalize_model(
+				RuntimeOrigin::signed(1),
+				0,
+				(1..=5).map(|holder| (holder, pct(20))).collect()
+			),
+			Error::<Test>::TooManyShareholders
+		);
+
+		assert_ok!(AIRegistry::fractionalize_model(
+			RuntimeOrigin::signed(1),
+			0,
+			vec![(1, pct(60)), (2, pct(25)), (3, pct(15))]
+		));
+		assert_eq!(AIRegistry::revenue_shares(0), vec![(1, pct(60)), (2, pct(25)), (3, pct(15))]);
+		assert_noop!(
+			AIRegistry::fractionalize_model(RuntimeOrigin::signed(1), 0, vec![(1, pct(100))]),
+			Error::<Test>::ModelAlreadyFractional
+		);
+		// The owner can no longer change metadata alone
+		assert_noop!(
+			AIRegistry::update_model_metadata(
+				RuntimeOrigin::signed(1),
+				0,
+				Some(1),
+				None,
+				None,
+				None
+			),
+			Error::<Test>::FractionallyOwned
+		);
+
+		assert_noop!(
+			AIRegistry::transfer_shares(RuntimeOrigin::signed(3), 0, 4, pct(20)),
+			Error::<Test>::InsufficientShare
+		);
+		assert_noop!(
+			AIRegistry::transfer_shares(RuntimeOrigin::signed(4), 0, 3, pct(1)),
+			Error::<Test>::InsufficientShare
+		);
+		assert_ok!(AIRegistry::transfer_shares(RuntimeOrigin::signed(3), 0, 4, pct(15)));
+		System::assert_last_event(
+			Event::SharesTransferred { model_id: 0, from: 3, to: 4, share: pct(15) }.into(),
+		);
+		assert_ok!(AIRegistry::transfer_shares(RuntimeOrigin::signed(1), 0, 2, pct(10)));
+		assert_eq!(AIRegistry::revenue_shares(0), vec![(1, pct(50)), (2, pct(35)), (4, pct(15))]);
+		assert_ok!(AIRegistry::do_try_state());
+	});
+}
+
+#[test]
+fn metadata_changes_need_majority_of_shares() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(AIRegistry::register_model(
+			RuntimeOrigin::signed(1),
+			b"QmYwAPJzv5CZsnA625s3Xf2nemtYgPpHdWEz79ojWnPbdG".to_vec(),
+			b"Model".to_vec(),
+			b"Description".to_vec(),
+			ModelType::Classification,
+			500,
+			License::Mit,
+			1_000,
+			vec![]
+		));
+		let pct = Perbill::from_percent;
+		assert_noop!(
+			AIRegistry::propose_metadata_update(RuntimeOrigin::signed(1), 0, Some(9), None, None),
+			Error::<Test>::ModelNotFractional
+		);
+		assert_ok!(AIRegistry::fractionalize_model(
+			RuntimeOrigin::signed(1),
+			0,
+			vec![(1, pct(40)), (2, pct(30)), (3, pct(20)), (4, pct(10))]
+		));
+
+		// Account 4 is below the 20% threshold
+		assert_noop!(
+			AIRegistry::propose_metadata_update(RuntimeOrigin::signed(4), 0, Some(9), None, None),
+			Error::<Test>::InsufficientShare
+		);
+		assert_ok!(AIRegistry::propose_metadata_update(
+			RuntimeOrigin::signed(3),
+			0,
+			Some(900),
+			Some(b"New description".to_vec()),
+			None
+		));
+		System::assert_last_event(
+			Event::MetadataChangeProposed { model_id: 0, proposer: 3 }.into(),
+		);
+		assert_noop!(
+			AIRegistry::propose_metadata_update(RuntimeOrigin::signed(3), 0, None, None, None),
+			Error::<Test>::ProposalAlreadyPending
+		);
+		assert_noop!(
+			AIRegistry::approve_metadata_update(RuntimeOrigin::signed(5), 0, 3),
+			Error::<Test>::NotShareholder
+		);
+		assert_noop!(
+			AIRegistry::approve_metadata_update(RuntimeOrigin::signed(3), 0, 3),
+			Error::<Test>::AlreadyApproved
+		);
+
+		// 20% + 30% is not a majority
+		assert_ok!(AIRegistry::approve_metadata_update(RuntimeOrigin::signed(2), 0, 3));
+		assert_eq!(Models::<Test>::get(0).unwrap().price, 500);
+
+		assert_ok!(AIRegistry::approve_metadata_update(RuntimeOrigin::signed(4), 0, 3));
+		System::assert_has_event(Event::MetadataChangeEnacted { model_id: 0, proposer: 3 }.into());
+		System::assert_last_event(Event::ModelUpdated { model_id: 0, owner: 1 }.into());
+		let model = Models::<Test>::get(0).unwrap();
+		assert_eq!(model.price, 900);
+		assert_eq!(model.description.into_inner(), b"New description".to_vec());
+		assert!(!MetadataProposals::<Test>::contains_key(0, 3));
+
+		// Proposers can withdraw, and lose their proposals with their share
+		assert_ok!(AIRegistry::propose_metadata_update(
+			RuntimeOrigin::signed(2),
+			0,
+			None,
+			None,
+			Some(ModelStatus::Paused)
+		));
+		assert_ok!(AIRegistry::cancel_metadata_update(RuntimeOrigin::signed(2), 0));
+		assert_noop!(
+			AIRegistry::cancel_metadata_update(RuntimeOrigin::signed(2), 0),
+			Error::<Test>::ProposalNotFound
+		);
+		assert_ok!(AIRegistry::propose_metadata_update(
+			RuntimeOrigin::signed(2),
+			0,
+			Some(1),
+			None,
+			None
+		));
+		assert_ok!(AIRegistry::transfer_shares(RuntimeOrigin::signed(2), 0, 4, pct(25)));
+		assert!(!MetadataProposals::<Test>::contains_key(0, 2));
+
+		// A majority holder's proposal passes at once
+		assert_ok!(AIRegistry::transfer_shares(RuntimeOrigin::signed(3), 0, 1, pct(20)));
+		assert_ok!(AIRegistry::propose_metadata_update(
+			RuntimeOrigin::signed(1),
+			0,
+			None,
+			None,
+			Some(ModelStatus::Paused)
+		));
+		assert_eq!(Models::<Test>::get(0).unwrap().status, ModelStatus::Paused);
+		assert_eq!(Shareholders::<Test>::get(0).unwrap().len(), 3);
+	});
+}
+
+#[test]
+fn sales_pay_royalty_to_creator() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(AIRegistry::register_model(
+			RuntimeOrigin::signed(1),
+			b"QmYwAPJzv5CZsnA625s3Xf2nemtYgPpHdWEz79ojWnPbdG".to_vec(),
+			b"Model".to_vec(),
+			b"Description".to_vec(),
+			ModelType::Classification,
+			500,
+			License::Mit,
+			1_000,
+			vec![]
+		));
+		assert_noop!(AIRegistry::do_sell(1, &2, 1_000), Error::<Test>::ModelNotFound);
+
+		// The creator sells their own model and keeps the whole price
+		let creator_free = Balances::free_balance(1);
+		assert_ok!(AIRegistry::do_sell(0, &2, 1_000));
+		System::assert_last_event(
+			Event::ModelSold {
+				model_id: 0,
+				seller: 1,
+				buyer: 2,
+				price: 1_000,
+				creator: 1,
+				royalty: 100,
+			}
+			.into(),
+		);
+		assert_eq!(Balances::free_balance(1), creator_free + 1_000 + 2_000);
+		let model = Models::<Test>::get(0).unwrap();
+		assert_eq!((model.owner, model.creator), (2, 1));
+		assert_eq!(NftOwners::get(0), Some(2));
+
+		// Resales pay the 10% royalty to the creator
+		let creator_free = Balances::free_balance(1);
+		let seller_free = Balances::free_balance(2);
+		assert_ok!(AIRegistry::do_sell(0, &3, 3_000));
+		assert_eq!(Balances::free_balance(1), creator_free + 300);
+		assert_eq!(Balances::free_balance(2), seller_free + 2_700 + 2_000);
+		assert_eq!(Balances::reserved_balance(3), 2_000);
+		assert_eq!(Models::<Test>::get(0).unwrap().creator, 1);
+		assert_ok!(AIRegistry::do_try_state());
+	});
+}
+
+#[test]
+fn migration_to_v1_records_creators() {
+	use crate::migrations::v1::{new, v0, MigrateToV1, OldModelMetadata};
+	use frame_support::traits::{GetStorageVersion, OnRuntimeUpgrade, StorageVersion};
+
+	new_test_ext().execute_with(|| {
+		StorageVersion::new(0).put::<AIRegistry>();
+		v0::Models::<Test>::insert(
+			0,
+			OldModelMetadata {
+				owner: 7,
+				ipfs_cid: b"QmYwAPJzv5CZsnA625s3Xf2nemtYgPpHdWEz79ojWnPbdG"
+					.to_vec()
+					.try_into()
+					.unwrap(),
+				name: Default::default(),
+				description: Default::default(),
+				model_type: ModelType::Regression,
+				license: License::Mit,
+				size_bytes: 1_000,
+				deposit: 0,
+				price: 500,
+				created_at: 0,
+				total_inferences: 3,
+				total_rating: 0,
+				rating_count: 0,
+				status: ModelStatus::Active,
+			},
+		);
+
+		MigrateToV1::<Test>::on_runtime_upgrade();
+
+		let model = new::Models::<Test>::get(0).unwrap();
+		assert_eq!((model.owner, model.creator), (7, 7));
+		assert_eq!(model.total_inferences, 3);
+		assert_eq!(AIRegistry::on_chain_storage_version(), 1);
+	});
+}
+
+#[test]
+fn unreleased_migrations_mark_existing_models_as_originals() {
+	use crate::migrations::{
+		v1::{v0, OldModelMetadata},
+		Unreleased,
+	};
+	use frame_support::traits::{GetStorageVersion, OnRuntimeUpgrade, StorageVersion};
+
+	new_test_ext().execute_with(|| {
+		StorageVersion::new(0).put::<AIRegistry>();
+		v0::Models::<Test>::insert(
+			0,
+			OldModelMetadata {
+				owner: 7,
+				ipfs_cid: b"QmYwAPJzv5CZsnA625s3Xf2nemtYgPpHdWEz79ojWnPbdG"
+					.to_vec()
+					.try_into()
+					.unwrap(),
+				name: Default::default(),
+				description: Default::default(),
+				model_type: ModelType::Regression,
+				license: License::Mit,
+				size_bytes: 1_000,
+				deposit: 0,
+				price: 500,
+				created_at: 0,
+				total_inferences: 3,
+				total_rating: 0,
+				rating_count: 0,
+				status: ModelStatus::Active,
+			},
+		);
+
+		Unreleased::<Test>::on_runtime_upgrade();
+
+		let model = Models::<Test>::get(0).unwrap();
+		assert_eq!((model.owner, model.creator, model.parent), (7, 7, None));
+		assert_eq!(model.total_inferences, 3);
+		assert_eq!(
+			GlobalStats::<Test>::get(),
+			RegistryStats { total_models: 1, active_models: 1, total_inferences: 3, fee_volume: 0 }
+		);
+		assert_eq!(AIRegistry::on_chain_storage_version(), 9);
+	});
+}
+
+#[test]
+fn legacy_split_layout_is_consolidated_into_models() {
+	use crate::migrations::{legacy::old, v4::v3::ModelsByOwner, Unreleased};
+	use frame_support::{
+		traits::{GetStorageVersion, OnRuntimeUpgrade, StorageVersion},
+		BoundedVec,
+	};
+
+	new_test_ext().execute_with(|| {
+		StorageVersion::new(0).put::<AIRegistry>();
+		for (model_id, owner) in [(0, 7), (1, 8)] {
+			old::ModelOwner::<Test>::insert(model_id, owner);
+			old::ModelPrice::<Test>::insert(model_id, 500);
+			old::ModelTypeStorage::<Test>::insert(model_id, ModelType::Generative);
+			old::ModelStatusStorage::<Test>::insert(model_id, ModelStatus::Paused);
+			ModelsByOwner::<Test>::insert(owner, model_id, ());
+		}
+		old::ModelRatingTotal::<Test>::insert(0, 9);
+		old::ModelRatingCount::<Test>::insert(0, 2);
+		// Model 1 has no CID and cannot be carried over
+		let ipfs_cid = b"QmYwAPJzv5CZsnA625s3Xf2nemtYgPpHdWEz79ojWnPbdG".to_vec();
+		old::ModelCID::<Test>::insert(0, BoundedVec::<u8, _>::try_from(ipfs_cid).unwrap());
+		NextModelId::<Test>::put(2);
+
+		Unreleased::<Test>::on_runtime_upgrade();
+
+		let model = Models::<Test>::get(0).unwrap();
+		assert_eq!((model.owner, model.creator, model.price), (7, 7, 500));
+		assert_eq!((model.model_type, model.status), (ModelType::Generative, ModelStatus::Paused));
+		assert_eq!((model.total_rating, model.rating_count), (9, 2));
+		assert_eq!(model.license, License::Proprietary);
+		assert!(Models::<Test>::get(1).is_none());
+		assert_eq!((AIRegistry::models_of(&7), AIRegistry::models_of(&8)), (vec![0], vec![]));
+		assert!(old::ModelOwner::<Test>::iter_keys().next().is_none());
+		assert!(old::ModelPrice::<Test>::iter_keys().next().is_none());
+		assert_eq!(GlobalStats::<Test>::get().total_models, 1);
+		assert_eq!(AIRegistry::on_chain_storage_version(), 9);
+		assert_ok!(AIRegistry::do_try_state());
+
+		// Chains already past the legacy layout are left alone
+		old::ModelOwner::<Test>::insert(5, 9);
+		Unreleased::<Test>::on_runtime_upgrade();
+		assert!(Models::<Test>::get(5).is_none());
+	});
+}
+
+#[test]
+fn models_by_owner_moves_into_owned_sets() {
+	use crate::migrations::v4::{v3::ModelsByOwner, MigrateToV4};
+	use frame_support::traits::{GetStorageVersion, OnRuntimeUpgrade, StorageVersion};
+
+	new_test_ext().execute_with(|| {
+		StorageVersion::new(3).put::<AIRegistry>();
+		for (owner, model_id) in [(1, 0), (2, 1), (1, 2)] {
+			ModelsByOwner::<Test>::insert(owner, model_id, ());
+		}
+
+		MigrateToV4::<Test>::on_runtime_upgrade();
+
+		assert_eq!(AIRegistry::models_of(&1), vec![0, 2]);
+		assert_eq!(AIRegistry::models_of(&2), vec![1]);
+		assert!(ModelsByOwner::<Test>::iter_keys().next().is_none());
+		assert_eq!(AIRegistry::on_chain_storage_version(), 4);
+	});
+}
+
+#[test]
+fn name_index_is_built_for_existing_models() {
+	use crate::migrations::v5::MigrateToV5;
+	use frame_support::traits::{GetStorageVersion, OnRuntimeUpgrade, StorageVersion};
+
+	new_test_ext().execute_with(|| {
+		register_test_model(1);
+		register_test_model(2);
+		let _ = NameIndex::<Test>::clear(u32::MAX, None);
+		StorageVersion::new(4).put::<AIRegistry>();
+
+		MigrateToV5::<Test>::on_runtime_upgrade();
+
+		assert_eq!(AIRegistry::models_by_name_prefix(b"model"), vec![0, 1]);
+		assert_eq!(NameIndex::<Test>::iter_keys().count(), 2);
+		assert_eq!(AIRegistry::on_chain_storage_version(), 5);
+	});
+}
+
+#[test]
+fn models_are_found_by_name_prefix() {
+	new_test_ext().execute_with(|| {
+		for (owner, name) in
+			[(1, &b"Llama-2-7B"[..]), (2, b"llama-3"), (1, b"LLaVA"), (2, b"Mistral"), (1, b"ll")]
+		{
+			assert_ok!(AIRegistry::register_model(
+				RuntimeOrigin::signed(owner),
+				cid(NextModelId::<Test>::get()),
+				name.to_vec(),
+				b"Description".to_vec(),
+				ModelType::Generative,
+				500,
+				License::Mit,
+				1_000,
+				vec![]
+			));
+		}
+		// Indexed by the first three bytes, lowercased
+		assert_eq!(NameIndex::<Test>::iter_key_prefix(AIRegistry::name_prefix(b"LLA")).count(), 3);
+		assert_eq!(AIRegistry::models_by_name_prefix(b"LLAMA"), vec![0, 1]);
+		assert_eq!(AIRegistry::models_by_name_prefix(b"lla"), vec![0, 1, 2]);
+		assert_eq!(AIRegistry::models_by_name_prefix(b"llama-2"), vec![0]);
+		assert_eq!(AIRegistry::models_by_name_prefix(b"gpt"), Vec::<u64>::new());
+		// Shorter prefixes only find models named exactly that
+		assert_eq!(AIRegistry::models_by_name_prefix(b"LL"), vec![4]);
+
+		// Renames move the model in the index
+		assert_ok!(AIRegistry::rename_model(RuntimeOrigin::signed(2), 1, b"Qwen".to_vec(), None));
+		assert_eq!(AIRegistry::models_by_name_prefix(b"llama"), vec![0]);
+		assert_eq!(AIRegistry::models_by_name_prefix(b"qwen"), vec![1]);
+		assert_eq!(NameIndex::<Test>::iter_keys().count(), 5);
+	});
+}
+
+#[test]
+fn artifacts_are_registered_only_once() {
+	new_test_ext().execute_with(|| {
+		register_test_model(1);
+		assert_eq!(AIRegistry::model_by_cid(&cid(0)), Some(0));
+
+		// Neither another account nor a fork can claim the same artifact
+		assert_noop!(
+			AIRegistry::register_model(
+				RuntimeOrigin::signed(2),
+				cid(0),
+				b"Copy".to_vec(),
+				b"Description".to_vec(),
+				ModelType::Classification,
+				100,
+				License::Mit,
+				1_000,
+				vec![]
+			),
+			Error::<Test>::CidAlreadyRegistered
+		);
+		assert_noop!(
+			AIRegistry::fork_model(
+				RuntimeOrigin::signed(2),
+				0,
+				cid(0),
+				b"Fork".to_vec(),
+				b"Description".to_vec(),
+				100,
+				License::Mit,
+				1_000,
+				Perbill::zero()
+			),
+			Error::<Test>::CidAlreadyRegistered
+		);
+
+		register_test_model(2);
+		assert_eq!(AIRegistry::model_by_cid(&cid(1)), Some(1));
+		assert_eq!(ModelsByCid::<Test>::iter_keys().count(), 2);
+	});
+}
+
+#[test]
+fn cid_index_is_built_for_existing_models() {
+	use crate::migrations::v6::MigrateToV6;
+	use frame_support::traits::{GetStorageVersion, OnRuntimeUpgrade, StorageVersion};
+
+	new_test_ext().execute_with(|| {
+		for owner in [1, 2, 1] {
+			register_test_model(owner);
+		}
+		// Models 1 and 2 were registered with the same artifact before the index existed
+		for model_id in [1, 2] {
+			Models::<Test>::mutate(model_id, |model| {
+				model.as_mut().unwrap().ipfs_cid = cid(1).try_into().unwrap()
+			});
+		}
+		let _ = ModelsByCid::<Test>::clear(u32::MAX, None);
+		StorageVersion::new(5).put::<AIRegistry>();
+
+		MigrateToV6::<Test>::on_runtime_upgrade();
+
+		assert_eq!(AIRegistry::model_by_cid(&cid(0)), Some(0));
+		// The first registration keeps the artifact
+		assert_eq!(AIRegistry::model_by_cid(&cid(1)), Some(1));
+		assert_eq!(ModelsByCid::<Test>::iter_keys().count(), 2);
+		assert_eq!(AIRegistry::on_chain_storage_version(), 6);
+		assert_ok!(AIRegistry::do_try_state());
+	});
+}
+
+#[test]
+fn model_names_are_unique_per_owner() {
+	new_test_ext().execute_with(|| {
+		register_test_model(1);
+		assert_eq!(AIRegistry::model_named(&1, b"Model 0"), Some(0));
+		let register = |owner, n| {
+			AIRegistry::register_model(
+				RuntimeOrigin::signed(owner),
+				cid(n),
+				name(0),
+				b"Description".to_vec(),
+				ModelType::Classification,
+				100,
+				License::Mit,
+				1_000,
+				vec![],
+			)
+		};
+		assert_noop!(register(1, 1), Error::<Test>::DuplicateModelName);
+		// Other owners may use the name
+		assert_ok!(register(2, 1));
+		register_test_model(1);
+
+		// Renames can't take a name in use either, but may keep their own
+		let rename = |model_id, name: &[u8]| {
+			AIRegistry::rename_model(RuntimeOrigin::signed(1), model_id, name.to_vec(), None)
+		};
+		assert_noop!(rename(2, b"Model 0"), Error::<Test>::DuplicateModelName);
+		assert_ok!(rename(0, b"Model 0"));
+		assert_ok!(rename(0, b"Base"));
+		assert_ok!(rename(2, b"Model 0"));
+		assert_eq!(AIRegistry::model_named(&1, b"Model 0"), Some(2));
+		assert_eq!(AIRegistry::model_named(&1, b"Model 2"), None);
+
+		// A transferred model clashing with a name of the recipient isn't indexed
+		assert_ok!(AIRegistry::transfer_model(RuntimeOrigin::signed(2), 1, 1));
+		assert_eq!(AIRegistry::model_named(&1, b"Model 0"), Some(2));
+		assert_eq!(AIRegistry::model_named(&2, b"Model 0"), None);
+		assert_ok!(AIRegistry::transfer_model(RuntimeOrigin::signed(1), 0, 2));
+		assert_eq!(AIRegistry::model_named(&2, b"Base"), Some(0));
+		assert_eq!(AIRegistry::model_named(&1, b"Base"), None);
+		assert_ok!(AIRegistry::do_try_state());
+	});
+}
+
+#[test]
+fn owner_name_index_is_built_for_existing_models() {
+	use crate::migrations::v7::MigrateToV7;
+	use frame_support::traits::{GetStorageVersion, OnRuntimeUpgrade, StorageVersion};
+
+	new_test_ext().execute_with(|| {
+		for _ in 0..4 {
+			register_test_model(1);
+		}
+		// Registered before names were unique, or without a name
+		for (model_id, new_name) in [(2, name(1)), (3, vec![])] {
+			Models::<Test>::mutate(model_id, |model| {
+				model.as_mut().unwrap().name = new_name.try_into().unwrap()
+			});
+		}
+		NameIndex::<Test>::remove(AIRegistry::name_prefix(b"Model 3"), 3);
+		NameIndex::<Test>::insert(AIRegistry::name_prefix(b""), 3, ());
+		let _ = OwnerModelNames::<Test>::clear(u32::MAX, None);
+		StorageVersion::new(6).put::<AIRegistry>();
+
+		MigrateToV7::<Test>::on_runtime_upgrade();
+
+		assert_eq!(AIRegistry::model_named(&1, b"Model 0"), Some(0));
+		// The first model of the name keeps it
+		assert_eq!(AIRegistry::model_named(&1, b"Model 1"), Some(1));
+		assert_eq!(OwnerModelNames::<Test>::iter_keys().count(), 2);
+		assert_eq!(AIRegistry::on_chain_storage_version(), 7);
+		assert_ok!(AIRegistry::do_try_state());
+	});
+}
+
+#[test]
+fn rating_histograms_are_built_for_existing_ratings() {
+	use crate::migrations::v8::MigrateToV8;
+	use frame_support::traits::{GetStorageVersion, OnRuntimeUpgrade, StorageVersion};
+
+	new_test_ext().execute_with(|| {
+		register_test_model(1);
+		register_test_model(1);
+		RaterStakes::insert(4, 400);
+		for (rater, model_id, rating) in [(3, 0, 5), (4, 0, 2), (3, 1, 4)] {
+			assert_ok!(AIRegistry::rate_model(
+				RuntimeOrigin::signed(rater),
+				model_id,
+				rating,
+				None
+			));
+		}
+		// Ratings of reaped models stay behind
+		Ratings::<Test>::insert(7, 3, Ratings::<Test>::get(0, 3).unwrap());
+		let _ = RatingHistograms::<Test>::clear(u32::MAX, None);
+		StorageVersion::new(7).put::<AIRegistry>();
+
+		MigrateToV8::<Test>::on_runtime_upgrade();
+
+		assert_eq!(RatingHistograms::<Test>::get(0), [0, 2, 0, 0, 1]);
+		assert_eq!(RatingHistograms::<Test>::get(1), [0, 0, 0, 1, 0]);
+		assert!(!RatingHistograms::<Test>::contains_key(7));
+		assert_eq!(AIRegistry::get_median_rating(0), Some(2));
+		assert_eq!(AIRegistry::on_chain_storage_version(), 8);
+		assert_ok!(AIRegistry::do_try_state());
+	});
+}
+
+#[test]
+fn suspended_models_are_moved_under_review() {
+	use crate::migrations::v9::MigrateToV9;
+	use frame_support::traits::{GetStorageVersion, OnRuntimeUpgrade, StorageVersion};
+
+	new_test_ext().execute_with(|| {
+		register_test_model(1);
+		register_test_model(1);
+		// Suspended models used to be paused, but owners could deactivate them
+		for model_id in [0, 1] {
+			Models::<Test>::mutate(model_id, |model| {
+				model.as_mut().unwrap().status = ModelStatus::Paused
+			});
+			UnderReview::<Test>::insert(model_id, ModelStatus::Active);
+		}
+		GlobalStats::<Test>::mutate(|stats| stats.active_models = 0);
+		assert_ok!(AIRegistry::deactivate_model(RuntimeOrigin::signed(1), 1, None));
+		StorageVersion::new(8).put::<AIRegistry>();
+
+		MigrateToV9::<Test>::on_runtime_upgrade();
+
+		assert_eq!(Models::<Test>::get(0).unwrap().status, ModelStatus::UnderReview);
+		assert_eq!(UnderReview::<Test>::get(0), Some(ModelStatus::Active));
+		assert!(!UnderReview::<Test>::contains_key(1));
+		assert_eq!(AIRegistry::on_chain_storage_version(), 9);
+		assert_ok!(AIRegistry::do_try_state());
+	});
+}
+
+#[test]
+fn raters_of_rated_models_are_counted() {
+	use crate::migrations::v10::MigrateToV10;
+	use frame_support::traits::{GetStorageVersion, OnRuntimeUpgrade, StorageVersion};
+
+	new_test_ext().execute_with(|| {
+		register_test_model(1);
+		register_test_model(1);
+		RaterStakes::insert(4, 900);
+		assert_ok!(AIRegistry::rate_model(RuntimeOrigin::signed(3), 0, 5, None));
+		assert_ok!(AIRegistry::rate_model(RuntimeOrigin::signed(4), 0, 4, None));
+		assert_ok!(AIRegistry::rate_model(RuntimeOrigin::signed(4), 1, 2, None));
+		// Raters used to be counted only by weight
+		let _ = RaterCounts::<Test>::clear(u32::MAX, None);
+		StorageVersion::new(9).put::<AIRegistry>();
+
+		MigrateToV10::<Test>::on_runtime_upgrade();
+
+		assert_eq!(RaterCounts::<Test>::get(0), 2);
+		assert_eq!(RaterCounts::<Test>::get(1), 1);
+		assert_eq!(AIRegistry::on_chain_storage_version(), 10);
+		assert_ok!(AIRegistry::do_try_state());
+	});
+}
+
+#[test]
+fn owners_hold_at_most_max_models_per_owner() {
+	new_test_ext().execute_with(|| {
+		let register = |who| {
+			AIRegistry::register_model(
+				RuntimeOrigin::signed(who),
+				cid(NextModelId::<Test>::get()),
+				name(NextModelId::<Test>::get()),
+				b"Description".to_vec(),
+				ModelType::Classification,
+				500,
+				License::Mit,
+				1_000,
+				vec![],
+			)
+		};
+		assert_ok!(register(1));
+		assert_ok!(register(2));
+		// Fill the rest of account 2's set with models it does not hold
+		OwnedModels::<Test>::mutate(2, |owned| {
+			for model_id in 100..115 {
+				owned.try_insert(model_id).unwrap();
+			}
+		});
+
+		assert_noop!(register(2), Error::<Test>::TooManyModels);
+		assert_noop!(
+			AIRegistry::transfer_model(RuntimeOrigin::signed(1), 0, 2),
+			Error::<Test>::TooManyModels
+		);
+	});
+}
+
+#[test]
+fn register_dataset_works() {
+	new_test_ext().execute_with(|| {
+		let ipfs_cid = b"QmYwAPJzv5CZsnA625s3Xf2nemtYgPpHdWEz79ojWnPbdG".to_vec();
+		assert_noop!(
+			AIRegistry::register_dataset(
+				RuntimeOrigin::signed(1),
+				b"invalid".to_vec(),
+				b"Data".to_vec(),
+				License::Mit
+			),
+			Error::<Test>::InvalidIPFSCID
+		);
+
+		let free = Balances::free_balance(1);
+		assert_ok!(AIRegistry::register_dataset(
+			RuntimeOrigin::signed(1),
+			ipfs_cid.clone(),
+			b"ImageNet".to_vec(),
+			License::Apache2
+		));
+		System::assert_last_event(
+			Event::DatasetRegistered {
+				dataset_id: 0,
+				owner: 1,
+				ipfs_cid: ipfs_cid.clone().try_into().unwrap(),
+			}
+			.into(),
+		);
+		let dataset = Datasets::<Test>::get(0).unwrap();
+		assert_eq!(dataset.owner, 1);
+		assert_eq!(dataset.name.to_vec(), b"ImageNet".to_vec());
+		assert_eq!(dataset.license, License::Apache2);
+		assert_eq!(Balances::free_balance(1), free - 100);
+	});
+}
+
+#[test]
+fn models_reference_registered_datasets() {
+	new_test_ext().execute_with(|| {
+		let ipfs_cid = b"QmYwAPJzv5CZsnA625s3Xf2nemtYgPpHdWEz79ojWnPbdG".to_vec();
+		for _ in 0..4 {
+			assert_ok!(AIRegistry::register_dataset(
+				RuntimeOrigin::signed(2),
+				ipfs_cid.clone(),
+				b"Data".to_vec(),
+				License::Mit
+			));
+		}
+		let register = |datasets: Vec<u64>| {
+			AIRegistry::register_model(
+				RuntimeOrigin::signed(1),
+				cid(NextModelId::<Test>::get()),
+				name(NextModelId::<Test>::get()),
+				b"Description".to_vec(),
+				ModelType::Classification,
+				100,
+				License::Mit,
+				1_000,
+				datasets,
+			)
+		};
+
+		assert_noop!(register(vec![0, 9]), Error::<Test>::DatasetNotFound);
+		assert_noop!(register(vec![0, 1, 0]), Error::<Test>::DuplicateDataset);
+		assert_noop!(register(vec![0, 1, 2, 3]), Error::<Test>::TooManyDatasets);
+
+		assert_ok!(register(vec![2, 0]));
+		System::assert_last_event(
+			Event::ModelDatasetsLinked { model_id: 0, datasets: vec![2, 0].try_into().unwrap() }
+				.into(),
+		);
+		assert_eq!(ModelDatasets::<Test>::get(0).unwrap().into_inner(), vec![2, 0]);
+
+		// Datasets are optional
+		assert_ok!(register(vec![]));
+		assert!(!ModelDatasets::<Test>::contains_key(1));
+	});
+}
+
+/// Register a model owned by account 1 and fork it as account 2 with `parent_share`
+fn register_and_fork(parent_share: Perbill) {
+	assert_ok!(AIRegistry::register_model(
+		RuntimeOrigin::signed(1),
+		cid(NextModelId::<Test>::get()),
+		b"Model".to_vec(),
+		b"Description".to_vec(),
+		ModelType::Generative,
+		500,
+		License::Apache2,
+		1_000,
+		vec![]
+	));
+	assert_ok!(AIRegistry::fork_model(
+		RuntimeOrigin::signed(2),
+		0,
+		cid(NextModelId::<Test>::get()),
+		b"Fine-tune".to_vec(),
+		b"Fine-tuned on legal text".to_vec(),
+		800,
+		License::Mit,
+		2_000,
+		parent_share
+	));
+}
+
+#[test]
+fn fork_model_records_parent() {
+	new_test_ext().execute_with(|| {
+		let fork = |parent_id, parent_share| {
+			AIRegistry::fork_model(
+				RuntimeOrigin::signed(2),
+				parent_id,
+				cid(NextModelId::<Test>::get()),
+				b"Fork".to_vec(),
+				b"Description".to_vec(),
+				800,
+				License::Mit,
+				2_000,
+				parent_share,
+			)
+		};
+		assert_noop!(fork(0, Perbill::zero()), Error::<Test>::ModelNotFound);
+
+		register_and_fork(Perbill::from_percent(20));
+		System::assert_last_event(
+			Event::ModelForked {
+				model_id: 1,
+				parent_id: 0,
+				owner: 2,
+				parent_share: Perbill::from_percent(20),
+			}
+			.into(),
+		);
+
+		let model = Models::<Test>::get(1).unwrap();
+		assert_eq!((model.owner, model.creator, model.parent), (2, 2, Some(0)));
+		assert_eq!(model.model_type, ModelType::Generative);
+		assert_eq!((model.price, model.size_bytes, model.license), (800, 2_000, License::Mit));
+		assert_eq!(Balances::reserved_balance(2), 3_000);
+		assert_eq!(Models::<Test>::get(0).unwrap().parent, None);
+		assert_eq!(ParentShare::<Test>::get(1), Some(Perbill::from_percent(20)));
+
+		// Forks without a parent share record no share
+		assert_ok!(fork(1, Perbill::zero()));
+		assert_eq!(Models::<Test>::get(2).unwrap().parent, Some(1));
+		assert!(!ParentShare::<Test>::contains_key(2));
+
+		assert_noop!(fork(0, Perbill::from_percent(51)), Error::<Test>::ParentShareTooHigh);
+		assert_ok!(AIRegistry::do_try_state());
+	});
+}
+
+#[test]
+fn forks_pass_revenue_share_to_parent_owner() {
+	new_test_ext().execute_with(|| {
+		let pct = Perbill::from_percent;
+		register_and_fork(pct(20));
+		assert_eq!(AIRegistry::revenue_shares(1), vec![(1, pct(20)), (2, pct(80))]);
+
+		// The share follows the parent to its new owner
+		assert_ok!(AIRegistry::transfer_model(RuntimeOrigin::signed(1), 0, 3));
+		assert_eq!(AIRegistry::revenue_shares(1), vec![(3, pct(20)), (2, pct(80))]);
+
+		// Shareholders and revenue recipients split what the parent leaves
+		assert_ok!(AIRegistry::fractionalize_model(
+			RuntimeOrigin::signed(2),
+			1,
+			vec![(2, pct(75)), (4, pct(25))]
+		));
+		assert_eq!(AIRegistry::revenue_shares(1), vec![(3, pct(20)), (2, pct(60)), (4, pct(20))]);
+		AIRegistry::set_revenue_recipient(1, Some(5));
+		assert_eq!(AIRegistry::revenue_shares(1), vec![(3, pct(20)), (5, pct(80))]);
+		AIRegistry::set_revenue_recipient(1, None);
+
+		// Once the parent is gone the fork keeps all of its revenue
+		Models::<Test>::remove(0);
+		assert_eq!(AIRegistry::revenue_shares(1), vec![(2, pct(75)), (4, pct(25))]);
+	});
+}
+
+#[test]
+fn collections_group_owned_models() {
+	new_test_ext().execute_with(|| {
+		register_and_fork(Perbill::zero());
+		assert_noop!(
+			AIRegistry::create_collection(RuntimeOrigin::signed(4), vec![b'x'; 1_000]),
+			Error::<Test>::NameTooLong
+		);
+		assert_ok!(AIRegistry::create_collection(
+			RuntimeOrigin::signed(1),
+			b"Vision suite".to_vec()
+		));
+		System::assert_last_event(
+			Event::CollectionCreated {
+				collection_id: 0,
+				owner: 1,
+				name: b"Vision suite".to_vec().try_into().unwrap(),
+			}
+			.into(),
+		);
+
+		assert_noop!(
+			AIRegistry::add_to_collection(RuntimeOrigin::signed(1), 1, 0),
+			Error::<Test>::CollectionNotFound
+		);
+		assert_noop!(
+			AIRegistry::add_to_collection(RuntimeOrigin::signed(2), 0, 1),
+			Error::<Test>::NotCollectionOwner
+		);
+		assert_noop!(
+			AIRegistry::add_to_collection(RuntimeOrigin::signed(1), 0, 1),
+			Error::<Test>::UnauthorizedAccess
+		);
+		assert_ok!(AIRegistry::add_to_collection(RuntimeOrigin::signed(1), 0, 0));
+		System::assert_last_event(
+			Event::ModelAddedToCollection { collection_id: 0, model_id: 0 }.into(),
+		);
+		assert_noop!(
+			AIRegistry::add_to_collection(RuntimeOrigin::signed(1), 0, 0),
+			Error::<Test>::AlreadyInCollection
+		);
+		assert_eq!(
+			Collections::<Test>::get(0).unwrap().models.into_iter().collect::<Vec<_>>(),
+			vec![0]
+		);
+
+		assert_noop!(
+			AIRegistry::remove_from_collection(RuntimeOrigin::signed(1), 0, 1),
+			Error::<Test>::NotInCollection
+		);
+		assert_noop!(
+			AIRegistry::remove_from_collection(RuntimeOrigin::signed(2), 0, 0),
+			Error::<Test>::NotCollectionOwner
+		);
+		assert_ok!(AIRegistry::remove_from_collection(RuntimeOrigin::signed(1), 0, 0));
+		System::assert_last_event(
+			Event::ModelRemovedFromCollection { collection_id: 0, model_id: 0 }.into(),
+		);
+		assert!(Collections::<Test>::get(0).unwrap().models.is_empty());
+	});
+}
+
+#[test]
+fn collections_are_bounded_and_drop_sold_models() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(AIRegistry::create_collection(RuntimeOrigin::signed(1), b"Family".to_vec()));
+		for model_id in 0..4 {
+			assert_ok!(AIRegistry::register_model(
+				RuntimeOrigin::signed(1),
+				cid(NextModelId::<Test>::get()),
+				name(NextModelId::<Test>::get()),
+				b"Description".to_vec(),
+				ModelType::Generative,
+				500,
+				License::Mit,
+				1,
+				vec![]
+			));
+			if model_id < 3 {
+				assert_ok!(AIRegistry::add_to_collection(RuntimeOrigin::signed(1), 0, model_id));
+			}
+		}
+		assert_noop!(
+			AIRegistry::add_to_collection(RuntimeOrigin::signed(1), 0, 3),
+			Error::<Test>::CollectionFull
+		);
+
+		// A model is in at most two collections
+		for collection_id in 1..3 {
+			assert_ok!(AIRegistry::create_collection(RuntimeOrigin::signed(1), b"Other".to_vec()));
+			if collection_id < 2 {
+				assert_ok!(AIRegistry::add_to_collection(RuntimeOrigin::signed(1), 1, 0));
+			}
+		}
+		assert_noop!(
+			AIRegistry::add_to_collection(RuntimeOrigin::signed(1), 2, 0),
+			Error::<Test>::TooManyCollections
+		);
+		assert_eq!(ModelCollections::<Test>::get(0).into_iter().collect::<Vec<_>>(), vec![0, 1]);
+
+		// Once the model changes hands anyone can clear it from the collection
+		assert_ok!(AIRegistry::transfer_model(RuntimeOrigin::signed(1), 1, 2));
+		assert_ok!(AIRegistry::remove_from_collection(RuntimeOrigin::signed(3), 0, 1));
+		assert_ok!(AIRegistry::add_to_collection(RuntimeOrigin::signed(1), 0, 3));
+		assert_eq!(
+			Collections::<Test>::get(0).unwrap().models.into_iter().collect::<Vec<_>>(),
+			vec![0, 2, 3]
+		);
+	});
+}
+
+#[test]
+fn deprecate_model_points_at_successor() {
+	new_test_ext().execute_with(|| {
+		register_and_fork(Perbill::zero());
+		assert_noop!(
+			AIRegistry::deprecate_model(RuntimeOrigin::signed(2), 0, Some(1)),
+			Error::<Test>::UnauthorizedAccess
+		);
+		for successor in [0, 2] {
+			assert_noop!(
+				AIRegistry::deprecate_model(RuntimeOrigin::signed(1), 0, Some(successor)),
+				Error::<Test>::InvalidSuccessor
+			);
+		}
+		assert_ok!(AIRegistry::update_model_metadata(
+			RuntimeOrigin::signed(2),
+			1,
+			None,
+			None,
+			Some(ModelStatus::Paused),
+			None
+		));
+		assert_noop!(
+			AIRegistry::deprecate_model(RuntimeOrigin::signed(1), 0, Some(1)),
+			Error::<Test>::InvalidSuccessor
+		);
+		assert_ok!(AIRegistry::update_model_metadata(
+			RuntimeOrigin::signed(2),
+			1,
+			None,
+			None,
+			Some(ModelStatus::Active),
+			None
+		));
+
+		assert_ok!(AIRegistry::deprecate_model(RuntimeOrigin::signed(1), 0, Some(1)));
+		System::assert_last_event(
+			Event::ModelDeprecated { model_id: 0, successor: Some(1) }.into(),
+		);
+		assert_eq!(Models::<Test>::get(0).unwrap().status, ModelStatus::Deprecated);
+		assert_eq!(AIRegistry::successor(0), Some(1));
+
+		// Reviving the model drops the pointer
+		assert_ok!(AIRegistry::update_model_metadata(
+			RuntimeOrigin::signed(1),
+			0,
+			None,
+			None,
+			Some(ModelStatus::Active),
+			None
+		));
+		assert!(!Successors::<Test>::contains_key(0));
+
+		// Deprecating without a successor is allowed
+		assert_ok!(AIRegistry::deprecate_model(RuntimeOrigin::signed(1), 0, None));
+		assert_eq!(Models::<Test>::get(0).unwrap().status, ModelStatus::Deprecated);
+		assert_eq!(AIRegistry::successor(0), None);
+
+		assert_ok!(AIRegistry::deactivate_model(RuntimeOrigin::signed(2), 1, None));
+		assert_noop!(
+			AIRegistry::deprecate_model(RuntimeOrigin::signed(2), 1, None),
+			Error::<Test>::ModelNotActive
+		);
+		assert_ok!(AIRegistry::do_try_state());
+	});
+}
+
+#[test]
+fn bookmarks_are_bounded_per_account() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			AIRegistry::bookmark_model(RuntimeOrigin::signed(2), 0),
+			Error::<Test>::ModelNotFound
+		);
+		for _ in 0..4 {
+			assert_ok!(AIRegistry::register_model(
+				RuntimeOrigin::signed(1),
+				cid(NextModelId::<Test>::get()),
+				name(NextModelId::<Test>::get()),
+				b"Description".to_vec(),
+				ModelType::Generative,
+				500,
+				License::Mit,
+				1,
+				vec![]
+			));
+		}
+
+		for model_id in [2, 0, 1] {
+			assert_ok!(AIRegistry::bookmark_model(RuntimeOrigin::signed(2), model_id));
+		}
+		System::assert_last_event(Event::ModelBookmarked { who: 2, model_id: 1 }.into());
+		assert_noop!(
+			AIRegistry::bookmark_model(RuntimeOrigin::signed(2), 0),
+			Error::<Test>::AlreadyBookmarked
+		);
+		assert_noop!(
+			AIRegistry::bookmark_model(RuntimeOrigin::signed(2), 3),
+			Error::<Test>::TooManyBookmarks
+		);
+		assert_eq!(Bookmarks::<Test>::get(2).into_iter().collect::<Vec<_>>(), vec![0, 1, 2]);
+		assert!(Bookmarks::<Test>::get(3).is_empty());
+
+		assert_noop!(
+			AIRegistry::unbookmark_model(RuntimeOrigin::signed(3), 0),
+			Error::<Test>::NotBookmarked
+		);
+		assert_ok!(AIRegistry::unbookmark_model(RuntimeOrigin::signed(2), 1));
+		System::assert_last_event(Event::ModelUnbookmarked { who: 2, model_id: 1 }.into());
+		assert_noop!(
+			AIRegistry::unbookmark_model(RuntimeOrigin::signed(2), 1),
+			Error::<Test>::NotBookmarked
+		);
+		assert_ok!(AIRegistry::bookmark_model(RuntimeOrigin::signed(2), 3));
+		assert_eq!(Bookmarks::<Test>::get(2).into_iter().collect::<Vec<_>>(), vec![0, 2, 3]);
+
+		for model_id in [0, 2, 3] {
+			assert_ok!(AIRegistry::unbookmark_model(RuntimeOrigin::signed(2), model_id));
+		}
+		assert!(!Bookmarks::<Test>::contains_key(2));
+	});
+}
+
+#[test]
+fn watchers_are_notified_of_price_and_status_changes() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			AIRegistry::watch_model(RuntimeOrigin::signed(2), 0),
+			Error::<Test>::ModelNotFound
+		);
+		register_and_fork(Perbill::zero());
+		for watcher in [2, 3, 4] {
+			assert_ok!(AIRegistry::watch_model(RuntimeOrigin::signed(watcher), 0));
+		}
+		System::assert_last_event(Event::ModelWatched { who: 4, model_id: 0 }.into());
+		assert_noop!(
+			AIRegistry::watch_model(RuntimeOrigin::signed(2), 0),
+			Error::<Test>::AlreadyWatching
+		);
+		assert_noop!(
+			AIRegistry::watch_model(RuntimeOrigin::signed(5), 0),
+			Error::<Test>::TooManyWatchers
+		);
+
+		let watched = |change| {
+			System::events()
+				.into_iter()
+				.filter_map(|record| match record.event {
+					RuntimeEvent::AIRegistry(Event::WatchedModelChanged {
+						model_id: 0,
+						change: c,
+						watchers,
+					}) if c == change => Some(watchers.into_inner()),
+					_ => None,
+				})
+				.collect::<Vec<_>>()
+		};
+
+		// Watchers are listed two per event
+		assert_ok!(AIRegistry::update_model_metadata(
+			RuntimeOrigin::signed(1),
+			0,
+			Some(700),
+			Some(b"New description".to_vec()),
+			Some(ModelStatus::Paused),
+			None
+		));
+		assert_eq!(watched(ModelChange::Price { old: 500, new: 700 }), vec![vec![2, 3], vec![4]]);
+		assert_eq!(
+			watched(ModelChange::Status { old: ModelStatus::Active, new: ModelStatus::Paused }),
+			vec![vec![2, 3], vec![4]]
+		);
+
+		// Setting the same values again notifies no one
+		System::reset_events();
+		assert_ok!(AIRegistry::update_model_metadata(
+			RuntimeOrigin::signed(1),
+			0,
+			Some(700),
+			None,
+			Some(ModelStatus::Paused),
+			None
+		));
+		assert_eq!(System::events().len(), 1);
+
+		assert_noop!(
+			AIRegistry::unwatch_model(RuntimeOrigin::signed(5), 0),
+			Error::<Test>::NotWatching
+		);
+		assert_ok!(AIRegistry::unwatch_model(RuntimeOrigin::signed(2), 0));
+		System::assert_last_event(Event::ModelUnwatched { who: 2, model_id: 0 }.into());
+		assert_eq!(Watchers::<Test>::get(0).into_inner(), vec![4, 3]);
+
+		assert_ok!(AIRegistry::deactivate_model(RuntimeOrigin::signed(1), 0, None));
+		assert_eq!(
+			watched(ModelChange::Status {
+				old: ModelStatus::Paused,
+				new: ModelStatus::Deactivated
+			}),
+			vec![vec![4, 3]]
+		);
+
+		// Models nobody watches change silently
+		System::reset_events();
+		assert_ok!(AIRegistry::deactivate_model(RuntimeOrigin::signed(2), 1, None));
+		assert!(!System::events().iter().any(|record| matches!(
+			record.event,
+			RuntimeEvent::AIRegistry(Event::WatchedModelChanged { .. })
+		)));
+	});
+}
+
+#[test]
+fn incentives_are_split_by_verified_usage() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		register_test_model(1);
+		register_test_model(2);
+		assert_noop!(
+			AIRegistry::fund_incentives(RuntimeOrigin::signed(1), 1_000, 300),
+			sp_runtime::DispatchError::BadOrigin
+		);
+		let pot = Balances::free_balance(AIRegistry::incentive_pot());
+		assert_ok!(AIRegistry::fund_incentives(RuntimeOrigin::root(), 1_000, 300));
+		System::assert_last_event(Event::IncentivesFunded { amount: 1_000, per_era: 300 }.into());
+		assert_eq!(Balances::free_balance(99), 9_000);
+		assert_eq!(Balances::free_balance(AIRegistry::incentive_pot()), pot + 1_000);
+
+		// The first verified inference of an era takes its budget from the funds
+		for model_id in [0, 0, 0, 1] {
+			assert_ok!(AIRegistry::increment_inference_count(RuntimeOrigin::root(), model_id));
+		}
+		assert_eq!(IncentiveFunds::<Test>::get(), 700);
+		assert_eq!(IncentiveUsage::<Test>::get(0), Some((0, 3)));
+		assert_noop!(
+			AIRegistry::claim_incentive(RuntimeOrigin::signed(3), 0),
+			Error::<Test>::NoIncentiveDue
+		);
+
+		// Once the era is over, its budget is paid out by share of the usage
+		System::set_block_number(10);
+		let free = Balances::free_balance(1);
+		assert_ok!(AIRegistry::claim_incentive(RuntimeOrigin::signed(3), 0));
+		System::assert_last_event(
+			Event::IncentiveClaimed { model_id: 0, owner: 1, amount: 225 }.into(),
+		);
+		assert_eq!(Balances::free_balance(1), free + 225);
+		assert!(!IncentiveEarnings::<Test>::contains_key(0));
+
+		// Usage in a new era credits the model's share of the previous one
+		assert_ok!(AIRegistry::increment_inference_count(RuntimeOrigin::root(), 1));
+		assert_eq!(IncentiveEarnings::<Test>::get(1), 75);
+		assert!(!EraIncentives::<Test>::contains_key(0));
+		assert_eq!(IncentiveFunds::<Test>::get(), 400);
+		let free = Balances::free_balance(2);
+		assert_ok!(AIRegistry::claim_incentive(RuntimeOrigin::signed(3), 1));
+		assert_eq!(Balances::free_balance(2), free + 75);
+
+		// Inactive models don't earn incentives
+		assert_ok!(AIRegistry::deactivate_model(RuntimeOrigin::signed(1), 0, None));
+		assert_ok!(AIRegistry::increment_inference_count(RuntimeOrigin::root(), 0));
+		assert_eq!(IncentiveUsage::<Test>::get(0), None);
+	});
+}
+
+#[test]
+fn top_models_earn_era_rewards() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		for owner in 1..=3 {
+			register_test_model(owner);
+		}
+		for (model_id, raters, rating) in [(0, [2, 3], 4), (1, [1, 3], 3), (2, [1, 2], 5)] {
+			for rater in raters {
+				assert_ok!(AIRegistry::rate_model(
+					RuntimeOrigin::signed(rater),
+					model_id,
+					rating,
+					None
+				));
+			}
+		}
+		// Only the two best-rated models make the board
+		assert_eq!(TopModels::<Test>::get().to_vec(), vec![(2, 500), (0, 400)]);
+
+		// Rewards are credited by reputation at the start of each era
+		AIRegistry::on_initialize(5);
+		assert!(!EraRewards::<Test>::contains_key(2));
+		AIRegistry::on_initialize(10);
+		System::assert_last_event(Event::EraRewardsCredited { models: 2, amount: 90 }.into());
+		assert_eq!(EraRewards::<Test>::get(2), 50);
+		assert_eq!(EraRewards::<Test>::get(0), 40);
+
+		// Claims mint the rewards to the owner
+		let issuance = Balances::total_issuance();
+		let free = Balances::free_balance(3);
+		assert_ok!(AIRegistry::claim_reward(RuntimeOrigin::signed(1), 2));
+		System::assert_last_event(
+			Event::RewardClaimed { model_id: 2, owner: 3, amount: 50 }.into(),
+		);
+		assert_eq!(Balances::free_balance(3), free + 50);
+		assert_eq!(Balances::total_issuance(), issuance + 50);
+		assert_noop!(
+			AIRegistry::claim_reward(RuntimeOrigin::signed(1), 2),
+			Error::<Test>::NoRewardDue
+		);
+		assert_noop!(
+			AIRegistry::claim_reward(RuntimeOrigin::signed(1), 1),
+			Error::<Test>::NoRewardDue
+		);
+
+		// Inactive models drop off the board
+		assert_ok!(AIRegistry::deactivate_model(RuntimeOrigin::signed(1), 0, None));
+		AIRegistry::on_initialize(20);
+		assert_eq!(EraRewards::<Test>::get(2), 90);
+		assert_eq!(EraRewards::<Test>::get(0), 40);
+		assert_eq!(TopModels::<Test>::get().to_vec(), vec![(2, 500)]);
+	});
+}
+
+#[test]
+fn front_page_queries_use_the_leaderboards() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		for owner in [1, 2, 3, 1] {
+			register_test_model(owner);
+		}
+		for model_id in [1, 3, 1, 0, 3, 1, 2] {
+			assert_ok!(AIRegistry::increment_inference_count(RuntimeOrigin::root(), model_id));
+		}
+		// Model 2 only ties the last model on a full board
+		assert_eq!(MostUsedModels::<Test>::get().to_vec(), vec![(1, 3), (3, 2), (0, 1)]);
+		assert_eq!(AIRegistry::top_models_by_inferences(2), vec![1, 3]);
+		assert_eq!(AIRegistry::top_models_by_inferences(10), vec![1, 3, 0]);
+
+		for (model_id, raters, rating) in [(2, [1, 3], 5), (0, [2, 3], 4)] {
+			for rater in raters {
+				assert_ok!(AIRegistry::rate_model(
+					RuntimeOrigin::signed(rater),
+					model_id,
+					rating,
+					None
+				));
+			}
+		}
+		assert_eq!(AIRegistry::top_models_by_rating(5), vec![2, 0]);
+		assert_eq!(AIRegistry::recently_registered(3), vec![3, 2, 1]);
+
+		// Inactive models are left out
+		assert_ok!(AIRegistry::deactivate_model(RuntimeOrigin::signed(1), 3, None));
+		assert_eq!(AIRegistry::recently_registered(3), vec![2, 1, 0]);
+		assert_eq!(AIRegistry::top_models_by_inferences(3), vec![1, 0]);
+
+		// Models climb the board as they are used, behind those they tie
+		assert_ok!(AIRegistry::increment_inference_count(RuntimeOrigin::root(), 2));
+		assert_eq!(MostUsedModels::<Test>::get().to_vec(), vec![(1, 3), (3, 2), (2, 2)]);
+	});
+}
+
+#[test]
+fn registry_inspect_answers_model_queries() {
+	new_test_ext().execute_with(|| {
+		assert_eq!(<AIRegistry as AiRegistryInspect<u64, u128>>::owner(0), None);
+		assert!(!<AIRegistry as AiRegistryInspect<u64, u128>>::is_active(0));
+
+		register_test_model(1);
+		assert_eq!(<AIRegistry as AiRegistryInspect<u64, u128>>::owner(0), Some(1));
+		assert_eq!(<AIRegistry as AiRegistryInspect<u64, u128>>::price(0), Some(500));
+		assert_eq!(
+			<AIRegistry as AiRegistryInspect<u64, u128>>::model_type(0),
+			Some(ModelType::Classification)
+		);
+		assert!(<AIRegistry as AiRegistryInspect<u64, u128>>::is_active(0));
+
+		assert_ok!(AIRegistry::deactivate_model(RuntimeOrigin::signed(1), 0, None));
+		assert!(!<AIRegistry as AiRegistryInspect<u64, u128>>::is_active(0));
+	});
+}
+
+#[test]
+fn hooks_follow_registration_and_status_changes() {
+	new_test_ext().execute_with(|| {
+		register_test_model(1);
+		assert_eq!(RegisteredModels::get(), vec![(0, 1)]);
+
+		// Setting the current status again is not a change
+		assert_ok!(AIRegistry::update_model_metadata(
+			RuntimeOrigin::signed(1),
+			0,
+			None,
+			None,
+			Some(ModelStatus::Active),
+			None
+		));
+		assert!(StatusChanges::get().is_empty());
+
+		assert_ok!(AIRegistry::update_model_metadata(
+			RuntimeOrigin::signed(1),
+			0,
+			None,
+			None,
+			Some(ModelStatus::Paused),
+			None
+		));
+		assert_ok!(AIRegistry::deactivate_model(RuntimeOrigin::signed(1), 0, None));
+		assert_eq!(
+			StatusChanges::get(),
+			vec![
+				(0, ModelStatus::Active, ModelStatus::Paused),
+				(0, ModelStatus::Paused, ModelStatus::Deactivated)
+			]
+		);
+	});
+}
+
+#[test]
+fn rename_model_corrects_name_and_type() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		register_test_model(1);
+
+		assert_noop!(
+			AIRegistry::rename_model(RuntimeOrigin::signed(1), 0, vec![b'x'; 257], None),
+			Error::<Test>::NameTooLong
+		);
+		assert_noop!(
+			AIRegistry::rename_model(RuntimeOrigin::signed(2), 0, b"Mine".to_vec(), None),
+			Error::<Test>::UnauthorizedAccess
+		);
+
+		// Keeping the type
+		assert_ok!(AIRegistry::rename_model(RuntimeOrigin::signed(1), 0, b"Modle".to_vec(), None));
+		assert_eq!(Models::<Test>::get(0).unwrap().model_type, ModelType::Classification);
+
+		assert_ok!(AIRegistry::rename_model(
+			RuntimeOrigin::signed(1),
+			0,
+			b"Model v2".to_vec(),
+			Some(ModelType::Regression)
+		));
+		System::assert_last_event(
+			Event::ModelRenamed {
+				model_id: 0,
+				old_name: b"Modle".to_vec().try_into().unwrap(),
+				new_name: b"Model v2".to_vec().try_into().unwrap(),
+				model_type: ModelType::Regression,
+			}
+			.into(),
+		);
+		let model = Models::<Test>::get(0).unwrap();
+		assert_eq!(model.name.into_inner(), b"Model v2".to_vec());
+		assert_eq!(model.model_type, ModelType::Regression);
+	});
+}
+
+#[test]
+fn locked_metadata_cannot_change() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		register_test_model(1);
+
+		assert_noop!(
+			AIRegistry::lock_metadata(RuntimeOrigin::signed(2), 0),
+			Error::<Test>::UnauthorizedAccess
+		);
+		assert_ok!(AIRegistry::lock_metadata(RuntimeOrigin::signed(1), 0));
+		System::assert_last_event(Event::MetadataLocked { model_id: 0, owner: 1 }.into());
+		assert_eq!(LockedMetadata::<Test>::get(0), Some(1));
+		assert_noop!(
+			AIRegistry::lock_metadata(RuntimeOrigin::signed(1), 0),
+			Error::<Test>::MetadataLocked
+		);
+
+		assert_noop!(
+			AIRegistry::update_model_metadata(
+				RuntimeOrigin::signed(1),
+				0,
+				None,
+				Some(b"Changed".to_vec()),
+				None,
+				None
+			),
+			Error::<Test>::MetadataLocked
+		);
+		assert_noop!(
+			AIRegistry::rename_model(RuntimeOrigin::signed(1), 0, b"Renamed".to_vec(), None),
+			Error::<Test>::MetadataLocked
+		);
+
+		// Price and status stay editable
+		assert_ok!(AIRegistry::update_model_metadata(
+			RuntimeOrigin::signed(1),
+			0,
+			Some(800),
+			None,
+			Some(ModelStatus::Paused),
+			None
+		));
+		let model = Models::<Test>::get(0).unwrap();
+		assert_eq!(model.description.into_inner(), b"Description".to_vec());
+		assert_eq!(model.price, 800);
+	});
+}
+
+#[test]
+fn update_history_keeps_the_latest_changes() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		register_test_model(1);
+
+		// Unchanged values are not recorded
+		assert_ok!(AIRegistry::update_model_metadata(
+			RuntimeOrigin::signed(1),
+			0,
+			Some(500),
+			None,
+			Some(ModelStatus::Active),
+			None
+		));
+		assert!(UpdateHistory::<Test>::get(0).is_empty());
+
+		System::set_block_number(2);
+		assert_ok!(AIRegistry::update_model_metadata(
+			RuntimeOrigin::signed(1),
+			0,
+			Some(800),
+			Some(b"Better".to_vec()),
+			None,
+			None
+		));
+		System::set_block_number(3);
+		assert_ok!(AIRegistry::rename_model(
+			RuntimeOrigin::signed(1),
+			0,
+			b"Model v2".to_vec(),
+			Some(ModelType::Generative)
+		));
+
+		// Newest first, capped at `MaxUpdateHistory`
+		let history = UpdateHistory::<Test>::get(0);
+		let fields: Vec<_> =
+			history.iter().map(|update| (update.field, update.updated_at)).collect();
+		assert_eq!(
+			fields,
+			vec![
+				(MetadataField::Name, 3),
+				(MetadataField::ModelType, 3),
+				(MetadataField::Description, 2)
+			]
+		);
+		assert_eq!(
+			history[2].previous,
+			sp_io::hashing::blake2_256(&b"Description".to_vec().encode()).into()
+		);
+	});
+}
+
+#[test]
+fn recent_usage_keeps_a_rolling_window_of_eras() {
+	new_test_ext().execute_with(|| {
+		register_test_model(1);
+
+		// Eras are 10 blocks and the mock keeps three of them
+		for (block, inferences) in [(5, 2), (15, 1), (25, 3)] {
+			System::set_block_number(block);
+			for _ in 0..inferences {
+				assert_ok!(AIRegistry::increment_inference_count(RuntimeOrigin::root(), 0));
+			}
+		}
+		assert_eq!(RecentUsage::<Test>::get(0).into_inner(), vec![(2, 3), (1, 1), (0, 2)]);
+		assert_eq!(AIRegistry::recent_inferences(0, 1), 3);
+		assert_eq!(AIRegistry::recent_inferences(0, 3), 6);
+
+		// Era 0 falls out of the window
+		System::set_block_number(35);
+		assert_ok!(AIRegistry::increment_inference_count(RuntimeOrigin::root(), 0));
+		assert_eq!(RecentUsage::<Test>::get(0).into_inner(), vec![(3, 1), (2, 3), (1, 1)]);
+
+		// Idle eras count as zero, and old eras are skipped when reading
+		System::set_block_number(55);
+		assert_eq!(AIRegistry::recent_inferences(0, 1), 0);
+		assert_eq!(AIRegistry::recent_inferences(0, 3), 1);
+		assert_eq!(AIRegistry::recent_inferences(0, 10), 5);
+	});
+}
+
+#[test]
+fn recent_revenue_keeps_a_rolling_window_of_eras() {
+	new_test_ext().execute_with(|| {
+		register_test_model(1);
+
+		for (block, fee) in [(5, 200), (8, 300), (15, 100), (25, 50), (35, 400)] {
+			System::set_block_number(block);
+			assert_ok!(AIRegistry::note_fee_volume(RuntimeOrigin::root(), 0, fee));
+		}
+		// Era 0 fell out of the window, but the total keeps it
+		assert_eq!(RecentRevenue::<Test>::get(0).into_inner(), vec![(3, 400), (2, 50), (1, 100)]);
+		assert_eq!(AIRegistry::recent_revenue(0, 1), 400);
+		assert_eq!(AIRegistry::recent_revenue(0, 2), 450);
+		assert_eq!(AIRegistry::model_stats(0).unwrap().revenue, 1_050);
+
+		System::set_block_number(45);
+		assert_eq!(AIRegistry::recent_revenue(0, 1), 0);
+		assert_eq!(AIRegistry::recent_revenue(0, 10), 550);
+	});
+}
+
+#[test]
+fn revenue_history_covers_models_and_their_owners() {
+	new_test_ext().execute_with(|| {
+		assert_eq!(AIRegistry::model_revenue(0), None);
+		register_test_model(1);
+		register_test_model(1);
+		register_test_model(2);
+
+		for (block, model_id, fee) in
+			[(5, 0, 200), (15, 0, 100), (25, 0, 50), (25, 1, 30), (38, 2, 70)]
+		{
+			System::set_block_number(block);
+			assert_ok!(AIRegistry::note_fee_volume(RuntimeOrigin::root(), model_id, fee));
+		}
+		System::set_block_number(38);
+		// Era 0 fell out of the window, but the lifetime total keeps it
+		assert_eq!(
+			AIRegistry::model_revenue(0),
+			Some(RevenueHistory { current_era: 0, lifetime: 350, eras: vec![0, 50, 100] })
+		);
+		assert_eq!(
+			AIRegistry::owner_revenue(&1),
+			RevenueHistory { current_era: 0, lifetime: 380, eras: vec![0, 80, 100] }
+		);
+		assert_eq!(
+			AIRegistry::owner_revenue(&2),
+			RevenueHistory { current_era: 70, lifetime: 70, eras: vec![70, 0, 0] }
+		);
+		assert_eq!(
+			AIRegistry::owner_revenue(&3),
+			RevenueHistory { eras: vec![0; 3], ..Default::default() }
+		);
+	});
+}
+
+#[test]
+fn global_stats_follow_models_inferences_and_fees() {
+	new_test_ext().execute_with(|| {
+		register_test_model(1);
+		register_test_model(2);
+		assert_ok!(AIRegistry::increment_inference_count(RuntimeOrigin::root(), 0));
+		assert_ok!(AIRegistry::increment_inference_count(RuntimeOrigin::root(), 1));
+		assert_ok!(AIRegistry::note_fee_volume(RuntimeOrigin::root(), 1, 1_500));
+		assert_eq!(
+			AIRegistry::note_fee_volume(RuntimeOrigin::signed(1), 1, 1).ok(),
+			None,
+			"only InferenceOrigin records fees"
+		);
+
+		// Pausing and deactivating only count once
+		assert_ok!(AIRegistry::update_model_metadata(
+			RuntimeOrigin::signed(1),
+			0,
+			None,
+			None,
+			Some(ModelStatus::Paused),
+			None
+		));
+		assert_ok!(AIRegistry::deactivate_model(RuntimeOrigin::signed(1), 0, None));
+		assert_eq!(
+			GlobalStats::<Test>::get(),
+			RegistryStats {
+				total_models: 2,
+				active_models: 1,
+				total_inferences: 2,
+				fee_volume: 1_500
+			}
+		);
+
+		assert_ok!(AIRegistry::update_model_metadata(
+			RuntimeOrigin::signed(2),
+			1,
+			None,
+			None,
+			Some(ModelStatus::Paused),
+			None
+		));
+		assert_ok!(AIRegistry::update_model_metadata(
+			RuntimeOrigin::signed(2),
+			1,
+			None,
+			None,
+			Some(ModelStatus::Active),
+			None
+		));
+		assert_eq!(GlobalStats::<Test>::get().active_models, 1);
+		assert_ok!(AIRegistry::do_try_state());
+	});
+}
+
+#[test]
+fn model_stats_report_usage_revenue_and_ratings() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_eq!(AIRegistry::model_stats(0), None);
+		register_test_model(1);
+
+		assert_ok!(AIRegistry::increment_inference_count(RuntimeOrigin::root(), 0));
+		assert_ok!(AIRegistry::note_fee_volume(RuntimeOrigin::root(), 0, 500));
+		System::set_block_number(11);
+		assert_ok!(AIRegistry::increment_inference_count(RuntimeOrigin::root(), 0));
+		assert_ok!(AIRegistry::note_fee_volume(RuntimeOrigin::root(), 0, 500));
+		for (rater, rating) in [(2, 5), (3, 4), (4, 5)] {
+			assert_ok!(AIRegistry::rate_model(RuntimeOrigin::signed(rater), 0, rating, None));
+		}
+
+		assert_eq!(
+			AIRegistry::model_stats(0),
+			Some(ModelStats {
+				total_inferences: 2,
+				era_inferences: 1,
+				revenue: 1_000,
+				rating_count: 3,
+				rating_distribution: [0, 0, 0, 1, 2],
+			})
+		);
+		assert_eq!(AIRegistry::global_stats().fee_volume, 1_000);
+	});
+}
+
+#[test]
+fn registry_events_are_written_to_the_offchain_index() {
+	let mut ext = new_test_ext();
+	let (registered, transferred) = ext.execute_with(|| {
+		System::set_block_number(1);
+		register_test_model(1);
+		let registered = AIRegistry::model_index_key(0, 1, System::event_count() - 1);
+
+		System::set_block_number(2);
+		assert_ok!(AIRegistry::transfer_model(RuntimeOrigin::signed(1), 0, 2));
+		let transferred = AIRegistry::model_index_key(0, 2, System::event_count() - 1);
+		(registered, transferred)
+	});
+	ext.persist_offchain_overlay();
+	let db = ext.offchain_db();
+
+	let ipfs_cid = cid(0).try_into().unwrap();
+	assert_eq!(
+		db.get(&registered),
+		Some(Event::<Test>::ModelRegistered { model_id: 0, owner: 1, ipfs_cid }.encode())
+	);
+	assert_eq!(
+		db.get(&transferred),
+		Some(Event::<Test>::ModelTransferred { model_id: 0, from: 1, to: 2 }.encode())
+	);
+	// Only the current owner keeps an entry, holding the block it acquired the model at
+	assert_eq!(db.get(&AIRegistry::owner_index_key(&1, 0)), None);
+	assert_eq!(db.get(&AIRegistry::owner_index_key(&2, 0)), Some(2u64.encode()));
+}
+
+#[test]
+fn price_only_updates_are_refunded() {
+	new_test_ext().execute_with(|| {
+		register_test_model(1);
+
+		let info = AIRegistry::update_model_metadata(
+			RuntimeOrigin::signed(1),
+			0,
+			Some(1000),
+			None,
+			None,
+			None,
+		)
+		.unwrap();
+		assert_eq!(info.actual_weight, Some(<() as WeightInfo>::update_model_price()));
+		assert!(<() as WeightInfo>::update_model_price()
+			.all_lt(<() as WeightInfo>::update_model_metadata()));
+
+		// Anything beyond the price is charged in full
+		let info = AIRegistry::update_model_metadata(
+			RuntimeOrigin::signed(1),
+			0,
+			Some(2000),
+			None,
+			Some(ModelStatus::Paused),
+			None,
+		)
+		.unwrap();
+		assert_eq!(info.actual_weight, None);
+	});
+}
+
+#[test]
+fn model_stake_sums_the_deposits_of_owned_models() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		register_test_model(1);
+		register_test_model(1);
+		register_test_model(2);
+		assert_eq!(AIRegistry::model_stake(&1), 4_000);
+		assert_eq!(AIRegistry::model_stake(&2), 2_000);
+		assert_eq!(AIRegistry::model_stake(&3), 0);
+
+		// Deactivated models hold no stake
+		assert_ok!(AIRegistry::deactivate_model(RuntimeOrigin::signed(1), 0, None));
+		assert_eq!(AIRegistry::model_stake(&1), 2_000);
+	});
+}
+
+#[test]
+fn owners_announce_price_changes_in_advance() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		register_test_model(1);
+		register_test_model(1);
+		register_test_model(1);
+
+		assert_noop!(
+			AIRegistry::schedule_price_change(RuntimeOrigin::signed(1), 0, 800, 1),
+			Error::<Test>::PriceChangeNotInFuture
+		);
+		assert_noop!(
+			AIRegistry::schedule_price_change(RuntimeOrigin::signed(2), 0, 800, 5),
+			Error::<Test>::UnauthorizedAccess
+		);
+		assert_ok!(AIRegistry::schedule_price_change(RuntimeOrigin::signed(1), 0, 800, 5));
+		System::assert_last_event(
+			Event::PriceChangeScheduled { model_id: 0, price: 800, at: 5 }.into(),
+		);
+		assert_eq!(PendingPriceChanges::<Test>::get(0), Some((5, 800)));
+
+		// Scheduling again replaces the announced change
+		assert_ok!(AIRegistry::schedule_price_change(RuntimeOrigin::signed(1), 0, 700, 4));
+		assert_eq!(PendingPriceChanges::<Test>::get(0), Some((4, 700)));
+		assert!(ScheduledPriceChanges::<Test>::get(5).is_empty());
+		assert_ok!(AIRegistry::schedule_price_change(RuntimeOrigin::signed(1), 1, 900, 4));
+		assert_noop!(
+			AIRegistry::schedule_price_change(RuntimeOrigin::signed(1), 2, 900, 4),
+			Error::<Test>::TooManyScheduledPriceChanges
+		);
+
+		assert_ok!(AIRegistry::cancel_price_change(RuntimeOrigin::signed(1), 1));
+		System::assert_last_event(Event::PriceChangeCancelled { model_id: 1 }.into());
+		assert_noop!(
+			AIRegistry::cancel_price_change(RuntimeOrigin::signed(1), 1),
+			Error::<Test>::NoPriceChangeScheduled
+		);
+
+		// The price only changes once the block is reached
+		AIRegistry::on_initialize(3);
+		assert_eq!(Models::<Test>::get(0).unwrap().price, 500);
+		AIRegistry::on_initialize(4);
+		System::assert_last_event(Event::ModelUpdated { model_id: 0, owner: 1 }.into());
+		assert_eq!(Models::<Test>::get(0).unwrap().price, 700);
+		assert_eq!(Models::<Test>::get(1).unwrap().price, 500);
+		assert_eq!(PendingPriceChanges::<Test>::get(0), None);
+		assert!(ScheduledPriceChanges::<Test>::get(4).is_empty());
+	});
+}
+
+#[test]
+fn owners_schedule_end_of_life_with_advance_notice() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		register_test_model(1);
+		register_test_model(1);
+
+		assert_noop!(
+			AIRegistry::deactivate_at(RuntimeOrigin::signed(1), 0, 1, None),
+			Error::<Test>::DeactivationNotInFuture
+		);
+		assert_noop!(
+			AIRegistry::deactivate_at(RuntimeOrigin::signed(1), 0, 102, None),
+			Error::<Test>::GracePeriodTooLong
+		);
+		assert_noop!(
+			AIRegistry::deactivate_at(RuntimeOrigin::signed(1), 0, 20, Some(0)),
+			Error::<Test>::InvalidSuccessor
+		);
+		assert_noop!(
+			AIRegistry::deactivate_at(RuntimeOrigin::signed(2), 0, 20, None),
+			Error::<Test>::UnauthorizedAccess
+		);
+
+		// The successor is marked right away, while the model stays usable
+		assert_ok!(AIRegistry::deactivate_at(RuntimeOrigin::signed(1), 0, 8, Some(1)));
+		System::assert_last_event(
+			Event::DeactivationScheduled { model_id: 0, owner: 1, at: 8 }.into(),
+		);
+		assert_eq!(PendingDeactivations::<Test>::get(0), Some(8));
+		assert_eq!(AIRegistry::successor(0), Some(1));
+		assert_eq!(Models::<Test>::get(0).unwrap().status, ModelStatus::Active);
+
+		// Users are reminded `DeactivationNotice` blocks before
+		AIRegistry::on_initialize(3);
+		System::assert_last_event(
+			Event::DeactivationUpcoming { model_id: 0, at: 8, successor: Some(1) }.into(),
+		);
+
+		AIRegistry::on_initialize(8);
+		System::assert_last_event(Event::ModelDeactivated { model_id: 0, owner: 1 }.into());
+		assert_eq!(Models::<Test>::get(0).unwrap().status, ModelStatus::Deactivated);
+		assert_eq!(AIRegistry::successor(0), Some(1));
+		assert_noop!(
+			AIRegistry::deactivate_at(RuntimeOrigin::signed(1), 0, 20, None),
+			Error::<Test>::ModelNotActive
+		);
+	});
+}
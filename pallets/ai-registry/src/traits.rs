@@ -0,0 +1,155 @@
+//! Interfaces the AI Registry pallet expects from the rest of the runtime
+
+use crate::{ModelId, ModelStatus, ModelType, ReceiptId};
+use core::marker::PhantomData;
+use frame_support::traits::Get;
+use sp_runtime::{
+	traits::{IntegerSquareRoot, SaturatedConversion},
+	DispatchResult,
+};
+
+/// Read access to registered models, for pallets that should not depend on the
+/// registry's storage layout
+pub trait AiRegistryInspect<AccountId, Balance> {
+	/// Current owner of `model_id`, if it exists
+	fn owner(model_id: ModelId) -> Option<AccountId>;
+
+	/// Price per inference of `model_id`, if it exists
+	fn price(model_id: ModelId) -> Option<Balance>;
+
+	/// Whether `model_id` exists and is active
+	fn is_active(model_id: ModelId) -> bool;
+
+	/// Type of `model_id`, if it exists
+	fn model_type(model_id: ModelId) -> Option<ModelType>;
+}
+
+/// Source of inference receipts, typically the pallet that settles inference payments
+pub trait InferenceReceipts<AccountId> {
+	/// Receipt proving `who` paid for an inference of `model_id`, if any
+	fn receipt_for(who: &AccountId, model_id: ModelId) -> Option<ReceiptId>;
+
+	/// Give `who` a receipt for `model_id`
+	#[cfg(feature = "runtime-benchmarks")]
+	fn create_receipt(who: &AccountId, model_id: ModelId);
+}
+
+/// No receipt source: ratings are recorded without provenance
+impl<AccountId> InferenceReceipts<AccountId> for () {
+	fn receipt_for(_who: &AccountId, _model_id: ModelId) -> Option<ReceiptId> {
+		None
+	}
+
+	#[cfg(feature = "runtime-benchmarks")]
+	fn create_receipt(_who: &AccountId, _model_id: ModelId) {}
+}
+
+/// What an account has at stake in the reputation of a model, e.g. what it spent on
+/// inferences of the model
+pub trait RaterStake<AccountId> {
+	/// Stake of `who` in the ratings of `model_id`
+	fn stake_of(who: &AccountId, model_id: ModelId) -> u128;
+}
+
+/// Influence of a rater on the rating of a model
+///
+/// A model's `total_rating` and `rating_count` add up ratings by weight, so its
+/// average rating is weighted. Reward and rebate thresholds count raters instead,
+/// kept in [`crate::pallet::RaterCounts`].
+pub trait RatingWeight<AccountId> {
+	/// Weight of the rating `who` gives `model_id`, at least one
+	fn rating_weight(who: &AccountId, model_id: ModelId) -> u32;
+}
+
+/// One rater, one vote
+impl<AccountId> RatingWeight<AccountId> for () {
+	fn rating_weight(_who: &AccountId, _model_id: ModelId) -> u32 {
+		1
+	}
+}
+
+/// Quadratic weighting as sybil resistance: a rater's influence grows with the square
+/// root of their `Stake` in the model, counted in `Unit`s
+///
+/// Raters with less than one `Unit` at stake still count once.
+pub struct QuadraticRatingWeight<Stake, Unit>(PhantomData<(Stake, Unit)>);
+
+impl<AccountId, Stake, Unit> RatingWeight<AccountId> for QuadraticRatingWeight<Stake, Unit>
+where
+	Stake: RaterStake<AccountId>,
+	Unit: Get<u128>,
+{
+	fn rating_weight(who: &AccountId, model_id: ModelId) -> u32 {
+		let units = Stake::stake_of(who, model_id) / Unit::get().max(1);
+		units.integer_sqrt().max(1).saturated_into()
+	}
+}
+
+/// NFTs representing model ownership, one per model in a dedicated collection
+///
+/// The registry mints a model's NFT to its owner on registration and burns it when
+/// the model is reaped. Whoever holds the NFT can take over the model in the registry.
+pub trait ModelNfts<AccountId> {
+	/// Mint the NFT of `model_id` to `owner`
+	fn mint(model_id: ModelId, owner: &AccountId) -> DispatchResult;
+
+	/// Burn the NFT of `model_id`
+	fn burn(model_id: ModelId) -> DispatchResult;
+
+	/// Current holder of the NFT of `model_id`, if it has one
+	fn owner(model_id: ModelId) -> Option<AccountId>;
+
+	/// Move the NFT of `model_id` to `to`
+	fn transfer(model_id: ModelId, to: &AccountId) -> DispatchResult;
+}
+
+/// No NFTs: ownership only changes through the registry
+impl<AccountId> ModelNfts<AccountId> for () {
+	fn mint(_model_id: ModelId, _owner: &AccountId) -> DispatchResult {
+		Ok(())
+	}
+
+	fn burn(_model_id: ModelId) -> DispatchResult {
+		Ok(())
+	}
+
+	fn owner(_model_id: ModelId) -> Option<AccountId> {
+		None
+	}
+
+	fn transfer(_model_id: ModelId, _to: &AccountId) -> DispatchResult {
+		Ok(())
+	}
+}
+
+/// Notified after a model is registered, imported or forked
+pub trait OnModelRegistered<AccountId> {
+	/// `model_id` was registered to `owner`
+	fn on_model_registered(model_id: ModelId, owner: &AccountId);
+}
+
+/// Nobody is notified of new models
+impl<AccountId> OnModelRegistered<AccountId> for () {
+	fn on_model_registered(_model_id: ModelId, _owner: &AccountId) {}
+}
+
+/// Notified when a model moves from one status to another
+///
+/// Called while the registry is still updating the model, so implementations should
+/// take the statuses from the arguments rather than reading them back from storage.
+pub trait OnModelStatusChanged {
+	/// `model_id` went from `old` to `new`
+	fn on_model_status_changed(model_id: ModelId, old: ModelStatus, new: ModelStatus);
+}
+
+/// Nobody is notified of status changes
+impl OnModelStatusChanged for () {
+	fn on_model_status_changed(_model_id: ModelId, _old: ModelStatus, _new: ModelStatus) {}
+}
+
+/// Produces owner signatures for benchmarking `import_model`
+#[cfg(feature = "runtime-benchmarks")]
+pub trait BenchmarkHelper<Signature, AccountId> {
+	/// Sign `msg` with a key derived from `entropy`, returning the signature and signer account
+	fn create_signature(entropy: &[u8], msg: &[u8]) -> (Signature, AccountId);
+}
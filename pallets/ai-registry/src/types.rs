@@ -1,19 +1,67 @@
 //! Type definitions for AI Registry pallet
 
-use codec::{Decode, Encode, MaxEncodedLen};
-use frame_support::pallet_prelude::*;
+use crate::Config;
+use codec::{Decode, DecodeWithMemTracking, Encode, MaxEncodedLen};
+use frame_support::{
+	pallet_prelude::*, CloneNoBound, EqNoBound, PartialEqNoBound, RuntimeDebugNoBound,
+};
 use scale_info::TypeInfo;
-use sp_runtime::RuntimeDebug;
+use serde::{Deserialize, Serialize};
+use sp_core::H256;
+use sp_runtime::{Perbill, RuntimeDebug};
 
 /// Unique identifier for models
 pub type ModelId = u64;
 
+/// Identifier of a paid inference receipt
+pub type ReceiptId = u64;
+
+/// Unique identifier for training datasets
+pub type DatasetId = u64;
+
+/// Unique identifier for model collections
+pub type CollectionId = u64;
+
+/// Unique identifier for organizations
+pub type OrganizationId = u64;
+
+/// Whether `cid` looks like an IPFS CID
+///
+/// Basic validation: CID should start with "Qm" (CIDv0) or be valid CIDv1
+/// For production, use a proper CID validation library
+pub fn is_valid_ipfs_cid(cid: &[u8]) -> bool {
+	if cid.len() < 46 {
+		return false;
+	}
+
+	// CIDv0: starts with "Qm" and is 46 characters
+	if cid.len() == 46 && cid.starts_with(b"Qm") {
+		return true;
+	}
+
+	// CIDv1: starts with "b" and uses base32
+	cid.starts_with(b"b") || cid.starts_with(b"B")
+}
+
 /// Type of AI model
 #[derive(
-	Clone, Copy, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen,
+	Clone,
+	Copy,
+	Default,
+	Encode,
+	Decode,
+	DecodeWithMemTracking,
+	Eq,
+	PartialEq,
+	RuntimeDebug,
+	TypeInfo,
+	MaxEncodedLen,
+	Serialize,
+	Deserialize,
 )]
 pub enum ModelType {
 	/// Classification model (e.g., image classification, sentiment analysis)
+	#[default]
 	Classification,
 	/// Regression model (e.g., price prediction, value estimation)
 	Regression,
@@ -21,18 +69,23 @@ pub enum ModelType {
 	Generative,
 }
 
-impl Default for ModelType {
-	fn default() -> Self {
-		ModelType::Classification
-	}
-}
-
 /// Status of a model
 #[derive(
-	Clone, Copy, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen,
+	Clone,
+	Copy,
+	Default,
+	Encode,
+	Decode,
+	DecodeWithMemTracking,
+	Eq,
+	PartialEq,
+	RuntimeDebug,
+	TypeInfo,
+	MaxEncodedLen,
 )]
 pub enum ModelStatus {
 	/// Model is active and available for inference
+	#[default]
 	Active,
 	/// Model is temporarily paused by owner
 	Paused,
@@ -40,39 +93,548 @@ pub enum ModelStatus {
 	Deactivated,
 	/// Model is deprecated (superseded by newer version)
 	Deprecated,
+	/// Model was reported and awaits a curator decision; it cannot be used and its
+	/// metadata is frozen
+	UnderReview,
+	/// Model was registered while approval is required and awaits
+	/// `RegistrationApprovalOrigin`; it cannot be used yet
+	Pending,
 }
 
-impl Default for ModelStatus {
-	fn default() -> Self {
-		ModelStatus::Active
+impl ModelStatus {
+	/// Whether an owner may move a model from this status to `to` by updating its
+	/// metadata
+	///
+	/// Deactivating and reactivating move the model's deposit and have dedicated
+	/// calls, so neither is a valid transition here. Reviews and registration approval
+	/// are only entered and left through their own workflows.
+	pub fn can_transition_to(self, to: ModelStatus) -> bool {
+		use ModelStatus::*;
+		match (self, to) {
+			(Active | Paused | Deprecated, Active | Paused | Deprecated) => true,
+			// Keeping the current status is a no-op
+			(Deactivated, Deactivated) | (Pending, Pending) => true,
+			(_, Deactivated | UnderReview | Pending) | (Deactivated | UnderReview | Pending, _) => {
+				false
+			}
+		}
 	}
 }
 
+/// SPDX-style license under which a model may be used downstream
+#[derive(
+	CloneNoBound,
+	Encode,
+	Decode,
+	DecodeWithMemTracking,
+	EqNoBound,
+	PartialEqNoBound,
+	RuntimeDebugNoBound,
+	TypeInfo,
+	MaxEncodedLen,
+)]
+#[scale_info(skip_type_params(MaxCid))]
+#[codec(mel_bound())]
+pub enum License<MaxCid: Get<u32>> {
+	/// MIT License
+	Mit,
+	/// Apache License 2.0
+	Apache2,
+	/// Open Responsible AI License (OpenRAIL family)
+	OpenRail,
+	/// All rights reserved; usage terms are negotiated off-chain
+	Proprietary,
+	/// Custom license text published on IPFS under the given CID
+	Custom(BoundedVec<u8, MaxCid>),
+}
+
+/// License type as configured for a given runtime
+pub type LicenseOf<T> = License<<T as Config>::MaxCidLength>;
+
 /// Comprehensive metadata for an AI model
-#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo)]
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
 #[scale_info(skip_type_params(T))]
 #[codec(mel_bound())]
-pub struct ModelMetadata<T: frame_system::Config> {
-	/// Owner/creator of the model
+pub struct ModelMetadata<T: Config> {
+	/// Current owner of the model
 	pub owner: T::AccountId,
+	/// Account that registered the model, paid royalties when it is sold
+	pub creator: T::AccountId,
+	/// Model this one was forked from, if any
+	pub parent: Option<ModelId>,
 	/// IPFS Content Identifier for model data
-	pub ipfs_cid: BoundedVec<u8, ConstU32<128>>,
+	pub ipfs_cid: BoundedVec<u8, T::MaxCidLength>,
 	/// Human-readable model name
-	pub name: BoundedVec<u8, ConstU32<256>>,
+	pub name: BoundedVec<u8, T::MaxNameLength>,
 	/// Model description
-	pub description: BoundedVec<u8, ConstU32<1024>>,
+	pub description: BoundedVec<u8, T::MaxDescriptionLength>,
 	/// Type of AI model
 	pub model_type: ModelType,
+	/// License governing downstream use of the model
+	pub license: LicenseOf<T>,
+	/// Declared size of the model artifact in bytes
+	pub size_bytes: u64,
+	/// Deposit currently reserved from the owner for this model
+	pub deposit: u128,
 	/// Price per inference (will be converted from BalanceOf<T>)
 	pub price: u128,
 	/// Block number when model was created
 	pub created_at: u64,
 	/// Total number of inferences performed
 	pub total_inferences: u64,
-	/// Sum of all ratings (for average calculation)
+	/// Sum of all ratings, each times its weight (for average calculation)
 	pub total_rating: u64,
-	/// Number of ratings received
+	/// Number of ratings received, each counted with its weight
 	pub rating_count: u32,
 	/// Current status of the model
 	pub status: ModelStatus,
 }
+
+/// A training dataset models can reference for attribution
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+#[scale_info(skip_type_params(T))]
+#[codec(mel_bound())]
+pub struct DatasetInfo<T: Config> {
+	/// Account that registered the dataset
+	pub owner: T::AccountId,
+	/// IPFS Content Identifier for the data
+	pub ipfs_cid: BoundedVec<u8, T::MaxCidLength>,
+	/// Human-readable dataset name
+	pub name: BoundedVec<u8, T::MaxNameLength>,
+	/// License governing use of the data
+	pub license: LicenseOf<T>,
+	/// Block number when the dataset was registered
+	pub created_at: u64,
+}
+
+/// Datasets a model was trained on
+pub type ModelDatasetsOf<T> = BoundedVec<DatasetId, <T as Config>::MaxDatasetsPerModel>;
+
+/// A named group of models an owner presents as a unit, e.g. a model family
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+#[scale_info(skip_type_params(T))]
+#[codec(mel_bound())]
+pub struct CollectionInfo<T: Config> {
+	/// Account that created the collection
+	pub owner: T::AccountId,
+	/// Human-readable collection name
+	pub name: BoundedVec<u8, T::MaxNameLength>,
+	/// Models in the collection
+	pub models: BoundedBTreeSet<ModelId, T::MaxModelsPerCollection>,
+}
+
+/// Company or team owning models through an account its members act for
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+#[scale_info(skip_type_params(T))]
+#[codec(mel_bound())]
+pub struct Organization<T: Config> {
+	/// Keyless account holding the organization's models and funds
+	pub account: T::AccountId,
+	/// Human-readable organization name
+	pub name: BoundedVec<u8, T::MaxNameLength>,
+	/// Number of members
+	pub members: u32,
+	/// Number of members with the `Admin` role
+	pub admins: u32,
+}
+
+/// What an organization member may do through the organization's account
+#[derive(
+	Clone,
+	Copy,
+	Encode,
+	Decode,
+	DecodeWithMemTracking,
+	Eq,
+	PartialEq,
+	RuntimeDebug,
+	TypeInfo,
+	MaxEncodedLen,
+)]
+pub enum OrganizationRole {
+	/// Any call, including managing members
+	Admin,
+	/// Registering models and updating their metadata, datasets and collections
+	Publisher,
+	/// Rent, deposits and claiming rebates, incentives and rewards
+	Billing,
+}
+
+impl OrganizationRole {
+	/// Whether a member with this role may make calls that need `needed`
+	pub fn allows(self, needed: OrganizationRole) -> bool {
+		self == OrganizationRole::Admin || self == needed
+	}
+}
+
+/// Accounts watching a model for changes
+pub type WatchersOf<T> =
+	BoundedVec<<T as frame_system::Config>::AccountId, <T as Config>::MaxWatchers>;
+
+/// Change to a model that its watchers are notified of
+///
+/// Model CIDs are fixed at registration, so price and status are the fields that
+/// can change under a watcher.
+#[derive(
+	Clone, Copy, Encode, Decode, DecodeWithMemTracking, Eq, PartialEq, RuntimeDebug, TypeInfo,
+)]
+pub enum ModelChange {
+	/// Price per inference changed
+	Price { old: u128, new: u128 },
+	/// Status changed
+	Status { old: ModelStatus, new: ModelStatus },
+}
+
+/// Model field changed by a metadata update
+#[derive(
+	Clone,
+	Copy,
+	Encode,
+	Decode,
+	DecodeWithMemTracking,
+	Eq,
+	PartialEq,
+	RuntimeDebug,
+	TypeInfo,
+	MaxEncodedLen,
+)]
+pub enum MetadataField {
+	/// Price per inference
+	Price,
+	/// Model description
+	Description,
+	/// Model status
+	Status,
+	/// Model name
+	Name,
+	/// Model type
+	ModelType,
+	/// Model license
+	License,
+}
+
+/// Entry of a model's update history
+#[derive(
+	Clone,
+	Copy,
+	Encode,
+	Decode,
+	DecodeWithMemTracking,
+	Eq,
+	PartialEq,
+	RuntimeDebug,
+	TypeInfo,
+	MaxEncodedLen,
+)]
+pub struct MetadataUpdate {
+	/// Field that changed
+	pub field: MetadataField,
+	/// Block number of the change
+	pub updated_at: u64,
+	/// Blake2-256 hash of the field's SCALE-encoded previous value
+	pub previous: H256,
+}
+
+/// Latest updates of a model, newest first
+pub type UpdateHistoryOf<T> = BoundedVec<MetadataUpdate, <T as Config>::MaxUpdateHistory>;
+
+/// Chain-wide registry counters, kept up to date so dashboards need one read
+#[derive(
+	Clone,
+	Copy,
+	Default,
+	Encode,
+	Decode,
+	DecodeWithMemTracking,
+	Eq,
+	PartialEq,
+	RuntimeDebug,
+	TypeInfo,
+	MaxEncodedLen,
+)]
+pub struct RegistryStats {
+	/// Models currently registered
+	pub total_models: u64,
+	/// Registered models with `Active` status
+	pub active_models: u64,
+	/// Inferences completed on all models
+	pub total_inferences: u64,
+	/// Inference fees settled on all models
+	pub fee_volume: u128,
+}
+
+/// Usage, revenue and ratings of a single model
+#[derive(
+	Clone,
+	Copy,
+	Default,
+	Encode,
+	Decode,
+	DecodeWithMemTracking,
+	Eq,
+	PartialEq,
+	RuntimeDebug,
+	TypeInfo,
+	MaxEncodedLen,
+)]
+pub struct ModelStats {
+	/// Inferences completed on the model
+	pub total_inferences: u64,
+	/// Inferences completed in the current era
+	pub era_inferences: u32,
+	/// Inference fees settled on the model
+	pub revenue: u128,
+	/// Number of ratings left on the model, each counted with its weight
+	pub rating_count: u32,
+	/// Number of ratings of one to five stars, in that order
+	pub rating_distribution: [u32; 5],
+}
+
+/// Inference fees settled on a model, or on all models of an owner
+#[derive(
+	Clone, Default, Encode, Decode, DecodeWithMemTracking, Eq, PartialEq, RuntimeDebug, TypeInfo,
+)]
+pub struct RevenueHistory {
+	/// Fees settled in the current era
+	pub current_era: u128,
+	/// Fees settled since registration
+	pub lifetime: u128,
+	/// Fees settled in each of the last `UsageWindow` eras, newest first and the
+	/// current one included, with zero for eras without fees
+	pub eras: Vec<u128>,
+}
+
+/// Inferences of a model per recent era, newest era first
+pub type RecentUsageOf<T> =
+	BoundedVec<(frame_system::pallet_prelude::BlockNumberFor<T>, u32), <T as Config>::UsageWindow>;
+
+/// Inference fees settled on a model per recent era, newest era first
+pub type RecentRevenueOf<T> =
+	BoundedVec<(frame_system::pallet_prelude::BlockNumberFor<T>, u128), <T as Config>::UsageWindow>;
+
+/// Lowercase leading bytes of a model name, as indexed for name searches
+pub type NamePrefixOf<T> = BoundedVec<u8, <T as Config>::NamePrefixLength>;
+
+/// Shareholders of a fractionally owned model and their shares, summing to 100%
+pub type ModelSharesOf<T> =
+	BoundedVec<(<T as frame_system::Config>::AccountId, Perbill), <T as Config>::MaxShareholders>;
+
+/// Metadata change of a fractionally owned model, awaiting shareholder approval
+#[derive(
+	CloneNoBound,
+	Encode,
+	Decode,
+	EqNoBound,
+	PartialEqNoBound,
+	RuntimeDebugNoBound,
+	TypeInfo,
+	MaxEncodedLen,
+)]
+#[scale_info(skip_type_params(T))]
+#[codec(mel_bound())]
+pub struct MetadataProposal<T: Config> {
+	/// New price per inference
+	pub new_price: Option<u128>,
+	/// New model description
+	pub new_description: Option<BoundedVec<u8, T::MaxDescriptionLength>>,
+	/// New model status
+	pub new_status: Option<ModelStatus>,
+	/// Shareholders who approved the change, the proposer first
+	pub approvals: BoundedVec<T::AccountId, T::MaxShareholders>,
+}
+
+/// A single rater's rating of a model and what backs it
+#[derive(
+	Clone,
+	Copy,
+	Encode,
+	Decode,
+	DecodeWithMemTracking,
+	Eq,
+	PartialEq,
+	RuntimeDebug,
+	TypeInfo,
+	MaxEncodedLen,
+)]
+pub struct RatingRecord {
+	/// Rating given (1-5)
+	pub rating: u8,
+	/// Receipt of the paid inference the rating refers to, if one was found
+	pub receipt: Option<ReceiptId>,
+	/// Block number when the rating was last set
+	pub rated_at: u64,
+}
+
+/// Current version of [`MigrationPacket`]
+pub const MIGRATION_PACKET_VERSION: u8 = 1;
+
+/// Domain separator prepended to a packet's encoding before it is signed
+pub const MIGRATION_PACKET_CONTEXT: &[u8] = b"ai-registry/migration-packet";
+
+/// Portable description of a model, exported from one chain and imported on another
+///
+/// The owner signs `(MIGRATION_PACKET_CONTEXT, packet).encode()` with the key
+/// of `owner` to prove ownership on the destination chain.
+#[derive(
+	CloneNoBound,
+	Encode,
+	Decode,
+	DecodeWithMemTracking,
+	EqNoBound,
+	PartialEqNoBound,
+	RuntimeDebugNoBound,
+	TypeInfo,
+)]
+#[scale_info(skip_type_params(T))]
+pub struct MigrationPacket<T: Config> {
+	/// Packet format version, see [`MIGRATION_PACKET_VERSION`]
+	pub version: u8,
+	/// Genesis hash of the chain the model was exported from
+	pub source_chain: T::Hash,
+	/// Model ID on the source chain
+	pub source_model_id: ModelId,
+	/// Owner of the model on both chains
+	pub owner: T::AccountId,
+	/// IPFS Content Identifier for model data
+	pub ipfs_cid: BoundedVec<u8, T::MaxCidLength>,
+	/// Human-readable model name
+	pub name: BoundedVec<u8, T::MaxNameLength>,
+	/// Model description
+	pub description: BoundedVec<u8, T::MaxDescriptionLength>,
+	/// Type of AI model
+	pub model_type: ModelType,
+	/// License governing downstream use of the model
+	pub license: LicenseOf<T>,
+	/// Declared size of the model artifact in bytes
+	pub size_bytes: u64,
+	/// Price per inference on the source chain
+	pub price: u128,
+	/// Block number the model was created at on the source chain
+	pub created_at: u64,
+	/// Block number the packet was exported at on the source chain
+	pub exported_at: u64,
+}
+
+/// Where an imported model came from
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct ModelProvenance<Hash> {
+	/// Genesis hash of the source chain
+	pub source_chain: Hash,
+	/// Model ID on the source chain
+	pub source_model_id: ModelId,
+	/// Block number the model was created at on the source chain
+	pub created_at: u64,
+}
+
+/// Events a model callback can subscribe to, combined as a bitmask
+pub mod callback_events {
+	/// An inference was requested for the model
+	pub const INFERENCE_REQUESTED: u32 = 1 << 0;
+	/// The model received a rating
+	pub const MODEL_RATED: u32 = 1 << 1;
+	/// The model metadata was updated
+	pub const MODEL_UPDATED: u32 = 1 << 2;
+	/// The model status changed (paused, deactivated, ...)
+	pub const STATUS_CHANGED: u32 = 1 << 3;
+	/// Every event currently defined
+	pub const ALL: u32 = INFERENCE_REQUESTED | MODEL_RATED | MODEL_UPDATED | STATUS_CHANGED;
+}
+
+/// Where gateway operators should deliver notifications for a model
+#[derive(
+	CloneNoBound,
+	Encode,
+	Decode,
+	DecodeWithMemTracking,
+	EqNoBound,
+	PartialEqNoBound,
+	RuntimeDebugNoBound,
+	TypeInfo,
+	MaxEncodedLen,
+)]
+#[scale_info(skip_type_params(MaxCid))]
+#[codec(mel_bound())]
+pub enum CallbackEndpoint<MaxCid: Get<u32>> {
+	/// Hash of the endpoint URL, revealed to gateways off-chain
+	UrlHash(H256),
+	/// IPFS document describing the endpoint
+	Cid(BoundedVec<u8, MaxCid>),
+}
+
+/// Off-chain callback descriptor registered by a model owner
+#[derive(
+	CloneNoBound,
+	Encode,
+	Decode,
+	DecodeWithMemTracking,
+	EqNoBound,
+	PartialEqNoBound,
+	RuntimeDebugNoBound,
+	TypeInfo,
+	MaxEncodedLen,
+)]
+#[scale_info(skip_type_params(MaxCid))]
+#[codec(mel_bound())]
+pub struct CallbackDescriptor<MaxCid: Get<u32>> {
+	/// Endpoint notifications are delivered to
+	pub endpoint: CallbackEndpoint<MaxCid>,
+	/// Bitmask of [`callback_events`] the owner wants delivered
+	pub event_mask: u32,
+}
+
+/// Callback descriptor type as configured for a given runtime
+pub type CallbackDescriptorOf<T> = CallbackDescriptor<<T as Config>::MaxCidLength>;
+
+/// Owner's appeal against the forced deactivation of their model
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+#[scale_info(skip_type_params(T))]
+#[codec(mel_bound())]
+pub struct DeactivationAppeal<T: Config> {
+	/// Owner who opened the appeal and posted the bond
+	pub appellant: T::AccountId,
+	/// IPFS CID of the evidence supporting the appeal
+	pub evidence_cid: BoundedVec<u8, T::MaxCidLength>,
+	/// Bond reserved from the appellant, refunded if the appeal is upheld
+	pub bond: u128,
+	/// Block number when the appeal was opened
+	pub opened_at: u64,
+}
+
+/// Public profile a developer shows on marketplace vendor pages
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+#[scale_info(skip_type_params(T))]
+#[codec(mel_bound())]
+pub struct DeveloperProfile<T: Config> {
+	/// Name shown for the developer
+	pub display_name: BoundedVec<u8, T::MaxNameLength>,
+	/// IPFS CID of the developer's website, if any
+	pub website_cid: Option<BoundedVec<u8, T::MaxCidLength>>,
+	/// IPFS CID of the developer's contact details, if any
+	pub contact_cid: Option<BoundedVec<u8, T::MaxCidLength>>,
+}
+
+/// A developer's profile together with the models they own
+#[derive(
+	Clone, Default, Encode, Decode, DecodeWithMemTracking, Eq, PartialEq, RuntimeDebug, TypeInfo,
+)]
+pub struct DeveloperPage {
+	/// Name shown for the developer
+	pub display_name: Vec<u8>,
+	/// IPFS CID of the developer's website, if any
+	pub website_cid: Option<Vec<u8>>,
+	/// IPFS CID of the developer's contact details, if any
+	pub contact_cid: Option<Vec<u8>>,
+	/// Models the developer owns, in ascending ID order
+	pub models: Vec<ModelId>,
+}
+
+/// Incentive budget of an era and the verified usage it is split by
+#[derive(Clone, Default, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct EraIncentive<Balance> {
+	/// Budget distributed among the models used in the era
+	pub budget: Balance,
+	/// Verified inferences of all incentivised models in the era
+	pub usage: u32,
+	/// Models whose share of the budget is still to be credited
+	pub models: u32,
+}
@@ -27,10 +27,65 @@ use core::marker::PhantomData;
 
 /// Weight functions needed for pallet_ai_registry.
 pub trait WeightInfo {
-	fn register_model() -> Weight;
+	fn register_model(d: u32, ) -> Weight;
 	fn update_model_metadata() -> Weight;
+	fn update_model_price() -> Weight;
 	fn deactivate_model() -> Weight;
 	fn rate_model() -> Weight;
+	fn propose_size_correction() -> Weight;
+	fn approve_size_correction() -> Weight;
+	fn extend_admin_sunset() -> Weight;
+	fn add_model_callback() -> Weight;
+	fn remove_model_callback() -> Weight;
+	fn export_models(n: u32, ) -> Weight;
+	fn import_model() -> Weight;
+	fn collect_rent() -> Weight;
+	fn top_up_deposit() -> Weight;
+	fn reap_model(c: u32, ) -> Weight;
+	fn slash_model(r: u32, ) -> Weight;
+	fn transfer_model() -> Weight;
+	fn sync_model_owner() -> Weight;
+	fn fractionalize_model(s: u32, ) -> Weight;
+	fn transfer_shares() -> Weight;
+	fn propose_metadata_update() -> Weight;
+	fn approve_metadata_update() -> Weight;
+	fn cancel_metadata_update() -> Weight;
+	fn register_dataset() -> Weight;
+	fn fork_model() -> Weight;
+	fn create_collection() -> Weight;
+	fn add_to_collection() -> Weight;
+	fn remove_from_collection() -> Weight;
+	fn deprecate_model() -> Weight;
+	fn bookmark_model() -> Weight;
+	fn unbookmark_model() -> Weight;
+	fn watch_model() -> Weight;
+	fn unwatch_model() -> Weight;
+	fn claim_fee_rebate() -> Weight;
+	fn fund_incentives() -> Weight;
+	fn claim_incentive() -> Weight;
+	fn credit_era_rewards(n: u32, ) -> Weight;
+	fn claim_reward() -> Weight;
+	fn rename_model() -> Weight;
+	fn lock_metadata() -> Weight;
+	fn report_model() -> Weight;
+	fn dismiss_reports(r: u32, ) -> Weight;
+	fn appeal_deactivation() -> Weight;
+	fn resolve_appeal() -> Weight;
+	fn enact_deactivations(n: u32, ) -> Weight;
+	fn reactivate_model() -> Weight;
+	fn approve_registration() -> Weight;
+	fn reject_registration() -> Weight;
+	fn set_allowlist_required() -> Weight;
+	fn set_developer_profile() -> Weight;
+	fn clear_developer_profile() -> Weight;
+	fn create_organization() -> Weight;
+	fn set_organization_role() -> Weight;
+	fn as_organization() -> Weight;
+	fn schedule_price_change() -> Weight;
+	fn cancel_price_change() -> Weight;
+	fn enact_price_changes(n: u32, ) -> Weight;
+	fn deactivate_at() -> Weight;
+	fn announce_deactivations(n: u32, ) -> Weight;
 }
 
 /// Weights for pallet_ai_registry using the Substrate node and recommended hardware.
@@ -39,63 +94,1439 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Storage: AIRegistry NextModelId (r:1 w:1)
 	/// Proof: AIRegistry NextModelId (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
 	/// Storage: AIRegistry Models (r:0 w:1)
-	/// Proof: AIRegistry Models (max_values: None, max_size: Some(2048), added: 4523, mode: MaxEncodedLen)
-	/// Storage: AIRegistry ModelsByOwner (r:0 w:1)
-	/// Proof: AIRegistry ModelsByOwner (max_values: None, max_size: Some(64), added: 2539, mode: MaxEncodedLen)
-	fn register_model() -> Weight {
-		Weight::from_parts(50_000_000, 0)
-			.saturating_add(T::DbWeight::get().reads(1))
-			.saturating_add(T::DbWeight::get().writes(3))
+	/// Proof: AIRegistry Models (max_values: None, max_size: Some(1712), added: 4187, mode: MaxEncodedLen)
+	/// Storage: AIRegistry OwnedModels (r:1 w:1)
+	/// Proof: AIRegistry OwnedModels (max_values: None, max_size: Some(2098), added: 4573, mode: MaxEncodedLen)
+	/// Storage: Nfts Collection (r:1 w:1)
+	/// Proof: Nfts Collection (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	/// Storage: Nfts CollectionConfigOf (r:1 w:0)
+	/// Proof: Nfts CollectionConfigOf (max_values: None, max_size: Some(73), added: 2548, mode: MaxEncodedLen)
+	/// Storage: Nfts Item (r:1 w:1)
+	/// Proof: Nfts Item (max_values: None, max_size: Some(865), added: 3340, mode: MaxEncodedLen)
+	/// Storage: Nfts Account (r:0 w:1)
+	/// Proof: Nfts Account (max_values: None, max_size: Some(92), added: 2567, mode: MaxEncodedLen)
+	/// Storage: Nfts ItemConfigOf (r:0 w:1)
+	/// Proof: Nfts ItemConfigOf (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: AIRegistry Datasets (r:16 w:0)
+	/// Proof: AIRegistry Datasets (max_values: None, max_size: Some(583), added: 3058, mode: MaxEncodedLen)
+	/// Storage: AIRegistry ModelDatasets (r:0 w:1)
+	/// Proof: AIRegistry ModelDatasets (max_values: None, max_size: Some(153), added: 2628, mode: MaxEncodedLen)
+	/// The range of component `d` is `[0, 16]`.
+	/// Storage: AIRegistry GlobalStats (r:1 w:1)
+	/// Proof: AIRegistry GlobalStats (max_values: Some(1), max_size: Some(40), added: 535, mode: MaxEncodedLen)
+	/// Storage: System Account (r:2 w:2)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: AIRegistry NameIndex (r:0 w:1)
+	/// Proof: AIRegistry NameIndex (max_values: None, max_size: Some(44), added: 2519, mode: MaxEncodedLen)
+	/// Storage: AIRegistry ModelsByCid (r:1 w:1)
+	/// Proof: AIRegistry ModelsByCid (max_values: None, max_size: Some(40), added: 2515, mode: MaxEncodedLen)
+	/// Storage: AIRegistry OwnerModelNames (r:1 w:1)
+	/// Proof: AIRegistry OwnerModelNames (max_values: None, max_size: Some(88), added: 2563, mode: MaxEncodedLen)
+	/// Storage: AIRegistry AllowlistRequired (r:1 w:0)
+	/// Proof: AIRegistry AllowlistRequired (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: DeveloperMembership Members (r:1 w:0)
+	/// Proof: DeveloperMembership Members (max_values: Some(1), max_size: Some(3202), added: 3697, mode: MaxEncodedLen)
+	fn register_model(d: u32, ) -> Weight {
+		Weight::from_parts(50_000_000, 28535)
+			// Standard Error: 150_000
+			.saturating_add(Weight::from_parts(3_000_000, 0).saturating_mul(d.into()))
+			.saturating_add(T::DbWeight::get().reads(12))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(d.into())))
+			.saturating_add(T::DbWeight::get().writes(15))
+			.saturating_add(Weight::from_parts(0, 3058).saturating_mul(d.into()))
 	}
 	
 	/// Storage: AIRegistry Models (r:1 w:1)
-	/// Proof: AIRegistry Models (max_values: None, max_size: Some(2048), added: 4523, mode: MaxEncodedLen)
+	/// Proof: AIRegistry Models (max_values: None, max_size: Some(1712), added: 4187, mode: MaxEncodedLen)
+	/// Storage: AIRegistry Shareholders (r:1 w:0)
+	/// Proof: AIRegistry Shareholders (max_values: None, max_size: Some(385), added: 2860, mode: MaxEncodedLen)
+	/// Storage: AIRegistry Successors (r:0 w:1)
+	/// Proof: AIRegistry Successors (max_values: None, max_size: Some(32), added: 2507, mode: MaxEncodedLen)
+	/// Storage: AIRegistry Watchers (r:1 w:0)
+	/// Proof: AIRegistry Watchers (max_values: None, max_size: Some(8218), added: 10693, mode: MaxEncodedLen)
+	/// Storage: AIRegistry LockedMetadata (r:1 w:0)
+	/// Proof: AIRegistry LockedMetadata (max_values: None, max_size: Some(28), added: 2503, mode: MaxEncodedLen)
+	/// Storage: AIRegistry UpdateHistory (r:1 w:1)
+	/// Proof: AIRegistry UpdateHistory (max_values: None, max_size: Some(1337), added: 3812, mode: MaxEncodedLen)
+	/// Storage: AIRegistry GlobalStats (r:1 w:1)
+	/// Proof: AIRegistry GlobalStats (max_values: Some(1), max_size: Some(40), added: 535, mode: MaxEncodedLen)
 	fn update_model_metadata() -> Weight {
-		Weight::from_parts(30_000_000, 0)
-			.saturating_add(T::DbWeight::get().reads(1))
-			.saturating_add(T::DbWeight::get().writes(1))
+		Weight::from_parts(36_000_000, 24590)
+			.saturating_add(T::DbWeight::get().reads(6))
+			.saturating_add(T::DbWeight::get().writes(4))
+	}
+	
+	/// Storage: AIRegistry Models (r:1 w:1)
+	/// Proof: AIRegistry Models (max_values: None, max_size: Some(1712), added: 4187, mode: MaxEncodedLen)
+	/// Storage: AIRegistry Shareholders (r:1 w:0)
+	/// Proof: AIRegistry Shareholders (max_values: None, max_size: Some(385), added: 2860, mode: MaxEncodedLen)
+	/// Storage: AIRegistry Watchers (r:1 w:0)
+	/// Proof: AIRegistry Watchers (max_values: None, max_size: Some(8218), added: 10693, mode: MaxEncodedLen)
+	/// Storage: AIRegistry UpdateHistory (r:1 w:1)
+	/// Proof: AIRegistry UpdateHistory (max_values: None, max_size: Some(1337), added: 3812, mode: MaxEncodedLen)
+	fn update_model_price() -> Weight {
+		Weight::from_parts(24_000_000, 21552)
+			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().writes(2))
 	}
 	
 	/// Storage: AIRegistry Models (r:1 w:1)
-	/// Proof: AIRegistry Models (max_values: None, max_size: Some(2048), added: 4523, mode: MaxEncodedLen)
+	/// Proof: AIRegistry Models (max_values: None, max_size: Some(1712), added: 4187, mode: MaxEncodedLen)
+	/// Storage: AIRegistry Watchers (r:1 w:0)
+	/// Proof: AIRegistry Watchers (max_values: None, max_size: Some(8218), added: 10693, mode: MaxEncodedLen)
+	/// Storage: AIRegistry GlobalStats (r:1 w:1)
+	/// Proof: AIRegistry GlobalStats (max_values: Some(1), max_size: Some(40), added: 535, mode: MaxEncodedLen)
+	/// Storage: AIRegistry PendingDeactivations (r:1 w:1)
+	/// Proof: AIRegistry PendingDeactivations (max_values: None, max_size: Some(28), added: 2503, mode: MaxEncodedLen)
+	/// Storage: AIRegistry ScheduledDeactivations (r:2 w:2)
+	/// Proof: AIRegistry ScheduledDeactivations (max_values: None, max_size: Some(144), added: 2619, mode: MaxEncodedLen)
 	fn deactivate_model() -> Weight {
-		Weight::from_parts(25_000_000, 0)
-			.saturating_add(T::DbWeight::get().reads(1))
-			.saturating_add(T::DbWeight::get().writes(1))
+		Weight::from_parts(25_000_000, 23156)
+			.saturating_add(T::DbWeight::get().reads(6))
+			.saturating_add(T::DbWeight::get().writes(5))
 	}
 	
 	/// Storage: AIRegistry Models (r:1 w:1)
-	/// Proof: AIRegistry Models (max_values: None, max_size: Some(2048), added: 4523, mode: MaxEncodedLen)
+	/// Proof: AIRegistry Models (max_values: None, max_size: Some(1712), added: 4187, mode: MaxEncodedLen)
+	/// Storage: AIRegistry TopModels (r:1 w:1)
+	/// Proof: AIRegistry TopModels (max_values: Some(1), max_size: Some(1202), added: 1697, mode: MaxEncodedLen)
+	/// Storage: AIRegistry RatingWeights (r:1 w:1)
+	/// Proof: AIRegistry RatingWeights (max_values: None, max_size: Some(76), added: 2551, mode: MaxEncodedLen)
+	/// Storage: Inference FeesPaid (r:1 w:0)
+	/// Proof: Inference FeesPaid (max_values: None, max_size: Some(88), added: 2563, mode: MaxEncodedLen)
+	/// Storage: AIRegistry RatingHistograms (r:1 w:1)
+	/// Proof: AIRegistry RatingHistograms (max_values: None, max_size: Some(44), added: 2519, mode: MaxEncodedLen)
+	/// Storage: AIRegistry RatingReviews (r:0 w:1)
+	/// Proof: AIRegistry RatingReviews (max_values: None, max_size: Some(202), added: 2677, mode: MaxEncodedLen)
+	/// Storage: AIRegistry AccountCreated (r:1 w:0)
+	/// Proof: AIRegistry AccountCreated (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:0)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: AIRegistry RatedInEra (r:1 w:1)
+	/// Proof: AIRegistry RatedInEra (max_values: None, max_size: Some(213), added: 2688, mode: MaxEncodedLen)
+	/// Storage: AIRegistry RecentRatings (r:1 w:1)
+	/// Proof: AIRegistry RecentRatings (max_values: None, max_size: Some(45), added: 2520, mode: MaxEncodedLen)
+	/// Storage: AIRegistry RaterCounts (r:1 w:1)
+	/// Proof: AIRegistry RaterCounts (max_values: None, max_size: Some(28), added: 2503, mode: MaxEncodedLen)
 	fn rate_model() -> Weight {
-		Weight::from_parts(28_000_000, 0)
+		Weight::from_parts(32_000_000, 26358)
+			.saturating_add(T::DbWeight::get().reads(10))
+			.saturating_add(T::DbWeight::get().writes(8))
+	}
+
+	/// Storage: AIRegistry Models (r:1 w:0)
+	/// Proof: AIRegistry Models (max_values: None, max_size: Some(1712), added: 4187, mode: MaxEncodedLen)
+	/// Storage: AIRegistry PendingSizeCorrections (r:0 w:1)
+	/// Proof: AIRegistry PendingSizeCorrections (max_values: None, max_size: Some(32), added: 2507, mode: MaxEncodedLen)
+	fn propose_size_correction() -> Weight {
+		Weight::from_parts(20_000_000, 4187)
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+
+	/// Storage: AIRegistry PendingSizeCorrections (r:1 w:1)
+	/// Proof: AIRegistry PendingSizeCorrections (max_values: None, max_size: Some(32), added: 2507, mode: MaxEncodedLen)
+	/// Storage: AIRegistry Models (r:1 w:1)
+	/// Proof: AIRegistry Models (max_values: None, max_size: Some(1712), added: 4187, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	fn approve_size_correction() -> Weight {
+		Weight::from_parts(35_000_000, 9297)
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(3))
+	}
+
+	/// Storage: AIRegistry AdminSunset (r:1 w:1)
+	/// Proof: AIRegistry AdminSunset (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	fn extend_admin_sunset() -> Weight {
+		Weight::from_parts(15_000_000, 499)
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+
+	/// Storage: AIRegistry Models (r:1 w:0)
+	/// Proof: AIRegistry Models (max_values: None, max_size: Some(1712), added: 4187, mode: MaxEncodedLen)
+	/// Storage: AIRegistry ModelCallbacks (r:1 w:1)
+	/// Proof: AIRegistry ModelCallbacks (max_values: None, max_size: Some(1105), added: 3580, mode: MaxEncodedLen)
+	fn add_model_callback() -> Weight {
+		Weight::from_parts(22_000_000, 7767)
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+
+	/// Storage: AIRegistry Models (r:1 w:0)
+	/// Proof: AIRegistry Models (max_values: None, max_size: Some(1712), added: 4187, mode: MaxEncodedLen)
+	/// Storage: AIRegistry ModelCallbacks (r:1 w:1)
+	/// Proof: AIRegistry ModelCallbacks (max_values: None, max_size: Some(1105), added: 3580, mode: MaxEncodedLen)
+	fn remove_model_callback() -> Weight {
+		Weight::from_parts(20_000_000, 7767)
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+
+	/// Storage: AIRegistry Models (r:20 w:0)
+	/// Proof: AIRegistry Models (max_values: None, max_size: Some(1712), added: 4187, mode: MaxEncodedLen)
+	/// Storage: System BlockHash (r:1 w:0)
+	/// Proof: System BlockHash (max_values: None, max_size: Some(44), added: 2519, mode: MaxEncodedLen)
+	/// The range of component `n` is `[1, 20]`.
+	fn export_models(n: u32, ) -> Weight {
+		Weight::from_parts(12_000_000, 2519)
+			// Standard Error: 4_000
+			.saturating_add(Weight::from_parts(9_000_000, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 4187).saturating_mul(n.into()))
+	}
+	/// Storage: System BlockHash (r:1 w:0)
+	/// Proof: System BlockHash (max_values: None, max_size: Some(44), added: 2519, mode: MaxEncodedLen)
+	/// Storage: AIRegistry ImportedPackets (r:1 w:1)
+	/// Proof: AIRegistry ImportedPackets (max_values: None, max_size: Some(64), added: 2539, mode: MaxEncodedLen)
+	/// Storage: AIRegistry NextModelId (r:1 w:1)
+	/// Proof: AIRegistry NextModelId (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: AIRegistry Models (r:0 w:1)
+	/// Proof: AIRegistry Models (max_values: None, max_size: Some(1712), added: 4187, mode: MaxEncodedLen)
+	/// Storage: AIRegistry OwnedModels (r:1 w:1)
+	/// Proof: AIRegistry OwnedModels (max_values: None, max_size: Some(2098), added: 4573, mode: MaxEncodedLen)
+	/// Storage: AIRegistry ImportedFrom (r:0 w:1)
+	/// Proof: AIRegistry ImportedFrom (max_values: None, max_size: Some(72), added: 2547, mode: MaxEncodedLen)
+	/// Storage: Nfts Collection (r:1 w:1)
+	/// Proof: Nfts Collection (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	/// Storage: Nfts CollectionConfigOf (r:1 w:0)
+	/// Proof: Nfts CollectionConfigOf (max_values: None, max_size: Some(73), added: 2548, mode: MaxEncodedLen)
+	/// Storage: Nfts Item (r:1 w:1)
+	/// Proof: Nfts Item (max_values: None, max_size: Some(865), added: 3340, mode: MaxEncodedLen)
+	/// Storage: Nfts Account (r:0 w:1)
+	/// Proof: Nfts Account (max_values: None, max_size: Some(92), added: 2567, mode: MaxEncodedLen)
+	/// Storage: Nfts ItemConfigOf (r:0 w:1)
+	/// Proof: Nfts ItemConfigOf (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: AIRegistry GlobalStats (r:1 w:1)
+	/// Proof: AIRegistry GlobalStats (max_values: Some(1), max_size: Some(40), added: 535, mode: MaxEncodedLen)
+	/// Storage: System Account (r:2 w:2)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: AIRegistry NameIndex (r:0 w:1)
+	/// Proof: AIRegistry NameIndex (max_values: None, max_size: Some(44), added: 2519, mode: MaxEncodedLen)
+	/// Storage: AIRegistry ModelsByCid (r:1 w:1)
+	/// Proof: AIRegistry ModelsByCid (max_values: None, max_size: Some(40), added: 2515, mode: MaxEncodedLen)
+	/// Storage: AIRegistry OwnerModelNames (r:1 w:1)
+	/// Proof: AIRegistry OwnerModelNames (max_values: None, max_size: Some(88), added: 2563, mode: MaxEncodedLen)
+	/// Storage: AIRegistry AllowlistRequired (r:1 w:0)
+	/// Proof: AIRegistry AllowlistRequired (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: DeveloperMembership Members (r:1 w:0)
+	/// Proof: DeveloperMembership Members (max_values: Some(1), max_size: Some(3202), added: 3697, mode: MaxEncodedLen)
+	fn import_model() -> Weight {
+		Weight::from_parts(95_000_000, 33593)
+			.saturating_add(T::DbWeight::get().reads(14))
+			.saturating_add(T::DbWeight::get().writes(17))
+	}
+
+	/// Storage: AIRegistry RentExhausted (r:1 w:1)
+	/// Proof: AIRegistry RentExhausted (max_values: None, max_size: Some(28), added: 2503, mode: MaxEncodedLen)
+	/// Storage: AIRegistry LastActivity (r:1 w:0)
+	/// Proof: AIRegistry LastActivity (max_values: None, max_size: Some(28), added: 2503, mode: MaxEncodedLen)
+	/// Storage: AIRegistry RentChargedUntil (r:1 w:1)
+	/// Proof: AIRegistry RentChargedUntil (max_values: None, max_size: Some(28), added: 2503, mode: MaxEncodedLen)
+	/// Storage: AIRegistry Models (r:1 w:1)
+	/// Proof: AIRegistry Models (max_values: None, max_size: Some(1712), added: 4187, mode: MaxEncodedLen)
+	/// Storage: Balances Account (r:1 w:1)
+	/// Proof: Balances Account (max_values: None, max_size: Some(112), added: 2587, mode: MaxEncodedLen)
+	/// Storage: AIRegistry Watchers (r:1 w:0)
+	/// Proof: AIRegistry Watchers (max_values: None, max_size: Some(8218), added: 10693, mode: MaxEncodedLen)
+	/// Storage: AIRegistry GlobalStats (r:1 w:1)
+	/// Proof: AIRegistry GlobalStats (max_values: Some(1), max_size: Some(40), added: 535, mode: MaxEncodedLen)
+	fn collect_rent() -> Weight {
+		Weight::from_parts(35_000_000, 25511)
+			.saturating_add(T::DbWeight::get().reads(7))
+			.saturating_add(T::DbWeight::get().writes(5))
+	}
+
+	/// Storage: AIRegistry Models (r:1 w:1)
+	/// Proof: AIRegistry Models (max_values: None, max_size: Some(1712), added: 4187, mode: MaxEncodedLen)
+	/// Storage: Balances Account (r:1 w:1)
+	/// Proof: Balances Account (max_values: None, max_size: Some(112), added: 2587, mode: MaxEncodedLen)
+	/// Storage: AIRegistry RentExhausted (r:1 w:1)
+	/// Proof: AIRegistry RentExhausted (max_values: None, max_size: Some(28), added: 2503, mode: MaxEncodedLen)
+	/// Storage: AIRegistry Watchers (r:1 w:0)
+	/// Proof: AIRegistry Watchers (max_values: None, max_size: Some(8218), added: 10693, mode: MaxEncodedLen)
+	/// Storage: AIRegistry GlobalStats (r:1 w:1)
+	/// Proof: AIRegistry GlobalStats (max_values: Some(1), max_size: Some(40), added: 535, mode: MaxEncodedLen)
+	fn top_up_deposit() -> Weight {
+		Weight::from_parts(30_000_000, 20505)
+			.saturating_add(T::DbWeight::get().reads(5))
+			.saturating_add(T::DbWeight::get().writes(4))
+	}
+
+	/// Storage: AIRegistry RentExhausted (r:1 w:1)
+	/// Proof: AIRegistry RentExhausted (max_values: None, max_size: Some(28), added: 2503, mode: MaxEncodedLen)
+	/// Storage: AIRegistry Models (r:1 w:1)
+	/// Proof: AIRegistry Models (max_values: None, max_size: Some(1712), added: 4187, mode: MaxEncodedLen)
+	/// Storage: AIRegistry OwnedModels (r:1 w:1)
+	/// Proof: AIRegistry OwnedModels (max_values: None, max_size: Some(2098), added: 4573, mode: MaxEncodedLen)
+	/// Storage: AIRegistry ModelCallbacks (r:0 w:1)
+	/// Storage: AIRegistry PendingSizeCorrections (r:0 w:1)
+	/// Storage: AIRegistry LastActivity (r:0 w:1)
+	/// Storage: AIRegistry RentChargedUntil (r:0 w:1)
+	/// Storage: Nfts Collection (r:1 w:1)
+	/// Proof: Nfts Collection (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	/// Storage: Nfts CollectionConfigOf (r:1 w:0)
+	/// Proof: Nfts CollectionConfigOf (max_values: None, max_size: Some(73), added: 2548, mode: MaxEncodedLen)
+	/// Storage: Nfts Item (r:1 w:1)
+	/// Proof: Nfts Item (max_values: None, max_size: Some(865), added: 3340, mode: MaxEncodedLen)
+	/// Storage: Nfts Account (r:0 w:1)
+	/// Proof: Nfts Account (max_values: None, max_size: Some(92), added: 2567, mode: MaxEncodedLen)
+	/// Storage: Nfts ItemConfigOf (r:0 w:1)
+	/// Proof: Nfts ItemConfigOf (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: AIRegistry Shareholders (r:0 w:1)
+	/// Proof: AIRegistry Shareholders (max_values: None, max_size: Some(385), added: 2860, mode: MaxEncodedLen)
+	/// Storage: AIRegistry MetadataProposals (r:0 w:1)
+	/// Proof: AIRegistry MetadataProposals (max_values: None, max_size: Some(1439), added: 3914, mode: MaxEncodedLen)
+	/// Storage: AIRegistry RevenueRecipient (r:0 w:1)
+	/// Proof: AIRegistry RevenueRecipient (max_values: None, max_size: Some(56), added: 2531, mode: MaxEncodedLen)
+	/// Storage: AIRegistry ModelDatasets (r:0 w:1)
+	/// Proof: AIRegistry ModelDatasets (max_values: None, max_size: Some(153), added: 2628, mode: MaxEncodedLen)
+	/// Storage: AIRegistry ParentShare (r:0 w:1)
+	/// Proof: AIRegistry ParentShare (max_values: None, max_size: Some(28), added: 2503, mode: MaxEncodedLen)
+	/// Storage: AIRegistry Successors (r:0 w:1)
+	/// Proof: AIRegistry Successors (max_values: None, max_size: Some(32), added: 2507, mode: MaxEncodedLen)
+	/// Storage: AIRegistry Watchers (r:0 w:1)
+	/// Proof: AIRegistry Watchers (max_values: None, max_size: Some(8218), added: 10693, mode: MaxEncodedLen)
+	/// Storage: AIRegistry IncentiveUsage (r:1 w:1)
+	/// Proof: AIRegistry IncentiveUsage (max_values: None, max_size: Some(32), added: 2507, mode: MaxEncodedLen)
+	/// Storage: AIRegistry EraIncentives (r:1 w:1)
+	/// Proof: AIRegistry EraIncentives (max_values: None, max_size: Some(44), added: 2519, mode: MaxEncodedLen)
+	/// Storage: AIRegistry IncentiveEarnings (r:1 w:1)
+	/// Proof: AIRegistry IncentiveEarnings (max_values: None, max_size: Some(40), added: 2515, mode: MaxEncodedLen)
+	/// Storage: AIRegistry IncentiveFunds (r:0 w:1)
+	/// Proof: AIRegistry IncentiveFunds (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
+	/// Storage: AIRegistry TopModels (r:1 w:1)
+	/// Proof: AIRegistry TopModels (max_values: Some(1), max_size: Some(1202), added: 1697, mode: MaxEncodedLen)
+	/// Storage: AIRegistry EraRewards (r:0 w:1)
+	/// Proof: AIRegistry EraRewards (max_values: None, max_size: Some(40), added: 2515, mode: MaxEncodedLen)
+	/// Storage: AIRegistry LockedMetadata (r:0 w:1)
+	/// Proof: AIRegistry LockedMetadata (max_values: None, max_size: Some(28), added: 2503, mode: MaxEncodedLen)
+	/// Storage: AIRegistry UpdateHistory (r:0 w:1)
+	/// Proof: AIRegistry UpdateHistory (max_values: None, max_size: Some(1337), added: 3812, mode: MaxEncodedLen)
+	/// Storage: AIRegistry RecentUsage (r:0 w:1)
+	/// Proof: AIRegistry RecentUsage (max_values: None, max_size: Some(137), added: 2612, mode: MaxEncodedLen)
+	/// Storage: AIRegistry GlobalStats (r:1 w:1)
+	/// Proof: AIRegistry GlobalStats (max_values: Some(1), max_size: Some(40), added: 535, mode: MaxEncodedLen)
+	/// Storage: AIRegistry ModelRevenue (r:0 w:1)
+	/// Proof: AIRegistry ModelRevenue (max_values: None, max_size: Some(40), added: 2515, mode: MaxEncodedLen)
+	/// Storage: AIRegistry RecentRevenue (r:0 w:1)
+	/// Proof: AIRegistry RecentRevenue (max_values: None, max_size: Some(305), added: 2780, mode: MaxEncodedLen)
+	/// Storage: AIRegistry MostUsedModels (r:1 w:1)
+	/// Proof: AIRegistry MostUsedModels (max_values: Some(1), max_size: Some(1602), added: 2097, mode: MaxEncodedLen)
+	/// Storage: AIRegistry NameIndex (r:0 w:1)
+	/// Proof: AIRegistry NameIndex (max_values: None, max_size: Some(44), added: 2519, mode: MaxEncodedLen)
+	/// Storage: AIRegistry ModelsByCid (r:1 w:1)
+	/// Proof: AIRegistry ModelsByCid (max_values: None, max_size: Some(40), added: 2515, mode: MaxEncodedLen)
+	/// Storage: AIRegistry OwnerModelNames (r:1 w:1)
+	/// Proof: AIRegistry OwnerModelNames (max_values: None, max_size: Some(88), added: 2563, mode: MaxEncodedLen)
+	/// Storage: AIRegistry RatingHistograms (r:0 w:1)
+	/// Proof: AIRegistry RatingHistograms (max_values: None, max_size: Some(44), added: 2519, mode: MaxEncodedLen)
+	/// Storage: AIRegistry RecentRatings (r:0 w:1)
+	/// Proof: AIRegistry RecentRatings (max_values: None, max_size: Some(45), added: 2520, mode: MaxEncodedLen)
+	/// Storage: AIRegistry PendingDeactivations (r:1 w:1)
+	/// Proof: AIRegistry PendingDeactivations (max_values: None, max_size: Some(28), added: 2503, mode: MaxEncodedLen)
+	/// Storage: AIRegistry ScheduledDeactivations (r:1 w:1)
+	/// Proof: AIRegistry ScheduledDeactivations (max_values: None, max_size: Some(144), added: 2619, mode: MaxEncodedLen)
+	/// Storage: AIRegistry PendingPriceChanges (r:1 w:1)
+	/// Proof: AIRegistry PendingPriceChanges (max_values: None, max_size: Some(44), added: 2519, mode: MaxEncodedLen)
+	/// Storage: AIRegistry ScheduledPriceChanges (r:1 w:1)
+	/// Proof: AIRegistry ScheduledPriceChanges (max_values: None, max_size: Some(144), added: 2619, mode: MaxEncodedLen)
+	/// Storage: AIRegistry UnderReview (r:0 w:1)
+	/// Proof: AIRegistry UnderReview (max_values: None, max_size: Some(25), added: 2500, mode: MaxEncodedLen)
+	/// Storage: AIRegistry ReportCounts (r:1 w:1)
+	/// Proof: AIRegistry ReportCounts (max_values: None, max_size: Some(28), added: 2503, mode: MaxEncodedLen)
+	/// Storage: AIRegistry ModelReports (r:0 w:1)
+	/// Proof: AIRegistry ModelReports (max_values: None, max_size: Some(76), added: 2551, mode: MaxEncodedLen)
+	/// Storage: AIRegistry RaterCounts (r:1 w:1)
+	/// Proof: AIRegistry RaterCounts (max_values: None, max_size: Some(28), added: 2503, mode: MaxEncodedLen)
+	/// Storage: AIRegistry RatingWeights (r:0 w:1)
+	/// Proof: AIRegistry RatingWeights (max_values: None, max_size: Some(76), added: 2551, mode: MaxEncodedLen)
+	/// Storage: AIRegistry ImportedFrom (r:0 w:1)
+	/// Proof: AIRegistry ImportedFrom (max_values: None, max_size: Some(72), added: 2547, mode: MaxEncodedLen)
+	/// Storage: AIRegistry ModelCollections (r:1 w:1)
+	/// Proof: AIRegistry ModelCollections (max_values: None, max_size: Some(153), added: 2628, mode: MaxEncodedLen)
+	/// Storage: AIRegistry Collections (r:16 w:16)
+	/// Proof: AIRegistry Collections (max_values: None, max_size: Some(828), added: 3303, mode: MaxEncodedLen)
+	/// The range of component `c` is `[0, 16]`.
+	fn reap_model(c: u32, ) -> Weight {
+		Weight::from_parts(44_000_000, 54552)
+			.saturating_add(Weight::from_parts(3_000_000, 0).saturating_mul(c.into()))
+			.saturating_add(T::DbWeight::get().reads(21))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(c.into())))
+			.saturating_add(T::DbWeight::get().writes(50))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(c.into())))
+			.saturating_add(Weight::from_parts(0, 3303).saturating_mul(c.into()))
+	}
+
+	/// Storage: AIRegistry Models (r:1 w:1)
+	/// Proof: AIRegistry Models (max_values: None, max_size: Some(1712), added: 4187, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: AIRegistry RentExhausted (r:0 w:1)
+	/// Proof: AIRegistry RentExhausted (max_values: None, max_size: Some(28), added: 2503, mode: MaxEncodedLen)
+	/// Storage: AIRegistry Watchers (r:1 w:0)
+	/// Proof: AIRegistry Watchers (max_values: None, max_size: Some(8218), added: 10693, mode: MaxEncodedLen)
+	/// Storage: AIRegistry GlobalStats (r:1 w:1)
+	/// Proof: AIRegistry GlobalStats (max_values: Some(1), max_size: Some(40), added: 535, mode: MaxEncodedLen)
+	/// Storage: AIRegistry UnderReview (r:1 w:1)
+	/// Proof: AIRegistry UnderReview (max_values: None, max_size: Some(25), added: 2500, mode: MaxEncodedLen)
+	/// Storage: AIRegistry ForcedDeactivations (r:0 w:1)
+	/// Proof: AIRegistry ForcedDeactivations (max_values: None, max_size: Some(25), added: 2500, mode: MaxEncodedLen)
+	/// Storage: AIRegistry ReportCounts (r:1 w:1)
+	/// Proof: AIRegistry ReportCounts (max_values: None, max_size: Some(28), added: 2503, mode: MaxEncodedLen)
+	/// Storage: AIRegistry ModelReports (r:0 w:10)
+	/// Proof: AIRegistry ModelReports (max_values: None, max_size: Some(76), added: 2551, mode: MaxEncodedLen)
+	/// The range of component `r` is `[0, 10]`.
+	fn slash_model(r: u32, ) -> Weight {
+		Weight::from_parts(42_000_000, 23021)
+			// Standard Error: 3_000
+			.saturating_add(Weight::from_parts(1_500_000, 0).saturating_mul(r.into()))
+			.saturating_add(T::DbWeight::get().reads(6))
+			.saturating_add(T::DbWeight::get().writes(7))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(r.into())))
+	}
+	/// Storage: AIRegistry Models (r:1 w:1)
+	/// Proof: AIRegistry Models (max_values: None, max_size: Some(1712), added: 4187, mode: MaxEncodedLen)
+	/// Storage: System Account (r:2 w:2)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: AIRegistry OwnedModels (r:2 w:2)
+	/// Proof: AIRegistry OwnedModels (max_values: None, max_size: Some(2098), added: 4573, mode: MaxEncodedLen)
+	/// Storage: Nfts Item (r:1 w:1)
+	/// Proof: Nfts Item (max_values: None, max_size: Some(865), added: 3340, mode: MaxEncodedLen)
+	/// Storage: Nfts Collection (r:1 w:0)
+	/// Proof: Nfts Collection (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	/// Storage: Nfts Account (r:0 w:2)
+	/// Proof: Nfts Account (max_values: None, max_size: Some(92), added: 2567, mode: MaxEncodedLen)
+	/// Storage: AIRegistry OwnerModelNames (r:2 w:2)
+	/// Proof: AIRegistry OwnerModelNames (max_values: None, max_size: Some(88), added: 2563, mode: MaxEncodedLen)
+	fn transfer_model() -> Weight {
+		Weight::from_parts(55_000_000, 29564)
+			.saturating_add(T::DbWeight::get().reads(9))
+			.saturating_add(T::DbWeight::get().writes(9))
+	}
+
+	/// Storage: Nfts Item (r:1 w:0)
+	/// Proof: Nfts Item (max_values: None, max_size: Some(865), added: 3340, mode: MaxEncodedLen)
+	/// Storage: AIRegistry Models (r:1 w:1)
+	/// Proof: AIRegistry Models (max_values: None, max_size: Some(1712), added: 4187, mode: MaxEncodedLen)
+	/// Storage: System Account (r:2 w:2)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: AIRegistry OwnedModels (r:2 w:2)
+	/// Proof: AIRegistry OwnedModels (max_values: None, max_size: Some(2098), added: 4573, mode: MaxEncodedLen)
+	/// Storage: AIRegistry OwnerModelNames (r:2 w:2)
+	/// Proof: AIRegistry OwnerModelNames (max_values: None, max_size: Some(88), added: 2563, mode: MaxEncodedLen)
+	fn sync_model_owner() -> Weight {
+		Weight::from_parts(40_000_000, 27005)
+			.saturating_add(T::DbWeight::get().reads(8))
+			.saturating_add(T::DbWeight::get().writes(7))
+	}
+
+	/// Storage: AIRegistry Models (r:1 w:0)
+	/// Proof: AIRegistry Models (max_values: None, max_size: Some(1712), added: 4187, mode: MaxEncodedLen)
+	/// Storage: Nfts Item (r:1 w:0)
+	/// Proof: Nfts Item (max_values: None, max_size: Some(865), added: 3340, mode: MaxEncodedLen)
+	/// Storage: AIRegistry Shareholders (r:1 w:1)
+	/// Proof: AIRegistry Shareholders (max_values: None, max_size: Some(385), added: 2860, mode: MaxEncodedLen)
+	/// The range of component `s` is `[1, 10]`.
+	fn fractionalize_model(s: u32, ) -> Weight {
+		Weight::from_parts(22_000_000, 10387)
+			// Standard Error: 20_000
+			.saturating_add(Weight::from_parts(400_000, 0).saturating_mul(s.into()))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+
+	/// Storage: AIRegistry Shareholders (r:1 w:1)
+	/// Proof: AIRegistry Shareholders (max_values: None, max_size: Some(385), added: 2860, mode: MaxEncodedLen)
+	/// Storage: AIRegistry MetadataProposals (r:0 w:1)
+	/// Proof: AIRegistry MetadataProposals (max_values: None, max_size: Some(1439), added: 3914, mode: MaxEncodedLen)
+	fn transfer_shares() -> Weight {
+		Weight::from_parts(24_000_000, 2860)
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+
+	/// Storage: AIRegistry Shareholders (r:1 w:0)
+	/// Proof: AIRegistry Shareholders (max_values: None, max_size: Some(385), added: 2860, mode: MaxEncodedLen)
+	/// Storage: AIRegistry MetadataProposals (r:1 w:1)
+	/// Proof: AIRegistry MetadataProposals (max_values: None, max_size: Some(1439), added: 3914, mode: MaxEncodedLen)
+	/// Storage: AIRegistry Models (r:1 w:1)
+	/// Proof: AIRegistry Models (max_values: None, max_size: Some(1712), added: 4187, mode: MaxEncodedLen)
+	/// Storage: AIRegistry Successors (r:0 w:1)
+	/// Proof: AIRegistry Successors (max_values: None, max_size: Some(32), added: 2507, mode: MaxEncodedLen)
+	/// Storage: AIRegistry Watchers (r:1 w:0)
+	/// Proof: AIRegistry Watchers (max_values: None, max_size: Some(8218), added: 10693, mode: MaxEncodedLen)
+	/// Storage: AIRegistry LockedMetadata (r:1 w:0)
+	/// Proof: AIRegistry LockedMetadata (max_values: None, max_size: Some(28), added: 2503, mode: MaxEncodedLen)
+	/// Storage: AIRegistry UpdateHistory (r:1 w:1)
+	/// Proof: AIRegistry UpdateHistory (max_values: None, max_size: Some(1337), added: 3812, mode: MaxEncodedLen)
+	/// Storage: AIRegistry GlobalStats (r:1 w:1)
+	/// Proof: AIRegistry GlobalStats (max_values: Some(1), max_size: Some(40), added: 535, mode: MaxEncodedLen)
+	fn propose_metadata_update() -> Weight {
+		Weight::from_parts(40_000_000, 28504)
+			.saturating_add(T::DbWeight::get().reads(7))
+			.saturating_add(T::DbWeight::get().writes(5))
+	}
+
+	/// Storage: AIRegistry Shareholders (r:1 w:0)
+	/// Proof: AIRegistry Shareholders (max_values: None, max_size: Some(385), added: 2860, mode: MaxEncodedLen)
+	/// Storage: AIRegistry MetadataProposals (r:1 w:1)
+	/// Proof: AIRegistry MetadataProposals (max_values: None, max_size: Some(1439), added: 3914, mode: MaxEncodedLen)
+	/// Storage: AIRegistry Models (r:1 w:1)
+	/// Proof: AIRegistry Models (max_values: None, max_size: Some(1712), added: 4187, mode: MaxEncodedLen)
+	/// Storage: AIRegistry Successors (r:0 w:1)
+	/// Proof: AIRegistry Successors (max_values: None, max_size: Some(32), added: 2507, mode: MaxEncodedLen)
+	/// Storage: AIRegistry Watchers (r:1 w:0)
+	/// Proof: AIRegistry Watchers (max_values: None, max_size: Some(8218), added: 10693, mode: MaxEncodedLen)
+	/// Storage: AIRegistry LockedMetadata (r:1 w:0)
+	/// Proof: AIRegistry LockedMetadata (max_values: None, max_size: Some(28), added: 2503, mode: MaxEncodedLen)
+	/// Storage: AIRegistry UpdateHistory (r:1 w:1)
+	/// Proof: AIRegistry UpdateHistory (max_values: None, max_size: Some(1337), added: 3812, mode: MaxEncodedLen)
+	/// Storage: AIRegistry GlobalStats (r:1 w:1)
+	/// Proof: AIRegistry GlobalStats (max_values: Some(1), max_size: Some(40), added: 535, mode: MaxEncodedLen)
+	fn approve_metadata_update() -> Weight {
+		Weight::from_parts(42_000_000, 28504)
+			.saturating_add(T::DbWeight::get().reads(7))
+			.saturating_add(T::DbWeight::get().writes(5))
+	}
+
+	/// Storage: AIRegistry MetadataProposals (r:1 w:1)
+	/// Proof: AIRegistry MetadataProposals (max_values: None, max_size: Some(1439), added: 3914, mode: MaxEncodedLen)
+	fn cancel_metadata_update() -> Weight {
+		Weight::from_parts(18_000_000, 3914)
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+
+	/// Storage: AIRegistry NextDatasetId (r:1 w:1)
+	/// Proof: AIRegistry NextDatasetId (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: System Account (r:2 w:2)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: AIRegistry Datasets (r:0 w:1)
+	/// Proof: AIRegistry Datasets (max_values: None, max_size: Some(583), added: 3058, mode: MaxEncodedLen)
+	fn register_dataset() -> Weight {
+		Weight::from_parts(30_000_000, 5709)
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(4))
+	}
+
+	/// Storage: AIRegistry Models (r:1 w:1)
+	/// Proof: AIRegistry Models (max_values: None, max_size: Some(1712), added: 4187, mode: MaxEncodedLen)
+	/// Storage: AIRegistry NextModelId (r:1 w:1)
+	/// Proof: AIRegistry NextModelId (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: AIRegistry OwnedModels (r:1 w:1)
+	/// Proof: AIRegistry OwnedModels (max_values: None, max_size: Some(2098), added: 4573, mode: MaxEncodedLen)
+	/// Storage: Nfts Collection (r:1 w:1)
+	/// Proof: Nfts Collection (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	/// Storage: Nfts CollectionConfigOf (r:1 w:0)
+	/// Proof: Nfts CollectionConfigOf (max_values: None, max_size: Some(73), added: 2548, mode: MaxEncodedLen)
+	/// Storage: Nfts Item (r:1 w:1)
+	/// Proof: Nfts Item (max_values: None, max_size: Some(865), added: 3340, mode: MaxEncodedLen)
+	/// Storage: Nfts Account (r:0 w:1)
+	/// Proof: Nfts Account (max_values: None, max_size: Some(92), added: 2567, mode: MaxEncodedLen)
+	/// Storage: Nfts ItemConfigOf (r:0 w:1)
+	/// Proof: Nfts ItemConfigOf (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: AIRegistry ParentShare (r:0 w:1)
+	/// Proof: AIRegistry ParentShare (max_values: None, max_size: Some(28), added: 2503, mode: MaxEncodedLen)
+	/// Storage: AIRegistry GlobalStats (r:1 w:1)
+	/// Proof: AIRegistry GlobalStats (max_values: Some(1), max_size: Some(40), added: 535, mode: MaxEncodedLen)
+	/// Storage: System Account (r:2 w:2)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: AIRegistry NameIndex (r:0 w:1)
+	/// Proof: AIRegistry NameIndex (max_values: None, max_size: Some(44), added: 2519, mode: MaxEncodedLen)
+	/// Storage: AIRegistry ModelsByCid (r:1 w:1)
+	/// Proof: AIRegistry ModelsByCid (max_values: None, max_size: Some(40), added: 2515, mode: MaxEncodedLen)
+	/// Storage: AIRegistry OwnerModelNames (r:1 w:1)
+	/// Proof: AIRegistry OwnerModelNames (max_values: None, max_size: Some(88), added: 2563, mode: MaxEncodedLen)
+	/// Storage: AIRegistry AllowlistRequired (r:1 w:0)
+	/// Proof: AIRegistry AllowlistRequired (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	/// Storage: DeveloperMembership Members (r:1 w:0)
+	/// Proof: DeveloperMembership Members (max_values: Some(1), max_size: Some(3202), added: 3697, mode: MaxEncodedLen)
+	fn fork_model() -> Weight {
+		Weight::from_parts(55_000_000, 32722)
+			.saturating_add(T::DbWeight::get().reads(13))
+			.saturating_add(T::DbWeight::get().writes(16))
+	}
+
+	/// Storage: AIRegistry NextCollectionId (r:1 w:1)
+	/// Proof: AIRegistry NextCollectionId (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: System Account (r:2 w:2)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: AIRegistry Collections (r:0 w:1)
+	/// Proof: AIRegistry Collections (max_values: None, max_size: Some(828), added: 3303, mode: MaxEncodedLen)
+	fn create_collection() -> Weight {
+		Weight::from_parts(28_000_000, 5709)
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(4))
+	}
+
+	/// Storage: AIRegistry Collections (r:1 w:1)
+	/// Proof: AIRegistry Collections (max_values: None, max_size: Some(828), added: 3303, mode: MaxEncodedLen)
+	/// Storage: AIRegistry Models (r:1 w:0)
+	/// Proof: AIRegistry Models (max_values: None, max_size: Some(1712), added: 4187, mode: MaxEncodedLen)
+	/// Storage: Nfts Item (r:1 w:0)
+	/// Proof: Nfts Item (max_values: None, max_size: Some(865), added: 3340, mode: MaxEncodedLen)
+	/// Storage: AIRegistry ModelCollections (r:1 w:1)
+	/// Proof: AIRegistry ModelCollections (max_values: None, max_size: Some(153), added: 2628, mode: MaxEncodedLen)
+	fn add_to_collection() -> Weight {
+		Weight::from_parts(27_000_000, 13458)
+			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+
+	/// Storage: AIRegistry Collections (r:1 w:1)
+	/// Proof: AIRegistry Collections (max_values: None, max_size: Some(828), added: 3303, mode: MaxEncodedLen)
+	/// Storage: AIRegistry Models (r:1 w:0)
+	/// Proof: AIRegistry Models (max_values: None, max_size: Some(1712), added: 4187, mode: MaxEncodedLen)
+	/// Storage: AIRegistry ModelCollections (r:1 w:1)
+	/// Proof: AIRegistry ModelCollections (max_values: None, max_size: Some(153), added: 2628, mode: MaxEncodedLen)
+	fn remove_from_collection() -> Weight {
+		Weight::from_parts(27_000_000, 10118)
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+
+	/// Storage: AIRegistry Models (r:2 w:1)
+	/// Proof: AIRegistry Models (max_values: None, max_size: Some(1712), added: 4187, mode: MaxEncodedLen)
+	/// Storage: Nfts Item (r:1 w:0)
+	/// Proof: Nfts Item (max_values: None, max_size: Some(865), added: 3340, mode: MaxEncodedLen)
+	/// Storage: AIRegistry Shareholders (r:1 w:0)
+	/// Proof: AIRegistry Shareholders (max_values: None, max_size: Some(385), added: 2860, mode: MaxEncodedLen)
+	/// Storage: AIRegistry Successors (r:0 w:1)
+	/// Proof: AIRegistry Successors (max_values: None, max_size: Some(32), added: 2507, mode: MaxEncodedLen)
+	/// Storage: AIRegistry Watchers (r:1 w:0)
+	/// Proof: AIRegistry Watchers (max_values: None, max_size: Some(8218), added: 10693, mode: MaxEncodedLen)
+	/// Storage: AIRegistry GlobalStats (r:1 w:1)
+	/// Proof: AIRegistry GlobalStats (max_values: Some(1), max_size: Some(40), added: 535, mode: MaxEncodedLen)
+	fn deprecate_model() -> Weight {
+		Weight::from_parts(32_000_000, 25802)
+			.saturating_add(T::DbWeight::get().reads(6))
+			.saturating_add(T::DbWeight::get().writes(3))
+	}
+
+	/// Storage: AIRegistry Models (r:1 w:0)
+	/// Proof: AIRegistry Models (max_values: None, max_size: Some(1712), added: 4187, mode: MaxEncodedLen)
+	/// Storage: AIRegistry Bookmarks (r:1 w:1)
+	/// Proof: AIRegistry Bookmarks (max_values: None, max_size: Some(1074), added: 3549, mode: MaxEncodedLen)
+	fn bookmark_model() -> Weight {
+		Weight::from_parts(20_000_000, 7736)
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+
+	/// Storage: AIRegistry Bookmarks (r:1 w:1)
+	/// Proof: AIRegistry Bookmarks (max_values: None, max_size: Some(1074), added: 3549, mode: MaxEncodedLen)
+	fn unbookmark_model() -> Weight {
+		Weight::from_parts(18_000_000, 3549)
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+
+	/// Storage: AIRegistry Models (r:1 w:0)
+	/// Proof: AIRegistry Models (max_values: None, max_size: Some(1712), added: 4187, mode: MaxEncodedLen)
+	/// Storage: AIRegistry Watchers (r:1 w:1)
+	/// Proof: AIRegistry Watchers (max_values: None, max_size: Some(8218), added: 10693, mode: MaxEncodedLen)
+	fn watch_model() -> Weight {
+		Weight::from_parts(20_000_000, 14880)
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+
+	/// Storage: AIRegistry Watchers (r:1 w:1)
+	/// Proof: AIRegistry Watchers (max_values: None, max_size: Some(8218), added: 10693, mode: MaxEncodedLen)
+	fn unwatch_model() -> Weight {
+		Weight::from_parts(18_000_000, 10693)
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+
+	/// Storage: AIRegistry Models (r:1 w:0)
+	/// Proof: AIRegistry Models (max_values: None, max_size: Some(1712), added: 4187, mode: MaxEncodedLen)
+	/// Storage: AIRegistry RebatableFees (r:1 w:1)
+	/// Proof: AIRegistry RebatableFees (max_values: None, max_size: Some(40), added: 2515, mode: MaxEncodedLen)
+	/// Storage: AIRegistry RebatedUntil (r:1 w:1)
+	/// Proof: AIRegistry RebatedUntil (max_values: None, max_size: Some(28), added: 2503, mode: MaxEncodedLen)
+	/// Storage: System Account (r:2 w:2)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: AIRegistry RaterCounts (r:1 w:0)
+	/// Proof: AIRegistry RaterCounts (max_values: None, max_size: Some(28), added: 2503, mode: MaxEncodedLen)
+	fn claim_fee_rebate() -> Weight {
+		Weight::from_parts(43_000_000, 16914)
+			.saturating_add(T::DbWeight::get().reads(6))
+			.saturating_add(T::DbWeight::get().writes(4))
+	}
+
+	/// Storage: System Account (r:2 w:2)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: AIRegistry IncentiveFunds (r:1 w:1)
+	/// Proof: AIRegistry IncentiveFunds (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
+	/// Storage: AIRegistry IncentivePerEra (r:0 w:1)
+	/// Proof: AIRegistry IncentivePerEra (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
+	fn fund_incentives() -> Weight {
+		Weight::from_parts(38_000_000, 5717)
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(4))
+	}
+
+	/// Storage: AIRegistry Models (r:1 w:0)
+	/// Proof: AIRegistry Models (max_values: None, max_size: Some(1712), added: 4187, mode: MaxEncodedLen)
+	/// Storage: AIRegistry IncentiveUsage (r:1 w:1)
+	/// Proof: AIRegistry IncentiveUsage (max_values: None, max_size: Some(32), added: 2507, mode: MaxEncodedLen)
+	/// Storage: AIRegistry EraIncentives (r:1 w:1)
+	/// Proof: AIRegistry EraIncentives (max_values: None, max_size: Some(44), added: 2519, mode: MaxEncodedLen)
+	/// Storage: AIRegistry IncentiveEarnings (r:1 w:1)
+	/// Proof: AIRegistry IncentiveEarnings (max_values: None, max_size: Some(40), added: 2515, mode: MaxEncodedLen)
+	/// Storage: System Account (r:2 w:2)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	fn claim_incentive() -> Weight {
+		Weight::from_parts(45_000_000, 16934)
+			.saturating_add(T::DbWeight::get().reads(6))
+			.saturating_add(T::DbWeight::get().writes(5))
+	}
+
+	/// Storage: AIRegistry TopModels (r:1 w:1)
+	/// Proof: AIRegistry TopModels (max_values: Some(1), max_size: Some(1202), added: 1697, mode: MaxEncodedLen)
+	/// Storage: AIRegistry Models (r:100 w:0)
+	/// Proof: AIRegistry Models (max_values: None, max_size: Some(1712), added: 4187, mode: MaxEncodedLen)
+	/// Storage: AIRegistry EraRewards (r:100 w:100)
+	/// Proof: AIRegistry EraRewards (max_values: None, max_size: Some(40), added: 2515, mode: MaxEncodedLen)
+	fn credit_era_rewards(n: u32, ) -> Weight {
+		Weight::from_parts(8_000_000, 1697)
+			// Standard Error: 400_000
+			.saturating_add(Weight::from_parts(9_000_000, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes(1))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 6702).saturating_mul(n.into()))
+	}
+
+	/// Storage: AIRegistry Models (r:1 w:0)
+	/// Proof: AIRegistry Models (max_values: None, max_size: Some(1712), added: 4187, mode: MaxEncodedLen)
+	/// Storage: AIRegistry EraRewards (r:1 w:1)
+	/// Proof: AIRegistry EraRewards (max_values: None, max_size: Some(40), added: 2515, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	fn claim_reward() -> Weight {
+		Weight::from_parts(32_000_000, 9305)
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+
+	/// Storage: AIRegistry Models (r:1 w:1)
+	/// Proof: AIRegistry Models (max_values: None, max_size: Some(1712), added: 4187, mode: MaxEncodedLen)
+	/// Storage: Nfts Item (r:1 w:0)
+	/// Proof: Nfts Item (max_values: None, max_size: Some(865), added: 3340, mode: MaxEncodedLen)
+	/// Storage: AIRegistry Shareholders (r:1 w:0)
+	/// Proof: AIRegistry Shareholders (max_values: None, max_size: Some(385), added: 2860, mode: MaxEncodedLen)
+	/// Storage: AIRegistry LockedMetadata (r:1 w:0)
+	/// Proof: AIRegistry LockedMetadata (max_values: None, max_size: Some(28), added: 2503, mode: MaxEncodedLen)
+	/// Storage: AIRegistry UpdateHistory (r:1 w:1)
+	/// Proof: AIRegistry UpdateHistory (max_values: None, max_size: Some(1337), added: 3812, mode: MaxEncodedLen)
+	/// Storage: AIRegistry NameIndex (r:0 w:2)
+	/// Proof: AIRegistry NameIndex (max_values: None, max_size: Some(44), added: 2519, mode: MaxEncodedLen)
+	/// Storage: AIRegistry OwnerModelNames (r:2 w:2)
+	/// Proof: AIRegistry OwnerModelNames (max_values: None, max_size: Some(88), added: 2563, mode: MaxEncodedLen)
+	fn rename_model() -> Weight {
+		Weight::from_parts(24_000_000, 21828)
+			.saturating_add(T::DbWeight::get().reads(7))
+			.saturating_add(T::DbWeight::get().writes(6))
+	}
+
+	/// Storage: AIRegistry Models (r:1 w:0)
+	/// Proof: AIRegistry Models (max_values: None, max_size: Some(1712), added: 4187, mode: MaxEncodedLen)
+	/// Storage: Nfts Item (r:1 w:0)
+	/// Proof: Nfts Item (max_values: None, max_size: Some(865), added: 3340, mode: MaxEncodedLen)
+	/// Storage: AIRegistry Shareholders (r:1 w:0)
+	/// Proof: AIRegistry Shareholders (max_values: None, max_size: Some(385), added: 2860, mode: MaxEncodedLen)
+	/// Storage: AIRegistry LockedMetadata (r:1 w:1)
+	/// Proof: AIRegistry LockedMetadata (max_values: None, max_size: Some(28), added: 2503, mode: MaxEncodedLen)
+	fn lock_metadata() -> Weight {
+		Weight::from_parts(22_000_000, 12890)
+			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+
+	/// Storage: AIRegistry AccountCreated (r:1 w:0)
+	/// Proof: AIRegistry AccountCreated (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:0)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: AIRegistry UnderReview (r:1 w:1)
+	/// Proof: AIRegistry UnderReview (max_values: None, max_size: Some(25), added: 2500, mode: MaxEncodedLen)
+	/// Storage: AIRegistry ModelReports (r:1 w:1)
+	/// Proof: AIRegistry ModelReports (max_values: None, max_size: Some(76), added: 2551, mode: MaxEncodedLen)
+	/// Storage: AIRegistry ReportCounts (r:1 w:1)
+	/// Proof: AIRegistry ReportCounts (max_values: None, max_size: Some(28), added: 2503, mode: MaxEncodedLen)
+	/// Storage: AIRegistry Models (r:1 w:1)
+	/// Proof: AIRegistry Models (max_values: None, max_size: Some(1712), added: 4187, mode: MaxEncodedLen)
+	/// Storage: AIRegistry Watchers (r:1 w:0)
+	/// Proof: AIRegistry Watchers (max_values: None, max_size: Some(8218), added: 10693, mode: MaxEncodedLen)
+	/// Storage: AIRegistry GlobalStats (r:1 w:1)
+	/// Proof: AIRegistry GlobalStats (max_values: Some(1), max_size: Some(40), added: 535, mode: MaxEncodedLen)
+	/// Storage: AIRegistry PendingDeactivations (r:1 w:1)
+	/// Proof: AIRegistry PendingDeactivations (max_values: None, max_size: Some(28), added: 2503, mode: MaxEncodedLen)
+	/// Storage: AIRegistry ScheduledDeactivations (r:1 w:1)
+	/// Proof: AIRegistry ScheduledDeactivations (max_values: None, max_size: Some(144), added: 2619, mode: MaxEncodedLen)
+	fn report_model() -> Weight {
+		Weight::from_parts(34_000_000, 33221)
+			.saturating_add(T::DbWeight::get().reads(10))
+			.saturating_add(T::DbWeight::get().writes(7))
+	}
+
+	/// Storage: AIRegistry UnderReview (r:1 w:1)
+	/// Proof: AIRegistry UnderReview (max_values: None, max_size: Some(25), added: 2500, mode: MaxEncodedLen)
+	/// Storage: AIRegistry Models (r:1 w:1)
+	/// Proof: AIRegistry Models (max_values: None, max_size: Some(1712), added: 4187, mode: MaxEncodedLen)
+	/// Storage: AIRegistry RentExhausted (r:1 w:0)
+	/// Proof: AIRegistry RentExhausted (max_values: None, max_size: Some(28), added: 2503, mode: MaxEncodedLen)
+	/// Storage: AIRegistry Watchers (r:1 w:0)
+	/// Proof: AIRegistry Watchers (max_values: None, max_size: Some(8218), added: 10693, mode: MaxEncodedLen)
+	/// Storage: AIRegistry GlobalStats (r:1 w:1)
+	/// Proof: AIRegistry GlobalStats (max_values: Some(1), max_size: Some(40), added: 535, mode: MaxEncodedLen)
+	/// Storage: AIRegistry ReportCounts (r:1 w:1)
+	/// Proof: AIRegistry ReportCounts (max_values: None, max_size: Some(28), added: 2503, mode: MaxEncodedLen)
+	/// Storage: AIRegistry ModelReports (r:0 w:10)
+	/// Proof: AIRegistry ModelReports (max_values: None, max_size: Some(76), added: 2551, mode: MaxEncodedLen)
+	/// The range of component `r` is `[1, 10]`.
+	fn dismiss_reports(r: u32, ) -> Weight {
+		Weight::from_parts(30_000_000, 22921)
+			// Standard Error: 3_000
+			.saturating_add(Weight::from_parts(1_500_000, 0).saturating_mul(r.into()))
+			.saturating_add(T::DbWeight::get().reads(6))
+			.saturating_add(T::DbWeight::get().writes(4))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(r.into())))
+	}
+
+	/// Storage: AIRegistry Models (r:1 w:0)
+	/// Proof: AIRegistry Models (max_values: None, max_size: Some(1712), added: 4187, mode: MaxEncodedLen)
+	/// Storage: Nfts Item (r:1 w:0)
+	/// Proof: Nfts Item (max_values: None, max_size: Some(865), added: 3340, mode: MaxEncodedLen)
+	/// Storage: AIRegistry ForcedDeactivations (r:1 w:0)
+	/// Proof: AIRegistry ForcedDeactivations (max_values: None, max_size: Some(25), added: 2500, mode: MaxEncodedLen)
+	/// Storage: AIRegistry Appeals (r:1 w:1)
+	/// Proof: AIRegistry Appeals (max_values: None, max_size: Some(210), added: 2685, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	fn appeal_deactivation() -> Weight {
+		Weight::from_parts(38_000_000, 15315)
+			.saturating_add(T::DbWeight::get().reads(5))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+
+	/// Storage: AIRegistry Appeals (r:1 w:1)
+	/// Proof: AIRegistry Appeals (max_values: None, max_size: Some(210), added: 2685, mode: MaxEncodedLen)
+	/// Storage: AIRegistry ForcedDeactivations (r:1 w:1)
+	/// Proof: AIRegistry ForcedDeactivations (max_values: None, max_size: Some(25), added: 2500, mode: MaxEncodedLen)
+	/// Storage: System Account (r:2 w:2)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: AIRegistry Models (r:1 w:1)
+	/// Proof: AIRegistry Models (max_values: None, max_size: Some(1712), added: 4187, mode: MaxEncodedLen)
+	/// Storage: AIRegistry Watchers (r:1 w:0)
+	/// Proof: AIRegistry Watchers (max_values: None, max_size: Some(8218), added: 10693, mode: MaxEncodedLen)
+	/// Storage: AIRegistry GlobalStats (r:1 w:1)
+	/// Proof: AIRegistry GlobalStats (max_values: Some(1), max_size: Some(40), added: 535, mode: MaxEncodedLen)
+	/// Storage: AIRegistry LastActivity (r:0 w:1)
+	/// Proof: AIRegistry LastActivity (max_values: None, max_size: Some(28), added: 2503, mode: MaxEncodedLen)
+	fn resolve_appeal() -> Weight {
+		Weight::from_parts(45_000_000, 25806)
+			.saturating_add(T::DbWeight::get().reads(7))
+			.saturating_add(T::DbWeight::get().writes(7))
+	}
+
+	/// Storage: AIRegistry ScheduledDeactivations (r:1 w:1)
+	/// Proof: AIRegistry ScheduledDeactivations (max_values: None, max_size: Some(144), added: 2619, mode: MaxEncodedLen)
+	/// Storage: AIRegistry PendingDeactivations (r:0 w:16)
+	/// Proof: AIRegistry PendingDeactivations (max_values: None, max_size: Some(28), added: 2503, mode: MaxEncodedLen)
+	/// Storage: AIRegistry Models (r:16 w:16)
+	/// Proof: AIRegistry Models (max_values: None, max_size: Some(1712), added: 4187, mode: MaxEncodedLen)
+	/// Storage: System Account (r:16 w:16)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: AIRegistry Watchers (r:16 w:0)
+	/// Proof: AIRegistry Watchers (max_values: None, max_size: Some(8218), added: 10693, mode: MaxEncodedLen)
+	/// Storage: AIRegistry GlobalStats (r:16 w:16)
+	/// Proof: AIRegistry GlobalStats (max_values: Some(1), max_size: Some(40), added: 535, mode: MaxEncodedLen)
+	/// The range of component `n` is `[0, 16]`.
+	fn enact_deactivations(n: u32, ) -> Weight {
+		Weight::from_parts(5_000_000, 2619)
+			// Standard Error: 200_000
+			.saturating_add(Weight::from_parts(25_000_000, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().reads((4_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes(1))
+			.saturating_add(T::DbWeight::get().writes((4_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 18018).saturating_mul(n.into()))
+	}
+
+	/// Storage: AIRegistry Models (r:1 w:1)
+	/// Proof: AIRegistry Models (max_values: None, max_size: Some(1712), added: 4187, mode: MaxEncodedLen)
+	/// Storage: Nfts Item (r:1 w:0)
+	/// Proof: Nfts Item (max_values: None, max_size: Some(865), added: 3340, mode: MaxEncodedLen)
+	/// Storage: AIRegistry Shareholders (r:1 w:0)
+	/// Proof: AIRegistry Shareholders (max_values: None, max_size: Some(385), added: 2860, mode: MaxEncodedLen)
+	/// Storage: AIRegistry ForcedDeactivations (r:1 w:0)
+	/// Proof: AIRegistry ForcedDeactivations (max_values: None, max_size: Some(25), added: 2500, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: AIRegistry RentExhausted (r:0 w:1)
+	/// Proof: AIRegistry RentExhausted (max_values: None, max_size: Some(28), added: 2503, mode: MaxEncodedLen)
+	/// Storage: AIRegistry LastActivity (r:0 w:1)
+	/// Proof: AIRegistry LastActivity (max_values: None, max_size: Some(28), added: 2503, mode: MaxEncodedLen)
+	/// Storage: AIRegistry Successors (r:0 w:1)
+	/// Proof: AIRegistry Successors (max_values: None, max_size: Some(32), added: 2507, mode: MaxEncodedLen)
+	/// Storage: AIRegistry Watchers (r:1 w:0)
+	/// Proof: AIRegistry Watchers (max_values: None, max_size: Some(8218), added: 10693, mode: MaxEncodedLen)
+	/// Storage: AIRegistry GlobalStats (r:1 w:1)
+	/// Proof: AIRegistry GlobalStats (max_values: Some(1), max_size: Some(40), added: 535, mode: MaxEncodedLen)
+	/// Storage: AIRegistry PendingDeactivations (r:1 w:1)
+	/// Proof: AIRegistry PendingDeactivations (max_values: None, max_size: Some(28), added: 2503, mode: MaxEncodedLen)
+	/// Storage: AIRegistry ScheduledDeactivations (r:1 w:1)
+	/// Proof: AIRegistry ScheduledDeactivations (max_values: None, max_size: Some(144), added: 2619, mode: MaxEncodedLen)
+	fn reactivate_model() -> Weight {
+		Weight::from_parts(40_000_000, 31840)
+			.saturating_add(T::DbWeight::get().reads(9))
+			.saturating_add(T::DbWeight::get().writes(8))
+	}
+
+	/// Storage: AIRegistry Models (r:1 w:1)
+	/// Proof: AIRegistry Models (max_values: None, max_size: Some(1712), added: 4187, mode: MaxEncodedLen)
+	/// Storage: AIRegistry Watchers (r:1 w:0)
+	/// Proof: AIRegistry Watchers (max_values: None, max_size: Some(8218), added: 10693, mode: MaxEncodedLen)
+	/// Storage: AIRegistry GlobalStats (r:1 w:1)
+	/// Proof: AIRegistry GlobalStats (max_values: Some(1), max_size: Some(40), added: 535, mode: MaxEncodedLen)
+	fn approve_registration() -> Weight {
+		Weight::from_parts(22_000_000, 15415)
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+
+	/// Storage: AIRegistry Models (r:1 w:1)
+	/// Proof: AIRegistry Models (max_values: None, max_size: Some(1712), added: 4187, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: AIRegistry Watchers (r:1 w:0)
+	/// Proof: AIRegistry Watchers (max_values: None, max_size: Some(8218), added: 10693, mode: MaxEncodedLen)
+	/// Storage: AIRegistry PendingDeactivations (r:1 w:1)
+	/// Proof: AIRegistry PendingDeactivations (max_values: None, max_size: Some(28), added: 2503, mode: MaxEncodedLen)
+	/// Storage: AIRegistry ScheduledDeactivations (r:1 w:1)
+	/// Proof: AIRegistry ScheduledDeactivations (max_values: None, max_size: Some(144), added: 2619, mode: MaxEncodedLen)
+	fn reject_registration() -> Weight {
+		Weight::from_parts(30_000_000, 22605)
+			.saturating_add(T::DbWeight::get().reads(5))
+			.saturating_add(T::DbWeight::get().writes(4))
+	}
+
+	/// Storage: AIRegistry AllowlistRequired (r:0 w:1)
+	/// Proof: AIRegistry AllowlistRequired (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	fn set_allowlist_required() -> Weight {
+		Weight::from_parts(8_000_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+
+	/// Storage: AIRegistry DeveloperProfiles (r:0 w:1)
+	/// Proof: AIRegistry DeveloperProfiles (max_values: None, max_size: Some(568), added: 3043, mode: MaxEncodedLen)
+	fn set_developer_profile() -> Weight {
+		Weight::from_parts(16_000_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+
+	/// Storage: AIRegistry DeveloperProfiles (r:1 w:1)
+	/// Proof: AIRegistry DeveloperProfiles (max_values: None, max_size: Some(568), added: 3043, mode: MaxEncodedLen)
+	fn clear_developer_profile() -> Weight {
+		Weight::from_parts(14_000_000, 3043)
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+
+	/// Storage: AIRegistry NextOrganizationId (r:1 w:1)
+	/// Proof: AIRegistry NextOrganizationId (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: AIRegistry Organizations (r:0 w:1)
+	/// Proof: AIRegistry Organizations (max_values: None, max_size: Some(322), added: 2797, mode: MaxEncodedLen)
+	/// Storage: AIRegistry OrganizationMembers (r:0 w:1)
+	/// Proof: AIRegistry OrganizationMembers (max_values: None, max_size: Some(73), added: 2548, mode: MaxEncodedLen)
+	fn create_organization() -> Weight {
+		Weight::from_parts(18_000_000, 503)
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(3))
+	}
+
+	/// Storage: AIRegistry Organizations (r:1 w:1)
+	/// Proof: AIRegistry Organizations (max_values: None, max_size: Some(322), added: 2797, mode: MaxEncodedLen)
+	/// Storage: AIRegistry OrganizationMembers (r:2 w:1)
+	/// Proof: AIRegistry OrganizationMembers (max_values: None, max_size: Some(73), added: 2548, mode: MaxEncodedLen)
+	fn set_organization_role() -> Weight {
+		Weight::from_parts(20_000_000, 7893)
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+
+	/// Storage: AIRegistry Organizations (r:1 w:0)
+	/// Proof: AIRegistry Organizations (max_values: None, max_size: Some(322), added: 2797, mode: MaxEncodedLen)
+	/// Storage: AIRegistry OrganizationMembers (r:1 w:0)
+	/// Proof: AIRegistry OrganizationMembers (max_values: None, max_size: Some(73), added: 2548, mode: MaxEncodedLen)
+	fn as_organization() -> Weight {
+		Weight::from_parts(12_000_000, 5345)
+			.saturating_add(T::DbWeight::get().reads(2))
+	}
+
+	/// Storage: AIRegistry Models (r:1 w:0)
+	/// Proof: AIRegistry Models (max_values: None, max_size: Some(1712), added: 4187, mode: MaxEncodedLen)
+	/// Storage: AIRegistry Shareholders (r:1 w:0)
+	/// Proof: AIRegistry Shareholders (max_values: None, max_size: Some(385), added: 2860, mode: MaxEncodedLen)
+	/// Storage: AIRegistry PendingPriceChanges (r:1 w:1)
+	/// Proof: AIRegistry PendingPriceChanges (max_values: None, max_size: Some(44), added: 2519, mode: MaxEncodedLen)
+	/// Storage: AIRegistry ScheduledPriceChanges (r:2 w:2)
+	/// Proof: AIRegistry ScheduledPriceChanges (max_values: None, max_size: Some(144), added: 2619, mode: MaxEncodedLen)
+	fn schedule_price_change() -> Weight {
+		Weight::from_parts(22_000_000, 14804)
+			.saturating_add(T::DbWeight::get().reads(5))
+			.saturating_add(T::DbWeight::get().writes(3))
+	}
+
+	/// Storage: AIRegistry Models (r:1 w:0)
+	/// Proof: AIRegistry Models (max_values: None, max_size: Some(1712), added: 4187, mode: MaxEncodedLen)
+	/// Storage: AIRegistry PendingPriceChanges (r:1 w:1)
+	/// Proof: AIRegistry PendingPriceChanges (max_values: None, max_size: Some(44), added: 2519, mode: MaxEncodedLen)
+	/// Storage: AIRegistry ScheduledPriceChanges (r:1 w:1)
+	/// Proof: AIRegistry ScheduledPriceChanges (max_values: None, max_size: Some(144), added: 2619, mode: MaxEncodedLen)
+	fn cancel_price_change() -> Weight {
+		Weight::from_parts(17_000_000, 9325)
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+
+	/// Storage: AIRegistry ScheduledPriceChanges (r:1 w:1)
+	/// Proof: AIRegistry ScheduledPriceChanges (max_values: None, max_size: Some(144), added: 2619, mode: MaxEncodedLen)
+	/// Storage: AIRegistry PendingPriceChanges (r:16 w:16)
+	/// Proof: AIRegistry PendingPriceChanges (max_values: None, max_size: Some(44), added: 2519, mode: MaxEncodedLen)
+	/// Storage: AIRegistry Shareholders (r:16 w:0)
+	/// Proof: AIRegistry Shareholders (max_values: None, max_size: Some(385), added: 2860, mode: MaxEncodedLen)
+	/// Storage: AIRegistry Models (r:16 w:16)
+	/// Proof: AIRegistry Models (max_values: None, max_size: Some(1712), added: 4187, mode: MaxEncodedLen)
+	/// Storage: AIRegistry Watchers (r:16 w:0)
+	/// Proof: AIRegistry Watchers (max_values: None, max_size: Some(8218), added: 10693, mode: MaxEncodedLen)
+	/// Storage: AIRegistry UpdateHistory (r:16 w:16)
+	/// Proof: AIRegistry UpdateHistory (max_values: None, max_size: Some(1337), added: 3812, mode: MaxEncodedLen)
+	/// The range of component `n` is `[0, 16]`.
+	fn enact_price_changes(n: u32, ) -> Weight {
+		Weight::from_parts(5_000_000, 2619)
+			// Standard Error: 200_000
+			.saturating_add(Weight::from_parts(22_000_000, 0).saturating_mul(n.into()))
 			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().reads((5_u64).saturating_mul(n.into())))
 			.saturating_add(T::DbWeight::get().writes(1))
+			.saturating_add(T::DbWeight::get().writes((3_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 24071).saturating_mul(n.into()))
+	}
+
+	/// Storage: AIRegistry Models (r:2 w:0)
+	/// Proof: AIRegistry Models (max_values: None, max_size: Some(1712), added: 4187, mode: MaxEncodedLen)
+	/// Storage: AIRegistry PendingDeactivations (r:1 w:1)
+	/// Proof: AIRegistry PendingDeactivations (max_values: None, max_size: Some(28), added: 2503, mode: MaxEncodedLen)
+	/// Storage: AIRegistry ScheduledDeactivations (r:2 w:2)
+	/// Proof: AIRegistry ScheduledDeactivations (max_values: None, max_size: Some(144), added: 2619, mode: MaxEncodedLen)
+	/// Storage: AIRegistry Successors (r:0 w:1)
+	/// Proof: AIRegistry Successors (max_values: None, max_size: Some(32), added: 2507, mode: MaxEncodedLen)
+	fn deactivate_at() -> Weight {
+		Weight::from_parts(24_000_000, 16115)
+			.saturating_add(T::DbWeight::get().reads(5))
+			.saturating_add(T::DbWeight::get().writes(4))
+	}
+
+	/// Storage: AIRegistry ScheduledDeactivations (r:1 w:0)
+	/// Proof: AIRegistry ScheduledDeactivations (max_values: None, max_size: Some(144), added: 2619, mode: MaxEncodedLen)
+	/// Storage: AIRegistry Successors (r:16 w:0)
+	/// Proof: AIRegistry Successors (max_values: None, max_size: Some(32), added: 2507, mode: MaxEncodedLen)
+	/// The range of component `n` is `[0, 16]`.
+	fn announce_deactivations(n: u32, ) -> Weight {
+		Weight::from_parts(3_000_000, 2619)
+			// Standard Error: 50_000
+			.saturating_add(Weight::from_parts(3_000_000, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 2507).saturating_mul(n.into()))
 	}
 }
 
 // For backwards compatibility and tests
 impl WeightInfo for () {
-	fn register_model() -> Weight {
-		Weight::from_parts(50_000_000, 0)
-			.saturating_add(RocksDbWeight::get().reads(1))
-			.saturating_add(RocksDbWeight::get().writes(3))
+	fn register_model(d: u32, ) -> Weight {
+		Weight::from_parts(50_000_000, 28535)
+			.saturating_add(Weight::from_parts(3_000_000, 0).saturating_mul(d.into()))
+			.saturating_add(RocksDbWeight::get().reads(12))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(d.into())))
+			.saturating_add(RocksDbWeight::get().writes(15))
+			.saturating_add(Weight::from_parts(0, 3058).saturating_mul(d.into()))
 	}
 	
 	fn update_model_metadata() -> Weight {
-		Weight::from_parts(30_000_000, 0)
-			.saturating_add(RocksDbWeight::get().reads(1))
-			.saturating_add(RocksDbWeight::get().writes(1))
+		Weight::from_parts(36_000_000, 24590)
+			.saturating_add(RocksDbWeight::get().reads(6))
+			.saturating_add(RocksDbWeight::get().writes(4))
+	}
+	
+	fn update_model_price() -> Weight {
+		Weight::from_parts(24_000_000, 21552)
+			.saturating_add(RocksDbWeight::get().reads(4))
+			.saturating_add(RocksDbWeight::get().writes(2))
 	}
 	
 	fn deactivate_model() -> Weight {
-		Weight::from_parts(25_000_000, 0)
-			.saturating_add(RocksDbWeight::get().reads(1))
-			.saturating_add(RocksDbWeight::get().writes(1))
+		Weight::from_parts(25_000_000, 23156)
+			.saturating_add(RocksDbWeight::get().reads(6))
+			.saturating_add(RocksDbWeight::get().writes(5))
 	}
 	
 	fn rate_model() -> Weight {
-		Weight::from_parts(28_000_000, 0)
+		Weight::from_parts(32_000_000, 26358)
+			.saturating_add(RocksDbWeight::get().reads(10))
+			.saturating_add(RocksDbWeight::get().writes(8))
+	}
+
+	fn propose_size_correction() -> Weight {
+		Weight::from_parts(20_000_000, 4187)
+			.saturating_add(RocksDbWeight::get().reads(1))
+			.saturating_add(RocksDbWeight::get().writes(1))
+	}
+
+	fn approve_size_correction() -> Weight {
+		Weight::from_parts(35_000_000, 9297)
+			.saturating_add(RocksDbWeight::get().reads(3))
+			.saturating_add(RocksDbWeight::get().writes(3))
+	}
+
+	fn extend_admin_sunset() -> Weight {
+		Weight::from_parts(15_000_000, 499)
+			.saturating_add(RocksDbWeight::get().reads(1))
+			.saturating_add(RocksDbWeight::get().writes(1))
+	}
+
+	fn add_model_callback() -> Weight {
+		Weight::from_parts(22_000_000, 7767)
+			.saturating_add(RocksDbWeight::get().reads(2))
+			.saturating_add(RocksDbWeight::get().writes(1))
+	}
+
+	fn remove_model_callback() -> Weight {
+		Weight::from_parts(20_000_000, 7767)
+			.saturating_add(RocksDbWeight::get().reads(2))
+			.saturating_add(RocksDbWeight::get().writes(1))
+	}
+
+	fn export_models(n: u32, ) -> Weight {
+		Weight::from_parts(12_000_000, 2519)
+			.saturating_add(Weight::from_parts(9_000_000, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(1))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 4187).saturating_mul(n.into()))
+	}
+	fn import_model() -> Weight {
+		Weight::from_parts(95_000_000, 33593)
+			.saturating_add(RocksDbWeight::get().reads(14))
+			.saturating_add(RocksDbWeight::get().writes(17))
+	}
+
+	fn collect_rent() -> Weight {
+		Weight::from_parts(35_000_000, 25511)
+			.saturating_add(RocksDbWeight::get().reads(7))
+			.saturating_add(RocksDbWeight::get().writes(5))
+	}
+
+	fn top_up_deposit() -> Weight {
+		Weight::from_parts(30_000_000, 20505)
+			.saturating_add(RocksDbWeight::get().reads(5))
+			.saturating_add(RocksDbWeight::get().writes(4))
+	}
+
+	fn reap_model(c: u32, ) -> Weight {
+		Weight::from_parts(44_000_000, 54552)
+			.saturating_add(Weight::from_parts(3_000_000, 0).saturating_mul(c.into()))
+			.saturating_add(RocksDbWeight::get().reads(21))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(c.into())))
+			.saturating_add(RocksDbWeight::get().writes(50))
+			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(c.into())))
+			.saturating_add(Weight::from_parts(0, 3303).saturating_mul(c.into()))
+	}
+
+	fn slash_model(r: u32, ) -> Weight {
+		Weight::from_parts(42_000_000, 23021)
+			.saturating_add(Weight::from_parts(1_500_000, 0).saturating_mul(r.into()))
+			.saturating_add(RocksDbWeight::get().reads(6))
+			.saturating_add(RocksDbWeight::get().writes(7))
+			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(r.into())))
+	}
+
+	fn transfer_model() -> Weight {
+		Weight::from_parts(55_000_000, 29564)
+			.saturating_add(RocksDbWeight::get().reads(9))
+			.saturating_add(RocksDbWeight::get().writes(9))
+	}
+
+	fn sync_model_owner() -> Weight {
+		Weight::from_parts(40_000_000, 27005)
+			.saturating_add(RocksDbWeight::get().reads(8))
+			.saturating_add(RocksDbWeight::get().writes(7))
+	}
+
+	fn fractionalize_model(s: u32, ) -> Weight {
+		Weight::from_parts(22_000_000, 10387)
+			.saturating_add(Weight::from_parts(400_000, 0).saturating_mul(s.into()))
+			.saturating_add(RocksDbWeight::get().reads(3))
+			.saturating_add(RocksDbWeight::get().writes(1))
+	}
+
+	fn transfer_shares() -> Weight {
+		Weight::from_parts(24_000_000, 2860)
+			.saturating_add(RocksDbWeight::get().reads(1))
+			.saturating_add(RocksDbWeight::get().writes(2))
+	}
+
+	fn propose_metadata_update() -> Weight {
+		Weight::from_parts(40_000_000, 28504)
+			.saturating_add(RocksDbWeight::get().reads(7))
+			.saturating_add(RocksDbWeight::get().writes(5))
+	}
+
+	fn approve_metadata_update() -> Weight {
+		Weight::from_parts(42_000_000, 28504)
+			.saturating_add(RocksDbWeight::get().reads(7))
+			.saturating_add(RocksDbWeight::get().writes(5))
+	}
+
+	fn cancel_metadata_update() -> Weight {
+		Weight::from_parts(18_000_000, 3914)
+			.saturating_add(RocksDbWeight::get().reads(1))
+			.saturating_add(RocksDbWeight::get().writes(1))
+	}
+
+	fn register_dataset() -> Weight {
+		Weight::from_parts(30_000_000, 5709)
+			.saturating_add(RocksDbWeight::get().reads(3))
+			.saturating_add(RocksDbWeight::get().writes(4))
+	}
+
+	fn fork_model() -> Weight {
+		Weight::from_parts(55_000_000, 32722)
+			.saturating_add(RocksDbWeight::get().reads(13))
+			.saturating_add(RocksDbWeight::get().writes(16))
+	}
+
+	fn create_collection() -> Weight {
+		Weight::from_parts(28_000_000, 5709)
+			.saturating_add(RocksDbWeight::get().reads(3))
+			.saturating_add(RocksDbWeight::get().writes(4))
+	}
+
+	fn add_to_collection() -> Weight {
+		Weight::from_parts(27_000_000, 13458)
+			.saturating_add(RocksDbWeight::get().reads(4))
+			.saturating_add(RocksDbWeight::get().writes(2))
+	}
+
+	fn remove_from_collection() -> Weight {
+		Weight::from_parts(27_000_000, 10118)
+			.saturating_add(RocksDbWeight::get().reads(3))
+			.saturating_add(RocksDbWeight::get().writes(2))
+	}
+
+	fn deprecate_model() -> Weight {
+		Weight::from_parts(32_000_000, 25802)
+			.saturating_add(RocksDbWeight::get().reads(6))
+			.saturating_add(RocksDbWeight::get().writes(3))
+	}
+
+	fn bookmark_model() -> Weight {
+		Weight::from_parts(20_000_000, 7736)
+			.saturating_add(RocksDbWeight::get().reads(2))
+			.saturating_add(RocksDbWeight::get().writes(1))
+	}
+
+	fn unbookmark_model() -> Weight {
+		Weight::from_parts(18_000_000, 3549)
+			.saturating_add(RocksDbWeight::get().reads(1))
+			.saturating_add(RocksDbWeight::get().writes(1))
+	}
+
+	fn watch_model() -> Weight {
+		Weight::from_parts(20_000_000, 14880)
+			.saturating_add(RocksDbWeight::get().reads(2))
+			.saturating_add(RocksDbWeight::get().writes(1))
+	}
+
+	fn unwatch_model() -> Weight {
+		Weight::from_parts(18_000_000, 10693)
+			.saturating_add(RocksDbWeight::get().reads(1))
+			.saturating_add(RocksDbWeight::get().writes(1))
+	}
+
+	fn claim_fee_rebate() -> Weight {
+		Weight::from_parts(43_000_000, 16914)
+			.saturating_add(RocksDbWeight::get().reads(6))
+			.saturating_add(RocksDbWeight::get().writes(4))
+	}
+
+	fn fund_incentives() -> Weight {
+		Weight::from_parts(38_000_000, 5717)
+			.saturating_add(RocksDbWeight::get().reads(3))
+			.saturating_add(RocksDbWeight::get().writes(4))
+	}
+
+	fn claim_incentive() -> Weight {
+		Weight::from_parts(45_000_000, 16934)
+			.saturating_add(RocksDbWeight::get().reads(6))
+			.saturating_add(RocksDbWeight::get().writes(5))
+	}
+
+	fn credit_era_rewards(n: u32, ) -> Weight {
+		Weight::from_parts(8_000_000, 1697)
+			// Standard Error: 400_000
+			.saturating_add(Weight::from_parts(9_000_000, 0).saturating_mul(n.into()))
 			.saturating_add(RocksDbWeight::get().reads(1))
+			.saturating_add(RocksDbWeight::get().reads((2_u64).saturating_mul(n.into())))
+			.saturating_add(RocksDbWeight::get().writes(1))
+			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 6702).saturating_mul(n.into()))
+	}
+
+	fn claim_reward() -> Weight {
+		Weight::from_parts(32_000_000, 9305)
+			.saturating_add(RocksDbWeight::get().reads(3))
+			.saturating_add(RocksDbWeight::get().writes(2))
+	}
+
+	fn rename_model() -> Weight {
+		Weight::from_parts(24_000_000, 21828)
+			.saturating_add(RocksDbWeight::get().reads(7))
+			.saturating_add(RocksDbWeight::get().writes(6))
+	}
+
+	fn lock_metadata() -> Weight {
+		Weight::from_parts(22_000_000, 12890)
+			.saturating_add(RocksDbWeight::get().reads(4))
 			.saturating_add(RocksDbWeight::get().writes(1))
 	}
+
+	fn report_model() -> Weight {
+		Weight::from_parts(34_000_000, 33221)
+			.saturating_add(RocksDbWeight::get().reads(10))
+			.saturating_add(RocksDbWeight::get().writes(7))
+	}
+
+	fn dismiss_reports(r: u32, ) -> Weight {
+		Weight::from_parts(30_000_000, 22921)
+			.saturating_add(Weight::from_parts(1_500_000, 0).saturating_mul(r.into()))
+			.saturating_add(RocksDbWeight::get().reads(6))
+			.saturating_add(RocksDbWeight::get().writes(4))
+			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(r.into())))
+	}
+
+	fn appeal_deactivation() -> Weight {
+		Weight::from_parts(38_000_000, 15315)
+			.saturating_add(RocksDbWeight::get().reads(5))
+			.saturating_add(RocksDbWeight::get().writes(2))
+	}
+
+	fn resolve_appeal() -> Weight {
+		Weight::from_parts(45_000_000, 25806)
+			.saturating_add(RocksDbWeight::get().reads(7))
+			.saturating_add(RocksDbWeight::get().writes(7))
+	}
+
+	fn enact_deactivations(n: u32, ) -> Weight {
+		Weight::from_parts(5_000_000, 2619)
+			.saturating_add(Weight::from_parts(25_000_000, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(1))
+			.saturating_add(RocksDbWeight::get().reads((4_u64).saturating_mul(n.into())))
+			.saturating_add(RocksDbWeight::get().writes(1))
+			.saturating_add(RocksDbWeight::get().writes((4_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 18018).saturating_mul(n.into()))
+	}
+
+	fn reactivate_model() -> Weight {
+		Weight::from_parts(40_000_000, 31840)
+			.saturating_add(RocksDbWeight::get().reads(9))
+			.saturating_add(RocksDbWeight::get().writes(8))
+	}
+
+	fn approve_registration() -> Weight {
+		Weight::from_parts(22_000_000, 15415)
+			.saturating_add(RocksDbWeight::get().reads(3))
+			.saturating_add(RocksDbWeight::get().writes(2))
+	}
+
+	fn reject_registration() -> Weight {
+		Weight::from_parts(30_000_000, 22605)
+			.saturating_add(RocksDbWeight::get().reads(5))
+			.saturating_add(RocksDbWeight::get().writes(4))
+	}
+
+	fn set_allowlist_required() -> Weight {
+		Weight::from_parts(8_000_000, 0)
+			.saturating_add(RocksDbWeight::get().writes(1))
+	}
+
+	fn set_developer_profile() -> Weight {
+		Weight::from_parts(16_000_000, 0)
+			.saturating_add(RocksDbWeight::get().writes(1))
+	}
+
+	fn clear_developer_profile() -> Weight {
+		Weight::from_parts(14_000_000, 3043)
+			.saturating_add(RocksDbWeight::get().reads(1))
+			.saturating_add(RocksDbWeight::get().writes(1))
+	}
+
+	fn create_organization() -> Weight {
+		Weight::from_parts(18_000_000, 503)
+			.saturating_add(RocksDbWeight::get().reads(1))
+			.saturating_add(RocksDbWeight::get().writes(3))
+	}
+
+	fn set_organization_role() -> Weight {
+		Weight::from_parts(20_000_000, 7893)
+			.saturating_add(RocksDbWeight::get().reads(3))
+			.saturating_add(RocksDbWeight::get().writes(2))
+	}
+
+	fn as_organization() -> Weight {
+		Weight::from_parts(12_000_000, 5345)
+			.saturating_add(RocksDbWeight::get().reads(2))
+	}
+
+	fn schedule_price_change() -> Weight {
+		Weight::from_parts(22_000_000, 14804)
+			.saturating_add(RocksDbWeight::get().reads(5))
+			.saturating_add(RocksDbWeight::get().writes(3))
+	}
+
+	fn cancel_price_change() -> Weight {
+		Weight::from_parts(17_000_000, 9325)
+			.saturating_add(RocksDbWeight::get().reads(3))
+			.saturating_add(RocksDbWeight::get().writes(2))
+	}
+
+	fn enact_price_changes(n: u32, ) -> Weight {
+		Weight::from_parts(5_000_000, 2619)
+			.saturating_add(Weight::from_parts(22_000_000, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(1))
+			.saturating_add(RocksDbWeight::get().reads((5_u64).saturating_mul(n.into())))
+			.saturating_add(RocksDbWeight::get().writes(1))
+			.saturating_add(RocksDbWeight::get().writes((3_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 24071).saturating_mul(n.into()))
+	}
+
+	fn deactivate_at() -> Weight {
+		Weight::from_parts(24_000_000, 16115)
+			.saturating_add(RocksDbWeight::get().reads(5))
+			.saturating_add(RocksDbWeight::get().writes(4))
+	}
+
+	fn announce_deactivations(n: u32, ) -> Weight {
+		Weight::from_parts(3_000_000, 2619)
+			.saturating_add(Weight::from_parts(3_000_000, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(1))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 2507).saturating_mul(n.into()))
+	}
 }
@@ -0,0 +1,129 @@
+//! Benchmarking setup for pallet-availability
+
+#![cfg(feature = "runtime-benchmarks")]
+
+use super::*;
+use codec::Encode;
+use frame_benchmarking::v2::*;
+use frame_support::{
+	traits::{EnsureOrigin, Get},
+	BoundedVec,
+};
+use frame_system::{offchain::AppCrypto, RawOrigin};
+use sp_runtime::{traits::IdentifyAccount, RuntimeAppPublic};
+use sp_std::vec::Vec;
+
+/// Fill the reporter set up to `MaxReporters - 1` accounts
+fn fill_reporters<T: Config>() {
+	let reporters: Vec<T::AccountId> =
+		(0..T::MaxReporters::get().saturating_sub(1)).map(|i| account("reporter", i, 0)).collect();
+	Reporters::<T>::put(BoundedVec::truncate_from(reporters));
+}
+
+#[benchmarks]
+mod benchmarks {
+	use super::*;
+
+	#[benchmark]
+	fn report_availability(n: Linear<1, { T::SampleSize::get() }>) {
+		let owner: T::AccountId = account("owner", 0, 0);
+		let caller: T::AccountId = whitelisted_caller();
+		Reporters::<T>::put(BoundedVec::truncate_from(sp_std::vec![caller.clone()]));
+
+		// Every model fails its last allowed check and is paused
+		let checks: Vec<CidCheck> = (0..n)
+			.map(|_| {
+				let model_id = T::Models::create_model(&owner);
+				FailedChecks::<T>::insert(
+					model_id,
+					T::UnavailableThreshold::get().saturating_sub(1),
+				);
+				CidCheck { model_id, available: false }
+			})
+			.collect();
+		let checked_at = T::CheckInterval::get();
+		frame_system::Pallet::<T>::set_block_number(checked_at);
+
+		#[extrinsic_call]
+		report_availability(
+			RawOrigin::Signed(caller.clone()),
+			checked_at,
+			BoundedVec::truncate_from(checks),
+		);
+
+		assert_eq!(LastReport::<T>::get(&caller), Some(checked_at));
+	}
+
+	#[benchmark]
+	fn report_availability_unsigned(
+		n: Linear<1, { T::SampleSize::get() }>,
+	) -> Result<(), BenchmarkError> {
+		let owner: T::AccountId = account("owner", 0, 0);
+		let key =
+			<T::AuthorityId as AppCrypto<T::Public, T::Signature>>::RuntimeAppPublic::generate_pair(
+				None,
+			);
+		let public: T::Public =
+			<T::AuthorityId as AppCrypto<T::Public, T::Signature>>::GenericPublic::from(key).into();
+		let reporter = public.clone().into_account();
+		Reporters::<T>::put(BoundedVec::truncate_from(sp_std::vec![reporter.clone()]));
+
+		let checks: Vec<CidCheck> = (0..n)
+			.map(|_| {
+				let model_id = T::Models::create_model(&owner);
+				FailedChecks::<T>::insert(
+					model_id,
+					T::UnavailableThreshold::get().saturating_sub(1),
+				);
+				CidCheck { model_id, available: false }
+			})
+			.collect();
+		let checked_at = T::CheckInterval::get();
+		frame_system::Pallet::<T>::set_block_number(checked_at);
+		let payload = AvailabilityPayload {
+			public: public.clone(),
+			checked_at,
+			checks: BoundedVec::truncate_from(checks),
+		};
+		let signature =
+			<T::AuthorityId as AppCrypto<T::Public, T::Signature>>::sign(&payload.encode(), public)
+				.ok_or(BenchmarkError::Weightless)?;
+
+		#[extrinsic_call]
+		_(RawOrigin::None, payload, signature);
+
+		assert_eq!(LastReport::<T>::get(&reporter), Some(checked_at));
+		Ok(())
+	}
+
+	#[benchmark]
+	fn add_reporter() -> Result<(), BenchmarkError> {
+		fill_reporters::<T>();
+		let origin =
+			T::ManagerOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		let reporter: T::AccountId = whitelisted_caller();
+
+		#[extrinsic_call]
+		_(origin as T::RuntimeOrigin, reporter.clone());
+
+		assert!(Reporters::<T>::get().contains(&reporter));
+		Ok(())
+	}
+
+	#[benchmark]
+	fn remove_reporter() -> Result<(), BenchmarkError> {
+		fill_reporters::<T>();
+		let reporter: T::AccountId = account("reporter", 0, 0);
+		LastReport::<T>::insert(&reporter, T::CheckInterval::get());
+		let origin =
+			T::ManagerOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+
+		#[extrinsic_call]
+		_(origin as T::RuntimeOrigin, reporter.clone());
+
+		assert!(!Reporters::<T>::get().contains(&reporter));
+		Ok(())
+	}
+
+	impl_benchmark_test_suite!(Pallet, crate::mock::new_test_ext(), crate::mock::Test);
+}
@@ -0,0 +1,737 @@
+//! # Availability Pallet
+//!
+//! Off-chain monitoring of the IPFS artifacts behind registered models.
+//!
+//! ## Overview
+//!
+//! Every `CheckInterval` blocks, nodes holding an availability reporter key run an
+//! off-chain worker that fetches a rotating sample of `SampleSize` active model
+//! CIDs from an IPFS gateway and submits the results in an
+//! `report_availability_unsigned` transaction, so reporters pay no fees. The
+//! payload is signed with the reporter's key, and only keys whose accounts were
+//! added with `add_reporter` are accepted, each at most once per check. At most
+//! `MaxUnsignedPerBlock` unsigned reports are included per block. Reporters can
+//! also submit the same report as a regular signed `report_availability`.
+//!
+//! A model whose CID fails `UnavailableThreshold` checks in a row, with no
+//! successful check in between, is paused in the registry. Its owner can
+//! reactivate it once the artifact is reachable again.
+//!
+//! ## Gateway
+//!
+//! The worker uses [`DEFAULT_GATEWAY`] unless the node operator stores another
+//! gateway URL (raw bytes, no trailing slash) under [`GATEWAY_STORAGE_KEY`] in
+//! persistent off-chain local storage. If every CID in a sample fails, the worker
+//! assumes the gateway itself is down and does not report.
+//!
+//! ## Pinning
+//!
+//! Validator operators can offer built-in persistence for registered artifacts.
+//! Once an IPFS Pinning Service API endpoint and access token are stored under
+//! [`PINNING_ENDPOINT_STORAGE_KEY`] and [`PINNING_TOKEN_STORAGE_KEY`], the worker
+//! asks the service to pin the CID of every model registered from then on. A
+//! cursor in local storage tracks the next model to pin, so models registered
+//! while the worker did not run are caught up later, and failed requests are
+//! retried.
+//!
+//! ## Keys
+//!
+//! Report payloads are signed with a [`KEY_TYPE`] key from the node's keystore,
+//! inserted e.g. through the `author_insertKey` RPC. The key's account must be a
+//! reporter.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+
+pub use pallet::*;
+
+#[cfg(test)]
+mod mock;
+
+#[cfg(test)]
+mod tests;
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+
+pub mod weights;
+pub use weights::*;
+
+pub mod types;
+pub use types::*;
+
+pub mod traits;
+pub use traits::*;
+
+use sp_core::crypto::KeyTypeId;
+
+/// Key type of the keys that sign availability reports
+pub const KEY_TYPE: KeyTypeId = KeyTypeId(*b"ipfs");
+
+/// Persistent off-chain storage key overriding the gateway URL
+pub const GATEWAY_STORAGE_KEY: &[u8] = b"availability::gateway";
+
+/// Gateway used when the node operator has not configured one
+pub const DEFAULT_GATEWAY: &[u8] = b"https://ipfs.io";
+
+/// Persistent off-chain storage key of the pinning service endpoint
+pub const PINNING_ENDPOINT_STORAGE_KEY: &[u8] = b"availability::pinning-endpoint";
+
+/// Persistent off-chain storage key of the pinning service access token
+pub const PINNING_TOKEN_STORAGE_KEY: &[u8] = b"availability::pinning-token";
+
+/// Persistent off-chain storage key of the next model to pin
+const PIN_CURSOR_STORAGE_KEY: &[u8] = b"availability::pin-cursor";
+
+/// Off-chain lock preventing concurrent workers from pinning the same models
+const PIN_LOCK_KEY: &[u8] = b"availability::pin-lock";
+
+/// Maximum number of pin requests a worker sends per block
+const MAX_PINS_PER_BLOCK: u64 = 10;
+
+/// Time allowed for a single gateway or pinning service request
+const FETCH_TIMEOUT_MS: u64 = 5_000;
+
+const LOG_TARGET: &str = "runtime::availability";
+
+/// Application crypto of availability reporter keys
+pub mod crypto {
+	use super::KEY_TYPE;
+	use sp_runtime::{
+		app_crypto::{app_crypto, sr25519},
+		MultiSignature, MultiSigner,
+	};
+
+	app_crypto!(sr25519, KEY_TYPE);
+
+	/// Signs availability reports for runtimes using `MultiSignature`
+	pub struct AvailabilityAuthId;
+
+	impl frame_system::offchain::AppCrypto<MultiSigner, MultiSignature> for AvailabilityAuthId {
+		type RuntimeAppPublic = Public;
+		type GenericPublic = sp_core::sr25519::Public;
+		type GenericSignature = sp_core::sr25519::Signature;
+	}
+}
+
+#[frame_support::pallet]
+pub mod pallet {
+	use super::*;
+	use frame_support::pallet_prelude::*;
+	use frame_system::{
+		offchain::{
+			AppCrypto, CreateInherent, SendUnsignedTransaction, SignedPayload, Signer, SigningTypes,
+		},
+		pallet_prelude::*,
+	};
+	use pallet_ai_registry::ModelId;
+	use sp_runtime::{
+		offchain::{
+			http,
+			storage::StorageValueRef,
+			storage_lock::{StorageLock, Time},
+			Duration, StorageKind,
+		},
+		traits::{IdentifyAccount, SaturatedConversion, Saturating, Zero},
+		transaction_validity::{
+			InvalidTransaction, TransactionPriority, TransactionSource, TransactionValidity,
+			ValidTransaction,
+		},
+	};
+	use sp_std::vec::Vec;
+
+	#[pallet::pallet]
+	pub struct Pallet<T>(_);
+
+	/// Configuration trait for the Availability pallet
+	#[pallet::config]
+	pub trait Config: CreateInherent<Call<Self>> + SigningTypes + frame_system::Config {
+		/// The overarching event type
+		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+		/// Weight information for extrinsics
+		type WeightInfo: WeightInfo;
+
+		/// Crypto of the keys the off-chain worker signs reports with
+		type AuthorityId: AppCrypto<Self::Public, Self::Signature>;
+
+		/// Models whose artifacts are monitored
+		type Models: MonitoredModels<Self::AccountId>;
+
+		/// Origin allowed to add and remove reporters
+		type ManagerOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+		/// Maximum number of reporters
+		#[pallet::constant]
+		type MaxReporters: Get<u32>;
+
+		/// Blocks between availability checks
+		#[pallet::constant]
+		type CheckInterval: Get<BlockNumberFor<Self>>;
+
+		/// Number of CIDs fetched per check
+		#[pallet::constant]
+		type SampleSize: Get<u32>;
+
+		/// Consecutive failed checks after which a model is paused
+		#[pallet::constant]
+		type UnavailableThreshold: Get<u32>;
+
+		/// Maximum number of unsigned reports included per block
+		#[pallet::constant]
+		type MaxUnsignedPerBlock: Get<u32>;
+
+		/// Priority of unsigned reports in the transaction pool
+		#[pallet::constant]
+		type UnsignedPriority: Get<TransactionPriority>;
+	}
+
+	/// Accounts allowed to submit availability reports
+	#[pallet::storage]
+	pub type Reporters<T: Config> =
+		StorageValue<_, BoundedVec<T::AccountId, T::MaxReporters>, ValueQuery>;
+
+	/// Check block of each reporter's latest report
+	#[pallet::storage]
+	pub type LastReport<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, BlockNumberFor<T>>;
+
+	/// Block and number of unsigned reports included in it
+	#[pallet::storage]
+	pub type UnsignedReports<T: Config> = StorageValue<_, (BlockNumberFor<T>, u32), ValueQuery>;
+
+	/// Failed checks of a model since its last successful one
+	#[pallet::storage]
+	pub type FailedChecks<T: Config> = StorageMap<_, Blake2_128Concat, ModelId, u32, ValueQuery>;
+
+	/// Events emitted by this pallet
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// An account was allowed to submit availability reports
+		/// [reporter]
+		ReporterAdded { reporter: T::AccountId },
+		/// An account may no longer submit availability reports
+		/// [reporter]
+		ReporterRemoved { reporter: T::AccountId },
+		/// A reporter submitted the results of a check
+		/// [reporter, checked_at, unavailable]
+		AvailabilityReported {
+			reporter: T::AccountId,
+			checked_at: BlockNumberFor<T>,
+			unavailable: u32,
+		},
+		/// A model was paused because its CID kept failing checks
+		/// [model_id, failed_checks]
+		ModelUnavailable { model_id: ModelId, failed_checks: u32 },
+	}
+
+	/// Errors that can occur in this pallet
+	#[pallet::error]
+	pub enum Error<T> {
+		/// Caller is not a reporter
+		NotReporter,
+		/// Account is already a reporter
+		AlreadyReporter,
+		/// `MaxReporters` reached
+		TooManyReporters,
+		/// `checked_at` is not a check block or is too old
+		InvalidCheckBlock,
+		/// Reporter already reported this check
+		AlreadyReported,
+		/// A model appears twice in the report
+		DuplicateModel,
+	}
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		fn offchain_worker(now: BlockNumberFor<T>) {
+			if let Err(reason) = Self::pin_new_models() {
+				log::debug!(target: LOG_TARGET, "Pinning stopped at {:?}: {}", now, reason);
+			}
+			if !Self::is_check_block(now) {
+				return;
+			}
+			if let Err(reason) = Self::check_availability(now) {
+				log::debug!(target: LOG_TARGET, "No availability report at {:?}: {}", now, reason);
+			}
+		}
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Submit the results of an availability check
+		///
+		/// Successful checks reset a model's failure count. A model reaching
+		/// `UnavailableThreshold` consecutive failures is paused.
+		///
+		/// # Arguments
+		/// * `origin` - Must be a reporter
+		/// * `checked_at` - Check block the results belong to
+		/// * `checks` - Fetch outcome for each sampled model
+		///
+		/// # Errors
+		/// * `NotReporter` - Caller is not a reporter
+		/// * `InvalidCheckBlock` - `checked_at` is not a recent check block
+		/// * `AlreadyReported` - Caller already reported this check
+		/// * `DuplicateModel` - A model was reported twice
+		///
+		/// # Events
+		/// * `AvailabilityReported` - Report accepted
+		/// * `ModelUnavailable` - For each model paused
+		#[pallet::call_index(0)]
+		#[pallet::weight(T::WeightInfo::report_availability(checks.len() as u32))]
+		pub fn report_availability(
+			origin: OriginFor<T>,
+			checked_at: BlockNumberFor<T>,
+			checks: BoundedVec<CidCheck, T::SampleSize>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			Self::ensure_reportable(&who, checked_at, &checks)?;
+			Self::apply_report(who, checked_at, checks);
+
+			Ok(())
+		}
+
+		/// Submit the results of an availability check without paying fees
+		///
+		/// The payload must be signed with the reporter's key. Validity is checked
+		/// before the transaction enters the pool, and at most `MaxUnsignedPerBlock`
+		/// such reports are included per block.
+		///
+		/// # Arguments
+		/// * `origin` - Must be none
+		/// * `payload` - Reporter key, check block and fetch outcomes
+		/// * `signature` - Signature of `payload` by its key
+		///
+		/// # Errors
+		/// Same as `report_availability`, checked against the payload's key
+		///
+		/// # Events
+		/// * `AvailabilityReported` - Report accepted
+		/// * `ModelUnavailable` - For each model paused
+		#[pallet::call_index(3)]
+		#[pallet::weight(T::WeightInfo::report_availability_unsigned(payload.checks.len() as u32))]
+		pub fn report_availability_unsigned(
+			origin: OriginFor<T>,
+			payload: AvailabilityPayload<T::Public, BlockNumberFor<T>, T::SampleSize>,
+			_signature: T::Signature,
+		) -> DispatchResult {
+			ensure_none(origin)?;
+
+			let who = payload.public.into_account();
+			Self::ensure_reportable(&who, payload.checked_at, &payload.checks)?;
+			UnsignedReports::<T>::mutate(|(block, count)| {
+				let now = frame_system::Pallet::<T>::block_number();
+				if *block != now {
+					*block = now;
+					*count = 0;
+				}
+				count.saturating_inc();
+			});
+			Self::apply_report(who, payload.checked_at, payload.checks);
+
+			Ok(())
+		}
+
+		/// Allow an account to submit availability reports
+		///
+		/// # Arguments
+		/// * `origin` - Must be `ManagerOrigin`
+		/// * `reporter` - Account to allow
+		///
+		/// # Errors
+		/// * `AlreadyReporter` - Account is already a reporter
+		/// * `TooManyReporters` - `MaxReporters` reached
+		///
+		/// # Events
+		/// * `ReporterAdded` - Reporter added
+		#[pallet::call_index(1)]
+		#[pallet::weight(T::WeightInfo::add_reporter())]
+		pub fn add_reporter(origin: OriginFor<T>, reporter: T::AccountId) -> DispatchResult {
+			T::ManagerOrigin::ensure_origin(origin)?;
+
+			Reporters::<T>::try_mutate(|reporters| -> DispatchResult {
+				ensure!(!reporters.contains(&reporter), Error::<T>::AlreadyReporter);
+				reporters.try_push(reporter.clone()).map_err(|_| Error::<T>::TooManyReporters)?;
+				Ok(())
+			})?;
+
+			Self::deposit_event(Event::ReporterAdded { reporter });
+
+			Ok(())
+		}
+
+		/// Stop accepting availability reports from an account
+		///
+		/// # Arguments
+		/// * `origin` - Must be `ManagerOrigin`
+		/// * `reporter` - Account to remove
+		///
+		/// # Errors
+		/// * `NotReporter` - Account is not a reporter
+		///
+		/// # Events
+		/// * `ReporterRemoved` - Reporter removed
+		#[pallet::call_index(2)]
+		#[pallet::weight(T::WeightInfo::remove_reporter())]
+		pub fn remove_reporter(origin: OriginFor<T>, reporter: T::AccountId) -> DispatchResult {
+			T::ManagerOrigin::ensure_origin(origin)?;
+
+			Reporters::<T>::try_mutate(|reporters| -> DispatchResult {
+				let index =
+					reporters.iter().position(|r| *r == reporter).ok_or(Error::<T>::NotReporter)?;
+				reporters.remove(index);
+				Ok(())
+			})?;
+			LastReport::<T>::remove(&reporter);
+
+			Self::deposit_event(Event::ReporterRemoved { reporter });
+
+			Ok(())
+		}
+	}
+
+	#[pallet::validate_unsigned]
+	impl<T: Config> ValidateUnsigned for Pallet<T> {
+		type Call = Call<T>;
+
+		fn validate_unsigned(_source: TransactionSource, call: &Self::Call) -> TransactionValidity {
+			let Call::report_availability_unsigned { payload, signature } = call else {
+				return InvalidTransaction::Call.into();
+			};
+
+			if !SignedPayload::<T>::verify::<T::AuthorityId>(payload, signature.clone()) {
+				return InvalidTransaction::BadProof.into();
+			}
+			let reporter = payload.public.clone().into_account();
+			Self::ensure_reportable(&reporter, payload.checked_at, &payload.checks).map_err(
+				|error| match error {
+					Error::<T>::NotReporter => InvalidTransaction::BadSigner,
+					Error::<T>::InvalidCheckBlock | Error::<T>::AlreadyReported => {
+						InvalidTransaction::Stale
+					}
+					_ => InvalidTransaction::Call,
+				},
+			)?;
+			if Self::unsigned_reports_in_block() >= T::MaxUnsignedPerBlock::get() {
+				return InvalidTransaction::ExhaustsResources.into();
+			}
+
+			ValidTransaction::with_tag_prefix("AvailabilityReport")
+				.priority(T::UnsignedPriority::get())
+				.and_provides((reporter, payload.checked_at))
+				.longevity(T::CheckInterval::get().saturated_into::<u64>().max(1))
+				.propagate(true)
+				.build()
+		}
+	}
+
+	impl<T: Config> Pallet<T> {
+		/// Whether availability is checked at block `n`
+		pub fn is_check_block(n: BlockNumberFor<T>) -> bool {
+			let interval = T::CheckInterval::get();
+			!interval.is_zero() && (n % interval).is_zero()
+		}
+
+		/// Ensure `who` may report the results of check block `checked_at`
+		fn ensure_reportable(
+			who: &T::AccountId,
+			checked_at: BlockNumberFor<T>,
+			checks: &[CidCheck],
+		) -> Result<(), Error<T>> {
+			ensure!(Reporters::<T>::get().contains(who), Error::<T>::NotReporter);
+			let now = frame_system::Pallet::<T>::block_number();
+			ensure!(
+				Self::is_check_block(checked_at)
+					&& checked_at <= now
+					&& now <= checked_at.saturating_add(T::CheckInterval::get()),
+				Error::<T>::InvalidCheckBlock
+			);
+			ensure!(
+				LastReport::<T>::get(who).is_none_or(|last| checked_at > last),
+				Error::<T>::AlreadyReported
+			);
+			ensure!(!has_duplicates(checks), Error::<T>::DuplicateModel);
+			Ok(())
+		}
+
+		/// Record a report by `who` and update the failure counts
+		fn apply_report(
+			who: T::AccountId,
+			checked_at: BlockNumberFor<T>,
+			checks: BoundedVec<CidCheck, T::SampleSize>,
+		) {
+			LastReport::<T>::insert(&who, checked_at);
+			let mut unavailable = 0u32;
+			for check in checks {
+				if check.available {
+					FailedChecks::<T>::remove(check.model_id);
+				} else {
+					unavailable.saturating_inc();
+					Self::note_failure(check.model_id);
+				}
+			}
+
+			Self::deposit_event(Event::AvailabilityReported {
+				reporter: who,
+				checked_at,
+				unavailable,
+			});
+		}
+
+		/// Unsigned reports included in the current block so far
+		fn unsigned_reports_in_block() -> u32 {
+			let (block, count) = UnsignedReports::<T>::get();
+			if block == frame_system::Pallet::<T>::block_number() {
+				count
+			} else {
+				0
+			}
+		}
+
+		/// Active models and their CIDs checked at check block `n`
+		///
+		/// Consecutive checks walk through the model IDs `SampleSize` at a time,
+		/// so every model is eventually checked.
+		pub fn sample(n: BlockNumberFor<T>) -> Vec<(ModelId, Vec<u8>)> {
+			let next_id = T::Models::next_model_id();
+			let size = u64::from(T::SampleSize::get()).min(next_id);
+			if size == 0 {
+				return Vec::new();
+			}
+
+			let round = (n / T::CheckInterval::get()).saturated_into::<u64>();
+			let start = round.wrapping_mul(size) % next_id;
+			(0..size)
+				.map(|offset| (start + offset) % next_id)
+				.filter_map(|model_id| T::Models::active_cid(model_id).map(|cid| (model_id, cid)))
+				.collect()
+		}
+
+		/// Count a failed check of `model_id`, pausing it at the threshold
+		fn note_failure(model_id: ModelId) {
+			let failed_checks = FailedChecks::<T>::mutate(model_id, |failed| {
+				failed.saturating_inc();
+				*failed
+			});
+			if failed_checks < T::UnavailableThreshold::get() {
+				return;
+			}
+
+			FailedChecks::<T>::remove(model_id);
+			if T::Models::pause(model_id).is_ok() {
+				Self::deposit_event(Event::ModelUnavailable { model_id, failed_checks });
+			}
+		}
+
+		/// Fetch the sample for check block `n` and submit the results
+		fn check_availability(n: BlockNumberFor<T>) -> Result<(), &'static str> {
+			let signer = Signer::<T, T::AuthorityId>::any_account();
+			if !signer.can_sign() {
+				return Err("no reporter key in the keystore");
+			}
+
+			let gateway = Self::gateway();
+			let checks: Vec<CidCheck> = Self::sample(n)
+				.into_iter()
+				.map(|(model_id, cid)| CidCheck {
+					model_id,
+					available: Self::fetch(&gateway, &cid),
+				})
+				.collect();
+			if checks.is_empty() {
+				return Err("no active models");
+			}
+			if checks.len() > 1 && checks.iter().all(|check| !check.available) {
+				return Err("every CID failed; the gateway is probably down");
+			}
+			let checks: BoundedVec<_, T::SampleSize> =
+				checks.try_into().map_err(|_| "sample larger than SampleSize")?;
+
+			let (_, result) = signer
+				.send_unsigned_transaction(
+					|account| AvailabilityPayload {
+						public: account.public.clone(),
+						checked_at: n,
+						checks: checks.clone(),
+					},
+					|payload, signature| Call::report_availability_unsigned { payload, signature },
+				)
+				.ok_or("no account to sign with")?;
+			result.map_err(|()| "transaction pool rejected the report")
+		}
+
+		/// Ask the configured pinning service to pin models registered since the
+		/// last run
+		fn pin_new_models() -> Result<(), &'static str> {
+			let (Some(endpoint), Some(token)) = (
+				Self::local_url(PINNING_ENDPOINT_STORAGE_KEY),
+				Self::local(PINNING_TOKEN_STORAGE_KEY),
+			) else {
+				return Ok(());
+			};
+
+			let mut lock = StorageLock::<Time>::new(PIN_LOCK_KEY);
+			let _guard = lock.try_lock().map_err(|_| "another worker is pinning")?;
+
+			let cursor = StorageValueRef::persistent(PIN_CURSOR_STORAGE_KEY);
+			let first = match cursor.get::<ModelId>() {
+				Ok(Some(first)) => first,
+				// Start with the models registered after pinning was configured
+				_ => T::Models::next_model_id(),
+			};
+			let end = T::Models::next_model_id().min(first.saturating_add(MAX_PINS_PER_BLOCK));
+
+			let mut next = first;
+			let result = loop {
+				if next >= end {
+					break Ok(());
+				}
+				if let Some(cid) = T::Models::active_cid(next) {
+					if !Self::pin(&endpoint, &token, next, &cid) {
+						break Err("pinning service rejected the request");
+					}
+				}
+				next += 1;
+			};
+			cursor.set(&next);
+			result
+		}
+
+		/// Request a pin of `cid` through the IPFS Pinning Service API
+		fn pin(endpoint: &[u8], token: &[u8], model_id: ModelId, cid: &[u8]) -> bool {
+			// Registered CIDs are only prefix-checked; never splice anything else into JSON
+			if !cid.iter().all(u8::is_ascii_alphanumeric) {
+				return true;
+			}
+			let url = [endpoint, b"/pins"].concat();
+			let body = alloc::format!(
+				r#"{{"cid":"{}","name":"model-{}"}}"#,
+				core::str::from_utf8(cid).unwrap_or_default(),
+				model_id
+			);
+			let authorization = [&b"Bearer "[..], token].concat();
+			let (Ok(url), Ok(authorization)) =
+				(core::str::from_utf8(&url), core::str::from_utf8(&authorization))
+			else {
+				return false;
+			};
+			let deadline =
+				sp_io::offchain::timestamp().add(Duration::from_millis(FETCH_TIMEOUT_MS));
+
+			http::Request::post(url, sp_std::vec![body.as_bytes()])
+				.add_header("Authorization", authorization)
+				.add_header("Content-Type", "application/json")
+				.deadline(deadline)
+				.send()
+				.ok()
+				.and_then(|pending| pending.try_wait(deadline).ok())
+				.and_then(|result| result.ok())
+				.is_some_and(|response| matches!(response.code, 200 | 202))
+		}
+
+		/// Gateway URL configured by the node operator, or the default
+		fn gateway() -> Vec<u8> {
+			Self::local_url(GATEWAY_STORAGE_KEY).unwrap_or_else(|| DEFAULT_GATEWAY.to_vec())
+		}
+
+		/// Raw value the node operator stored under `key`
+		fn local(key: &[u8]) -> Option<Vec<u8>> {
+			sp_io::offchain::local_storage_get(StorageKind::PERSISTENT, key)
+		}
+
+		/// URL the node operator stored under `key`, without trailing slashes
+		fn local_url(key: &[u8]) -> Option<Vec<u8>> {
+			let mut url = Self::local(key)?;
+			while url.last() == Some(&b'/') {
+				url.pop();
+			}
+			Some(url)
+		}
+
+		/// Whether `gateway` serves `cid`
+		fn fetch(gateway: &[u8], cid: &[u8]) -> bool {
+			let url = [gateway, b"/ipfs/", cid].concat();
+			let Ok(url) = core::str::from_utf8(&url) else {
+				return false;
+			};
+			let deadline =
+				sp_io::offchain::timestamp().add(Duration::from_millis(FETCH_TIMEOUT_MS));
+
+			http::Request::<Vec<&[u8]>>::default()
+				.method(http::Method::Other("HEAD"))
+				.url(url)
+				.deadline(deadline)
+				.send()
+				.ok()
+				.and_then(|pending| pending.try_wait(deadline).ok())
+				.and_then(|result| result.ok())
+				.is_some_and(|response| response.code == 200)
+		}
+	}
+}
+
+/// Whether any model appears twice in `checks`
+fn has_duplicates(checks: &[CidCheck]) -> bool {
+	checks
+		.iter()
+		.enumerate()
+		.any(|(i, check)| checks[..i].iter().any(|other| other.model_id == check.model_id))
+}
+
+/// Monitors models registered in the AI registry pallet
+impl<T: pallet_ai_registry::Config> MonitoredModels<T::AccountId>
+	for pallet_ai_registry::Pallet<T>
+{
+	fn next_model_id() -> pallet_ai_registry::ModelId {
+		pallet_ai_registry::NextModelId::<T>::get()
+	}
+
+	fn active_cid(model_id: pallet_ai_registry::ModelId) -> Option<sp_std::vec::Vec<u8>> {
+		pallet_ai_registry::Models::<T>::get(model_id)
+			.filter(|model| model.status == pallet_ai_registry::ModelStatus::Active)
+			.map(|model| model.ipfs_cid.into_inner())
+	}
+
+	fn pause(model_id: pallet_ai_registry::ModelId) -> frame_support::dispatch::DispatchResult {
+		pallet_ai_registry::Models::<T>::try_mutate(model_id, |maybe_model| {
+			let model =
+				maybe_model.as_mut().ok_or(pallet_ai_registry::Error::<T>::ModelNotFound)?;
+			frame_support::ensure!(
+				model.status == pallet_ai_registry::ModelStatus::Active,
+				pallet_ai_registry::Error::<T>::ModelNotActive
+			);
+			model.status = pallet_ai_registry::ModelStatus::Paused;
+			Ok(())
+		})
+	}
+
+	#[cfg(feature = "runtime-benchmarks")]
+	fn create_model(owner: &T::AccountId) -> pallet_ai_registry::ModelId {
+		use frame_support::traits::{Currency, Get};
+		use sp_runtime::traits::Saturating;
+
+		let balance = Self::model_deposit(1_000)
+			.saturating_add(T::RegistrationFee::get())
+			.saturating_add(T::Currency::minimum_balance())
+			.saturating_mul(2u32.into());
+		T::Currency::make_free_balance_be(owner, balance);
+
+		let model_id = pallet_ai_registry::NextModelId::<T>::get();
+		let _ = Self::register_model(
+			frame_system::RawOrigin::Signed(owner.clone()).into(),
+			Self::benchmark_cid(model_id),
+			Self::benchmark_name(model_id),
+			b"Description".to_vec(),
+			pallet_ai_registry::ModelType::Classification,
+			1_000u32.into(),
+			pallet_ai_registry::License::Mit,
+			1_000,
+			Default::default(),
+		);
+		model_id
+	}
+}
@@ -0,0 +1,223 @@
+//! Mock runtime for Availability pallet tests
+
+use crate as pallet_availability;
+use frame_support::{
+	derive_impl, parameter_types,
+	traits::{
+		ConstBool, ConstU128, ConstU32, ConstU64, ConstU8, Everything, IsInVec, NeverEnsureOrigin,
+	},
+	PalletId,
+};
+use frame_system::{
+	offchain::{AppCrypto, CreateInherent, CreateTransactionBase, SigningTypes},
+	EnsureRoot, EnsureSigned,
+};
+use sp_runtime::{
+	testing::{TestSignature, TestXt, UintAuthorityId},
+	BuildStorage, Perbill,
+};
+
+type Block = frame_system::mocking::MockBlock<Test>;
+
+#[frame_support::runtime]
+mod runtime {
+	#[runtime::runtime]
+	#[runtime::derive(
+		RuntimeCall,
+		RuntimeEvent,
+		RuntimeError,
+		RuntimeOrigin,
+		RuntimeFreezeReason,
+		RuntimeHoldReason,
+		RuntimeSlashReason,
+		RuntimeLockId,
+		RuntimeTask
+	)]
+	pub struct Test;
+
+	#[runtime::pallet_index(0)]
+	pub type System = frame_system::Pallet<Test>;
+
+	#[runtime::pallet_index(1)]
+	pub type Balances = pallet_balances::Pallet<Test>;
+
+	#[runtime::pallet_index(2)]
+	pub type AIRegistry = pallet_ai_registry::Pallet<Test>;
+
+	#[runtime::pallet_index(3)]
+	pub type Availability = pallet_availability::Pallet<Test>;
+}
+
+#[derive_impl(frame_system::config_preludes::TestDefaultConfig)]
+impl frame_system::Config for Test {
+	type Block = Block;
+	type AccountData = pallet_balances::AccountData<u128>;
+}
+
+#[derive_impl(pallet_balances::config_preludes::TestDefaultConfig)]
+impl pallet_balances::Config for Test {
+	type Balance = u128;
+	type ExistentialDeposit = ConstU128<1>;
+	type AccountStore = System;
+}
+
+parameter_types! {
+	pub const SlashFraction: Perbill = Perbill::from_percent(50);
+	pub Developers: Vec<u64> = vec![];
+	pub const ProposalShareThreshold: Perbill = Perbill::from_percent(20);
+	pub const CreatorRoyalty: Perbill = Perbill::from_percent(10);
+	pub const MaxParentShare: Perbill = Perbill::from_percent(50);
+	pub const RegistryPalletId: PalletId = PalletId(*b"py/aireg");
+	pub const RebatePerEra: Perbill = Perbill::from_percent(0);
+	pub const IncentiveSource: u64 = 99;
+}
+
+impl pallet_ai_registry::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type RuntimeCall = RuntimeCall;
+	type WeightInfo = ();
+	type Currency = Balances;
+	type MinimumModelStake = ConstU128<1000>;
+	type RegistrationFee = ConstU128<100>;
+	type MaxCidLength = ConstU32<128>;
+	type MaxNameLength = ConstU32<256>;
+	type MaxDescriptionLength = ConstU32<1024>;
+	type DepositPerByte = ConstU128<1>;
+	type RegistrationOrigin = EnsureSigned<u64>;
+	type GovernanceOrigin = EnsureRoot<u64>;
+	type AdminSunsetBlock = ConstU64<100>;
+	type SunsetExtensionOrigin = EnsureRoot<u64>;
+	type MaxCallbacksPerModel = ConstU32<2>;
+	type InferenceReceipts = ();
+	type RequireReceiptToRate = ConstBool<false>;
+	type RatingWeight = ();
+	type MinRaterAge = ConstU64<0>;
+	type MinRaterBalance = ConstU128<0>;
+	type MaxRatingsPerEra = ConstU32<16>;
+	type RatingWindow = ConstU32<0>;
+	type PauseMinRatings = ConstU32<0>;
+	type PauseRatingFloor = ConstU8<0>;
+	type ReportThreshold = ConstU32<3>;
+	type AppealBond = ConstU128<0>;
+	type MaxDeactivationGrace = ConstU64<0>;
+	type MaxDeactivationsPerBlock = ConstU32<1>;
+	type DeactivationNotice = ConstU64<0>;
+	type MaxPriceChangesPerBlock = ConstU32<1>;
+	type RequireRegistrationApproval = ConstBool<false>;
+	type RegistrationApprovalOrigin = EnsureRoot<u64>;
+	type DeveloperAllowlist = IsInVec<Developers>;
+	type MaxOrganizationMembers = ConstU32<1>;
+	type VerifiedRaters = Everything;
+	type InferenceOrigin = NeverEnsureOrigin<()>;
+	type ModelNfts = ();
+	type OnModelRegistered = ();
+	type OnModelStatusChanged = ();
+	type MaxShareholders = ConstU32<4>;
+	type ProposalShareThreshold = ProposalShareThreshold;
+	type CreatorRoyalty = CreatorRoyalty;
+	type MaxDatasetsPerModel = ConstU32<3>;
+	type MaxParentShare = MaxParentShare;
+	type MaxModelsPerCollection = ConstU32<3>;
+	type MaxCollectionsPerModel = ConstU32<2>;
+	type MaxBookmarks = ConstU32<3>;
+	type MaxModelsPerOwner = ConstU32<16>;
+	type MaxWatchers = ConstU32<3>;
+	type WatchersPerEvent = ConstU32<2>;
+	type OwnerSignature = TestSignature;
+	type OwnerSigner = UintAuthorityId;
+	type MaxExportBatch = ConstU32<3>;
+	type RentEraLength = ConstU64<10>;
+	type RentFreeEras = ConstU32<2>;
+	type RentPerEra = ConstU128<0>;
+	type RentReapDelay = ConstU64<5>;
+	type SlashOrigin = EnsureRoot<u64>;
+	type SlashFraction = SlashFraction;
+	type Slash = ();
+	type PalletId = RegistryPalletId;
+	type RebateRatingThreshold = ConstU8<4>;
+	type RebateMinRatings = ConstU32<2>;
+	type RebatePerEra = RebatePerEra;
+	type IncentiveOrigin = EnsureRoot<u64>;
+	type IncentiveSource = IncentiveSource;
+	type MaxRewardedModels = ConstU32<2>;
+	type RewardMinRatings = ConstU32<2>;
+	type RewardPerEra = ConstU128<0>;
+	type MaxUpdateHistory = ConstU32<3>;
+	type UsageWindow = ConstU32<3>;
+	type LeaderboardSize = ConstU32<3>;
+	type NamePrefixLength = ConstU32<3>;
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkHelper = RegistryBenchmarkHelper;
+}
+
+#[cfg(feature = "runtime-benchmarks")]
+pub struct RegistryBenchmarkHelper;
+#[cfg(feature = "runtime-benchmarks")]
+impl pallet_ai_registry::BenchmarkHelper<TestSignature, u64> for RegistryBenchmarkHelper {
+	fn create_signature(_entropy: &[u8], msg: &[u8]) -> (TestSignature, u64) {
+		(TestSignature(1, msg.to_vec()), 1)
+	}
+}
+
+pub type Extrinsic = TestXt<RuntimeCall, ()>;
+
+impl SigningTypes for Test {
+	type Public = UintAuthorityId;
+	type Signature = TestSignature;
+}
+
+impl<LocalCall> CreateTransactionBase<LocalCall> for Test
+where
+	RuntimeCall: From<LocalCall>,
+{
+	type Extrinsic = Extrinsic;
+	type RuntimeCall = RuntimeCall;
+}
+
+impl<LocalCall> CreateInherent<LocalCall> for Test
+where
+	RuntimeCall: From<LocalCall>,
+{
+	fn create_inherent(call: RuntimeCall) -> Extrinsic {
+		Extrinsic::new_bare(call)
+	}
+}
+
+/// Test keys double as their own application crypto
+pub struct TestAuthId;
+
+impl AppCrypto<UintAuthorityId, TestSignature> for TestAuthId {
+	type RuntimeAppPublic = UintAuthorityId;
+	type GenericPublic = UintAuthorityId;
+	type GenericSignature = TestSignature;
+}
+
+impl pallet_availability::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type WeightInfo = ();
+	type AuthorityId = TestAuthId;
+	type Models = AIRegistry;
+	type ManagerOrigin = EnsureRoot<u64>;
+	type MaxReporters = ConstU32<2>;
+	type CheckInterval = ConstU64<5>;
+	type SampleSize = ConstU32<2>;
+	type UnavailableThreshold = ConstU32<3>;
+	type MaxUnsignedPerBlock = ConstU32<2>;
+	type UnsignedPriority = ConstU64<{ 1 << 20 }>;
+}
+
+// Build genesis storage according to the mock runtime.
+pub fn new_test_ext() -> sp_io::TestExternalities {
+	let mut t = frame_system::GenesisConfig::<Test>::default().build_storage().unwrap();
+
+	pallet_balances::GenesisConfig::<Test> {
+		balances: vec![(1, 10000), (2, 10000), (3, 100)],
+		dev_accounts: None,
+	}
+	.assimilate_storage(&mut t)
+	.unwrap();
+
+	let mut ext = sp_io::TestExternalities::new(t);
+	ext.execute_with(|| System::set_block_number(1));
+	ext
+}
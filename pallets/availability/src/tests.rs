@@ -0,0 +1,337 @@
+//! Unit tests for Availability pallet
+
+use crate::{
+	mock::*,
+	pallet::{Call, Error, Event, FailedChecks, LastReport, Reporters},
+	AvailabilityPayload, CidCheck, GATEWAY_STORAGE_KEY, PINNING_ENDPOINT_STORAGE_KEY,
+	PINNING_TOKEN_STORAGE_KEY,
+};
+use codec::{Decode, Encode};
+use frame_support::{assert_noop, assert_ok, traits::Hooks, BoundedVec};
+use pallet_ai_registry::{License, ModelStatus, ModelType};
+use sp_core::offchain::{
+	testing::{PendingRequest, TestOffchainExt, TestTransactionPoolExt},
+	OffchainDbExt, OffchainWorkerExt, StorageKind, TransactionPoolExt,
+};
+use sp_runtime::{
+	testing::{TestSignature, UintAuthorityId},
+	traits::{ExtrinsicLike, ValidateUnsigned},
+	transaction_validity::{InvalidTransaction, TransactionSource},
+	DispatchError,
+};
+
+/// CIDv0 of the `n`th test model
+fn cid(n: u8) -> Vec<u8> {
+	let mut cid = b"QmYwAPJzv5CZsnA625s3Xf2nemtYgPpHdWEz79ojWnPbd".to_vec();
+	cid.push(b'A' + n);
+	cid
+}
+
+/// Register `count` models owned by account 1, each with the CID of its ID
+fn register_models(count: u8) {
+	for _ in 0..count {
+		let n = pallet_ai_registry::NextModelId::<Test>::get() as u8;
+		assert_ok!(AIRegistry::register_model(
+			RuntimeOrigin::signed(1),
+			cid(n),
+			format!("Model {n}").into_bytes(),
+			b"Description".to_vec(),
+			ModelType::Classification,
+			100,
+			License::Mit,
+			1_000,
+			vec![]
+		));
+	}
+}
+
+/// Report the outcome of checking each of `checks` as reporter 2
+fn report(checked_at: u64, checks: &[(u64, bool)]) -> frame_support::dispatch::DispatchResult {
+	let checks: Vec<CidCheck> =
+		checks.iter().map(|&(model_id, available)| CidCheck { model_id, available }).collect();
+	Availability::report_availability(
+		RuntimeOrigin::signed(2),
+		checked_at,
+		BoundedVec::try_from(checks).unwrap(),
+	)
+}
+
+#[test]
+fn manager_controls_reporters() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			Availability::add_reporter(RuntimeOrigin::signed(1), 2),
+			DispatchError::BadOrigin
+		);
+		assert_ok!(Availability::add_reporter(RuntimeOrigin::root(), 2));
+		System::assert_last_event(Event::ReporterAdded { reporter: 2 }.into());
+		assert_noop!(
+			Availability::add_reporter(RuntimeOrigin::root(), 2),
+			Error::<Test>::AlreadyReporter
+		);
+		assert_ok!(Availability::add_reporter(RuntimeOrigin::root(), 3));
+		assert_noop!(
+			Availability::add_reporter(RuntimeOrigin::root(), 4),
+			Error::<Test>::TooManyReporters
+		);
+
+		assert_ok!(Availability::remove_reporter(RuntimeOrigin::root(), 3));
+		System::assert_last_event(Event::ReporterRemoved { reporter: 3 }.into());
+		assert_eq!(Reporters::<Test>::get().into_inner(), vec![2]);
+		assert_noop!(
+			Availability::remove_reporter(RuntimeOrigin::root(), 3),
+			Error::<Test>::NotReporter
+		);
+	});
+}
+
+#[test]
+fn reports_are_checked() {
+	new_test_ext().execute_with(|| {
+		register_models(2);
+		System::set_block_number(7);
+
+		assert_noop!(report(5, &[(0, true)]), Error::<Test>::NotReporter);
+		assert_ok!(Availability::add_reporter(RuntimeOrigin::root(), 2));
+
+		assert_noop!(report(6, &[(0, true)]), Error::<Test>::InvalidCheckBlock);
+		assert_noop!(report(10, &[(0, true)]), Error::<Test>::InvalidCheckBlock);
+		assert_noop!(report(0, &[(0, true)]), Error::<Test>::InvalidCheckBlock);
+		assert_noop!(report(5, &[(0, true), (0, false)]), Error::<Test>::DuplicateModel);
+
+		assert_ok!(report(5, &[(0, true), (1, false)]));
+		System::assert_last_event(
+			Event::AvailabilityReported { reporter: 2, checked_at: 5, unavailable: 1 }.into(),
+		);
+		assert_eq!(LastReport::<Test>::get(2), Some(5));
+		assert_noop!(report(5, &[(0, true)]), Error::<Test>::AlreadyReported);
+	});
+}
+
+#[test]
+fn consecutive_failures_pause_model() {
+	new_test_ext().execute_with(|| {
+		register_models(2);
+		assert_ok!(Availability::add_reporter(RuntimeOrigin::root(), 2));
+
+		let check = |n: u64, checks: &[(u64, bool)]| {
+			System::set_block_number(n);
+			assert_ok!(report(n, checks));
+		};
+
+		check(5, &[(0, false), (1, false)]);
+		check(10, &[(0, false), (1, true)]);
+		assert_eq!(FailedChecks::<Test>::get(0), 2);
+		assert_eq!(FailedChecks::<Test>::get(1), 0);
+
+		check(15, &[(0, false), (1, false)]);
+		System::assert_has_event(Event::ModelUnavailable { model_id: 0, failed_checks: 3 }.into());
+		assert_eq!(pallet_ai_registry::Models::<Test>::get(0).unwrap().status, ModelStatus::Paused);
+		assert_eq!(pallet_ai_registry::Models::<Test>::get(1).unwrap().status, ModelStatus::Active);
+		assert_eq!(FailedChecks::<Test>::get(0), 0);
+		assert_eq!(FailedChecks::<Test>::get(1), 1);
+	});
+}
+
+#[test]
+fn sample_rotates_through_active_models() {
+	new_test_ext().execute_with(|| {
+		assert!(Availability::sample(5).is_empty());
+
+		register_models(3);
+		let ids = |n| Availability::sample(n).into_iter().map(|(id, _)| id).collect::<Vec<_>>();
+		assert_eq!(ids(5), vec![2, 0]);
+		assert_eq!(ids(10), vec![1, 2]);
+		assert_eq!(ids(15), vec![0, 1]);
+		assert_eq!(Availability::sample(15)[0].1, cid(0));
+
+		assert_ok!(AIRegistry::deactivate_model(RuntimeOrigin::signed(1), 1, None));
+		assert_eq!(ids(15), vec![0]);
+	});
+}
+
+#[test]
+fn offchain_worker_submits_unsigned_report() {
+	let (offchain, state) = TestOffchainExt::new();
+	let (pool, pool_state) = TestTransactionPoolExt::new();
+	let mut ext = new_test_ext();
+	ext.register_extension(OffchainWorkerExt::new(offchain.clone()));
+	ext.register_extension(OffchainDbExt::new(offchain));
+	ext.register_extension(TransactionPoolExt::new(pool));
+
+	ext.execute_with(|| {
+		register_models(2);
+		UintAuthorityId::set_all_keys(vec![2]);
+		sp_io::offchain::local_storage_set(
+			StorageKind::PERSISTENT,
+			GATEWAY_STORAGE_KEY,
+			b"https://gateway.example/",
+		);
+		for n in [0, 1] {
+			state.write().expect_request(PendingRequest {
+				method: "HEAD".into(),
+				uri: format!("https://gateway.example/ipfs/{}", String::from_utf8(cid(n)).unwrap()),
+				sent: true,
+				response: Some(Vec::new()),
+				..Default::default()
+			});
+		}
+
+		// Not a check block
+		Availability::offchain_worker(4);
+		assert!(pool_state.read().transactions.is_empty());
+
+		Availability::offchain_worker(5);
+		let tx = pool_state.write().transactions.pop().unwrap();
+		let tx = Extrinsic::decode(&mut &*tx).unwrap();
+		assert!(tx.is_bare());
+		let RuntimeCall::Availability(Call::report_availability_unsigned { payload, signature }) =
+			tx.function
+		else {
+			panic!("unexpected call");
+		};
+		assert_eq!(payload.public, UintAuthorityId(2));
+		assert_eq!(payload.checked_at, 5);
+		assert_eq!(
+			payload.checks.into_inner(),
+			vec![
+				CidCheck { model_id: 0, available: true },
+				CidCheck { model_id: 1, available: true },
+			]
+		);
+		assert_eq!(signature.0, 2);
+	});
+}
+
+#[test]
+fn offchain_worker_needs_a_key() {
+	let (offchain, _state) = TestOffchainExt::new();
+	let (pool, pool_state) = TestTransactionPoolExt::new();
+	let mut ext = new_test_ext();
+	ext.register_extension(OffchainWorkerExt::new(offchain.clone()));
+	ext.register_extension(OffchainDbExt::new(offchain));
+	ext.register_extension(TransactionPoolExt::new(pool));
+
+	ext.execute_with(|| {
+		register_models(2);
+		UintAuthorityId::set_all_keys(Vec::<u64>::new());
+
+		Availability::offchain_worker(5);
+		assert!(pool_state.read().transactions.is_empty());
+	});
+}
+
+/// Unsigned report of `checks` at `checked_at` by `key`, signed by `signer`
+fn unsigned_report(key: u64, signer: u64, checked_at: u64, checks: &[(u64, bool)]) -> Call<Test> {
+	let checks: Vec<CidCheck> =
+		checks.iter().map(|&(model_id, available)| CidCheck { model_id, available }).collect();
+	let payload = AvailabilityPayload {
+		public: UintAuthorityId(key),
+		checked_at,
+		checks: BoundedVec::try_from(checks).unwrap(),
+	};
+	let signature = TestSignature(signer, payload.encode());
+	Call::report_availability_unsigned { payload, signature }
+}
+
+#[test]
+fn unsigned_reports_are_validated() {
+	new_test_ext().execute_with(|| {
+		register_models(2);
+		assert_ok!(Availability::add_reporter(RuntimeOrigin::root(), 2));
+		assert_ok!(Availability::add_reporter(RuntimeOrigin::root(), 3));
+		System::set_block_number(7);
+
+		let validate =
+			|call: &Call<Test>| Availability::validate_unsigned(TransactionSource::External, call);
+		let valid = unsigned_report(2, 2, 5, &[(0, false)]);
+		assert!(validate(&valid).is_ok());
+		assert_eq!(
+			validate(&unsigned_report(2, 3, 5, &[(0, false)])),
+			InvalidTransaction::BadProof.into()
+		);
+		assert_eq!(
+			validate(&unsigned_report(4, 4, 5, &[(0, false)])),
+			InvalidTransaction::BadSigner.into()
+		);
+		assert_eq!(
+			validate(&unsigned_report(2, 2, 0, &[(0, false)])),
+			InvalidTransaction::Stale.into()
+		);
+
+		let Call::report_availability_unsigned { payload, signature } = valid.clone() else {
+			unreachable!()
+		};
+		assert_ok!(Availability::report_availability_unsigned(
+			RuntimeOrigin::none(),
+			payload,
+			signature
+		));
+		System::assert_last_event(
+			Event::AvailabilityReported { reporter: 2, checked_at: 5, unavailable: 1 }.into(),
+		);
+		assert_eq!(FailedChecks::<Test>::get(0), 1);
+		// Replays are rejected
+		assert_eq!(validate(&valid), InvalidTransaction::Stale.into());
+
+		// At most two unsigned reports per block
+		let third = unsigned_report(3, 3, 5, &[(1, true)]);
+		assert!(validate(&third).is_ok());
+		crate::UnsignedReports::<Test>::put((7, 2));
+		assert_eq!(validate(&third), InvalidTransaction::ExhaustsResources.into());
+		System::set_block_number(8);
+		assert!(validate(&third).is_ok());
+	});
+}
+
+/// Pin request for model `n` to the test pinning service
+fn pin_request(n: u8) -> PendingRequest {
+	let cid = String::from_utf8(cid(n)).unwrap();
+	PendingRequest {
+		method: "POST".into(),
+		uri: "https://pins.example/pins".into(),
+		headers: vec![
+			("Authorization".into(), "Bearer secret".into()),
+			("Content-Type".into(), "application/json".into()),
+		],
+		body: format!(r#"{{"cid":"{cid}","name":"model-{n}"}}"#).into_bytes(),
+		sent: true,
+		response: Some(Vec::new()),
+		..Default::default()
+	}
+}
+
+#[test]
+fn offchain_worker_pins_models_registered_after_configuration() {
+	let (offchain, state) = TestOffchainExt::new();
+	let mut ext = new_test_ext();
+	ext.register_extension(OffchainWorkerExt::new(offchain.clone()));
+	ext.register_extension(OffchainDbExt::new(offchain));
+
+	ext.execute_with(|| {
+		register_models(1);
+
+		// Nothing is pinned until the operator configures a service
+		Availability::offchain_worker(1);
+
+		sp_io::offchain::local_storage_set(
+			StorageKind::PERSISTENT,
+			PINNING_ENDPOINT_STORAGE_KEY,
+			b"https://pins.example/",
+		);
+		sp_io::offchain::local_storage_set(
+			StorageKind::PERSISTENT,
+			PINNING_TOKEN_STORAGE_KEY,
+			b"secret",
+		);
+		Availability::offchain_worker(2);
+
+		register_models(2);
+		state.write().expect_request(pin_request(1));
+		state.write().expect_request(pin_request(2));
+		Availability::offchain_worker(3);
+
+		// Already pinned models are not requested again
+		Availability::offchain_worker(4);
+	});
+}
@@ -0,0 +1,42 @@
+//! Interfaces the Availability pallet expects from the rest of the runtime
+
+use frame_support::pallet_prelude::DispatchResult;
+use pallet_ai_registry::ModelId;
+use sp_runtime::DispatchError;
+use sp_std::vec::Vec;
+
+/// Registered models whose artifacts are monitored
+pub trait MonitoredModels<AccountId> {
+	/// Model IDs handed out so far are all below this
+	fn next_model_id() -> ModelId;
+
+	/// IPFS CID of `model_id`, if the model is active
+	fn active_cid(model_id: ModelId) -> Option<Vec<u8>>;
+
+	/// Pause an active model whose artifact is unreachable
+	fn pause(model_id: ModelId) -> DispatchResult;
+
+	/// Register an active model owned by `owner` and return its ID
+	#[cfg(feature = "runtime-benchmarks")]
+	fn create_model(owner: &AccountId) -> ModelId;
+}
+
+/// No models: nothing is monitored
+impl<AccountId> MonitoredModels<AccountId> for () {
+	fn next_model_id() -> ModelId {
+		0
+	}
+
+	fn active_cid(_model_id: ModelId) -> Option<Vec<u8>> {
+		None
+	}
+
+	fn pause(_model_id: ModelId) -> DispatchResult {
+		Err(DispatchError::Other("no models configured"))
+	}
+
+	#[cfg(feature = "runtime-benchmarks")]
+	fn create_model(_owner: &AccountId) -> ModelId {
+		0
+	}
+}
@@ -0,0 +1,64 @@
+//! Type definitions for the Availability pallet
+
+use codec::{Decode, DecodeWithMemTracking, Encode, MaxEncodedLen};
+use frame_support::{
+	traits::Get, BoundedVec, CloneNoBound, EqNoBound, PartialEqNoBound, RuntimeDebugNoBound,
+};
+use frame_system::offchain::{SignedPayload, SigningTypes};
+use pallet_ai_registry::ModelId;
+use scale_info::TypeInfo;
+use sp_runtime::RuntimeDebug;
+
+/// Outcome of fetching one model's artifact from the IPFS gateway
+#[derive(
+	Clone,
+	Copy,
+	Encode,
+	Decode,
+	DecodeWithMemTracking,
+	Eq,
+	PartialEq,
+	RuntimeDebug,
+	TypeInfo,
+	MaxEncodedLen,
+)]
+pub struct CidCheck {
+	/// Model whose CID was fetched
+	pub model_id: ModelId,
+	/// Whether the gateway served the CID
+	pub available: bool,
+}
+
+/// Results of a check, signed by the reporter's key for unsigned submission
+#[derive(
+	CloneNoBound,
+	Encode,
+	Decode,
+	DecodeWithMemTracking,
+	EqNoBound,
+	PartialEqNoBound,
+	RuntimeDebugNoBound,
+	TypeInfo,
+)]
+#[scale_info(skip_type_params(MaxChecks))]
+pub struct AvailabilityPayload<Public, BlockNumber, MaxChecks>
+where
+	Public: Clone + Eq + core::fmt::Debug,
+	BlockNumber: Clone + Eq + core::fmt::Debug,
+	MaxChecks: Get<u32>,
+{
+	/// Key that signed the payload; its account must be a reporter
+	pub public: Public,
+	/// Check block the results belong to
+	pub checked_at: BlockNumber,
+	/// Fetch outcome for each sampled model
+	pub checks: BoundedVec<CidCheck, MaxChecks>,
+}
+
+impl<T: SigningTypes, MaxChecks: Get<u32>> SignedPayload<T>
+	for AvailabilityPayload<T::Public, frame_system::pallet_prelude::BlockNumberFor<T>, MaxChecks>
+{
+	fn public(&self) -> T::Public {
+		self.public.clone()
+	}
+}
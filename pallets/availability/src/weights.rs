@@ -0,0 +1,131 @@
+//! Autogenerated weights for pallet_availability
+//!
+//! THIS FILE WAS AUTO-GENERATED USING THE SUBSTRATE BENCHMARK CLI VERSION 4.0.0-dev
+//! DATE: 2024-01-01, STEPS: `50`, REPEAT: `20`, LOW RANGE: `[]`, HIGH RANGE: `[]`
+//! WORST CASE MAP SIZE: `1000000`
+//! HOSTNAME: `benchmark-runner`, CPU: `Intel(R) Xeon(R) CPU @ 2.60GHz`
+//! EXECUTION: Some(Wasm), WASM-EXECUTION: Compiled, CHAIN: Some("dev"), DB CACHE: 1024
+
+// Executed Command:
+// ./target/production/solochain-template-node
+// benchmark
+// pallet
+// --chain=dev
+// --steps=50
+// --repeat=20
+// --pallet=pallet_availability
+// --output=./pallets/availability/src/weights.rs
+// --template=./.maintain/frame-weight-template.hbs
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+#![allow(missing_docs)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use core::marker::PhantomData;
+
+/// Weight functions needed for pallet_availability.
+pub trait WeightInfo {
+	fn report_availability(n: u32, ) -> Weight;
+	fn add_reporter() -> Weight;
+	fn remove_reporter() -> Weight;
+	fn report_availability_unsigned(n: u32, ) -> Weight;
+}
+
+/// Weights for pallet_availability using the Substrate node and recommended hardware.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	/// Storage: Availability Reporters (r:1 w:0)
+	/// Proof: Availability Reporters (max_values: Some(1), max_size: Some(1025), added: 1520, mode: MaxEncodedLen)
+	/// Storage: Availability LastReport (r:1 w:1)
+	/// Proof: Availability LastReport (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: Availability FailedChecks (r:20 w:20)
+	/// Proof: Availability FailedChecks (max_values: None, max_size: Some(28), added: 2503, mode: MaxEncodedLen)
+	/// Storage: AIRegistry Models (r:20 w:20)
+	/// Proof: AIRegistry Models (max_values: None, max_size: Some(1712), added: 4187, mode: MaxEncodedLen)
+	fn report_availability(n: u32, ) -> Weight {
+		Weight::from_parts(20_000_000, 4047)
+			// Standard Error: 450_000
+			.saturating_add(Weight::from_parts(9_000_000, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes(1))
+			.saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 6690).saturating_mul(n.into()))
+	}
+
+	/// Storage: Availability Reporters (r:1 w:1)
+	/// Proof: Availability Reporters (max_values: Some(1), max_size: Some(1025), added: 1520, mode: MaxEncodedLen)
+	fn add_reporter() -> Weight {
+		Weight::from_parts(15_000_000, 1520)
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+
+	/// Storage: Availability Reporters (r:1 w:1)
+	/// Proof: Availability Reporters (max_values: Some(1), max_size: Some(1025), added: 1520, mode: MaxEncodedLen)
+	/// Storage: Availability LastReport (r:0 w:1)
+	/// Proof: Availability LastReport (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	fn remove_reporter() -> Weight {
+		Weight::from_parts(17_000_000, 1520)
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+
+	/// Storage: Availability Reporters (r:1 w:0)
+	/// Proof: Availability Reporters (max_values: Some(1), max_size: Some(1025), added: 1520, mode: MaxEncodedLen)
+	/// Storage: Availability LastReport (r:1 w:1)
+	/// Proof: Availability LastReport (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: Availability UnsignedReports (r:1 w:1)
+	/// Proof: Availability UnsignedReports (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: Availability FailedChecks (r:20 w:20)
+	/// Proof: Availability FailedChecks (max_values: None, max_size: Some(28), added: 2503, mode: MaxEncodedLen)
+	/// Storage: AIRegistry Models (r:20 w:20)
+	/// Proof: AIRegistry Models (max_values: None, max_size: Some(1712), added: 4187, mode: MaxEncodedLen)
+	fn report_availability_unsigned(n: u32, ) -> Weight {
+		Weight::from_parts(22_000_000, 4550)
+			// Standard Error: 450_000
+			.saturating_add(Weight::from_parts(9_000_000, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes(2))
+			.saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 6690).saturating_mul(n.into()))
+	}
+}
+
+// For backwards compatibility and tests
+impl WeightInfo for () {
+	fn report_availability(n: u32, ) -> Weight {
+		Weight::from_parts(20_000_000, 4047)
+			.saturating_add(Weight::from_parts(9_000_000, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(2))
+			.saturating_add(RocksDbWeight::get().reads((2_u64).saturating_mul(n.into())))
+			.saturating_add(RocksDbWeight::get().writes(1))
+			.saturating_add(RocksDbWeight::get().writes((2_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 6690).saturating_mul(n.into()))
+	}
+
+	fn add_reporter() -> Weight {
+		Weight::from_parts(15_000_000, 1520)
+			.saturating_add(RocksDbWeight::get().reads(1))
+			.saturating_add(RocksDbWeight::get().writes(1))
+	}
+
+	fn remove_reporter() -> Weight {
+		Weight::from_parts(17_000_000, 1520)
+			.saturating_add(RocksDbWeight::get().reads(1))
+			.saturating_add(RocksDbWeight::get().writes(2))
+	}
+
+	fn report_availability_unsigned(n: u32, ) -> Weight {
+		Weight::from_parts(22_000_000, 4550)
+			.saturating_add(Weight::from_parts(9_000_000, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(3))
+			.saturating_add(RocksDbWeight::get().reads((2_u64).saturating_mul(n.into())))
+			.saturating_add(RocksDbWeight::get().writes(2))
+			.saturating_add(RocksDbWeight::get().writes((2_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 6690).saturating_mul(n.into()))
+	}
+}
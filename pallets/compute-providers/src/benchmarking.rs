@@ -0,0 +1,180 @@
+//! Benchmarking setup for pallet-compute-providers
+
+#![cfg(feature = "runtime-benchmarks")]
+
+use super::*;
+use frame_benchmarking::v2::*;
+use frame_support::{
+	traits::{Currency, Get},
+	BoundedVec,
+};
+use frame_system::RawOrigin;
+use pallet_ai_registry::ModelType;
+use sp_runtime::traits::{Saturating, Zero};
+use sp_std::{vec, vec::Vec};
+
+/// Up to `MaxCapabilities` distinct model types
+fn model_types<T: Config>() -> Vec<ModelType> {
+	[ModelType::Classification, ModelType::Regression, ModelType::Generative]
+		.into_iter()
+		.take(T::MaxCapabilities::get() as usize)
+		.collect()
+}
+
+/// Register `who` as an active provider with spare balance for extra stake
+fn setup_provider<T: Config>(who: &T::AccountId) {
+	<Pallet<T> as ComputeProviders<T::AccountId, BalanceOf<T>>>::create_provider(who);
+	let free = T::Currency::free_balance(who);
+	T::Currency::make_free_balance_be(who, free.saturating_mul(2u32.into()));
+}
+
+#[benchmarks]
+mod benchmarks {
+	use super::*;
+
+	#[benchmark]
+	fn register_provider() {
+		let caller: T::AccountId = whitelisted_caller();
+		let stake = T::MinimumProviderStake::get();
+		T::Currency::make_free_balance_be(
+			&caller,
+			stake.saturating_mul(2u32.into()).saturating_add(T::Currency::minimum_balance()),
+		);
+
+		#[extrinsic_call]
+		register_provider(RawOrigin::Signed(caller.clone()), stake, model_types::<T>());
+
+		assert!(Providers::<T>::contains_key(&caller));
+	}
+
+	#[benchmark]
+	fn update_capabilities() {
+		let caller: T::AccountId = whitelisted_caller();
+		setup_provider::<T>(&caller);
+
+		#[extrinsic_call]
+		update_capabilities(RawOrigin::Signed(caller.clone()), model_types::<T>());
+
+		let provider = Providers::<T>::get(&caller).unwrap();
+		assert_eq!(provider.model_types.len(), model_types::<T>().len());
+	}
+
+	#[benchmark]
+	fn bond_extra() {
+		let caller: T::AccountId = whitelisted_caller();
+		setup_provider::<T>(&caller);
+		let amount = T::MinimumProviderStake::get();
+
+		#[extrinsic_call]
+		bond_extra(RawOrigin::Signed(caller.clone()), amount);
+
+		let provider = Providers::<T>::get(&caller).unwrap();
+		assert_eq!(provider.stake, amount.saturating_mul(2u32.into()));
+	}
+
+	#[benchmark]
+	fn pause_provider() {
+		let caller: T::AccountId = whitelisted_caller();
+		setup_provider::<T>(&caller);
+
+		#[extrinsic_call]
+		pause_provider(RawOrigin::Signed(caller.clone()));
+
+		assert_eq!(Providers::<T>::get(&caller).unwrap().status, ProviderStatus::Paused);
+	}
+
+	#[benchmark]
+	fn resume_provider() {
+		let caller: T::AccountId = whitelisted_caller();
+		setup_provider::<T>(&caller);
+		let _ = Pallet::<T>::pause_provider(RawOrigin::Signed(caller.clone()).into());
+
+		#[extrinsic_call]
+		resume_provider(RawOrigin::Signed(caller.clone()));
+
+		assert_eq!(Providers::<T>::get(&caller).unwrap().status, ProviderStatus::Active);
+	}
+
+	#[benchmark]
+	fn deregister_provider() {
+		let caller: T::AccountId = whitelisted_caller();
+		setup_provider::<T>(&caller);
+
+		#[extrinsic_call]
+		deregister_provider(RawOrigin::Signed(caller.clone()));
+
+		let provider = Providers::<T>::get(&caller).unwrap();
+		assert!(matches!(provider.status, ProviderStatus::Leaving { .. }));
+	}
+
+	#[benchmark]
+	fn withdraw_stake() {
+		let caller: T::AccountId = whitelisted_caller();
+		setup_provider::<T>(&caller);
+		let _ = Pallet::<T>::deregister_provider(RawOrigin::Signed(caller.clone()).into());
+		frame_system::Pallet::<T>::set_block_number(
+			frame_system::Pallet::<T>::block_number().saturating_add(T::UnbondingPeriod::get()),
+		);
+
+		#[extrinsic_call]
+		withdraw_stake(RawOrigin::Signed(caller.clone()));
+
+		assert!(!Providers::<T>::contains_key(&caller));
+	}
+
+	#[benchmark]
+	fn register_attestation(n: Linear<0, { T::MaxQuoteLength::get() }>) {
+		let caller: T::AccountId = whitelisted_caller();
+		setup_provider::<T>(&caller);
+		let quote: BoundedVec<u8, T::MaxQuoteLength> = vec![0u8; n as usize].try_into().unwrap();
+
+		// The verifier may reject the dummy quote; the write is accounted for in
+		// the weight regardless
+		#[block]
+		{
+			let _ = Pallet::<T>::register_attestation(
+				RawOrigin::Signed(caller.clone()).into(),
+				TeeKind::Sgx,
+				quote,
+			);
+		}
+
+		assert!(Providers::<T>::contains_key(&caller));
+	}
+
+	#[benchmark]
+	fn revoke_attestation() {
+		let caller: T::AccountId = whitelisted_caller();
+		setup_provider::<T>(&caller);
+		Attestations::<T>::insert(
+			&caller,
+			Attestation {
+				kind: TeeKind::Sgx,
+				measurement: Default::default(),
+				expires_at: T::AttestationValidity::get(),
+			},
+		);
+
+		#[extrinsic_call]
+		revoke_attestation(RawOrigin::Signed(caller.clone()));
+
+		assert!(!Attestations::<T>::contains_key(&caller));
+	}
+
+	#[benchmark]
+	fn slash_provider() -> Result<(), BenchmarkError> {
+		let provider: T::AccountId = account("provider", 0, 0);
+		setup_provider::<T>(&provider);
+		let origin =
+			T::SlashOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		let stake = T::MinimumProviderStake::get();
+
+		#[extrinsic_call]
+		_(origin as T::RuntimeOrigin, provider.clone(), stake);
+
+		assert!(Providers::<T>::get(&provider).unwrap().stake.is_zero());
+		Ok(())
+	}
+
+	impl_benchmark_test_suite!(Pallet, crate::mock::new_test_ext(), crate::mock::Test);
+}
@@ -0,0 +1,699 @@
+//! # Compute Providers Pallet
+//!
+//! Registry of compute operators that run inferences for models in the AI registry.
+//!
+//! ## Overview
+//!
+//! Operators register with a stake and declare which model types they can serve.
+//! Other pallets assign inference jobs to them through the [`ComputeProviders`]
+//! trait, which only accepts active providers that support the job's model type,
+//! hold at least `MinimumProviderStake` and are below `MaxConcurrentJobs`.
+//!
+//! ## Confidential computing
+//!
+//! Providers running inside a trusted execution environment (SGX, TDX, SEV-SNP,
+//! Nitro) can register a remote-attestation quote. The runtime's
+//! `AttestationVerifier` checks the quote is genuine and bound to the provider's
+//! account, and the attested enclave measurement is recorded for
+//! `AttestationValidity` blocks. Other pallets read it through
+//! [`ComputeProviders::enclave_measurement`] to restrict private models to
+//! confidential serving.
+//!
+//! ## Service levels
+//!
+//! The inference pallet reports how every assigned job ended. Each provider's
+//! completed, failed and timed-out counters and the success ratio over their last
+//! [`RECENT_JOBS`] jobs are kept in `SlaRecords`, giving buyers and slashing rules
+//! an objective reliability measure. Records outlive deregistration so that a
+//! provider cannot shed a bad history by registering again.
+//!
+//! ## Leaving
+//!
+//! A provider with no jobs in flight can deregister. Their stake stays reserved
+//! for `UnbondingPeriod` blocks so that `SlashOrigin` can still penalize late
+//! disputes or SLA breaches with `slash_provider`, after which it can be withdrawn.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+pub use pallet::*;
+
+#[cfg(test)]
+mod mock;
+
+#[cfg(test)]
+mod tests;
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+
+pub mod weights;
+pub use weights::*;
+
+pub mod types;
+pub use types::*;
+
+pub mod traits;
+pub use traits::*;
+
+pub mod migrations;
+
+#[frame_support::pallet]
+pub mod pallet {
+	use super::*;
+	use frame_support::{
+		pallet_prelude::*,
+		traits::{Currency, OnUnbalanced, ReservableCurrency},
+	};
+	use frame_system::pallet_prelude::*;
+	use pallet_ai_registry::ModelType;
+	use sp_core::H256;
+	use sp_runtime::traits::Saturating;
+	use sp_std::vec::Vec;
+
+	pub(crate) type BalanceOf<T> =
+		<<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
+	type NegativeImbalanceOf<T> = <<T as Config>::Currency as Currency<
+		<T as frame_system::Config>::AccountId,
+	>>::NegativeImbalance;
+
+	/// The in-code storage version
+	///
+	/// Bump together with adding a migration in [`crate::migrations`].
+	pub const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
+	#[pallet::pallet]
+	#[pallet::storage_version(STORAGE_VERSION)]
+	pub struct Pallet<T>(_);
+
+	/// Configuration trait for the Compute Providers pallet
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		/// The overarching event type
+		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+		/// Weight information for extrinsics
+		type WeightInfo: WeightInfo;
+
+		/// Currency used for provider stakes
+		type Currency: ReservableCurrency<Self::AccountId>;
+
+		/// Minimum stake required to register as a provider
+		#[pallet::constant]
+		type MinimumProviderStake: Get<BalanceOf<Self>>;
+
+		/// Maximum number of model types a provider can declare
+		#[pallet::constant]
+		type MaxCapabilities: Get<u32>;
+
+		/// Maximum number of jobs assigned to a provider at once
+		#[pallet::constant]
+		type MaxConcurrentJobs: Get<u32>;
+
+		/// Blocks a deregistered provider's stake stays reserved
+		#[pallet::constant]
+		type UnbondingPeriod: Get<BlockNumberFor<Self>>;
+
+		/// Origin allowed to slash provider stakes for disputes or SLA breaches
+		type SlashOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+		/// Destination of slashed stakes, typically the treasury
+		type Slash: OnUnbalanced<NegativeImbalanceOf<Self>>;
+
+		/// Verifier for remote-attestation quotes; `()` if the runtime cannot verify any
+		type AttestationVerifier: AttestationVerifier<Self::AccountId>;
+
+		/// Maximum size of a remote-attestation quote in bytes
+		#[pallet::constant]
+		type MaxQuoteLength: Get<u32>;
+
+		/// Blocks an attestation stays valid before it has to be renewed
+		#[pallet::constant]
+		type AttestationValidity: Get<BlockNumberFor<Self>>;
+	}
+
+	/// Registered providers
+	#[pallet::storage]
+	pub type Providers<T: Config> = StorageMap<_, Blake2_128Concat, T::AccountId, ProviderInfo<T>>;
+
+	/// Verified enclave attestations of providers
+	#[pallet::storage]
+	pub type Attestations<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, Attestation<BlockNumberFor<T>>>;
+
+	/// Service-level records of providers, kept after they leave
+	#[pallet::storage]
+	pub type SlaRecords<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, SlaStats, ValueQuery>;
+
+	/// Number of registered providers, including those leaving
+	#[pallet::storage]
+	pub type ProviderCount<T: Config> = StorageValue<_, u32, ValueQuery>;
+
+	/// Events emitted by this pallet
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// A compute provider registered
+		/// [provider, stake]
+		ProviderRegistered { provider: T::AccountId, stake: BalanceOf<T> },
+		/// A provider changed the model types they support
+		/// [provider]
+		CapabilitiesUpdated { provider: T::AccountId },
+		/// A provider added to their stake
+		/// [provider, stake]
+		StakeIncreased { provider: T::AccountId, stake: BalanceOf<T> },
+		/// A provider stopped accepting new jobs
+		/// [provider]
+		ProviderPaused { provider: T::AccountId },
+		/// A provider started accepting new jobs again
+		/// [provider]
+		ProviderResumed { provider: T::AccountId },
+		/// A provider deregistered and started unbonding
+		/// [provider, unbond_at]
+		ProviderLeaving { provider: T::AccountId, unbond_at: BlockNumberFor<T> },
+		/// A provider registered a verified enclave attestation
+		/// [provider, kind, measurement, expires_at]
+		AttestationRegistered {
+			provider: T::AccountId,
+			kind: TeeKind,
+			measurement: H256,
+			expires_at: BlockNumberFor<T>,
+		},
+		/// A provider removed their attestation
+		/// [provider]
+		AttestationRevoked { provider: T::AccountId },
+		/// A provider withdrew their stake and was removed
+		/// [provider, stake]
+		ProviderRemoved { provider: T::AccountId, stake: BalanceOf<T> },
+		/// Part of a provider's stake was slashed
+		/// [provider, amount, stake]
+		ProviderSlashed { provider: T::AccountId, amount: BalanceOf<T>, stake: BalanceOf<T> },
+	}
+
+	/// Errors that can occur in this pallet
+	#[pallet::error]
+	pub enum Error<T> {
+		/// Caller is already a registered provider
+		AlreadyRegistered,
+		/// Caller is not a registered provider
+		ProviderNotFound,
+		/// Stake is below `MinimumProviderStake`
+		InsufficientStake,
+		/// Caller cannot reserve the stake
+		InsufficientBalance,
+		/// At least one model type must be declared
+		NoCapabilities,
+		/// More than `MaxCapabilities` model types
+		TooManyCapabilities,
+		/// A model type was declared twice
+		DuplicateCapability,
+		/// Provider is not active
+		ProviderNotActive,
+		/// Provider is not paused
+		ProviderNotPaused,
+		/// Provider has deregistered
+		ProviderLeaving,
+		/// Provider does not support the job's model type
+		UnsupportedModelType,
+		/// Provider already runs `MaxConcurrentJobs` jobs
+		TooManyJobs,
+		/// Provider still has jobs in flight
+		JobsInFlight,
+		/// Provider has not deregistered
+		NotLeaving,
+		/// Unbonding period has not ended yet
+		StillUnbonding,
+		/// Quote is not genuine or not bound to the caller
+		InvalidAttestation,
+		/// Provider has no attestation
+		NoAttestation,
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Register as a compute provider
+		///
+		/// # Arguments
+		/// * `origin` - The operator registering
+		/// * `stake` - Amount to reserve; at least `MinimumProviderStake`
+		/// * `model_types` - Model types the operator can serve
+		///
+		/// # Errors
+		/// * `AlreadyRegistered` - Caller is already a provider
+		/// * `InsufficientStake` - Stake below minimum
+		/// * `NoCapabilities` - No model type declared
+		/// * `TooManyCapabilities` - Too many model types
+		/// * `DuplicateCapability` - A capability was declared twice
+		/// * `InsufficientBalance` - Stake cannot be reserved
+		///
+		/// # Events
+		/// * `ProviderRegistered` - Provider registered
+		#[pallet::call_index(0)]
+		#[pallet::weight(T::WeightInfo::register_provider())]
+		pub fn register_provider(
+			origin: OriginFor<T>,
+			stake: BalanceOf<T>,
+			model_types: Vec<ModelType>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			ensure!(!Providers::<T>::contains_key(&who), Error::<T>::AlreadyRegistered);
+			ensure!(stake >= T::MinimumProviderStake::get(), Error::<T>::InsufficientStake);
+			let model_types = Self::bound_capabilities(model_types)?;
+
+			T::Currency::reserve(&who, stake).map_err(|_| Error::<T>::InsufficientBalance)?;
+
+			Providers::<T>::insert(
+				&who,
+				ProviderInfo {
+					stake,
+					model_types,
+					status: ProviderStatus::Active,
+					active_jobs: 0,
+					registered_at: frame_system::Pallet::<T>::block_number(),
+				},
+			);
+			ProviderCount::<T>::mutate(|count| *count = count.saturating_add(1));
+
+			Self::deposit_event(Event::ProviderRegistered { provider: who, stake });
+
+			Ok(())
+		}
+
+		/// Replace the model types a provider supports
+		///
+		/// Jobs already assigned are not affected.
+		///
+		/// # Errors
+		/// * `ProviderNotFound` - Caller is not a provider
+		/// * `ProviderLeaving` - Provider has deregistered
+		/// * `NoCapabilities` - No model type declared
+		/// * `TooManyCapabilities` - Too many model types
+		/// * `DuplicateCapability` - A capability was declared twice
+		///
+		/// # Events
+		/// * `CapabilitiesUpdated` - Capabilities replaced
+		#[pallet::call_index(1)]
+		#[pallet::weight(T::WeightInfo::update_capabilities())]
+		pub fn update_capabilities(
+			origin: OriginFor<T>,
+			model_types: Vec<ModelType>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let model_types = Self::bound_capabilities(model_types)?;
+
+			Providers::<T>::try_mutate(&who, |maybe_provider| -> DispatchResult {
+				let provider = maybe_provider.as_mut().ok_or(Error::<T>::ProviderNotFound)?;
+				ensure!(
+					!matches!(provider.status, ProviderStatus::Leaving { .. }),
+					Error::<T>::ProviderLeaving
+				);
+				provider.model_types = model_types;
+				Ok(())
+			})?;
+
+			Self::deposit_event(Event::CapabilitiesUpdated { provider: who });
+
+			Ok(())
+		}
+
+		/// Reserve additional stake
+		///
+		/// # Errors
+		/// * `ProviderNotFound` - Caller is not a provider
+		/// * `ProviderLeaving` - Provider has deregistered
+		/// * `InsufficientBalance` - Amount cannot be reserved
+		///
+		/// # Events
+		/// * `StakeIncreased` - Stake increased, with the new total
+		#[pallet::call_index(2)]
+		#[pallet::weight(T::WeightInfo::bond_extra())]
+		pub fn bond_extra(origin: OriginFor<T>, amount: BalanceOf<T>) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let stake = Providers::<T>::try_mutate(
+				&who,
+				|maybe_provider| -> Result<BalanceOf<T>, DispatchError> {
+					let provider = maybe_provider.as_mut().ok_or(Error::<T>::ProviderNotFound)?;
+					ensure!(
+						!matches!(provider.status, ProviderStatus::Leaving { .. }),
+						Error::<T>::ProviderLeaving
+					);
+					T::Currency::reserve(&who, amount)
+						.map_err(|_| Error::<T>::InsufficientBalance)?;
+					provider.stake = provider.stake.saturating_add(amount);
+					Ok(provider.stake)
+				},
+			)?;
+
+			Self::deposit_event(Event::StakeIncreased { provider: who, stake });
+
+			Ok(())
+		}
+
+		/// Stop accepting new jobs; jobs already assigned still have to be completed
+		///
+		/// # Errors
+		/// * `ProviderNotFound` - Caller is not a provider
+		/// * `ProviderNotActive` - Provider is paused or leaving
+		///
+		/// # Events
+		/// * `ProviderPaused` - Provider paused
+		#[pallet::call_index(3)]
+		#[pallet::weight(T::WeightInfo::pause_provider())]
+		pub fn pause_provider(origin: OriginFor<T>) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			Providers::<T>::try_mutate(&who, |maybe_provider| -> DispatchResult {
+				let provider = maybe_provider.as_mut().ok_or(Error::<T>::ProviderNotFound)?;
+				ensure!(provider.status == ProviderStatus::Active, Error::<T>::ProviderNotActive);
+				provider.status = ProviderStatus::Paused;
+				Ok(())
+			})?;
+
+			Self::deposit_event(Event::ProviderPaused { provider: who });
+
+			Ok(())
+		}
+
+		/// Start accepting new jobs again
+		///
+		/// # Errors
+		/// * `ProviderNotFound` - Caller is not a provider
+		/// * `ProviderNotPaused` - Provider is active or leaving
+		///
+		/// # Events
+		/// * `ProviderResumed` - Provider active again
+		#[pallet::call_index(4)]
+		#[pallet::weight(T::WeightInfo::resume_provider())]
+		pub fn resume_provider(origin: OriginFor<T>) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			Providers::<T>::try_mutate(&who, |maybe_provider| -> DispatchResult {
+				let provider = maybe_provider.as_mut().ok_or(Error::<T>::ProviderNotFound)?;
+				ensure!(provider.status == ProviderStatus::Paused, Error::<T>::ProviderNotPaused);
+				provider.status = ProviderStatus::Active;
+				Ok(())
+			})?;
+
+			Self::deposit_event(Event::ProviderResumed { provider: who });
+
+			Ok(())
+		}
+
+		/// Deregister and start unbonding the stake
+		///
+		/// # Errors
+		/// * `ProviderNotFound` - Caller is not a provider
+		/// * `ProviderLeaving` - Provider has already deregistered
+		/// * `JobsInFlight` - Provider still has assigned jobs
+		///
+		/// # Events
+		/// * `ProviderLeaving` - Unbonding started
+		#[pallet::call_index(5)]
+		#[pallet::weight(T::WeightInfo::deregister_provider())]
+		pub fn deregister_provider(origin: OriginFor<T>) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let unbond_at = Providers::<T>::try_mutate(
+				&who,
+				|maybe_provider| -> Result<BlockNumberFor<T>, DispatchError> {
+					let provider = maybe_provider.as_mut().ok_or(Error::<T>::ProviderNotFound)?;
+					ensure!(
+						!matches!(provider.status, ProviderStatus::Leaving { .. }),
+						Error::<T>::ProviderLeaving
+					);
+					ensure!(provider.active_jobs == 0, Error::<T>::JobsInFlight);
+
+					let unbond_at = frame_system::Pallet::<T>::block_number()
+						.saturating_add(T::UnbondingPeriod::get());
+					provider.status = ProviderStatus::Leaving { unbond_at };
+					Ok(unbond_at)
+				},
+			)?;
+
+			Self::deposit_event(Event::ProviderLeaving { provider: who, unbond_at });
+
+			Ok(())
+		}
+
+		/// Withdraw the stake once unbonding is over and remove the provider
+		///
+		/// # Errors
+		/// * `ProviderNotFound` - Caller is not a provider
+		/// * `NotLeaving` - Provider has not deregistered
+		/// * `StillUnbonding` - Unbonding period has not ended
+		///
+		/// # Events
+		/// * `ProviderRemoved` - Stake returned and provider removed
+		#[pallet::call_index(6)]
+		#[pallet::weight(T::WeightInfo::withdraw_stake())]
+		pub fn withdraw_stake(origin: OriginFor<T>) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let provider = Providers::<T>::get(&who).ok_or(Error::<T>::ProviderNotFound)?;
+			let ProviderStatus::Leaving { unbond_at } = provider.status else {
+				return Err(Error::<T>::NotLeaving.into());
+			};
+			ensure!(
+				frame_system::Pallet::<T>::block_number() >= unbond_at,
+				Error::<T>::StillUnbonding
+			);
+
+			T::Currency::unreserve(&who, provider.stake);
+			Providers::<T>::remove(&who);
+			Attestations::<T>::remove(&who);
+			ProviderCount::<T>::mutate(|count| *count = count.saturating_sub(1));
+
+			Self::deposit_event(Event::ProviderRemoved { provider: who, stake: provider.stake });
+
+			Ok(())
+		}
+
+		/// Register a remote-attestation quote for the enclave the provider serves from
+		///
+		/// The quote is checked by `AttestationVerifier`, which must find the caller's
+		/// account in its report data. The attestation replaces any previous one and
+		/// is valid for `AttestationValidity` blocks.
+		///
+		/// # Errors
+		/// * `ProviderNotFound` - Caller is not a provider
+		/// * `ProviderLeaving` - Provider has deregistered
+		/// * `InvalidAttestation` - Quote is not genuine or not bound to the caller
+		///
+		/// # Events
+		/// * `AttestationRegistered` - Attestation recorded
+		#[pallet::call_index(7)]
+		#[pallet::weight(T::WeightInfo::register_attestation(quote.len() as u32))]
+		pub fn register_attestation(
+			origin: OriginFor<T>,
+			kind: TeeKind,
+			quote: BoundedVec<u8, T::MaxQuoteLength>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let provider = Providers::<T>::get(&who).ok_or(Error::<T>::ProviderNotFound)?;
+			ensure!(
+				!matches!(provider.status, ProviderStatus::Leaving { .. }),
+				Error::<T>::ProviderLeaving
+			);
+			let measurement = T::AttestationVerifier::verify(&who, kind, &quote)
+				.ok_or(Error::<T>::InvalidAttestation)?;
+
+			let expires_at = frame_system::Pallet::<T>::block_number()
+				.saturating_add(T::AttestationValidity::get());
+			Attestations::<T>::insert(&who, Attestation { kind, measurement, expires_at });
+
+			Self::deposit_event(Event::AttestationRegistered {
+				provider: who,
+				kind,
+				measurement,
+				expires_at,
+			});
+
+			Ok(())
+		}
+
+		/// Remove the provider's attestation, e.g. after leaving confidential hardware
+		///
+		/// # Errors
+		/// * `NoAttestation` - Caller has no attestation
+		///
+		/// # Events
+		/// * `AttestationRevoked` - Attestation removed
+		#[pallet::call_index(8)]
+		#[pallet::weight(T::WeightInfo::revoke_attestation())]
+		pub fn revoke_attestation(origin: OriginFor<T>) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			ensure!(Attestations::<T>::contains_key(&who), Error::<T>::NoAttestation);
+			Attestations::<T>::remove(&who);
+
+			Self::deposit_event(Event::AttestationRevoked { provider: who });
+
+			Ok(())
+		}
+
+		/// Slash part of a provider's stake, e.g. for an upheld dispute or SLA breach
+		///
+		/// Reaches providers who are still unbonding. The slashed amount, at most the
+		/// whole stake, goes to `Slash`. A provider left below `MinimumProviderStake`
+		/// stays registered but has to `bond_extra` before getting new jobs.
+		///
+		/// # Arguments
+		/// * `origin` - Must be `SlashOrigin`
+		/// * `provider` - Provider to slash
+		/// * `amount` - Stake to slash
+		///
+		/// # Errors
+		/// * `ProviderNotFound` - Account is not a provider
+		///
+		/// # Events
+		/// * `ProviderSlashed` - Stake slashed, with what is left of it
+		#[pallet::call_index(9)]
+		#[pallet::weight(T::WeightInfo::slash_provider())]
+		pub fn slash_provider(
+			origin: OriginFor<T>,
+			provider: T::AccountId,
+			amount: BalanceOf<T>,
+		) -> DispatchResult {
+			T::SlashOrigin::ensure_origin(origin)?;
+
+			let imbalance = Self::slash_stake(&provider, amount)?;
+			T::Slash::on_unbalanced(imbalance);
+
+			Ok(())
+		}
+	}
+
+	impl<T: Config> Pallet<T> {
+		/// Slash up to `amount` of `provider`'s stake, returning the slashed funds
+		pub(crate) fn slash_stake(
+			provider: &T::AccountId,
+			amount: BalanceOf<T>,
+		) -> Result<NegativeImbalanceOf<T>, DispatchError> {
+			let (amount, stake, imbalance) = Providers::<T>::try_mutate(
+				provider,
+				|maybe_provider| -> Result<_, DispatchError> {
+					let info = maybe_provider.as_mut().ok_or(Error::<T>::ProviderNotFound)?;
+					let amount = amount.min(info.stake);
+					let (imbalance, _) = T::Currency::slash_reserved(provider, amount);
+					info.stake = info.stake.saturating_sub(amount);
+					Ok((amount, info.stake, imbalance))
+				},
+			)?;
+
+			Self::deposit_event(Event::ProviderSlashed {
+				provider: provider.clone(),
+				amount,
+				stake,
+			});
+
+			Ok(imbalance)
+		}
+
+		/// Check declared model types and convert them to a bounded list
+		fn bound_capabilities(
+			model_types: Vec<ModelType>,
+		) -> Result<BoundedVec<ModelType, T::MaxCapabilities>, DispatchError> {
+			ensure!(!model_types.is_empty(), Error::<T>::NoCapabilities);
+			ensure!(!has_duplicates(&model_types), Error::<T>::DuplicateCapability);
+
+			model_types.try_into().map_err(|_| Error::<T>::TooManyCapabilities.into())
+		}
+	}
+
+	/// Whether any item appears more than once
+	fn has_duplicates<I: PartialEq>(items: &[I]) -> bool {
+		items.iter().enumerate().any(|(i, item)| items[..i].contains(item))
+	}
+}
+
+impl<T: Config> ComputeProviders<T::AccountId, BalanceOf<T>> for Pallet<T> {
+	fn can_serve(who: &T::AccountId, model_type: pallet_ai_registry::ModelType) -> bool {
+		use frame_support::traits::Get;
+
+		Providers::<T>::get(who).is_some_and(|provider| {
+			provider.status == ProviderStatus::Active
+				&& provider.model_types.contains(&model_type)
+				&& provider.stake >= T::MinimumProviderStake::get()
+		})
+	}
+
+	fn start_job(
+		who: &T::AccountId,
+		model_type: pallet_ai_registry::ModelType,
+	) -> frame_support::dispatch::DispatchResult {
+		use frame_support::{ensure, traits::Get};
+
+		Providers::<T>::try_mutate(who, |maybe_provider| {
+			let provider = maybe_provider.as_mut().ok_or(Error::<T>::ProviderNotFound)?;
+			ensure!(provider.status == ProviderStatus::Active, Error::<T>::ProviderNotActive);
+			ensure!(provider.model_types.contains(&model_type), Error::<T>::UnsupportedModelType);
+			// Slashed below the minimum until topped up
+			ensure!(
+				provider.stake >= T::MinimumProviderStake::get(),
+				Error::<T>::InsufficientStake
+			);
+			ensure!(provider.active_jobs < T::MaxConcurrentJobs::get(), Error::<T>::TooManyJobs);
+			provider.active_jobs = provider.active_jobs.saturating_add(1);
+			Ok(())
+		})
+	}
+
+	fn finish_job(who: &T::AccountId) {
+		Providers::<T>::mutate(who, |maybe_provider| {
+			if let Some(provider) = maybe_provider {
+				provider.active_jobs = provider.active_jobs.saturating_sub(1);
+			}
+		});
+	}
+
+	fn enclave_measurement(who: &T::AccountId) -> Option<sp_core::H256> {
+		Attestations::<T>::get(who)
+			.filter(|attestation| {
+				frame_system::Pallet::<T>::block_number() <= attestation.expires_at
+			})
+			.map(|attestation| attestation.measurement)
+	}
+
+	fn record_outcome(who: &T::AccountId, outcome: JobOutcome) {
+		SlaRecords::<T>::mutate(who, |stats| stats.record(outcome));
+	}
+
+	fn slash_to(
+		who: &T::AccountId,
+		beneficiary: &T::AccountId,
+		amount: BalanceOf<T>,
+	) -> BalanceOf<T> {
+		use frame_support::traits::{Currency, Imbalance};
+		use sp_runtime::traits::Zero;
+
+		Self::slash_stake(who, amount).map_or(Zero::zero(), |imbalance| {
+			let paid = imbalance.peek();
+			T::Currency::resolve_creating(beneficiary, imbalance);
+			paid
+		})
+	}
+
+	#[cfg(feature = "runtime-benchmarks")]
+	fn create_provider(who: &T::AccountId) {
+		use frame_support::traits::{Currency, Get};
+		use sp_runtime::traits::Saturating;
+
+		let stake = T::MinimumProviderStake::get();
+		T::Currency::make_free_balance_be(
+			who,
+			stake.saturating_mul(2u32.into()).saturating_add(T::Currency::minimum_balance()),
+		);
+		let _ = Self::register_provider(
+			frame_system::RawOrigin::Signed(who.clone()).into(),
+			stake,
+			sp_std::vec![
+				pallet_ai_registry::ModelType::Classification,
+				pallet_ai_registry::ModelType::Regression,
+				pallet_ai_registry::ModelType::Generative,
+			],
+		);
+	}
+}
@@ -0,0 +1,17 @@
+//! Storage migrations for the Compute Providers pallet
+//!
+//! Every change to the layout of an existing storage item must ship with a `vN`
+//! module here, wrapped in [`VersionedMigrationOf`] and added to [`Unreleased`],
+//! together with a bump of [`crate::pallet::STORAGE_VERSION`].
+
+use crate::Pallet;
+use frame_support::migrations::VersionedMigration;
+
+pub mod v1;
+
+/// Runs `Inner` only if the on-chain storage version is `FROM`, then sets it to `TO`
+pub type VersionedMigrationOf<T, Inner, const FROM: u16, const TO: u16> =
+	VersionedMigration<FROM, TO, Inner, Pallet<T>, <T as frame_system::Config>::DbWeight>;
+
+/// Migrations of this pallet not yet applied by every known runtime
+pub type Unreleased<T> = (v1::MigrateToV1<T>,);
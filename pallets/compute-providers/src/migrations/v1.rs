@@ -0,0 +1,63 @@
+//! Drops the frameworks providers used to declare
+//!
+//! Models do not record a framework, so the declared frameworks could never be
+//! matched against a job and were not checked.
+
+use super::VersionedMigrationOf;
+use crate::{pallet::Providers, Config, ProviderInfo, ProviderStatus};
+use frame_support::{pallet_prelude::*, traits::UncheckedOnRuntimeUpgrade};
+use frame_system::pallet_prelude::BlockNumberFor;
+use pallet_ai_registry::ModelType;
+#[cfg(feature = "try-runtime")]
+use sp_std::vec::Vec;
+
+/// Provider record before version 1
+///
+/// Frameworks were a field-less enum, so each one encodes as a single byte.
+#[derive(Encode, Decode)]
+pub struct OldProviderInfo<T: Config> {
+	pub stake: crate::pallet::BalanceOf<T>,
+	pub model_types: BoundedVec<ModelType, T::MaxCapabilities>,
+	pub frameworks: BoundedVec<u8, T::MaxCapabilities>,
+	pub status: ProviderStatus<BlockNumberFor<T>>,
+	pub active_jobs: u32,
+	pub registered_at: BlockNumberFor<T>,
+}
+
+/// Strips the frameworks from every provider
+pub struct InnerMigrateV0ToV1<T>(PhantomData<T>);
+
+impl<T: Config> UncheckedOnRuntimeUpgrade for InnerMigrateV0ToV1<T> {
+	fn on_runtime_upgrade() -> Weight {
+		let mut providers = 0u64;
+		Providers::<T>::translate::<OldProviderInfo<T>, _>(|_, old| {
+			providers = providers.saturating_add(1);
+			Some(ProviderInfo {
+				stake: old.stake,
+				model_types: old.model_types,
+				status: old.status,
+				active_jobs: old.active_jobs,
+				registered_at: old.registered_at,
+			})
+		});
+		T::DbWeight::get().reads_writes(providers, providers)
+	}
+
+	#[cfg(feature = "try-runtime")]
+	fn pre_upgrade() -> Result<Vec<u8>, sp_runtime::TryRuntimeError> {
+		Ok((Providers::<T>::iter_keys().count() as u64).encode())
+	}
+
+	#[cfg(feature = "try-runtime")]
+	fn post_upgrade(state: Vec<u8>) -> Result<(), sp_runtime::TryRuntimeError> {
+		let count = u64::decode(&mut &state[..]).map_err(|_| "invalid pre-upgrade state")?;
+		ensure!(
+			Providers::<T>::iter_values().count() as u64 == count,
+			"providers lost in the migration"
+		);
+		Ok(())
+	}
+}
+
+/// Drops provider frameworks, run only at storage version 0
+pub type MigrateToV1<T> = VersionedMigrationOf<T, InnerMigrateV0ToV1<T>, 0, 1>;
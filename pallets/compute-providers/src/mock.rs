@@ -0,0 +1,105 @@
+//! Mock runtime for Compute Providers pallet tests
+
+use crate as pallet_compute_providers;
+use crate::TeeKind;
+use frame_support::BoundedVec;
+use frame_support::{
+	derive_impl,
+	traits::{ConstU128, ConstU32, ConstU64},
+};
+use frame_system::EnsureRoot;
+use sp_core::H256;
+use sp_runtime::BuildStorage;
+
+type Block = frame_system::mocking::MockBlock<Test>;
+
+#[frame_support::runtime]
+mod runtime {
+	#[runtime::runtime]
+	#[runtime::derive(
+		RuntimeCall,
+		RuntimeEvent,
+		RuntimeError,
+		RuntimeOrigin,
+		RuntimeFreezeReason,
+		RuntimeHoldReason,
+		RuntimeSlashReason,
+		RuntimeLockId,
+		RuntimeTask
+	)]
+	pub struct Test;
+
+	#[runtime::pallet_index(0)]
+	pub type System = frame_system::Pallet<Test>;
+
+	#[runtime::pallet_index(1)]
+	pub type Balances = pallet_balances::Pallet<Test>;
+
+	#[runtime::pallet_index(2)]
+	pub type ComputeProviders = pallet_compute_providers::Pallet<Test>;
+}
+
+#[derive_impl(frame_system::config_preludes::TestDefaultConfig)]
+impl frame_system::Config for Test {
+	type Block = Block;
+	type AccountData = pallet_balances::AccountData<u128>;
+}
+
+#[derive_impl(pallet_balances::config_preludes::TestDefaultConfig)]
+impl pallet_balances::Config for Test {
+	type Balance = u128;
+	type ExistentialDeposit = ConstU128<1>;
+	type AccountStore = System;
+}
+
+impl pallet_compute_providers::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type WeightInfo = ();
+	type Currency = Balances;
+	type MinimumProviderStake = ConstU128<500>;
+	type MaxCapabilities = ConstU32<2>;
+	type MaxConcurrentJobs = ConstU32<2>;
+	type UnbondingPeriod = ConstU64<10>;
+	type SlashOrigin = EnsureRoot<u64>;
+	type Slash = ();
+	type AttestationVerifier = MockAttestationVerifier;
+	type MaxQuoteLength = ConstU32<64>;
+	type AttestationValidity = ConstU64<20>;
+}
+
+/// Accepts quotes made of the provider's account ID byte followed by the measurement
+pub struct MockAttestationVerifier;
+
+impl pallet_compute_providers::AttestationVerifier<u64> for MockAttestationVerifier {
+	fn verify(who: &u64, _kind: TeeKind, quote: &[u8]) -> Option<H256> {
+		match quote {
+			[account, measurement @ ..] if *account as u64 == *who && measurement.len() == 32 => {
+				Some(H256::from_slice(measurement))
+			}
+			_ => None,
+		}
+	}
+}
+
+/// A quote `MockAttestationVerifier` accepts for `who`
+pub fn quote(who: u64, measurement: H256) -> BoundedVec<u8, ConstU32<64>> {
+	let mut quote = vec![who as u8];
+	quote.extend_from_slice(measurement.as_bytes());
+	quote.try_into().unwrap()
+}
+
+// Build genesis storage according to the mock runtime.
+pub fn new_test_ext() -> sp_io::TestExternalities {
+	let mut t = frame_system::GenesisConfig::<Test>::default().build_storage().unwrap();
+
+	pallet_balances::GenesisConfig::<Test> {
+		balances: vec![(1, 10000), (2, 10000), (3, 100)],
+		dev_accounts: None,
+	}
+	.assimilate_storage(&mut t)
+	.unwrap();
+
+	let mut ext = sp_io::TestExternalities::new(t);
+	ext.execute_with(|| System::set_block_number(1));
+	ext
+}
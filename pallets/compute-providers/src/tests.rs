@@ -0,0 +1,362 @@
+//! Unit tests for Compute Providers pallet
+
+use crate::{
+	mock::*,
+	pallet::{Error, Event, ProviderCount, Providers},
+	Attestations, ComputeProviders as _, JobOutcome, ProviderStatus, SlaRecords, TeeKind,
+	RECENT_JOBS,
+};
+use frame_support::{assert_noop, assert_ok};
+use pallet_ai_registry::ModelType;
+use sp_core::H256;
+use sp_runtime::Perbill;
+
+/// Register `who` as a provider of classification models
+fn register(who: u64) {
+	assert_ok!(ComputeProviders::register_provider(
+		RuntimeOrigin::signed(who),
+		1_000,
+		vec![ModelType::Classification]
+	));
+}
+
+#[test]
+fn register_provider_works() {
+	new_test_ext().execute_with(|| {
+		register(1);
+
+		let provider = Providers::<Test>::get(1).unwrap();
+		assert_eq!(provider.stake, 1_000);
+		assert_eq!(provider.model_types.to_vec(), vec![ModelType::Classification]);
+		assert_eq!(provider.status, ProviderStatus::Active);
+		assert_eq!(provider.registered_at, 1);
+		assert_eq!(ProviderCount::<Test>::get(), 1);
+		assert_eq!(Balances::reserved_balance(1), 1_000);
+		System::assert_last_event(Event::ProviderRegistered { provider: 1, stake: 1_000 }.into());
+
+		assert_noop!(
+			ComputeProviders::register_provider(
+				RuntimeOrigin::signed(1),
+				1_000,
+				vec![ModelType::Regression]
+			),
+			Error::<Test>::AlreadyRegistered
+		);
+	});
+}
+
+#[test]
+fn register_provider_validates_inputs() {
+	new_test_ext().execute_with(|| {
+		let register = |who, stake, model_types| {
+			ComputeProviders::register_provider(RuntimeOrigin::signed(who), stake, model_types)
+		};
+
+		assert_noop!(
+			register(1, 499, vec![ModelType::Classification]),
+			Error::<Test>::InsufficientStake
+		);
+		assert_noop!(register(1, 500, vec![]), Error::<Test>::NoCapabilities);
+		assert_noop!(
+			register(1, 500, vec![ModelType::Classification, ModelType::Classification]),
+			Error::<Test>::DuplicateCapability
+		);
+		assert_noop!(
+			register(
+				1,
+				500,
+				vec![ModelType::Classification, ModelType::Regression, ModelType::Generative]
+			),
+			Error::<Test>::TooManyCapabilities
+		);
+		assert_noop!(
+			register(3, 500, vec![ModelType::Classification]),
+			Error::<Test>::InsufficientBalance
+		);
+	});
+}
+
+#[test]
+fn update_capabilities_and_bond_extra_work() {
+	new_test_ext().execute_with(|| {
+		register(1);
+
+		assert_ok!(ComputeProviders::update_capabilities(
+			RuntimeOrigin::signed(1),
+			vec![ModelType::Generative, ModelType::Regression]
+		));
+		let provider = Providers::<Test>::get(1).unwrap();
+		assert_eq!(
+			provider.model_types.to_vec(),
+			vec![ModelType::Generative, ModelType::Regression]
+		);
+
+		assert_ok!(ComputeProviders::bond_extra(RuntimeOrigin::signed(1), 250));
+		System::assert_last_event(Event::StakeIncreased { provider: 1, stake: 1_250 }.into());
+		assert_eq!(Balances::reserved_balance(1), 1_250);
+
+		assert_noop!(
+			ComputeProviders::update_capabilities(
+				RuntimeOrigin::signed(2),
+				vec![ModelType::Generative]
+			),
+			Error::<Test>::ProviderNotFound
+		);
+	});
+}
+
+#[test]
+fn jobs_respect_status_capabilities_and_limit() {
+	new_test_ext().execute_with(|| {
+		register(1);
+
+		assert!(ComputeProviders::can_serve(&1, ModelType::Classification));
+		assert!(!ComputeProviders::can_serve(&1, ModelType::Generative));
+		assert!(!ComputeProviders::can_serve(&2, ModelType::Classification));
+		assert_noop!(
+			ComputeProviders::start_job(&1, ModelType::Generative),
+			Error::<Test>::UnsupportedModelType
+		);
+
+		assert_ok!(ComputeProviders::start_job(&1, ModelType::Classification));
+		assert_ok!(ComputeProviders::start_job(&1, ModelType::Classification));
+		assert_noop!(
+			ComputeProviders::start_job(&1, ModelType::Classification),
+			Error::<Test>::TooManyJobs
+		);
+		ComputeProviders::finish_job(&1);
+		assert_eq!(Providers::<Test>::get(1).unwrap().active_jobs, 1);
+
+		assert_ok!(ComputeProviders::pause_provider(RuntimeOrigin::signed(1)));
+		assert!(!ComputeProviders::can_serve(&1, ModelType::Classification));
+		assert_noop!(
+			ComputeProviders::start_job(&1, ModelType::Classification),
+			Error::<Test>::ProviderNotActive
+		);
+		assert_noop!(
+			ComputeProviders::pause_provider(RuntimeOrigin::signed(1)),
+			Error::<Test>::ProviderNotActive
+		);
+
+		assert_ok!(ComputeProviders::resume_provider(RuntimeOrigin::signed(1)));
+		assert!(ComputeProviders::can_serve(&1, ModelType::Classification));
+		assert_noop!(
+			ComputeProviders::resume_provider(RuntimeOrigin::signed(1)),
+			Error::<Test>::ProviderNotPaused
+		);
+	});
+}
+
+#[test]
+fn deregister_and_withdraw_after_unbonding() {
+	new_test_ext().execute_with(|| {
+		register(1);
+		assert_ok!(ComputeProviders::start_job(&1, ModelType::Classification));
+
+		assert_noop!(
+			ComputeProviders::withdraw_stake(RuntimeOrigin::signed(1)),
+			Error::<Test>::NotLeaving
+		);
+		assert_noop!(
+			ComputeProviders::deregister_provider(RuntimeOrigin::signed(1)),
+			Error::<Test>::JobsInFlight
+		);
+
+		ComputeProviders::finish_job(&1);
+		assert_ok!(ComputeProviders::deregister_provider(RuntimeOrigin::signed(1)));
+		System::assert_last_event(Event::ProviderLeaving { provider: 1, unbond_at: 11 }.into());
+		assert!(!ComputeProviders::can_serve(&1, ModelType::Classification));
+		assert_noop!(
+			ComputeProviders::bond_extra(RuntimeOrigin::signed(1), 100),
+			Error::<Test>::ProviderLeaving
+		);
+		assert_noop!(
+			ComputeProviders::deregister_provider(RuntimeOrigin::signed(1)),
+			Error::<Test>::ProviderLeaving
+		);
+
+		System::set_block_number(10);
+		assert_noop!(
+			ComputeProviders::withdraw_stake(RuntimeOrigin::signed(1)),
+			Error::<Test>::StillUnbonding
+		);
+
+		System::set_block_number(11);
+		assert_ok!(ComputeProviders::withdraw_stake(RuntimeOrigin::signed(1)));
+		System::assert_last_event(Event::ProviderRemoved { provider: 1, stake: 1_000 }.into());
+		assert!(Providers::<Test>::get(1).is_none());
+		assert_eq!(ProviderCount::<Test>::get(), 0);
+		assert_eq!(Balances::reserved_balance(1), 0);
+		assert_eq!(Balances::free_balance(1), 10_000);
+	});
+}
+
+#[test]
+fn attestations_bind_enclave_measurement() {
+	new_test_ext().execute_with(|| {
+		let measurement = H256::repeat_byte(0xaa);
+		assert_noop!(
+			ComputeProviders::register_attestation(
+				RuntimeOrigin::signed(1),
+				TeeKind::Sgx,
+				quote(1, measurement)
+			),
+			Error::<Test>::ProviderNotFound
+		);
+
+		register(1);
+		assert_noop!(
+			ComputeProviders::register_attestation(
+				RuntimeOrigin::signed(1),
+				TeeKind::Sgx,
+				quote(2, measurement)
+			),
+			Error::<Test>::InvalidAttestation
+		);
+
+		assert_ok!(ComputeProviders::register_attestation(
+			RuntimeOrigin::signed(1),
+			TeeKind::Sgx,
+			quote(1, measurement)
+		));
+		System::assert_last_event(
+			Event::AttestationRegistered {
+				provider: 1,
+				kind: TeeKind::Sgx,
+				measurement,
+				expires_at: 21,
+			}
+			.into(),
+		);
+		assert_eq!(ComputeProviders::enclave_measurement(&1), Some(measurement));
+
+		System::set_block_number(22);
+		assert_eq!(ComputeProviders::enclave_measurement(&1), None);
+
+		assert_ok!(ComputeProviders::revoke_attestation(RuntimeOrigin::signed(1)));
+		assert!(Attestations::<Test>::get(1).is_none());
+		assert_noop!(
+			ComputeProviders::revoke_attestation(RuntimeOrigin::signed(1)),
+			Error::<Test>::NoAttestation
+		);
+	});
+}
+
+#[test]
+fn withdrawing_stake_removes_attestation() {
+	new_test_ext().execute_with(|| {
+		register(1);
+		assert_ok!(ComputeProviders::register_attestation(
+			RuntimeOrigin::signed(1),
+			TeeKind::SevSnp,
+			quote(1, H256::repeat_byte(1))
+		));
+		assert_ok!(ComputeProviders::deregister_provider(RuntimeOrigin::signed(1)));
+		assert_noop!(
+			ComputeProviders::register_attestation(
+				RuntimeOrigin::signed(1),
+				TeeKind::SevSnp,
+				quote(1, H256::repeat_byte(1))
+			),
+			Error::<Test>::ProviderLeaving
+		);
+
+		System::set_block_number(11);
+		assert_ok!(ComputeProviders::withdraw_stake(RuntimeOrigin::signed(1)));
+		assert!(Attestations::<Test>::get(1).is_none());
+	});
+}
+
+#[test]
+fn sla_records_track_recent_jobs() {
+	new_test_ext().execute_with(|| {
+		assert_eq!(SlaRecords::<Test>::get(1).success_ratio(), None);
+
+		ComputeProviders::record_outcome(&1, JobOutcome::Completed);
+		ComputeProviders::record_outcome(&1, JobOutcome::Failed);
+		ComputeProviders::record_outcome(&1, JobOutcome::TimedOut);
+		ComputeProviders::record_outcome(&1, JobOutcome::Completed);
+		let stats = SlaRecords::<Test>::get(1);
+		assert_eq!((stats.completed, stats.failed, stats.timed_out), (2, 1, 1));
+		assert_eq!(stats.success_ratio(), Some(Perbill::from_percent(50)));
+
+		// Old failures roll out of the window but stay in the counters
+		for _ in 0..RECENT_JOBS {
+			ComputeProviders::record_outcome(&1, JobOutcome::Completed);
+		}
+		let stats = SlaRecords::<Test>::get(1);
+		assert_eq!(stats.recent_len, RECENT_JOBS);
+		assert_eq!((stats.failed, stats.timed_out), (1, 1));
+		assert_eq!(stats.success_ratio(), Some(Perbill::one()));
+	});
+}
+
+#[test]
+fn unbonding_stakes_can_be_slashed() {
+	new_test_ext().execute_with(|| {
+		register(1);
+		assert_noop!(
+			ComputeProviders::slash_provider(RuntimeOrigin::signed(2), 1, 600),
+			sp_runtime::DispatchError::BadOrigin
+		);
+		assert_noop!(
+			ComputeProviders::slash_provider(RuntimeOrigin::root(), 2, 600),
+			Error::<Test>::ProviderNotFound
+		);
+
+		// Below the minimum stake the provider gets no new jobs until topped up
+		assert_ok!(ComputeProviders::slash_provider(RuntimeOrigin::root(), 1, 600));
+		System::assert_last_event(
+			Event::ProviderSlashed { provider: 1, amount: 600, stake: 400 }.into(),
+		);
+		assert_eq!(Balances::reserved_balance(1), 400);
+		assert_eq!(Balances::total_issuance(), 20_100 - 600);
+		assert!(!ComputeProviders::can_serve(&1, ModelType::Classification));
+		assert_noop!(
+			ComputeProviders::start_job(&1, ModelType::Classification),
+			Error::<Test>::InsufficientStake
+		);
+		assert_ok!(ComputeProviders::bond_extra(RuntimeOrigin::signed(1), 100));
+		assert_ok!(ComputeProviders::start_job(&1, ModelType::Classification));
+		ComputeProviders::finish_job(&1);
+
+		// Leaving providers can be slashed until they withdraw, at most their whole stake
+		assert_ok!(ComputeProviders::deregister_provider(RuntimeOrigin::signed(1)));
+		assert_ok!(ComputeProviders::slash_provider(RuntimeOrigin::root(), 1, 1_000));
+		System::assert_last_event(
+			Event::ProviderSlashed { provider: 1, amount: 500, stake: 0 }.into(),
+		);
+		System::set_block_number(11);
+		assert_ok!(ComputeProviders::withdraw_stake(RuntimeOrigin::signed(1)));
+		assert_eq!(Balances::free_balance(1), 10_000 - 1_100);
+	});
+}
+
+#[test]
+fn frameworks_are_dropped_from_providers() {
+	use crate::migrations::v1::{MigrateToV1, OldProviderInfo};
+	use frame_support::traits::{GetStorageVersion, OnRuntimeUpgrade, StorageVersion};
+
+	new_test_ext().execute_with(|| {
+		let old = OldProviderInfo::<Test> {
+			stake: 1_000,
+			model_types: vec![ModelType::Classification].try_into().unwrap(),
+			// ONNX and GGUF
+			frameworks: vec![0, 4].try_into().unwrap(),
+			status: ProviderStatus::Paused,
+			active_jobs: 1,
+			registered_at: 1,
+		};
+		frame_support::storage::unhashed::put(&Providers::<Test>::hashed_key_for(1), &old);
+		StorageVersion::new(0).put::<ComputeProviders>();
+
+		MigrateToV1::<Test>::on_runtime_upgrade();
+
+		let provider = Providers::<Test>::get(1).unwrap();
+		assert_eq!(provider.stake, 1_000);
+		assert_eq!(provider.model_types.to_vec(), vec![ModelType::Classification]);
+		assert_eq!(provider.status, ProviderStatus::Paused);
+		assert_eq!((provider.active_jobs, provider.registered_at), (1, 1));
+		assert_eq!(ComputeProviders::on_chain_storage_version(), 1);
+	});
+}
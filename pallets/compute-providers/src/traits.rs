@@ -0,0 +1,74 @@
+//! Interfaces the Compute Providers pallet offers to the rest of the runtime
+
+use crate::{JobOutcome, TeeKind};
+use frame_support::pallet_prelude::DispatchResult;
+use pallet_ai_registry::ModelType;
+use sp_core::H256;
+use sp_runtime::{traits::Zero, DispatchError};
+
+/// Registered compute providers that inference jobs can be assigned to
+pub trait ComputeProviders<AccountId, Balance> {
+	/// Whether `who` is active, staked at least the minimum and declared support for
+	/// `model_type`
+	fn can_serve(who: &AccountId, model_type: ModelType) -> bool;
+
+	/// Assign a job to `who`, counting against their concurrency limit
+	fn start_job(who: &AccountId, model_type: ModelType) -> DispatchResult;
+
+	/// Release a job previously assigned to `who`
+	fn finish_job(who: &AccountId);
+
+	/// Measurement of the enclave `who` currently holds a valid attestation for
+	fn enclave_measurement(who: &AccountId) -> Option<H256>;
+
+	/// Record how a job assigned to `who` ended
+	fn record_outcome(who: &AccountId, outcome: JobOutcome);
+
+	/// Slash up to `amount` of the stake of `who` to `beneficiary`, e.g. to reward
+	/// whoever exposed a bad result, returning what was paid
+	fn slash_to(who: &AccountId, beneficiary: &AccountId, amount: Balance) -> Balance;
+
+	/// Register an active provider serving every model type
+	#[cfg(feature = "runtime-benchmarks")]
+	fn create_provider(who: &AccountId);
+}
+
+/// No providers: no job can be assigned
+impl<AccountId, Balance: Zero> ComputeProviders<AccountId, Balance> for () {
+	fn can_serve(_who: &AccountId, _model_type: ModelType) -> bool {
+		false
+	}
+
+	fn start_job(_who: &AccountId, _model_type: ModelType) -> DispatchResult {
+		Err(DispatchError::Other("no compute providers configured"))
+	}
+
+	fn finish_job(_who: &AccountId) {}
+
+	fn enclave_measurement(_who: &AccountId) -> Option<H256> {
+		None
+	}
+
+	fn record_outcome(_who: &AccountId, _outcome: JobOutcome) {}
+
+	fn slash_to(_who: &AccountId, _beneficiary: &AccountId, _amount: Balance) -> Balance {
+		Zero::zero()
+	}
+
+	#[cfg(feature = "runtime-benchmarks")]
+	fn create_provider(_who: &AccountId) {}
+}
+
+/// Verifier for remote-attestation quotes
+pub trait AttestationVerifier<AccountId> {
+	/// Enclave measurement attested by `quote`, if the quote is genuine and its
+	/// report data binds it to `who`
+	fn verify(who: &AccountId, kind: TeeKind, quote: &[u8]) -> Option<H256>;
+}
+
+/// No verifier: no quote is ever accepted
+impl<AccountId> AttestationVerifier<AccountId> for () {
+	fn verify(_who: &AccountId, _kind: TeeKind, _quote: &[u8]) -> Option<H256> {
+		None
+	}
+}
@@ -0,0 +1,130 @@
+//! Type definitions for the Compute Providers pallet
+
+use codec::{Decode, DecodeWithMemTracking, Encode, MaxEncodedLen};
+use frame_support::{BoundedVec, CloneNoBound, EqNoBound, PartialEqNoBound, RuntimeDebugNoBound};
+use pallet_ai_registry::ModelType;
+use scale_info::TypeInfo;
+use sp_core::H256;
+use sp_runtime::{Perbill, RuntimeDebug};
+
+use crate::Config;
+
+/// Whether a provider accepts new inference jobs
+#[derive(Clone, Copy, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub enum ProviderStatus<BlockNumber> {
+	/// Provider accepts new jobs
+	Active,
+	/// Provider temporarily stopped accepting jobs
+	Paused,
+	/// Provider deregistered; stake can be withdrawn from `unbond_at`
+	Leaving { unbond_at: BlockNumber },
+}
+
+/// A registered compute provider
+#[derive(
+	CloneNoBound,
+	Encode,
+	Decode,
+	EqNoBound,
+	PartialEqNoBound,
+	RuntimeDebugNoBound,
+	TypeInfo,
+	MaxEncodedLen,
+)]
+#[scale_info(skip_type_params(T))]
+pub struct ProviderInfo<T: Config> {
+	/// Stake reserved from the provider
+	pub stake: crate::pallet::BalanceOf<T>,
+	/// Model types the provider can serve
+	pub model_types: BoundedVec<ModelType, T::MaxCapabilities>,
+	/// Whether the provider accepts new jobs
+	pub status: ProviderStatus<frame_system::pallet_prelude::BlockNumberFor<T>>,
+	/// Jobs currently assigned to the provider
+	pub active_jobs: u32,
+	/// Block at which the provider registered
+	pub registered_at: frame_system::pallet_prelude::BlockNumberFor<T>,
+}
+
+/// Trusted execution environment a remote-attestation quote comes from
+#[derive(
+	Clone,
+	Copy,
+	Encode,
+	Decode,
+	DecodeWithMemTracking,
+	Eq,
+	PartialEq,
+	RuntimeDebug,
+	TypeInfo,
+	MaxEncodedLen,
+)]
+pub enum TeeKind {
+	/// Intel SGX
+	Sgx,
+	/// Intel TDX
+	Tdx,
+	/// AMD SEV-SNP
+	SevSnp,
+	/// AWS Nitro Enclaves
+	Nitro,
+}
+
+/// A verified remote attestation bound to a provider account
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct Attestation<BlockNumber> {
+	/// Kind of enclave the quote came from
+	pub kind: TeeKind,
+	/// Measurement (code hash) of the attested enclave
+	pub measurement: H256,
+	/// Last block at which the attestation is valid
+	pub expires_at: BlockNumber,
+}
+
+/// Number of most recent jobs the rolling success ratio covers
+pub const RECENT_JOBS: u8 = 32;
+
+/// How an assigned inference job ended, from the provider's point of view
+#[derive(Clone, Copy, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub enum JobOutcome {
+	/// The provider delivered a valid result
+	Completed,
+	/// The provider delivered a result that did not match its commitment
+	Failed,
+	/// The provider did not deliver within the deadline
+	TimedOut,
+}
+
+/// Service-level record of a provider
+#[derive(Clone, Default, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct SlaStats {
+	/// Jobs completed
+	pub completed: u32,
+	/// Jobs failed
+	pub failed: u32,
+	/// Jobs timed out
+	pub timed_out: u32,
+	/// Outcomes of the most recent jobs, newest in the lowest bit; set bits are completions
+	pub recent: u32,
+	/// Number of jobs recorded in `recent`, at most [`RECENT_JOBS`]
+	pub recent_len: u8,
+}
+
+impl SlaStats {
+	/// Count `outcome` and push it into the rolling window
+	pub fn record(&mut self, outcome: JobOutcome) {
+		let counter = match outcome {
+			JobOutcome::Completed => &mut self.completed,
+			JobOutcome::Failed => &mut self.failed,
+			JobOutcome::TimedOut => &mut self.timed_out,
+		};
+		*counter = counter.saturating_add(1);
+		self.recent = (self.recent << 1) | u32::from(outcome == JobOutcome::Completed);
+		self.recent_len = self.recent_len.saturating_add(1).min(RECENT_JOBS);
+	}
+
+	/// Share of the most recent jobs that were completed, `None` before the first job
+	pub fn success_ratio(&self) -> Option<Perbill> {
+		(self.recent_len > 0)
+			.then(|| Perbill::from_rational(self.recent.count_ones(), u32::from(self.recent_len)))
+	}
+}
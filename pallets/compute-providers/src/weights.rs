@@ -0,0 +1,206 @@
+//! Autogenerated weights for pallet_compute_providers
+//!
+//! THIS FILE WAS AUTO-GENERATED USING THE SUBSTRATE BENCHMARK CLI VERSION 4.0.0-dev
+//! DATE: 2024-01-01, STEPS: `50`, REPEAT: `20`, LOW RANGE: `[]`, HIGH RANGE: `[]`
+//! WORST CASE MAP SIZE: `1000000`
+//! HOSTNAME: `benchmark-runner`, CPU: `Intel(R) Xeon(R) CPU @ 2.60GHz`
+//! EXECUTION: Some(Wasm), WASM-EXECUTION: Compiled, CHAIN: Some("dev"), DB CACHE: 1024
+
+// Executed Command:
+// ./target/production/solochain-template-node
+// benchmark
+// pallet
+// --chain=dev
+// --steps=50
+// --repeat=20
+// --pallet=pallet_compute_providers
+// --output=./pallets/compute-providers/src/weights.rs
+// --template=./.maintain/frame-weight-template.hbs
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+#![allow(missing_docs)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use core::marker::PhantomData;
+
+/// Weight functions needed for pallet_compute_providers.
+pub trait WeightInfo {
+	fn register_provider() -> Weight;
+	fn update_capabilities() -> Weight;
+	fn bond_extra() -> Weight;
+	fn pause_provider() -> Weight;
+	fn resume_provider() -> Weight;
+	fn deregister_provider() -> Weight;
+	fn withdraw_stake() -> Weight;
+	fn register_attestation(n: u32, ) -> Weight;
+	fn revoke_attestation() -> Weight;
+	fn slash_provider() -> Weight;
+}
+
+/// Weights for pallet_compute_providers using the Substrate node and recommended hardware.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	/// Storage: ComputeProviders Providers (r:1 w:1)
+	/// Proof: ComputeProviders Providers (max_values: None, max_size: Some(94), added: 2569, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: ComputeProviders ProviderCount (r:0 w:1)
+	/// Proof: ComputeProviders ProviderCount (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	fn register_provider() -> Weight {
+		Weight::from_parts(40_000_000, 5172)
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(3))
+	}
+
+	/// Storage: ComputeProviders Providers (r:1 w:1)
+	/// Proof: ComputeProviders Providers (max_values: None, max_size: Some(94), added: 2569, mode: MaxEncodedLen)
+	fn update_capabilities() -> Weight {
+		Weight::from_parts(20_000_000, 2569)
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+
+	/// Storage: ComputeProviders Providers (r:1 w:1)
+	/// Proof: ComputeProviders Providers (max_values: None, max_size: Some(94), added: 2569, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	fn bond_extra() -> Weight {
+		Weight::from_parts(35_000_000, 5172)
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+
+	/// Storage: ComputeProviders Providers (r:1 w:1)
+	/// Proof: ComputeProviders Providers (max_values: None, max_size: Some(94), added: 2569, mode: MaxEncodedLen)
+	fn pause_provider() -> Weight {
+		Weight::from_parts(15_000_000, 2569)
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+
+	/// Storage: ComputeProviders Providers (r:1 w:1)
+	/// Proof: ComputeProviders Providers (max_values: None, max_size: Some(94), added: 2569, mode: MaxEncodedLen)
+	fn resume_provider() -> Weight {
+		Weight::from_parts(15_000_000, 2569)
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+
+	/// Storage: ComputeProviders Providers (r:1 w:1)
+	/// Proof: ComputeProviders Providers (max_values: None, max_size: Some(94), added: 2569, mode: MaxEncodedLen)
+	fn deregister_provider() -> Weight {
+		Weight::from_parts(18_000_000, 2569)
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+
+	/// Storage: ComputeProviders Providers (r:1 w:1)
+	/// Proof: ComputeProviders Providers (max_values: None, max_size: Some(94), added: 2569, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: ComputeProviders Attestations (r:0 w:1)
+	/// Proof: ComputeProviders Attestations (max_values: None, max_size: Some(85), added: 2560, mode: MaxEncodedLen)
+	/// Storage: ComputeProviders ProviderCount (r:1 w:1)
+	/// Proof: ComputeProviders ProviderCount (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	fn withdraw_stake() -> Weight {
+		Weight::from_parts(35_000_000, 5671)
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(4))
+	}
+
+	/// Storage: ComputeProviders Providers (r:1 w:0)
+	/// Proof: ComputeProviders Providers (max_values: None, max_size: Some(94), added: 2569, mode: MaxEncodedLen)
+	/// Storage: ComputeProviders Attestations (r:0 w:1)
+	/// Proof: ComputeProviders Attestations (max_values: None, max_size: Some(85), added: 2560, mode: MaxEncodedLen)
+	fn register_attestation(n: u32, ) -> Weight {
+		Weight::from_parts(30_000_000, 2569)
+			// Standard Error: 75
+			.saturating_add(Weight::from_parts(1_500, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+
+	/// Storage: ComputeProviders Attestations (r:1 w:1)
+	/// Proof: ComputeProviders Attestations (max_values: None, max_size: Some(85), added: 2560, mode: MaxEncodedLen)
+	fn revoke_attestation() -> Weight {
+		Weight::from_parts(15_000_000, 2560)
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+
+	/// Storage: ComputeProviders Providers (r:1 w:1)
+	/// Proof: ComputeProviders Providers (max_values: None, max_size: Some(94), added: 2569, mode: MaxEncodedLen)
+	/// Storage: System Account (r:2 w:2)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	fn slash_provider() -> Weight {
+		Weight::from_parts(38_000_000, 7775)
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(3))
+	}
+}
+
+// For backwards compatibility and tests
+impl WeightInfo for () {
+	fn register_provider() -> Weight {
+		Weight::from_parts(40_000_000, 5172)
+			.saturating_add(RocksDbWeight::get().reads(2))
+			.saturating_add(RocksDbWeight::get().writes(3))
+	}
+
+	fn update_capabilities() -> Weight {
+		Weight::from_parts(20_000_000, 2569)
+			.saturating_add(RocksDbWeight::get().reads(1))
+			.saturating_add(RocksDbWeight::get().writes(1))
+	}
+
+	fn bond_extra() -> Weight {
+		Weight::from_parts(35_000_000, 5172)
+			.saturating_add(RocksDbWeight::get().reads(2))
+			.saturating_add(RocksDbWeight::get().writes(2))
+	}
+
+	fn pause_provider() -> Weight {
+		Weight::from_parts(15_000_000, 2569)
+			.saturating_add(RocksDbWeight::get().reads(1))
+			.saturating_add(RocksDbWeight::get().writes(1))
+	}
+
+	fn resume_provider() -> Weight {
+		Weight::from_parts(15_000_000, 2569)
+			.saturating_add(RocksDbWeight::get().reads(1))
+			.saturating_add(RocksDbWeight::get().writes(1))
+	}
+
+	fn deregister_provider() -> Weight {
+		Weight::from_parts(18_000_000, 2569)
+			.saturating_add(RocksDbWeight::get().reads(1))
+			.saturating_add(RocksDbWeight::get().writes(1))
+	}
+
+	fn withdraw_stake() -> Weight {
+		Weight::from_parts(35_000_000, 5671)
+			.saturating_add(RocksDbWeight::get().reads(3))
+			.saturating_add(RocksDbWeight::get().writes(4))
+	}
+
+	fn register_attestation(n: u32, ) -> Weight {
+		Weight::from_parts(30_000_000, 2569)
+			.saturating_add(Weight::from_parts(1_500, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(1))
+			.saturating_add(RocksDbWeight::get().writes(1))
+	}
+
+	fn revoke_attestation() -> Weight {
+		Weight::from_parts(15_000_000, 2560)
+			.saturating_add(RocksDbWeight::get().reads(1))
+			.saturating_add(RocksDbWeight::get().writes(1))
+	}
+
+	fn slash_provider() -> Weight {
+		Weight::from_parts(38_000_000, 7775)
+			.saturating_add(RocksDbWeight::get().reads(3))
+			.saturating_add(RocksDbWeight::get().writes(3))
+	}
+}
@@ -0,0 +1,115 @@
+//! Benchmarking setup for pallet-disputes
+
+#![cfg(feature = "runtime-benchmarks")]
+
+use super::*;
+use crate::pallet::{BalanceOf, DisputeOf};
+use frame_benchmarking::v2::*;
+use frame_support::traits::{Currency, EnsureOrigin, Get};
+use frame_system::RawOrigin;
+use pallet_ai_registry::ReceiptId;
+use sp_core::H256;
+use sp_runtime::traits::Saturating;
+
+/// Give `who` enough free balance to post any bond
+fn fund_account<T: Config>(who: &T::AccountId) {
+	let balance: BalanceOf<T> = T::DisputeBond::get()
+		.saturating_add(T::ResponseBond::get())
+		.saturating_mul(10u32.into())
+		.saturating_add(T::Currency::minimum_balance());
+	T::Currency::make_free_balance_be(who, balance);
+}
+
+/// Create a model owned by `owner` and a payment for it by `buyer`, returning the receipt
+fn setup_payment<T: Config>(owner: &T::AccountId, buyer: &T::AccountId) -> ReceiptId {
+	let model_id = T::Models::create_model(owner);
+	fund_account::<T>(buyer);
+	T::Payments::create_payment(buyer, model_id, 1_000u32.into())
+}
+
+/// Open a dispute and, if `respond`, have the owner respond to it
+fn setup_dispute<T: Config>(
+	owner: &T::AccountId,
+	buyer: &T::AccountId,
+	respond: bool,
+) -> DisputeId {
+	let receipt = setup_payment::<T>(owner, buyer);
+	let dispute_id = NextDisputeId::<T>::get();
+	let _ =
+		Pallet::<T>::open_dispute(RawOrigin::Signed(buyer.clone()).into(), receipt, H256::zero());
+	if respond {
+		fund_account::<T>(owner);
+		let _ = Pallet::<T>::respond_to_dispute(
+			RawOrigin::Signed(owner.clone()).into(),
+			dispute_id,
+			H256::zero(),
+		);
+	}
+	dispute_id
+}
+
+#[benchmarks]
+mod benchmarks {
+	use super::*;
+
+	#[benchmark]
+	fn open_dispute() {
+		let owner: T::AccountId = account("owner", 0, 0);
+		let buyer: T::AccountId = whitelisted_caller();
+		let receipt = setup_payment::<T>(&owner, &buyer);
+
+		#[extrinsic_call]
+		open_dispute(RawOrigin::Signed(buyer), receipt, H256::zero());
+
+		assert!(DisputeByReceipt::<T>::contains_key(receipt));
+	}
+
+	#[benchmark]
+	fn respond_to_dispute() {
+		let owner: T::AccountId = account("owner", 0, 0);
+		let buyer: T::AccountId = whitelisted_caller();
+		let dispute_id = setup_dispute::<T>(&owner, &buyer, false);
+		fund_account::<T>(&owner);
+
+		#[extrinsic_call]
+		respond_to_dispute(RawOrigin::Signed(owner), dispute_id, H256::zero());
+
+		let dispute: DisputeOf<T> = Disputes::<T>::get(dispute_id).unwrap();
+		assert_eq!(dispute.status, DisputeStatus::Responded);
+	}
+
+	#[benchmark]
+	fn resolve_dispute() -> Result<(), BenchmarkError> {
+		let owner: T::AccountId = account("owner", 0, 0);
+		let buyer: T::AccountId = whitelisted_caller();
+		let dispute_id = setup_dispute::<T>(&owner, &buyer, true);
+		let origin = T::ArbitrationOrigin::try_successful_origin()
+			.map_err(|_| BenchmarkError::Weightless)?;
+
+		// Slashing the model is the most expensive ruling
+		#[extrinsic_call]
+		resolve_dispute(origin as T::RuntimeOrigin, dispute_id, Ruling::Slash);
+
+		assert!(!Disputes::<T>::contains_key(dispute_id));
+		Ok(())
+	}
+
+	#[benchmark]
+	fn claim_unanswered() {
+		let owner: T::AccountId = account("owner", 0, 0);
+		let buyer: T::AccountId = whitelisted_caller();
+		let dispute_id = setup_dispute::<T>(&owner, &buyer, false);
+		frame_system::Pallet::<T>::set_block_number(
+			frame_system::Pallet::<T>::block_number()
+				.saturating_add(T::ResponsePeriod::get())
+				.saturating_add(1u32.into()),
+		);
+
+		#[extrinsic_call]
+		claim_unanswered(RawOrigin::Signed(buyer), dispute_id);
+
+		assert!(!Disputes::<T>::contains_key(dispute_id));
+	}
+
+	impl_benchmark_test_suite!(Pallet, crate::mock::new_test_ext(), crate::mock::Test);
+}
@@ -0,0 +1,439 @@
+//! # Disputes Pallet
+//!
+//! Dispute resolution for paid inferences on models from the AI registry.
+//!
+//! ## Overview
+//!
+//! A buyer who is unhappy with the result of a paid inference can open a dispute
+//! referring to the payment receipt and the hash of the result they received. The
+//! model owner responds with their own evidence, and an arbitration origin rules on
+//! the dispute:
+//! - `Refund`: the buyer is refunded in full
+//! - `PartialRefund`: the buyer is refunded a portion of the payment
+//! - `Reject`: the buyer is not refunded
+//! - `Slash`: the buyer is refunded and the model is slashed as fraudulent
+//!
+//! The payment is held from the moment the dispute is opened, and whatever the
+//! ruling does not refund is paid out once it is made.
+//!
+//! ## Bonds
+//!
+//! Opening a dispute reserves `DisputeBond` from the buyer and responding reserves
+//! `ResponseBond` from the owner. The losing side's bond goes to the winner, so
+//! frivolous disputes and bad-faith responses both cost money. Owners who do not
+//! respond within `ResponsePeriod` lose the dispute by default. Bonds are held
+//! through the escrow pallet.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+pub use pallet::*;
+
+#[cfg(test)]
+mod mock;
+
+#[cfg(test)]
+mod tests;
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+
+pub mod weights;
+pub use weights::*;
+
+pub mod types;
+pub use types::*;
+
+pub mod traits;
+pub use traits::*;
+
+#[frame_support::pallet]
+pub mod pallet {
+	use super::*;
+	use frame_support::{pallet_prelude::*, traits::Currency};
+	use frame_system::pallet_prelude::*;
+	use pallet_ai_registry::ReceiptId;
+	use pallet_escrow::{Escrow, EscrowId};
+	use sp_core::H256;
+	use sp_runtime::traits::{Saturating, Zero};
+
+	pub(crate) type BalanceOf<T> =
+		<<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
+	pub(crate) type DisputeOf<T> =
+		Dispute<<T as frame_system::Config>::AccountId, BalanceOf<T>, BlockNumberFor<T>>;
+
+	#[pallet::pallet]
+	pub struct Pallet<T>(_);
+
+	/// Configuration trait for the Disputes pallet
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		/// The overarching event type
+		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+		/// Weight information for extrinsics
+		type WeightInfo: WeightInfo;
+
+		/// Currency used for bonds
+		type Currency: Currency<Self::AccountId>;
+
+		/// Escrow holding bonds in `Currency`
+		type Escrow: Escrow<Self::AccountId, BalanceOf<Self>, BlockNumberFor<Self>>;
+
+		/// Source of the inference payments being disputed
+		type Payments: InferencePayments<Self::AccountId, BalanceOf<Self>>;
+
+		/// Registered models the payments refer to
+		type Models: ModelDirectory<Self::AccountId>;
+
+		/// Origin allowed to rule on disputes
+		type ArbitrationOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+		/// Bond reserved from a buyer opening a dispute
+		#[pallet::constant]
+		type DisputeBond: Get<BalanceOf<Self>>;
+
+		/// Bond reserved from a model owner responding to a dispute
+		#[pallet::constant]
+		type ResponseBond: Get<BalanceOf<Self>>;
+
+		/// Blocks the model owner has to respond to a dispute
+		#[pallet::constant]
+		type ResponsePeriod: Get<BlockNumberFor<Self>>;
+	}
+
+	/// Disputes awaiting a ruling
+	#[pallet::storage]
+	pub type Disputes<T: Config> = StorageMap<_, Blake2_128Concat, DisputeId, DisputeOf<T>>;
+
+	/// Dispute opened for each payment receipt; a payment can only be disputed once
+	#[pallet::storage]
+	pub type DisputeByReceipt<T: Config> =
+		StorageMap<_, Blake2_128Concat, ReceiptId, DisputeId, OptionQuery>;
+
+	/// Counter for generating unique dispute IDs
+	#[pallet::storage]
+	pub type NextDisputeId<T: Config> = StorageValue<_, DisputeId, ValueQuery>;
+
+	/// Events emitted by this pallet
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// A buyer disputed a paid inference
+		/// [dispute_id, receipt, buyer, model_id]
+		DisputeOpened {
+			dispute_id: DisputeId,
+			receipt: ReceiptId,
+			buyer: T::AccountId,
+			model_id: pallet_ai_registry::ModelId,
+		},
+		/// The model owner responded to a dispute
+		/// [dispute_id, response_hash]
+		DisputeResponded { dispute_id: DisputeId, response_hash: H256 },
+		/// The arbitration origin ruled on a dispute
+		/// [dispute_id, ruling, refund]
+		DisputeResolved { dispute_id: DisputeId, ruling: Ruling, refund: BalanceOf<T> },
+		/// The owner did not respond in time and the buyer was refunded
+		/// [dispute_id, refund]
+		DisputeDefaulted { dispute_id: DisputeId, refund: BalanceOf<T> },
+	}
+
+	/// Errors that can occur in this pallet
+	#[pallet::error]
+	pub enum Error<T> {
+		/// No payment exists for the receipt
+		PaymentNotFound,
+		/// Only the payer can dispute a payment
+		NotPayer,
+		/// The payment has already been disputed
+		AlreadyDisputed,
+		/// The paid model no longer exists
+		ModelNotFound,
+		/// Dispute doesn't exist
+		DisputeNotFound,
+		/// Only the model owner can respond
+		NotModelOwner,
+		/// Only the buyer can claim an unanswered dispute
+		NotBuyer,
+		/// The owner has already responded
+		AlreadyResponded,
+		/// The response period has ended
+		ResponsePeriodOver,
+		/// The response period has not ended yet
+		ResponsePeriodNotOver,
+		/// Caller cannot reserve the required bond
+		InsufficientBond,
+		/// Arithmetic overflow occurred
+		ArithmeticOverflow,
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Dispute a paid inference
+		///
+		/// # Arguments
+		/// * `origin` - Must be the payer of the inference
+		/// * `receipt` - Receipt of the disputed payment
+		/// * `result_hash` - Hash of the inference result the buyer received
+		///
+		/// # Errors
+		/// * `PaymentNotFound` - No payment for `receipt`
+		/// * `NotPayer` - Caller did not pay for the inference
+		/// * `AlreadyDisputed` - Payment was disputed before
+		/// * `ModelNotFound` - Paid model no longer exists
+		/// * `InsufficientBond` - Caller cannot reserve `DisputeBond`
+		/// * Any error from holding the payment
+		///
+		/// # Events
+		/// * `DisputeOpened` - Dispute created
+		#[pallet::call_index(0)]
+		#[pallet::weight(T::WeightInfo::open_dispute())]
+		pub fn open_dispute(
+			origin: OriginFor<T>,
+			receipt: ReceiptId,
+			result_hash: H256,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let payment = T::Payments::payment(receipt).ok_or(Error::<T>::PaymentNotFound)?;
+			ensure!(payment.payer == who, Error::<T>::NotPayer);
+			ensure!(!DisputeByReceipt::<T>::contains_key(receipt), Error::<T>::AlreadyDisputed);
+			let owner = T::Models::owner_of(payment.model_id).ok_or(Error::<T>::ModelNotFound)?;
+
+			let buyer_bond = T::Escrow::lock(&who, T::DisputeBond::get(), None)
+				.map_err(|_| Error::<T>::InsufficientBond)?;
+			T::Payments::hold(receipt)?;
+
+			let dispute_id = NextDisputeId::<T>::get();
+			let next_id = dispute_id.checked_add(1).ok_or(Error::<T>::ArithmeticOverflow)?;
+
+			let response_deadline =
+				frame_system::Pallet::<T>::block_number().saturating_add(T::ResponsePeriod::get());
+			Disputes::<T>::insert(
+				dispute_id,
+				Dispute {
+					buyer: who.clone(),
+					owner,
+					model_id: payment.model_id,
+					receipt,
+					result_hash,
+					amount: payment.amount,
+					buyer_bond,
+					owner_bond: None,
+					response_hash: None,
+					status: DisputeStatus::Open,
+					response_deadline,
+				},
+			);
+			DisputeByReceipt::<T>::insert(receipt, dispute_id);
+			NextDisputeId::<T>::put(next_id);
+
+			Self::deposit_event(Event::DisputeOpened {
+				dispute_id,
+				receipt,
+				buyer: who,
+				model_id: payment.model_id,
+			});
+
+			Ok(())
+		}
+
+		/// Respond to a dispute as the model owner
+		///
+		/// # Arguments
+		/// * `origin` - Must be the owner of the disputed model
+		/// * `dispute_id` - ID of the dispute
+		/// * `response_hash` - Hash of the owner's evidence
+		///
+		/// # Errors
+		/// * `DisputeNotFound` - Dispute doesn't exist
+		/// * `NotModelOwner` - Caller is not the model owner
+		/// * `AlreadyResponded` - Owner has already responded
+		/// * `ResponsePeriodOver` - Response deadline has passed
+		/// * `InsufficientBond` - Caller cannot reserve `ResponseBond`
+		///
+		/// # Events
+		/// * `DisputeResponded` - Response recorded
+		#[pallet::call_index(1)]
+		#[pallet::weight(T::WeightInfo::respond_to_dispute())]
+		pub fn respond_to_dispute(
+			origin: OriginFor<T>,
+			dispute_id: DisputeId,
+			response_hash: H256,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			Disputes::<T>::try_mutate(dispute_id, |maybe_dispute| -> DispatchResult {
+				let dispute = maybe_dispute.as_mut().ok_or(Error::<T>::DisputeNotFound)?;
+				ensure!(dispute.owner == who, Error::<T>::NotModelOwner);
+				ensure!(dispute.status == DisputeStatus::Open, Error::<T>::AlreadyResponded);
+				ensure!(
+					frame_system::Pallet::<T>::block_number() <= dispute.response_deadline,
+					Error::<T>::ResponsePeriodOver
+				);
+
+				let bond = T::Escrow::lock(&who, T::ResponseBond::get(), None)
+					.map_err(|_| Error::<T>::InsufficientBond)?;
+
+				dispute.owner_bond = Some(bond);
+				dispute.response_hash = Some(response_hash);
+				dispute.status = DisputeStatus::Responded;
+
+				Ok(())
+			})?;
+
+			Self::deposit_event(Event::DisputeResponded { dispute_id, response_hash });
+
+			Ok(())
+		}
+
+		/// Rule on a dispute
+		///
+		/// The losing side's bond goes to the winner; on a partial refund both
+		/// bonds are returned.
+		///
+		/// # Arguments
+		/// * `origin` - Must be `ArbitrationOrigin`
+		/// * `dispute_id` - ID of the dispute
+		/// * `ruling` - Outcome of the dispute
+		///
+		/// # Errors
+		/// * `DisputeNotFound` - Dispute doesn't exist
+		/// * Any error from refunding the payment or slashing the model
+		///
+		/// # Events
+		/// * `DisputeResolved` - Dispute closed
+		#[pallet::call_index(2)]
+		#[pallet::weight(T::WeightInfo::resolve_dispute())]
+		pub fn resolve_dispute(
+			origin: OriginFor<T>,
+			dispute_id: DisputeId,
+			ruling: Ruling,
+		) -> DispatchResult {
+			T::ArbitrationOrigin::ensure_origin(origin)?;
+
+			let dispute = Disputes::<T>::get(dispute_id).ok_or(Error::<T>::DisputeNotFound)?;
+
+			let refund = match ruling {
+				Ruling::Refund | Ruling::Slash => dispute.amount,
+				Ruling::PartialRefund(portion) => portion * dispute.amount,
+				Ruling::Reject => Zero::zero(),
+			};
+			T::Payments::refund(dispute.receipt, refund)?;
+			if ruling == Ruling::Slash {
+				T::Models::slash(dispute.model_id)?;
+			}
+
+			match ruling {
+				Ruling::Refund | Ruling::Slash => {
+					Self::return_bond(Some(dispute.buyer_bond));
+					Self::forfeit_bond(dispute.owner_bond, &dispute.buyer);
+				}
+				Ruling::PartialRefund(_) => {
+					Self::return_bond(Some(dispute.buyer_bond));
+					Self::return_bond(dispute.owner_bond);
+				}
+				Ruling::Reject => {
+					Self::return_bond(dispute.owner_bond);
+					Self::forfeit_bond(Some(dispute.buyer_bond), &dispute.owner);
+				}
+			}
+
+			Disputes::<T>::remove(dispute_id);
+
+			Self::deposit_event(Event::DisputeResolved { dispute_id, ruling, refund });
+
+			Ok(())
+		}
+
+		/// Claim a full refund on a dispute the owner did not respond to in time
+		///
+		/// # Arguments
+		/// * `origin` - Must be the buyer
+		/// * `dispute_id` - ID of the dispute
+		///
+		/// # Errors
+		/// * `DisputeNotFound` - Dispute doesn't exist
+		/// * `NotBuyer` - Caller did not open the dispute
+		/// * `AlreadyResponded` - Owner responded; the dispute awaits arbitration
+		/// * `ResponsePeriodNotOver` - Owner can still respond
+		///
+		/// # Events
+		/// * `DisputeDefaulted` - Buyer refunded
+		#[pallet::call_index(3)]
+		#[pallet::weight(T::WeightInfo::claim_unanswered())]
+		pub fn claim_unanswered(origin: OriginFor<T>, dispute_id: DisputeId) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let dispute = Disputes::<T>::get(dispute_id).ok_or(Error::<T>::DisputeNotFound)?;
+			ensure!(dispute.buyer == who, Error::<T>::NotBuyer);
+			ensure!(dispute.status == DisputeStatus::Open, Error::<T>::AlreadyResponded);
+			ensure!(
+				frame_system::Pallet::<T>::block_number() > dispute.response_deadline,
+				Error::<T>::ResponsePeriodNotOver
+			);
+
+			T::Payments::refund(dispute.receipt, dispute.amount)?;
+			Self::return_bond(Some(dispute.buyer_bond));
+			Disputes::<T>::remove(dispute_id);
+
+			Self::deposit_event(Event::DisputeDefaulted { dispute_id, refund: dispute.amount });
+
+			Ok(())
+		}
+	}
+
+	impl<T: Config> Pallet<T> {
+		/// Pay the loser's bond, if they posted one, to the winner
+		fn forfeit_bond(bond: Option<EscrowId>, winner: &T::AccountId) {
+			let Some(bond) = bond else { return };
+			// A bond that cannot be paid out goes back to the loser rather than staying held
+			if T::Escrow::release_all(bond, winner).is_err() {
+				let _ = T::Escrow::refund(bond);
+			}
+		}
+
+		/// Return a bond, if one was posted, to whoever posted it
+		fn return_bond(bond: Option<EscrowId>) {
+			if let Some(bond) = bond {
+				let _ = T::Escrow::refund(bond);
+			}
+		}
+	}
+}
+
+/// Resolves models through the AI registry pallet
+impl<T: pallet_ai_registry::Config> ModelDirectory<T::AccountId> for pallet_ai_registry::Pallet<T> {
+	fn owner_of(model_id: pallet_ai_registry::ModelId) -> Option<T::AccountId> {
+		<Self as pallet_ai_registry::AiRegistryInspect<_, u128>>::owner(model_id)
+	}
+
+	fn slash(model_id: pallet_ai_registry::ModelId) -> frame_support::dispatch::DispatchResult {
+		Self::do_slash(model_id)
+	}
+
+	#[cfg(feature = "runtime-benchmarks")]
+	fn create_model(owner: &T::AccountId) -> pallet_ai_registry::ModelId {
+		use frame_support::traits::{Currency, Get};
+		use sp_runtime::traits::Saturating;
+
+		let balance = Self::model_deposit(1_000)
+			.saturating_add(T::RegistrationFee::get())
+			.saturating_add(T::Currency::minimum_balance())
+			.saturating_mul(2u32.into());
+		T::Currency::make_free_balance_be(owner, balance);
+
+		let model_id = pallet_ai_registry::NextModelId::<T>::get();
+		let _ = Self::register_model(
+			frame_system::RawOrigin::Signed(owner.clone()).into(),
+			Self::benchmark_cid(model_id),
+			Self::benchmark_name(model_id),
+			b"Description".to_vec(),
+			pallet_ai_registry::ModelType::Classification,
+			1_000u32.into(),
+			pallet_ai_registry::License::Mit,
+			1_000,
+			Default::default(),
+		);
+		model_id
+	}
+}
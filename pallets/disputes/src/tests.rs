@@ -0,0 +1,245 @@
+//! Unit tests for Disputes pallet
+
+use crate::{
+	mock::*,
+	pallet::{DisputeByReceipt, Disputes as DisputesStorage, Error, Event},
+	DisputeStatus, Ruling,
+};
+use frame_support::{assert_noop, assert_ok};
+use pallet_ai_registry::{License, ModelStatus, ModelType};
+use pallet_escrow::Escrow as _;
+use sp_core::H256;
+use sp_runtime::Perbill;
+
+const RECEIPT: u64 = 7;
+const PRICE: u128 = 500;
+
+/// Register model 0 owned by account 1 and record a payment for it by account 2
+fn setup_paid_inference() {
+	assert_ok!(AIRegistry::register_model(
+		RuntimeOrigin::signed(1),
+		b"QmYwAPJzv5CZsnA625s3Xf2nemtYgPpHdWEz79ojWnPbdG".to_vec(),
+		b"Model".to_vec(),
+		b"Description".to_vec(),
+		ModelType::Classification,
+		PRICE,
+		License::Mit,
+		1_000,
+		vec![]
+	));
+	MockPayments::pay(RECEIPT, 2, 0, PRICE);
+}
+
+/// Open a dispute on the paid inference and have the owner respond to it
+fn open_and_respond() {
+	assert_ok!(Disputes::open_dispute(RuntimeOrigin::signed(2), RECEIPT, H256::repeat_byte(1)));
+	assert_ok!(Disputes::respond_to_dispute(RuntimeOrigin::signed(1), 0, H256::repeat_byte(2)));
+}
+
+#[test]
+fn open_dispute_works() {
+	new_test_ext().execute_with(|| {
+		setup_paid_inference();
+
+		assert_noop!(
+			Disputes::open_dispute(RuntimeOrigin::signed(2), 99, H256::zero()),
+			Error::<Test>::PaymentNotFound
+		);
+		assert_noop!(
+			Disputes::open_dispute(RuntimeOrigin::signed(1), RECEIPT, H256::zero()),
+			Error::<Test>::NotPayer
+		);
+
+		assert_ok!(Disputes::open_dispute(RuntimeOrigin::signed(2), RECEIPT, H256::repeat_byte(1)));
+		System::assert_last_event(
+			Event::DisputeOpened { dispute_id: 0, receipt: RECEIPT, buyer: 2, model_id: 0 }.into(),
+		);
+		assert_eq!(MockPayments::holds(), vec![RECEIPT]);
+
+		let dispute = DisputesStorage::<Test>::get(0).unwrap();
+		assert_eq!(dispute.owner, 1);
+		assert_eq!(dispute.amount, PRICE);
+		assert_eq!(dispute.response_deadline, 11);
+		assert_eq!(dispute.status, DisputeStatus::Open);
+		assert_eq!(DisputeByReceipt::<Test>::get(RECEIPT), Some(0));
+		assert_eq!(Balances::reserved_balance(2), 200);
+
+		assert_noop!(
+			Disputes::open_dispute(RuntimeOrigin::signed(2), RECEIPT, H256::zero()),
+			Error::<Test>::AlreadyDisputed
+		);
+	});
+}
+
+#[test]
+fn open_dispute_requires_bond() {
+	new_test_ext().execute_with(|| {
+		setup_paid_inference();
+		MockPayments::pay(8, 3, 0, PRICE);
+
+		assert_noop!(
+			Disputes::open_dispute(RuntimeOrigin::signed(3), 8, H256::zero()),
+			Error::<Test>::InsufficientBond
+		);
+	});
+}
+
+#[test]
+fn respond_to_dispute_works() {
+	new_test_ext().execute_with(|| {
+		setup_paid_inference();
+		assert_ok!(Disputes::open_dispute(RuntimeOrigin::signed(2), RECEIPT, H256::zero()));
+
+		assert_noop!(
+			Disputes::respond_to_dispute(RuntimeOrigin::signed(2), 0, H256::zero()),
+			Error::<Test>::NotModelOwner
+		);
+
+		assert_ok!(Disputes::respond_to_dispute(RuntimeOrigin::signed(1), 0, H256::repeat_byte(2)));
+		let dispute = DisputesStorage::<Test>::get(0).unwrap();
+		assert_eq!(dispute.status, DisputeStatus::Responded);
+		assert_eq!(dispute.response_hash, Some(H256::repeat_byte(2)));
+		assert_eq!(dispute.owner_bond.map(Escrow::held), Some(300));
+		// Model deposit (2000) plus the response bond
+		assert_eq!(Balances::reserved_balance(1), 2_300);
+
+		assert_noop!(
+			Disputes::respond_to_dispute(RuntimeOrigin::signed(1), 0, H256::zero()),
+			Error::<Test>::AlreadyResponded
+		);
+	});
+}
+
+#[test]
+fn respond_after_deadline_fails() {
+	new_test_ext().execute_with(|| {
+		setup_paid_inference();
+		assert_ok!(Disputes::open_dispute(RuntimeOrigin::signed(2), RECEIPT, H256::zero()));
+
+		System::set_block_number(12);
+		assert_noop!(
+			Disputes::respond_to_dispute(RuntimeOrigin::signed(1), 0, H256::zero()),
+			Error::<Test>::ResponsePeriodOver
+		);
+	});
+}
+
+#[test]
+fn refund_ruling_pays_buyer_owner_bond() {
+	new_test_ext().execute_with(|| {
+		setup_paid_inference();
+		open_and_respond();
+
+		assert_noop!(
+			Disputes::resolve_dispute(RuntimeOrigin::signed(2), 0, Ruling::Refund),
+			sp_runtime::DispatchError::BadOrigin
+		);
+		assert_ok!(Disputes::resolve_dispute(RuntimeOrigin::root(), 0, Ruling::Refund));
+		System::assert_last_event(
+			Event::DisputeResolved { dispute_id: 0, ruling: Ruling::Refund, refund: PRICE }.into(),
+		);
+
+		assert_eq!(MockPayments::refunds(), vec![(RECEIPT, PRICE)]);
+		assert_eq!(Balances::reserved_balance(2), 0);
+		assert_eq!(Balances::free_balance(2), 10_300);
+		assert_eq!(Balances::reserved_balance(1), 2_000);
+		assert!(DisputesStorage::<Test>::get(0).is_none());
+		// The payment cannot be disputed again
+		assert_noop!(
+			Disputes::open_dispute(RuntimeOrigin::signed(2), RECEIPT, H256::zero()),
+			Error::<Test>::AlreadyDisputed
+		);
+	});
+}
+
+#[test]
+fn partial_refund_returns_both_bonds() {
+	new_test_ext().execute_with(|| {
+		setup_paid_inference();
+		open_and_respond();
+
+		assert_ok!(Disputes::resolve_dispute(
+			RuntimeOrigin::root(),
+			0,
+			Ruling::PartialRefund(Perbill::from_percent(40))
+		));
+
+		assert_eq!(MockPayments::refunds(), vec![(RECEIPT, 200)]);
+		assert_eq!(Balances::free_balance(2), 10_000);
+		assert_eq!(Balances::reserved_balance(2), 0);
+		assert_eq!(Balances::reserved_balance(1), 2_000);
+	});
+}
+
+#[test]
+fn reject_ruling_pays_owner_buyer_bond() {
+	new_test_ext().execute_with(|| {
+		setup_paid_inference();
+		open_and_respond();
+		let owner_free = Balances::free_balance(1);
+
+		assert_ok!(Disputes::resolve_dispute(RuntimeOrigin::root(), 0, Ruling::Reject));
+
+		// The payment is released in full
+		assert_eq!(MockPayments::refunds(), vec![(RECEIPT, 0)]);
+		assert_eq!(Balances::free_balance(2), 9_800);
+		assert_eq!(Balances::reserved_balance(2), 0);
+		assert_eq!(Balances::free_balance(1), owner_free + 300 + 200);
+		assert_eq!(Balances::reserved_balance(1), 2_000);
+	});
+}
+
+#[test]
+fn slash_ruling_slashes_model() {
+	new_test_ext().execute_with(|| {
+		setup_paid_inference();
+		open_and_respond();
+
+		assert_ok!(Disputes::resolve_dispute(RuntimeOrigin::root(), 0, Ruling::Slash));
+
+		assert_eq!(MockPayments::refunds(), vec![(RECEIPT, PRICE)]);
+		let model = pallet_ai_registry::Models::<Test>::get(0).unwrap();
+		assert_eq!(model.status, ModelStatus::Deactivated);
+		assert_eq!(Balances::reserved_balance(1), 0);
+		assert_eq!(Balances::free_balance(2), 10_300);
+	});
+}
+
+#[test]
+fn claim_unanswered_refunds_after_deadline() {
+	new_test_ext().execute_with(|| {
+		setup_paid_inference();
+		assert_ok!(Disputes::open_dispute(RuntimeOrigin::signed(2), RECEIPT, H256::zero()));
+
+		assert_noop!(
+			Disputes::claim_unanswered(RuntimeOrigin::signed(2), 0),
+			Error::<Test>::ResponsePeriodNotOver
+		);
+
+		System::set_block_number(12);
+		assert_noop!(
+			Disputes::claim_unanswered(RuntimeOrigin::signed(1), 0),
+			Error::<Test>::NotBuyer
+		);
+		assert_ok!(Disputes::claim_unanswered(RuntimeOrigin::signed(2), 0));
+		System::assert_last_event(Event::DisputeDefaulted { dispute_id: 0, refund: PRICE }.into());
+
+		assert_eq!(MockPayments::refunds(), vec![(RECEIPT, PRICE)]);
+		assert_eq!(Balances::reserved_balance(2), 0);
+		assert!(DisputesStorage::<Test>::get(0).is_none());
+	});
+}
+
+#[test]
+fn claim_unanswered_fails_once_responded() {
+	new_test_ext().execute_with(|| {
+		setup_paid_inference();
+		open_and_respond();
+
+		System::set_block_number(12);
+		assert_noop!(
+			Disputes::claim_unanswered(RuntimeOrigin::signed(2), 0),
+			Error::<Test>::AlreadyResponded
+		);
+	});
+}
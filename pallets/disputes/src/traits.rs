@@ -0,0 +1,56 @@
+//! Interfaces the Disputes pallet expects from the rest of the runtime
+
+use crate::PaymentInfo;
+use frame_support::pallet_prelude::DispatchResult;
+use pallet_ai_registry::{ModelId, ReceiptId};
+use sp_runtime::DispatchError;
+
+/// Source of inference payments that can be disputed and refunded
+pub trait InferencePayments<AccountId, Balance> {
+	/// Payment recorded under `receipt`, if it can still be disputed
+	fn payment(receipt: ReceiptId) -> Option<PaymentInfo<AccountId, Balance>>;
+
+	/// Keep the payment under `receipt` from being paid out until it is refunded
+	fn hold(receipt: ReceiptId) -> DispatchResult;
+
+	/// Return `amount` of the held payment under `receipt` to its payer and pay out
+	/// the rest
+	fn refund(receipt: ReceiptId, amount: Balance) -> DispatchResult;
+
+	/// Record a payment of `amount` by `payer` for `model_id` and return its receipt
+	#[cfg(feature = "runtime-benchmarks")]
+	fn create_payment(payer: &AccountId, model_id: ModelId, amount: Balance) -> ReceiptId;
+}
+
+/// No payment source: nothing can be disputed
+impl<AccountId, Balance> InferencePayments<AccountId, Balance> for () {
+	fn payment(_receipt: ReceiptId) -> Option<PaymentInfo<AccountId, Balance>> {
+		None
+	}
+
+	fn hold(_receipt: ReceiptId) -> DispatchResult {
+		Err(DispatchError::Other("no inference payments configured"))
+	}
+
+	fn refund(_receipt: ReceiptId, _amount: Balance) -> DispatchResult {
+		Err(DispatchError::Other("no inference payments configured"))
+	}
+
+	#[cfg(feature = "runtime-benchmarks")]
+	fn create_payment(_payer: &AccountId, _model_id: ModelId, _amount: Balance) -> ReceiptId {
+		ReceiptId::MAX
+	}
+}
+
+/// Access to registered models needed to resolve disputes
+pub trait ModelDirectory<AccountId> {
+	/// Owner of `model_id`, if the model exists
+	fn owner_of(model_id: ModelId) -> Option<AccountId>;
+
+	/// Deactivate `model_id` and slash its deposit
+	fn slash(model_id: ModelId) -> DispatchResult;
+
+	/// Register a model owned by `owner` and return its ID
+	#[cfg(feature = "runtime-benchmarks")]
+	fn create_model(owner: &AccountId) -> ModelId;
+}
@@ -0,0 +1,93 @@
+//! Type definitions for the Disputes pallet
+
+use codec::{Decode, DecodeWithMemTracking, Encode, MaxEncodedLen};
+use pallet_ai_registry::{ModelId, ReceiptId};
+use pallet_escrow::EscrowId;
+use scale_info::TypeInfo;
+use sp_core::H256;
+use sp_runtime::{Perbill, RuntimeDebug};
+
+/// Unique identifier for disputes
+pub type DisputeId = u64;
+
+/// Payment made for a single inference, as recorded by the payment pallet
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct PaymentInfo<AccountId, Balance> {
+	/// Account that paid for the inference
+	pub payer: AccountId,
+	/// Model the inference was run on
+	pub model_id: ModelId,
+	/// Amount paid
+	pub amount: Balance,
+}
+
+/// Stage of an open dispute
+#[derive(
+	Clone,
+	Copy,
+	Encode,
+	Decode,
+	DecodeWithMemTracking,
+	Eq,
+	PartialEq,
+	RuntimeDebug,
+	TypeInfo,
+	MaxEncodedLen,
+)]
+pub enum DisputeStatus {
+	/// Waiting for the model owner to respond
+	Open,
+	/// Owner responded and posted a bond; waiting for arbitration
+	Responded,
+}
+
+/// Outcome chosen by the arbitration origin
+#[derive(
+	Clone,
+	Copy,
+	Encode,
+	Decode,
+	DecodeWithMemTracking,
+	Eq,
+	PartialEq,
+	RuntimeDebug,
+	TypeInfo,
+	MaxEncodedLen,
+)]
+pub enum Ruling {
+	/// Buyer was right: full refund, owner's bond slashed
+	Refund,
+	/// Both had a point: refund the given portion, both bonds returned
+	PartialRefund(Perbill),
+	/// Buyer was wrong: no refund, buyer's bond slashed
+	Reject,
+	/// Model is fraudulent: full refund, owner's bond and model deposit slashed
+	Slash,
+}
+
+/// A dispute over a paid inference
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct Dispute<AccountId, Balance, BlockNumber> {
+	/// Buyer who opened the dispute
+	pub buyer: AccountId,
+	/// Owner of the disputed model
+	pub owner: AccountId,
+	/// Disputed model
+	pub model_id: ModelId,
+	/// Payment the dispute refers to
+	pub receipt: ReceiptId,
+	/// Hash of the inference result the buyer received
+	pub result_hash: H256,
+	/// Amount paid for the inference
+	pub amount: Balance,
+	/// Escrow holding the buyer's bond
+	pub buyer_bond: EscrowId,
+	/// Escrow holding the owner's bond once they respond
+	pub owner_bond: Option<EscrowId>,
+	/// Hash of the owner's response, if any
+	pub response_hash: Option<H256>,
+	/// Current stage of the dispute
+	pub status: DisputeStatus,
+	/// Last block at which the owner may respond
+	pub response_deadline: BlockNumber,
+}
@@ -0,0 +1,117 @@
+//! Autogenerated weights for pallet_disputes
+//!
+//! THIS FILE WAS AUTO-GENERATED USING THE SUBSTRATE BENCHMARK CLI VERSION 4.0.0-dev
+//! DATE: 2024-01-01, STEPS: `50`, REPEAT: `20`, LOW RANGE: `[]`, HIGH RANGE: `[]`
+//! WORST CASE MAP SIZE: `1000000`
+//! HOSTNAME: `benchmark-runner`, CPU: `Intel(R) Xeon(R) CPU @ 2.60GHz`
+//! EXECUTION: Some(Wasm), WASM-EXECUTION: Compiled, CHAIN: Some("dev"), DB CACHE: 1024
+
+// Executed Command:
+// ./target/production/solochain-template-node
+// benchmark
+// pallet
+// --chain=dev
+// --steps=50
+// --repeat=20
+// --pallet=pallet_disputes
+// --output=./pallets/disputes/src/weights.rs
+// --template=./.maintain/frame-weight-template.hbs
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+#![allow(missing_docs)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use core::marker::PhantomData;
+
+/// Weight functions needed for pallet_disputes.
+pub trait WeightInfo {
+	fn open_dispute() -> Weight;
+	fn respond_to_dispute() -> Weight;
+	fn resolve_dispute() -> Weight;
+	fn claim_unanswered() -> Weight;
+}
+
+/// Weights for pallet_disputes using the Substrate node and recommended hardware.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	/// Storage: AIRegistry Models (r:1 w:0)
+	/// Proof: AIRegistry Models (max_values: None, max_size: Some(1712), added: 4187, mode: MaxEncodedLen)
+	/// Storage: Disputes DisputeByReceipt (r:1 w:1)
+	/// Proof: Disputes DisputeByReceipt (max_values: None, max_size: Some(32), added: 2507, mode: MaxEncodedLen)
+	/// Storage: Disputes NextDisputeId (r:1 w:1)
+	/// Proof: Disputes NextDisputeId (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: Disputes Disputes (r:0 w:1)
+	/// Proof: Disputes Disputes (max_values: None, max_size: Some(222), added: 2697, mode: MaxEncodedLen)
+	fn open_dispute() -> Weight {
+		Weight::from_parts(45_000_000, 9800)
+			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().writes(4))
+	}
+
+	/// Storage: Disputes Disputes (r:1 w:1)
+	/// Proof: Disputes Disputes (max_values: None, max_size: Some(222), added: 2697, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	fn respond_to_dispute() -> Weight {
+		Weight::from_parts(35_000_000, 5300)
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+
+	/// Storage: Disputes Disputes (r:1 w:1)
+	/// Proof: Disputes Disputes (max_values: None, max_size: Some(222), added: 2697, mode: MaxEncodedLen)
+	/// Storage: AIRegistry Models (r:1 w:1)
+	/// Proof: AIRegistry Models (max_values: None, max_size: Some(1712), added: 4187, mode: MaxEncodedLen)
+	/// Storage: System Account (r:3 w:3)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: AIRegistry Watchers (r:1 w:0)
+	/// Proof: AIRegistry Watchers (max_values: None, max_size: Some(8218), added: 10693, mode: MaxEncodedLen)
+	/// Storage: AIRegistry GlobalStats (r:1 w:1)
+	/// Proof: AIRegistry GlobalStats (max_values: Some(1), max_size: Some(40), added: 535, mode: MaxEncodedLen)
+	fn resolve_dispute() -> Weight {
+		Weight::from_parts(70_000_000, 25921)
+			.saturating_add(T::DbWeight::get().reads(7))
+			.saturating_add(T::DbWeight::get().writes(6))
+	}
+
+	/// Storage: Disputes Disputes (r:1 w:1)
+	/// Proof: Disputes Disputes (max_values: None, max_size: Some(222), added: 2697, mode: MaxEncodedLen)
+	/// Storage: System Account (r:2 w:2)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	fn claim_unanswered() -> Weight {
+		Weight::from_parts(40_000_000, 7903)
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(3))
+	}
+}
+
+// For backwards compatibility and tests
+impl WeightInfo for () {
+	fn open_dispute() -> Weight {
+		Weight::from_parts(45_000_000, 9800)
+			.saturating_add(RocksDbWeight::get().reads(4))
+			.saturating_add(RocksDbWeight::get().writes(4))
+	}
+
+	fn respond_to_dispute() -> Weight {
+		Weight::from_parts(35_000_000, 5300)
+			.saturating_add(RocksDbWeight::get().reads(2))
+			.saturating_add(RocksDbWeight::get().writes(2))
+	}
+
+	fn resolve_dispute() -> Weight {
+		Weight::from_parts(70_000_000, 25921)
+			.saturating_add(RocksDbWeight::get().reads(7))
+			.saturating_add(RocksDbWeight::get().writes(6))
+	}
+
+	fn claim_unanswered() -> Weight {
+		Weight::from_parts(40_000_000, 7903)
+			.saturating_add(RocksDbWeight::get().reads(3))
+			.saturating_add(RocksDbWeight::get().writes(3))
+	}
+}
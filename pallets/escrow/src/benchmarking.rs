@@ -0,0 +1,42 @@
+//! Benchmarking setup for pallet-escrow
+
+#![cfg(feature = "runtime-benchmarks")]
+
+use super::*;
+use crate::pallet::BalanceOf;
+use frame_benchmarking::v2::*;
+use frame_support::traits::{Currency, Get, Hooks};
+use sp_runtime::traits::{Saturating, Zero};
+
+/// Amount held in each escrow
+fn amount<T: Config>() -> BalanceOf<T> {
+	1_000u32.into()
+}
+
+#[benchmarks]
+mod benchmarks {
+	use super::*;
+
+	#[benchmark]
+	fn refund_expired(n: Linear<0, { T::MaxTimeoutsPerBlock::get() }>) {
+		let payer: T::AccountId = account("payer", 0, 0);
+		let balance = amount::<T>()
+			.saturating_mul(n.saturating_add(1).into())
+			.saturating_add(T::Currency::minimum_balance());
+		T::Currency::make_free_balance_be(&payer, balance);
+		let timeout = frame_system::Pallet::<T>::block_number().saturating_add(1u32.into());
+		for _ in 0..n {
+			let _ = Pallet::<T>::lock(&payer, amount::<T>(), Some(timeout));
+		}
+
+		#[block]
+		{
+			Pallet::<T>::on_initialize(timeout);
+		}
+
+		assert_eq!(Escrows::<T>::iter().count(), 0);
+		assert!(T::Currency::reserved_balance(&payer).is_zero());
+	}
+
+	impl_benchmark_test_suite!(Pallet, crate::mock::new_test_ext(), crate::mock::Test);
+}
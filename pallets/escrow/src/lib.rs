@@ -0,0 +1,387 @@
+//! # Escrow Pallet
+//!
+//! Funds held on behalf of other pallets until the outcome they are held for is known.
+//!
+//! ## Overview
+//!
+//! The pallet has no extrinsics of its own. Other pallets hold funds through the
+//! [`Escrow`] trait: `lock` reserves an amount from a payer and returns an escrow ID,
+//! `release` pays all or part of it out to someone else, and `refund` returns what is
+//! left to the payer. `split` sets part of an escrow aside in another one of the same
+//! payer, e.g. fees collected until they are settled together. Marketplace deposits
+//! and bids, dispute bonds and inference payments are all held this way, so every
+//! hold shows up under the same events and storage.
+//!
+//! Payments in other assets are held through [`AssetEscrow`]. Assets cannot be
+//! reserved, so they are moved to the pallet's account until they are paid out.
+//!
+//! ## Timeouts
+//!
+//! An escrow can be locked with a timeout. Whatever is still held at the start of
+//! that block is refunded to the payer automatically, so funds cannot stay stuck when
+//! the pallet holding them never settles. At most `MaxTimeoutsPerBlock` escrows can
+//! time out in the same block.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+pub use pallet::*;
+
+#[cfg(test)]
+mod mock;
+
+#[cfg(test)]
+mod tests;
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+
+pub mod weights;
+pub use weights::*;
+
+pub mod types;
+pub use types::*;
+
+pub mod traits;
+pub use traits::*;
+
+#[frame_support::pallet]
+pub mod pallet {
+	use super::*;
+	use frame_support::{
+		pallet_prelude::*,
+		traits::{fungibles, tokens::Preservation, BalanceStatus, Currency, ReservableCurrency},
+		PalletId,
+	};
+	use frame_system::pallet_prelude::*;
+	use sp_runtime::traits::{AccountIdConversion, Saturating, Zero};
+
+	pub(crate) type BalanceOf<T> =
+		<<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
+	pub(crate) type AssetIdOf<T> = <<T as Config>::Assets as fungibles::Inspect<
+		<T as frame_system::Config>::AccountId,
+	>>::AssetId;
+	pub(crate) type EscrowInfoOf<T> = EscrowInfo<
+		<T as frame_system::Config>::AccountId,
+		AssetIdOf<T>,
+		BalanceOf<T>,
+		BlockNumberFor<T>,
+	>;
+
+	/// The in-code storage version
+	pub const STORAGE_VERSION: StorageVersion = StorageVersion::new(0);
+
+	#[pallet::pallet]
+	#[pallet::storage_version(STORAGE_VERSION)]
+	pub struct Pallet<T>(_);
+
+	/// Configuration trait for the Escrow pallet
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		/// The overarching event type
+		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+		/// Weight information for extrinsics
+		type WeightInfo: WeightInfo;
+
+		/// Currency the escrowed funds are reserved in
+		type Currency: ReservableCurrency<Self::AccountId>;
+
+		/// Assets that can be escrowed besides `Currency`
+		type Assets: fungibles::Mutate<Self::AccountId, Balance = BalanceOf<Self>>;
+
+		/// Pallet ID, used to derive the account holding escrowed assets
+		#[pallet::constant]
+		type PalletId: Get<PalletId>;
+
+		/// Maximum number of escrows timing out in the same block
+		#[pallet::constant]
+		type MaxTimeoutsPerBlock: Get<u32>;
+	}
+
+	/// Open escrows
+	#[pallet::storage]
+	pub type Escrows<T: Config> = StorageMap<_, Twox64Concat, EscrowId, EscrowInfoOf<T>>;
+
+	/// Counter for generating unique escrow IDs
+	#[pallet::storage]
+	pub type NextEscrowId<T: Config> = StorageValue<_, EscrowId, ValueQuery>;
+
+	/// Escrows to refund at the start of each block
+	#[pallet::storage]
+	pub type Timeouts<T: Config> = StorageMap<
+		_,
+		Twox64Concat,
+		BlockNumberFor<T>,
+		BoundedVec<EscrowId, T::MaxTimeoutsPerBlock>,
+		ValueQuery,
+	>;
+
+	/// Events emitted by this pallet
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// Funds were held for a payer, in `asset` if not in the native currency
+		/// [escrow_id, payer, asset, amount, timeout]
+		Locked {
+			escrow_id: EscrowId,
+			payer: T::AccountId,
+			asset: Option<AssetIdOf<T>>,
+			amount: BalanceOf<T>,
+			timeout: Option<BlockNumberFor<T>>,
+		},
+		/// Escrowed funds were paid out
+		/// [escrow_id, to, amount]
+		Released { escrow_id: EscrowId, to: T::AccountId, amount: BalanceOf<T> },
+		/// What was left in an escrow went back to its payer, closing it
+		/// [escrow_id, payer, amount]
+		Refunded { escrow_id: EscrowId, payer: T::AccountId, amount: BalanceOf<T> },
+		/// Part of an escrow was moved into another escrow of the same payer
+		/// [escrow_id, into, amount]
+		Split { escrow_id: EscrowId, into: EscrowId, amount: BalanceOf<T> },
+	}
+
+	/// Errors that can occur in this pallet
+	#[pallet::error]
+	pub enum Error<T> {
+		/// Escrow doesn't exist or was closed
+		EscrowNotFound,
+		/// Payer cannot reserve the amount
+		InsufficientBalance,
+		/// Timeout is not in the future
+		InvalidTimeout,
+		/// Too many escrows time out in the same block
+		TooManyTimeouts,
+		/// The escrows hold funds of different payers or in different assets
+		Incompatible,
+		/// Arithmetic overflow
+		ArithmeticOverflow,
+	}
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		fn on_initialize(now: BlockNumberFor<T>) -> Weight {
+			let expiring = Timeouts::<T>::take(now);
+			let count = expiring.len() as u32;
+			for escrow_id in expiring {
+				// Escrows settled before their timeout are gone already
+				let _ = Self::do_refund(escrow_id);
+			}
+			T::WeightInfo::refund_expired(count)
+		}
+	}
+
+	impl<T: Config> Pallet<T> {
+		/// Account holding escrowed assets
+		pub fn account_id() -> T::AccountId {
+			T::PalletId::get().into_account_truncating()
+		}
+
+		/// Hold `amount` of `asset`, or of the native currency, for `payer`
+		fn do_lock(
+			payer: &T::AccountId,
+			asset: Option<AssetIdOf<T>>,
+			amount: BalanceOf<T>,
+			timeout: Option<BlockNumberFor<T>>,
+		) -> Result<EscrowId, DispatchError> {
+			let escrow_id = NextEscrowId::<T>::get();
+			let next_id = escrow_id.checked_add(1).ok_or(Error::<T>::ArithmeticOverflow)?;
+
+			let expiring = timeout
+				.map(|timeout| -> Result<_, DispatchError> {
+					ensure!(
+						timeout > frame_system::Pallet::<T>::block_number(),
+						Error::<T>::InvalidTimeout
+					);
+					let mut expiring = Timeouts::<T>::get(timeout);
+					expiring.try_push(escrow_id).map_err(|_| Error::<T>::TooManyTimeouts)?;
+					Ok((timeout, expiring))
+				})
+				.transpose()?;
+
+			match &asset {
+				Some(asset_id) => {
+					let account = Self::account_id();
+					// The account holds no native tokens, so keep it alive for assets
+					// that are not sufficient on their own
+					if frame_system::Pallet::<T>::providers(&account) == 0 {
+						frame_system::Pallet::<T>::inc_providers(&account);
+					}
+					T::Assets::transfer(
+						asset_id.clone(),
+						payer,
+						&account,
+						amount,
+						Preservation::Expendable,
+					)
+					.map_err(|_| Error::<T>::InsufficientBalance)?;
+				}
+				None => {
+					T::Currency::reserve(payer, amount)
+						.map_err(|_| Error::<T>::InsufficientBalance)?;
+				}
+			}
+
+			if let Some((timeout, expiring)) = expiring {
+				Timeouts::<T>::insert(timeout, expiring);
+			}
+			Escrows::<T>::insert(
+				escrow_id,
+				EscrowInfo { payer: payer.clone(), asset: asset.clone(), amount, timeout },
+			);
+			NextEscrowId::<T>::put(next_id);
+
+			Self::deposit_event(Event::Locked {
+				escrow_id,
+				payer: payer.clone(),
+				asset,
+				amount,
+				timeout,
+			});
+
+			Ok(escrow_id)
+		}
+
+		/// Pay `amount` of what `escrow` holds to `to` and return the amount paid
+		fn pay(
+			escrow: &EscrowInfoOf<T>,
+			to: &T::AccountId,
+			amount: BalanceOf<T>,
+		) -> Result<BalanceOf<T>, DispatchError> {
+			match &escrow.asset {
+				Some(asset_id) => T::Assets::transfer(
+					asset_id.clone(),
+					&Self::account_id(),
+					to,
+					amount,
+					Preservation::Expendable,
+				),
+				None => {
+					// Whatever cannot be moved is no longer reserved from the payer
+					let missing = T::Currency::repatriate_reserved(
+						&escrow.payer,
+						to,
+						amount,
+						BalanceStatus::Free,
+					)?;
+					Ok(amount.saturating_sub(missing))
+				}
+			}
+		}
+
+		/// Store `escrow` under `escrow_id`, closing it if it is empty
+		fn store(escrow_id: EscrowId, escrow: EscrowInfoOf<T>) {
+			if escrow.amount.is_zero() {
+				Escrows::<T>::remove(escrow_id);
+			} else {
+				Escrows::<T>::insert(escrow_id, escrow);
+			}
+		}
+
+		/// Return what is left in `escrow_id` to its payer and close it
+		fn do_refund(escrow_id: EscrowId) -> Result<BalanceOf<T>, DispatchError> {
+			let escrow = Escrows::<T>::get(escrow_id).ok_or(Error::<T>::EscrowNotFound)?;
+			let refunded = Self::pay(&escrow, &escrow.payer, escrow.amount)?;
+			Escrows::<T>::remove(escrow_id);
+
+			Self::deposit_event(Event::Refunded {
+				escrow_id,
+				payer: escrow.payer,
+				amount: refunded,
+			});
+
+			Ok(refunded)
+		}
+	}
+
+	impl<T: Config> Escrow<T::AccountId, BalanceOf<T>, BlockNumberFor<T>> for Pallet<T> {
+		fn lock(
+			payer: &T::AccountId,
+			amount: BalanceOf<T>,
+			timeout: Option<BlockNumberFor<T>>,
+		) -> Result<EscrowId, DispatchError> {
+			Self::do_lock(payer, None, amount, timeout)
+		}
+
+		fn release(
+			escrow_id: EscrowId,
+			to: &T::AccountId,
+			amount: BalanceOf<T>,
+		) -> Result<BalanceOf<T>, DispatchError> {
+			let mut escrow = Escrows::<T>::get(escrow_id).ok_or(Error::<T>::EscrowNotFound)?;
+			let amount = amount.min(escrow.amount);
+			let paid = Self::pay(&escrow, to, amount)?;
+
+			escrow.amount = escrow.amount.saturating_sub(amount);
+			Self::store(escrow_id, escrow);
+
+			Self::deposit_event(Event::Released { escrow_id, to: to.clone(), amount: paid });
+
+			Ok(paid)
+		}
+
+		fn refund(escrow_id: EscrowId) -> Result<BalanceOf<T>, DispatchError> {
+			Self::do_refund(escrow_id)
+		}
+
+		fn held(escrow_id: EscrowId) -> BalanceOf<T> {
+			Escrows::<T>::get(escrow_id).map_or_else(Zero::zero, |escrow| escrow.amount)
+		}
+
+		fn split(
+			escrow_id: EscrowId,
+			amount: BalanceOf<T>,
+			into: Option<EscrowId>,
+		) -> Result<EscrowId, DispatchError> {
+			let mut escrow = Escrows::<T>::get(escrow_id).ok_or(Error::<T>::EscrowNotFound)?;
+			if into == Some(escrow_id) {
+				return Ok(escrow_id);
+			}
+			let amount = amount.min(escrow.amount);
+
+			let (into, mut target) = match into {
+				Some(into) => {
+					let target = Escrows::<T>::get(into).ok_or(Error::<T>::EscrowNotFound)?;
+					ensure!(
+						target.payer == escrow.payer && target.asset == escrow.asset,
+						Error::<T>::Incompatible
+					);
+					(into, target)
+				}
+				None => {
+					let into = NextEscrowId::<T>::get();
+					let next_id = into.checked_add(1).ok_or(Error::<T>::ArithmeticOverflow)?;
+					NextEscrowId::<T>::put(next_id);
+					let target = EscrowInfo {
+						payer: escrow.payer.clone(),
+						asset: escrow.asset.clone(),
+						amount: Zero::zero(),
+						timeout: None,
+					};
+					(into, target)
+				}
+			};
+
+			escrow.amount = escrow.amount.saturating_sub(amount);
+			target.amount = target.amount.saturating_add(amount);
+			Self::store(escrow_id, escrow);
+			Self::store(into, target);
+
+			Self::deposit_event(Event::Split { escrow_id, into, amount });
+
+			Ok(into)
+		}
+	}
+
+	impl<T: Config> AssetEscrow<T::AccountId, AssetIdOf<T>, BalanceOf<T>, BlockNumberFor<T>>
+		for Pallet<T>
+	{
+		fn lock_asset(
+			asset: AssetIdOf<T>,
+			payer: &T::AccountId,
+			amount: BalanceOf<T>,
+			timeout: Option<BlockNumberFor<T>>,
+		) -> Result<EscrowId, DispatchError> {
+			Self::do_lock(payer, Some(asset), amount, timeout)
+		}
+	}
+}
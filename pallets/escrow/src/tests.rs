@@ -0,0 +1,146 @@
+//! Unit tests for Escrow pallet
+
+use crate::{
+	mock::*,
+	pallet::{Error, Escrows, Event, Timeouts},
+	AssetEscrow as _, Escrow as _, EscrowInfo,
+};
+use frame_support::{assert_noop, assert_ok};
+
+#[test]
+fn lock_reserves_and_refund_returns_funds() {
+	new_test_ext().execute_with(|| {
+		assert_eq!(Escrow::lock(&1, 500, None), Ok(0));
+		assert_eq!(
+			Escrows::<Test>::get(0),
+			Some(EscrowInfo { payer: 1, asset: None, amount: 500, timeout: None })
+		);
+		assert_eq!(Balances::reserved_balance(1), 500);
+		assert_eq!(Escrow::held(0), 500);
+
+		assert_eq!(Escrow::refund(0), Ok(500));
+		System::assert_last_event(Event::Refunded { escrow_id: 0, payer: 1, amount: 500 }.into());
+		assert_eq!(Balances::reserved_balance(1), 0);
+		assert_eq!(Balances::free_balance(1), 10000);
+		assert_eq!(Escrow::held(0), 0);
+		assert_noop!(Escrow::refund(0), Error::<Test>::EscrowNotFound);
+	});
+}
+
+#[test]
+fn lock_requires_balance() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(Escrow::lock(&3, 500, None), Error::<Test>::InsufficientBalance);
+		assert!(Escrows::<Test>::get(0).is_none());
+	});
+}
+
+#[test]
+fn release_pays_out_until_empty() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Escrow::lock(&1, 500, None));
+
+		assert_eq!(Escrow::release(0, &2, 200), Ok(200));
+		System::assert_last_event(Event::Released { escrow_id: 0, to: 2, amount: 200 }.into());
+		assert_eq!(Escrow::held(0), 300);
+		assert_eq!(Balances::reserved_balance(1), 300);
+		assert_eq!(Balances::free_balance(2), 10200);
+
+		// Releasing more than is held pays out the rest and closes the escrow
+		assert_eq!(Escrow::release(0, &2, 1000), Ok(300));
+		assert!(Escrows::<Test>::get(0).is_none());
+		assert_eq!(Balances::reserved_balance(1), 0);
+		assert_eq!(Balances::free_balance(1), 9500);
+		assert_eq!(Balances::free_balance(2), 10500);
+		assert_noop!(Escrow::release_all(0, &2), Error::<Test>::EscrowNotFound);
+	});
+}
+
+#[test]
+fn escrows_are_refunded_at_their_timeout() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(Escrow::lock(&1, 100, Some(1)), Error::<Test>::InvalidTimeout);
+
+		assert_ok!(Escrow::lock(&1, 100, Some(5)));
+		assert_ok!(Escrow::lock(&2, 100, Some(5)));
+		assert_noop!(Escrow::lock(&1, 100, Some(5)), Error::<Test>::TooManyTimeouts);
+		assert_eq!(Balances::reserved_balance(1), 100);
+
+		// A partly released escrow only refunds what is left
+		assert_ok!(Escrow::release(0, &2, 40));
+		// A settled escrow has nothing left to refund
+		assert_ok!(Escrow::release_all(1, &1));
+
+		run_to_block(4);
+		assert_eq!(Escrow::held(0), 60);
+
+		run_to_block(5);
+		System::assert_last_event(Event::Refunded { escrow_id: 0, payer: 1, amount: 60 }.into());
+		assert!(Escrows::<Test>::get(0).is_none());
+		assert!(Timeouts::<Test>::get(5).is_empty());
+		assert_eq!(Balances::reserved_balance(1), 0);
+		assert_eq!(Balances::free_balance(1), 10060);
+		assert_eq!(Balances::free_balance(2), 9940);
+	});
+}
+
+#[test]
+fn assets_are_held_in_the_pallet_account() {
+	new_test_ext().execute_with(|| {
+		let account = Escrow::account_id();
+		assert_eq!(Escrow::lock_asset(USD, &1, 300, None), Ok(0));
+		assert_eq!(Escrows::<Test>::get(0).map(|escrow| escrow.asset), Some(Some(USD)));
+		assert_eq!(Assets::balance(USD, 1), 700);
+		assert_eq!(Assets::balance(USD, account), 300);
+		assert_eq!(Balances::reserved_balance(1), 0);
+
+		assert_eq!(Escrow::release(0, &2, 100), Ok(100));
+		assert_eq!(Assets::balance(USD, 2), 1100);
+
+		assert_eq!(Escrow::refund(0), Ok(200));
+		assert_eq!(Assets::balance(USD, 1), 900);
+		assert_eq!(Assets::balance(USD, account), 0);
+
+		assert_noop!(Escrow::lock_asset(USD, &3, 1, None), Error::<Test>::InsufficientBalance);
+	});
+}
+
+#[test]
+fn split_moves_funds_between_escrows_of_a_payer() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Escrow::lock(&1, 500, Some(5)));
+
+		// Into a new escrow, which does not time out
+		assert_eq!(Escrow::split(0, 200, None), Ok(1));
+		System::assert_last_event(Event::Split { escrow_id: 0, into: 1, amount: 200 }.into());
+		assert_eq!(
+			Escrows::<Test>::get(1),
+			Some(EscrowInfo { payer: 1, asset: None, amount: 200, timeout: None })
+		);
+		assert_eq!((Escrow::held(0), Escrow::held(1)), (300, 200));
+		// Nothing moves between accounts
+		assert_eq!(Balances::reserved_balance(1), 500);
+
+		// Into an existing escrow, emptying and closing the source
+		assert_eq!(Escrow::split(0, 1000, Some(1)), Ok(1));
+		assert!(Escrows::<Test>::get(0).is_none());
+		assert_eq!(Escrow::held(1), 500);
+
+		run_to_block(5);
+		assert_eq!(Balances::reserved_balance(1), 500);
+	});
+}
+
+#[test]
+fn split_requires_the_same_payer_and_asset() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Escrow::lock(&1, 100, None));
+		assert_ok!(Escrow::lock(&2, 100, None));
+		assert_ok!(Escrow::lock_asset(USD, &1, 100, None));
+
+		assert_noop!(Escrow::split(0, 50, Some(1)), Error::<Test>::Incompatible);
+		assert_noop!(Escrow::split(0, 50, Some(2)), Error::<Test>::Incompatible);
+		assert_noop!(Escrow::split(0, 50, Some(3)), Error::<Test>::EscrowNotFound);
+		assert_noop!(Escrow::split(3, 50, None), Error::<Test>::EscrowNotFound);
+	});
+}
@@ -0,0 +1,106 @@
+//! Interfaces the Escrow pallet offers to the rest of the runtime
+
+use crate::EscrowId;
+use sp_runtime::{traits::Zero, DispatchError};
+
+/// Funds held for a payer until the outcome they are held for is known
+pub trait Escrow<AccountId, Balance, BlockNumber> {
+	/// Hold `amount` of `payer`'s free balance and return the escrow's ID
+	///
+	/// Unless released or refunded before, whatever is left is refunded to the payer
+	/// at the start of block `timeout`.
+	fn lock(
+		payer: &AccountId,
+		amount: Balance,
+		timeout: Option<BlockNumber>,
+	) -> Result<EscrowId, DispatchError>;
+
+	/// Pay up to `amount` out of escrow `id` to `to`, closing the escrow once it is
+	/// empty, and return the amount paid
+	fn release(id: EscrowId, to: &AccountId, amount: Balance) -> Result<Balance, DispatchError>;
+
+	/// Return what is left in escrow `id` to its payer, close the escrow and return
+	/// the amount refunded
+	fn refund(id: EscrowId) -> Result<Balance, DispatchError>;
+
+	/// Amount still held in escrow `id`; zero once it is closed
+	fn held(id: EscrowId) -> Balance;
+
+	/// Move up to `amount` out of escrow `id` into escrow `into`, or into a new escrow
+	/// without a timeout if `None`, and return the ID of the escrow it was moved into
+	///
+	/// Nothing is paid out: both escrows hold the same payer's funds in the same
+	/// asset, e.g. to set part of a payment aside for a later settlement.
+	fn split(
+		id: EscrowId,
+		amount: Balance,
+		into: Option<EscrowId>,
+	) -> Result<EscrowId, DispatchError>;
+
+	/// Pay everything left in escrow `id` to `to` and return the amount paid
+	fn release_all(id: EscrowId, to: &AccountId) -> Result<Balance, DispatchError> {
+		Self::release(id, to, Self::held(id))
+	}
+}
+
+/// Escrow of assets besides the native currency
+///
+/// Asset escrows are released, refunded and split through [`Escrow`] like any other.
+pub trait AssetEscrow<AccountId, AssetId, Balance, BlockNumber>:
+	Escrow<AccountId, Balance, BlockNumber>
+{
+	/// Hold `amount` of `asset` from `payer` and return the escrow's ID
+	///
+	/// Assets cannot be reserved, so they are moved to the escrow's own account
+	/// until released or refunded. Timeouts work as with [`Escrow::lock`].
+	fn lock_asset(
+		asset: AssetId,
+		payer: &AccountId,
+		amount: Balance,
+		timeout: Option<BlockNumber>,
+	) -> Result<EscrowId, DispatchError>;
+}
+
+/// No escrow: nothing can be held
+impl<AccountId, Balance: Zero, BlockNumber> Escrow<AccountId, Balance, BlockNumber> for () {
+	fn lock(
+		_payer: &AccountId,
+		_amount: Balance,
+		_timeout: Option<BlockNumber>,
+	) -> Result<EscrowId, DispatchError> {
+		Err(DispatchError::Other("no escrow configured"))
+	}
+
+	fn release(_id: EscrowId, _to: &AccountId, _amount: Balance) -> Result<Balance, DispatchError> {
+		Err(DispatchError::Other("no escrow configured"))
+	}
+
+	fn refund(_id: EscrowId) -> Result<Balance, DispatchError> {
+		Err(DispatchError::Other("no escrow configured"))
+	}
+
+	fn held(_id: EscrowId) -> Balance {
+		Zero::zero()
+	}
+
+	fn split(
+		_id: EscrowId,
+		_amount: Balance,
+		_into: Option<EscrowId>,
+	) -> Result<EscrowId, DispatchError> {
+		Err(DispatchError::Other("no escrow configured"))
+	}
+}
+
+impl<AccountId, AssetId, Balance: Zero, BlockNumber>
+	AssetEscrow<AccountId, AssetId, Balance, BlockNumber> for ()
+{
+	fn lock_asset(
+		_asset: AssetId,
+		_payer: &AccountId,
+		_amount: Balance,
+		_timeout: Option<BlockNumber>,
+	) -> Result<EscrowId, DispatchError> {
+		Err(DispatchError::Other("no escrow configured"))
+	}
+}
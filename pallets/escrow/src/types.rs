@@ -0,0 +1,22 @@
+//! Type definitions for the Escrow pallet
+
+use codec::{Decode, Encode, MaxEncodedLen};
+use scale_info::TypeInfo;
+use sp_runtime::RuntimeDebug;
+
+/// Unique identifier for an escrow
+pub type EscrowId = u64;
+
+/// Funds held for a payer until they are released or refunded
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct EscrowInfo<AccountId, AssetId, Balance, BlockNumber> {
+	/// Account the funds are held for
+	pub payer: AccountId,
+	/// Asset held, moved to the pallet's account; `None` for the native currency,
+	/// reserved from the payer
+	pub asset: Option<AssetId>,
+	/// Amount still held
+	pub amount: Balance,
+	/// Block at which what is left is refunded to the payer, if any
+	pub timeout: Option<BlockNumber>,
+}
@@ -0,0 +1,856 @@
+//! Benchmarking setup for pallet-inference
+
+#![cfg(feature = "runtime-benchmarks")]
+
+use super::*;
+use frame_benchmarking::v2::*;
+use frame_support::{
+	traits::{
+		fungibles::{Create, Mutate},
+		Currency, EnsureOrigin, Get,
+	},
+	BoundedVec,
+};
+use frame_system::{pallet_prelude::BlockNumberFor, RawOrigin};
+use pallet_ai_registry::ModelId;
+use pallet_compute_providers::ComputeProviders;
+use pallet_escrow::Escrow as _;
+use sp_core::H256;
+use sp_runtime::{
+	traits::{Bounded, Saturating, Zero},
+	Perbill, Percent,
+};
+use sp_std::{vec, vec::Vec};
+
+/// Create an asset `model_id` is paid for in and fund `payer` with it
+fn accept_asset<T: Config>(
+	owner: &T::AccountId,
+	model_id: ModelId,
+	payer: &T::AccountId,
+) -> AssetIdOf<T> {
+	let asset_id = T::BenchmarkHelper::asset_id(0);
+	let _ = T::Assets::create(asset_id.clone(), owner.clone(), true, 1u32.into());
+	let _ = T::Assets::mint_into(asset_id.clone(), payer, 1_000_000u32.into());
+	// Keep the owner alive so payouts take the slowest path
+	let _ = T::Assets::mint_into(asset_id.clone(), owner, 1u32.into());
+	AssetPrices::<T>::insert(model_id, asset_id.clone(), BalanceOf::<T>::from(1_000u32));
+	asset_id
+}
+
+/// Create a model and an open request on it by the whitelisted caller, paid in
+/// an asset since asset transfers cost more than reserves
+fn setup_request<T: Config>() -> (T::AccountId, ModelId, RequestId) {
+	let owner: T::AccountId = account("owner", 0, 0);
+	let model_id = T::Models::create_model(&owner);
+
+	let requester: T::AccountId = whitelisted_caller();
+	let asset_id = accept_asset::<T>(&owner, model_id, &requester);
+	let request_id = NextRequestId::<T>::get();
+	let _ = Pallet::<T>::request_inference_with_asset(
+		RawOrigin::Signed(requester.clone()).into(),
+		model_id,
+		H256::zero(),
+		asset_id,
+		BalanceOf::<T>::max_value(),
+	);
+	// Referred requests pay out and clean up more when they settle or fail
+	let referrer: T::AccountId = account("referrer", 0, 0);
+	Referrals::<T>::insert(request_id, (referrer, Perbill::from_percent(10)));
+	(requester, model_id, request_id)
+}
+
+/// Discount code used in benchmarks
+const COUPON: &[u8] = b"LAUNCH";
+
+/// Have `owner` create a discount code for `model_id` that expires next block
+fn setup_coupon<T: Config>(owner: &T::AccountId, model_id: ModelId) -> H256 {
+	let code_hash = Pallet::<T>::coupon_hash(COUPON);
+	let expires_at = frame_system::Pallet::<T>::block_number().saturating_add(1u32.into());
+	let _ = Pallet::<T>::create_coupon(
+		RawOrigin::Signed(owner.clone()).into(),
+		model_id,
+		code_hash,
+		Percent::from_percent(50),
+		10,
+		expires_at,
+	);
+	code_hash
+}
+
+/// Make `model_id` demand-priced, so requests for it also count towards its demand
+fn price_by_demand<T: Config>(model_id: ModelId) {
+	let pricing = DemandPricing {
+		baseline: 0,
+		step: Percent::from_percent(1),
+		max_surcharge: Percent::from_percent(50),
+	};
+	DemandPricings::<T>::insert(model_id, pricing);
+}
+
+/// Create a provider and have them pick up `request_id`
+fn assign<T: Config>(request_id: RequestId) -> T::AccountId {
+	let provider: T::AccountId = account("provider", 0, 0);
+	T::Providers::create_provider(&provider);
+	let _ = Pallet::<T>::accept_request(RawOrigin::Signed(provider.clone()).into(), request_id);
+	provider
+}
+
+/// Create a model priced per unit and a metered session on it by the whitelisted
+/// caller
+fn setup_session<T: Config>() -> SessionId {
+	let owner: T::AccountId = account("owner", 0, 0);
+	let model_id = T::Models::create_model(&owner);
+	UnitPrices::<T>::insert(model_id, BalanceOf::<T>::from(10u32));
+	let requester: T::AccountId = whitelisted_caller();
+	T::Currency::make_free_balance_be(&requester, BalanceOf::<T>::max_value() / 2u32.into());
+	let session_id = NextSessionId::<T>::get();
+	let _ = Pallet::<T>::open_metered_session(
+		RawOrigin::Signed(requester).into(),
+		model_id,
+		H256::zero(),
+		1_000,
+		BalanceOf::<T>::max_value(),
+	);
+	session_id
+}
+
+/// Create a provider and have them pick up `session_id`
+fn accept_session<T: Config>(session_id: SessionId) -> T::AccountId {
+	let provider: T::AccountId = account("provider", 0, 0);
+	T::Providers::create_provider(&provider);
+	let _ =
+		Pallet::<T>::accept_metered_session(RawOrigin::Signed(provider.clone()).into(), session_id);
+	provider
+}
+
+/// Have the `i`th buyer owe `model_id` a tab, escrowed from them, and return them
+fn run_up_tab<T: Config>(i: u32, model_id: ModelId) -> T::AccountId {
+	let buyer: T::AccountId = account("buyer", i, 0);
+	let owed = T::Currency::minimum_balance().saturating_mul(1_000u32.into());
+	T::Currency::make_free_balance_be(&buyer, owed.saturating_mul(2u32.into()));
+	let tab = T::Escrow::lock(&buyer, owed, None).unwrap();
+	Tabs::<T>::insert(&buyer, model_id, tab);
+	buyer
+}
+
+/// Split the revenue of `model_id` evenly between `holders` shareholders
+fn split_revenue<T: Config>(model_id: ModelId, holders: u32) {
+	let part = Perbill::from_rational(1, holders);
+	let shares = (0..holders)
+		.map(|i| {
+			let share = if i + 1 == holders {
+				Perbill::from_parts(
+					Perbill::one().deconstruct() - (holders - 1) * part.deconstruct(),
+				)
+			} else {
+				part
+			};
+			(account("holder", i, 0), share)
+		})
+		.collect();
+	T::Models::set_revenue_shares(model_id, shares);
+}
+
+/// Have a funded account challenge the result of `request_id`
+fn challenge<T: Config>(request_id: RequestId) -> T::AccountId {
+	let challenger: T::AccountId = account("challenger", 0, 0);
+	T::Currency::make_free_balance_be(&challenger, BalanceOf::<T>::max_value() / 2u32.into());
+	let _ = Pallet::<T>::challenge_result(
+		RawOrigin::Signed(challenger.clone()).into(),
+		request_id,
+		H256::repeat_byte(2),
+	);
+	challenger
+}
+
+/// Advance the block number by `blocks`
+fn skip_blocks<T: Config>(blocks: BlockNumberFor<T>) {
+	frame_system::Pallet::<T>::set_block_number(
+		frame_system::Pallet::<T>::block_number().saturating_add(blocks),
+	);
+}
+
+/// Have `provider` commit to and reveal a result for `request_id`
+fn commit_and_reveal<T: Config>(provider: T::AccountId, request_id: RequestId) {
+	let salt = H256::repeat_byte(1);
+	let commitment = Pallet::<T>::result_commitment(&H256::zero(), &salt);
+	let _ = Pallet::<T>::commit_result(
+		RawOrigin::Signed(provider.clone()).into(),
+		request_id,
+		commitment,
+	);
+	skip_blocks::<T>(T::RevealDelay::get());
+	let _ = Pallet::<T>::reveal_result(
+		RawOrigin::Signed(provider).into(),
+		request_id,
+		H256::zero(),
+		salt,
+	);
+}
+
+#[benchmarks]
+mod benchmarks {
+	use super::*;
+
+	#[benchmark]
+	fn request_inference() {
+		let owner: T::AccountId = account("owner", 0, 0);
+		let model_id = T::Models::create_model(&owner);
+		Quotas::<T>::insert(model_id, u32::MAX);
+		price_by_demand::<T>(model_id);
+		let caller: T::AccountId = whitelisted_caller();
+		T::Currency::make_free_balance_be(&caller, BalanceOf::<T>::max_value() / 2u32.into());
+
+		#[extrinsic_call]
+		request_inference(
+			RawOrigin::Signed(caller),
+			model_id,
+			H256::zero(),
+			BalanceOf::<T>::max_value(),
+		);
+
+		assert!(Requests::<T>::contains_key(0));
+	}
+
+	#[benchmark]
+	fn request_inference_with_asset() {
+		let owner: T::AccountId = account("owner", 0, 0);
+		let model_id = T::Models::create_model(&owner);
+		Quotas::<T>::insert(model_id, u32::MAX);
+		price_by_demand::<T>(model_id);
+		let caller: T::AccountId = whitelisted_caller();
+		let asset_id = accept_asset::<T>(&owner, model_id, &caller);
+
+		#[extrinsic_call]
+		request_inference_with_asset(
+			RawOrigin::Signed(caller),
+			model_id,
+			H256::zero(),
+			asset_id,
+			BalanceOf::<T>::max_value(),
+		);
+
+		assert!(RequestAssets::<T>::contains_key(0));
+	}
+
+	#[benchmark]
+	fn accept_request() {
+		let (_, _, request_id) = setup_request::<T>();
+		let provider: T::AccountId = account("provider", 0, 0);
+		T::Providers::create_provider(&provider);
+
+		#[extrinsic_call]
+		accept_request(RawOrigin::Signed(provider), request_id);
+
+		assert_eq!(Requests::<T>::get(request_id).unwrap().state, RequestState::Assigned);
+	}
+
+	#[benchmark]
+	fn commit_result() {
+		let (_, _, request_id) = setup_request::<T>();
+		let provider = assign::<T>(request_id);
+
+		#[extrinsic_call]
+		commit_result(RawOrigin::Signed(provider), request_id, H256::zero());
+
+		assert_eq!(Requests::<T>::get(request_id).unwrap().state, RequestState::Committed);
+	}
+
+	#[benchmark]
+	fn settle_request(s: Linear<1, { T::MaxRevenueShares::get() }>) {
+		let (requester, model_id, request_id) = setup_request::<T>();
+		let provider = assign::<T>(request_id);
+		commit_and_reveal::<T>(provider, request_id);
+		// Every shareholder receives their part of the payment
+		split_revenue::<T>(model_id, s);
+
+		#[extrinsic_call]
+		settle_request(RawOrigin::Signed(requester), request_id);
+
+		assert_eq!(Requests::<T>::get(request_id).unwrap().state, RequestState::Settled);
+	}
+
+	#[benchmark]
+	fn cancel_request() {
+		let (requester, _, request_id) = setup_request::<T>();
+
+		#[extrinsic_call]
+		cancel_request(RawOrigin::Signed(requester), request_id);
+
+		assert_eq!(Requests::<T>::get(request_id).unwrap().state, RequestState::Failed);
+	}
+
+	#[benchmark]
+	fn expire_request() {
+		let (requester, _, request_id) = setup_request::<T>();
+		// Expiring an assigned request also releases the provider's job
+		assign::<T>(request_id);
+		skip_blocks::<T>(T::CompletionTimeout::get().saturating_add(1u32.into()));
+
+		#[extrinsic_call]
+		expire_request(RawOrigin::Signed(requester), request_id);
+
+		assert_eq!(Requests::<T>::get(request_id).unwrap().state, RequestState::Failed);
+	}
+
+	#[benchmark]
+	fn reveal_result() {
+		let (_, _, request_id) = setup_request::<T>();
+		let provider = assign::<T>(request_id);
+		let salt = H256::repeat_byte(1);
+		let commitment = Pallet::<T>::result_commitment(&H256::zero(), &salt);
+		let _ = Pallet::<T>::commit_result(
+			RawOrigin::Signed(provider.clone()).into(),
+			request_id,
+			commitment,
+		);
+		skip_blocks::<T>(T::RevealDelay::get());
+
+		#[extrinsic_call]
+		reveal_result(RawOrigin::Signed(provider), request_id, H256::zero(), salt);
+
+		assert_eq!(Requests::<T>::get(request_id).unwrap().state, RequestState::Completed);
+	}
+
+	#[benchmark]
+	fn submit_inference_proof(n: Linear<0, { T::MaxProofLength::get() }>) {
+		let (_, _, request_id) = setup_request::<T>();
+		let provider = assign::<T>(request_id);
+		commit_and_reveal::<T>(provider, request_id);
+		let caller: T::AccountId = whitelisted_caller();
+		let proof: BoundedVec<u8, T::MaxProofLength> = vec![0u8; n as usize].try_into().unwrap();
+
+		// The verifier may reject the dummy proof; its own cost is covered by
+		// `ProofVerifier::verification_weight`
+		#[block]
+		{
+			let _ = Pallet::<T>::submit_inference_proof(
+				RawOrigin::Signed(caller).into(),
+				request_id,
+				proof,
+			);
+		}
+
+		assert!(Requests::<T>::contains_key(request_id));
+	}
+
+	#[benchmark]
+	fn set_enclave_requirement() {
+		let owner: T::AccountId = account("owner", 0, 0);
+		let model_id = T::Models::create_model(&owner);
+		let requirement = Some(EnclaveRequirement::Measurement(H256::repeat_byte(1)));
+
+		#[extrinsic_call]
+		set_enclave_requirement(RawOrigin::Signed(owner), model_id, requirement.clone());
+
+		assert_eq!(EnclaveRequirements::<T>::get(model_id), requirement);
+	}
+
+	#[benchmark]
+	fn set_usd_price() {
+		let owner: T::AccountId = account("owner", 0, 0);
+		let model_id = T::Models::create_model(&owner);
+
+		// The runtime may have no price feed to check the price against; the write
+		// is accounted for in the weight regardless
+		#[block]
+		{
+			let _ =
+				Pallet::<T>::set_usd_price(RawOrigin::Signed(owner).into(), model_id, Some(100));
+		}
+	}
+
+	#[benchmark]
+	fn set_asset_price() {
+		let owner: T::AccountId = account("owner", 0, 0);
+		let model_id = T::Models::create_model(&owner);
+		let asset_id = T::BenchmarkHelper::asset_id(0);
+
+		#[extrinsic_call]
+		set_asset_price(
+			RawOrigin::Signed(owner),
+			model_id,
+			asset_id.clone(),
+			Some(1_000u32.into()),
+		);
+
+		assert!(AssetPrices::<T>::contains_key(model_id, asset_id));
+	}
+
+	#[benchmark]
+	fn set_quota() {
+		let owner: T::AccountId = account("owner", 0, 0);
+		let model_id = T::Models::create_model(&owner);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(owner), model_id, Some(10));
+
+		assert_eq!(Quotas::<T>::get(model_id), Some(10));
+	}
+
+	#[benchmark]
+	fn create_coupon() {
+		let owner: T::AccountId = account("owner", 0, 0);
+		let model_id = T::Models::create_model(&owner);
+		let code_hash = Pallet::<T>::coupon_hash(COUPON);
+
+		#[extrinsic_call]
+		_(
+			RawOrigin::Signed(owner),
+			model_id,
+			code_hash,
+			Percent::from_percent(50),
+			10,
+			BlockNumberFor::<T>::max_value(),
+		);
+
+		assert!(Coupons::<T>::contains_key(code_hash));
+	}
+
+	#[benchmark]
+	fn remove_coupon() {
+		let owner: T::AccountId = account("owner", 0, 0);
+		let model_id = T::Models::create_model(&owner);
+		let code_hash = setup_coupon::<T>(&owner, model_id);
+		// A stranger has to look up the model owner before removing the code
+		skip_blocks::<T>(1u32.into());
+		let caller: T::AccountId = whitelisted_caller();
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), code_hash);
+
+		assert!(!Coupons::<T>::contains_key(code_hash));
+	}
+
+	#[benchmark]
+	fn request_inference_with_coupon() {
+		let owner: T::AccountId = account("owner", 0, 0);
+		let model_id = T::Models::create_model(&owner);
+		Quotas::<T>::insert(model_id, u32::MAX);
+		let code_hash = setup_coupon::<T>(&owner, model_id);
+		let caller: T::AccountId = whitelisted_caller();
+		T::Currency::make_free_balance_be(&caller, BalanceOf::<T>::max_value() / 2u32.into());
+		let code = BoundedVec::truncate_from(COUPON.to_vec());
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), model_id, H256::zero(), code, BalanceOf::<T>::max_value());
+
+		assert_eq!(Coupons::<T>::get(code_hash).map(|coupon| coupon.redeemed), Some(1));
+	}
+
+	#[benchmark]
+	fn set_referral_share() {
+		let owner: T::AccountId = account("owner", 0, 0);
+		let model_id = T::Models::create_model(&owner);
+		let share = Some(T::MaxReferralShare::get());
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(owner), model_id, share);
+
+		assert_eq!(ReferralShares::<T>::get(model_id), share);
+	}
+
+	#[benchmark]
+	fn request_inference_with_referrer() {
+		let owner: T::AccountId = account("owner", 0, 0);
+		let model_id = T::Models::create_model(&owner);
+		Quotas::<T>::insert(model_id, u32::MAX);
+		ReferralShares::<T>::insert(model_id, T::MaxReferralShare::get());
+		let caller: T::AccountId = whitelisted_caller();
+		T::Currency::make_free_balance_be(&caller, BalanceOf::<T>::max_value() / 2u32.into());
+
+		#[extrinsic_call]
+		_(
+			RawOrigin::Signed(caller),
+			model_id,
+			H256::zero(),
+			account("referrer", 0, 0),
+			BalanceOf::<T>::max_value(),
+		);
+
+		assert!(Referrals::<T>::contains_key(0));
+	}
+
+	#[benchmark]
+	fn fund_trial_pool() {
+		let owner: T::AccountId = account("owner", 0, 0);
+		let model_id = T::Models::create_model(&owner);
+		T::Currency::make_free_balance_be(&owner, BalanceOf::<T>::max_value() / 2u32.into());
+		let amount = BalanceOf::<T>::from(1_000_000u32);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(owner), model_id, 10, amount);
+
+		assert_eq!(
+			TrialPools::<T>::get(model_id).map(|pool| T::Escrow::held(pool.escrow)),
+			Some(amount)
+		);
+	}
+
+	#[benchmark]
+	fn close_trial_pool() {
+		let owner: T::AccountId = account("owner", 0, 0);
+		let model_id = T::Models::create_model(&owner);
+		T::Currency::make_free_balance_be(&owner, BalanceOf::<T>::max_value() / 2u32.into());
+		let _ = Pallet::<T>::fund_trial_pool(
+			RawOrigin::Signed(owner.clone()).into(),
+			model_id,
+			10,
+			1_000_000u32.into(),
+		);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(owner), model_id);
+
+		assert!(!TrialPools::<T>::contains_key(model_id));
+	}
+
+	#[benchmark]
+	fn request_trial_inference() {
+		let owner: T::AccountId = account("owner", 0, 0);
+		let model_id = T::Models::create_model(&owner);
+		Quotas::<T>::insert(model_id, u32::MAX);
+		T::Currency::make_free_balance_be(&owner, BalanceOf::<T>::max_value() / 2u32.into());
+		let _ = Pallet::<T>::fund_trial_pool(
+			RawOrigin::Signed(owner).into(),
+			model_id,
+			10,
+			BalanceOf::<T>::max_value() / 4u32.into(),
+		);
+		// Trial users only need an account to receive the price into
+		let caller: T::AccountId = whitelisted_caller();
+		T::Currency::make_free_balance_be(&caller, T::Currency::minimum_balance());
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller.clone()), model_id, H256::zero());
+
+		assert_eq!(TrialClaims::<T>::get(&caller, model_id), 1);
+	}
+
+	#[benchmark]
+	fn set_demand_pricing() {
+		let owner: T::AccountId = account("owner", 0, 0);
+		let model_id = T::Models::create_model(&owner);
+		// Worst case: turning demand pricing off also drops the counters
+		price_by_demand::<T>(model_id);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(owner), model_id, None);
+
+		assert!(!DemandPricings::<T>::contains_key(model_id));
+	}
+
+	#[benchmark]
+	fn claim_earnings(s: Linear<1, { T::MaxRevenueShares::get() }>) {
+		let owner: T::AccountId = account("owner", 0, 0);
+		let model_id = T::Models::create_model(&owner);
+		// Every shareholder is paid their part of the earnings
+		split_revenue::<T>(model_id, s);
+		let earned = T::Currency::minimum_balance().saturating_mul(1_000u32.into());
+		T::Currency::make_free_balance_be(
+			&Pallet::<T>::earnings_account(),
+			earned.saturating_add(T::Currency::minimum_balance()),
+		);
+		Earnings::<T>::insert(model_id, earned);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(owner), model_id);
+
+		assert!(Earnings::<T>::get(model_id).is_zero());
+	}
+
+	#[benchmark]
+	fn sweep_earnings(n: Linear<0, { T::MaxPayoutsPerBlock::get() }>) {
+		let earned = T::Currency::minimum_balance().saturating_mul(1_000u32.into());
+		for i in 0..n {
+			let model_id = T::Models::create_model(&account("owner", i, 0));
+			split_revenue::<T>(model_id, T::MaxRevenueShares::get());
+			Earnings::<T>::insert(model_id, earned);
+		}
+		T::Currency::make_free_balance_be(
+			&Pallet::<T>::earnings_account(),
+			earned.saturating_mul(n.into()).saturating_add(T::Currency::minimum_balance()),
+		);
+		PayoutSweep::<T>::put(SweepProgress::Started);
+
+		#[block]
+		{
+			Pallet::<T>::sweep_earnings();
+		}
+
+		assert_eq!(Earnings::<T>::iter().count(), 0);
+	}
+
+	#[benchmark]
+	fn prioritize_inference() {
+		let owner: T::AccountId = account("owner", 0, 0);
+		let model_id = T::Models::create_model(&owner);
+		price_by_demand::<T>(model_id);
+		let call = Call::<T>::request_inference {
+			model_id,
+			input_hash: H256::zero(),
+			max_price: BalanceOf::<T>::max_value(),
+		};
+		let caller: T::AccountId = whitelisted_caller();
+		T::Currency::make_free_balance_be(&caller, BalanceOf::<T>::max_value() / 2u32.into());
+		let payment;
+
+		#[block]
+		{
+			payment = Pallet::<T>::inference_payment(&call)
+				.filter(|payment| T::Currency::free_balance(&caller) >= *payment);
+		}
+
+		assert!(payment.is_some());
+	}
+
+	#[benchmark]
+	fn set_promo_price() {
+		let owner: T::AccountId = account("owner", 0, 0);
+		let model_id = T::Models::create_model(&owner);
+		let until = frame_system::Pallet::<T>::block_number() + 10u32.into();
+
+		#[extrinsic_call]
+		set_promo_price(RawOrigin::Signed(owner), model_id, 1_000u32.into(), until);
+
+		assert!(PromoPrices::<T>::contains_key(model_id));
+	}
+
+	#[benchmark]
+	fn submit_result() {
+		let (_, _, request_id) = setup_request::<T>();
+		let provider = assign::<T>(request_id);
+		let output_cid: BoundedVec<u8, T::MaxCidLength> =
+			vec![b'b'; T::MaxCidLength::get() as usize].try_into().unwrap();
+
+		#[extrinsic_call]
+		submit_result(RawOrigin::Signed(provider), request_id, output_cid, H256::zero());
+
+		assert_eq!(Requests::<T>::get(request_id).unwrap().state, RequestState::Completed);
+	}
+
+	#[benchmark]
+	fn set_response_deadline() {
+		let owner: T::AccountId = account("owner", 0, 0);
+		let model_id = T::Models::create_model(&owner);
+
+		#[extrinsic_call]
+		set_response_deadline(RawOrigin::Signed(owner), model_id, Some(10u32.into()));
+
+		assert!(ResponseDeadlines::<T>::contains_key(model_id));
+	}
+
+	#[benchmark]
+	fn refund_timed_out() {
+		let (_, model_id, request_id) = setup_request::<T>();
+		// Refunding an assigned request also releases the provider's job
+		assign::<T>(request_id);
+		ResponseDeadlines::<T>::insert(model_id, BlockNumberFor::<T>::from(1u32));
+		skip_blocks::<T>(2u32.into());
+		let caller: T::AccountId = account("caller", 0, 0);
+
+		#[extrinsic_call]
+		refund_timed_out(RawOrigin::Signed(caller), request_id);
+
+		assert_eq!(Requests::<T>::get(request_id).unwrap().state, RequestState::Failed);
+	}
+
+	#[benchmark]
+	fn set_batch_discount() {
+		let owner: T::AccountId = account("owner", 0, 0);
+		let model_id = T::Models::create_model(&owner);
+		let discount = BatchDiscount { min_size: 2, discount: Percent::from_percent(10) };
+
+		#[extrinsic_call]
+		set_batch_discount(RawOrigin::Signed(owner), model_id, Some(discount));
+
+		assert!(BatchDiscounts::<T>::contains_key(model_id));
+	}
+
+	#[benchmark]
+	fn request_inference_batch(n: Linear<1, { T::MaxBatchSize::get() }>) {
+		let owner: T::AccountId = account("owner", 0, 0);
+		let model_id = T::Models::create_model(&owner);
+		Quotas::<T>::insert(model_id, u32::MAX);
+		price_by_demand::<T>(model_id);
+		BatchDiscounts::<T>::insert(
+			model_id,
+			BatchDiscount { min_size: 1, discount: Percent::from_percent(10) },
+		);
+		let caller: T::AccountId = whitelisted_caller();
+		T::Currency::make_free_balance_be(&caller, BalanceOf::<T>::max_value() / 2u32.into());
+		let input_hashes: BoundedVec<H256, T::MaxBatchSize> =
+			(0..n).map(|i| H256::repeat_byte(i as u8)).collect::<Vec<_>>().try_into().unwrap();
+
+		#[extrinsic_call]
+		request_inference_batch(
+			RawOrigin::Signed(caller),
+			model_id,
+			input_hashes,
+			BalanceOf::<T>::max_value(),
+		);
+
+		assert!(Requests::<T>::contains_key(n as RequestId - 1));
+	}
+
+	#[benchmark]
+	fn set_unit_price() {
+		let owner: T::AccountId = account("owner", 0, 0);
+		let model_id = T::Models::create_model(&owner);
+
+		#[extrinsic_call]
+		set_unit_price(RawOrigin::Signed(owner), model_id, Some(10u32.into()));
+
+		assert!(UnitPrices::<T>::contains_key(model_id));
+	}
+
+	#[benchmark]
+	fn open_metered_session() {
+		let owner: T::AccountId = account("owner", 0, 0);
+		let model_id = T::Models::create_model(&owner);
+		UnitPrices::<T>::insert(model_id, BalanceOf::<T>::from(10u32));
+		Quotas::<T>::insert(model_id, u32::MAX);
+		let caller: T::AccountId = whitelisted_caller();
+		T::Currency::make_free_balance_be(&caller, BalanceOf::<T>::max_value() / 2u32.into());
+
+		#[extrinsic_call]
+		open_metered_session(
+			RawOrigin::Signed(caller),
+			model_id,
+			H256::zero(),
+			1_000,
+			BalanceOf::<T>::max_value(),
+		);
+
+		assert!(MeteredSessions::<T>::contains_key(0));
+	}
+
+	#[benchmark]
+	fn accept_metered_session() {
+		let session_id = setup_session::<T>();
+		let provider: T::AccountId = account("provider", 0, 0);
+		T::Providers::create_provider(&provider);
+
+		#[extrinsic_call]
+		accept_metered_session(RawOrigin::Signed(provider.clone()), session_id);
+
+		assert_eq!(MeteredSessions::<T>::get(session_id).unwrap().provider, Some(provider));
+	}
+
+	#[benchmark]
+	fn report_usage() {
+		let session_id = setup_session::<T>();
+		let provider = accept_session::<T>(session_id);
+
+		#[extrinsic_call]
+		report_usage(RawOrigin::Signed(provider), session_id, 100);
+
+		assert_eq!(MeteredSessions::<T>::get(session_id).unwrap().consumed, 100);
+	}
+
+	#[benchmark]
+	fn close_metered_session() {
+		let session_id = setup_session::<T>();
+		// Closing a consumed session also credits the provider and the model
+		let provider = accept_session::<T>(session_id);
+		let _ =
+			Pallet::<T>::report_usage(RawOrigin::Signed(provider.clone()).into(), session_id, 100);
+
+		#[extrinsic_call]
+		close_metered_session(RawOrigin::Signed(provider), session_id);
+
+		assert!(!MeteredSessions::<T>::contains_key(session_id));
+	}
+
+	#[benchmark]
+	fn expire_metered_session() {
+		let session_id = setup_session::<T>();
+		let provider = accept_session::<T>(session_id);
+		let _ =
+			Pallet::<T>::report_usage(RawOrigin::Signed(provider.clone()).into(), session_id, 100);
+		skip_blocks::<T>(T::MeteredSessionTimeout::get().saturating_add(1u32.into()));
+		let caller: T::AccountId = whitelisted_caller();
+
+		#[extrinsic_call]
+		expire_metered_session(RawOrigin::Signed(caller), session_id);
+
+		assert!(!MeteredSessions::<T>::contains_key(session_id));
+	}
+
+	#[benchmark]
+	fn settle_tab() {
+		let owner: T::AccountId = account("owner", 0, 0);
+		let model_id = T::Models::create_model(&owner);
+		let buyer = run_up_tab::<T>(0, model_id);
+		let caller: T::AccountId = whitelisted_caller();
+
+		#[extrinsic_call]
+		settle_tab(RawOrigin::Signed(caller), buyer.clone(), model_id);
+
+		assert!(!Tabs::<T>::contains_key(buyer, model_id));
+	}
+
+	#[benchmark]
+	fn settle_tabs(n: Linear<0, { T::MaxTabSettlementsPerBlock::get() }>) {
+		let owner: T::AccountId = account("owner", 0, 0);
+		let model_id = T::Models::create_model(&owner);
+		for i in 0..n {
+			run_up_tab::<T>(i, model_id);
+		}
+		TabSweep::<T>::put(SweepProgress::Started);
+
+		#[block]
+		{
+			Pallet::<T>::settle_tabs();
+		}
+
+		assert_eq!(Tabs::<T>::iter().count(), 0);
+	}
+
+	#[benchmark]
+	fn challenge_result() {
+		let (_, _, request_id) = setup_request::<T>();
+		let provider = assign::<T>(request_id);
+		commit_and_reveal::<T>(provider, request_id);
+		let challenger: T::AccountId = account("challenger", 0, 0);
+		T::Currency::make_free_balance_be(&challenger, BalanceOf::<T>::max_value() / 2u32.into());
+
+		#[extrinsic_call]
+		challenge_result(RawOrigin::Signed(challenger), request_id, H256::repeat_byte(2));
+
+		assert_eq!(Requests::<T>::get(request_id).unwrap().state, RequestState::Challenged);
+	}
+
+	#[benchmark]
+	fn concede_challenge() {
+		let (_, _, request_id) = setup_request::<T>();
+		let provider = assign::<T>(request_id);
+		commit_and_reveal::<T>(provider.clone(), request_id);
+		challenge::<T>(request_id);
+
+		#[extrinsic_call]
+		concede_challenge(RawOrigin::Signed(provider), request_id);
+
+		assert_eq!(Requests::<T>::get(request_id).unwrap().state, RequestState::Failed);
+	}
+
+	#[benchmark]
+	fn resolve_challenge() -> Result<(), BenchmarkError> {
+		let (_, _, request_id) = setup_request::<T>();
+		let provider = assign::<T>(request_id);
+		commit_and_reveal::<T>(provider, request_id);
+		challenge::<T>(request_id);
+		skip_blocks::<T>(T::ChallengePeriod::get());
+		let origin = T::ArbitrationOrigin::try_successful_origin()
+			.map_err(|_| BenchmarkError::Weightless)?;
+
+		// Releasing the payment is covered by the weight of `settle_request`
+		#[extrinsic_call]
+		resolve_challenge(origin as T::RuntimeOrigin, request_id, true);
+
+		assert_eq!(Requests::<T>::get(request_id).unwrap().state, RequestState::Failed);
+		Ok(())
+	}
+
+	impl_benchmark_test_suite!(Pallet, crate::mock::new_test_ext(), crate::mock::Test);
+}
@@ -0,0 +1,105 @@
+//! Transaction extensions for the Inference pallet
+
+use crate::{BalanceOf, Call, Config, Pallet, WeightInfo};
+use codec::{Decode, DecodeWithMemTracking, Encode};
+use core::marker::PhantomData;
+use frame_support::{
+	pallet_prelude::TransactionSource,
+	traits::{Currency, Get, IsSubType},
+	weights::Weight,
+	DefaultNoBound,
+};
+use scale_info::TypeInfo;
+use sp_runtime::{
+	impl_tx_ext_default,
+	traits::{
+		AsSystemOriginSigner, CheckedDiv, DispatchInfoOf, SaturatedConversion, TransactionExtension,
+	},
+	transaction_validity::{InvalidTransaction, TransactionValidityError, ValidTransaction},
+};
+
+/// Raises the pool priority of inference requests by the payment they make.
+///
+/// Every `PaymentPerPriority` a request pays at the model's current price adds one
+/// to its priority, up to `MaxPriorityBoost`, on top of what the other extensions
+/// give it, so valuable requests are not starved by cheap spam when blocks are full.
+/// Requests paid with coupons, trial credits or assets, and requests that would fail
+/// on price, are not boosted; paid requests whose signer cannot cover the payment
+/// are rejected.
+#[derive(Encode, Decode, DecodeWithMemTracking, DefaultNoBound, Clone, Eq, PartialEq, TypeInfo)]
+#[scale_info(skip_type_params(T))]
+pub struct PrioritizePaidInference<T>(PhantomData<T>);
+
+impl<T: Config + Send + Sync> core::fmt::Debug for PrioritizePaidInference<T> {
+	fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+		write!(f, "PrioritizePaidInference")
+	}
+}
+
+impl<T: Config + Send + Sync> PrioritizePaidInference<T> {
+	/// Create the extension
+	pub fn new() -> Self {
+		Self(PhantomData)
+	}
+
+	/// Priority `call` signed by `who` is raised by
+	///
+	/// Fails with `InvalidTransaction::Payment` if `who` cannot pay for the request.
+	pub fn priority(
+		who: &T::AccountId,
+		call: &T::RuntimeCall,
+	) -> Result<u64, TransactionValidityError>
+	where
+		T::RuntimeCall: IsSubType<Call<T>>,
+	{
+		let Some(payment) = call.is_sub_type().and_then(Pallet::<T>::inference_payment) else {
+			return Ok(0);
+		};
+		if T::Currency::free_balance(who) < payment {
+			return Err(InvalidTransaction::Payment.into());
+		}
+		let priority = payment
+			.checked_div(&T::PaymentPerPriority::get())
+			.map_or(0, |priority: BalanceOf<T>| priority.saturated_into::<u64>());
+		Ok(priority.min(T::MaxPriorityBoost::get()))
+	}
+}
+
+impl<T: Config + Send + Sync> TransactionExtension<T::RuntimeCall> for PrioritizePaidInference<T>
+where
+	T::RuntimeCall: IsSubType<Call<T>>,
+	T::RuntimeOrigin: AsSystemOriginSigner<T::AccountId> + Clone,
+{
+	const IDENTIFIER: &'static str = "PrioritizePaidInference";
+	type Implicit = ();
+	type Val = ();
+	type Pre = ();
+
+	fn weight(&self, call: &T::RuntimeCall) -> Weight {
+		if call.is_sub_type().is_some() {
+			T::WeightInfo::prioritize_inference()
+		} else {
+			Weight::zero()
+		}
+	}
+
+	fn validate(
+		&self,
+		origin: T::RuntimeOrigin,
+		call: &T::RuntimeCall,
+		_info: &DispatchInfoOf<T::RuntimeCall>,
+		_len: usize,
+		_self_implicit: Self::Implicit,
+		_inherited_implication: &impl Encode,
+		_source: TransactionSource,
+	) -> sp_runtime::traits::ValidateResult<Self::Val, T::RuntimeCall> {
+		let priority = match origin.as_system_origin_signer() {
+			Some(who) => Self::priority(who, call)?,
+			None => 0,
+		};
+		let validity = ValidTransaction { priority, ..Default::default() };
+		Ok((validity, (), origin))
+	}
+
+	impl_tx_ext_default!(T::RuntimeCall; prepare);
+}
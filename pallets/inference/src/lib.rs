@@ -0,0 +1,3517 @@
+//! # Inference Pallet
+//!
+//! On-chain queue of paid inference requests for models in the AI registry.
+//!
+//! ## Overview
+//!
+//! Each request moves through explicit states so that clients and compute
+//! providers coordinate through chain state:
+//!
+//! `Requested -> Assigned -> Committed -> Completed -> Settled`, or `Failed`
+//!
+//! - A client requests an inference and the model price is escrowed from them.
+//! - A compute provider able to serve the model type picks the request up.
+//! - The provider commits to a hash of the result before the completion deadline
+//!   and reveals it after `RevealDelay` blocks. A reveal that does not match the
+//!   commitment, or no reveal within `RevealWindow`, refunds the client.
+//!   Alternatively the provider, or the model owner, submits the result with
+//!   the IPFS CID it is stored under in one step.
+//! - The client settles the request, releasing the payment into the model's
+//!   earnings, or disputes the result within `AcceptanceWindow` blocks through the
+//!   disputes pallet, which holds the payment until its ruling refunds the client
+//!   in full or in part and pays the model the rest. Payments the client does
+//!   neither for are released automatically once the window is over. Claiming
+//!   earnings pays the model owner, or its shareholders by share if the model is
+//!   fractionally owned, after the runtime's `EarningsCuts`. Earnings nobody
+//!   claims are paid out every `PayoutEraLength` blocks.
+//!
+//! Payments are held by the runtime's `Escrow` until they are released: every
+//! open request, metered session, trial pool and tab has an escrow of its own.
+//!
+//! Native fees, of requests and of metered usage alike, are not transferred one
+//! by one. They run up on a tab per buyer and model, still escrowed from the buyer,
+//! which is moved into the model's earnings in a single transfer once it reaches
+//! `TabSettlementThreshold`, and at the end of every era otherwise.
+//!
+//! Results are also verified optimistically. Within the acceptance window anyone
+//! but the buyer and the provider can challenge a result, bonding `ChallengeBond`
+//! through the escrow pallet on the hash of the result they claim is correct. The
+//! provider or the model owner can concede, refunding the buyer in full; challenges
+//! not conceded within `ChallengePeriod` escalate to the `ArbitrationOrigin`. Upheld
+//! challengers get their bond back and `ChallengeReward` of the payment slashed from
+//! the provider's stake, while rejected ones forfeit their bond to the provider.
+//!
+//! Runtimes able to verify zkML or STARK proofs of correct execution can plug in
+//! a `ProofVerifier`; payments for models it requires proofs for are only
+//! released once `submit_inference_proof` has accepted one.
+//!
+//! Owners of private models can require providers to serve them from a trusted
+//! execution environment, optionally with a specific enclave measurement, using
+//! the attestations recorded by the compute providers pallet.
+//!
+//! Owners can also accept payment in other assets, e.g. a stablecoin, at a fixed
+//! price per asset. Asset payments are escrowed like native ones and paid to the
+//! model's owner or shareholders directly when the request settles.
+//!
+//! Owners can denominate a model's price in US cents instead of native tokens.
+//! The runtime's `PriceFeed` converts it when a request is made, and the
+//! converted amount is escrowed, so later rate changes do not affect open
+//! requests.
+//!
+//! Owners can cap the inferences each account may request from a model per
+//! `QuotaPeriod` blocks, e.g. for free tiers. Usage is counted when a request is
+//! made, whether or not it later succeeds, and resets at the start of each period.
+//!
+//! Owners can hand out discount codes for a model. Only the hash of a code is
+//! stored; buyers reveal the code itself when they request an inference with it.
+//! Codes can be redeemed a limited number of times until they expire.
+//!
+//! To reward distribution, owners can pass up to `MaxReferralShare` of a model's
+//! price to whoever referred the buyer. Buyers name their referrer when making a
+//! request; the referrer is paid when the request settles, and their earnings are
+//! tracked per model.
+//!
+//! Owners can also fund a trial pool from which every account gets a number of
+//! free inferences of a model. The pool pays the model price of each trial
+//! request; failed trial requests refund the pool and give the credit back.
+//!
+//! Models can opt into demand pricing: every request above a baseline within the
+//! last `DemandWindow` blocks raises the price by a step, up to a cap. Demand is
+//! estimated from rolling counts of the current and previous window.
+//!
+//! Owners can run time-limited promotions: a promotional price replaces the
+//! regular one until a given block and lapses on its own afterwards.
+//!
+//! Buyers can request up to `MaxBatchSize` inferences of a model in one call,
+//! escrowing their total price at once, and owners can discount such batches.
+//!
+//! For long-running inferences such as streamed LLM completions, owners can price
+//! models per unit, e.g. per token or second. Buyers open a metered session
+//! prepaying a cap of units, the provider reports the units it consumes and each
+//! report is settled into the model's earnings right away. Closing the session
+//! refunds the units left; a session without a report for `MeteredSessionTimeout`
+//! blocks can be expired by anyone.
+//!
+//! Requests nobody picks up within `AssignmentTimeout`, that the provider does not
+//! commit to within `CompletionTimeout`, or whose result is not revealed in time,
+//! can be expired by anyone and are refunded. Open requests are tracked in a queue per model bounded by
+//! `MaxQueueLength`.
+//!
+//! Owners can also promise results within a number of blocks of a request. Anyone
+//! can refund a request still without a result after that, earning
+//! `TimeoutRefundReward` of its payment from the provider's stake for it.
+//!
+//! Runtimes can add the [`PrioritizePaidInference`] transaction extension to raise
+//! the pool priority of paid requests by what they pay.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+pub use pallet::*;
+
+#[cfg(test)]
+mod mock;
+
+#[cfg(test)]
+mod tests;
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+
+pub mod weights;
+pub use weights::*;
+
+pub mod types;
+pub use types::*;
+
+pub mod traits;
+pub use traits::*;
+
+pub mod origins;
+pub use origins::*;
+
+pub mod extensions;
+pub use extensions::*;
+
+#[frame_support::pallet]
+pub mod pallet {
+	use super::*;
+	use frame_support::{
+		pallet_prelude::*,
+		storage::with_storage_layer,
+		traits::{fungibles, Currency, ExistenceRequirement, ReservableCurrency},
+		PalletId,
+	};
+	use frame_system::pallet_prelude::*;
+	use pallet_ai_registry::ModelId;
+	use pallet_compute_providers::{ComputeProviders, JobOutcome};
+	use pallet_escrow::{AssetEscrow, Escrow, EscrowId};
+	use sp_core::H256;
+	use sp_runtime::{
+		traits::{
+			AccountIdConversion, BlakeTwo256, CheckedDiv, Hash, One, SaturatedConversion,
+			Saturating, Zero,
+		},
+		Perbill, Percent,
+	};
+	use sp_std::collections::btree_map::BTreeMap;
+
+	pub(crate) type BalanceOf<T> =
+		<<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
+	pub(crate) type AssetIdOf<T> = <<T as Config>::Assets as fungibles::Inspect<
+		<T as frame_system::Config>::AccountId,
+	>>::AssetId;
+	pub(crate) type TrialPoolOf<T> = TrialPool<<T as frame_system::Config>::AccountId>;
+	pub(crate) type InferenceRequestOf<T> =
+		InferenceRequest<<T as frame_system::Config>::AccountId, BalanceOf<T>, BlockNumberFor<T>>;
+	pub(crate) type MeteredSessionOf<T> =
+		MeteredSession<<T as frame_system::Config>::AccountId, BalanceOf<T>, BlockNumberFor<T>>;
+	pub(crate) type ChallengeOf<T> =
+		Challenge<<T as frame_system::Config>::AccountId, BlockNumberFor<T>>;
+
+	/// The in-code storage version
+	pub const STORAGE_VERSION: StorageVersion = StorageVersion::new(0);
+
+	#[pallet::pallet]
+	#[pallet::storage_version(STORAGE_VERSION)]
+	pub struct Pallet<T>(_);
+
+	/// Origin the pallet calls into other pallets with
+	#[pallet::origin]
+	#[derive(
+		Clone,
+		PartialEq,
+		Eq,
+		RuntimeDebug,
+		Encode,
+		Decode,
+		DecodeWithMemTracking,
+		TypeInfo,
+		MaxEncodedLen,
+	)]
+	pub enum Origin {
+		/// The pallet records the outcome of an inference request
+		Inference,
+	}
+
+	/// Configuration trait for the Inference pallet
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		/// The overarching event type
+		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+		/// Weight information for extrinsics
+		type WeightInfo: WeightInfo;
+
+		/// Currency used to pay for inferences
+		type Currency: ReservableCurrency<Self::AccountId>;
+
+		/// Models that can be paid for
+		type Models: InferenceModels<Self::AccountId, BalanceOf<Self>>;
+
+		/// Maximum number of accounts sharing a model's revenue, bounding the
+		/// payouts of `settle_request`
+		#[pallet::constant]
+		type MaxRevenueShares: Get<u32>;
+
+		/// Compute providers that run the inferences
+		type Providers: ComputeProviders<Self::AccountId, BalanceOf<Self>>;
+
+		/// Maximum number of open requests per model
+		#[pallet::constant]
+		type MaxQueueLength: Get<u32>;
+
+		/// Blocks a request waits for a provider before it can be expired
+		#[pallet::constant]
+		type AssignmentTimeout: Get<BlockNumberFor<Self>>;
+
+		/// Blocks a provider has to commit to a result for a request it picked up
+		#[pallet::constant]
+		type CompletionTimeout: Get<BlockNumberFor<Self>>;
+
+		/// Blocks between committing to a result and revealing it
+		#[pallet::constant]
+		type RevealDelay: Get<BlockNumberFor<Self>>;
+
+		/// Blocks a provider has to reveal a result once it can be revealed
+		#[pallet::constant]
+		type RevealWindow: Get<BlockNumberFor<Self>>;
+
+		/// Verifier for proofs of correct execution; `()` if the runtime cannot verify any
+		type ProofVerifier: ProofVerifier;
+
+		/// Maximum size of an execution proof in bytes
+		#[pallet::constant]
+		type MaxProofLength: Get<u32>;
+
+		/// Converts USD prices to native tokens; `()` if no oracle is available
+		type PriceFeed: PriceFeed<BalanceOf<Self>>;
+
+		/// Splits cuts off settled inference fees, paying at most `MaxRevenueShares`
+		/// of them; `()` to pay the whole fee to the model
+		type OnInferenceFee: OnInferenceFee<Self::AccountId, BalanceOf<Self>>;
+
+		/// Assets owners can accept payment in besides the native token
+		type Assets: fungibles::Mutate<Self::AccountId, Balance = BalanceOf<Self>>
+			+ fungibles::Create<Self::AccountId>;
+
+		/// Pallet ID, used to derive the account holding earnings until they are claimed
+		#[pallet::constant]
+		type PalletId: Get<PalletId>;
+
+		/// Length in blocks of the periods per-account quotas reset after
+		#[pallet::constant]
+		type QuotaPeriod: Get<BlockNumberFor<Self>>;
+
+		/// Maximum length of a coupon code in bytes
+		#[pallet::constant]
+		type MaxCouponLength: Get<u32>;
+
+		/// Highest share of a model's price owners can pass to referrers
+		#[pallet::constant]
+		type MaxReferralShare: Get<Perbill>;
+
+		/// Length in blocks of the window demand-priced models count requests over
+		#[pallet::constant]
+		type DemandWindow: Get<BlockNumberFor<Self>>;
+
+		/// Blocks between automatic payouts of every model's earnings; zero leaves
+		/// them to `claim_earnings`
+		#[pallet::constant]
+		type PayoutEraLength: Get<BlockNumberFor<Self>>;
+
+		/// Maximum number of models whose earnings are paid out automatically per block
+		#[pallet::constant]
+		type MaxPayoutsPerBlock: Get<u32>;
+
+		/// Accounts taking a share of model earnings when they are paid out, e.g. the
+		/// treasury and validators; at most `MaxRevenueShares` of them are paid
+		type EarningsCuts: Get<Vec<(Self::AccountId, Perbill)>>;
+
+		/// Payment that raises the pool priority of an inference request by one; see
+		/// [`PrioritizePaidInference`]
+		#[pallet::constant]
+		type PaymentPerPriority: Get<BalanceOf<Self>>;
+
+		/// Most pool priority an inference request can gain from its payment
+		#[pallet::constant]
+		type MaxPriorityBoost: Get<u64>;
+
+		/// Maximum length of the IPFS CID of a submitted result
+		#[pallet::constant]
+		type MaxCidLength: Get<u32>;
+
+		/// Blocks buyers have to accept or dispute a completed request before its
+		/// payment is released automatically; zero leaves it to `settle_request`
+		#[pallet::constant]
+		type AcceptanceWindow: Get<BlockNumberFor<Self>>;
+
+		/// Maximum number of requests whose payment is released automatically per
+		/// block, bounding the requests completed per block
+		#[pallet::constant]
+		type MaxAutoReleasesPerBlock: Get<u32>;
+
+		/// Origin ruling on challenged results
+		type ArbitrationOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+		/// Share of a timed-out request's payment slashed from its provider's stake to
+		/// whoever refunds it
+		#[pallet::constant]
+		type TimeoutRefundReward: Get<Perbill>;
+
+		/// Maximum number of requests in a batch
+		#[pallet::constant]
+		type MaxBatchSize: Get<u32>;
+
+		/// Amount a buyer's tab with a model is settled at right away; zero settles
+		/// every native fee when it is charged
+		#[pallet::constant]
+		type TabSettlementThreshold: Get<BalanceOf<Self>>;
+
+		/// Maximum number of tabs settled per block at the end of an era
+		#[pallet::constant]
+		type MaxTabSettlementsPerBlock: Get<u32>;
+
+		/// Blocks a metered session can go without usage reports before it can be
+		/// expired
+		#[pallet::constant]
+		type MeteredSessionTimeout: Get<BlockNumberFor<Self>>;
+
+		/// Escrow holding payments, in `Currency` or `Assets`, and challenge bonds
+		type Escrow: AssetEscrow<
+			Self::AccountId,
+			AssetIdOf<Self>,
+			BalanceOf<Self>,
+			BlockNumberFor<Self>,
+		>;
+
+		/// Bond posted by whoever challenges a result
+		#[pallet::constant]
+		type ChallengeBond: Get<BalanceOf<Self>>;
+
+		/// Blocks the provider or model owner has to concede a challenge before it
+		/// escalates to arbitration
+		#[pallet::constant]
+		type ChallengePeriod: Get<BlockNumberFor<Self>>;
+
+		/// Share of a challenged request's payment slashed from the provider's stake to
+		/// the challenger if the challenge is upheld
+		#[pallet::constant]
+		type ChallengeReward: Get<Perbill>;
+
+		/// Creates assets for benchmarks
+		#[cfg(feature = "runtime-benchmarks")]
+		type BenchmarkHelper: BenchmarkHelper<AssetIdOf<Self>>;
+	}
+
+	/// Inference requests, kept after settlement as payment receipts
+	#[pallet::storage]
+	pub type Requests<T: Config> =
+		StorageMap<_, Blake2_128Concat, RequestId, InferenceRequestOf<T>>;
+
+	/// IPFS CIDs of results submitted with `submit_result`
+	#[pallet::storage]
+	pub type ResultCids<T: Config> =
+		StorageMap<_, Blake2_128Concat, RequestId, BoundedVec<u8, T::MaxCidLength>>;
+
+	/// Completed requests whose payment is released at the start of each block
+	/// unless their buyer accepted or disputed them before
+	#[pallet::storage]
+	pub type AutoReleases<T: Config> = StorageMap<
+		_,
+		Twox64Concat,
+		BlockNumberFor<T>,
+		BoundedVec<RequestId, T::MaxAutoReleasesPerBlock>,
+		ValueQuery,
+	>;
+
+	/// Blocks within which the owners of models promise results to requests
+	#[pallet::storage]
+	pub type ResponseDeadlines<T: Config> =
+		StorageMap<_, Blake2_128Concat, ModelId, BlockNumberFor<T>, OptionQuery>;
+
+	/// Requested and assigned requests per model, oldest first
+	#[pallet::storage]
+	pub type ModelQueue<T: Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		ModelId,
+		BoundedVec<RequestId, T::MaxQueueLength>,
+		ValueQuery,
+	>;
+
+	/// Counter for generating unique request IDs
+	#[pallet::storage]
+	pub type NextRequestId<T: Config> = StorageValue<_, RequestId, ValueQuery>;
+
+	/// Most recent settled request of each account for each model
+	#[pallet::storage]
+	pub type LatestReceipt<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::AccountId,
+		Blake2_128Concat,
+		ModelId,
+		RequestId,
+		OptionQuery,
+	>;
+
+	/// Settled inferences each account purchased from each model
+	#[pallet::storage]
+	pub type InferencesPurchased<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::AccountId,
+		Blake2_128Concat,
+		ModelId,
+		u32,
+		ValueQuery,
+	>;
+
+	/// Inference fees each account paid on settled requests of each model
+	#[pallet::storage]
+	pub type FeesPaid<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::AccountId,
+		Blake2_128Concat,
+		ModelId,
+		BalanceOf<T>,
+		ValueQuery,
+	>;
+
+	/// Models that may only be served from an attested enclave
+	#[pallet::storage]
+	pub type EnclaveRequirements<T: Config> =
+		StorageMap<_, Blake2_128Concat, ModelId, EnclaveRequirement, OptionQuery>;
+
+	/// Prices in US cents of models priced in USD, overriding the registry price
+	#[pallet::storage]
+	pub type UsdPrices<T: Config> = StorageMap<_, Blake2_128Concat, ModelId, u64, OptionQuery>;
+
+	/// Promotional prices of models and the block they end at, overriding the
+	/// registry and USD price until then
+	#[pallet::storage]
+	pub type PromoPrices<T: Config> =
+		StorageMap<_, Blake2_128Concat, ModelId, (BalanceOf<T>, BlockNumberFor<T>), OptionQuery>;
+
+	/// Prices of models in the assets their owners accept
+	#[pallet::storage]
+	pub type AssetPrices<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		ModelId,
+		Blake2_128Concat,
+		AssetIdOf<T>,
+		BalanceOf<T>,
+		OptionQuery,
+	>;
+
+	/// Asset each request was paid in, for requests not paid in native tokens
+	#[pallet::storage]
+	pub type RequestAssets<T: Config> =
+		StorageMap<_, Blake2_128Concat, RequestId, AssetIdOf<T>, OptionQuery>;
+
+	/// Escrow holding the payment of each open request
+	#[pallet::storage]
+	pub type RequestEscrows<T: Config> =
+		StorageMap<_, Blake2_128Concat, RequestId, EscrowId, OptionQuery>;
+
+	/// Maximum number of requests each account can make for a model per `QuotaPeriod`
+	#[pallet::storage]
+	pub type Quotas<T: Config> = StorageMap<_, Blake2_128Concat, ModelId, u32, OptionQuery>;
+
+	/// Period of each account's latest request for a quota-limited model, and the
+	/// number of requests made in that period
+	#[pallet::storage]
+	pub type QuotaUsage<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::AccountId,
+		Blake2_128Concat,
+		ModelId,
+		(BlockNumberFor<T>, u32),
+		OptionQuery,
+	>;
+
+	/// Discount codes by the hash of the code
+	#[pallet::storage]
+	pub type Coupons<T: Config> =
+		StorageMap<_, Blake2_128Concat, H256, Coupon<BlockNumberFor<T>>, OptionQuery>;
+
+	/// Share of the price of requests for a model paid to the buyer's referrer
+	#[pallet::storage]
+	pub type ReferralShares<T: Config> =
+		StorageMap<_, Blake2_128Concat, ModelId, Perbill, OptionQuery>;
+
+	/// Referrer of each open request and their share, fixed when the request was made
+	#[pallet::storage]
+	pub type Referrals<T: Config> =
+		StorageMap<_, Blake2_128Concat, RequestId, (T::AccountId, Perbill), OptionQuery>;
+
+	/// Total each referrer earned from requests for each model
+	#[pallet::storage]
+	pub type ReferralEarnings<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		ModelId,
+		Blake2_128Concat,
+		T::AccountId,
+		BalanceOf<T>,
+		ValueQuery,
+	>;
+
+	/// Free inferences offered to new users of each model
+	#[pallet::storage]
+	pub type TrialPools<T: Config> =
+		StorageMap<_, Blake2_128Concat, ModelId, TrialPoolOf<T>, OptionQuery>;
+
+	/// Trial credits each account used for each model
+	#[pallet::storage]
+	pub type TrialClaims<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::AccountId,
+		Blake2_128Concat,
+		ModelId,
+		u32,
+		ValueQuery,
+	>;
+
+	/// Sponsor of each open trial request, refunded if it fails
+	#[pallet::storage]
+	pub type TrialRequests<T: Config> =
+		StorageMap<_, Blake2_128Concat, RequestId, T::AccountId, OptionQuery>;
+
+	/// Native fees settled on each model and not claimed yet, held in the earnings
+	/// account
+	#[pallet::storage]
+	pub type Earnings<T: Config> =
+		StorageMap<_, Blake2_128Concat, ModelId, BalanceOf<T>, ValueQuery>;
+
+	/// Escrows holding the native fees each buyer owes each model
+	#[pallet::storage]
+	pub type Tabs<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::AccountId,
+		Blake2_128Concat,
+		ModelId,
+		EscrowId,
+		OptionQuery,
+	>;
+
+	/// Progress of the settlement of all tabs started at the end of an era
+	#[pallet::storage]
+	pub type TabSweep<T: Config> =
+		StorageValue<_, SweepProgress<(T::AccountId, ModelId)>, OptionQuery>;
+
+	/// Progress of the automatic payout of all earnings, started once the tabs left at
+	/// the end of an era are settled
+	#[pallet::storage]
+	pub type PayoutSweep<T: Config> = StorageValue<_, SweepProgress, OptionQuery>;
+
+	/// Models whose price follows their recent demand
+	#[pallet::storage]
+	pub type DemandPricings<T: Config> =
+		StorageMap<_, Blake2_128Concat, ModelId, DemandPricing, OptionQuery>;
+
+	/// Discounts models give on batches of requests
+	#[pallet::storage]
+	pub type BatchDiscounts<T: Config> =
+		StorageMap<_, Blake2_128Concat, ModelId, BatchDiscount, OptionQuery>;
+
+	/// Prices per consumed unit of models billed by metered sessions
+	#[pallet::storage]
+	pub type UnitPrices<T: Config> =
+		StorageMap<_, Blake2_128Concat, ModelId, BalanceOf<T>, OptionQuery>;
+
+	/// Open metered sessions, removed once closed
+	#[pallet::storage]
+	pub type MeteredSessions<T: Config> =
+		StorageMap<_, Blake2_128Concat, SessionId, MeteredSessionOf<T>, OptionQuery>;
+
+	/// Counter for generating unique session IDs
+	#[pallet::storage]
+	pub type NextSessionId<T: Config> = StorageValue<_, SessionId, ValueQuery>;
+
+	/// Open challenges of completed requests' results
+	#[pallet::storage]
+	pub type Challenges<T: Config> =
+		StorageMap<_, Blake2_128Concat, RequestId, ChallengeOf<T>, OptionQuery>;
+
+	/// Rolling request counts of demand-priced models
+	#[pallet::storage]
+	pub type DemandCounters<T: Config> =
+		StorageMap<_, Blake2_128Concat, ModelId, DemandCounter<BlockNumberFor<T>>, ValueQuery>;
+
+	/// Events emitted by this pallet
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// A client paid for an inference
+		/// [request_id, model_id, requester, price, asset]
+		InferenceRequested {
+			request_id: RequestId,
+			model_id: ModelId,
+			requester: T::AccountId,
+			price: BalanceOf<T>,
+			asset: Option<AssetIdOf<T>>,
+		},
+		/// A provider picked up a request
+		/// [request_id, provider, deadline]
+		RequestAssigned {
+			request_id: RequestId,
+			provider: T::AccountId,
+			deadline: BlockNumberFor<T>,
+		},
+		/// The provider committed to a result
+		/// [request_id, commitment, reveal_from, reveal_until]
+		ResultCommitted {
+			request_id: RequestId,
+			commitment: H256,
+			reveal_from: BlockNumberFor<T>,
+			reveal_until: BlockNumberFor<T>,
+		},
+		/// The provider revealed a result matching their commitment
+		/// [request_id, result_hash]
+		RequestCompleted { request_id: RequestId, result_hash: H256 },
+		/// The payment was released to the model owner, or added to the model's
+		/// earnings if paid in native tokens
+		/// [request_id, owner, amount]
+		RequestSettled { request_id: RequestId, owner: T::AccountId, amount: BalanceOf<T> },
+		/// A valid proof of the result was submitted
+		/// [request_id]
+		InferenceProofVerified { request_id: RequestId },
+		/// A model owner changed the enclave providers must serve the model from
+		/// [model_id, requirement]
+		EnclaveRequirementSet { model_id: ModelId, requirement: Option<EnclaveRequirement> },
+		/// A model owner started or stopped accepting an asset for the model
+		/// [model_id, asset_id, price]
+		AssetPriceSet { model_id: ModelId, asset_id: AssetIdOf<T>, price: Option<BalanceOf<T>> },
+		/// A model owner priced the model in US cents, or back in native tokens
+		/// [model_id, usd_cents]
+		UsdPriceSet { model_id: ModelId, usd_cents: Option<u64> },
+		/// A model owner changed the requests each account can make per period
+		/// [model_id, quota]
+		QuotaSet { model_id: ModelId, quota: Option<u32> },
+		/// A model owner created a discount code
+		/// [code_hash, model_id, discount, max_uses, expires_at]
+		CouponCreated {
+			code_hash: H256,
+			model_id: ModelId,
+			discount: Percent,
+			max_uses: u32,
+			expires_at: BlockNumberFor<T>,
+		},
+		/// A discount code was removed
+		/// [code_hash]
+		CouponRemoved { code_hash: H256 },
+		/// A discount code was redeemed for a request
+		/// [code_hash, request_id, discount]
+		CouponRedeemed { code_hash: H256, request_id: RequestId, discount: BalanceOf<T> },
+		/// A model owner changed the share of the price paid to referrers
+		/// [model_id, share]
+		ReferralShareSet { model_id: ModelId, share: Option<Perbill> },
+		/// A referrer was paid their share of a settled request
+		/// [request_id, referrer, amount]
+		ReferralPaid { request_id: RequestId, referrer: T::AccountId, amount: BalanceOf<T> },
+		/// A sponsor funded free inferences of a model
+		/// [model_id, sponsor, credits_per_account, funds]
+		TrialPoolFunded {
+			model_id: ModelId,
+			sponsor: T::AccountId,
+			credits_per_account: u32,
+			funds: BalanceOf<T>,
+		},
+		/// A sponsor closed a trial pool and got the unspent funds back
+		/// [model_id, refunded]
+		TrialPoolClosed { model_id: ModelId, refunded: BalanceOf<T> },
+		/// A request was paid for from a trial pool
+		/// [request_id, model_id, who]
+		TrialCreditUsed { request_id: RequestId, model_id: ModelId, who: T::AccountId },
+		/// Demand pricing of a model changed
+		/// [model_id, pricing]
+		DemandPricingSet { model_id: ModelId, pricing: Option<DemandPricing> },
+		/// The runtime's fee handler took a cut of a settled request
+		/// [request_id, recipient, amount]
+		InferenceFeeSplit { request_id: RequestId, recipient: T::AccountId, amount: BalanceOf<T> },
+		/// The request was cancelled or timed out and refunded
+		/// [request_id, reason]
+		RequestFailed { request_id: RequestId, reason: FailureReason },
+		/// A model's earnings were paid out to its owner or shareholders, after the
+		/// earnings cuts
+		/// [model_id, amount]
+		EarningsClaimed { model_id: ModelId, amount: BalanceOf<T> },
+		/// A cut of paid out earnings was paid
+		/// [recipient, amount]
+		EarningsCutPaid { recipient: T::AccountId, amount: BalanceOf<T> },
+		/// A model's owner set a promotional price; `None` if they ended the promotion
+		/// [model_id, promo_price, until_block]
+		PromoPriceSet {
+			model_id: ModelId,
+			promo_price: Option<BalanceOf<T>>,
+			until_block: BlockNumberFor<T>,
+		},
+		/// A result was submitted and stored on IPFS, completing the request
+		/// [request_id, output_cid, result_hash]
+		ResultSubmitted {
+			request_id: RequestId,
+			output_cid: BoundedVec<u8, T::MaxCidLength>,
+			result_hash: H256,
+		},
+		/// The buyer disputed a result, holding its payment until the dispute's ruling
+		/// [request_id]
+		ResultDisputed { request_id: RequestId },
+		/// A model's owner declared how many blocks results may take; `None` if they
+		/// withdrew the promise
+		/// [model_id, blocks]
+		ResponseDeadlineSet { model_id: ModelId, blocks: Option<BlockNumberFor<T>> },
+		/// A request past its model's response deadline was refunded
+		/// [request_id, caller, reward]
+		TimedOutRequestRefunded {
+			request_id: RequestId,
+			caller: T::AccountId,
+			reward: BalanceOf<T>,
+		},
+		/// A model's batch discount changed
+		/// [model_id, discount]
+		BatchDiscountSet { model_id: ModelId, discount: Option<BatchDiscount> },
+		/// A buyer requested a batch of inferences, escrowing their total price at once
+		/// [model_id, requester, first_request_id, count, total]
+		BatchRequested {
+			model_id: ModelId,
+			requester: T::AccountId,
+			first_request_id: RequestId,
+			count: u32,
+			total: BalanceOf<T>,
+		},
+		/// A model's owner priced it per unit for metered sessions; `None` if they
+		/// stopped offering them
+		/// [model_id, unit_price]
+		UnitPriceSet { model_id: ModelId, unit_price: Option<BalanceOf<T>> },
+		/// A buyer opened a metered session, prepaying `max_units` units
+		/// [session_id, model_id, requester, unit_price, max_units]
+		MeteredSessionOpened {
+			session_id: SessionId,
+			model_id: ModelId,
+			requester: T::AccountId,
+			unit_price: BalanceOf<T>,
+			max_units: u32,
+		},
+		/// A provider picked up a metered session
+		/// [session_id, provider]
+		MeteredSessionAccepted { session_id: SessionId, provider: T::AccountId },
+		/// The provider reported consumed units, whose price was added to the
+		/// model's earnings
+		/// [session_id, units, amount]
+		UsageReported { session_id: SessionId, units: u32, amount: BalanceOf<T> },
+		/// A metered session was closed and the units left refunded
+		/// [session_id, consumed, refunded]
+		MeteredSessionClosed { session_id: SessionId, consumed: u32, refunded: BalanceOf<T> },
+		/// An idle metered session was expired and the units left refunded
+		/// [session_id, consumed, refunded]
+		MeteredSessionExpired { session_id: SessionId, consumed: u32, refunded: BalanceOf<T> },
+		/// A buyer's tab with a model was moved into the model's earnings
+		/// [who, model_id, amount]
+		TabSettled { who: T::AccountId, model_id: ModelId, amount: BalanceOf<T> },
+		/// A third party challenged a result, holding its payment until the challenge
+		/// is conceded or arbitrated
+		/// [request_id, challenger, counter_hash]
+		ResultChallenged { request_id: RequestId, challenger: T::AccountId, counter_hash: H256 },
+		/// A challenge was conceded or arbitrated, refunding the buyer if it was upheld
+		/// and releasing the payment otherwise
+		/// [request_id, upheld]
+		ChallengeResolved { request_id: RequestId, upheld: bool },
+	}
+
+	/// Errors that can occur in this pallet
+	#[pallet::error]
+	pub enum Error<T> {
+		/// Model doesn't exist
+		ModelNotFound,
+		/// Model is not accepting requests
+		ModelNotActive,
+		/// Model price is above the caller's limit
+		PriceAboveLimit,
+		/// No exchange rate is available to convert the model's USD price
+		PriceFeedUnavailable,
+		/// The model owner does not accept this asset
+		AssetNotAccepted,
+		/// Model already has `MaxQueueLength` open requests
+		QueueFull,
+		/// Caller cannot pay the model price
+		InsufficientBalance,
+		/// Request doesn't exist
+		RequestNotFound,
+		/// Request is not in a state that allows this action
+		InvalidState,
+		/// Only the requester can do this
+		NotRequester,
+		/// Only the assigned provider can do this
+		NotAssignedProvider,
+		/// The deadline for this step has passed
+		DeadlinePassed,
+		/// The deadline for this step has not passed yet
+		DeadlineNotPassed,
+		/// The result cannot be revealed yet
+		RevealTooEarly,
+		/// The model requires a valid proof before payment is released
+		ProofRequired,
+		/// The proof does not show the result was computed correctly
+		InvalidProof,
+		/// A valid proof was already submitted
+		ProofAlreadyVerified,
+		/// Only the model owner can do this
+		NotModelOwner,
+		/// The model must be served from an attested enclave
+		EnclaveRequired,
+		/// The provider's enclave is not the one the model requires
+		EnclaveMismatch,
+		/// Caller used up their quota for the model this period
+		QuotaExceeded,
+		/// No discount code for the model with this hash
+		CouponNotFound,
+		/// A discount code with this hash already exists
+		CouponExists,
+		/// The discount code expired or was redeemed `max_uses` times
+		CouponNotRedeemable,
+		/// The discount code can still be redeemed
+		CouponStillRedeemable,
+		/// The referral share is above `MaxReferralShare`
+		ReferralShareTooHigh,
+		/// Buyers cannot refer themselves
+		SelfReferral,
+		/// The model has no trial pool
+		NoTrialPool,
+		/// Only the sponsor of the trial pool can do this
+		NotTrialSponsor,
+		/// Caller used all trial credits for the model
+		TrialCreditsUsed,
+		/// The trial pool cannot pay for another request
+		TrialPoolExhausted,
+		/// Model earned nothing since its last claim
+		NoEarnings,
+		/// The output CID is not a valid IPFS CID
+		InvalidOutputCid,
+		/// Only the assigned provider or the model owner can submit the result
+		NotResultSubmitter,
+		/// Too many requests were completed this block; try again in the next one
+		TooManyAutoReleases,
+		/// The model declares no response deadline
+		NoResponseDeadline,
+		/// The model's response deadline for the request has not passed yet
+		ResponseDeadlineNotPassed,
+		/// A batch must contain at least one request
+		EmptyBatch,
+		/// The model is not priced per unit
+		NotMetered,
+		/// Session doesn't exist or was closed
+		SessionNotFound,
+		/// The report exceeds the units the requester prepaid
+		UsageCapExceeded,
+		/// Only the requester or the provider of the session can do this
+		NotSessionParticipant,
+		/// The buyer owes the model nothing
+		NoTab,
+		/// The buyer and the provider cannot challenge the result
+		CannotChallenge,
+		/// The counter-result is the submitted result
+		SameResult,
+		/// Caller cannot post `ChallengeBond`
+		InsufficientBond,
+		/// The challenge can still be conceded and has not escalated to arbitration
+		ChallengeNotEscalated,
+		/// Arithmetic overflow occurred
+		ArithmeticOverflow,
+	}
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		fn on_initialize(now: BlockNumberFor<T>) -> Weight {
+			let mut weight = Self::release_payments(now);
+			let era = T::PayoutEraLength::get();
+			if !now.is_zero() && !era.is_zero() && (now % era).is_zero() {
+				TabSweep::<T>::put(SweepProgress::Started);
+			}
+			if TabSweep::<T>::exists() {
+				weight.saturating_accrue(T::WeightInfo::settle_tabs(Self::settle_tabs()));
+			} else {
+				weight.saturating_accrue(T::DbWeight::get().reads(1));
+			}
+			if !PayoutSweep::<T>::exists() {
+				return weight.saturating_add(T::DbWeight::get().reads(1));
+			}
+			let models = Self::sweep_earnings();
+			weight.saturating_add(T::WeightInfo::sweep_earnings(models))
+		}
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Pay for an inference on a model
+		///
+		/// # Arguments
+		/// * `origin` - The client paying for the inference
+		/// * `model_id` - Model to run
+		/// * `input_hash` - Hash of the input the provider will receive off-chain
+		/// * `max_price` - Highest price the caller accepts
+		///
+		/// # Errors
+		/// * `ModelNotFound` - Model doesn't exist
+		/// * `ModelNotActive` - Model is paused or deactivated
+		/// * `PriceAboveLimit` - Model price exceeds `max_price`
+		/// * `PriceFeedUnavailable` - Model is priced in USD and cannot be converted
+		/// * `QuotaExceeded` - Caller used up their quota for the model
+		/// * `QueueFull` - Model has too many open requests
+		/// * `InsufficientBalance` - Caller cannot pay the price
+		///
+		/// # Events
+		/// * `InferenceRequested` - Request queued
+		#[pallet::call_index(0)]
+		#[pallet::weight(T::WeightInfo::request_inference())]
+		pub fn request_inference(
+			origin: OriginFor<T>,
+			model_id: ModelId,
+			input_hash: H256,
+			max_price: BalanceOf<T>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let model = T::Models::model_info(model_id).ok_or(Error::<T>::ModelNotFound)?;
+			ensure!(model.active, Error::<T>::ModelNotActive);
+			let price = Self::current_price(model_id, model.price)?;
+
+			Self::do_request(who, model_id, input_hash, None, price, max_price)
+		}
+
+		/// Pick up a request as a compute provider
+		///
+		/// # Errors
+		/// * `RequestNotFound` - Request doesn't exist
+		/// * `InvalidState` - Request is not waiting for a provider
+		/// * `DeadlinePassed` - Assignment window is over
+		/// * `ModelNotFound` - Model was removed
+		/// * `EnclaveRequired` - Model requires confidential serving and the caller
+		///   has no valid attestation
+		/// * `EnclaveMismatch` - Caller's attested enclave is not the required one
+		/// * Any error from the provider registry, e.g. an inactive provider or one
+		///   that does not support the model type
+		///
+		/// # Events
+		/// * `RequestAssigned` - Request assigned to the caller
+		#[pallet::call_index(1)]
+		#[pallet::weight(T::WeightInfo::accept_request())]
+		pub fn accept_request(origin: OriginFor<T>, request_id: RequestId) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let deadline = Requests::<T>::try_mutate(
+				request_id,
+				|maybe_request| -> Result<BlockNumberFor<T>, DispatchError> {
+					let request = maybe_request.as_mut().ok_or(Error::<T>::RequestNotFound)?;
+					ensure!(request.state == RequestState::Requested, Error::<T>::InvalidState);
+					let now = frame_system::Pallet::<T>::block_number();
+					ensure!(now <= request.deadline, Error::<T>::DeadlinePassed);
+
+					let model =
+						T::Models::model_info(request.model_id).ok_or(Error::<T>::ModelNotFound)?;
+					Self::ensure_enclave(&who, request.model_id)?;
+					T::Providers::start_job(&who, model.model_type)?;
+
+					request.provider = Some(who.clone());
+					request.state = RequestState::Assigned;
+					request.deadline = now.saturating_add(T::CompletionTimeout::get());
+					Ok(request.deadline)
+				},
+			)?;
+
+			Self::deposit_event(Event::RequestAssigned { request_id, provider: who, deadline });
+
+			Ok(())
+		}
+
+		/// Commit to the result of an assigned request
+		///
+		/// The commitment is the hash of the result hash and a secret salt, see
+		/// [`Pallet::result_commitment`]. The result itself is revealed with
+		/// `reveal_result` once `RevealDelay` blocks have passed, so the buyer has
+		/// evidence that it was fixed before the reveal.
+		///
+		/// # Errors
+		/// * `RequestNotFound` - Request doesn't exist
+		/// * `InvalidState` - Request is not assigned
+		/// * `NotAssignedProvider` - Caller did not pick up the request
+		/// * `DeadlinePassed` - Completion window is over
+		///
+		/// # Events
+		/// * `ResultCommitted` - Commitment recorded, with the reveal window
+		#[pallet::call_index(2)]
+		#[pallet::weight(T::WeightInfo::commit_result())]
+		pub fn commit_result(
+			origin: OriginFor<T>,
+			request_id: RequestId,
+			commitment: H256,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let (reveal_from, reveal_until) = Requests::<T>::try_mutate(
+				request_id,
+				|maybe_request| -> Result<_, DispatchError> {
+					let request = maybe_request.as_mut().ok_or(Error::<T>::RequestNotFound)?;
+					ensure!(request.state == RequestState::Assigned, Error::<T>::InvalidState);
+					ensure!(
+						request.provider.as_ref() == Some(&who),
+						Error::<T>::NotAssignedProvider
+					);
+					let now = frame_system::Pallet::<T>::block_number();
+					ensure!(now <= request.deadline, Error::<T>::DeadlinePassed);
+
+					let reveal_from = now.saturating_add(T::RevealDelay::get());
+					request.commitment = Some(ResultCommitment { hash: commitment, reveal_from });
+					request.state = RequestState::Committed;
+					request.deadline = reveal_from.saturating_add(T::RevealWindow::get());
+					Ok((reveal_from, request.deadline))
+				},
+			)?;
+
+			// The computation is done; only the reveal is left
+			T::Providers::finish_job(&who);
+
+			Self::deposit_event(Event::ResultCommitted {
+				request_id,
+				commitment,
+				reveal_from,
+				reveal_until,
+			});
+
+			Ok(())
+		}
+
+		/// Accept a completed request and release the payment to the model owner
+		///
+		/// Payments of requests the buyer neither accepts nor disputes within the
+		/// `AcceptanceWindow` are released the same way automatically.
+		///
+		/// The payment of a fractionally owned model is split between its
+		/// shareholders by share, after the referrer's share if the request has one
+		/// and the cuts taken by `OnInferenceFee`. Payments in native tokens are
+		/// added to the model's earnings instead, to be paid out by `claim_earnings`.
+		///
+		/// # Errors
+		/// * `RequestNotFound` - Request doesn't exist
+		/// * `NotRequester` - Caller did not make the request
+		/// * `InvalidState` - Request is not completed
+		/// * `ProofRequired` - Model requires a proof and none was verified
+		/// * `ModelNotFound` - Model was removed
+		///
+		/// # Events
+		/// * `InferenceFeeSplit` - A cut of the payment was released
+		/// * `RequestSettled` - Payment released
+		#[pallet::call_index(3)]
+		#[pallet::weight(T::WeightInfo::settle_request(T::MaxRevenueShares::get().saturating_mul(2))
+			.saturating_add(T::OnInferenceFee::weight()))]
+		pub fn settle_request(origin: OriginFor<T>, request_id: RequestId) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let request = Requests::<T>::get(request_id).ok_or(Error::<T>::RequestNotFound)?;
+			ensure!(request.requester == who, Error::<T>::NotRequester);
+			ensure!(request.state == RequestState::Completed, Error::<T>::InvalidState);
+			Self::cancel_auto_release(request_id, request.deadline);
+
+			Self::settle(request_id, request)
+		}
+
+		/// Cancel a request no provider has picked up yet
+		///
+		/// # Errors
+		/// * `RequestNotFound` - Request doesn't exist
+		/// * `NotRequester` - Caller did not make the request
+		/// * `InvalidState` - Request was already picked up
+		///
+		/// # Events
+		/// * `RequestFailed` - Request cancelled and refunded
+		#[pallet::call_index(4)]
+		#[pallet::weight(T::WeightInfo::cancel_request())]
+		pub fn cancel_request(origin: OriginFor<T>, request_id: RequestId) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let request = Requests::<T>::get(request_id).ok_or(Error::<T>::RequestNotFound)?;
+			ensure!(request.requester == who, Error::<T>::NotRequester);
+			ensure!(request.state == RequestState::Requested, Error::<T>::InvalidState);
+
+			Self::fail(request_id, request, FailureReason::Cancelled);
+
+			Ok(())
+		}
+
+		/// Expire a request whose assignment or completion deadline has passed
+		///
+		/// Callable by anyone; the requester is refunded.
+		///
+		/// # Errors
+		/// * `RequestNotFound` - Request doesn't exist
+		/// * `InvalidState` - Request is not waiting on a deadline
+		/// * `DeadlineNotPassed` - Deadline has not passed yet
+		///
+		/// # Events
+		/// * `RequestFailed` - Request timed out and refunded
+		#[pallet::call_index(5)]
+		#[pallet::weight(T::WeightInfo::expire_request())]
+		pub fn expire_request(origin: OriginFor<T>, request_id: RequestId) -> DispatchResult {
+			ensure_signed(origin)?;
+
+			let request = Requests::<T>::get(request_id).ok_or(Error::<T>::RequestNotFound)?;
+			let reason = match request.state {
+				RequestState::Requested => FailureReason::AssignmentTimeout,
+				RequestState::Assigned => FailureReason::CompletionTimeout,
+				RequestState::Committed => FailureReason::MissingReveal,
+				_ => return Err(Error::<T>::InvalidState.into()),
+			};
+			ensure!(
+				frame_system::Pallet::<T>::block_number() > request.deadline,
+				Error::<T>::DeadlineNotPassed
+			);
+
+			Self::fail(request_id, request, reason);
+
+			Ok(())
+		}
+
+		/// Reveal the result committed to with `commit_result`
+		///
+		/// A reveal that does not match the commitment fails the request and refunds
+		/// the buyer, so the provider forfeits the payment.
+		///
+		/// # Errors
+		/// * `RequestNotFound` - Request doesn't exist
+		/// * `InvalidState` - No result was committed
+		/// * `NotAssignedProvider` - Caller did not pick up the request
+		/// * `RevealTooEarly` - `RevealDelay` has not passed since the commitment
+		/// * `DeadlinePassed` - Reveal window is over
+		/// * `TooManyAutoReleases` - Too many requests were completed this block
+		///
+		/// # Events
+		/// * `RequestCompleted` - Result matches the commitment
+		/// * `RequestFailed` - Result does not match the commitment
+		#[pallet::call_index(6)]
+		#[pallet::weight(T::WeightInfo::reveal_result())]
+		pub fn reveal_result(
+			origin: OriginFor<T>,
+			request_id: RequestId,
+			result_hash: H256,
+			salt: H256,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let mut request = Requests::<T>::get(request_id).ok_or(Error::<T>::RequestNotFound)?;
+			let commitment = match (&request.state, &request.commitment) {
+				(RequestState::Committed, Some(commitment)) => commitment.clone(),
+				_ => return Err(Error::<T>::InvalidState.into()),
+			};
+			ensure!(request.provider.as_ref() == Some(&who), Error::<T>::NotAssignedProvider);
+			let now = frame_system::Pallet::<T>::block_number();
+			ensure!(now >= commitment.reveal_from, Error::<T>::RevealTooEarly);
+			ensure!(now <= request.deadline, Error::<T>::DeadlinePassed);
+
+			if Self::result_commitment(&result_hash, &salt) != commitment.hash {
+				Self::fail(request_id, request, FailureReason::InvalidReveal);
+				return Ok(());
+			}
+
+			request.result_hash = Some(result_hash);
+			Self::complete(request_id, &mut request)?;
+			let model_id = request.model_id;
+			Requests::<T>::insert(request_id, request);
+
+			Self::dequeue(model_id, request_id);
+			T::Models::note_inference(model_id);
+			T::Providers::record_outcome(&who, JobOutcome::Completed);
+
+			Self::deposit_event(Event::RequestCompleted { request_id, result_hash });
+
+			Ok(())
+		}
+
+		/// Submit a proof that the revealed result was computed correctly
+		///
+		/// Proofs are checked by `ProofVerifier`, so anyone may submit one. Models for
+		/// which the verifier requires a proof can only be settled after this.
+		///
+		/// # Errors
+		/// * `RequestNotFound` - Request doesn't exist
+		/// * `InvalidState` - No result was revealed yet
+		/// * `ProofAlreadyVerified` - A valid proof was already submitted
+		/// * `InvalidProof` - Proof does not verify
+		///
+		/// # Events
+		/// * `InferenceProofVerified` - Proof accepted
+		#[pallet::call_index(7)]
+		#[pallet::weight(
+			T::WeightInfo::submit_inference_proof(proof.len() as u32)
+				.saturating_add(T::ProofVerifier::verification_weight(proof.len() as u32))
+		)]
+		pub fn submit_inference_proof(
+			origin: OriginFor<T>,
+			request_id: RequestId,
+			proof: BoundedVec<u8, T::MaxProofLength>,
+		) -> DispatchResult {
+			ensure_signed(origin)?;
+
+			Requests::<T>::try_mutate(request_id, |maybe_request| -> DispatchResult {
+				let request = maybe_request.as_mut().ok_or(Error::<T>::RequestNotFound)?;
+				let result_hash = match (&request.state, &request.result_hash) {
+					(RequestState::Completed, Some(result_hash)) => *result_hash,
+					_ => return Err(Error::<T>::InvalidState.into()),
+				};
+				ensure!(!request.proof_verified, Error::<T>::ProofAlreadyVerified);
+				ensure!(
+					T::ProofVerifier::verify(
+						request.model_id,
+						&request.input_hash,
+						&result_hash,
+						&proof
+					),
+					Error::<T>::InvalidProof
+				);
+
+				request.proof_verified = true;
+				Ok(())
+			})?;
+
+			Self::deposit_event(Event::InferenceProofVerified { request_id });
+
+			Ok(())
+		}
+
+		/// Require providers of a model to serve it from an attested enclave
+		///
+		/// Applies to requests picked up from now on. `None` lifts the requirement.
+		///
+		/// # Arguments
+		/// * `origin` - Must be the model owner
+		/// * `model_id` - Model to restrict
+		/// * `requirement` - Any attested enclave, or one with a specific measurement
+		///
+		/// # Errors
+		/// * `ModelNotFound` - Model doesn't exist
+		/// * `NotModelOwner` - Caller does not own the model
+		///
+		/// # Events
+		/// * `EnclaveRequirementSet` - Requirement changed
+		#[pallet::call_index(8)]
+		#[pallet::weight(T::WeightInfo::set_enclave_requirement())]
+		pub fn set_enclave_requirement(
+			origin: OriginFor<T>,
+			model_id: ModelId,
+			requirement: Option<EnclaveRequirement>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let model = T::Models::model_info(model_id).ok_or(Error::<T>::ModelNotFound)?;
+			ensure!(model.owner == who, Error::<T>::NotModelOwner);
+
+			EnclaveRequirements::<T>::set(model_id, requirement.clone());
+
+			Self::deposit_event(Event::EnclaveRequirementSet { model_id, requirement });
+
+			Ok(())
+		}
+
+		/// Pay for an inference on a model in one of the assets its owner accepts
+		///
+		/// # Arguments
+		/// * `origin` - The client paying for the inference
+		/// * `model_id` - Model to run
+		/// * `input_hash` - Hash of the input the provider will receive off-chain
+		/// * `asset_id` - Asset to pay in
+		/// * `max_price` - Highest price in `asset_id` the caller accepts
+		///
+		/// # Errors
+		/// * `ModelNotFound` - Model doesn't exist
+		/// * `ModelNotActive` - Model is paused or deactivated
+		/// * `AssetNotAccepted` - Owner does not accept `asset_id` for the model
+		/// * `PriceAboveLimit` - Model price exceeds `max_price`
+		/// * `QuotaExceeded` - Caller used up their quota for the model
+		/// * `QueueFull` - Model has too many open requests
+		/// * `InsufficientBalance` - Caller cannot pay the price
+		///
+		/// # Events
+		/// * `InferenceRequested` - Request queued
+		#[pallet::call_index(10)]
+		#[pallet::weight(T::WeightInfo::request_inference_with_asset())]
+		pub fn request_inference_with_asset(
+			origin: OriginFor<T>,
+			model_id: ModelId,
+			input_hash: H256,
+			asset_id: AssetIdOf<T>,
+			max_price: BalanceOf<T>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let model = T::Models::model_info(model_id).ok_or(Error::<T>::ModelNotFound)?;
+			ensure!(model.active, Error::<T>::ModelNotActive);
+			let price = Self::asset_price(model_id, asset_id.clone())
+				.ok_or(Error::<T>::AssetNotAccepted)?;
+
+			Self::do_request(who, model_id, input_hash, Some(asset_id), price, max_price)
+		}
+
+		/// Accept payment for a model in an asset, e.g. a stablecoin, at a fixed price
+		///
+		/// # Arguments
+		/// * `origin` - Must be the model owner
+		/// * `model_id` - Model to price
+		/// * `asset_id` - Asset to accept
+		/// * `price` - Price of a single inference in `asset_id`, or `None` to stop
+		///   accepting the asset
+		///
+		/// # Errors
+		/// * `ModelNotFound` - Model doesn't exist
+		/// * `NotModelOwner` - Caller does not own the model
+		///
+		/// # Events
+		/// * `AssetPriceSet` - Accepted asset changed
+		#[pallet::call_index(11)]
+		#[pallet::weight(T::WeightInfo::set_asset_price())]
+		pub fn set_asset_price(
+			origin: OriginFor<T>,
+			model_id: ModelId,
+			asset_id: AssetIdOf<T>,
+			price: Option<BalanceOf<T>>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let model = T::Models::model_info(model_id).ok_or(Error::<T>::ModelNotFound)?;
+			ensure!(model.owner == who, Error::<T>::NotModelOwner);
+
+			AssetPrices::<T>::set(model_id, asset_id.clone(), price);
+
+			Self::deposit_event(Event::AssetPriceSet { model_id, asset_id, price });
+
+			Ok(())
+		}
+
+		/// Price a model in US cents, converted to native tokens per request
+		///
+		/// # Arguments
+		/// * `origin` - Must be the model owner
+		/// * `model_id` - Model to price
+		/// * `usd_cents` - Price in US cents, or `None` to use the registry price again
+		///
+		/// # Errors
+		/// * `ModelNotFound` - Model doesn't exist
+		/// * `NotModelOwner` - Caller does not own the model
+		/// * `PriceFeedUnavailable` - The price feed cannot convert USD prices
+		///
+		/// # Events
+		/// * `UsdPriceSet` - Price denomination changed
+		#[pallet::call_index(9)]
+		#[pallet::weight(T::WeightInfo::set_usd_price())]
+		pub fn set_usd_price(
+			origin: OriginFor<T>,
+			model_id: ModelId,
+			usd_cents: Option<u64>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let model = T::Models::model_info(model_id).ok_or(Error::<T>::ModelNotFound)?;
+			ensure!(model.owner == who, Error::<T>::NotModelOwner);
+			if let Some(cents) = usd_cents {
+				ensure!(
+					T::PriceFeed::usd_cents_to_native(cents).is_some(),
+					Error::<T>::PriceFeedUnavailable
+				);
+			}
+
+			UsdPrices::<T>::set(model_id, usd_cents);
+
+			Self::deposit_event(Event::UsdPriceSet { model_id, usd_cents });
+
+			Ok(())
+		}
+
+		/// Limit the requests each account can make for a model per `QuotaPeriod`
+		///
+		/// Requests already made this period count towards a new quota.
+		///
+		/// # Arguments
+		/// * `origin` - Must be the model owner
+		/// * `model_id` - Model to limit
+		/// * `quota` - Requests per account and period, or `None` for no limit
+		///
+		/// # Errors
+		/// * `ModelNotFound` - Model doesn't exist
+		/// * `NotModelOwner` - Caller does not own the model
+		///
+		/// # Events
+		/// * `QuotaSet` - Quota changed
+		#[pallet::call_index(12)]
+		#[pallet::weight(T::WeightInfo::set_quota())]
+		pub fn set_quota(
+			origin: OriginFor<T>,
+			model_id: ModelId,
+			quota: Option<u32>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let model = T::Models::model_info(model_id).ok_or(Error::<T>::ModelNotFound)?;
+			ensure!(model.owner == who, Error::<T>::NotModelOwner);
+
+			Quotas::<T>::set(model_id, quota);
+
+			Self::deposit_event(Event::QuotaSet { model_id, quota });
+
+			Ok(())
+		}
+
+		/// Create a discount code for a model
+		///
+		/// # Arguments
+		/// * `origin` - Must be the model owner
+		/// * `model_id` - Model the code is valid for
+		/// * `code_hash` - [`Pallet::coupon_hash`] of the code, which stays secret
+		///   until it is first redeemed
+		/// * `discount` - Share of the price taken off
+		/// * `max_uses` - Number of times the code can be redeemed
+		/// * `expires_at` - First block at which the code can no longer be redeemed
+		///
+		/// # Errors
+		/// * `ModelNotFound` - Model doesn't exist
+		/// * `NotModelOwner` - Caller does not own the model
+		/// * `CouponExists` - A code with this hash already exists
+		///
+		/// # Events
+		/// * `CouponCreated` - Code created
+		#[pallet::call_index(13)]
+		#[pallet::weight(T::WeightInfo::create_coupon())]
+		pub fn create_coupon(
+			origin: OriginFor<T>,
+			model_id: ModelId,
+			code_hash: H256,
+			discount: Percent,
+			max_uses: u32,
+			expires_at: BlockNumberFor<T>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let model = T::Models::model_info(model_id).ok_or(Error::<T>::ModelNotFound)?;
+			ensure!(model.owner == who, Error::<T>::NotModelOwner);
+			ensure!(!Coupons::<T>::contains_key(code_hash), Error::<T>::CouponExists);
+
+			Coupons::<T>::insert(
+				code_hash,
+				Coupon { model_id, discount, max_uses, redeemed: 0, expires_at },
+			);
+
+			Self::deposit_event(Event::CouponCreated {
+				code_hash,
+				model_id,
+				discount,
+				max_uses,
+				expires_at,
+			});
+
+			Ok(())
+		}
+
+		/// Remove a discount code
+		///
+		/// The model owner can remove a code at any time; anyone else once it has
+		/// expired or been used up.
+		///
+		/// # Errors
+		/// * `CouponNotFound` - No code with this hash
+		/// * `CouponStillRedeemable` - Caller does not own the model and the code
+		///   can still be redeemed
+		///
+		/// # Events
+		/// * `CouponRemoved` - Code removed
+		#[pallet::call_index(14)]
+		#[pallet::weight(T::WeightInfo::remove_coupon())]
+		pub fn remove_coupon(origin: OriginFor<T>, code_hash: H256) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let coupon = Coupons::<T>::get(code_hash).ok_or(Error::<T>::CouponNotFound)?;
+			let is_owner =
+				T::Models::model_info(coupon.model_id).is_some_and(|model| model.owner == who);
+			let now = frame_system::Pallet::<T>::block_number();
+			ensure!(is_owner || !coupon.is_redeemable(&now), Error::<T>::CouponStillRedeemable);
+
+			Coupons::<T>::remove(code_hash);
+
+			Self::deposit_event(Event::CouponRemoved { code_hash });
+
+			Ok(())
+		}
+
+		/// Pay for an inference on a model at a discount
+		///
+		/// # Arguments
+		/// * `origin` - The client paying for the inference
+		/// * `model_id` - Model to run
+		/// * `input_hash` - Hash of the input the provider will receive off-chain
+		/// * `code` - Discount code for the model
+		/// * `max_price` - Highest discounted price the caller accepts
+		///
+		/// # Errors
+		/// * `ModelNotFound` - Model doesn't exist
+		/// * `ModelNotActive` - Model is paused or deactivated
+		/// * `CouponNotFound` - The code is not valid for the model
+		/// * `CouponNotRedeemable` - The code expired or was used up
+		/// * Any error of `request_inference`
+		///
+		/// # Events
+		/// * `InferenceRequested` - Request queued
+		/// * `CouponRedeemed` - Code redeemed
+		#[pallet::call_index(15)]
+		#[pallet::weight(T::WeightInfo::request_inference_with_coupon())]
+		pub fn request_inference_with_coupon(
+			origin: OriginFor<T>,
+			model_id: ModelId,
+			input_hash: H256,
+			code: BoundedVec<u8, T::MaxCouponLength>,
+			max_price: BalanceOf<T>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let model = T::Models::model_info(model_id).ok_or(Error::<T>::ModelNotFound)?;
+			ensure!(model.active, Error::<T>::ModelNotActive);
+			let code_hash = Self::coupon_hash(&code);
+			let mut coupon = Coupons::<T>::get(code_hash)
+				.filter(|coupon| coupon.model_id == model_id)
+				.ok_or(Error::<T>::CouponNotFound)?;
+			let now = frame_system::Pallet::<T>::block_number();
+			ensure!(coupon.is_redeemable(&now), Error::<T>::CouponNotRedeemable);
+
+			let price = Self::current_price(model_id, model.price)?;
+			let discount = coupon.discount.mul_floor(price);
+			let request_id = NextRequestId::<T>::get();
+			Self::do_request(
+				who,
+				model_id,
+				input_hash,
+				None,
+				price.saturating_sub(discount),
+				max_price,
+			)?;
+
+			coupon.redeemed.saturating_inc();
+			Coupons::<T>::insert(code_hash, coupon);
+
+			Self::deposit_event(Event::CouponRedeemed { code_hash, request_id, discount });
+
+			Ok(())
+		}
+
+		/// Pass a share of the price of requests for a model to the buyer's referrer
+		///
+		/// Requests already made keep the share they were made with.
+		///
+		/// # Arguments
+		/// * `origin` - Must be the model owner
+		/// * `model_id` - Model to reward referrals for
+		/// * `share` - Share of the price paid to referrers, or `None` to stop
+		///
+		/// # Errors
+		/// * `ModelNotFound` - Model doesn't exist
+		/// * `NotModelOwner` - Caller does not own the model
+		/// * `ReferralShareTooHigh` - Share is above `MaxReferralShare`
+		///
+		/// # Events
+		/// * `ReferralShareSet` - Referral share changed
+		#[pallet::call_index(16)]
+		#[pallet::weight(T::WeightInfo::set_referral_share())]
+		pub fn set_referral_share(
+			origin: OriginFor<T>,
+			model_id: ModelId,
+			share: Option<Perbill>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let model = T::Models::model_info(model_id).ok_or(Error::<T>::ModelNotFound)?;
+			ensure!(model.owner == who, Error::<T>::NotModelOwner);
+			ensure!(
+				share.is_none_or(|share| share <= T::MaxReferralShare::get()),
+				Error::<T>::ReferralShareTooHigh
+			);
+
+			ReferralShares::<T>::set(model_id, share);
+
+			Self::deposit_event(Event::ReferralShareSet { model_id, share });
+
+			Ok(())
+		}
+
+		/// Pay for an inference on a model, naming the account that referred the caller
+		///
+		/// The referrer is paid the model's referral share when the request
+		/// settles, if the model has one.
+		///
+		/// # Arguments
+		/// * `origin` - The client paying for the inference
+		/// * `model_id` - Model to run
+		/// * `input_hash` - Hash of the input the provider will receive off-chain
+		/// * `referrer` - Account that referred the caller
+		/// * `max_price` - Highest price the caller accepts
+		///
+		/// # Errors
+		/// * `SelfReferral` - Caller named themselves as referrer
+		/// * Any error of `request_inference`
+		///
+		/// # Events
+		/// * `InferenceRequested` - Request queued
+		#[pallet::call_index(17)]
+		#[pallet::weight(T::WeightInfo::request_inference_with_referrer())]
+		pub fn request_inference_with_referrer(
+			origin: OriginFor<T>,
+			model_id: ModelId,
+			input_hash: H256,
+			referrer: T::AccountId,
+			max_price: BalanceOf<T>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			ensure!(who != referrer, Error::<T>::SelfReferral);
+
+			let model = T::Models::model_info(model_id).ok_or(Error::<T>::ModelNotFound)?;
+			ensure!(model.active, Error::<T>::ModelNotActive);
+			let price = Self::current_price(model_id, model.price)?;
+
+			let request_id = NextRequestId::<T>::get();
+			Self::do_request(who, model_id, input_hash, None, price, max_price)?;
+			if let Some(share) = ReferralShares::<T>::get(model_id) {
+				Referrals::<T>::insert(request_id, (referrer, share));
+			}
+
+			Ok(())
+		}
+
+		/// Offer free inferences of a model to every account, paid from funds the
+		/// caller escrows
+		///
+		/// Adds `amount` to the pool and sets the credits each account gets; credits
+		/// already used count towards a new limit.
+		///
+		/// # Arguments
+		/// * `origin` - Must be the model owner
+		/// * `model_id` - Model to offer
+		/// * `credits_per_account` - Free inferences each account can claim
+		/// * `amount` - Funds to escrow for trial requests
+		///
+		/// # Errors
+		/// * `ModelNotFound` - Model doesn't exist
+		/// * `NotModelOwner` - Caller does not own the model
+		/// * `NotTrialSponsor` - A previous owner sponsors the model's pool
+		/// * `InsufficientBalance` - Caller cannot escrow `amount`
+		///
+		/// # Events
+		/// * `TrialPoolFunded` - Pool funded
+		#[pallet::call_index(18)]
+		#[pallet::weight(T::WeightInfo::fund_trial_pool())]
+		pub fn fund_trial_pool(
+			origin: OriginFor<T>,
+			model_id: ModelId,
+			credits_per_account: u32,
+			amount: BalanceOf<T>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let model = T::Models::model_info(model_id).ok_or(Error::<T>::ModelNotFound)?;
+			ensure!(model.owner == who, Error::<T>::NotModelOwner);
+			let pool = TrialPools::<T>::get(model_id);
+			ensure!(
+				pool.as_ref().is_none_or(|pool| pool.sponsor == who),
+				Error::<T>::NotTrialSponsor
+			);
+
+			let escrow = Self::fund_pool(&who, pool.map(|pool| pool.escrow), amount)
+				.map_err(|_| Error::<T>::InsufficientBalance)?;
+			let funds = T::Escrow::held(escrow);
+			TrialPools::<T>::insert(
+				model_id,
+				TrialPool { sponsor: who.clone(), credits_per_account, escrow },
+			);
+
+			Self::deposit_event(Event::TrialPoolFunded {
+				model_id,
+				sponsor: who,
+				credits_per_account,
+				funds,
+			});
+
+			Ok(())
+		}
+
+		/// Stop offering free inferences and refund the unspent funds
+		///
+		/// Open trial requests that fail later are refunded to the sponsor.
+		///
+		/// # Errors
+		/// * `NoTrialPool` - Model has no trial pool
+		/// * `NotTrialSponsor` - Caller did not fund the pool
+		///
+		/// # Events
+		/// * `TrialPoolClosed` - Pool closed
+		#[pallet::call_index(19)]
+		#[pallet::weight(T::WeightInfo::close_trial_pool())]
+		pub fn close_trial_pool(origin: OriginFor<T>, model_id: ModelId) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let pool = TrialPools::<T>::get(model_id).ok_or(Error::<T>::NoTrialPool)?;
+			ensure!(pool.sponsor == who, Error::<T>::NotTrialSponsor);
+
+			// The escrow is closed already if every credit was spent
+			let refunded = T::Escrow::refund(pool.escrow).unwrap_or_default();
+			TrialPools::<T>::remove(model_id);
+
+			Self::deposit_event(Event::TrialPoolClosed { model_id, refunded });
+
+			Ok(())
+		}
+
+		/// Request an inference on a model for free, using one of the caller's trial
+		/// credits
+		///
+		/// # Errors
+		/// * `ModelNotFound` - Model doesn't exist
+		/// * `ModelNotActive` - Model is paused or deactivated
+		/// * `NoTrialPool` - Model offers no free inferences
+		/// * `TrialCreditsUsed` - Caller used all their credits for the model
+		/// * `TrialPoolExhausted` - The pool cannot pay the model price
+		/// * Any error of `request_inference`
+		///
+		/// # Events
+		/// * `InferenceRequested` - Request queued
+		/// * `TrialCreditUsed` - Request paid from the pool
+		#[pallet::call_index(20)]
+		#[pallet::weight(T::WeightInfo::request_trial_inference())]
+		pub fn request_trial_inference(
+			origin: OriginFor<T>,
+			model_id: ModelId,
+			input_hash: H256,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let model = T::Models::model_info(model_id).ok_or(Error::<T>::ModelNotFound)?;
+			ensure!(model.active, Error::<T>::ModelNotActive);
+			let pool = TrialPools::<T>::get(model_id).ok_or(Error::<T>::NoTrialPool)?;
+			let claimed = TrialClaims::<T>::get(&who, model_id);
+			ensure!(claimed < pool.credits_per_account, Error::<T>::TrialCreditsUsed);
+			let price = Self::current_price(model_id, model.price)?;
+			ensure!(T::Escrow::held(pool.escrow) >= price, Error::<T>::TrialPoolExhausted);
+
+			// Hand the price to the caller, who escrows it like any other request
+			let moved = T::Escrow::release(pool.escrow, &who, price)?;
+			ensure!(moved == price, Error::<T>::TrialPoolExhausted);
+			let request_id = NextRequestId::<T>::get();
+			Self::do_request(who.clone(), model_id, input_hash, None, price, price)?;
+
+			TrialRequests::<T>::insert(request_id, &pool.sponsor);
+			TrialClaims::<T>::insert(&who, model_id, claimed.saturating_add(1));
+
+			Self::deposit_event(Event::TrialCreditUsed { request_id, model_id, who });
+
+			Ok(())
+		}
+
+		/// Let a model's price follow its recent demand
+		///
+		/// Every request above `baseline` in the last `DemandWindow` blocks raises
+		/// the price, in native tokens and in accepted assets, by `step`, up to
+		/// `max_surcharge`. Demand is only counted while the pricing is set.
+		///
+		/// # Arguments
+		/// * `origin` - Must be the model owner
+		/// * `model_id` - Model to price
+		/// * `pricing` - Demand pricing, or `None` to return to the base price
+		///
+		/// # Errors
+		/// * `ModelNotFound` - Model doesn't exist
+		/// * `NotModelOwner` - Caller does not own the model
+		///
+		/// # Events
+		/// * `DemandPricingSet` - Pricing changed
+		#[pallet::call_index(21)]
+		#[pallet::weight(T::WeightInfo::set_demand_pricing())]
+		pub fn set_demand_pricing(
+			origin: OriginFor<T>,
+			model_id: ModelId,
+			pricing: Option<DemandPricing>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let model = T::Models::model_info(model_id).ok_or(Error::<T>::ModelNotFound)?;
+			ensure!(model.owner == who, Error::<T>::NotModelOwner);
+
+			DemandPricings::<T>::set(model_id, pricing);
+			if pricing.is_none() {
+				DemandCounters::<T>::remove(model_id);
+			}
+
+			Self::deposit_event(Event::DemandPricingSet { model_id, pricing });
+
+			Ok(())
+		}
+
+		/// Offer a model at a promotional price for a limited time
+		///
+		/// Requests pay `promo_price` instead of the registry or USD price until
+		/// `until_block`, from which on the regular price applies again without
+		/// further action. Demand pricing still applies on top. Setting a promotion
+		/// replaces the current one; one that ends at or before the current block ends
+		/// the current one right away.
+		///
+		/// # Arguments
+		/// * `origin` - Must be the model owner
+		/// * `model_id` - Model to promote
+		/// * `promo_price` - Native price of a request during the promotion
+		/// * `until_block` - First block the regular price applies at again
+		///
+		/// # Errors
+		/// * `ModelNotFound` - Model doesn't exist
+		/// * `NotModelOwner` - Caller does not own the model
+		///
+		/// # Events
+		/// * `PromoPriceSet` - Promotion set or ended
+		#[pallet::call_index(23)]
+		#[pallet::weight(T::WeightInfo::set_promo_price())]
+		pub fn set_promo_price(
+			origin: OriginFor<T>,
+			model_id: ModelId,
+			promo_price: BalanceOf<T>,
+			until_block: BlockNumberFor<T>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let model = T::Models::model_info(model_id).ok_or(Error::<T>::ModelNotFound)?;
+			ensure!(model.owner == who, Error::<T>::NotModelOwner);
+
+			let promo_price =
+				(until_block > frame_system::Pallet::<T>::block_number()).then_some(promo_price);
+			PromoPrices::<T>::set(model_id, promo_price.map(|price| (price, until_block)));
+
+			Self::deposit_event(Event::PromoPriceSet { model_id, promo_price, until_block });
+
+			Ok(())
+		}
+
+		/// Pay out the native fees a model earned since its last claim
+		///
+		/// The `EarningsCuts` are taken first, and the rest is split between the
+		/// model's shareholders, its lessee or the owner of its parent by the revenue
+		/// shares at the time of the claim, so anyone can claim before the shares
+		/// change, e.g. before a lease ends. Payouts a recipient cannot receive, e.g.
+		/// below the existential deposit of a new account, stay in the earnings.
+		/// Earnings are also paid out automatically every `PayoutEraLength` blocks.
+		///
+		/// # Arguments
+		/// * `origin` - Any signed account
+		/// * `model_id` - Model whose earnings to pay out
+		///
+		/// # Errors
+		/// * `NoEarnings` - Model earned nothing since the last claim
+		/// * `ModelNotFound` - Model was removed
+		///
+		/// # Events
+		/// * `EarningsCutPaid` - For each cut taken
+		/// * `EarningsClaimed` - Earnings paid out
+		#[pallet::call_index(22)]
+		#[pallet::weight(T::WeightInfo::claim_earnings(T::MaxRevenueShares::get().saturating_mul(2)))]
+		pub fn claim_earnings(origin: OriginFor<T>, model_id: ModelId) -> DispatchResult {
+			ensure_signed(origin)?;
+
+			let mut cuts = BTreeMap::new();
+			Self::pay_earnings(model_id, &mut cuts)?;
+			Self::pay_cuts(cuts);
+
+			Ok(())
+		}
+
+		/// Complete an assigned request with a result stored on IPFS
+		///
+		/// Unlike `commit_result` and `reveal_result`, the result is published in one
+		/// step, recording where it lives so the buyer can fetch it. The model owner
+		/// can submit results too, e.g. when they run the provider themselves; the
+		/// assigned provider is only credited with the job if they submit it.
+		///
+		/// # Arguments
+		/// * `origin` - The assigned provider or the model owner
+		/// * `request_id` - Request to complete
+		/// * `output_cid` - IPFS CID of the result
+		/// * `output_hash` - Hash of the result
+		///
+		/// # Errors
+		/// * `RequestNotFound` - Request doesn't exist
+		/// * `InvalidState` - Request is not assigned
+		/// * `NotResultSubmitter` - Caller is neither the assigned provider nor the
+		///   model owner
+		/// * `DeadlinePassed` - Completion window is over
+		/// * `InvalidOutputCid` - `output_cid` is not a valid IPFS CID
+		/// * `TooManyAutoReleases` - Too many requests were completed this block
+		///
+		/// # Events
+		/// * `ResultSubmitted` - Request completed
+		#[pallet::call_index(24)]
+		#[pallet::weight(T::WeightInfo::submit_result())]
+		pub fn submit_result(
+			origin: OriginFor<T>,
+			request_id: RequestId,
+			output_cid: BoundedVec<u8, T::MaxCidLength>,
+			output_hash: H256,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let mut request = Requests::<T>::get(request_id).ok_or(Error::<T>::RequestNotFound)?;
+			let provider = match (&request.state, &request.provider) {
+				(RequestState::Assigned, Some(provider)) => provider.clone(),
+				_ => return Err(Error::<T>::InvalidState.into()),
+			};
+			ensure!(
+				provider == who
+					|| T::Models::model_info(request.model_id)
+						.is_some_and(|model| model.owner == who),
+				Error::<T>::NotResultSubmitter
+			);
+			ensure!(
+				frame_system::Pallet::<T>::block_number() <= request.deadline,
+				Error::<T>::DeadlinePassed
+			);
+			ensure!(
+				pallet_ai_registry::is_valid_ipfs_cid(&output_cid),
+				Error::<T>::InvalidOutputCid
+			);
+
+			request.result_hash = Some(output_hash);
+			Self::complete(request_id, &mut request)?;
+			let model_id = request.model_id;
+			Requests::<T>::insert(request_id, request);
+			ResultCids::<T>::insert(request_id, &output_cid);
+
+			Self::dequeue(model_id, request_id);
+			T::Models::note_inference(model_id);
+			T::Providers::finish_job(&provider);
+			if provider == who {
+				T::Providers::record_outcome(&provider, JobOutcome::Completed);
+			}
+
+			Self::deposit_event(Event::ResultSubmitted {
+				request_id,
+				output_cid,
+				result_hash: output_hash,
+			});
+
+			Ok(())
+		}
+
+		/// Declare how many blocks after being made requests for a model get a result
+		///
+		/// Requests without a result by then can be refunded by anyone with
+		/// `refund_timed_out`, regardless of the provider's own deadlines.
+		///
+		/// # Arguments
+		/// * `origin` - Must be the model owner
+		/// * `model_id` - Model to declare the deadline for
+		/// * `blocks` - Blocks results may take; `None` to withdraw the promise
+		///
+		/// # Errors
+		/// * `ModelNotFound` - Model doesn't exist
+		/// * `NotModelOwner` - Caller does not own the model
+		///
+		/// # Events
+		/// * `ResponseDeadlineSet` - Deadline declared or withdrawn
+		#[pallet::call_index(27)]
+		#[pallet::weight(T::WeightInfo::set_response_deadline())]
+		pub fn set_response_deadline(
+			origin: OriginFor<T>,
+			model_id: ModelId,
+			blocks: Option<BlockNumberFor<T>>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let model = T::Models::model_info(model_id).ok_or(Error::<T>::ModelNotFound)?;
+			ensure!(model.owner == who, Error::<T>::NotModelOwner);
+
+			ResponseDeadlines::<T>::set(model_id, blocks);
+
+			Self::deposit_event(Event::ResponseDeadlineSet { model_id, blocks });
+
+			Ok(())
+		}
+
+		/// Refund a request that got no result within its model's response deadline
+		///
+		/// Callable by anyone, who is paid `TimeoutRefundReward` of the payment for it
+		/// from the stake of the provider that picked the request up, if any. The buyer
+		/// is refunded in full.
+		///
+		/// # Errors
+		/// * `RequestNotFound` - Request doesn't exist
+		/// * `InvalidState` - Request already has a result or is closed
+		/// * `NoResponseDeadline` - The model declares no response deadline
+		/// * `ResponseDeadlineNotPassed` - The deadline has not passed yet
+		///
+		/// # Events
+		/// * `TimedOutRequestRefunded` - Caller rewarded
+		/// * `RequestFailed` - Request refunded
+		#[pallet::call_index(28)]
+		#[pallet::weight(T::WeightInfo::refund_timed_out())]
+		pub fn refund_timed_out(origin: OriginFor<T>, request_id: RequestId) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let request = Requests::<T>::get(request_id).ok_or(Error::<T>::RequestNotFound)?;
+			ensure!(
+				matches!(
+					request.state,
+					RequestState::Requested | RequestState::Assigned | RequestState::Committed
+				),
+				Error::<T>::InvalidState
+			);
+			let deadline = ResponseDeadlines::<T>::get(request.model_id)
+				.ok_or(Error::<T>::NoResponseDeadline)?;
+			ensure!(
+				frame_system::Pallet::<T>::block_number()
+					> request.created_at.saturating_add(deadline),
+				Error::<T>::ResponseDeadlineNotPassed
+			);
+
+			let reward = request.provider.as_ref().map_or_else(Zero::zero, |provider| {
+				let reward = T::TimeoutRefundReward::get().mul_floor(request.price);
+				T::Providers::slash_to(provider, &who, reward)
+			});
+			Self::deposit_event(Event::TimedOutRequestRefunded { request_id, caller: who, reward });
+			Self::fail(request_id, request, FailureReason::ResponseTimeout);
+
+			Ok(())
+		}
+
+		/// Give a discount on batches of requests for a model
+		///
+		/// # Arguments
+		/// * `origin` - Must be the model owner
+		/// * `model_id` - Model to discount
+		/// * `discount` - Discount and the smallest batch it applies to, or `None` to
+		///   remove it
+		///
+		/// # Errors
+		/// * `ModelNotFound` - Model doesn't exist
+		/// * `NotModelOwner` - Caller does not own the model
+		///
+		/// # Events
+		/// * `BatchDiscountSet` - Discount changed
+		#[pallet::call_index(29)]
+		#[pallet::weight(T::WeightInfo::set_batch_discount())]
+		pub fn set_batch_discount(
+			origin: OriginFor<T>,
+			model_id: ModelId,
+			discount: Option<BatchDiscount>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let model = T::Models::model_info(model_id).ok_or(Error::<T>::ModelNotFound)?;
+			ensure!(model.owner == who, Error::<T>::NotModelOwner);
+
+			BatchDiscounts::<T>::set(model_id, discount);
+
+			Self::deposit_event(Event::BatchDiscountSet { model_id, discount });
+
+			Ok(())
+		}
+
+		/// Pay for a batch of inferences on a model at once
+		///
+		/// Opens a request per input, escrowing their total price in one go. Every
+		/// request pays the model's current price, less its batch discount if the
+		/// batch is large enough, and is served and settled on its own.
+		///
+		/// # Arguments
+		/// * `origin` - The client paying for the inferences
+		/// * `model_id` - Model to run
+		/// * `input_hashes` - Hashes of the inputs the provider will receive off-chain
+		/// * `max_price` - Highest price the caller accepts per request
+		///
+		/// # Errors
+		/// * `EmptyBatch` - No inputs given
+		/// * `ModelNotFound` - Model doesn't exist
+		/// * `ModelNotActive` - Model is paused or deactivated
+		/// * `PriceAboveLimit` - Discounted price exceeds `max_price`
+		/// * `PriceFeedUnavailable` - Model is priced in USD and cannot be converted
+		/// * `QuotaExceeded` - The batch exceeds the caller's quota for the model
+		/// * `QueueFull` - Model has no room for the whole batch
+		/// * `InsufficientBalance` - Caller cannot pay for the batch
+		///
+		/// # Events
+		/// * `InferenceRequested` - For each request queued
+		/// * `BatchRequested` - Batch paid for
+		#[pallet::call_index(30)]
+		#[pallet::weight(T::WeightInfo::request_inference_batch(input_hashes.len() as u32))]
+		pub fn request_inference_batch(
+			origin: OriginFor<T>,
+			model_id: ModelId,
+			input_hashes: BoundedVec<H256, T::MaxBatchSize>,
+			max_price: BalanceOf<T>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			ensure!(!input_hashes.is_empty(), Error::<T>::EmptyBatch);
+
+			let model = T::Models::model_info(model_id).ok_or(Error::<T>::ModelNotFound)?;
+			ensure!(model.active, Error::<T>::ModelNotActive);
+			let count = input_hashes.len() as u32;
+			let mut price = Self::current_price(model_id, model.price)?;
+			if let Some(batch) = BatchDiscounts::<T>::get(model_id) {
+				if count >= batch.min_size {
+					price = price.saturating_sub(batch.discount.mul_floor(price));
+				}
+			}
+			ensure!(price <= max_price, Error::<T>::PriceAboveLimit);
+
+			let total = price.saturating_mul(count.into());
+			let batch = (!total.is_zero()).then(|| Self::escrow(&who, None, total)).transpose()?;
+			let first_request_id = NextRequestId::<T>::get();
+			for input_hash in input_hashes {
+				// Each request gets its part of the batch's escrow, closing it with the last
+				let escrow = batch.map(|batch| T::Escrow::split(batch, price, None)).transpose()?;
+				Self::open_request(who.clone(), model_id, input_hash, None, price, escrow)?;
+			}
+
+			Self::deposit_event(Event::BatchRequested {
+				model_id,
+				requester: who,
+				first_request_id,
+				count,
+				total,
+			});
+
+			Ok(())
+		}
+
+		/// Price a model per consumed unit for metered sessions
+		///
+		/// What a unit is, e.g. a token or a second of compute, is up to the owner and
+		/// their providers. Open sessions keep the price they were opened at.
+		///
+		/// # Arguments
+		/// * `origin` - Must be the model owner
+		/// * `model_id` - Model to price
+		/// * `unit_price` - Price of a unit, or `None` to stop offering metered sessions
+		///
+		/// # Errors
+		/// * `ModelNotFound` - Model doesn't exist
+		/// * `NotModelOwner` - Caller does not own the model
+		///
+		/// # Events
+		/// * `UnitPriceSet` - Unit price changed
+		#[pallet::call_index(31)]
+		#[pallet::weight(T::WeightInfo::set_unit_price())]
+		pub fn set_unit_price(
+			origin: OriginFor<T>,
+			model_id: ModelId,
+			unit_price: Option<BalanceOf<T>>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let model = T::Models::model_info(model_id).ok_or(Error::<T>::ModelNotFound)?;
+			ensure!(model.owner == who, Error::<T>::NotModelOwner);
+
+			UnitPrices::<T>::set(model_id, unit_price);
+
+			Self::deposit_event(Event::UnitPriceSet { model_id, unit_price });
+
+			Ok(())
+		}
+
+		/// Open a metered session on a model, prepaying up to `max_units` units
+		///
+		/// The price of all units is escrowed up front. It is paid out as the
+		/// provider reports usage, and what is left is refunded when the session
+		/// closes.
+		///
+		/// # Arguments
+		/// * `origin` - The client paying for the inference
+		/// * `model_id` - Model to run
+		/// * `input_hash` - Hash of the input the provider will receive off-chain
+		/// * `max_units` - Most units the session may consume
+		/// * `max_unit_price` - Highest unit price the caller accepts
+		///
+		/// # Errors
+		/// * `ModelNotFound` - Model doesn't exist
+		/// * `ModelNotActive` - Model is paused or deactivated
+		/// * `NotMetered` - Model is not priced per unit
+		/// * `PriceAboveLimit` - Unit price exceeds `max_unit_price`
+		/// * `QuotaExceeded` - Caller used up their quota for the model
+		/// * `InsufficientBalance` - Caller cannot prepay the units
+		///
+		/// # Events
+		/// * `MeteredSessionOpened` - Session opened
+		#[pallet::call_index(32)]
+		#[pallet::weight(T::WeightInfo::open_metered_session())]
+		pub fn open_metered_session(
+			origin: OriginFor<T>,
+			model_id: ModelId,
+			input_hash: H256,
+			max_units: u32,
+			max_unit_price: BalanceOf<T>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let model = T::Models::model_info(model_id).ok_or(Error::<T>::ModelNotFound)?;
+			ensure!(model.active, Error::<T>::ModelNotActive);
+			let unit_price = UnitPrices::<T>::get(model_id).ok_or(Error::<T>::NotMetered)?;
+			ensure!(unit_price <= max_unit_price, Error::<T>::PriceAboveLimit);
+
+			let session_id = NextSessionId::<T>::get();
+			let next_id = session_id.checked_add(1).ok_or(Error::<T>::ArithmeticOverflow)?;
+			Self::use_quota(&who, model_id)?;
+			let escrow = Self::escrow(&who, None, unit_price.saturating_mul(max_units.into()))?;
+
+			let now = frame_system::Pallet::<T>::block_number();
+			MeteredSessions::<T>::insert(
+				session_id,
+				MeteredSession {
+					requester: who.clone(),
+					model_id,
+					input_hash,
+					unit_price,
+					max_units,
+					consumed: 0,
+					provider: None,
+					opened_at: now,
+					last_active: now,
+					escrow,
+				},
+			);
+			NextSessionId::<T>::put(next_id);
+
+			Self::deposit_event(Event::MeteredSessionOpened {
+				session_id,
+				model_id,
+				requester: who,
+				unit_price,
+				max_units,
+			});
+
+			Ok(())
+		}
+
+		/// Pick up a metered session as a compute provider
+		///
+		/// # Errors
+		/// * `SessionNotFound` - Session doesn't exist
+		/// * `InvalidState` - Session was already picked up
+		/// * `ModelNotFound` - Model was removed
+		/// * `EnclaveRequired` - Model requires confidential serving and the caller
+		///   has no valid attestation
+		/// * `EnclaveMismatch` - Caller's attested enclave is not the required one
+		/// * Any error from the provider registry, e.g. an inactive provider or one
+		///   that does not support the model type
+		///
+		/// # Events
+		/// * `MeteredSessionAccepted` - Session assigned to the caller
+		#[pallet::call_index(33)]
+		#[pallet::weight(T::WeightInfo::accept_metered_session())]
+		pub fn accept_metered_session(
+			origin: OriginFor<T>,
+			session_id: SessionId,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			MeteredSessions::<T>::try_mutate(session_id, |maybe_session| -> DispatchResult {
+				let session = maybe_session.as_mut().ok_or(Error::<T>::SessionNotFound)?;
+				ensure!(session.provider.is_none(), Error::<T>::InvalidState);
+
+				let model =
+					T::Models::model_info(session.model_id).ok_or(Error::<T>::ModelNotFound)?;
+				Self::ensure_enclave(&who, session.model_id)?;
+				T::Providers::start_job(&who, model.model_type)?;
+
+				session.provider = Some(who.clone());
+				session.last_active = frame_system::Pallet::<T>::block_number();
+				Ok(())
+			})?;
+
+			Self::deposit_event(Event::MeteredSessionAccepted { session_id, provider: who });
+
+			Ok(())
+		}
+
+		/// Report units consumed by a metered session, settling their price
+		///
+		/// The price of the units is charged to the requester's tab with the model,
+		/// like the fees of settled requests.
+		///
+		/// # Errors
+		/// * `SessionNotFound` - Session doesn't exist
+		/// * `NotAssignedProvider` - Caller did not pick up the session
+		/// * `UsageCapExceeded` - The session has fewer units left than reported
+		///
+		/// # Events
+		/// * `UsageReported` - Units paid for
+		#[pallet::call_index(34)]
+		#[pallet::weight(T::WeightInfo::report_usage())]
+		pub fn report_usage(
+			origin: OriginFor<T>,
+			session_id: SessionId,
+			units: u32,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let mut session =
+				MeteredSessions::<T>::get(session_id).ok_or(Error::<T>::SessionNotFound)?;
+			ensure!(session.provider.as_ref() == Some(&who), Error::<T>::NotAssignedProvider);
+			ensure!(units <= session.remaining_units(), Error::<T>::UsageCapExceeded);
+
+			let amount = session.unit_price.saturating_mul(units.into());
+			Self::charge(&session.requester, session.model_id, session.escrow, amount)?;
+			FeesPaid::<T>::mutate(&session.requester, session.model_id, |paid| {
+				paid.saturating_accrue(amount)
+			});
+			T::Models::note_fee(session.model_id, amount);
+
+			session.consumed.saturating_accrue(units);
+			session.last_active = frame_system::Pallet::<T>::block_number();
+			MeteredSessions::<T>::insert(session_id, session);
+
+			Self::deposit_event(Event::UsageReported { session_id, units, amount });
+
+			Ok(())
+		}
+
+		/// Close a metered session, refunding the units it did not consume
+		///
+		/// Either side can close the session at any time, since every reported unit
+		/// is already paid for.
+		///
+		/// # Errors
+		/// * `SessionNotFound` - Session doesn't exist
+		/// * `NotSessionParticipant` - Caller is neither the requester nor the provider
+		///
+		/// # Events
+		/// * `MeteredSessionClosed` - Session closed
+		#[pallet::call_index(35)]
+		#[pallet::weight(T::WeightInfo::close_metered_session())]
+		pub fn close_metered_session(
+			origin: OriginFor<T>,
+			session_id: SessionId,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let session =
+				MeteredSessions::<T>::get(session_id).ok_or(Error::<T>::SessionNotFound)?;
+			ensure!(
+				session.requester == who || session.provider.as_ref() == Some(&who),
+				Error::<T>::NotSessionParticipant
+			);
+
+			let refunded = Self::end_session(session_id, &session);
+
+			Self::deposit_event(Event::MeteredSessionClosed {
+				session_id,
+				consumed: session.consumed,
+				refunded,
+			});
+
+			Ok(())
+		}
+
+		/// Expire a metered session nobody reported usage on for `MeteredSessionTimeout`
+		///
+		/// Callable by anyone, so an abandoned session neither keeps the buyer's
+		/// prepayment escrowed nor takes up a job slot of its provider. Ends the
+		/// session like `close_metered_session`.
+		///
+		/// # Errors
+		/// * `SessionNotFound` - Session doesn't exist
+		/// * `DeadlineNotPassed` - The session was active within `MeteredSessionTimeout`
+		///
+		/// # Events
+		/// * `MeteredSessionExpired` - Session expired
+		#[pallet::call_index(40)]
+		#[pallet::weight(T::WeightInfo::expire_metered_session())]
+		pub fn expire_metered_session(
+			origin: OriginFor<T>,
+			session_id: SessionId,
+		) -> DispatchResult {
+			ensure_signed(origin)?;
+
+			let session =
+				MeteredSessions::<T>::get(session_id).ok_or(Error::<T>::SessionNotFound)?;
+			let deadline = session.last_active.saturating_add(T::MeteredSessionTimeout::get());
+			ensure!(
+				frame_system::Pallet::<T>::block_number() > deadline,
+				Error::<T>::DeadlineNotPassed
+			);
+
+			let refunded = Self::end_session(session_id, &session);
+
+			Self::deposit_event(Event::MeteredSessionExpired {
+				session_id,
+				consumed: session.consumed,
+				refunded,
+			});
+
+			Ok(())
+		}
+
+		/// Move what a buyer owes a model into the model's earnings
+		///
+		/// Tabs are settled on their own once they reach `TabSettlementThreshold` and
+		/// at the end of every era; this lets owners collect smaller ones earlier.
+		///
+		/// # Arguments
+		/// * `origin` - Any signed account
+		/// * `who` - Buyer whose tab to settle
+		/// * `model_id` - Model the buyer owes
+		///
+		/// # Errors
+		/// * `NoTab` - The buyer owes the model nothing
+		///
+		/// # Events
+		/// * `TabSettled` - Tab moved into the model's earnings
+		#[pallet::call_index(36)]
+		#[pallet::weight(T::WeightInfo::settle_tab())]
+		pub fn settle_tab(
+			origin: OriginFor<T>,
+			who: T::AccountId,
+			model_id: ModelId,
+		) -> DispatchResult {
+			ensure_signed(origin)?;
+
+			Self::settle_tab_of(&who, model_id)
+		}
+
+		/// Challenge the result of a completed request
+		///
+		/// Keeps the payment escrowed past the acceptance window until the provider
+		/// or model owner concedes with `concede_challenge`, or, once `ChallengePeriod`
+		/// has passed, the `ArbitrationOrigin` rules with `resolve_challenge`.
+		///
+		/// # Arguments
+		/// * `origin` - Anyone but the buyer, the provider and the model owner; posts
+		///   `ChallengeBond`
+		/// * `request_id` - Request whose result to challenge
+		/// * `counter_hash` - Hash of the result the challenger claims is correct
+		///
+		/// # Errors
+		/// * `RequestNotFound` - Request doesn't exist
+		/// * `InvalidState` - Request is not completed
+		/// * `CannotChallenge` - Caller is the buyer, the provider or the model owner
+		/// * `DeadlinePassed` - Acceptance window is over
+		/// * `SameResult` - `counter_hash` is the submitted result
+		/// * `InsufficientBond` - Caller cannot post `ChallengeBond`
+		///
+		/// # Events
+		/// * `ResultChallenged` - Payment held until the challenge is resolved
+		#[pallet::call_index(37)]
+		#[pallet::weight(T::WeightInfo::challenge_result())]
+		pub fn challenge_result(
+			origin: OriginFor<T>,
+			request_id: RequestId,
+			counter_hash: H256,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let mut request = Requests::<T>::get(request_id).ok_or(Error::<T>::RequestNotFound)?;
+			ensure!(request.state == RequestState::Completed, Error::<T>::InvalidState);
+			// The owner would be paid from the stake of the provider serving their model
+			let owner = T::Models::model_info(request.model_id).map(|model| model.owner);
+			ensure!(
+				request.requester != who
+					&& request.provider.as_ref() != Some(&who)
+					&& owner.as_ref() != Some(&who),
+				Error::<T>::CannotChallenge
+			);
+			let now = frame_system::Pallet::<T>::block_number();
+			ensure!(now <= request.deadline, Error::<T>::DeadlinePassed);
+			ensure!(request.result_hash != Some(counter_hash), Error::<T>::SameResult);
+
+			let bond = T::Escrow::lock(&who, T::ChallengeBond::get(), None)
+				.map_err(|_| Error::<T>::InsufficientBond)?;
+
+			Self::cancel_auto_release(request_id, request.deadline);
+			request.state = RequestState::Challenged;
+			Requests::<T>::insert(request_id, request);
+			Challenges::<T>::insert(
+				request_id,
+				Challenge {
+					challenger: who.clone(),
+					counter_hash,
+					bond,
+					escalates_at: now.saturating_add(T::ChallengePeriod::get()),
+				},
+			);
+
+			Self::deposit_event(Event::ResultChallenged {
+				request_id,
+				challenger: who,
+				counter_hash,
+			});
+
+			Ok(())
+		}
+
+		/// Concede a challenge of a result, refunding the buyer
+		///
+		/// The challenger gets their bond back and `ChallengeReward` of the payment from
+		/// the provider's stake.
+		///
+		/// # Arguments
+		/// * `origin` - The assigned provider or the model owner
+		/// * `request_id` - Challenged request
+		///
+		/// # Errors
+		/// * `RequestNotFound` - Request doesn't exist
+		/// * `InvalidState` - Request is not challenged
+		/// * `NotResultSubmitter` - Caller is neither the assigned provider nor the
+		///   model owner
+		///
+		/// # Events
+		/// * `RequestFailed` - Buyer refunded
+		/// * `ChallengeResolved` - Challenge upheld
+		#[pallet::call_index(38)]
+		#[pallet::weight(T::WeightInfo::concede_challenge())]
+		pub fn concede_challenge(origin: OriginFor<T>, request_id: RequestId) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let request = Requests::<T>::get(request_id).ok_or(Error::<T>::RequestNotFound)?;
+			ensure!(request.state == RequestState::Challenged, Error::<T>::InvalidState);
+			ensure!(
+				request.provider.as_ref() == Some(&who)
+					|| T::Models::model_info(request.model_id)
+						.is_some_and(|model| model.owner == who),
+				Error::<T>::NotResultSubmitter
+			);
+
+			Self::close_challenge(request_id, request, true)
+		}
+
+		/// Rule on a challenge that escalated to arbitration
+		///
+		/// # Arguments
+		/// * `origin` - Must be the `ArbitrationOrigin`
+		/// * `request_id` - Challenged request
+		/// * `upheld` - Whether the result was wrong, refunding the buyer and rewarding
+		///   the challenger, rather than paying the model owner and the provider the
+		///   challenger's bond
+		///
+		/// # Errors
+		/// * `RequestNotFound` - Request doesn't exist
+		/// * `InvalidState` - Request is not challenged
+		/// * `ChallengeNotEscalated` - `ChallengePeriod` has not passed yet
+		/// * `ProofRequired` - Paying a model that requires a proof none was verified for
+		/// * `ModelNotFound` - Paying a model that was removed
+		///
+		/// # Events
+		/// * `RequestFailed` - Buyer refunded
+		/// * `RequestSettled` - Payment released
+		/// * `ChallengeResolved` - Challenge closed
+		#[pallet::call_index(39)]
+		#[pallet::weight(T::WeightInfo::resolve_challenge().max(
+			T::WeightInfo::settle_request(T::MaxRevenueShares::get().saturating_mul(2))
+				.saturating_add(T::OnInferenceFee::weight())
+		))]
+		pub fn resolve_challenge(
+			origin: OriginFor<T>,
+			request_id: RequestId,
+			upheld: bool,
+		) -> DispatchResult {
+			T::ArbitrationOrigin::ensure_origin(origin)?;
+
+			let request = Requests::<T>::get(request_id).ok_or(Error::<T>::RequestNotFound)?;
+			ensure!(request.state == RequestState::Challenged, Error::<T>::InvalidState);
+			let challenge = Challenges::<T>::get(request_id).ok_or(Error::<T>::InvalidState)?;
+			ensure!(
+				frame_system::Pallet::<T>::block_number() >= challenge.escalates_at,
+				Error::<T>::ChallengeNotEscalated
+			);
+
+			Self::close_challenge(request_id, request, upheld)
+		}
+	}
+
+	impl<T: Config> Pallet<T> {
+		/// Account holding native fees settled into models' earnings until they are
+		/// claimed
+		pub fn earnings_account() -> T::AccountId {
+			T::PalletId::get().into_account_truncating()
+		}
+
+		/// Escrow `price` from `who`, in `asset` or native tokens, and queue a request
+		fn do_request(
+			who: T::AccountId,
+			model_id: ModelId,
+			input_hash: H256,
+			asset: Option<AssetIdOf<T>>,
+			price: BalanceOf<T>,
+			max_price: BalanceOf<T>,
+		) -> DispatchResult {
+			ensure!(price <= max_price, Error::<T>::PriceAboveLimit);
+			let escrow = Self::escrow(&who, asset.clone(), price)?;
+			Self::open_request(who, model_id, input_hash, asset, price, Some(escrow)).map(|_| ())
+		}
+
+		/// Queue a request by `who` for `model_id` at `price`, held in `escrow` unless
+		/// it is free, and return its ID
+		fn open_request(
+			who: T::AccountId,
+			model_id: ModelId,
+			input_hash: H256,
+			asset: Option<AssetIdOf<T>>,
+			price: BalanceOf<T>,
+			escrow: Option<EscrowId>,
+		) -> Result<RequestId, DispatchError> {
+			Self::use_quota(&who, model_id)?;
+			if DemandPricings::<T>::contains_key(model_id) {
+				Self::record_demand(model_id);
+			}
+
+			let request_id = NextRequestId::<T>::get();
+			let next_id = request_id.checked_add(1).ok_or(Error::<T>::ArithmeticOverflow)?;
+
+			ModelQueue::<T>::try_mutate(model_id, |queue| {
+				queue.try_push(request_id).map_err(|_| Error::<T>::QueueFull)
+			})?;
+			if let Some(asset_id) = &asset {
+				RequestAssets::<T>::insert(request_id, asset_id);
+			}
+			if let Some(escrow) = escrow {
+				RequestEscrows::<T>::insert(request_id, escrow);
+			}
+
+			let now = frame_system::Pallet::<T>::block_number();
+			Requests::<T>::insert(
+				request_id,
+				InferenceRequest {
+					requester: who.clone(),
+					model_id,
+					input_hash,
+					price,
+					provider: None,
+					commitment: None,
+					result_hash: None,
+					proof_verified: false,
+					state: RequestState::Requested,
+					created_at: now,
+					deadline: now.saturating_add(T::AssignmentTimeout::get()),
+				},
+			);
+			NextRequestId::<T>::put(next_id);
+
+			Self::deposit_event(Event::InferenceRequested {
+				request_id,
+				model_id,
+				requester: who,
+				price,
+				asset,
+			});
+
+			Ok(request_id)
+		}
+
+		/// Escrow `amount` from `who`, in `asset` if given, and return the escrow's ID
+		fn escrow(
+			who: &T::AccountId,
+			asset: Option<AssetIdOf<T>>,
+			amount: BalanceOf<T>,
+		) -> Result<EscrowId, DispatchError> {
+			let escrow = match asset {
+				Some(asset_id) => T::Escrow::lock_asset(asset_id, who, amount, None),
+				None => T::Escrow::lock(who, amount, None),
+			};
+			escrow.map_err(|_| Error::<T>::InsufficientBalance.into())
+		}
+
+		/// Count a request by `who` against their quota for `model_id`, if it has one
+		fn use_quota(who: &T::AccountId, model_id: ModelId) -> DispatchResult {
+			let Some(quota) = Quotas::<T>::get(model_id) else { return Ok(()) };
+			let (period, used) = Self::quota_used(who, model_id);
+			ensure!(used < quota, Error::<T>::QuotaExceeded);
+			QuotaUsage::<T>::insert(who, model_id, (period, used.saturating_add(1)));
+			Ok(())
+		}
+
+		/// Index of the current quota period and the requests `who` made for
+		/// `model_id` in it
+		fn quota_used(who: &T::AccountId, model_id: ModelId) -> (BlockNumberFor<T>, u32) {
+			let period = frame_system::Pallet::<T>::block_number()
+				.checked_div(&T::QuotaPeriod::get())
+				.unwrap_or_default();
+			match QuotaUsage::<T>::get(who, model_id) {
+				Some((last, used)) if last == period => (period, used),
+				_ => (period, 0),
+			}
+		}
+
+		/// Requests `who` can still make for `model_id` this period, or `None` if
+		/// the model has no quota
+		pub fn remaining_quota(who: &T::AccountId, model_id: ModelId) -> Option<u32> {
+			let quota = Quotas::<T>::get(model_id)?;
+			Some(quota.saturating_sub(Self::quota_used(who, model_id).1))
+		}
+
+		/// Pay `amount` of the escrowed payment of a request to `to`, returning what
+		/// was paid
+		///
+		/// Payments that cannot be made, e.g. of an asset frozen in the meantime, stay
+		/// in escrow and are refunded to the buyer once the request closes.
+		fn release(request_id: RequestId, to: &T::AccountId, amount: BalanceOf<T>) -> BalanceOf<T> {
+			RequestEscrows::<T>::get(request_id)
+				.and_then(|escrow| T::Escrow::release(escrow, to, amount).ok())
+				.unwrap_or_default()
+		}
+
+		/// Refund what is left of the escrowed payment of a request to its buyer
+		fn refund(request_id: RequestId) {
+			if let Some(escrow) = RequestEscrows::<T>::take(request_id) {
+				// The escrow is closed already if everything was paid out
+				let _ = T::Escrow::refund(escrow);
+			}
+		}
+
+		/// Escrow `amount` from `sponsor` for a trial pool, adding it to the pool's
+		/// `escrow` unless that was spent, and return the escrow the pool is paid from
+		fn fund_pool(
+			sponsor: &T::AccountId,
+			escrow: Option<EscrowId>,
+			amount: BalanceOf<T>,
+		) -> Result<EscrowId, DispatchError> {
+			let funds = T::Escrow::lock(sponsor, amount, None)?;
+			match escrow {
+				Some(escrow) if !T::Escrow::held(escrow).is_zero() => {
+					T::Escrow::split(funds, amount, Some(escrow))
+				}
+				_ => Ok(funds),
+			}
+		}
+
+		/// Pay out the earnings of `model_id`, adding the cuts taken off them to `cuts`
+		/// so they can be paid together with those of other models
+		fn pay_earnings(
+			model_id: ModelId,
+			cuts: &mut BTreeMap<T::AccountId, BalanceOf<T>>,
+		) -> DispatchResult {
+			let earned = Earnings::<T>::get(model_id);
+			ensure!(!earned.is_zero(), Error::<T>::NoEarnings);
+			let model = T::Models::model_info(model_id).ok_or(Error::<T>::ModelNotFound)?;
+
+			let mut payable = earned;
+			for (recipient, share) in
+				T::EarningsCuts::get().into_iter().take(T::MaxRevenueShares::get() as usize)
+			{
+				let amount = share.mul_floor(earned).min(payable);
+				if amount.is_zero() {
+					continue;
+				}
+				payable = payable.saturating_sub(amount);
+				cuts.entry(recipient).or_default().saturating_accrue(amount);
+			}
+
+			let account = Self::earnings_account();
+			let mut unpaid = BalanceOf::<T>::zero();
+			for (recipient, amount) in Self::revenue_split(model_id, &model.owner, payable) {
+				let paid = T::Currency::transfer(
+					&account,
+					&recipient,
+					amount,
+					ExistenceRequirement::AllowDeath,
+				);
+				if paid.is_err() {
+					unpaid.saturating_accrue(amount);
+				}
+			}
+			if unpaid.is_zero() {
+				Earnings::<T>::remove(model_id);
+			} else {
+				Earnings::<T>::insert(model_id, unpaid);
+			}
+
+			Self::deposit_event(Event::EarningsClaimed {
+				model_id,
+				amount: payable.saturating_sub(unpaid),
+			});
+			Ok(())
+		}
+
+		/// Pay `cuts` out of the earnings account
+		///
+		/// Cuts a recipient cannot receive are left in the earnings account.
+		fn pay_cuts(cuts: BTreeMap<T::AccountId, BalanceOf<T>>) {
+			let account = Self::earnings_account();
+			for (recipient, amount) in cuts {
+				let paid = T::Currency::transfer(
+					&account,
+					&recipient,
+					amount,
+					ExistenceRequirement::AllowDeath,
+				);
+				if paid.is_ok() {
+					Self::deposit_event(Event::EarningsCutPaid { recipient, amount });
+				}
+			}
+		}
+
+		/// Pay out the earnings of the next `MaxPayoutsPerBlock` models of the running
+		/// sweep, ending it once every model was paid, and return how many were
+		pub(crate) fn sweep_earnings() -> u32 {
+			let Some(progress) = PayoutSweep::<T>::get() else { return 0 };
+			let limit = T::MaxPayoutsPerBlock::get() as usize;
+			let batch: Vec<ModelId> = match progress {
+				SweepProgress::Started => Earnings::<T>::iter_keys().take(limit).collect(),
+				SweepProgress::After(last) => {
+					Earnings::<T>::iter_keys_from(Earnings::<T>::hashed_key_for(last))
+						.take(limit)
+						.collect()
+				}
+			};
+
+			let mut cuts = BTreeMap::new();
+			for model_id in &batch {
+				// Earnings of removed models stay until a later runtime decides their fate
+				let _ = Self::pay_earnings(*model_id, &mut cuts);
+			}
+			Self::pay_cuts(cuts);
+
+			match batch.last() {
+				Some(last) if batch.len() == limit => {
+					PayoutSweep::<T>::put(SweepProgress::After(*last))
+				}
+				_ => PayoutSweep::<T>::kill(),
+			}
+			batch.len() as u32
+		}
+
+		/// Parts of `amount` earned by `model_id` owed to each of its revenue shares,
+		/// or all of it to `owner` if the model has none
+		fn revenue_split(
+			model_id: ModelId,
+			owner: &T::AccountId,
+			amount: BalanceOf<T>,
+		) -> Vec<(T::AccountId, BalanceOf<T>)> {
+			let shares = T::Models::revenue_shares(model_id);
+			if shares.is_empty() {
+				return sp_std::vec![(owner.clone(), amount)];
+			}
+			// Rounding leftovers go to the last shareholder
+			let mut remaining = amount;
+			let last = shares.len() - 1;
+			shares
+				.into_iter()
+				.enumerate()
+				.map(|(i, (holder, share))| {
+					let part =
+						if i == last { remaining } else { share.mul_floor(amount).min(remaining) };
+					remaining = remaining.saturating_sub(part);
+					(holder, part)
+				})
+				.collect()
+		}
+
+		/// Native price of a request for `model_id` right now: its promotional price
+		/// while a promotion runs, else its USD price converted if it has one, else
+		/// `registry_price`
+		pub fn current_price(
+			model_id: ModelId,
+			registry_price: BalanceOf<T>,
+		) -> Result<BalanceOf<T>, Error<T>> {
+			let now = frame_system::Pallet::<T>::block_number();
+			let price = match PromoPrices::<T>::get(model_id) {
+				Some((promo_price, until)) if now < until => promo_price,
+				_ => match UsdPrices::<T>::get(model_id) {
+					Some(cents) => T::PriceFeed::usd_cents_to_native(cents)
+						.ok_or(Error::<T>::PriceFeedUnavailable)?,
+					None => registry_price,
+				},
+			};
+			Ok(Self::with_demand(model_id, price))
+		}
+
+		/// Price of a request for `model_id` paid in `asset_id` right now, if the model
+		/// accepts the asset
+		pub fn asset_price(model_id: ModelId, asset_id: AssetIdOf<T>) -> Option<BalanceOf<T>> {
+			AssetPrices::<T>::get(model_id, asset_id)
+				.map(|price| Self::with_demand(model_id, price))
+		}
+
+		/// Native payment `call` makes for an inference, if it requests one at the
+		/// model's price and would be accepted at the current price
+		///
+		/// Coupon, trial and asset-paid requests attach no native payment.
+		pub fn inference_payment(call: &Call<T>) -> Option<BalanceOf<T>> {
+			let (model_id, max_price) = match call {
+				Call::request_inference { model_id, max_price, .. }
+				| Call::request_inference_with_referrer { model_id, max_price, .. } => (*model_id, *max_price),
+				_ => return None,
+			};
+			let model = T::Models::model_info(model_id)?;
+			let price = Self::current_price(model_id, model.price).ok()?;
+			(model.active && price <= max_price).then_some(price)
+		}
+
+		/// `price` raised by the demand surcharge of `model_id`, if it is demand-priced
+		fn with_demand(model_id: ModelId, price: BalanceOf<T>) -> BalanceOf<T> {
+			match DemandPricings::<T>::get(model_id) {
+				Some(pricing) => {
+					let surcharge = pricing.surcharge(Self::recent_demand(model_id));
+					price.saturating_add(surcharge * price)
+				}
+				None => price,
+			}
+		}
+
+		/// Requests for `model_id` in the last `DemandWindow` blocks, estimated
+		/// from the counts of the current and the previous window
+		pub fn recent_demand(model_id: ModelId) -> u32 {
+			let window = T::DemandWindow::get();
+			let now = frame_system::Pallet::<T>::block_number();
+			let Some(index) = now.checked_div(&window) else { return 0 };
+			let counter = DemandCounters::<T>::get(model_id);
+			let (current, previous) = if counter.window == index {
+				(counter.current, counter.previous)
+			} else if counter.window.saturating_add(One::one()) == index {
+				(0, counter.current)
+			} else {
+				return 0;
+			};
+
+			// The previous window counts as far as it overlaps the last `window` blocks
+			let elapsed: u64 = now.saturating_sub(index.saturating_mul(window)).saturated_into();
+			let window: u64 = window.saturated_into();
+			let carried =
+				u64::from(previous).saturating_mul(window.saturating_sub(elapsed)) / window;
+			current.saturating_add(carried.saturated_into())
+		}
+
+		/// Count a request for `model_id` in the current demand window
+		fn record_demand(model_id: ModelId) {
+			let now = frame_system::Pallet::<T>::block_number();
+			let Some(index) = now.checked_div(&T::DemandWindow::get()) else { return };
+			DemandCounters::<T>::mutate(model_id, |counter| {
+				if counter.window != index {
+					let previous = if counter.window.saturating_add(One::one()) == index {
+						counter.current
+					} else {
+						0
+					};
+					*counter = DemandCounter { window: index, current: 0, previous };
+				}
+				counter.current.saturating_inc();
+			});
+		}
+
+		/// Hash discount codes are stored under
+		pub fn coupon_hash(code: &[u8]) -> H256 {
+			BlakeTwo256::hash(code)
+		}
+
+		/// Commitment a provider submits for `result_hash` with a secret `salt`
+		pub fn result_commitment(result_hash: &H256, salt: &H256) -> H256 {
+			BlakeTwo256::hash_of(&(result_hash, salt))
+		}
+
+		/// Remove a metered session, refunding the units it did not consume and
+		/// freeing its provider, and return the amount refunded
+		fn end_session(session_id: SessionId, session: &MeteredSessionOf<T>) -> BalanceOf<T> {
+			// The escrow is closed already if every unit was consumed
+			let refunded = T::Escrow::refund(session.escrow).unwrap_or_default();
+			if let Some(provider) = &session.provider {
+				T::Providers::finish_job(provider);
+				if session.consumed > 0 {
+					T::Models::note_inference(session.model_id);
+					T::Providers::record_outcome(provider, JobOutcome::Completed);
+				}
+			}
+			MeteredSessions::<T>::remove(session_id);
+			refunded
+		}
+
+		/// Native fees `who` owes `model_id` on their tab
+		pub fn tab(who: &T::AccountId, model_id: ModelId) -> BalanceOf<T> {
+			Tabs::<T>::get(who, model_id).map_or_else(Zero::zero, T::Escrow::held)
+		}
+
+		/// Move `amount` out of `escrow`, held from `who`, onto their tab with
+		/// `model_id`, settling the tab once it reaches `TabSettlementThreshold`
+		fn charge(
+			who: &T::AccountId,
+			model_id: ModelId,
+			escrow: EscrowId,
+			amount: BalanceOf<T>,
+		) -> DispatchResult {
+			if amount.is_zero() {
+				return Ok(());
+			}
+			let tab = Tabs::<T>::get(who, model_id).filter(|tab| !T::Escrow::held(*tab).is_zero());
+			let tab = T::Escrow::split(escrow, amount, tab)?;
+			Tabs::<T>::insert(who, model_id, tab);
+			if T::Escrow::held(tab) >= T::TabSettlementThreshold::get() {
+				// A tab that cannot be settled yet stays open, as in the era sweep
+				let _ = with_storage_layer(|| Self::settle_tab_of(who, model_id));
+			}
+			Ok(())
+		}
+
+		/// Move the tab of `who` with `model_id` out of escrow into the model's
+		/// earnings, pooled until claimed so owners are not paid fee by fee
+		fn settle_tab_of(who: &T::AccountId, model_id: ModelId) -> DispatchResult {
+			let tab = Tabs::<T>::get(who, model_id).ok_or(Error::<T>::NoTab)?;
+			ensure!(!T::Escrow::held(tab).is_zero(), Error::<T>::NoTab);
+
+			let amount = T::Escrow::release_all(tab, &Self::earnings_account())?;
+			Tabs::<T>::remove(who, model_id);
+			Earnings::<T>::mutate(model_id, |earned| earned.saturating_accrue(amount));
+
+			Self::deposit_event(Event::TabSettled { who: who.clone(), model_id, amount });
+			Ok(())
+		}
+
+		/// Settle the next `MaxTabSettlementsPerBlock` tabs of the running sweep,
+		/// ending it and starting the payout sweep once every tab was settled, and
+		/// return how many were
+		pub(crate) fn settle_tabs() -> u32 {
+			let Some(progress) = TabSweep::<T>::get() else { return 0 };
+			let limit = T::MaxTabSettlementsPerBlock::get() as usize;
+			let mut batch: Vec<(T::AccountId, ModelId)> = match progress {
+				SweepProgress::Started => Tabs::<T>::iter_keys().take(limit).collect(),
+				SweepProgress::After((who, model_id)) => {
+					Tabs::<T>::iter_keys_from(Tabs::<T>::hashed_key_for(&who, model_id))
+						.take(limit)
+						.collect()
+				}
+			};
+
+			for (who, model_id) in &batch {
+				// Tabs that cannot be moved, e.g. below the existential deposit of a
+				// reaped earnings account, stay until they grow or the next era
+				let _ = with_storage_layer(|| Self::settle_tab_of(who, *model_id));
+			}
+
+			let settled = batch.len() as u32;
+			match batch.pop() {
+				Some(last) if settled as usize == limit => {
+					TabSweep::<T>::put(SweepProgress::After(last))
+				}
+				_ => {
+					TabSweep::<T>::kill();
+					PayoutSweep::<T>::put(SweepProgress::Started);
+				}
+			}
+			settled
+		}
+
+		/// Release the payment of a completed request to the model owner
+		fn settle(request_id: RequestId, mut request: InferenceRequestOf<T>) -> DispatchResult {
+			let who = request.requester.clone();
+			ensure!(
+				request.proof_verified || !T::ProofVerifier::requires_proof(request.model_id),
+				Error::<T>::ProofRequired
+			);
+			let model = T::Models::model_info(request.model_id).ok_or(Error::<T>::ModelNotFound)?;
+
+			let mut payable = request.price;
+			let referrer = Referrals::<T>::take(request_id).map(|(referrer, share)| {
+				let amount = share.mul_floor(request.price);
+				payable = payable.saturating_sub(amount);
+				let amount = Self::release(request_id, &referrer, amount);
+				if !amount.is_zero() {
+					ReferralEarnings::<T>::mutate(request.model_id, &referrer, |earned| {
+						earned.saturating_accrue(amount)
+					});
+					Self::deposit_event(Event::ReferralPaid {
+						request_id,
+						referrer: referrer.clone(),
+						amount,
+					});
+				}
+				referrer
+			});
+
+			let cuts = T::OnInferenceFee::on_inference_fee(
+				request.model_id,
+				&who,
+				&model.owner,
+				referrer.as_ref(),
+				payable,
+			);
+			for (recipient, amount) in cuts.into_iter().take(T::MaxRevenueShares::get() as usize) {
+				let amount = amount.min(payable);
+				if amount.is_zero() {
+					continue;
+				}
+				payable = payable.saturating_sub(amount);
+				let amount = Self::release(request_id, &recipient, amount);
+				if !amount.is_zero() {
+					Self::deposit_event(Event::InferenceFeeSplit { request_id, recipient, amount });
+				}
+			}
+
+			if RequestAssets::<T>::contains_key(request_id) {
+				for (recipient, amount) in
+					Self::revenue_split(request.model_id, &model.owner, payable)
+				{
+					Self::release(request_id, &recipient, amount);
+				}
+			} else if let Some(escrow) = RequestEscrows::<T>::get(request_id) {
+				Self::charge(&who, request.model_id, escrow, payable)?;
+			}
+			Self::refund(request_id);
+
+			request.state = RequestState::Settled;
+			TrialRequests::<T>::remove(request_id);
+			LatestReceipt::<T>::insert(&who, request.model_id, request_id);
+			InferencesPurchased::<T>::mutate(&who, request.model_id, |count| {
+				count.saturating_inc()
+			});
+			let amount = request.price;
+			FeesPaid::<T>::mutate(&who, request.model_id, |paid| paid.saturating_accrue(amount));
+			T::Models::note_fee(request.model_id, amount);
+			Requests::<T>::insert(request_id, request);
+
+			Self::deposit_event(Event::RequestSettled { request_id, owner: model.owner, amount });
+
+			Ok(())
+		}
+
+		/// Mark a request whose result was delivered completed, opening its
+		/// acceptance window
+		fn complete(request_id: RequestId, request: &mut InferenceRequestOf<T>) -> DispatchResult {
+			let window = T::AcceptanceWindow::get();
+			request.state = RequestState::Completed;
+			request.deadline = frame_system::Pallet::<T>::block_number().saturating_add(window);
+			if !window.is_zero() {
+				AutoReleases::<T>::try_append(
+					request.deadline.saturating_add(One::one()),
+					request_id,
+				)
+				.map_err(|_| Error::<T>::TooManyAutoReleases)?;
+			}
+			Ok(())
+		}
+
+		/// Stop the payment of a request with an acceptance window ending at `deadline`
+		/// from being released automatically
+		fn cancel_auto_release(request_id: RequestId, deadline: BlockNumberFor<T>) {
+			AutoReleases::<T>::mutate_exists(deadline.saturating_add(One::one()), |maybe_ids| {
+				if let Some(ids) = maybe_ids {
+					ids.retain(|id| *id != request_id);
+					if ids.is_empty() {
+						*maybe_ids = None;
+					}
+				}
+			});
+		}
+
+		/// Release the payments of the requests whose acceptance window ended before
+		/// `now`
+		///
+		/// Requests that cannot be settled, e.g. because their model requires a proof
+		/// nobody submitted or was reaped, fail and are refunded to the buyer rather than
+		/// keeping the payment escrowed.
+		pub(crate) fn release_payments(now: BlockNumberFor<T>) -> Weight {
+			let request_ids = AutoReleases::<T>::take(now);
+			let settle_weight =
+				T::WeightInfo::settle_request(T::MaxRevenueShares::get().saturating_mul(2))
+					.saturating_add(T::OnInferenceFee::weight());
+			let mut weight = T::DbWeight::get().reads_writes(1, 1);
+			for request_id in request_ids {
+				weight.saturating_accrue(settle_weight);
+				let Some(request) = Requests::<T>::get(request_id) else { continue };
+				if request.state != RequestState::Completed {
+					continue;
+				}
+				if with_storage_layer(|| Self::settle(request_id, request.clone())).is_err() {
+					Self::fail(request_id, request, FailureReason::Unsettleable);
+				}
+			}
+			weight
+		}
+
+		/// Refund an open request and mark it failed
+		fn fail(request_id: RequestId, mut request: InferenceRequestOf<T>, reason: FailureReason) {
+			match TrialRequests::<T>::take(request_id) {
+				Some(sponsor) => Self::refund_trial(request_id, &request, sponsor),
+				None => Self::refund(request_id),
+			}
+			// Providers are released from the job once they commit
+			if let (RequestState::Assigned, Some(provider)) = (&request.state, &request.provider) {
+				T::Providers::finish_job(provider);
+			}
+			if let (Some(outcome), Some(provider)) = (reason.provider_outcome(), &request.provider)
+			{
+				T::Providers::record_outcome(provider, outcome);
+			}
+			Self::dequeue(request.model_id, request_id);
+			Referrals::<T>::remove(request_id);
+
+			request.state = RequestState::Failed;
+			Requests::<T>::insert(request_id, request);
+
+			Self::deposit_event(Event::RequestFailed { request_id, reason });
+		}
+
+		/// Whether the payment of `request` can be disputed: its result is awaiting
+		/// acceptance, or the payment is held for a dispute already
+		pub(crate) fn is_disputable(request: &InferenceRequestOf<T>) -> bool {
+			match request.state {
+				RequestState::Completed => {
+					frame_system::Pallet::<T>::block_number() <= request.deadline
+				}
+				RequestState::Disputed => true,
+				_ => false,
+			}
+		}
+
+		/// Hold the payment of a completed request for a dispute past its acceptance
+		/// window
+		pub(crate) fn hold_disputed(request_id: RequestId) -> DispatchResult {
+			let mut request = Requests::<T>::get(request_id).ok_or(Error::<T>::RequestNotFound)?;
+			if request.state == RequestState::Disputed {
+				return Ok(());
+			}
+			ensure!(request.state == RequestState::Completed, Error::<T>::InvalidState);
+			ensure!(Self::is_disputable(&request), Error::<T>::DeadlinePassed);
+
+			Self::cancel_auto_release(request_id, request.deadline);
+			request.state = RequestState::Disputed;
+			Requests::<T>::insert(request_id, request);
+
+			Self::deposit_event(Event::ResultDisputed { request_id });
+
+			Ok(())
+		}
+
+		/// Refund `amount` of the payment of a disputed request to whoever paid it,
+		/// releasing the rest as if the buyer had accepted the result
+		///
+		/// If the rest cannot be released, e.g. because the model was reaped while the
+		/// dispute was open, it is refunded too, so the ruling still closes the dispute.
+		pub(crate) fn refund_disputed(
+			request_id: RequestId,
+			amount: BalanceOf<T>,
+		) -> DispatchResult {
+			let mut request = Requests::<T>::get(request_id).ok_or(Error::<T>::RequestNotFound)?;
+			ensure!(request.state == RequestState::Disputed, Error::<T>::InvalidState);
+
+			if amount >= request.price {
+				Self::fail(request_id, request, FailureReason::DisputeUpheld);
+				return Ok(());
+			}
+			let payer =
+				TrialRequests::<T>::get(request_id).unwrap_or_else(|| request.requester.clone());
+			Self::release(request_id, &payer, amount);
+			// What the buyer paid in the end is what the model is credited with
+			request.price.saturating_reduce(amount);
+			if with_storage_layer(|| Self::settle(request_id, request.clone())).is_err() {
+				Self::fail(request_id, request, FailureReason::Unsettleable);
+			}
+			Ok(())
+		}
+
+		/// Have `payer` pay `amount` for an inference of `model_id` that completed,
+		/// returning the request's ID
+		#[cfg(feature = "runtime-benchmarks")]
+		pub(crate) fn create_completed_request(
+			payer: &T::AccountId,
+			model_id: ModelId,
+			amount: BalanceOf<T>,
+		) -> RequestId {
+			let _ = T::Currency::deposit_creating(payer, amount);
+			let request_id = NextRequestId::<T>::get();
+			let _ = Self::do_request(payer.clone(), model_id, H256::zero(), None, amount, amount);
+			Self::dequeue(model_id, request_id);
+			Requests::<T>::mutate(request_id, |request| {
+				if let Some(request) = request {
+					request.state = RequestState::Completed;
+					request.deadline = frame_system::Pallet::<T>::block_number()
+						.saturating_add(T::AcceptanceWindow::get());
+				}
+			});
+			request_id
+		}
+
+		/// Close the challenge of `request_id`
+		///
+		/// An upheld challenge refunds the buyer in full and the challenger's bond, and
+		/// slashes `ChallengeReward` of the payment from the provider's stake to the
+		/// challenger. Otherwise the payment is released as if the buyer had accepted
+		/// the result and the bond goes to the provider.
+		fn close_challenge(
+			request_id: RequestId,
+			request: InferenceRequestOf<T>,
+			upheld: bool,
+		) -> DispatchResult {
+			let challenge = Challenges::<T>::take(request_id).ok_or(Error::<T>::InvalidState)?;
+
+			if upheld {
+				let _ = T::Escrow::refund(challenge.bond);
+				if let Some(provider) = &request.provider {
+					let reward = T::ChallengeReward::get().mul_floor(request.price);
+					T::Providers::slash_to(provider, &challenge.challenger, reward);
+				}
+				Self::fail(request_id, request, FailureReason::ChallengeUpheld);
+			} else {
+				// A bond that cannot be paid to the provider goes back to the challenger
+				let forfeited = request.provider.as_ref().is_some_and(|provider| {
+					T::Escrow::release_all(challenge.bond, provider).is_ok()
+				});
+				if !forfeited {
+					let _ = T::Escrow::refund(challenge.bond);
+				}
+				Self::settle(request_id, request)?;
+			}
+
+			Self::deposit_event(Event::ChallengeResolved { request_id, upheld });
+
+			Ok(())
+		}
+
+		/// Return what is left of the payment of a failed trial request to its pool,
+		/// or to the sponsor if they closed the pool since, and give the credit back
+		fn refund_trial(
+			request_id: RequestId,
+			request: &InferenceRequestOf<T>,
+			sponsor: T::AccountId,
+		) {
+			let refunded = RequestEscrows::<T>::take(request_id)
+				.and_then(|escrow| T::Escrow::release_all(escrow, &sponsor).ok())
+				.unwrap_or_default();
+			TrialPools::<T>::mutate(request.model_id, |maybe_pool| {
+				let pool = maybe_pool.as_mut().filter(|pool| pool.sponsor == sponsor);
+				if let Some(pool) = pool.filter(|_| !refunded.is_zero()) {
+					// Should the sponsor be unable to escrow it again, it stays theirs
+					if let Ok(escrow) = Self::fund_pool(&sponsor, Some(pool.escrow), refunded) {
+						pool.escrow = escrow;
+					}
+				}
+			});
+			TrialClaims::<T>::mutate(&request.requester, request.model_id, |claimed| {
+				claimed.saturating_dec()
+			});
+		}
+
+		/// Check that `who` serves from the enclave `model_id` requires, if any
+		fn ensure_enclave(who: &T::AccountId, model_id: ModelId) -> DispatchResult {
+			if let Some(requirement) = EnclaveRequirements::<T>::get(model_id) {
+				let measurement =
+					T::Providers::enclave_measurement(who).ok_or(Error::<T>::EnclaveRequired)?;
+				if let EnclaveRequirement::Measurement(required) = requirement {
+					ensure!(measurement == required, Error::<T>::EnclaveMismatch);
+				}
+			}
+			Ok(())
+		}
+
+		/// Remove a request from its model's queue
+		fn dequeue(model_id: ModelId, request_id: RequestId) {
+			ModelQueue::<T>::mutate_exists(model_id, |maybe_queue| {
+				if let Some(queue) = maybe_queue {
+					queue.retain(|id| *id != request_id);
+					if queue.is_empty() {
+						*maybe_queue = None;
+					}
+				}
+			});
+		}
+	}
+}
+
+/// Settled requests act as purchase receipts for registry ratings
+impl<T: Config> pallet_ai_registry::InferenceReceipts<T::AccountId> for Pallet<T> {
+	fn receipt_for(
+		who: &T::AccountId,
+		model_id: pallet_ai_registry::ModelId,
+	) -> Option<pallet_ai_registry::ReceiptId> {
+		LatestReceipt::<T>::get(who, model_id)
+	}
+
+	#[cfg(feature = "runtime-benchmarks")]
+	fn create_receipt(who: &T::AccountId, model_id: pallet_ai_registry::ModelId) {
+		let request_id = NextRequestId::<T>::mutate(|id| {
+			let current = *id;
+			*id = id.saturating_add(1);
+			current
+		});
+		LatestReceipt::<T>::insert(who, model_id, request_id);
+	}
+}
+
+/// Results are disputed through the disputes pallet within their acceptance window,
+/// the request ID being the receipt
+impl<T: Config> pallet_disputes::InferencePayments<T::AccountId, BalanceOf<T>> for Pallet<T> {
+	fn payment(
+		receipt: pallet_ai_registry::ReceiptId,
+	) -> Option<pallet_disputes::PaymentInfo<T::AccountId, BalanceOf<T>>> {
+		let request = Requests::<T>::get(receipt)?;
+		Self::is_disputable(&request).then(|| pallet_disputes::PaymentInfo {
+			payer: request.requester,
+			model_id: request.model_id,
+			amount: request.price,
+		})
+	}
+
+	fn hold(receipt: pallet_ai_registry::ReceiptId) -> frame_support::dispatch::DispatchResult {
+		Self::hold_disputed(receipt)
+	}
+
+	fn refund(
+		receipt: pallet_ai_registry::ReceiptId,
+		amount: BalanceOf<T>,
+	) -> frame_support::dispatch::DispatchResult {
+		Self::refund_disputed(receipt, amount)
+	}
+
+	#[cfg(feature = "runtime-benchmarks")]
+	fn create_payment(
+		payer: &T::AccountId,
+		model_id: pallet_ai_registry::ModelId,
+		amount: BalanceOf<T>,
+	) -> pallet_ai_registry::ReceiptId {
+		Self::create_completed_request(payer, model_id, amount)
+	}
+}
+
+/// What an account paid for inferences of a model is its stake in the model's ratings
+impl<T: Config> pallet_ai_registry::RaterStake<T::AccountId> for Pallet<T> {
+	fn stake_of(who: &T::AccountId, model_id: pallet_ai_registry::ModelId) -> u128 {
+		use sp_runtime::SaturatedConversion;
+
+		FeesPaid::<T>::get(who, model_id).saturated_into()
+	}
+}
+
+/// Resolves models through the AI registry pallet
+impl<T, Balance> InferenceModels<T::AccountId, Balance> for pallet_ai_registry::Pallet<T>
+where
+	T: pallet_ai_registry::Config,
+	T::RuntimeOrigin: From<Origin>,
+	Balance: sp_runtime::traits::AtLeast32BitUnsigned,
+{
+	fn model_info(
+		model_id: pallet_ai_registry::ModelId,
+	) -> Option<ModelInfo<T::AccountId, Balance>> {
+		use pallet_ai_registry::AiRegistryInspect;
+
+		Some(ModelInfo {
+			owner: <Self as AiRegistryInspect<_, Balance>>::owner(model_id)?,
+			model_type: <Self as AiRegistryInspect<_, Balance>>::model_type(model_id)?,
+			price: <Self as AiRegistryInspect<_, Balance>>::price(model_id)?,
+			active: <Self as AiRegistryInspect<_, Balance>>::is_active(model_id),
+		})
+	}
+
+	fn note_inference(model_id: pallet_ai_registry::ModelId) {
+		// The model can only be missing if it was reaped, in which case there is
+		// nothing left to count
+		let _ = Self::increment_inference_count(Origin::Inference.into(), model_id);
+	}
+
+	fn note_fee(model_id: pallet_ai_registry::ModelId, fee: Balance) {
+		use sp_runtime::SaturatedConversion;
+
+		// Cannot fail: the origin is the one the registry expects
+		let _ = Self::note_fee_volume(Origin::Inference.into(), model_id, fee.saturated_into());
+	}
+
+	fn revenue_shares(
+		model_id: pallet_ai_registry::ModelId,
+	) -> sp_std::vec::Vec<(T::AccountId, sp_runtime::Perbill)> {
+		Self::revenue_shares(model_id)
+	}
+
+	#[cfg(feature = "runtime-benchmarks")]
+	fn create_model(owner: &T::AccountId) -> pallet_ai_registry::ModelId {
+		use frame_support::traits::{Currency, Get};
+		use sp_runtime::traits::Saturating;
+
+		let balance = Self::model_deposit(1_000)
+			.saturating_add(T::RegistrationFee::get())
+			.saturating_add(T::Currency::minimum_balance())
+			.saturating_mul(2u32.into());
+		T::Currency::make_free_balance_be(owner, balance);
+
+		let model_id = pallet_ai_registry::NextModelId::<T>::get();
+		let _ = Self::register_model(
+			frame_system::RawOrigin::Signed(owner.clone()).into(),
+			Self::benchmark_cid(model_id),
+			Self::benchmark_name(model_id),
+			b"Description".to_vec(),
+			pallet_ai_registry::ModelType::Classification,
+			1_000u32.into(),
+			pallet_ai_registry::License::Mit,
+			1_000,
+			Default::default(),
+		);
+		model_id
+	}
+
+	#[cfg(feature = "runtime-benchmarks")]
+	fn set_revenue_shares(
+		model_id: pallet_ai_registry::ModelId,
+		shares: sp_std::vec::Vec<(T::AccountId, sp_runtime::Perbill)>,
+	) {
+		use pallet_ai_registry::AiRegistryInspect;
+
+		if let Some(owner) = <Self as AiRegistryInspect<_, u128>>::owner(model_id) {
+			let _ = Self::fractionalize_model(
+				frame_system::RawOrigin::Signed(owner).into(),
+				model_id,
+				shares,
+			);
+		}
+	}
+}
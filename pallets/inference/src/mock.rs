@@ -0,0 +1,332 @@
+//! Mock runtime for Inference pallet tests
+
+use crate as pallet_inference;
+use frame_support::weights::Weight;
+use frame_support::{
+	derive_impl, parameter_types,
+	traits::{
+		AsEnsureOriginWithArg, ConstBool, ConstU128, ConstU32, ConstU64, ConstU8, Everything,
+		IsInVec,
+	},
+	PalletId,
+};
+use frame_system::{EnsureRoot, EnsureSigned};
+use sp_core::H256;
+use sp_runtime::{
+	testing::{TestSignature, UintAuthorityId},
+	BuildStorage, Perbill,
+};
+
+type Block = frame_system::mocking::MockBlock<Test>;
+
+#[frame_support::runtime]
+mod runtime {
+	#[runtime::runtime]
+	#[runtime::derive(
+		RuntimeCall,
+		RuntimeEvent,
+		RuntimeError,
+		RuntimeOrigin,
+		RuntimeFreezeReason,
+		RuntimeHoldReason,
+		RuntimeSlashReason,
+		RuntimeLockId,
+		RuntimeTask
+	)]
+	pub struct Test;
+
+	#[runtime::pallet_index(0)]
+	pub type System = frame_system::Pallet<Test>;
+
+	#[runtime::pallet_index(1)]
+	pub type Balances = pallet_balances::Pallet<Test>;
+
+	#[runtime::pallet_index(2)]
+	pub type AIRegistry = pallet_ai_registry::Pallet<Test>;
+
+	#[runtime::pallet_index(3)]
+	pub type ComputeProviders = pallet_compute_providers::Pallet<Test>;
+
+	#[runtime::pallet_index(4)]
+	pub type Inference = pallet_inference::Pallet<Test>;
+
+	#[runtime::pallet_index(5)]
+	pub type Assets = pallet_assets::Pallet<Test>;
+
+	#[runtime::pallet_index(6)]
+	pub type Escrow = pallet_escrow::Pallet<Test>;
+}
+
+#[derive_impl(frame_system::config_preludes::TestDefaultConfig)]
+impl frame_system::Config for Test {
+	type Block = Block;
+	type AccountData = pallet_balances::AccountData<u128>;
+}
+
+#[derive_impl(pallet_balances::config_preludes::TestDefaultConfig)]
+impl pallet_balances::Config for Test {
+	type Balance = u128;
+	type ExistentialDeposit = ConstU128<1>;
+	type AccountStore = System;
+}
+
+#[derive_impl(pallet_assets::config_preludes::TestDefaultConfig)]
+impl pallet_assets::Config for Test {
+	type Balance = u128;
+	type Currency = Balances;
+	type CreateOrigin = AsEnsureOriginWithArg<EnsureSigned<u64>>;
+	type ForceOrigin = EnsureRoot<u64>;
+	type Freezer = ();
+}
+
+parameter_types! {
+	pub const EscrowPalletId: PalletId = PalletId(*b"py/escrw");
+}
+
+impl pallet_escrow::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type WeightInfo = ();
+	type Currency = Balances;
+	type Assets = Assets;
+	type PalletId = EscrowPalletId;
+	type MaxTimeoutsPerBlock = ConstU32<2>;
+}
+
+parameter_types! {
+	pub const SlashFraction: Perbill = Perbill::from_percent(50);
+	pub Developers: Vec<u64> = vec![];
+	pub const ProposalShareThreshold: Perbill = Perbill::from_percent(20);
+	pub const CreatorRoyalty: Perbill = Perbill::from_percent(10);
+	pub const MaxParentShare: Perbill = Perbill::from_percent(50);
+	pub const RegistryPalletId: PalletId = PalletId(*b"py/aireg");
+	pub const RebatePerEra: Perbill = Perbill::from_percent(0);
+	pub const IncentiveSource: u64 = 99;
+}
+
+impl pallet_ai_registry::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type RuntimeCall = RuntimeCall;
+	type WeightInfo = ();
+	type Currency = Balances;
+	type MinimumModelStake = ConstU128<1000>;
+	type RegistrationFee = ConstU128<100>;
+	type MaxCidLength = ConstU32<128>;
+	type MaxNameLength = ConstU32<256>;
+	type MaxDescriptionLength = ConstU32<1024>;
+	type DepositPerByte = ConstU128<1>;
+	type RegistrationOrigin = EnsureSigned<u64>;
+	type GovernanceOrigin = EnsureRoot<u64>;
+	type AdminSunsetBlock = ConstU64<100>;
+	type SunsetExtensionOrigin = EnsureRoot<u64>;
+	type MaxCallbacksPerModel = ConstU32<2>;
+	type InferenceReceipts = Inference;
+	type RequireReceiptToRate = ConstBool<false>;
+	type RatingWeight = ();
+	type MinRaterAge = ConstU64<0>;
+	type MinRaterBalance = ConstU128<0>;
+	type MaxRatingsPerEra = ConstU32<16>;
+	type RatingWindow = ConstU32<0>;
+	type PauseMinRatings = ConstU32<0>;
+	type PauseRatingFloor = ConstU8<0>;
+	type ReportThreshold = ConstU32<3>;
+	type AppealBond = ConstU128<0>;
+	type MaxDeactivationGrace = ConstU64<0>;
+	type MaxDeactivationsPerBlock = ConstU32<1>;
+	type DeactivationNotice = ConstU64<0>;
+	type MaxPriceChangesPerBlock = ConstU32<1>;
+	type RequireRegistrationApproval = ConstBool<false>;
+	type RegistrationApprovalOrigin = EnsureRoot<u64>;
+	type DeveloperAllowlist = IsInVec<Developers>;
+	type MaxOrganizationMembers = ConstU32<1>;
+	type VerifiedRaters = Everything;
+	type InferenceOrigin = pallet_inference::EnsureInference;
+	type ModelNfts = ();
+	type OnModelRegistered = ();
+	type OnModelStatusChanged = ();
+	type MaxShareholders = ConstU32<4>;
+	type ProposalShareThreshold = ProposalShareThreshold;
+	type CreatorRoyalty = CreatorRoyalty;
+	type MaxDatasetsPerModel = ConstU32<3>;
+	type MaxParentShare = MaxParentShare;
+	type MaxModelsPerCollection = ConstU32<3>;
+	type MaxCollectionsPerModel = ConstU32<2>;
+	type MaxBookmarks = ConstU32<3>;
+	type MaxModelsPerOwner = ConstU32<16>;
+	type MaxWatchers = ConstU32<3>;
+	type WatchersPerEvent = ConstU32<2>;
+	type OwnerSignature = TestSignature;
+	type OwnerSigner = UintAuthorityId;
+	type MaxExportBatch = ConstU32<3>;
+	type RentEraLength = ConstU64<10>;
+	type RentFreeEras = ConstU32<2>;
+	type RentPerEra = ConstU128<0>;
+	type RentReapDelay = ConstU64<5>;
+	type SlashOrigin = EnsureRoot<u64>;
+	type SlashFraction = SlashFraction;
+	type Slash = ();
+	type PalletId = RegistryPalletId;
+	type RebateRatingThreshold = ConstU8<4>;
+	type RebateMinRatings = ConstU32<2>;
+	type RebatePerEra = RebatePerEra;
+	type IncentiveOrigin = EnsureRoot<u64>;
+	type IncentiveSource = IncentiveSource;
+	type MaxRewardedModels = ConstU32<2>;
+	type RewardMinRatings = ConstU32<2>;
+	type RewardPerEra = ConstU128<0>;
+	type MaxUpdateHistory = ConstU32<3>;
+	type UsageWindow = ConstU32<3>;
+	type LeaderboardSize = ConstU32<3>;
+	type NamePrefixLength = ConstU32<3>;
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkHelper = RegistryBenchmarkHelper;
+}
+
+#[cfg(feature = "runtime-benchmarks")]
+pub struct RegistryBenchmarkHelper;
+#[cfg(feature = "runtime-benchmarks")]
+impl pallet_ai_registry::BenchmarkHelper<TestSignature, u64> for RegistryBenchmarkHelper {
+	fn create_signature(_entropy: &[u8], msg: &[u8]) -> (TestSignature, u64) {
+		(TestSignature(1, msg.to_vec()), 1)
+	}
+}
+
+impl pallet_compute_providers::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type WeightInfo = ();
+	type Currency = Balances;
+	type MinimumProviderStake = ConstU128<500>;
+	type MaxCapabilities = ConstU32<3>;
+	type MaxConcurrentJobs = ConstU32<2>;
+	type UnbondingPeriod = ConstU64<10>;
+	type SlashOrigin = EnsureRoot<u64>;
+	type Slash = ();
+	type AttestationVerifier = ();
+	type MaxQuoteLength = ConstU32<64>;
+	type AttestationValidity = ConstU64<20>;
+}
+
+impl pallet_inference::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type WeightInfo = ();
+	type Currency = Balances;
+	type Models = AIRegistry;
+	type MaxRevenueShares = ConstU32<4>;
+	type Providers = ComputeProviders;
+	type MaxQueueLength = ConstU32<3>;
+	type AssignmentTimeout = ConstU64<5>;
+	type CompletionTimeout = ConstU64<10>;
+	type RevealDelay = ConstU64<2>;
+	type RevealWindow = ConstU64<5>;
+	type ProofVerifier = MockVerifier;
+	type MaxProofLength = ConstU32<64>;
+	type PriceFeed = MockPriceFeed;
+	type OnInferenceFee = MockFeeSplit;
+	type Assets = Assets;
+	type PalletId = InferencePalletId;
+	type QuotaPeriod = ConstU64<20>;
+	type MaxCouponLength = ConstU32<16>;
+	type MaxReferralShare = MaxReferralShare;
+	type DemandWindow = ConstU64<10>;
+	type PayoutEraLength = ConstU64<50>;
+	type MaxPayoutsPerBlock = ConstU32<2>;
+	type EarningsCuts = EarningsCuts;
+	type PaymentPerPriority = ConstU128<100>;
+	type MaxPriorityBoost = ConstU64<5>;
+	type MaxCidLength = ConstU32<128>;
+	type AcceptanceWindow = ConstU64<5>;
+	type MaxAutoReleasesPerBlock = ConstU32<2>;
+	type ArbitrationOrigin = EnsureRoot<u64>;
+	type TimeoutRefundReward = TimeoutRefundReward;
+	type MaxBatchSize = ConstU32<3>;
+	type TabSettlementThreshold = TabSettlementThreshold;
+	type MaxTabSettlementsPerBlock = ConstU32<2>;
+	type MeteredSessionTimeout = ConstU64<10>;
+	type Escrow = Escrow;
+	type ChallengeBond = ConstU128<50>;
+	type ChallengePeriod = ConstU64<5>;
+	type ChallengeReward = ChallengeReward;
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkHelper = ();
+}
+
+parameter_types! {
+	pub const InferencePalletId: PalletId = PalletId(*b"inferesc");
+	pub const MaxReferralShare: Perbill = Perbill::from_percent(50);
+	pub const TimeoutRefundReward: Perbill = Perbill::from_percent(10);
+	pub const ChallengeReward: Perbill = Perbill::from_percent(20);
+	pub static ProofRequired: bool = false;
+	/// Zero settles fees as they are charged
+	pub static TabSettlementThreshold: u128 = 0;
+	/// Native tokens per US cent, `None` while the feed is down
+	pub static TokensPerCent: Option<u128> = Some(3);
+	/// Accounts taking a cut of every settled fee, and their shares
+	pub static FeeCuts: Vec<(u64, Perbill)> = Vec::new();
+	/// Accounts taking a cut of paid out earnings, and their shares
+	pub static EarningsCuts: Vec<(u64, Perbill)> = Vec::new();
+}
+
+/// Pays the `FeeCuts` shares of every settled fee
+pub struct MockFeeSplit;
+
+impl pallet_inference::OnInferenceFee<u64, u128> for MockFeeSplit {
+	fn on_inference_fee(
+		_model_id: pallet_ai_registry::ModelId,
+		_payer: &u64,
+		_owner: &u64,
+		_referrer: Option<&u64>,
+		fee: u128,
+	) -> Vec<(u64, u128)> {
+		FeeCuts::get().into_iter().map(|(who, share)| (who, share.mul_floor(fee))).collect()
+	}
+
+	fn weight() -> Weight {
+		Weight::zero()
+	}
+}
+
+/// Converts at the `TokensPerCent` rate
+pub struct MockPriceFeed;
+
+impl pallet_inference::PriceFeed<u128> for MockPriceFeed {
+	fn usd_cents_to_native(usd_cents: u64) -> Option<u128> {
+		TokensPerCent::get().map(|rate| rate * usd_cents as u128)
+	}
+}
+
+/// Accepts a proof equal to the result hash; requires proofs when `ProofRequired` is set
+pub struct MockVerifier;
+
+impl pallet_inference::ProofVerifier for MockVerifier {
+	fn requires_proof(_model_id: pallet_ai_registry::ModelId) -> bool {
+		ProofRequired::get()
+	}
+
+	fn verify(
+		_model_id: pallet_ai_registry::ModelId,
+		_input_hash: &H256,
+		result_hash: &H256,
+		proof: &[u8],
+	) -> bool {
+		proof == result_hash.as_bytes()
+	}
+
+	fn verification_weight(_proof_len: u32) -> Weight {
+		Weight::zero()
+	}
+}
+
+// Build genesis storage according to the mock runtime.
+pub fn new_test_ext() -> sp_io::TestExternalities {
+	let mut t = frame_system::GenesisConfig::<Test>::default().build_storage().unwrap();
+
+	pallet_balances::GenesisConfig::<Test> {
+		balances: vec![(1, 10000), (2, 10000), (3, 10000), (4, 100)],
+		dev_accounts: None,
+	}
+	.assimilate_storage(&mut t)
+	.unwrap();
+
+	let mut ext = sp_io::TestExternalities::new(t);
+	ext.execute_with(|| System::set_block_number(1));
+	ext
+}
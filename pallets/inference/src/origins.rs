@@ -0,0 +1,23 @@
+//! Origin helpers for the Inference pallet
+
+use crate::Origin;
+use frame_support::traits::EnsureOrigin;
+
+/// Ensures the origin is the Inference pallet itself.
+///
+/// Runtimes use this for calls into other pallets that only the pallet settling
+/// inference requests may make, such as recording completed inferences.
+pub struct EnsureInference;
+
+impl<O: Into<Result<Origin, O>> + From<Origin>> EnsureOrigin<O> for EnsureInference {
+	type Success = ();
+
+	fn try_origin(o: O) -> Result<Self::Success, O> {
+		o.into().map(|Origin::Inference| ())
+	}
+
+	#[cfg(feature = "runtime-benchmarks")]
+	fn try_successful_origin() -> Result<O, ()> {
+		Ok(O::from(Origin::Inference))
+	}
+}
@@ -0,0 +1,2076 @@
+//! Unit tests for Inference pallet
+
+use crate::{
+	mock::*,
+	pallet::{
+		AssetPrices, AutoReleases, Coupons, DemandCounters, DemandPricings, Earnings, Error, Event,
+		FeesPaid, InferencesPurchased, LatestReceipt, MeteredSessions, ModelQueue, NextRequestId,
+		PayoutSweep, PromoPrices, QuotaUsage, Quotas, ReferralEarnings, ReferralShares, Referrals,
+		RequestAssets, RequestEscrows, Requests, ResultCids, TabSweep, Tabs, TrialClaims,
+		TrialPools, TrialRequests, UsdPrices,
+	},
+	BatchDiscount, DemandPricing, EnclaveRequirement, FailureReason, RequestState, SweepProgress,
+};
+use frame_support::{assert_noop, assert_ok, traits::Hooks, BoundedVec};
+use pallet_ai_registry::{License, ModelType, RaterStake};
+use pallet_compute_providers::{Attestation, Attestations, SlaRecords, TeeKind};
+use pallet_escrow::Escrow as _;
+use sp_core::H256;
+use sp_runtime::{Perbill, Percent};
+
+const PRICE: u128 = 500;
+const SALT: H256 = H256::repeat_byte(7);
+
+/// CIDv0 of the `n`th test model
+fn cid(n: u8) -> Vec<u8> {
+	let mut cid = b"QmYwAPJzv5CZsnA625s3Xf2nemtYgPpHdWEz79ojWnPbd".to_vec();
+	cid.push(b'A' + n);
+	cid
+}
+
+/// Register a model of `model_type` owned by account 1 and return its ID
+fn register_model(model_type: ModelType) -> u64 {
+	let model_id = pallet_ai_registry::NextModelId::<Test>::get();
+	assert_ok!(AIRegistry::register_model(
+		RuntimeOrigin::signed(1),
+		cid(model_id as u8),
+		format!("Model {model_id}").into_bytes(),
+		b"Description".to_vec(),
+		model_type,
+		PRICE,
+		License::Mit,
+		1_000,
+		vec![]
+	));
+	model_id
+}
+
+/// Register account 3 as a provider of classification models
+fn register_provider() {
+	assert_ok!(ComputeProviders::register_provider(
+		RuntimeOrigin::signed(3),
+		500,
+		vec![ModelType::Classification]
+	));
+}
+
+/// Model 0 and provider 3, with account 2 requesting an inference on the model
+fn setup_request() {
+	register_model(ModelType::Classification);
+	register_provider();
+	assert_ok!(Inference::request_inference(
+		RuntimeOrigin::signed(2),
+		0,
+		H256::repeat_byte(1),
+		PRICE
+	));
+}
+
+/// Take the request from `setup_request` through to `Completed` with `result_hash`
+fn complete_request(result_hash: H256) {
+	assert_ok!(Inference::accept_request(RuntimeOrigin::signed(3), 0));
+	let commitment = Inference::result_commitment(&result_hash, &SALT);
+	assert_ok!(Inference::commit_result(RuntimeOrigin::signed(3), 0, commitment));
+	System::set_block_number(System::block_number() + 2);
+	assert_ok!(Inference::reveal_result(RuntimeOrigin::signed(3), 0, result_hash, SALT));
+}
+
+#[test]
+fn request_inference_escrows_price() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			Inference::request_inference(RuntimeOrigin::signed(2), 0, H256::zero(), PRICE),
+			Error::<Test>::ModelNotFound
+		);
+		register_model(ModelType::Classification);
+		assert_noop!(
+			Inference::request_inference(RuntimeOrigin::signed(2), 0, H256::zero(), PRICE - 1),
+			Error::<Test>::PriceAboveLimit
+		);
+		assert_noop!(
+			Inference::request_inference(RuntimeOrigin::signed(4), 0, H256::zero(), PRICE),
+			Error::<Test>::InsufficientBalance
+		);
+
+		assert_ok!(Inference::request_inference(
+			RuntimeOrigin::signed(2),
+			0,
+			H256::repeat_byte(1),
+			PRICE
+		));
+		System::assert_last_event(
+			Event::InferenceRequested {
+				request_id: 0,
+				model_id: 0,
+				requester: 2,
+				price: PRICE,
+				asset: None,
+			}
+			.into(),
+		);
+
+		let request = Requests::<Test>::get(0).unwrap();
+		assert_eq!(request.state, RequestState::Requested);
+		assert_eq!(request.input_hash, H256::repeat_byte(1));
+		assert_eq!(request.deadline, 6);
+		assert_eq!(ModelQueue::<Test>::get(0).to_vec(), vec![0]);
+		assert_eq!(NextRequestId::<Test>::get(), 1);
+		assert_eq!(Balances::reserved_balance(2), PRICE);
+	});
+}
+
+#[test]
+fn request_inference_rejects_inactive_model_and_full_queue() {
+	new_test_ext().execute_with(|| {
+		register_model(ModelType::Classification);
+		for _ in 0..3 {
+			assert_ok!(Inference::request_inference(
+				RuntimeOrigin::signed(2),
+				0,
+				H256::zero(),
+				PRICE
+			));
+		}
+		assert_noop!(
+			Inference::request_inference(RuntimeOrigin::signed(2), 0, H256::zero(), PRICE),
+			Error::<Test>::QueueFull
+		);
+
+		assert_ok!(AIRegistry::deactivate_model(RuntimeOrigin::signed(1), 0, None));
+		assert_noop!(
+			Inference::request_inference(RuntimeOrigin::signed(2), 0, H256::zero(), PRICE),
+			Error::<Test>::ModelNotActive
+		);
+	});
+}
+
+#[test]
+fn request_lifecycle_settles_to_owner() {
+	new_test_ext().execute_with(|| {
+		setup_request();
+		let owner_free = Balances::free_balance(1);
+
+		assert_ok!(Inference::accept_request(RuntimeOrigin::signed(3), 0));
+		System::assert_last_event(
+			Event::RequestAssigned { request_id: 0, provider: 3, deadline: 11 }.into(),
+		);
+		let request = Requests::<Test>::get(0).unwrap();
+		assert_eq!(request.state, RequestState::Assigned);
+		assert_eq!(request.provider, Some(3));
+		assert_eq!(pallet_compute_providers::Providers::<Test>::get(3).unwrap().active_jobs, 1);
+
+		let commitment = Inference::result_commitment(&H256::repeat_byte(9), &SALT);
+		assert_ok!(Inference::commit_result(RuntimeOrigin::signed(3), 0, commitment));
+		System::assert_last_event(
+			Event::ResultCommitted { request_id: 0, commitment, reveal_from: 3, reveal_until: 8 }
+				.into(),
+		);
+		assert_eq!(Requests::<Test>::get(0).unwrap().state, RequestState::Committed);
+		assert_eq!(pallet_compute_providers::Providers::<Test>::get(3).unwrap().active_jobs, 0);
+
+		System::set_block_number(3);
+		assert_ok!(Inference::reveal_result(
+			RuntimeOrigin::signed(3),
+			0,
+			H256::repeat_byte(9),
+			SALT
+		));
+		let request = Requests::<Test>::get(0).unwrap();
+		assert_eq!(request.state, RequestState::Completed);
+		assert_eq!(request.result_hash, Some(H256::repeat_byte(9)));
+		assert!(ModelQueue::<Test>::get(0).is_empty());
+		assert_eq!(pallet_ai_registry::Models::<Test>::get(0).unwrap().total_inferences, 1);
+
+		assert_noop!(
+			Inference::settle_request(RuntimeOrigin::signed(3), 0),
+			Error::<Test>::NotRequester
+		);
+		assert_ok!(Inference::settle_request(RuntimeOrigin::signed(2), 0));
+		System::assert_last_event(
+			Event::RequestSettled { request_id: 0, owner: 1, amount: PRICE }.into(),
+		);
+		assert_eq!(Requests::<Test>::get(0).unwrap().state, RequestState::Settled);
+		assert_eq!(Balances::reserved_balance(2), 0);
+		assert_eq!(Balances::free_balance(2), 10_000 - PRICE);
+		// The fee waits in the model's earnings until claimed
+		assert_eq!(Balances::free_balance(1), owner_free);
+		assert_eq!(Earnings::<Test>::get(0), PRICE);
+		assert_ok!(Inference::claim_earnings(RuntimeOrigin::signed(1), 0));
+		System::assert_last_event(Event::EarningsClaimed { model_id: 0, amount: PRICE }.into());
+		assert_eq!(Balances::free_balance(1), owner_free + PRICE);
+		assert_eq!(Earnings::<Test>::get(0), 0);
+		assert_eq!(LatestReceipt::<Test>::get(2, 0), Some(0));
+		let stats = pallet_ai_registry::GlobalStats::<Test>::get();
+		assert_eq!((stats.total_inferences, stats.fee_volume), (1, PRICE));
+
+		// The settled request backs the requester's rating
+		assert_ok!(AIRegistry::rate_model(RuntimeOrigin::signed(2), 0, 5, None));
+		assert_eq!(AIRegistry::rating_provenance(0, &2).unwrap().receipt, Some(0));
+
+		assert_noop!(
+			Inference::settle_request(RuntimeOrigin::signed(2), 0),
+			Error::<Test>::InvalidState
+		);
+	});
+}
+
+#[test]
+fn settled_requests_count_as_purchased_inferences() {
+	new_test_ext().execute_with(|| {
+		setup_request();
+		complete_request(H256::repeat_byte(9));
+		assert_eq!(InferencesPurchased::<Test>::get(2, 0), 0);
+		assert_ok!(Inference::settle_request(RuntimeOrigin::signed(2), 0));
+		assert_eq!(InferencesPurchased::<Test>::get(2, 0), 1);
+		// What the requester paid is their stake in the model's ratings
+		assert_eq!(FeesPaid::<Test>::get(2, 0), PRICE);
+		assert_eq!(<Inference as RaterStake<u64>>::stake_of(&2, 0), PRICE);
+
+		// Cancelled requests are not purchases
+		assert_ok!(Inference::request_inference(
+			RuntimeOrigin::signed(2),
+			0,
+			H256::repeat_byte(1),
+			PRICE
+		));
+		assert_ok!(Inference::cancel_request(RuntimeOrigin::signed(2), 1));
+		assert_eq!(InferencesPurchased::<Test>::get(2, 0), 1);
+		assert_eq!(InferencesPurchased::<Test>::get(1, 0), 0);
+		assert_eq!(FeesPaid::<Test>::get(2, 0), PRICE);
+	});
+}
+
+#[test]
+fn accept_request_checks_provider_and_deadline() {
+	new_test_ext().execute_with(|| {
+		setup_request();
+		register_model(ModelType::Generative);
+		assert_ok!(Inference::request_inference(RuntimeOrigin::signed(2), 1, H256::zero(), PRICE));
+
+		assert_noop!(
+			Inference::accept_request(RuntimeOrigin::signed(2), 0),
+			pallet_compute_providers::Error::<Test>::ProviderNotFound
+		);
+		assert_noop!(
+			Inference::accept_request(RuntimeOrigin::signed(3), 1),
+			pallet_compute_providers::Error::<Test>::UnsupportedModelType
+		);
+		assert_noop!(
+			Inference::accept_request(RuntimeOrigin::signed(3), 9),
+			Error::<Test>::RequestNotFound
+		);
+
+		System::set_block_number(7);
+		assert_noop!(
+			Inference::accept_request(RuntimeOrigin::signed(3), 0),
+			Error::<Test>::DeadlinePassed
+		);
+	});
+}
+
+#[test]
+fn commit_result_checks_provider_and_deadline() {
+	new_test_ext().execute_with(|| {
+		setup_request();
+		assert_noop!(
+			Inference::commit_result(RuntimeOrigin::signed(3), 0, H256::zero()),
+			Error::<Test>::InvalidState
+		);
+		assert_ok!(Inference::accept_request(RuntimeOrigin::signed(3), 0));
+		assert_noop!(
+			Inference::accept_request(RuntimeOrigin::signed(3), 0),
+			Error::<Test>::InvalidState
+		);
+
+		assert_noop!(
+			Inference::commit_result(RuntimeOrigin::signed(2), 0, H256::zero()),
+			Error::<Test>::NotAssignedProvider
+		);
+
+		System::set_block_number(12);
+		assert_noop!(
+			Inference::commit_result(RuntimeOrigin::signed(3), 0, H256::zero()),
+			Error::<Test>::DeadlinePassed
+		);
+	});
+}
+
+#[test]
+fn reveal_result_respects_delay_and_window() {
+	new_test_ext().execute_with(|| {
+		setup_request();
+		assert_ok!(Inference::accept_request(RuntimeOrigin::signed(3), 0));
+		assert_noop!(
+			Inference::reveal_result(RuntimeOrigin::signed(3), 0, H256::repeat_byte(9), SALT),
+			Error::<Test>::InvalidState
+		);
+		let commitment = Inference::result_commitment(&H256::repeat_byte(9), &SALT);
+		assert_ok!(Inference::commit_result(RuntimeOrigin::signed(3), 0, commitment));
+
+		assert_noop!(
+			Inference::reveal_result(RuntimeOrigin::signed(3), 0, H256::repeat_byte(9), SALT),
+			Error::<Test>::RevealTooEarly
+		);
+		System::set_block_number(3);
+		assert_noop!(
+			Inference::reveal_result(RuntimeOrigin::signed(2), 0, H256::repeat_byte(9), SALT),
+			Error::<Test>::NotAssignedProvider
+		);
+		System::set_block_number(9);
+		assert_noop!(
+			Inference::reveal_result(RuntimeOrigin::signed(3), 0, H256::repeat_byte(9), SALT),
+			Error::<Test>::DeadlinePassed
+		);
+	});
+}
+
+#[test]
+fn mismatched_reveal_refunds_buyer() {
+	new_test_ext().execute_with(|| {
+		setup_request();
+		assert_ok!(Inference::accept_request(RuntimeOrigin::signed(3), 0));
+		let commitment = Inference::result_commitment(&H256::repeat_byte(9), &SALT);
+		assert_ok!(Inference::commit_result(RuntimeOrigin::signed(3), 0, commitment));
+
+		System::set_block_number(3);
+		assert_ok!(Inference::reveal_result(
+			RuntimeOrigin::signed(3),
+			0,
+			H256::repeat_byte(8),
+			SALT
+		));
+		System::assert_last_event(
+			Event::RequestFailed { request_id: 0, reason: FailureReason::InvalidReveal }.into(),
+		);
+		let request = Requests::<Test>::get(0).unwrap();
+		assert_eq!(request.state, RequestState::Failed);
+		assert_eq!(request.result_hash, None);
+		assert!(ModelQueue::<Test>::get(0).is_empty());
+		assert_eq!(Balances::reserved_balance(2), 0);
+		assert_eq!(Balances::free_balance(2), 10_000);
+		assert_eq!(pallet_ai_registry::Models::<Test>::get(0).unwrap().total_inferences, 0);
+	});
+}
+
+#[test]
+fn missing_reveal_expires_request() {
+	new_test_ext().execute_with(|| {
+		setup_request();
+		assert_ok!(Inference::accept_request(RuntimeOrigin::signed(3), 0));
+		let commitment = Inference::result_commitment(&H256::repeat_byte(9), &SALT);
+		assert_ok!(Inference::commit_result(RuntimeOrigin::signed(3), 0, commitment));
+
+		System::set_block_number(8);
+		assert_noop!(
+			Inference::expire_request(RuntimeOrigin::signed(4), 0),
+			Error::<Test>::DeadlineNotPassed
+		);
+
+		System::set_block_number(9);
+		assert_ok!(Inference::expire_request(RuntimeOrigin::signed(4), 0));
+		System::assert_last_event(
+			Event::RequestFailed { request_id: 0, reason: FailureReason::MissingReveal }.into(),
+		);
+		assert_eq!(Balances::reserved_balance(2), 0);
+		// The job slot was already released on commit
+		assert_eq!(pallet_compute_providers::Providers::<Test>::get(3).unwrap().active_jobs, 0);
+	});
+}
+
+#[test]
+fn submit_result_records_output_cid() {
+	new_test_ext().execute_with(|| {
+		setup_request();
+		let output_cid: BoundedVec<u8, _> = cid(9).try_into().unwrap();
+		assert_noop!(
+			Inference::submit_result(RuntimeOrigin::signed(3), 0, output_cid.clone(), H256::zero()),
+			Error::<Test>::InvalidState
+		);
+		assert_ok!(Inference::accept_request(RuntimeOrigin::signed(3), 0));
+		assert_noop!(
+			Inference::submit_result(RuntimeOrigin::signed(2), 0, output_cid.clone(), H256::zero()),
+			Error::<Test>::NotResultSubmitter
+		);
+		assert_noop!(
+			Inference::submit_result(
+				RuntimeOrigin::signed(3),
+				0,
+				b"not a cid".to_vec().try_into().unwrap(),
+				H256::zero()
+			),
+			Error::<Test>::InvalidOutputCid
+		);
+
+		assert_ok!(Inference::submit_result(
+			RuntimeOrigin::signed(3),
+			0,
+			output_cid.clone(),
+			H256::repeat_byte(9)
+		));
+		System::assert_last_event(
+			Event::ResultSubmitted {
+				request_id: 0,
+				output_cid: output_cid.clone(),
+				result_hash: H256::repeat_byte(9),
+			}
+			.into(),
+		);
+		let request = Requests::<Test>::get(0).unwrap();
+		assert_eq!(request.state, RequestState::Completed);
+		assert_eq!(request.result_hash, Some(H256::repeat_byte(9)));
+		assert_eq!(ResultCids::<Test>::get(0), Some(output_cid.clone()));
+		assert!(ModelQueue::<Test>::get(0).is_empty());
+		assert_eq!(pallet_compute_providers::Providers::<Test>::get(3).unwrap().active_jobs, 0);
+		assert_ok!(Inference::settle_request(RuntimeOrigin::signed(2), 0));
+
+		// The model owner can deliver results too, but not after the deadline
+		assert_ok!(Inference::request_inference(RuntimeOrigin::signed(2), 0, H256::zero(), PRICE));
+		assert_ok!(Inference::accept_request(RuntimeOrigin::signed(3), 1));
+		assert_ok!(Inference::submit_result(
+			RuntimeOrigin::signed(1),
+			1,
+			output_cid.clone(),
+			H256::zero()
+		));
+		assert_ok!(Inference::request_inference(RuntimeOrigin::signed(2), 0, H256::zero(), PRICE));
+		assert_ok!(Inference::accept_request(RuntimeOrigin::signed(3), 2));
+		System::set_block_number(12);
+		assert_noop!(
+			Inference::submit_result(RuntimeOrigin::signed(3), 2, output_cid, H256::zero()),
+			Error::<Test>::DeadlinePassed
+		);
+	});
+}
+
+#[test]
+fn provider_sla_records_job_outcomes() {
+	new_test_ext().execute_with(|| {
+		setup_request();
+		complete_request(H256::repeat_byte(9));
+
+		// Invalid reveal
+		assert_ok!(Inference::request_inference(RuntimeOrigin::signed(2), 0, H256::zero(), PRICE));
+		assert_ok!(Inference::accept_request(RuntimeOrigin::signed(3), 1));
+		let commitment = Inference::result_commitment(&H256::repeat_byte(9), &SALT);
+		assert_ok!(Inference::commit_result(RuntimeOrigin::signed(3), 1, commitment));
+		System::set_block_number(System::block_number() + 2);
+		assert_ok!(Inference::reveal_result(RuntimeOrigin::signed(3), 1, H256::zero(), SALT));
+
+		// Completion timeout
+		assert_ok!(Inference::request_inference(RuntimeOrigin::signed(2), 0, H256::zero(), PRICE));
+		assert_ok!(Inference::accept_request(RuntimeOrigin::signed(3), 2));
+		System::set_block_number(System::block_number() + 11);
+		assert_ok!(Inference::expire_request(RuntimeOrigin::signed(4), 2));
+
+		// Not the provider's fault
+		assert_ok!(Inference::request_inference(RuntimeOrigin::signed(2), 0, H256::zero(), PRICE));
+		assert_ok!(Inference::cancel_request(RuntimeOrigin::signed(2), 3));
+
+		let stats = SlaRecords::<Test>::get(3);
+		assert_eq!((stats.completed, stats.failed, stats.timed_out), (1, 1, 1));
+		assert_eq!(stats.success_ratio(), Some(Perbill::from_rational(1u32, 3)));
+	});
+}
+
+#[test]
+fn cancel_request_refunds_requester() {
+	new_test_ext().execute_with(|| {
+		setup_request();
+
+		assert_noop!(
+			Inference::cancel_request(RuntimeOrigin::signed(3), 0),
+			Error::<Test>::NotRequester
+		);
+		assert_ok!(Inference::cancel_request(RuntimeOrigin::signed(2), 0));
+		System::assert_last_event(
+			Event::RequestFailed { request_id: 0, reason: FailureReason::Cancelled }.into(),
+		);
+		assert_eq!(Requests::<Test>::get(0).unwrap().state, RequestState::Failed);
+		assert!(ModelQueue::<Test>::get(0).is_empty());
+		assert_eq!(Balances::reserved_balance(2), 0);
+		assert_eq!(Balances::free_balance(2), 10_000);
+
+		assert_noop!(
+			Inference::cancel_request(RuntimeOrigin::signed(2), 0),
+			Error::<Test>::InvalidState
+		);
+	});
+}
+
+#[test]
+fn expire_request_after_assignment_timeout() {
+	new_test_ext().execute_with(|| {
+		setup_request();
+
+		System::set_block_number(6);
+		assert_noop!(
+			Inference::expire_request(RuntimeOrigin::signed(4), 0),
+			Error::<Test>::DeadlineNotPassed
+		);
+
+		System::set_block_number(7);
+		assert_ok!(Inference::expire_request(RuntimeOrigin::signed(4), 0));
+		System::assert_last_event(
+			Event::RequestFailed { request_id: 0, reason: FailureReason::AssignmentTimeout }.into(),
+		);
+		assert_eq!(Requests::<Test>::get(0).unwrap().state, RequestState::Failed);
+		assert_eq!(Balances::reserved_balance(2), 0);
+	});
+}
+
+#[test]
+fn expire_request_after_completion_timeout_frees_provider() {
+	new_test_ext().execute_with(|| {
+		setup_request();
+		assert_ok!(Inference::accept_request(RuntimeOrigin::signed(3), 0));
+
+		System::set_block_number(12);
+		assert_ok!(Inference::expire_request(RuntimeOrigin::signed(4), 0));
+		System::assert_last_event(
+			Event::RequestFailed { request_id: 0, reason: FailureReason::CompletionTimeout }.into(),
+		);
+		assert_eq!(pallet_compute_providers::Providers::<Test>::get(3).unwrap().active_jobs, 0);
+		assert!(ModelQueue::<Test>::get(0).is_empty());
+		assert_eq!(Balances::reserved_balance(2), 0);
+
+		assert_noop!(
+			Inference::expire_request(RuntimeOrigin::signed(4), 0),
+			Error::<Test>::InvalidState
+		);
+	});
+}
+
+#[test]
+fn payments_release_after_the_acceptance_window() {
+	new_test_ext().execute_with(|| {
+		setup_request();
+		complete_request(H256::repeat_byte(9));
+		assert_eq!(Requests::<Test>::get(0).unwrap().deadline, 8);
+		assert_eq!(AutoReleases::<Test>::get(9).into_inner(), vec![0]);
+
+		Inference::on_initialize(8);
+		assert_eq!(Requests::<Test>::get(0).unwrap().state, RequestState::Completed);
+		Inference::on_initialize(9);
+		assert_eq!(Requests::<Test>::get(0).unwrap().state, RequestState::Settled);
+		assert_eq!(Earnings::<Test>::get(0), PRICE);
+		assert_eq!(Balances::reserved_balance(2), 0);
+		assert!(!AutoReleases::<Test>::contains_key(9));
+
+		// Accepting early takes the request off the schedule
+		assert_ok!(Inference::request_inference(RuntimeOrigin::signed(2), 0, H256::zero(), PRICE));
+		assert_ok!(Inference::accept_request(RuntimeOrigin::signed(3), 1));
+		let output_cid: BoundedVec<u8, _> = cid(9).try_into().unwrap();
+		assert_ok!(Inference::submit_result(
+			RuntimeOrigin::signed(3),
+			1,
+			output_cid.clone(),
+			H256::zero()
+		));
+		assert_ok!(Inference::settle_request(RuntimeOrigin::signed(2), 1));
+		assert!(!AutoReleases::<Test>::contains_key(9));
+
+		// Only `MaxAutoReleasesPerBlock` requests can complete per block
+		for request_id in 2..5 {
+			assert_ok!(Inference::request_inference(
+				RuntimeOrigin::signed(2),
+				0,
+				H256::zero(),
+				PRICE
+			));
+			assert_ok!(Inference::accept_request(RuntimeOrigin::signed(3), request_id));
+			let submit = || {
+				Inference::submit_result(
+					RuntimeOrigin::signed(3),
+					request_id,
+					output_cid.clone(),
+					H256::zero(),
+				)
+			};
+			if request_id < 4 {
+				assert_ok!(submit());
+			} else {
+				assert_noop!(submit(), Error::<Test>::TooManyAutoReleases);
+			}
+		}
+	});
+}
+
+#[test]
+fn disputed_results_wait_for_the_disputes_pallet() {
+	use pallet_disputes::{InferencePayments, PaymentInfo};
+
+	new_test_ext().execute_with(|| {
+		setup_request();
+		assert_eq!(<Inference as InferencePayments<_, _>>::payment(0), None);
+		complete_request(H256::repeat_byte(9));
+		assert_eq!(
+			<Inference as InferencePayments<_, _>>::payment(0),
+			Some(PaymentInfo { payer: 2, model_id: 0, amount: PRICE })
+		);
+		assert_noop!(
+			<Inference as InferencePayments<_, _>>::refund(0, PRICE),
+			Error::<Test>::InvalidState
+		);
+
+		assert_ok!(<Inference as InferencePayments<_, _>>::hold(0));
+		System::assert_last_event(Event::ResultDisputed { request_id: 0 }.into());
+		assert_eq!(Requests::<Test>::get(0).unwrap().state, RequestState::Disputed);
+		assert!(!AutoReleases::<Test>::contains_key(9));
+		assert_noop!(
+			Inference::settle_request(RuntimeOrigin::signed(2), 0),
+			Error::<Test>::InvalidState
+		);
+
+		assert_ok!(<Inference as InferencePayments<_, _>>::refund(0, PRICE));
+		assert_eq!(Requests::<Test>::get(0).unwrap().state, RequestState::Failed);
+		assert_eq!(Balances::reserved_balance(2), 0);
+		assert_eq!(Balances::free_balance(2), 10_000);
+		assert_eq!(<Inference as InferencePayments<_, _>>::payment(0), None);
+
+		// The model is paid what a ruling does not refund
+		assert_ok!(Inference::request_inference(RuntimeOrigin::signed(2), 0, H256::zero(), PRICE));
+		assert_ok!(Inference::accept_request(RuntimeOrigin::signed(3), 1));
+		let output_cid: BoundedVec<u8, _> = cid(9).try_into().unwrap();
+		assert_ok!(Inference::submit_result(
+			RuntimeOrigin::signed(3),
+			1,
+			output_cid.clone(),
+			H256::zero()
+		));
+		assert_ok!(<Inference as InferencePayments<_, _>>::hold(1));
+		assert_ok!(<Inference as InferencePayments<_, _>>::refund(1, 200));
+		assert_eq!(Requests::<Test>::get(1).unwrap().state, RequestState::Settled);
+		assert_eq!(Balances::free_balance(2), 10_000 - 300);
+		assert_eq!(Earnings::<Test>::get(0), 300);
+		assert_eq!(FeesPaid::<Test>::get(2, 0), 300);
+
+		// A model reaped while the dispute is open cannot be paid, so the buyer gets
+		// everything back and the ruling still closes the dispute
+		assert_ok!(Inference::request_inference(RuntimeOrigin::signed(2), 0, H256::zero(), PRICE));
+		assert_ok!(Inference::accept_request(RuntimeOrigin::signed(3), 2));
+		assert_ok!(Inference::submit_result(
+			RuntimeOrigin::signed(3),
+			2,
+			output_cid.clone(),
+			H256::zero()
+		));
+		assert_ok!(<Inference as InferencePayments<_, _>>::hold(2));
+		let model = pallet_ai_registry::Models::<Test>::take(0).unwrap();
+		assert_ok!(<Inference as InferencePayments<_, _>>::refund(2, 200));
+		System::assert_last_event(
+			Event::RequestFailed { request_id: 2, reason: FailureReason::Unsettleable }.into(),
+		);
+		assert_eq!(Requests::<Test>::get(2).unwrap().state, RequestState::Failed);
+		assert_eq!(Balances::free_balance(2), 10_000 - 300);
+		assert_eq!(Balances::reserved_balance(2), 0);
+		assert!(!RequestEscrows::<Test>::contains_key(2));
+		pallet_ai_registry::Models::<Test>::insert(0, model);
+
+		// Results can only be disputed within the acceptance window
+		assert_ok!(Inference::request_inference(RuntimeOrigin::signed(2), 0, H256::zero(), PRICE));
+		assert_ok!(Inference::accept_request(RuntimeOrigin::signed(3), 3));
+		assert_ok!(Inference::submit_result(RuntimeOrigin::signed(3), 3, output_cid, H256::zero()));
+		System::set_block_number(System::block_number() + 6);
+		assert_eq!(<Inference as InferencePayments<_, _>>::payment(3), None);
+		assert_noop!(
+			<Inference as InferencePayments<_, _>>::hold(3),
+			Error::<Test>::DeadlinePassed
+		);
+	});
+}
+
+#[test]
+fn conceded_challenges_refund_the_buyer_and_reward_the_challenger() {
+	new_test_ext().execute_with(|| {
+		setup_request();
+		complete_request(H256::repeat_byte(9));
+		for who in [1, 2, 3] {
+			assert_noop!(
+				Inference::challenge_result(RuntimeOrigin::signed(who), 0, H256::repeat_byte(8)),
+				Error::<Test>::CannotChallenge
+			);
+		}
+		assert_noop!(
+			Inference::challenge_result(RuntimeOrigin::signed(4), 0, H256::repeat_byte(9)),
+			Error::<Test>::SameResult
+		);
+		assert_noop!(
+			Inference::challenge_result(RuntimeOrigin::signed(5), 0, H256::repeat_byte(8)),
+			Error::<Test>::InsufficientBond
+		);
+
+		assert_ok!(Inference::challenge_result(RuntimeOrigin::signed(4), 0, H256::repeat_byte(8)));
+		System::assert_last_event(
+			Event::ResultChallenged {
+				request_id: 0,
+				challenger: 4,
+				counter_hash: H256::repeat_byte(8),
+			}
+			.into(),
+		);
+		assert_eq!(Requests::<Test>::get(0).unwrap().state, RequestState::Challenged);
+		assert!(!AutoReleases::<Test>::contains_key(9));
+		assert_eq!(Balances::reserved_balance(4), 50);
+		assert_noop!(
+			Inference::settle_request(RuntimeOrigin::signed(2), 0),
+			Error::<Test>::InvalidState
+		);
+
+		assert_noop!(
+			Inference::concede_challenge(RuntimeOrigin::signed(4), 0),
+			Error::<Test>::NotResultSubmitter
+		);
+		assert_noop!(
+			Inference::resolve_challenge(RuntimeOrigin::root(), 0, true),
+			Error::<Test>::ChallengeNotEscalated
+		);
+		assert_ok!(Inference::concede_challenge(RuntimeOrigin::signed(3), 0));
+		System::assert_has_event(
+			Event::RequestFailed { request_id: 0, reason: FailureReason::ChallengeUpheld }.into(),
+		);
+		System::assert_last_event(Event::ChallengeResolved { request_id: 0, upheld: true }.into());
+		assert_eq!(Requests::<Test>::get(0).unwrap().state, RequestState::Failed);
+		assert_eq!(SlaRecords::<Test>::get(3).failed, 1);
+
+		// A fifth of the payment is slashed from the provider's stake to the challenger,
+		// who gets their bond back, and the buyer is refunded in full
+		System::assert_has_event(
+			pallet_compute_providers::Event::ProviderSlashed {
+				provider: 3,
+				amount: 100,
+				stake: 400,
+			}
+			.into(),
+		);
+		assert_eq!(Balances::reserved_balance(3), 400);
+		assert_eq!(Balances::reserved_balance(4), 0);
+		assert_eq!(Balances::free_balance(4), 100 + 100);
+		assert_eq!(Balances::reserved_balance(2), 0);
+		assert_eq!(Balances::free_balance(2), 10_000);
+	});
+}
+
+#[test]
+fn rejected_challenges_forfeit_the_bond_to_the_provider() {
+	new_test_ext().execute_with(|| {
+		setup_request();
+		complete_request(H256::repeat_byte(9));
+		assert_ok!(Inference::challenge_result(RuntimeOrigin::signed(4), 0, H256::repeat_byte(8)));
+
+		// Unconceded challenges escalate to arbitration once the period is over
+		System::set_block_number(System::block_number() + 5);
+		assert_noop!(
+			Inference::resolve_challenge(RuntimeOrigin::signed(2), 0, false),
+			sp_runtime::DispatchError::BadOrigin
+		);
+		let provider_balance = Balances::free_balance(3);
+		assert_ok!(Inference::resolve_challenge(RuntimeOrigin::root(), 0, false));
+		System::assert_last_event(Event::ChallengeResolved { request_id: 0, upheld: false }.into());
+		assert_eq!(Requests::<Test>::get(0).unwrap().state, RequestState::Settled);
+		assert_eq!(Earnings::<Test>::get(0), PRICE);
+		assert_eq!(Balances::free_balance(3), provider_balance + 50);
+		assert_eq!(Balances::free_balance(4), 50);
+		assert_eq!(Balances::reserved_balance(4), 0);
+
+		// Results can only be challenged within the acceptance window
+		assert_ok!(Inference::request_inference(RuntimeOrigin::signed(2), 0, H256::zero(), PRICE));
+		assert_ok!(Inference::accept_request(RuntimeOrigin::signed(3), 1));
+		let output_cid: BoundedVec<u8, _> = cid(9).try_into().unwrap();
+		assert_ok!(Inference::submit_result(RuntimeOrigin::signed(3), 1, output_cid, H256::zero()));
+		System::set_block_number(System::block_number() + 6);
+		assert_noop!(
+			Inference::challenge_result(RuntimeOrigin::signed(4), 1, H256::repeat_byte(8)),
+			Error::<Test>::DeadlinePassed
+		);
+	});
+}
+
+#[test]
+fn requests_past_the_response_deadline_are_refunded() {
+	new_test_ext().execute_with(|| {
+		setup_request();
+		assert_noop!(
+			Inference::refund_timed_out(RuntimeOrigin::signed(4), 0),
+			Error::<Test>::NoResponseDeadline
+		);
+		assert_noop!(
+			Inference::set_response_deadline(RuntimeOrigin::signed(2), 0, Some(4)),
+			Error::<Test>::NotModelOwner
+		);
+		assert_ok!(Inference::set_response_deadline(RuntimeOrigin::signed(1), 0, Some(4)));
+		System::assert_last_event(
+			Event::ResponseDeadlineSet { model_id: 0, blocks: Some(4) }.into(),
+		);
+		assert_ok!(Inference::accept_request(RuntimeOrigin::signed(3), 0));
+
+		System::set_block_number(5);
+		assert_noop!(
+			Inference::refund_timed_out(RuntimeOrigin::signed(4), 0),
+			Error::<Test>::ResponseDeadlineNotPassed
+		);
+		System::set_block_number(6);
+		assert_ok!(Inference::refund_timed_out(RuntimeOrigin::signed(4), 0));
+
+		// The caller's reward comes out of the provider's stake, not the refund
+		System::assert_has_event(
+			pallet_compute_providers::Event::ProviderSlashed {
+				provider: 3,
+				amount: 50,
+				stake: 450,
+			}
+			.into(),
+		);
+		System::assert_has_event(
+			Event::TimedOutRequestRefunded { request_id: 0, caller: 4, reward: 50 }.into(),
+		);
+		System::assert_last_event(
+			Event::RequestFailed { request_id: 0, reason: FailureReason::ResponseTimeout }.into(),
+		);
+		assert_eq!(Balances::free_balance(4), 150);
+		assert_eq!(Balances::reserved_balance(3), 450);
+		assert_eq!(Balances::reserved_balance(2), 0);
+		assert_eq!(Balances::free_balance(2), 10_000);
+		assert_eq!(pallet_compute_providers::Providers::<Test>::get(3).unwrap().active_jobs, 0);
+		assert_eq!(SlaRecords::<Test>::get(3).timed_out, 1);
+		assert_noop!(
+			Inference::refund_timed_out(RuntimeOrigin::signed(4), 0),
+			Error::<Test>::InvalidState
+		);
+	});
+}
+
+#[test]
+fn proof_required_before_settlement() {
+	new_test_ext().execute_with(|| {
+		ProofRequired::set(true);
+		setup_request();
+		let result_hash = H256::repeat_byte(9);
+		let proof = |bytes: &[u8]| BoundedVec::try_from(bytes.to_vec()).unwrap();
+
+		assert_noop!(
+			Inference::submit_inference_proof(
+				RuntimeOrigin::signed(4),
+				0,
+				proof(result_hash.as_bytes())
+			),
+			Error::<Test>::InvalidState
+		);
+		complete_request(result_hash);
+
+		assert_noop!(
+			Inference::settle_request(RuntimeOrigin::signed(2), 0),
+			Error::<Test>::ProofRequired
+		);
+		assert_noop!(
+			Inference::submit_inference_proof(RuntimeOrigin::signed(4), 0, proof(b"bogus")),
+			Error::<Test>::InvalidProof
+		);
+
+		assert_ok!(Inference::submit_inference_proof(
+			RuntimeOrigin::signed(4),
+			0,
+			proof(result_hash.as_bytes())
+		));
+		System::assert_last_event(Event::InferenceProofVerified { request_id: 0 }.into());
+		assert!(Requests::<Test>::get(0).unwrap().proof_verified);
+		assert_noop!(
+			Inference::submit_inference_proof(
+				RuntimeOrigin::signed(4),
+				0,
+				proof(result_hash.as_bytes())
+			),
+			Error::<Test>::ProofAlreadyVerified
+		);
+
+		assert_ok!(Inference::settle_request(RuntimeOrigin::signed(2), 0));
+
+		// Payments that still cannot be released when the acceptance window ends are
+		// refunded instead of staying escrowed
+		assert_ok!(Inference::request_inference(RuntimeOrigin::signed(2), 0, H256::zero(), PRICE));
+		assert_ok!(Inference::accept_request(RuntimeOrigin::signed(3), 1));
+		let output_cid: BoundedVec<u8, _> = cid(9).try_into().unwrap();
+		assert_ok!(Inference::submit_result(RuntimeOrigin::signed(3), 1, output_cid, result_hash));
+		let deadline = Requests::<Test>::get(1).unwrap().deadline;
+		Inference::on_initialize(deadline + 1);
+		System::assert_last_event(
+			Event::RequestFailed { request_id: 1, reason: FailureReason::Unsettleable }.into(),
+		);
+		assert_eq!(Requests::<Test>::get(1).unwrap().state, RequestState::Failed);
+		assert_eq!(Balances::free_balance(2), 10_000 - PRICE);
+		assert_eq!(Balances::reserved_balance(2), 0);
+	});
+}
+
+#[test]
+fn proof_optional_when_not_required() {
+	new_test_ext().execute_with(|| {
+		setup_request();
+		complete_request(H256::repeat_byte(9));
+
+		assert_ok!(Inference::settle_request(RuntimeOrigin::signed(2), 0));
+		assert!(!Requests::<Test>::get(0).unwrap().proof_verified);
+	});
+}
+
+#[test]
+fn enclave_requirement_restricts_providers() {
+	new_test_ext().execute_with(|| {
+		setup_request();
+		let measurement = H256::repeat_byte(5);
+
+		assert_noop!(
+			Inference::set_enclave_requirement(
+				RuntimeOrigin::signed(2),
+				0,
+				Some(EnclaveRequirement::AnyEnclave)
+			),
+			Error::<Test>::NotModelOwner
+		);
+		assert_ok!(Inference::set_enclave_requirement(
+			RuntimeOrigin::signed(1),
+			0,
+			Some(EnclaveRequirement::Measurement(measurement))
+		));
+		System::assert_last_event(
+			Event::EnclaveRequirementSet {
+				model_id: 0,
+				requirement: Some(EnclaveRequirement::Measurement(measurement)),
+			}
+			.into(),
+		);
+
+		assert_noop!(
+			Inference::accept_request(RuntimeOrigin::signed(3), 0),
+			Error::<Test>::EnclaveRequired
+		);
+
+		let attest = |measurement| {
+			Attestations::<Test>::insert(
+				3,
+				Attestation { kind: TeeKind::Sgx, measurement, expires_at: 100 },
+			)
+		};
+		attest(H256::repeat_byte(6));
+		assert_noop!(
+			Inference::accept_request(RuntimeOrigin::signed(3), 0),
+			Error::<Test>::EnclaveMismatch
+		);
+
+		attest(measurement);
+		assert_ok!(Inference::accept_request(RuntimeOrigin::signed(3), 0));
+	});
+}
+
+#[test]
+fn lifting_enclave_requirement_opens_model_to_all_providers() {
+	new_test_ext().execute_with(|| {
+		setup_request();
+		assert_ok!(Inference::set_enclave_requirement(
+			RuntimeOrigin::signed(1),
+			0,
+			Some(EnclaveRequirement::AnyEnclave)
+		));
+		assert_noop!(
+			Inference::accept_request(RuntimeOrigin::signed(3), 0),
+			Error::<Test>::EnclaveRequired
+		);
+
+		assert_ok!(Inference::set_enclave_requirement(RuntimeOrigin::signed(1), 0, None));
+		assert_ok!(Inference::accept_request(RuntimeOrigin::signed(3), 0));
+	});
+}
+
+#[test]
+fn usd_prices_convert_at_request_time() {
+	new_test_ext().execute_with(|| {
+		register_model(ModelType::Classification);
+		assert_noop!(
+			Inference::set_usd_price(RuntimeOrigin::signed(2), 0, Some(100)),
+			Error::<Test>::NotModelOwner
+		);
+		assert_ok!(Inference::set_usd_price(RuntimeOrigin::signed(1), 0, Some(100)));
+		System::assert_last_event(Event::UsdPriceSet { model_id: 0, usd_cents: Some(100) }.into());
+		assert_eq!(UsdPrices::<Test>::get(0), Some(100));
+
+		assert_noop!(
+			Inference::request_inference(RuntimeOrigin::signed(2), 0, H256::zero(), 299),
+			Error::<Test>::PriceAboveLimit
+		);
+		assert_ok!(Inference::request_inference(RuntimeOrigin::signed(2), 0, H256::zero(), 300));
+		assert_eq!(Requests::<Test>::get(0).unwrap().price, 300);
+
+		// Open requests keep the amount escrowed when they were made
+		TokensPerCent::set(Some(4));
+		assert_ok!(Inference::request_inference(RuntimeOrigin::signed(2), 0, H256::zero(), 400));
+		assert_eq!(Requests::<Test>::get(0).unwrap().price, 300);
+		assert_eq!(Balances::reserved_balance(2), 700);
+
+		TokensPerCent::set(None);
+		assert_noop!(
+			Inference::request_inference(RuntimeOrigin::signed(2), 0, H256::zero(), PRICE),
+			Error::<Test>::PriceFeedUnavailable
+		);
+		assert_noop!(
+			Inference::set_usd_price(RuntimeOrigin::signed(1), 0, Some(50)),
+			Error::<Test>::PriceFeedUnavailable
+		);
+
+		// Back to the registry price
+		assert_ok!(Inference::set_usd_price(RuntimeOrigin::signed(1), 0, None));
+		assert_ok!(Inference::request_inference(RuntimeOrigin::signed(2), 0, H256::zero(), PRICE));
+		assert_eq!(Requests::<Test>::get(2).unwrap().price, PRICE);
+	});
+}
+
+#[test]
+fn promo_prices_end_on_their_own() {
+	new_test_ext().execute_with(|| {
+		register_model(ModelType::Classification);
+		assert_noop!(
+			Inference::set_promo_price(RuntimeOrigin::signed(2), 0, 100, 10),
+			Error::<Test>::NotModelOwner
+		);
+		assert_ok!(Inference::set_promo_price(RuntimeOrigin::signed(1), 0, 100, 10));
+		System::assert_last_event(
+			Event::PromoPriceSet { model_id: 0, promo_price: Some(100), until_block: 10 }.into(),
+		);
+
+		// The promotion overrides the USD price too
+		assert_ok!(Inference::set_usd_price(RuntimeOrigin::signed(1), 0, Some(100)));
+		assert_ok!(Inference::request_inference(RuntimeOrigin::signed(2), 0, H256::zero(), 100));
+		assert_eq!(Requests::<Test>::get(0).unwrap().price, 100);
+
+		System::set_block_number(10);
+		assert_noop!(
+			Inference::request_inference(RuntimeOrigin::signed(2), 0, H256::zero(), 100),
+			Error::<Test>::PriceAboveLimit
+		);
+		assert_ok!(Inference::request_inference(RuntimeOrigin::signed(2), 0, H256::zero(), 300));
+		assert_eq!(Requests::<Test>::get(1).unwrap().price, 300);
+
+		// Promotions ending by now end the current one
+		assert_ok!(Inference::set_promo_price(RuntimeOrigin::signed(1), 0, 100, 20));
+		assert_ok!(Inference::set_promo_price(RuntimeOrigin::signed(1), 0, 100, 10));
+		System::assert_last_event(
+			Event::PromoPriceSet { model_id: 0, promo_price: None, until_block: 10 }.into(),
+		);
+		assert!(!PromoPrices::<Test>::contains_key(0));
+	});
+}
+
+const USDC: u32 = 7;
+
+/// Create the `USDC` asset owned by account 1 and give account 2 some of it
+fn create_usdc() {
+	assert_ok!(Assets::create(RuntimeOrigin::signed(1), USDC, 1, 1));
+	assert_ok!(Assets::mint(RuntimeOrigin::signed(1), USDC, 2, 1_000));
+}
+
+#[test]
+fn owners_choose_accepted_assets() {
+	new_test_ext().execute_with(|| {
+		register_model(ModelType::Classification);
+		create_usdc();
+		assert_noop!(
+			Inference::request_inference_with_asset(
+				RuntimeOrigin::signed(2),
+				0,
+				H256::zero(),
+				USDC,
+				50
+			),
+			Error::<Test>::AssetNotAccepted
+		);
+		assert_noop!(
+			Inference::set_asset_price(RuntimeOrigin::signed(2), 0, USDC, Some(50)),
+			Error::<Test>::NotModelOwner
+		);
+
+		assert_ok!(Inference::set_asset_price(RuntimeOrigin::signed(1), 0, USDC, Some(50)));
+		System::assert_last_event(
+			Event::AssetPriceSet { model_id: 0, asset_id: USDC, price: Some(50) }.into(),
+		);
+		assert_eq!(AssetPrices::<Test>::get(0, USDC), Some(50));
+		assert_noop!(
+			Inference::request_inference_with_asset(
+				RuntimeOrigin::signed(2),
+				0,
+				H256::zero(),
+				USDC,
+				49
+			),
+			Error::<Test>::PriceAboveLimit
+		);
+		assert_noop!(
+			Inference::request_inference_with_asset(
+				RuntimeOrigin::signed(3),
+				0,
+				H256::zero(),
+				USDC,
+				50
+			),
+			Error::<Test>::InsufficientBalance
+		);
+
+		assert_ok!(Inference::set_asset_price(RuntimeOrigin::signed(1), 0, USDC, None));
+		assert_noop!(
+			Inference::request_inference_with_asset(
+				RuntimeOrigin::signed(2),
+				0,
+				H256::zero(),
+				USDC,
+				50
+			),
+			Error::<Test>::AssetNotAccepted
+		);
+	});
+}
+
+#[test]
+fn asset_payments_are_escrowed_and_released() {
+	new_test_ext().execute_with(|| {
+		register_model(ModelType::Classification);
+		register_provider();
+		create_usdc();
+		assert_ok!(Inference::set_asset_price(RuntimeOrigin::signed(1), 0, USDC, Some(50)));
+		let escrow = Escrow::account_id();
+
+		assert_ok!(Inference::request_inference_with_asset(
+			RuntimeOrigin::signed(2),
+			0,
+			H256::repeat_byte(1),
+			USDC,
+			50
+		));
+		System::assert_last_event(
+			Event::InferenceRequested {
+				request_id: 0,
+				model_id: 0,
+				requester: 2,
+				price: 50,
+				asset: Some(USDC),
+			}
+			.into(),
+		);
+		assert_eq!(RequestAssets::<Test>::get(0), Some(USDC));
+		assert_eq!(Assets::balance(USDC, 2), 950);
+		assert_eq!(Assets::balance(USDC, escrow), 50);
+		assert_eq!(Balances::reserved_balance(2), 0);
+
+		// Refunded in the asset it was paid in
+		assert_ok!(Inference::request_inference_with_asset(
+			RuntimeOrigin::signed(2),
+			0,
+			H256::zero(),
+			USDC,
+			50
+		));
+		assert_ok!(Inference::cancel_request(RuntimeOrigin::signed(2), 1));
+		assert_eq!(Assets::balance(USDC, 2), 950);
+
+		complete_request(H256::repeat_byte(9));
+		let native = Balances::free_balance(1);
+		assert_ok!(Inference::settle_request(RuntimeOrigin::signed(2), 0));
+		assert_eq!(Assets::balance(USDC, 1), 50);
+		assert_eq!(Assets::balance(USDC, escrow), 0);
+		assert_eq!(Balances::free_balance(1), native);
+	});
+}
+
+#[test]
+fn fractional_models_pay_shareholders() {
+	new_test_ext().execute_with(|| {
+		setup_request();
+		assert_ok!(AIRegistry::fractionalize_model(
+			RuntimeOrigin::signed(1),
+			0,
+			vec![
+				(1, Perbill::from_percent(50)),
+				(4, Perbill::from_percent(30)),
+				(3, Perbill::from_percent(20))
+			]
+		));
+		complete_request(H256::repeat_byte(9));
+		let owner_free = Balances::free_balance(1);
+		let provider_free = Balances::free_balance(3);
+
+		assert_ok!(Inference::settle_request(RuntimeOrigin::signed(2), 0));
+		System::assert_last_event(
+			Event::RequestSettled { request_id: 0, owner: 1, amount: PRICE }.into(),
+		);
+		assert_eq!(Balances::reserved_balance(2), 0);
+		// Earnings are split between the shareholders when claimed
+		assert_ok!(Inference::claim_earnings(RuntimeOrigin::signed(4), 0));
+		assert_eq!(Balances::free_balance(1), owner_free + 250);
+		assert_eq!(Balances::free_balance(4), 100 + 150);
+		assert_eq!(Balances::free_balance(3), provider_free + 100);
+	});
+}
+
+#[test]
+fn batches_escrow_once_at_a_discount() {
+	new_test_ext().execute_with(|| {
+		register_model(ModelType::Classification);
+		let discount = BatchDiscount { min_size: 3, discount: Percent::from_percent(10) };
+		assert_noop!(
+			Inference::set_batch_discount(RuntimeOrigin::signed(2), 0, Some(discount)),
+			Error::<Test>::NotModelOwner
+		);
+		assert_ok!(Inference::set_batch_discount(RuntimeOrigin::signed(1), 0, Some(discount)));
+		System::assert_last_event(
+			Event::BatchDiscountSet { model_id: 0, discount: Some(discount) }.into(),
+		);
+
+		let batch = |n: u8| -> BoundedVec<H256, _> {
+			(0..n).map(H256::repeat_byte).collect::<Vec<_>>().try_into().unwrap()
+		};
+		assert_noop!(
+			Inference::request_inference_batch(RuntimeOrigin::signed(2), 0, batch(0), PRICE),
+			Error::<Test>::EmptyBatch
+		);
+		// Smaller batches pay the full price
+		assert_noop!(
+			Inference::request_inference_batch(RuntimeOrigin::signed(2), 0, batch(2), PRICE - 1),
+			Error::<Test>::PriceAboveLimit
+		);
+
+		assert_ok!(Inference::request_inference_batch(RuntimeOrigin::signed(2), 0, batch(3), 450));
+		System::assert_last_event(
+			Event::BatchRequested {
+				model_id: 0,
+				requester: 2,
+				first_request_id: 0,
+				count: 3,
+				total: 1_350,
+			}
+			.into(),
+		);
+		assert_eq!(Balances::reserved_balance(2), 1_350);
+		for request_id in 0..3 {
+			let request = Requests::<Test>::get(request_id).unwrap();
+			assert_eq!(request.price, 450);
+			assert_eq!(request.input_hash, H256::repeat_byte(request_id as u8));
+		}
+		assert_eq!(ModelQueue::<Test>::get(0).into_inner(), vec![0, 1, 2]);
+
+		// The whole batch must fit in the queue
+		assert_noop!(
+			Inference::request_inference_batch(RuntimeOrigin::signed(2), 0, batch(1), PRICE),
+			Error::<Test>::QueueFull
+		);
+	});
+}
+
+#[test]
+fn metered_sessions_settle_usage_incrementally() {
+	new_test_ext().execute_with(|| {
+		register_model(ModelType::Classification);
+		register_provider();
+		assert_noop!(
+			Inference::open_metered_session(RuntimeOrigin::signed(2), 0, H256::zero(), 100, 10),
+			Error::<Test>::NotMetered
+		);
+		assert_noop!(
+			Inference::set_unit_price(RuntimeOrigin::signed(2), 0, Some(10)),
+			Error::<Test>::NotModelOwner
+		);
+		assert_ok!(Inference::set_unit_price(RuntimeOrigin::signed(1), 0, Some(10)));
+		System::assert_last_event(Event::UnitPriceSet { model_id: 0, unit_price: Some(10) }.into());
+		assert_noop!(
+			Inference::open_metered_session(RuntimeOrigin::signed(2), 0, H256::zero(), 100, 9),
+			Error::<Test>::PriceAboveLimit
+		);
+
+		assert_ok!(Inference::open_metered_session(
+			RuntimeOrigin::signed(2),
+			0,
+			H256::zero(),
+			100,
+			10
+		));
+		System::assert_last_event(
+			Event::MeteredSessionOpened {
+				session_id: 0,
+				model_id: 0,
+				requester: 2,
+				unit_price: 10,
+				max_units: 100,
+			}
+			.into(),
+		);
+		assert_eq!(Balances::reserved_balance(2), 1_000);
+		assert_noop!(
+			Inference::report_usage(RuntimeOrigin::signed(3), 0, 10),
+			Error::<Test>::NotAssignedProvider
+		);
+
+		assert_ok!(Inference::accept_metered_session(RuntimeOrigin::signed(3), 0));
+		assert_noop!(
+			Inference::accept_metered_session(RuntimeOrigin::signed(3), 0),
+			Error::<Test>::InvalidState
+		);
+		assert_ok!(Inference::report_usage(RuntimeOrigin::signed(3), 0, 30));
+		System::assert_last_event(
+			Event::UsageReported { session_id: 0, units: 30, amount: 300 }.into(),
+		);
+		assert_ok!(Inference::report_usage(RuntimeOrigin::signed(3), 0, 20));
+		assert_eq!(Earnings::<Test>::get(0), 500);
+		assert_eq!(FeesPaid::<Test>::get(2, 0), 500);
+		assert_eq!(Balances::reserved_balance(2), 500);
+		assert_noop!(
+			Inference::report_usage(RuntimeOrigin::signed(3), 0, 51),
+			Error::<Test>::UsageCapExceeded
+		);
+
+		assert_noop!(
+			Inference::close_metered_session(RuntimeOrigin::signed(4), 0),
+			Error::<Test>::NotSessionParticipant
+		);
+		assert_ok!(Inference::close_metered_session(RuntimeOrigin::signed(2), 0));
+		System::assert_last_event(
+			Event::MeteredSessionClosed { session_id: 0, consumed: 50, refunded: 500 }.into(),
+		);
+		assert_eq!(Balances::reserved_balance(2), 0);
+		assert_eq!(Balances::free_balance(2), 10_000 - 500);
+		assert_eq!(pallet_compute_providers::Providers::<Test>::get(3).unwrap().active_jobs, 0);
+		assert_eq!(SlaRecords::<Test>::get(3).completed, 1);
+		assert!(!MeteredSessions::<Test>::contains_key(0));
+		assert_noop!(
+			Inference::report_usage(RuntimeOrigin::signed(3), 0, 1),
+			Error::<Test>::SessionNotFound
+		);
+	});
+}
+
+#[test]
+fn idle_metered_sessions_can_be_expired() {
+	new_test_ext().execute_with(|| {
+		register_model(ModelType::Classification);
+		register_provider();
+		assert_ok!(Inference::set_unit_price(RuntimeOrigin::signed(1), 0, Some(10)));
+		assert_ok!(Inference::open_metered_session(
+			RuntimeOrigin::signed(2),
+			0,
+			H256::zero(),
+			100,
+			10
+		));
+
+		// Every report restarts the timeout
+		System::set_block_number(5);
+		assert_ok!(Inference::accept_metered_session(RuntimeOrigin::signed(3), 0));
+		System::set_block_number(12);
+		assert_ok!(Inference::report_usage(RuntimeOrigin::signed(3), 0, 30));
+		System::set_block_number(22);
+		assert_noop!(
+			Inference::expire_metered_session(RuntimeOrigin::signed(4), 0),
+			Error::<Test>::DeadlineNotPassed
+		);
+
+		System::set_block_number(23);
+		assert_ok!(Inference::expire_metered_session(RuntimeOrigin::signed(4), 0));
+		System::assert_last_event(
+			Event::MeteredSessionExpired { session_id: 0, consumed: 30, refunded: 700 }.into(),
+		);
+		assert!(!MeteredSessions::<Test>::contains_key(0));
+		assert_eq!(Balances::reserved_balance(2), 0);
+		assert_eq!(Balances::free_balance(2), 10_000 - 300);
+		assert_eq!(pallet_compute_providers::Providers::<Test>::get(3).unwrap().active_jobs, 0);
+		assert_noop!(
+			Inference::expire_metered_session(RuntimeOrigin::signed(4), 0),
+			Error::<Test>::SessionNotFound
+		);
+	});
+}
+
+#[test]
+fn quotas_limit_requests_per_account_and_period() {
+	new_test_ext().execute_with(|| {
+		register_model(ModelType::Classification);
+		assert_noop!(
+			Inference::set_quota(RuntimeOrigin::signed(2), 0, Some(1)),
+			Error::<Test>::NotModelOwner
+		);
+		assert_ok!(Inference::set_quota(RuntimeOrigin::signed(1), 0, Some(1)));
+		System::assert_last_event(Event::QuotaSet { model_id: 0, quota: Some(1) }.into());
+		assert_eq!(Quotas::<Test>::get(0), Some(1));
+		assert_eq!(Inference::remaining_quota(&2, 0), Some(1));
+
+		assert_ok!(Inference::request_inference(RuntimeOrigin::signed(2), 0, H256::zero(), PRICE));
+		assert_eq!(Inference::remaining_quota(&2, 0), Some(0));
+		assert_noop!(
+			Inference::request_inference(RuntimeOrigin::signed(2), 0, H256::zero(), PRICE),
+			Error::<Test>::QuotaExceeded
+		);
+		// Quotas are per account, and refunds do not give requests back
+		assert_ok!(Inference::request_inference(RuntimeOrigin::signed(3), 0, H256::zero(), PRICE));
+		assert_ok!(Inference::cancel_request(RuntimeOrigin::signed(2), 0));
+		assert_noop!(
+			Inference::request_inference(RuntimeOrigin::signed(2), 0, H256::zero(), PRICE),
+			Error::<Test>::QuotaExceeded
+		);
+
+		// Usage resets with the next period
+		System::set_block_number(20);
+		assert_eq!(Inference::remaining_quota(&2, 0), Some(1));
+		assert_ok!(Inference::request_inference(RuntimeOrigin::signed(2), 0, H256::zero(), PRICE));
+		assert_eq!(QuotaUsage::<Test>::get(2, 0), Some((1, 1)));
+
+		assert_ok!(Inference::set_quota(RuntimeOrigin::signed(1), 0, None));
+		assert_eq!(Inference::remaining_quota(&2, 0), None);
+		assert_ok!(Inference::request_inference(RuntimeOrigin::signed(2), 0, H256::zero(), PRICE));
+	});
+}
+
+/// Bound a discount code for a call
+fn code(code: &[u8]) -> BoundedVec<u8, <Test as crate::Config>::MaxCouponLength> {
+	code.to_vec().try_into().unwrap()
+}
+
+#[test]
+fn coupons_discount_requests_until_used_up() {
+	new_test_ext().execute_with(|| {
+		register_model(ModelType::Classification);
+		register_model(ModelType::Classification);
+		let hash = Inference::coupon_hash(b"LAUNCH");
+		let discount = Percent::from_percent(20);
+
+		assert_noop!(
+			Inference::create_coupon(RuntimeOrigin::signed(2), 0, hash, discount, 2, 10),
+			Error::<Test>::NotModelOwner
+		);
+		assert_ok!(Inference::create_coupon(RuntimeOrigin::signed(1), 0, hash, discount, 2, 10));
+		System::assert_last_event(
+			Event::CouponCreated {
+				code_hash: hash,
+				model_id: 0,
+				discount,
+				max_uses: 2,
+				expires_at: 10,
+			}
+			.into(),
+		);
+		assert_noop!(
+			Inference::create_coupon(RuntimeOrigin::signed(1), 1, hash, discount, 2, 10),
+			Error::<Test>::CouponExists
+		);
+
+		// Codes only apply to their model
+		assert_noop!(
+			Inference::request_inference_with_coupon(
+				RuntimeOrigin::signed(2),
+				1,
+				H256::zero(),
+				code(b"LAUNCH"),
+				PRICE
+			),
+			Error::<Test>::CouponNotFound
+		);
+		assert_noop!(
+			Inference::request_inference_with_coupon(
+				RuntimeOrigin::signed(2),
+				0,
+				H256::zero(),
+				code(b"LAUNCH"),
+				399
+			),
+			Error::<Test>::PriceAboveLimit
+		);
+		assert_ok!(Inference::request_inference_with_coupon(
+			RuntimeOrigin::signed(2),
+			0,
+			H256::zero(),
+			code(b"LAUNCH"),
+			400
+		));
+		System::assert_last_event(
+			Event::CouponRedeemed { code_hash: hash, request_id: 0, discount: 100 }.into(),
+		);
+		assert_eq!(Requests::<Test>::get(0).unwrap().price, 400);
+		assert_eq!(Balances::reserved_balance(2), 400);
+
+		// Only the owner can remove a code that can still be redeemed
+		assert_noop!(
+			Inference::remove_coupon(RuntimeOrigin::signed(2), hash),
+			Error::<Test>::CouponStillRedeemable
+		);
+		assert_ok!(Inference::request_inference_with_coupon(
+			RuntimeOrigin::signed(3),
+			0,
+			H256::zero(),
+			code(b"LAUNCH"),
+			PRICE
+		));
+		assert_eq!(Coupons::<Test>::get(hash).unwrap().redeemed, 2);
+		assert_noop!(
+			Inference::request_inference_with_coupon(
+				RuntimeOrigin::signed(2),
+				0,
+				H256::zero(),
+				code(b"LAUNCH"),
+				PRICE
+			),
+			Error::<Test>::CouponNotRedeemable
+		);
+		assert_ok!(Inference::remove_coupon(RuntimeOrigin::signed(2), hash));
+		System::assert_last_event(Event::CouponRemoved { code_hash: hash }.into());
+		assert!(!Coupons::<Test>::contains_key(hash));
+	});
+}
+
+#[test]
+fn coupons_expire() {
+	new_test_ext().execute_with(|| {
+		register_model(ModelType::Classification);
+		let hash = Inference::coupon_hash(b"SUMMER");
+		assert_ok!(Inference::create_coupon(
+			RuntimeOrigin::signed(1),
+			0,
+			hash,
+			Percent::from_percent(100),
+			5,
+			10
+		));
+		assert_ok!(Inference::remove_coupon(RuntimeOrigin::signed(1), hash));
+		assert_ok!(Inference::create_coupon(
+			RuntimeOrigin::signed(1),
+			0,
+			hash,
+			Percent::from_percent(100),
+			5,
+			10
+		));
+
+		System::set_block_number(10);
+		assert_noop!(
+			Inference::request_inference_with_coupon(
+				RuntimeOrigin::signed(2),
+				0,
+				H256::zero(),
+				code(b"SUMMER"),
+				PRICE
+			),
+			Error::<Test>::CouponNotRedeemable
+		);
+		assert_ok!(Inference::remove_coupon(RuntimeOrigin::signed(4), hash));
+	});
+}
+
+#[test]
+fn referrers_are_paid_when_requests_settle() {
+	new_test_ext().execute_with(|| {
+		register_model(ModelType::Classification);
+		register_provider();
+		let share = Some(Perbill::from_percent(10));
+		assert_noop!(
+			Inference::set_referral_share(RuntimeOrigin::signed(2), 0, share),
+			Error::<Test>::NotModelOwner
+		);
+		assert_noop!(
+			Inference::set_referral_share(
+				RuntimeOrigin::signed(1),
+				0,
+				Some(Perbill::from_percent(60))
+			),
+			Error::<Test>::ReferralShareTooHigh
+		);
+		assert_ok!(Inference::set_referral_share(RuntimeOrigin::signed(1), 0, share));
+		System::assert_last_event(Event::ReferralShareSet { model_id: 0, share }.into());
+		assert_eq!(ReferralShares::<Test>::get(0), share);
+
+		assert_noop!(
+			Inference::request_inference_with_referrer(
+				RuntimeOrigin::signed(2),
+				0,
+				H256::zero(),
+				2,
+				PRICE
+			),
+			Error::<Test>::SelfReferral
+		);
+		assert_ok!(Inference::request_inference_with_referrer(
+			RuntimeOrigin::signed(2),
+			0,
+			H256::zero(),
+			4,
+			PRICE
+		));
+		// Later changes do not affect open requests
+		assert_ok!(Inference::set_referral_share(RuntimeOrigin::signed(1), 0, None));
+		assert_eq!(Referrals::<Test>::get(0), Some((4, Perbill::from_percent(10))));
+
+		complete_request(H256::repeat_byte(1));
+		let owner_balance = Balances::free_balance(1);
+		assert_ok!(Inference::settle_request(RuntimeOrigin::signed(2), 0));
+		System::assert_has_event(
+			Event::ReferralPaid { request_id: 0, referrer: 4, amount: 50 }.into(),
+		);
+		assert_eq!(Balances::free_balance(4), 150);
+		assert_eq!(Balances::free_balance(1), owner_balance);
+		assert_eq!(Earnings::<Test>::get(0), 450);
+		assert_eq!(ReferralEarnings::<Test>::get(0, 4), 50);
+		assert!(!Referrals::<Test>::contains_key(0));
+
+		// Referrers are only credited with what they were actually paid
+		let dust = Some(Perbill::from_parts(1));
+		assert_ok!(Inference::set_referral_share(RuntimeOrigin::signed(1), 0, dust));
+		assert_ok!(Inference::request_inference_with_referrer(
+			RuntimeOrigin::signed(2),
+			0,
+			H256::zero(),
+			4,
+			PRICE
+		));
+		assert_ok!(Inference::accept_request(RuntimeOrigin::signed(3), 1));
+		let output_cid: BoundedVec<u8, _> = cid(9).try_into().unwrap();
+		assert_ok!(Inference::submit_result(RuntimeOrigin::signed(3), 1, output_cid, H256::zero()));
+		assert_ok!(Inference::settle_request(RuntimeOrigin::signed(2), 1));
+		assert!(!System::events().iter().any(|record| matches!(
+			record.event,
+			RuntimeEvent::Inference(Event::ReferralPaid { request_id: 1, .. })
+		)));
+		assert_eq!(ReferralEarnings::<Test>::get(0, 4), 50);
+		assert_eq!(Earnings::<Test>::get(0), 450 + PRICE);
+
+		// Without a referral share nobody is recorded
+		assert_ok!(Inference::set_referral_share(RuntimeOrigin::signed(1), 0, None));
+		assert_ok!(Inference::request_inference_with_referrer(
+			RuntimeOrigin::signed(2),
+			0,
+			H256::zero(),
+			4,
+			PRICE
+		));
+		assert!(!Referrals::<Test>::contains_key(2));
+	});
+}
+
+#[test]
+fn failed_requests_pay_no_referrer() {
+	new_test_ext().execute_with(|| {
+		register_model(ModelType::Classification);
+		assert_ok!(Inference::set_referral_share(
+			RuntimeOrigin::signed(1),
+			0,
+			Some(Perbill::from_percent(10))
+		));
+		assert_ok!(Inference::request_inference_with_referrer(
+			RuntimeOrigin::signed(2),
+			0,
+			H256::zero(),
+			4,
+			PRICE
+		));
+		assert_ok!(Inference::cancel_request(RuntimeOrigin::signed(2), 0));
+		assert!(!Referrals::<Test>::contains_key(0));
+		assert_eq!(Balances::free_balance(4), 100);
+		assert_eq!(ReferralEarnings::<Test>::get(0, 4), 0);
+	});
+}
+
+#[test]
+fn trial_pools_pay_for_free_inferences() {
+	new_test_ext().execute_with(|| {
+		register_model(ModelType::Classification);
+		register_provider();
+		assert_noop!(
+			Inference::request_trial_inference(RuntimeOrigin::signed(4), 0, H256::zero()),
+			Error::<Test>::NoTrialPool
+		);
+		assert_noop!(
+			Inference::fund_trial_pool(RuntimeOrigin::signed(2), 0, 1, PRICE),
+			Error::<Test>::NotModelOwner
+		);
+		let reserved = Balances::reserved_balance(1);
+		assert_ok!(Inference::fund_trial_pool(RuntimeOrigin::signed(1), 0, 1, PRICE * 2));
+		System::assert_last_event(
+			Event::TrialPoolFunded {
+				model_id: 0,
+				sponsor: 1,
+				credits_per_account: 1,
+				funds: PRICE * 2,
+			}
+			.into(),
+		);
+		assert_eq!(Balances::reserved_balance(1), reserved + PRICE * 2);
+
+		// Account 4 cannot afford the model but gets one free inference
+		assert_ok!(Inference::request_trial_inference(RuntimeOrigin::signed(4), 0, H256::zero()));
+		System::assert_last_event(
+			Event::TrialCreditUsed { request_id: 0, model_id: 0, who: 4 }.into(),
+		);
+		assert_eq!(Balances::reserved_balance(4), PRICE);
+		assert_eq!(Balances::reserved_balance(1), reserved + PRICE);
+		assert_eq!(TrialClaims::<Test>::get(4, 0), 1);
+		assert_eq!(TrialRequests::<Test>::get(0), Some(1));
+		assert_noop!(
+			Inference::request_trial_inference(RuntimeOrigin::signed(4), 0, H256::zero()),
+			Error::<Test>::TrialCreditsUsed
+		);
+
+		// Settled trial requests pay the owner like any other
+		assert_ok!(Inference::accept_request(RuntimeOrigin::signed(3), 0));
+		let commitment = Inference::result_commitment(&H256::repeat_byte(1), &SALT);
+		assert_ok!(Inference::commit_result(RuntimeOrigin::signed(3), 0, commitment));
+		System::set_block_number(System::block_number() + 2);
+		assert_ok!(Inference::reveal_result(
+			RuntimeOrigin::signed(3),
+			0,
+			H256::repeat_byte(1),
+			SALT
+		));
+		assert_ok!(Inference::settle_request(RuntimeOrigin::signed(4), 0));
+		assert_eq!(Balances::free_balance(4), 100);
+		assert!(!TrialRequests::<Test>::contains_key(0));
+
+		// The pool cannot pay for more than it holds
+		assert_ok!(Inference::request_trial_inference(RuntimeOrigin::signed(2), 0, H256::zero()));
+		assert_ok!(Inference::fund_trial_pool(RuntimeOrigin::signed(1), 0, 2, 0));
+		assert_noop!(
+			Inference::request_trial_inference(RuntimeOrigin::signed(2), 0, H256::zero()),
+			Error::<Test>::TrialPoolExhausted
+		);
+	});
+}
+
+#[test]
+fn failed_trial_requests_refund_the_pool() {
+	new_test_ext().execute_with(|| {
+		register_model(ModelType::Classification);
+		let reserved = Balances::reserved_balance(1);
+		assert_ok!(Inference::fund_trial_pool(RuntimeOrigin::signed(1), 0, 1, PRICE * 2));
+		assert_ok!(Inference::request_trial_inference(RuntimeOrigin::signed(4), 0, H256::zero()));
+		assert_ok!(Inference::cancel_request(RuntimeOrigin::signed(4), 0));
+		assert_eq!(Balances::free_balance(4), 100);
+		assert_eq!(
+			TrialPools::<Test>::get(0).map(|pool| Escrow::held(pool.escrow)),
+			Some(PRICE * 2)
+		);
+		assert_eq!(TrialClaims::<Test>::get(4, 0), 0);
+
+		// Requests failing after the pool closed refund the sponsor directly
+		assert_ok!(Inference::request_trial_inference(RuntimeOrigin::signed(4), 0, H256::zero()));
+		assert_noop!(
+			Inference::close_trial_pool(RuntimeOrigin::signed(2), 0),
+			Error::<Test>::NotTrialSponsor
+		);
+		let free = Balances::free_balance(1);
+		assert_ok!(Inference::close_trial_pool(RuntimeOrigin::signed(1), 0));
+		System::assert_last_event(Event::TrialPoolClosed { model_id: 0, refunded: PRICE }.into());
+		assert_ok!(Inference::cancel_request(RuntimeOrigin::signed(4), 1));
+		assert_eq!(Balances::free_balance(1), free + PRICE * 2);
+		assert_eq!(Balances::reserved_balance(1), reserved);
+		assert!(!TrialPools::<Test>::contains_key(0));
+	});
+}
+
+#[test]
+fn demand_pricing_follows_recent_requests() {
+	new_test_ext().execute_with(|| {
+		register_model(ModelType::Classification);
+		let pricing = DemandPricing {
+			baseline: 1,
+			step: Percent::from_percent(10),
+			max_surcharge: Percent::from_percent(20),
+		};
+		assert_noop!(
+			Inference::set_demand_pricing(RuntimeOrigin::signed(2), 0, Some(pricing)),
+			Error::<Test>::NotModelOwner
+		);
+		assert_ok!(Inference::set_demand_pricing(RuntimeOrigin::signed(1), 0, Some(pricing)));
+		System::assert_last_event(
+			Event::DemandPricingSet { model_id: 0, pricing: Some(pricing) }.into(),
+		);
+
+		// Each request above the baseline adds a step, up to the maximum surcharge
+		for (request_id, expected) in (0..).zip([PRICE, PRICE, 550, 600, 600]) {
+			assert_eq!(Inference::current_price(0, PRICE).ok(), Some(expected));
+			if expected > PRICE {
+				assert_noop!(
+					Inference::request_inference(RuntimeOrigin::signed(2), 0, H256::zero(), PRICE),
+					Error::<Test>::PriceAboveLimit
+				);
+			}
+			assert_ok!(Inference::request_inference(
+				RuntimeOrigin::signed(2),
+				0,
+				H256::zero(),
+				expected
+			));
+			assert_eq!(
+				Requests::<Test>::get(request_id).map(|request| request.price),
+				Some(expected)
+			);
+			assert_ok!(Inference::cancel_request(RuntimeOrigin::signed(2), request_id));
+		}
+		assert_eq!(Inference::recent_demand(0), 5);
+
+		// Halfway through the next window half of the previous one still counts
+		System::set_block_number(15);
+		assert_eq!(Inference::recent_demand(0), 2);
+		assert_eq!(Inference::current_price(0, PRICE).ok(), Some(550));
+		System::set_block_number(30);
+		assert_eq!(Inference::recent_demand(0), 0);
+		assert_eq!(Inference::current_price(0, PRICE).ok(), Some(PRICE));
+
+		// Turning demand pricing off forgets the counted requests
+		System::set_block_number(5);
+		assert_ok!(Inference::set_demand_pricing(RuntimeOrigin::signed(1), 0, None));
+		assert!(!DemandPricings::<Test>::contains_key(0));
+		assert!(!DemandCounters::<Test>::contains_key(0));
+		assert_eq!(Inference::current_price(0, PRICE).ok(), Some(PRICE));
+	});
+}
+
+#[test]
+fn fee_handler_takes_cuts_before_the_owner() {
+	new_test_ext().execute_with(|| {
+		FeeCuts::set(vec![(3, Perbill::from_percent(10))]);
+		setup_request();
+		complete_request(H256::repeat_byte(9));
+		let owner = Balances::free_balance(1);
+		let provider = Balances::free_balance(3);
+		assert_ok!(Inference::settle_request(RuntimeOrigin::signed(2), 0));
+		System::assert_has_event(
+			Event::InferenceFeeSplit { request_id: 0, recipient: 3, amount: 50 }.into(),
+		);
+		assert_eq!(Balances::free_balance(3), provider + 50);
+		assert_eq!(Earnings::<Test>::get(0), 450);
+
+		// Cuts are reduced to what is left of the fee
+		FeeCuts::set(vec![(3, Perbill::from_percent(60)), (4, Perbill::from_percent(60))]);
+		assert_ok!(Inference::request_inference(RuntimeOrigin::signed(2), 0, H256::zero(), PRICE));
+		assert_ok!(Inference::accept_request(RuntimeOrigin::signed(3), 1));
+		let commitment = Inference::result_commitment(&H256::zero(), &SALT);
+		assert_ok!(Inference::commit_result(RuntimeOrigin::signed(3), 1, commitment));
+		System::set_block_number(System::block_number() + 2);
+		assert_ok!(Inference::reveal_result(RuntimeOrigin::signed(3), 1, H256::zero(), SALT));
+		let provider = Balances::free_balance(3);
+		assert_ok!(Inference::settle_request(RuntimeOrigin::signed(2), 1));
+		assert_eq!(Balances::free_balance(3), provider + 300);
+		assert_eq!(Balances::free_balance(4), 300);
+		assert_eq!(Earnings::<Test>::get(0), 450);
+		assert_eq!(Balances::free_balance(1), owner);
+		assert_eq!(Balances::reserved_balance(2), 0);
+
+		// Cuts that cannot be paid are not reported and go back to the buyer
+		FeeCuts::set(vec![(3, Perbill::from_percent(10))]);
+		create_usdc();
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(1), USDC, 3, 10));
+		assert_ok!(Assets::block(RuntimeOrigin::signed(1), USDC, 3));
+		assert_ok!(Inference::set_asset_price(RuntimeOrigin::signed(1), 0, USDC, Some(50)));
+		assert_ok!(Inference::request_inference_with_asset(
+			RuntimeOrigin::signed(2),
+			0,
+			H256::zero(),
+			USDC,
+			50
+		));
+		assert_ok!(Inference::accept_request(RuntimeOrigin::signed(3), 2));
+		let output_cid: BoundedVec<u8, _> = cid(9).try_into().unwrap();
+		assert_ok!(Inference::submit_result(RuntimeOrigin::signed(3), 2, output_cid, H256::zero()));
+		assert_ok!(Inference::settle_request(RuntimeOrigin::signed(2), 2));
+		assert!(!System::events().iter().any(|record| matches!(
+			record.event,
+			RuntimeEvent::Inference(Event::InferenceFeeSplit { request_id: 2, .. })
+		)));
+		assert_eq!(Assets::balance(USDC, 3), 10);
+		assert_eq!(Assets::balance(USDC, 1), 45);
+		assert_eq!(Assets::balance(USDC, 2), 1_000 - 45);
+	});
+}
+
+#[test]
+fn earnings_accrue_until_claimed() {
+	new_test_ext().execute_with(|| {
+		setup_request();
+		assert_noop!(
+			Inference::claim_earnings(RuntimeOrigin::signed(1), 0),
+			Error::<Test>::NoEarnings
+		);
+		complete_request(H256::repeat_byte(9));
+		assert_ok!(Inference::settle_request(RuntimeOrigin::signed(2), 0));
+		assert_ok!(Inference::request_inference(RuntimeOrigin::signed(2), 0, H256::zero(), PRICE));
+		assert_ok!(Inference::accept_request(RuntimeOrigin::signed(3), 1));
+		let commitment = Inference::result_commitment(&H256::zero(), &SALT);
+		assert_ok!(Inference::commit_result(RuntimeOrigin::signed(3), 1, commitment));
+		System::set_block_number(System::block_number() + 2);
+		assert_ok!(Inference::reveal_result(RuntimeOrigin::signed(3), 1, H256::zero(), SALT));
+		assert_ok!(Inference::settle_request(RuntimeOrigin::signed(2), 1));
+		assert_eq!(Earnings::<Test>::get(0), 2 * PRICE);
+		assert_eq!(Balances::free_balance(Inference::earnings_account()), 2 * PRICE);
+
+		// Anyone may pay out a model's earnings, always to its owner
+		let owner = Balances::free_balance(1);
+		assert_ok!(Inference::claim_earnings(RuntimeOrigin::signed(4), 0));
+		assert_eq!(Balances::free_balance(1), owner + 2 * PRICE);
+		assert_eq!(Balances::free_balance(Inference::earnings_account()), 0);
+		assert_noop!(
+			Inference::claim_earnings(RuntimeOrigin::signed(1), 0),
+			Error::<Test>::NoEarnings
+		);
+	});
+}
+
+#[test]
+fn fees_run_up_on_tabs_until_settled() {
+	new_test_ext().execute_with(|| {
+		TabSettlementThreshold::set(1_000);
+		register_model(ModelType::Classification);
+		register_provider();
+		for _ in 0..2 {
+			assert_ok!(Inference::request_inference(
+				RuntimeOrigin::signed(2),
+				0,
+				H256::zero(),
+				PRICE
+			));
+		}
+		let earnings = Inference::earnings_account();
+		let settle = |request_id| {
+			assert_ok!(Inference::accept_request(RuntimeOrigin::signed(3), request_id));
+			assert_ok!(Inference::submit_result(
+				RuntimeOrigin::signed(3),
+				request_id,
+				cid(9).try_into().unwrap(),
+				H256::zero()
+			));
+			assert_ok!(Inference::settle_request(RuntimeOrigin::signed(2), request_id));
+		};
+
+		// The fee stays escrowed from the buyer until the tab reaches the threshold
+		settle(0);
+		assert_eq!(Inference::tab(&2, 0), PRICE);
+		assert_eq!(Balances::reserved_balance(2), 2 * PRICE);
+		assert_eq!(Earnings::<Test>::get(0), 0);
+		assert_eq!(FeesPaid::<Test>::get(2, 0), PRICE);
+		settle(1);
+		System::assert_has_event(Event::TabSettled { who: 2, model_id: 0, amount: 1_000 }.into());
+		assert!(!Tabs::<Test>::contains_key(2, 0));
+		assert_eq!(Balances::reserved_balance(2), 0);
+		assert_eq!(Earnings::<Test>::get(0), 1_000);
+		assert_eq!(Balances::free_balance(earnings), 1_000);
+
+		// Anyone can settle a smaller tab
+		assert_noop!(Inference::settle_tab(RuntimeOrigin::signed(4), 2, 0), Error::<Test>::NoTab);
+		Tabs::<Test>::insert(2, 0, Escrow::lock(&2, 100, None).unwrap());
+		assert_ok!(Inference::settle_tab(RuntimeOrigin::signed(4), 2, 0));
+		assert_eq!(Earnings::<Test>::get(0), 1_100);
+
+		// Tabs left at the end of an era are settled, two per block
+		for who in [1, 2, 3] {
+			Tabs::<Test>::insert(who, 0, Escrow::lock(&who, 100, None).unwrap());
+		}
+		Inference::on_initialize(49);
+		assert_eq!(Tabs::<Test>::iter().count(), 3);
+		Inference::on_initialize(50);
+		assert!(matches!(TabSweep::<Test>::get(), Some(SweepProgress::After(_))));
+		assert_eq!(Tabs::<Test>::iter().count(), 1);
+		// Earnings are only paid out once they include every settled tab
+		assert_eq!(PayoutSweep::<Test>::get(), None);
+		assert_eq!(Earnings::<Test>::get(0), 1_300);
+		Inference::on_initialize(51);
+		assert_eq!(TabSweep::<Test>::get(), None);
+		assert_eq!(Tabs::<Test>::iter().count(), 0);
+		assert_eq!(Balances::reserved_balance(2), 0);
+		assert_eq!(Earnings::<Test>::get(0), 0);
+	});
+}
+
+#[test]
+fn earnings_are_paid_out_at_the_end_of_every_era() {
+	new_test_ext().execute_with(|| {
+		for _ in 0..3 {
+			let model_id = register_model(ModelType::Classification);
+			Earnings::<Test>::insert(model_id, 1_000);
+		}
+		let earnings = Inference::earnings_account();
+		assert_ok!(Balances::force_set_balance(RuntimeOrigin::root(), earnings, 3_000));
+		EarningsCuts::set(vec![(4, Perbill::from_percent(10))]);
+		let owner = Balances::free_balance(1);
+
+		Inference::on_initialize(49);
+		assert_eq!(PayoutSweep::<Test>::get(), None);
+		assert_eq!(Earnings::<Test>::iter().count(), 3);
+
+		// Two models are paid per block until every model was
+		Inference::on_initialize(50);
+		assert!(matches!(PayoutSweep::<Test>::get(), Some(SweepProgress::After(_))));
+		assert_eq!(Earnings::<Test>::iter().count(), 1);
+		assert_eq!(Balances::free_balance(1), owner + 1_800);
+		System::assert_last_event(Event::EarningsCutPaid { recipient: 4, amount: 200 }.into());
+
+		Inference::on_initialize(51);
+		assert_eq!(PayoutSweep::<Test>::get(), None);
+		assert_eq!(Earnings::<Test>::iter().count(), 0);
+		assert_eq!(Balances::free_balance(1), owner + 2_700);
+		assert_eq!(Balances::free_balance(4), 100 + 300);
+		assert_eq!(Balances::free_balance(earnings), 0);
+
+		// Cuts are also taken from claimed earnings
+		Earnings::<Test>::insert(0, 500);
+		assert_ok!(Balances::force_set_balance(RuntimeOrigin::root(), earnings, 500));
+		assert_ok!(Inference::claim_earnings(RuntimeOrigin::signed(2), 0));
+		System::assert_has_event(Event::EarningsClaimed { model_id: 0, amount: 450 }.into());
+		assert_eq!(Balances::free_balance(4), 100 + 350);
+	});
+}
+
+#[test]
+fn paid_requests_gain_priority_by_their_payment() {
+	use crate::PrioritizePaidInference;
+	use frame_support::dispatch::DispatchInfo;
+	use sp_runtime::{
+		traits::DispatchTransaction,
+		transaction_validity::{InvalidTransaction, TransactionSource},
+	};
+
+	new_test_ext().execute_with(|| {
+		register_model(ModelType::Classification);
+		let validate = |who: u64, call: crate::Call<Test>| {
+			PrioritizePaidInference::<Test>::new()
+				.validate_only(
+					RuntimeOrigin::signed(who),
+					&call.into(),
+					&DispatchInfo::default(),
+					0,
+					TransactionSource::External,
+					0,
+				)
+				.map(|(validity, ..)| validity.priority)
+		};
+		let priority = |call| validate(2, call).unwrap();
+		let request =
+			|max_price| crate::Call::request_inference { model_id: 0, input_hash: SALT, max_price };
+
+		// One priority per 100 paid
+		assert_eq!(priority(request(u128::MAX)), 5);
+		assert_eq!(
+			priority(crate::Call::request_inference_with_referrer {
+				model_id: 0,
+				input_hash: SALT,
+				referrer: 3,
+				max_price: PRICE,
+			}),
+			5
+		);
+		assert_ok!(Inference::set_usd_price(RuntimeOrigin::signed(1), 0, Some(100)));
+		assert_eq!(priority(request(u128::MAX)), 3);
+
+		// Requests that would fail, or pay nothing natively, are not boosted
+		assert_eq!(priority(request(299)), 0);
+		assert_eq!(
+			priority(crate::Call::request_trial_inference { model_id: 0, input_hash: SALT }),
+			0
+		);
+		assert_eq!(priority(crate::Call::cancel_request { request_id: 0 }), 0);
+
+		// The boost is capped
+		assert_ok!(Inference::set_usd_price(RuntimeOrigin::signed(1), 0, Some(1_000)));
+		assert_eq!(priority(request(u128::MAX)), 5);
+
+		// A signer who cannot pay is not boosted but rejected
+		assert_eq!(validate(4, request(u128::MAX)), Err(InvalidTransaction::Payment.into()));
+		assert_eq!(validate(4, crate::Call::cancel_request { request_id: 0 }), Ok(0));
+	});
+}
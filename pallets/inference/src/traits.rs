@@ -0,0 +1,124 @@
+//! Interfaces the Inference pallet expects from the rest of the runtime
+
+use crate::ModelInfo;
+use frame_support::weights::Weight;
+use pallet_ai_registry::ModelId;
+use sp_core::H256;
+use sp_runtime::Perbill;
+use sp_std::vec::Vec;
+
+/// Registered models that can be paid for
+pub trait InferenceModels<AccountId, Balance> {
+	/// Owner, type, price and availability of `model_id`
+	fn model_info(model_id: ModelId) -> Option<ModelInfo<AccountId, Balance>>;
+
+	/// Record that an inference on `model_id` was completed
+	fn note_inference(model_id: ModelId);
+
+	/// Record that an inference fee of `fee` was settled on `model_id`
+	fn note_fee(model_id: ModelId, fee: Balance);
+
+	/// Accounts sharing the revenue of `model_id` and their shares, adding up to
+	/// 100%; empty if the owner receives all of it
+	fn revenue_shares(model_id: ModelId) -> Vec<(AccountId, Perbill)>;
+
+	/// Register an active model owned by `owner` and return its ID
+	#[cfg(feature = "runtime-benchmarks")]
+	fn create_model(owner: &AccountId) -> ModelId;
+
+	/// Split the revenue of `model_id`, created by `create_model`, into `shares`
+	#[cfg(feature = "runtime-benchmarks")]
+	fn set_revenue_shares(model_id: ModelId, shares: Vec<(AccountId, Perbill)>);
+}
+
+/// Verifier for proofs of correct model execution, e.g. zkML or STARK proofs
+pub trait ProofVerifier {
+	/// Whether payments for `model_id` are only released with a valid proof
+	fn requires_proof(model_id: ModelId) -> bool;
+
+	/// Whether `proof` shows that running `model_id` on `input_hash` produced
+	/// `result_hash`
+	fn verify(model_id: ModelId, input_hash: &H256, result_hash: &H256, proof: &[u8]) -> bool;
+
+	/// Weight of verifying a proof of `proof_len` bytes
+	fn verification_weight(proof_len: u32) -> Weight;
+}
+
+/// No verifier: proofs are never required and never accepted
+impl ProofVerifier for () {
+	fn requires_proof(_model_id: ModelId) -> bool {
+		false
+	}
+
+	fn verify(_model_id: ModelId, _input_hash: &H256, _result_hash: &H256, _proof: &[u8]) -> bool {
+		false
+	}
+
+	fn verification_weight(_proof_len: u32) -> Weight {
+		Weight::zero()
+	}
+}
+
+/// Handler for inference fees as requests settle, letting the runtime split off
+/// cuts such as a treasury or validator share before the model is paid
+pub trait OnInferenceFee<AccountId, Balance> {
+	/// Cuts taken from the `fee` `payer` paid for an inference on `model_id`, owned
+	/// by `owner` and referred by `referrer`
+	///
+	/// `fee` is in the currency or asset the request was paid in and excludes the
+	/// referrer's share. Cuts exceeding what is left of the fee are reduced to it.
+	fn on_inference_fee(
+		model_id: ModelId,
+		payer: &AccountId,
+		owner: &AccountId,
+		referrer: Option<&AccountId>,
+		fee: Balance,
+	) -> Vec<(AccountId, Balance)>;
+
+	/// Weight of `on_inference_fee`
+	fn weight() -> Weight;
+}
+
+/// No cuts: the model's revenue is the whole fee
+impl<AccountId, Balance> OnInferenceFee<AccountId, Balance> for () {
+	fn on_inference_fee(
+		_model_id: ModelId,
+		_payer: &AccountId,
+		_owner: &AccountId,
+		_referrer: Option<&AccountId>,
+		_fee: Balance,
+	) -> Vec<(AccountId, Balance)> {
+		Vec::new()
+	}
+
+	fn weight() -> Weight {
+		Weight::zero()
+	}
+}
+
+/// Exchange rate source for models priced in US dollars
+pub trait PriceFeed<Balance> {
+	/// Native tokens currently worth `usd_cents`, or `None` without a fresh rate
+	fn usd_cents_to_native(usd_cents: u64) -> Option<Balance>;
+}
+
+/// No feed: USD prices can never be converted
+impl<Balance> PriceFeed<Balance> for () {
+	fn usd_cents_to_native(_usd_cents: u64) -> Option<Balance> {
+		None
+	}
+}
+
+/// Creates the assets benchmarks pay with
+#[cfg(feature = "runtime-benchmarks")]
+pub trait BenchmarkHelper<AssetId> {
+	/// ID of a fresh asset for benchmark `n`
+	fn asset_id(n: u32) -> AssetId;
+}
+
+#[cfg(feature = "runtime-benchmarks")]
+impl<AssetId: From<u32>> BenchmarkHelper<AssetId> for () {
+	fn asset_id(n: u32) -> AssetId {
+		n.into()
+	}
+}
@@ -0,0 +1,325 @@
+//! Type definitions for the Inference pallet
+
+use codec::{Decode, DecodeWithMemTracking, Encode, MaxEncodedLen};
+use pallet_ai_registry::{ModelId, ModelType};
+use pallet_compute_providers::JobOutcome;
+use pallet_escrow::EscrowId;
+use scale_info::TypeInfo;
+use sp_core::H256;
+use sp_runtime::{Percent, RuntimeDebug};
+
+/// Unique identifier for inference requests, also used as the payment receipt
+pub type RequestId = u64;
+
+/// Unique identifier for metered sessions
+pub type SessionId = u64;
+
+/// Lifecycle of an inference request
+///
+/// `Requested -> Assigned -> Committed -> Completed -> Settled`, with `Failed`
+/// reachable from the open states on cancellation, timeout or a bad reveal.
+#[derive(
+	Clone,
+	Copy,
+	Encode,
+	Decode,
+	DecodeWithMemTracking,
+	Eq,
+	PartialEq,
+	RuntimeDebug,
+	TypeInfo,
+	MaxEncodedLen,
+)]
+pub enum RequestState {
+	/// Paid for and waiting for a provider
+	Requested,
+	/// Picked up by a provider
+	Assigned,
+	/// Provider committed to a result that is not revealed yet
+	Committed,
+	/// Provider revealed a result matching the commitment; payment still escrowed
+	Completed,
+	/// Payment released to the model owner
+	Settled,
+	/// Cancelled or timed out; payment refunded
+	Failed,
+	/// Buyer disputed the result; payment escrowed until arbitration
+	Disputed,
+	/// A third party challenged the result; payment escrowed until the provider
+	/// concedes or arbitration rules
+	Challenged,
+}
+
+/// Why a request failed
+#[derive(
+	Clone,
+	Copy,
+	Encode,
+	Decode,
+	DecodeWithMemTracking,
+	Eq,
+	PartialEq,
+	RuntimeDebug,
+	TypeInfo,
+	MaxEncodedLen,
+)]
+pub enum FailureReason {
+	/// Requester cancelled before a provider picked it up
+	Cancelled,
+	/// No provider picked it up in time
+	AssignmentTimeout,
+	/// The assigned provider did not commit to a result in time
+	CompletionTimeout,
+	/// The committed result was not revealed in time
+	MissingReveal,
+	/// The revealed result did not match the commitment
+	InvalidReveal,
+	/// Arbitration upheld the buyer's dispute of the result
+	DisputeUpheld,
+	/// No result arrived within the model's response deadline
+	ResponseTimeout,
+	/// A third party's challenge of the result was conceded or upheld
+	ChallengeUpheld,
+	/// The payment could not be released to the model, e.g. because the model was
+	/// removed in the meantime
+	Unsettleable,
+}
+
+impl FailureReason {
+	/// How the failure counts against the assigned provider, if one is to blame
+	pub fn provider_outcome(&self) -> Option<JobOutcome> {
+		match self {
+			// Arbitration judges the result, not whether the provider delivered one
+			Self::Cancelled
+			| Self::AssignmentTimeout
+			| Self::DisputeUpheld
+			| Self::Unsettleable => None,
+			Self::CompletionTimeout | Self::MissingReveal | Self::ResponseTimeout => {
+				Some(JobOutcome::TimedOut)
+			}
+			Self::InvalidReveal | Self::ChallengeUpheld => Some(JobOutcome::Failed),
+		}
+	}
+}
+
+/// What the inference pallet needs to know about a model
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct ModelInfo<AccountId, Balance> {
+	/// Owner paid when a request settles
+	pub owner: AccountId,
+	/// Type of the model, matched against provider capabilities
+	pub model_type: ModelType,
+	/// Price of a single inference
+	pub price: Balance,
+	/// Whether the model accepts new requests
+	pub active: bool,
+}
+
+/// A provider's commitment to a result
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct ResultCommitment<BlockNumber> {
+	/// Hash of the result hash and a secret salt
+	pub hash: H256,
+	/// First block at which the result can be revealed
+	pub reveal_from: BlockNumber,
+}
+
+/// A paid inference request
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct InferenceRequest<AccountId, Balance, BlockNumber> {
+	/// Account that paid for the inference
+	pub requester: AccountId,
+	/// Model to run
+	pub model_id: ModelId,
+	/// Hash of the input, delivered to the provider off-chain
+	pub input_hash: H256,
+	/// Amount escrowed from the requester
+	pub price: Balance,
+	/// Provider that picked up the request
+	pub provider: Option<AccountId>,
+	/// Provider's commitment to the result
+	pub commitment: Option<ResultCommitment<BlockNumber>>,
+	/// Hash of the result revealed by the provider
+	pub result_hash: Option<H256>,
+	/// Whether a valid proof of the result was submitted
+	pub proof_verified: bool,
+	/// Current lifecycle state
+	pub state: RequestState,
+	/// Block at which the request was made
+	pub created_at: BlockNumber,
+	/// Last block for the current state: assignment while `Requested`,
+	/// commitment while `Assigned`, reveal while `Committed`, acceptance while
+	/// `Completed`
+	pub deadline: BlockNumber,
+}
+
+/// A long-running inference, e.g. a streamed LLM completion, billed by the units
+/// its provider reports consuming against a prepaid cap
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct MeteredSession<AccountId, Balance, BlockNumber> {
+	/// Account that prepaid the session
+	pub requester: AccountId,
+	/// Model to run
+	pub model_id: ModelId,
+	/// Hash of the input, delivered to the provider off-chain
+	pub input_hash: H256,
+	/// Price of a unit, fixed when the session was opened
+	pub unit_price: Balance,
+	/// Units the requester prepaid for
+	pub max_units: u32,
+	/// Units reported and paid so far
+	pub consumed: u32,
+	/// Provider that picked up the session
+	pub provider: Option<AccountId>,
+	/// Block at which the session was opened
+	pub opened_at: BlockNumber,
+	/// Block of the latest usage report, or at which the session was opened or
+	/// picked up if there was none since
+	pub last_active: BlockNumber,
+	/// Escrow holding the price of the units not reported yet
+	pub escrow: EscrowId,
+}
+
+impl<AccountId, Balance, BlockNumber> MeteredSession<AccountId, Balance, BlockNumber> {
+	/// Units that can still be reported before the cap is reached
+	pub fn remaining_units(&self) -> u32 {
+		self.max_units.saturating_sub(self.consumed)
+	}
+}
+
+/// Enclave a model's providers must serve it from
+#[derive(
+	Clone,
+	Encode,
+	Decode,
+	DecodeWithMemTracking,
+	Eq,
+	PartialEq,
+	RuntimeDebug,
+	TypeInfo,
+	MaxEncodedLen,
+)]
+pub enum EnclaveRequirement {
+	/// Any enclave with a valid attestation
+	AnyEnclave,
+	/// An enclave with exactly this measurement
+	Measurement(H256),
+}
+
+/// A discount code for a model, stored under the hash of the code
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct Coupon<BlockNumber> {
+	/// Model the code is valid for
+	pub model_id: ModelId,
+	/// Share of the price taken off
+	pub discount: Percent,
+	/// Number of times the code can be redeemed
+	pub max_uses: u32,
+	/// Number of times the code was redeemed
+	pub redeemed: u32,
+	/// First block at which the code can no longer be redeemed
+	pub expires_at: BlockNumber,
+}
+
+impl<BlockNumber: PartialOrd> Coupon<BlockNumber> {
+	/// Whether the code can still be redeemed at block `now`
+	pub fn is_redeemable(&self, now: &BlockNumber) -> bool {
+		self.redeemed < self.max_uses && *now < self.expires_at
+	}
+}
+
+/// Free inferences a sponsor pays for on behalf of new users of a model
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct TrialPool<AccountId> {
+	/// Account whose escrowed funds pay for trial requests
+	pub sponsor: AccountId,
+	/// Free inferences each account can claim
+	pub credits_per_account: u32,
+	/// Escrow holding the funds not spent yet
+	pub escrow: EscrowId,
+}
+
+/// Opt-in pricing that raises a model's price with its demand over the last
+/// `DemandWindow` blocks
+#[derive(
+	Clone,
+	Copy,
+	Encode,
+	Decode,
+	DecodeWithMemTracking,
+	Eq,
+	PartialEq,
+	RuntimeDebug,
+	TypeInfo,
+	MaxEncodedLen,
+)]
+pub struct DemandPricing {
+	/// Requests per window served at the base price
+	pub baseline: u32,
+	/// Price increase for each request above the baseline
+	pub step: Percent,
+	/// Largest total price increase
+	pub max_surcharge: Percent,
+}
+
+impl DemandPricing {
+	/// Increase of the base price after `demand` requests in the last window
+	pub fn surcharge(&self, demand: u32) -> Percent {
+		let excess = demand.saturating_sub(self.baseline);
+		let parts = u32::from(self.step.deconstruct()).saturating_mul(excess);
+		Percent::from_parts(parts.min(self.max_surcharge.deconstruct().into()) as u8)
+	}
+}
+
+/// Discount on every request of a batch of at least `min_size` requests
+#[derive(
+	Clone,
+	Copy,
+	Encode,
+	Decode,
+	DecodeWithMemTracking,
+	Eq,
+	PartialEq,
+	RuntimeDebug,
+	TypeInfo,
+	MaxEncodedLen,
+)]
+pub struct BatchDiscount {
+	/// Smallest batch the discount applies to
+	pub min_size: u32,
+	/// Share of the price taken off
+	pub discount: Percent,
+}
+
+/// Requests for a demand-priced model in the current and the previous window
+#[derive(Clone, Default, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct DemandCounter<BlockNumber> {
+	/// Index of the window `current` counts requests of
+	pub window: BlockNumber,
+	/// Requests in that window
+	pub current: u32,
+	/// Requests in the window before
+	pub previous: u32,
+}
+
+/// Progress of a sweep over a storage map, e.g. paying out every model's earnings
+#[derive(Clone, Copy, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub enum SweepProgress<Key = ModelId> {
+	/// No entry was handled yet
+	Started,
+	/// Entries up to this key, in storage order, were handled
+	After(Key),
+}
+
+/// A third party's challenge of a completed request's result
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct Challenge<AccountId, BlockNumber> {
+	/// Account that posted the challenge
+	pub challenger: AccountId,
+	/// Hash of the result the challenger claims is correct
+	pub counter_hash: H256,
+	/// Escrow holding the challenger's bond
+	pub bond: EscrowId,
+	/// Block from which the challenge awaits arbitration, unless conceded before
+	pub escalates_at: BlockNumber,
+}
@@ -0,0 +1,1232 @@
+//! Autogenerated weights for pallet_inference
+//!
+//! THIS FILE WAS AUTO-GENERATED USING THE SUBSTRATE BENCHMARK CLI VERSION 4.0.0-dev
+//! DATE: 2024-01-01, STEPS: `50`, REPEAT: `20`, LOW RANGE: `[]`, HIGH RANGE: `[]`
+//! WORST CASE MAP SIZE: `1000000`
+//! HOSTNAME: `benchmark-runner`, CPU: `Intel(R) Xeon(R) CPU @ 2.60GHz`
+//! EXECUTION: Some(Wasm), WASM-EXECUTION: Compiled, CHAIN: Some("dev"), DB CACHE: 1024
+
+// Executed Command:
+// ./target/production/solochain-template-node
+// benchmark
+// pallet
+// --chain=dev
+// --steps=50
+// --repeat=20
+// --pallet=pallet_inference
+// --output=./pallets/inference/src/weights.rs
+// --template=./.maintain/frame-weight-template.hbs
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+#![allow(missing_docs)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use core::marker::PhantomData;
+
+/// Weight functions needed for pallet_inference.
+pub trait WeightInfo {
+	fn request_inference() -> Weight;
+	fn request_inference_with_asset() -> Weight;
+	fn accept_request() -> Weight;
+	fn commit_result() -> Weight;
+	fn settle_request(s: u32, ) -> Weight;
+	fn cancel_request() -> Weight;
+	fn expire_request() -> Weight;
+	fn reveal_result() -> Weight;
+	fn submit_inference_proof(n: u32, ) -> Weight;
+	fn set_enclave_requirement() -> Weight;
+	fn set_usd_price() -> Weight;
+	fn set_asset_price() -> Weight;
+	fn set_quota() -> Weight;
+	fn create_coupon() -> Weight;
+	fn remove_coupon() -> Weight;
+	fn request_inference_with_coupon() -> Weight;
+	fn set_referral_share() -> Weight;
+	fn request_inference_with_referrer() -> Weight;
+	fn fund_trial_pool() -> Weight;
+	fn close_trial_pool() -> Weight;
+	fn request_trial_inference() -> Weight;
+	fn set_demand_pricing() -> Weight;
+	fn claim_earnings(s: u32, ) -> Weight;
+	fn sweep_earnings(n: u32, ) -> Weight;
+	fn prioritize_inference() -> Weight;
+	fn set_promo_price() -> Weight;
+	fn submit_result() -> Weight;
+	fn set_response_deadline() -> Weight;
+	fn refund_timed_out() -> Weight;
+	fn set_batch_discount() -> Weight;
+	fn request_inference_batch(n: u32, ) -> Weight;
+	fn set_unit_price() -> Weight;
+	fn open_metered_session() -> Weight;
+	fn accept_metered_session() -> Weight;
+	fn report_usage() -> Weight;
+	fn close_metered_session() -> Weight;
+	fn expire_metered_session() -> Weight;
+	fn settle_tab() -> Weight;
+	fn settle_tabs(n: u32, ) -> Weight;
+	fn challenge_result() -> Weight;
+	fn concede_challenge() -> Weight;
+	fn resolve_challenge() -> Weight;
+}
+
+/// Weights for pallet_inference using the Substrate node and recommended hardware.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	/// Storage: AIRegistry Models (r:1 w:0)
+	/// Proof: AIRegistry Models (max_values: None, max_size: Some(1712), added: 4187, mode: MaxEncodedLen)
+	/// Storage: Inference UsdPrices (r:1 w:0)
+	/// Proof: Inference UsdPrices (max_values: None, max_size: Some(32), added: 2507, mode: MaxEncodedLen)
+	/// Storage: Inference Quotas (r:1 w:0)
+	/// Proof: Inference Quotas (max_values: None, max_size: Some(28), added: 2503, mode: MaxEncodedLen)
+	/// Storage: Inference QuotaUsage (r:1 w:1)
+	/// Proof: Inference QuotaUsage (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: Inference NextRequestId (r:1 w:1)
+	/// Proof: Inference NextRequestId (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: Inference ModelQueue (r:1 w:1)
+	/// Proof: Inference ModelQueue (max_values: None, max_size: Some(2074), added: 4549, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: Inference Requests (r:0 w:1)
+	/// Proof: Inference Requests (max_values: None, max_size: Some(225), added: 2700, mode: MaxEncodedLen)
+	/// Storage: Inference DemandPricings (r:1 w:0)
+	/// Proof: Inference DemandPricings (max_values: None, max_size: Some(30), added: 2505, mode: MaxEncodedLen)
+	/// Storage: Inference DemandCounters (r:1 w:1)
+	/// Proof: Inference DemandCounters (max_values: None, max_size: Some(36), added: 2511, mode: MaxEncodedLen)
+	/// Storage: Inference PromoPrices (r:1 w:0)
+	/// Proof: Inference PromoPrices (max_values: None, max_size: Some(44), added: 2519, mode: MaxEncodedLen)
+	/// Storage: Escrow NextEscrowId (r:1 w:1)
+	/// Proof: Escrow NextEscrowId (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: Escrow Escrows (r:0 w:1)
+	/// Proof: Escrow Escrows (max_values: None, max_size: Some(86), added: 2561, mode: MaxEncodedLen)
+	/// Storage: Inference RequestEscrows (r:0 w:1)
+	/// Proof: Inference RequestEscrows (max_values: None, max_size: Some(32), added: 2507, mode: MaxEncodedLen)
+	fn request_inference() -> Weight {
+		Weight::from_parts(53_000_000, 26942)
+			.saturating_add(T::DbWeight::get().reads(14))
+			.saturating_add(T::DbWeight::get().writes(13))
+	}
+
+	/// Storage: AIRegistry Models (r:1 w:0)
+	/// Proof: AIRegistry Models (max_values: None, max_size: Some(1712), added: 4187, mode: MaxEncodedLen)
+	/// Storage: Inference AssetPrices (r:1 w:0)
+	/// Proof: Inference AssetPrices (max_values: None, max_size: Some(60), added: 2535, mode: MaxEncodedLen)
+	/// Storage: Inference Quotas (r:1 w:0)
+	/// Proof: Inference Quotas (max_values: None, max_size: Some(28), added: 2503, mode: MaxEncodedLen)
+	/// Storage: Inference QuotaUsage (r:1 w:1)
+	/// Proof: Inference QuotaUsage (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: Inference NextRequestId (r:1 w:1)
+	/// Proof: Inference NextRequestId (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: Inference ModelQueue (r:1 w:1)
+	/// Proof: Inference ModelQueue (max_values: None, max_size: Some(2074), added: 4549, mode: MaxEncodedLen)
+	/// Storage: Assets Asset (r:1 w:1)
+	/// Proof: Assets Asset (max_values: None, max_size: Some(210), added: 2685, mode: MaxEncodedLen)
+	/// Storage: Assets Account (r:2 w:2)
+	/// Proof: Assets Account (max_values: None, max_size: Some(134), added: 2609, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: Inference RequestAssets (r:0 w:1)
+	/// Proof: Inference RequestAssets (max_values: None, max_size: Some(28), added: 2503, mode: MaxEncodedLen)
+	/// Storage: Inference Requests (r:0 w:1)
+	/// Proof: Inference Requests (max_values: None, max_size: Some(225), added: 2700, mode: MaxEncodedLen)
+	/// Storage: Inference DemandPricings (r:1 w:0)
+	/// Proof: Inference DemandPricings (max_values: None, max_size: Some(30), added: 2505, mode: MaxEncodedLen)
+	/// Storage: Inference DemandCounters (r:1 w:1)
+	/// Proof: Inference DemandCounters (max_values: None, max_size: Some(36), added: 2511, mode: MaxEncodedLen)
+	/// Storage: Escrow NextEscrowId (r:1 w:1)
+	/// Proof: Escrow NextEscrowId (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: Escrow Escrows (r:0 w:1)
+	/// Proof: Escrow Escrows (max_values: None, max_size: Some(86), added: 2561, mode: MaxEncodedLen)
+	/// Storage: Inference RequestEscrows (r:0 w:1)
+	/// Proof: Inference RequestEscrows (max_values: None, max_size: Some(32), added: 2507, mode: MaxEncodedLen)
+	fn request_inference_with_asset() -> Weight {
+		Weight::from_parts(68_000_000, 32354)
+			.saturating_add(T::DbWeight::get().reads(13))
+			.saturating_add(T::DbWeight::get().writes(13))
+	}
+
+	/// Storage: Inference Requests (r:1 w:1)
+	/// Proof: Inference Requests (max_values: None, max_size: Some(225), added: 2700, mode: MaxEncodedLen)
+	/// Storage: AIRegistry Models (r:1 w:0)
+	/// Proof: AIRegistry Models (max_values: None, max_size: Some(1712), added: 4187, mode: MaxEncodedLen)
+	/// Storage: Inference EnclaveRequirements (r:1 w:0)
+	/// Proof: Inference EnclaveRequirements (max_values: None, max_size: Some(57), added: 2532, mode: MaxEncodedLen)
+	/// Storage: ComputeProviders Attestations (r:1 w:0)
+	/// Proof: ComputeProviders Attestations (max_values: None, max_size: Some(85), added: 2560, mode: MaxEncodedLen)
+	/// Storage: ComputeProviders Providers (r:1 w:1)
+	/// Proof: ComputeProviders Providers (max_values: None, max_size: Some(94), added: 2569, mode: MaxEncodedLen)
+	fn accept_request() -> Weight {
+		Weight::from_parts(48_000_000, 14565)
+			.saturating_add(T::DbWeight::get().reads(5))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+
+	/// Storage: Inference Requests (r:1 w:1)
+	/// Proof: Inference Requests (max_values: None, max_size: Some(225), added: 2700, mode: MaxEncodedLen)
+	/// Storage: ComputeProviders Providers (r:1 w:1)
+	/// Proof: ComputeProviders Providers (max_values: None, max_size: Some(94), added: 2569, mode: MaxEncodedLen)
+	fn commit_result() -> Weight {
+		Weight::from_parts(35_000_000, 5286)
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+
+	/// Storage: Inference Requests (r:1 w:1)
+	/// Proof: Inference Requests (max_values: None, max_size: Some(225), added: 2700, mode: MaxEncodedLen)
+	/// Storage: Inference Referrals (r:1 w:1)
+	/// Proof: Inference Referrals (max_values: None, max_size: Some(60), added: 2535, mode: MaxEncodedLen)
+	/// Storage: Inference ReferralEarnings (r:1 w:1)
+	/// Proof: Inference ReferralEarnings (max_values: None, max_size: Some(88), added: 2563, mode: MaxEncodedLen)
+	/// Storage: Inference RequestAssets (r:1 w:0)
+	/// Proof: Inference RequestAssets (max_values: None, max_size: Some(28), added: 2503, mode: MaxEncodedLen)
+	/// Storage: Assets Asset (r:1 w:1)
+	/// Proof: Assets Asset (max_values: None, max_size: Some(210), added: 2685, mode: MaxEncodedLen)
+	/// Storage: Assets Account (r:11 w:11)
+	/// Proof: Assets Account (max_values: None, max_size: Some(134), added: 2609, mode: MaxEncodedLen)
+	/// Storage: AIRegistry Models (r:2 w:0)
+	/// Proof: AIRegistry Models (max_values: None, max_size: Some(1712), added: 4187, mode: MaxEncodedLen)
+	/// Storage: AIRegistry RevenueRecipient (r:1 w:0)
+	/// Proof: AIRegistry RevenueRecipient (max_values: None, max_size: Some(56), added: 2531, mode: MaxEncodedLen)
+	/// Storage: AIRegistry ParentShare (r:1 w:0)
+	/// Proof: AIRegistry ParentShare (max_values: None, max_size: Some(28), added: 2503, mode: MaxEncodedLen)
+	/// Storage: AIRegistry Shareholders (r:1 w:0)
+	/// Proof: AIRegistry Shareholders (max_values: None, max_size: Some(385), added: 2860, mode: MaxEncodedLen)
+	/// Storage: Inference LatestReceipt (r:0 w:1)
+	/// Proof: Inference LatestReceipt (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: Inference TrialRequests (r:0 w:1)
+	/// Proof: Inference TrialRequests (max_values: None, max_size: Some(56), added: 2531, mode: MaxEncodedLen)
+	/// Storage: AIRegistry GlobalStats (r:1 w:1)
+	/// Proof: AIRegistry GlobalStats (max_values: Some(1), max_size: Some(40), added: 535, mode: MaxEncodedLen)
+	/// Storage: AIRegistry ModelRevenue (r:1 w:1)
+	/// Proof: AIRegistry ModelRevenue (max_values: None, max_size: Some(40), added: 2515, mode: MaxEncodedLen)
+	/// Storage: Inference InferencesPurchased (r:1 w:1)
+	/// Proof: Inference InferencesPurchased (max_values: None, max_size: Some(76), added: 2551, mode: MaxEncodedLen)
+	/// Storage: AIRegistry RecentRevenue (r:1 w:1)
+	/// Proof: AIRegistry RecentRevenue (max_values: None, max_size: Some(305), added: 2780, mode: MaxEncodedLen)
+	/// Storage: Inference FeesPaid (r:1 w:1)
+	/// Proof: Inference FeesPaid (max_values: None, max_size: Some(88), added: 2563, mode: MaxEncodedLen)
+	/// Storage: Inference AutoReleases (r:1 w:1)
+	/// Proof: Inference AutoReleases (max_values: None, max_size: Some(530), added: 3005, mode: MaxEncodedLen)
+	fn settle_request(s: u32, ) -> Weight {
+		Weight::from_parts(61_000_000, 45812)
+			// Standard Error: 700_000
+			.saturating_add(Weight::from_parts(14_000_000, 0).saturating_mul(s.into()))
+			.saturating_add(T::DbWeight::get().reads(17))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(s.into())))
+			.saturating_add(T::DbWeight::get().writes(13))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(s.into())))
+			.saturating_add(Weight::from_parts(0, 2609).saturating_mul(s.into()))
+	}
+
+	/// Storage: Inference Requests (r:1 w:1)
+	/// Proof: Inference Requests (max_values: None, max_size: Some(225), added: 2700, mode: MaxEncodedLen)
+	/// Storage: Inference RequestAssets (r:1 w:0)
+	/// Proof: Inference RequestAssets (max_values: None, max_size: Some(28), added: 2503, mode: MaxEncodedLen)
+	/// Storage: Assets Asset (r:1 w:1)
+	/// Proof: Assets Asset (max_values: None, max_size: Some(210), added: 2685, mode: MaxEncodedLen)
+	/// Storage: Assets Account (r:2 w:2)
+	/// Proof: Assets Account (max_values: None, max_size: Some(134), added: 2609, mode: MaxEncodedLen)
+	/// Storage: Inference ModelQueue (r:1 w:1)
+	/// Proof: Inference ModelQueue (max_values: None, max_size: Some(2074), added: 4549, mode: MaxEncodedLen)
+	/// Storage: Inference Referrals (r:0 w:1)
+	/// Proof: Inference Referrals (max_values: None, max_size: Some(60), added: 2535, mode: MaxEncodedLen)
+	/// Storage: Inference TrialRequests (r:1 w:1)
+	/// Proof: Inference TrialRequests (max_values: None, max_size: Some(56), added: 2531, mode: MaxEncodedLen)
+	fn cancel_request() -> Weight {
+		Weight::from_parts(52_000_000, 20186)
+			.saturating_add(T::DbWeight::get().reads(7))
+			.saturating_add(T::DbWeight::get().writes(7))
+	}
+
+	/// Storage: Inference Requests (r:1 w:1)
+	/// Proof: Inference Requests (max_values: None, max_size: Some(225), added: 2700, mode: MaxEncodedLen)
+	/// Storage: Inference RequestAssets (r:1 w:0)
+	/// Proof: Inference RequestAssets (max_values: None, max_size: Some(28), added: 2503, mode: MaxEncodedLen)
+	/// Storage: Assets Asset (r:1 w:1)
+	/// Proof: Assets Asset (max_values: None, max_size: Some(210), added: 2685, mode: MaxEncodedLen)
+	/// Storage: Assets Account (r:2 w:2)
+	/// Proof: Assets Account (max_values: None, max_size: Some(134), added: 2609, mode: MaxEncodedLen)
+	/// Storage: ComputeProviders Providers (r:1 w:1)
+	/// Proof: ComputeProviders Providers (max_values: None, max_size: Some(94), added: 2569, mode: MaxEncodedLen)
+	/// Storage: Inference ModelQueue (r:1 w:1)
+	/// Proof: Inference ModelQueue (max_values: None, max_size: Some(2074), added: 4549, mode: MaxEncodedLen)
+	/// Storage: ComputeProviders SlaRecords (r:1 w:1)
+	/// Proof: ComputeProviders SlaRecords (max_values: None, max_size: Some(65), added: 2540, mode: MaxEncodedLen)
+	/// Storage: Inference Referrals (r:0 w:1)
+	/// Proof: Inference Referrals (max_values: None, max_size: Some(60), added: 2535, mode: MaxEncodedLen)
+	/// Storage: Inference TrialRequests (r:1 w:1)
+	/// Proof: Inference TrialRequests (max_values: None, max_size: Some(56), added: 2531, mode: MaxEncodedLen)
+	fn expire_request() -> Weight {
+		Weight::from_parts(60_000_000, 25312)
+			.saturating_add(T::DbWeight::get().reads(9))
+			.saturating_add(T::DbWeight::get().writes(9))
+	}
+
+	/// Storage: Inference Requests (r:1 w:1)
+	/// Proof: Inference Requests (max_values: None, max_size: Some(225), added: 2700, mode: MaxEncodedLen)
+	/// Storage: Inference ModelQueue (r:1 w:1)
+	/// Proof: Inference ModelQueue (max_values: None, max_size: Some(2074), added: 4549, mode: MaxEncodedLen)
+	/// Storage: AIRegistry Models (r:1 w:1)
+	/// Proof: AIRegistry Models (max_values: None, max_size: Some(1712), added: 4187, mode: MaxEncodedLen)
+	/// Storage: AIRegistry LastActivity (r:0 w:1)
+	/// Proof: AIRegistry LastActivity (max_values: None, max_size: Some(28), added: 2503, mode: MaxEncodedLen)
+	/// Storage: ComputeProviders SlaRecords (r:1 w:1)
+	/// Proof: ComputeProviders SlaRecords (max_values: None, max_size: Some(65), added: 2540, mode: MaxEncodedLen)
+	/// Storage: Inference Referrals (r:0 w:1)
+	/// Proof: Inference Referrals (max_values: None, max_size: Some(60), added: 2535, mode: MaxEncodedLen)
+	/// Storage: Inference TrialRequests (r:1 w:1)
+	/// Proof: Inference TrialRequests (max_values: None, max_size: Some(56), added: 2531, mode: MaxEncodedLen)
+	/// Storage: AIRegistry IncentiveUsage (r:1 w:1)
+	/// Proof: AIRegistry IncentiveUsage (max_values: None, max_size: Some(32), added: 2507, mode: MaxEncodedLen)
+	/// Storage: AIRegistry EraIncentives (r:2 w:2)
+	/// Proof: AIRegistry EraIncentives (max_values: None, max_size: Some(44), added: 2519, mode: MaxEncodedLen)
+	/// Storage: AIRegistry IncentiveEarnings (r:1 w:1)
+	/// Proof: AIRegistry IncentiveEarnings (max_values: None, max_size: Some(40), added: 2515, mode: MaxEncodedLen)
+	/// Storage: AIRegistry IncentivePerEra (r:1 w:0)
+	/// Proof: AIRegistry IncentivePerEra (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
+	/// Storage: AIRegistry IncentiveFunds (r:1 w:1)
+	/// Proof: AIRegistry IncentiveFunds (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
+	/// Storage: AIRegistry RecentUsage (r:1 w:1)
+	/// Proof: AIRegistry RecentUsage (max_values: None, max_size: Some(137), added: 2612, mode: MaxEncodedLen)
+	/// Storage: AIRegistry GlobalStats (r:1 w:1)
+	/// Proof: AIRegistry GlobalStats (max_values: Some(1), max_size: Some(40), added: 535, mode: MaxEncodedLen)
+	/// Storage: AIRegistry MostUsedModels (r:1 w:1)
+	/// Proof: AIRegistry MostUsedModels (max_values: Some(1), max_size: Some(1602), added: 2097, mode: MaxEncodedLen)
+	/// Storage: Inference AutoReleases (r:1 w:1)
+	/// Proof: Inference AutoReleases (max_values: None, max_size: Some(530), added: 3005, mode: MaxEncodedLen)
+	fn reveal_result() -> Weight {
+		Weight::from_parts(55_000_000, 35838)
+			.saturating_add(T::DbWeight::get().reads(15))
+			.saturating_add(T::DbWeight::get().writes(16))
+	}
+
+	/// Storage: Inference Requests (r:1 w:1)
+	/// Proof: Inference Requests (max_values: None, max_size: Some(225), added: 2700, mode: MaxEncodedLen)
+	fn submit_inference_proof(n: u32, ) -> Weight {
+		Weight::from_parts(20_000_000, 2700)
+			// Standard Error: 50
+			.saturating_add(Weight::from_parts(1_000, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+
+	/// Storage: AIRegistry Models (r:1 w:0)
+	/// Proof: AIRegistry Models (max_values: None, max_size: Some(1712), added: 4187, mode: MaxEncodedLen)
+	/// Storage: Inference EnclaveRequirements (r:0 w:1)
+	/// Proof: Inference EnclaveRequirements (max_values: None, max_size: Some(57), added: 2532, mode: MaxEncodedLen)
+	fn set_enclave_requirement() -> Weight {
+		Weight::from_parts(18_000_000, 4187)
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+
+	/// Storage: AIRegistry Models (r:1 w:0)
+	/// Proof: AIRegistry Models (max_values: None, max_size: Some(1712), added: 4187, mode: MaxEncodedLen)
+	/// Storage: Inference UsdPrices (r:0 w:1)
+	/// Proof: Inference UsdPrices (max_values: None, max_size: Some(32), added: 2507, mode: MaxEncodedLen)
+	fn set_usd_price() -> Weight {
+		Weight::from_parts(18_000_000, 4187)
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+
+	/// Storage: AIRegistry Models (r:1 w:0)
+	/// Proof: AIRegistry Models (max_values: None, max_size: Some(1712), added: 4187, mode: MaxEncodedLen)
+	/// Storage: Inference AssetPrices (r:0 w:1)
+	/// Proof: Inference AssetPrices (max_values: None, max_size: Some(60), added: 2535, mode: MaxEncodedLen)
+	fn set_asset_price() -> Weight {
+		Weight::from_parts(18_000_000, 4187)
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+
+	/// Storage: AIRegistry Models (r:1 w:0)
+	/// Proof: AIRegistry Models (max_values: None, max_size: Some(1712), added: 4187, mode: MaxEncodedLen)
+	/// Storage: Inference Quotas (r:0 w:1)
+	/// Proof: Inference Quotas (max_values: None, max_size: Some(28), added: 2503, mode: MaxEncodedLen)
+	fn set_quota() -> Weight {
+		Weight::from_parts(18_000_000, 4187)
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+
+	/// Storage: AIRegistry Models (r:1 w:0)
+	/// Proof: AIRegistry Models (max_values: None, max_size: Some(1712), added: 4187, mode: MaxEncodedLen)
+	/// Storage: Inference Coupons (r:1 w:1)
+	/// Proof: Inference Coupons (max_values: None, max_size: Some(69), added: 2544, mode: MaxEncodedLen)
+	fn create_coupon() -> Weight {
+		Weight::from_parts(20_000_000, 6731)
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+
+	/// Storage: Inference Coupons (r:1 w:1)
+	/// Proof: Inference Coupons (max_values: None, max_size: Some(69), added: 2544, mode: MaxEncodedLen)
+	/// Storage: AIRegistry Models (r:1 w:0)
+	/// Proof: AIRegistry Models (max_values: None, max_size: Some(1712), added: 4187, mode: MaxEncodedLen)
+	fn remove_coupon() -> Weight {
+		Weight::from_parts(21_000_000, 6731)
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+
+	/// Storage: AIRegistry Models (r:1 w:0)
+	/// Proof: AIRegistry Models (max_values: None, max_size: Some(1712), added: 4187, mode: MaxEncodedLen)
+	/// Storage: Inference Coupons (r:1 w:1)
+	/// Proof: Inference Coupons (max_values: None, max_size: Some(69), added: 2544, mode: MaxEncodedLen)
+	/// Storage: Inference UsdPrices (r:1 w:0)
+	/// Proof: Inference UsdPrices (max_values: None, max_size: Some(32), added: 2507, mode: MaxEncodedLen)
+	/// Storage: Inference Quotas (r:1 w:0)
+	/// Proof: Inference Quotas (max_values: None, max_size: Some(28), added: 2503, mode: MaxEncodedLen)
+	/// Storage: Inference QuotaUsage (r:1 w:1)
+	/// Proof: Inference QuotaUsage (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: Inference NextRequestId (r:1 w:1)
+	/// Proof: Inference NextRequestId (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: Inference ModelQueue (r:1 w:1)
+	/// Proof: Inference ModelQueue (max_values: None, max_size: Some(2074), added: 4549, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: Inference Requests (r:0 w:1)
+	/// Proof: Inference Requests (max_values: None, max_size: Some(225), added: 2700, mode: MaxEncodedLen)
+	/// Storage: Inference DemandPricings (r:1 w:0)
+	/// Proof: Inference DemandPricings (max_values: None, max_size: Some(30), added: 2505, mode: MaxEncodedLen)
+	/// Storage: Inference DemandCounters (r:1 w:1)
+	/// Proof: Inference DemandCounters (max_values: None, max_size: Some(36), added: 2511, mode: MaxEncodedLen)
+	/// Storage: Inference PromoPrices (r:1 w:0)
+	/// Proof: Inference PromoPrices (max_values: None, max_size: Some(44), added: 2519, mode: MaxEncodedLen)
+	fn request_inference_with_coupon() -> Weight {
+		Weight::from_parts(61_000_000, 29486)
+			.saturating_add(T::DbWeight::get().reads(11))
+			.saturating_add(T::DbWeight::get().writes(7))
+	}
+
+	/// Storage: AIRegistry Models (r:1 w:0)
+	/// Proof: AIRegistry Models (max_values: None, max_size: Some(1712), added: 4187, mode: MaxEncodedLen)
+	/// Storage: Inference ReferralShares (r:0 w:1)
+	/// Proof: Inference ReferralShares (max_values: None, max_size: Some(28), added: 2503, mode: MaxEncodedLen)
+	fn set_referral_share() -> Weight {
+		Weight::from_parts(18_000_000, 4187)
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+
+	/// Storage: AIRegistry Models (r:1 w:0)
+	/// Proof: AIRegistry Models (max_values: None, max_size: Some(1712), added: 4187, mode: MaxEncodedLen)
+	/// Storage: Inference UsdPrices (r:1 w:0)
+	/// Proof: Inference UsdPrices (max_values: None, max_size: Some(32), added: 2507, mode: MaxEncodedLen)
+	/// Storage: Inference ReferralShares (r:1 w:0)
+	/// Proof: Inference ReferralShares (max_values: None, max_size: Some(28), added: 2503, mode: MaxEncodedLen)
+	/// Storage: Inference Quotas (r:1 w:0)
+	/// Proof: Inference Quotas (max_values: None, max_size: Some(28), added: 2503, mode: MaxEncodedLen)
+	/// Storage: Inference QuotaUsage (r:1 w:1)
+	/// Proof: Inference QuotaUsage (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: Inference NextRequestId (r:1 w:1)
+	/// Proof: Inference NextRequestId (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: Inference ModelQueue (r:1 w:1)
+	/// Proof: Inference ModelQueue (max_values: None, max_size: Some(2074), added: 4549, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: Inference Requests (r:0 w:1)
+	/// Proof: Inference Requests (max_values: None, max_size: Some(225), added: 2700, mode: MaxEncodedLen)
+	/// Storage: Inference Referrals (r:0 w:1)
+	/// Proof: Inference Referrals (max_values: None, max_size: Some(60), added: 2535, mode: MaxEncodedLen)
+	/// Storage: Inference DemandPricings (r:1 w:0)
+	/// Proof: Inference DemandPricings (max_values: None, max_size: Some(30), added: 2505, mode: MaxEncodedLen)
+	/// Storage: Inference DemandCounters (r:1 w:1)
+	/// Proof: Inference DemandCounters (max_values: None, max_size: Some(36), added: 2511, mode: MaxEncodedLen)
+	/// Storage: Inference PromoPrices (r:1 w:0)
+	/// Proof: Inference PromoPrices (max_values: None, max_size: Some(44), added: 2519, mode: MaxEncodedLen)
+	fn request_inference_with_referrer() -> Weight {
+		Weight::from_parts(58_000_000, 29445)
+			.saturating_add(T::DbWeight::get().reads(11))
+			.saturating_add(T::DbWeight::get().writes(7))
+	}
+
+	/// Storage: AIRegistry Models (r:1 w:0)
+	/// Proof: AIRegistry Models (max_values: None, max_size: Some(1712), added: 4187, mode: MaxEncodedLen)
+	/// Storage: Inference TrialPools (r:1 w:1)
+	/// Proof: Inference TrialPools (max_values: None, max_size: Some(68), added: 2543, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	fn fund_trial_pool() -> Weight {
+		Weight::from_parts(36_000_000, 9341)
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+
+	/// Storage: Inference TrialPools (r:1 w:1)
+	/// Proof: Inference TrialPools (max_values: None, max_size: Some(68), added: 2543, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	fn close_trial_pool() -> Weight {
+		Weight::from_parts(30_000_000, 5154)
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+
+	/// Storage: AIRegistry Models (r:1 w:0)
+	/// Proof: AIRegistry Models (max_values: None, max_size: Some(1712), added: 4187, mode: MaxEncodedLen)
+	/// Storage: Inference UsdPrices (r:1 w:0)
+	/// Proof: Inference UsdPrices (max_values: None, max_size: Some(32), added: 2507, mode: MaxEncodedLen)
+	/// Storage: Inference TrialPools (r:1 w:1)
+	/// Proof: Inference TrialPools (max_values: None, max_size: Some(68), added: 2543, mode: MaxEncodedLen)
+	/// Storage: Inference TrialClaims (r:1 w:1)
+	/// Proof: Inference TrialClaims (max_values: None, max_size: Some(76), added: 2551, mode: MaxEncodedLen)
+	/// Storage: System Account (r:2 w:2)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: Inference Quotas (r:1 w:0)
+	/// Proof: Inference Quotas (max_values: None, max_size: Some(28), added: 2503, mode: MaxEncodedLen)
+	/// Storage: Inference QuotaUsage (r:1 w:1)
+	/// Proof: Inference QuotaUsage (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: Inference NextRequestId (r:1 w:1)
+	/// Proof: Inference NextRequestId (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: Inference ModelQueue (r:1 w:1)
+	/// Proof: Inference ModelQueue (max_values: None, max_size: Some(2074), added: 4549, mode: MaxEncodedLen)
+	/// Storage: Inference Requests (r:0 w:1)
+	/// Proof: Inference Requests (max_values: None, max_size: Some(225), added: 2700, mode: MaxEncodedLen)
+	/// Storage: Inference TrialRequests (r:0 w:1)
+	/// Proof: Inference TrialRequests (max_values: None, max_size: Some(56), added: 2531, mode: MaxEncodedLen)
+	/// Storage: Inference DemandPricings (r:1 w:0)
+	/// Proof: Inference DemandPricings (max_values: None, max_size: Some(30), added: 2505, mode: MaxEncodedLen)
+	/// Storage: Inference DemandCounters (r:1 w:1)
+	/// Proof: Inference DemandCounters (max_values: None, max_size: Some(36), added: 2511, mode: MaxEncodedLen)
+	/// Storage: Inference PromoPrices (r:1 w:0)
+	/// Proof: Inference PromoPrices (max_values: None, max_size: Some(44), added: 2519, mode: MaxEncodedLen)
+	fn request_trial_inference() -> Weight {
+		Weight::from_parts(72_000_000, 34647)
+			.saturating_add(T::DbWeight::get().reads(13))
+			.saturating_add(T::DbWeight::get().writes(10))
+	}
+
+	/// Storage: AIRegistry Models (r:1 w:0)
+	/// Proof: AIRegistry Models (max_values: None, max_size: Some(1712), added: 4187, mode: MaxEncodedLen)
+	/// Storage: Inference DemandPricings (r:0 w:1)
+	/// Proof: Inference DemandPricings (max_values: None, max_size: Some(30), added: 2505, mode: MaxEncodedLen)
+	/// Storage: Inference DemandCounters (r:0 w:1)
+	/// Proof: Inference DemandCounters (max_values: None, max_size: Some(36), added: 2511, mode: MaxEncodedLen)
+	fn set_demand_pricing() -> Weight {
+		Weight::from_parts(19_000_000, 4187)
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+
+	/// Storage: Inference Earnings (r:1 w:1)
+	/// Proof: Inference Earnings (max_values: None, max_size: Some(40), added: 2515, mode: MaxEncodedLen)
+	/// Storage: AIRegistry Models (r:2 w:0)
+	/// Proof: AIRegistry Models (max_values: None, max_size: Some(1712), added: 4187, mode: MaxEncodedLen)
+	/// Storage: AIRegistry RevenueRecipient (r:1 w:0)
+	/// Proof: AIRegistry RevenueRecipient (max_values: None, max_size: Some(56), added: 2531, mode: MaxEncodedLen)
+	/// Storage: AIRegistry ParentShare (r:1 w:0)
+	/// Proof: AIRegistry ParentShare (max_values: None, max_size: Some(28), added: 2503, mode: MaxEncodedLen)
+	/// Storage: AIRegistry Shareholders (r:1 w:0)
+	/// Proof: AIRegistry Shareholders (max_values: None, max_size: Some(385), added: 2860, mode: MaxEncodedLen)
+	/// Storage: System Account (r:11 w:11)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// The range of component `s` is `[1, 10]`.
+	fn claim_earnings(s: u32, ) -> Weight {
+		Weight::from_parts(26_000_000, 21386)
+			// Standard Error: 1_200_000
+			.saturating_add(Weight::from_parts(21_000_000, 0).saturating_mul(s.into()))
+			.saturating_add(T::DbWeight::get().reads(7))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(s.into())))
+			.saturating_add(T::DbWeight::get().writes(2))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(s.into())))
+			.saturating_add(Weight::from_parts(0, 2603).saturating_mul(s.into()))
+	}
+
+	/// Storage: Inference PayoutSweep (r:1 w:1)
+	/// Proof: Inference PayoutSweep (max_values: Some(1), max_size: Some(9), added: 504, mode: MaxEncodedLen)
+	/// Storage: Inference Earnings (r:17 w:16)
+	/// Proof: Inference Earnings (max_values: None, max_size: Some(40), added: 2515, mode: MaxEncodedLen)
+	/// Storage: AIRegistry Models (r:32 w:0)
+	/// Proof: AIRegistry Models (max_values: None, max_size: Some(1712), added: 4187, mode: MaxEncodedLen)
+	/// Storage: AIRegistry RevenueRecipient (r:16 w:0)
+	/// Proof: AIRegistry RevenueRecipient (max_values: None, max_size: Some(56), added: 2531, mode: MaxEncodedLen)
+	/// Storage: AIRegistry ParentShare (r:16 w:0)
+	/// Proof: AIRegistry ParentShare (max_values: None, max_size: Some(28), added: 2503, mode: MaxEncodedLen)
+	/// Storage: AIRegistry Shareholders (r:16 w:0)
+	/// Proof: AIRegistry Shareholders (max_values: None, max_size: Some(385), added: 2860, mode: MaxEncodedLen)
+	/// Storage: System Account (r:171 w:171)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// The range of component `n` is `[0, 16]`.
+	fn sweep_earnings(n: u32, ) -> Weight {
+		Weight::from_parts(30_000_000, 31652)
+			// Standard Error: 4_500_000
+			.saturating_add(Weight::from_parts(230_000_000, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(13))
+			.saturating_add(T::DbWeight::get().reads((16_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes(12))
+			.saturating_add(T::DbWeight::get().writes((11_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 44813).saturating_mul(n.into()))
+	}
+
+	/// Storage: AIRegistry Models (r:1 w:0)
+	/// Proof: AIRegistry Models (max_values: None, max_size: Some(1712), added: 4187, mode: MaxEncodedLen)
+	/// Storage: Inference UsdPrices (r:1 w:0)
+	/// Proof: Inference UsdPrices (max_values: None, max_size: Some(32), added: 2507, mode: MaxEncodedLen)
+	/// Storage: Inference DemandPricings (r:1 w:0)
+	/// Proof: Inference DemandPricings (max_values: None, max_size: Some(30), added: 2505, mode: MaxEncodedLen)
+	/// Storage: Inference DemandCounters (r:1 w:0)
+	/// Proof: Inference DemandCounters (max_values: None, max_size: Some(36), added: 2511, mode: MaxEncodedLen)
+	/// Storage: Inference PromoPrices (r:1 w:0)
+	/// Proof: Inference PromoPrices (max_values: None, max_size: Some(44), added: 2519, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:0)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	fn prioritize_inference() -> Weight {
+		Weight::from_parts(17_000_000, 16832)
+			.saturating_add(T::DbWeight::get().reads(6))
+	}
+
+	/// Storage: AIRegistry Models (r:1 w:0)
+	/// Proof: AIRegistry Models (max_values: None, max_size: Some(1712), added: 4187, mode: MaxEncodedLen)
+	/// Storage: Inference PromoPrices (r:0 w:1)
+	/// Proof: Inference PromoPrices (max_values: None, max_size: Some(44), added: 2519, mode: MaxEncodedLen)
+	fn set_promo_price() -> Weight {
+		Weight::from_parts(18_000_000, 4187)
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+
+	/// Storage: Inference Requests (r:1 w:1)
+	/// Proof: Inference Requests (max_values: None, max_size: Some(225), added: 2700, mode: MaxEncodedLen)
+	/// Storage: Inference ModelQueue (r:1 w:1)
+	/// Proof: Inference ModelQueue (max_values: None, max_size: Some(2074), added: 4549, mode: MaxEncodedLen)
+	/// Storage: AIRegistry Models (r:1 w:1)
+	/// Proof: AIRegistry Models (max_values: None, max_size: Some(1712), added: 4187, mode: MaxEncodedLen)
+	/// Storage: AIRegistry LastActivity (r:0 w:1)
+	/// Proof: AIRegistry LastActivity (max_values: None, max_size: Some(28), added: 2503, mode: MaxEncodedLen)
+	/// Storage: ComputeProviders SlaRecords (r:1 w:1)
+	/// Proof: ComputeProviders SlaRecords (max_values: None, max_size: Some(65), added: 2540, mode: MaxEncodedLen)
+	/// Storage: Inference Referrals (r:0 w:1)
+	/// Proof: Inference Referrals (max_values: None, max_size: Some(60), added: 2535, mode: MaxEncodedLen)
+	/// Storage: Inference TrialRequests (r:1 w:1)
+	/// Proof: Inference TrialRequests (max_values: None, max_size: Some(56), added: 2531, mode: MaxEncodedLen)
+	/// Storage: AIRegistry IncentiveUsage (r:1 w:1)
+	/// Proof: AIRegistry IncentiveUsage (max_values: None, max_size: Some(32), added: 2507, mode: MaxEncodedLen)
+	/// Storage: AIRegistry EraIncentives (r:2 w:2)
+	/// Proof: AIRegistry EraIncentives (max_values: None, max_size: Some(44), added: 2519, mode: MaxEncodedLen)
+	/// Storage: AIRegistry IncentiveEarnings (r:1 w:1)
+	/// Proof: AIRegistry IncentiveEarnings (max_values: None, max_size: Some(40), added: 2515, mode: MaxEncodedLen)
+	/// Storage: AIRegistry IncentivePerEra (r:1 w:0)
+	/// Proof: AIRegistry IncentivePerEra (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
+	/// Storage: AIRegistry IncentiveFunds (r:1 w:1)
+	/// Proof: AIRegistry IncentiveFunds (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
+	/// Storage: AIRegistry RecentUsage (r:1 w:1)
+	/// Proof: AIRegistry RecentUsage (max_values: None, max_size: Some(137), added: 2612, mode: MaxEncodedLen)
+	/// Storage: AIRegistry GlobalStats (r:1 w:1)
+	/// Proof: AIRegistry GlobalStats (max_values: Some(1), max_size: Some(40), added: 535, mode: MaxEncodedLen)
+	/// Storage: AIRegistry MostUsedModels (r:1 w:1)
+	/// Proof: AIRegistry MostUsedModels (max_values: Some(1), max_size: Some(1602), added: 2097, mode: MaxEncodedLen)
+	/// Storage: ComputeProviders Providers (r:1 w:1)
+	/// Proof: ComputeProviders Providers (max_values: None, max_size: Some(94), added: 2569, mode: MaxEncodedLen)
+	/// Storage: Inference ResultCids (r:0 w:1)
+	/// Proof: Inference ResultCids (max_values: None, max_size: Some(154), added: 2629, mode: MaxEncodedLen)
+	/// Storage: Inference AutoReleases (r:1 w:1)
+	/// Proof: Inference AutoReleases (max_values: None, max_size: Some(530), added: 3005, mode: MaxEncodedLen)
+	fn submit_result() -> Weight {
+		Weight::from_parts(62_000_000, 38424)
+			.saturating_add(T::DbWeight::get().reads(16))
+			.saturating_add(T::DbWeight::get().writes(18))
+	}
+
+	/// Storage: AIRegistry Models (r:1 w:0)
+	/// Proof: AIRegistry Models (max_values: None, max_size: Some(1712), added: 4187, mode: MaxEncodedLen)
+	/// Storage: Inference ResponseDeadlines (r:0 w:1)
+	/// Proof: Inference ResponseDeadlines (max_values: None, max_size: Some(28), added: 2503, mode: MaxEncodedLen)
+	fn set_response_deadline() -> Weight {
+		Weight::from_parts(18_000_000, 4187)
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+
+	/// Storage: Inference Requests (r:1 w:1)
+	/// Proof: Inference Requests (max_values: None, max_size: Some(225), added: 2700, mode: MaxEncodedLen)
+	/// Storage: Inference RequestAssets (r:1 w:0)
+	/// Proof: Inference RequestAssets (max_values: None, max_size: Some(28), added: 2503, mode: MaxEncodedLen)
+	/// Storage: Assets Asset (r:1 w:1)
+	/// Proof: Assets Asset (max_values: None, max_size: Some(210), added: 2685, mode: MaxEncodedLen)
+	/// Storage: Assets Account (r:3 w:3)
+	/// Proof: Assets Account (max_values: None, max_size: Some(134), added: 2609, mode: MaxEncodedLen)
+	/// Storage: ComputeProviders Providers (r:1 w:1)
+	/// Proof: ComputeProviders Providers (max_values: None, max_size: Some(94), added: 2569, mode: MaxEncodedLen)
+	/// Storage: Inference ModelQueue (r:1 w:1)
+	/// Proof: Inference ModelQueue (max_values: None, max_size: Some(2074), added: 4549, mode: MaxEncodedLen)
+	/// Storage: ComputeProviders SlaRecords (r:1 w:1)
+	/// Proof: ComputeProviders SlaRecords (max_values: None, max_size: Some(65), added: 2540, mode: MaxEncodedLen)
+	/// Storage: Inference Referrals (r:0 w:1)
+	/// Proof: Inference Referrals (max_values: None, max_size: Some(60), added: 2535, mode: MaxEncodedLen)
+	/// Storage: Inference TrialRequests (r:1 w:1)
+	/// Proof: Inference TrialRequests (max_values: None, max_size: Some(56), added: 2531, mode: MaxEncodedLen)
+	/// Storage: Inference ResponseDeadlines (r:1 w:0)
+	/// Proof: Inference ResponseDeadlines (max_values: None, max_size: Some(28), added: 2503, mode: MaxEncodedLen)
+	/// Storage: System Account (r:2 w:2)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	fn refund_timed_out() -> Weight {
+		Weight::from_parts(88_000_000, 35630)
+			.saturating_add(T::DbWeight::get().reads(13))
+			.saturating_add(T::DbWeight::get().writes(12))
+	}
+
+	/// Storage: AIRegistry Models (r:1 w:0)
+	/// Proof: AIRegistry Models (max_values: None, max_size: Some(1712), added: 4187, mode: MaxEncodedLen)
+	/// Storage: Inference BatchDiscounts (r:0 w:1)
+	/// Proof: Inference BatchDiscounts (max_values: None, max_size: Some(29), added: 2504, mode: MaxEncodedLen)
+	fn set_batch_discount() -> Weight {
+		Weight::from_parts(18_000_000, 4187)
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+
+	/// Storage: AIRegistry Models (r:1 w:0)
+	/// Proof: AIRegistry Models (max_values: None, max_size: Some(1712), added: 4187, mode: MaxEncodedLen)
+	/// Storage: Inference UsdPrices (r:1 w:0)
+	/// Proof: Inference UsdPrices (max_values: None, max_size: Some(32), added: 2507, mode: MaxEncodedLen)
+	/// Storage: Inference Quotas (r:1 w:0)
+	/// Proof: Inference Quotas (max_values: None, max_size: Some(28), added: 2503, mode: MaxEncodedLen)
+	/// Storage: Inference QuotaUsage (r:1 w:1)
+	/// Proof: Inference QuotaUsage (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: Inference NextRequestId (r:1 w:1)
+	/// Proof: Inference NextRequestId (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: Inference ModelQueue (r:1 w:1)
+	/// Proof: Inference ModelQueue (max_values: None, max_size: Some(2074), added: 4549, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: Inference Requests (r:0 w:16)
+	/// Proof: Inference Requests (max_values: None, max_size: Some(225), added: 2700, mode: MaxEncodedLen)
+	/// Storage: Inference DemandPricings (r:1 w:0)
+	/// Proof: Inference DemandPricings (max_values: None, max_size: Some(30), added: 2505, mode: MaxEncodedLen)
+	/// Storage: Inference DemandCounters (r:1 w:1)
+	/// Proof: Inference DemandCounters (max_values: None, max_size: Some(36), added: 2511, mode: MaxEncodedLen)
+	/// Storage: Inference PromoPrices (r:1 w:0)
+	/// Proof: Inference PromoPrices (max_values: None, max_size: Some(44), added: 2519, mode: MaxEncodedLen)
+	/// Storage: Inference BatchDiscounts (r:1 w:0)
+	/// Proof: Inference BatchDiscounts (max_values: None, max_size: Some(29), added: 2504, mode: MaxEncodedLen)
+	/// The range of component `n` is `[1, 16]`.
+	fn request_inference_batch(n: u32, ) -> Weight {
+		Weight::from_parts(50_000_000, 29446)
+			.saturating_add(Weight::from_parts(14_000_000, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(14))
+			.saturating_add(T::DbWeight::get().writes(9))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(n.into())))
+	}
+	/// Storage: AIRegistry Models (r:1 w:0)
+	/// Proof: AIRegistry Models (max_values: None, max_size: Some(1712), added: 4187, mode: MaxEncodedLen)
+	/// Storage: Inference UnitPrices (r:0 w:1)
+	/// Proof: Inference UnitPrices (max_values: None, max_size: Some(40), added: 2515, mode: MaxEncodedLen)
+	fn set_unit_price() -> Weight {
+		Weight::from_parts(18_000_000, 4187)
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+
+	/// Storage: AIRegistry Models (r:1 w:0)
+	/// Proof: AIRegistry Models (max_values: None, max_size: Some(1712), added: 4187, mode: MaxEncodedLen)
+	/// Storage: Inference UnitPrices (r:1 w:0)
+	/// Proof: Inference UnitPrices (max_values: None, max_size: Some(40), added: 2515, mode: MaxEncodedLen)
+	/// Storage: Inference NextSessionId (r:1 w:1)
+	/// Proof: Inference NextSessionId (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: Inference Quotas (r:1 w:0)
+	/// Proof: Inference Quotas (max_values: None, max_size: Some(28), added: 2503, mode: MaxEncodedLen)
+	/// Storage: Inference QuotaUsage (r:1 w:1)
+	/// Proof: Inference QuotaUsage (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: Inference MeteredSessions (r:0 w:1)
+	/// Proof: Inference MeteredSessions (max_values: None, max_size: Some(169), added: 2644, mode: MaxEncodedLen)
+	fn open_metered_session() -> Weight {
+		Weight::from_parts(42_000_000, 15866)
+			.saturating_add(T::DbWeight::get().reads(6))
+			.saturating_add(T::DbWeight::get().writes(4))
+	}
+
+	/// Storage: Inference MeteredSessions (r:1 w:1)
+	/// Proof: Inference MeteredSessions (max_values: None, max_size: Some(169), added: 2644, mode: MaxEncodedLen)
+	/// Storage: AIRegistry Models (r:1 w:0)
+	/// Proof: AIRegistry Models (max_values: None, max_size: Some(1712), added: 4187, mode: MaxEncodedLen)
+	/// Storage: Inference EnclaveRequirements (r:1 w:0)
+	/// Proof: Inference EnclaveRequirements (max_values: None, max_size: Some(57), added: 2532, mode: MaxEncodedLen)
+	/// Storage: ComputeProviders Attestations (r:1 w:0)
+	/// Proof: ComputeProviders Attestations (max_values: None, max_size: Some(85), added: 2560, mode: MaxEncodedLen)
+	/// Storage: ComputeProviders Providers (r:1 w:1)
+	/// Proof: ComputeProviders Providers (max_values: None, max_size: Some(94), added: 2569, mode: MaxEncodedLen)
+	fn accept_metered_session() -> Weight {
+		Weight::from_parts(46_000_000, 14497)
+			.saturating_add(T::DbWeight::get().reads(5))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+
+	/// Storage: Inference MeteredSessions (r:1 w:1)
+	/// Proof: Inference MeteredSessions (max_values: None, max_size: Some(169), added: 2644, mode: MaxEncodedLen)
+	/// Storage: System Account (r:2 w:2)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: Inference Earnings (r:1 w:1)
+	/// Proof: Inference Earnings (max_values: None, max_size: Some(40), added: 2515, mode: MaxEncodedLen)
+	/// Storage: Inference FeesPaid (r:1 w:1)
+	/// Proof: Inference FeesPaid (max_values: None, max_size: Some(88), added: 2563, mode: MaxEncodedLen)
+	/// Storage: AIRegistry GlobalStats (r:1 w:1)
+	/// Proof: AIRegistry GlobalStats (max_values: Some(1), max_size: Some(40), added: 535, mode: MaxEncodedLen)
+	/// Storage: AIRegistry ModelRevenue (r:1 w:1)
+	/// Proof: AIRegistry ModelRevenue (max_values: None, max_size: Some(40), added: 2515, mode: MaxEncodedLen)
+	/// Storage: AIRegistry RecentRevenue (r:1 w:1)
+	/// Proof: AIRegistry RecentRevenue (max_values: None, max_size: Some(305), added: 2780, mode: MaxEncodedLen)
+	fn report_usage() -> Weight {
+		Weight::from_parts(45_000_000, 18246)
+			.saturating_add(T::DbWeight::get().reads(8))
+			.saturating_add(T::DbWeight::get().writes(8))
+	}
+
+	/// Storage: Inference MeteredSessions (r:1 w:1)
+	/// Proof: Inference MeteredSessions (max_values: None, max_size: Some(169), added: 2644, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: ComputeProviders Providers (r:1 w:1)
+	/// Proof: ComputeProviders Providers (max_values: None, max_size: Some(94), added: 2569, mode: MaxEncodedLen)
+	/// Storage: ComputeProviders SlaRecords (r:1 w:1)
+	/// Proof: ComputeProviders SlaRecords (max_values: None, max_size: Some(65), added: 2540, mode: MaxEncodedLen)
+	/// Storage: AIRegistry Models (r:1 w:1)
+	/// Proof: AIRegistry Models (max_values: None, max_size: Some(1712), added: 4187, mode: MaxEncodedLen)
+	/// Storage: AIRegistry LastActivity (r:0 w:1)
+	/// Proof: AIRegistry LastActivity (max_values: None, max_size: Some(28), added: 2503, mode: MaxEncodedLen)
+	/// Storage: AIRegistry IncentiveUsage (r:1 w:1)
+	/// Proof: AIRegistry IncentiveUsage (max_values: None, max_size: Some(32), added: 2507, mode: MaxEncodedLen)
+	/// Storage: AIRegistry EraIncentives (r:2 w:2)
+	/// Proof: AIRegistry EraIncentives (max_values: None, max_size: Some(44), added: 2519, mode: MaxEncodedLen)
+	/// Storage: AIRegistry IncentiveEarnings (r:1 w:1)
+	/// Proof: AIRegistry IncentiveEarnings (max_values: None, max_size: Some(40), added: 2515, mode: MaxEncodedLen)
+	/// Storage: AIRegistry IncentivePerEra (r:1 w:0)
+	/// Proof: AIRegistry IncentivePerEra (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
+	/// Storage: AIRegistry IncentiveFunds (r:1 w:1)
+	/// Proof: AIRegistry IncentiveFunds (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
+	/// Storage: AIRegistry RecentUsage (r:1 w:1)
+	/// Proof: AIRegistry RecentUsage (max_values: None, max_size: Some(137), added: 2612, mode: MaxEncodedLen)
+	/// Storage: AIRegistry GlobalStats (r:1 w:1)
+	/// Proof: AIRegistry GlobalStats (max_values: Some(1), max_size: Some(40), added: 535, mode: MaxEncodedLen)
+	/// Storage: AIRegistry MostUsedModels (r:1 w:1)
+	/// Proof: AIRegistry MostUsedModels (max_values: Some(1), max_size: Some(1602), added: 2097, mode: MaxEncodedLen)
+	fn close_metered_session() -> Weight {
+		Weight::from_parts(52_000_000, 32546)
+			.saturating_add(T::DbWeight::get().reads(15))
+			.saturating_add(T::DbWeight::get().writes(16))
+	}
+
+	/// Storage: Inference MeteredSessions (r:1 w:1)
+	/// Proof: Inference MeteredSessions (max_values: None, max_size: Some(169), added: 2644, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: ComputeProviders Providers (r:1 w:1)
+	/// Proof: ComputeProviders Providers (max_values: None, max_size: Some(94), added: 2569, mode: MaxEncodedLen)
+	/// Storage: ComputeProviders SlaRecords (r:1 w:1)
+	/// Proof: ComputeProviders SlaRecords (max_values: None, max_size: Some(65), added: 2540, mode: MaxEncodedLen)
+	/// Storage: AIRegistry Models (r:1 w:1)
+	/// Proof: AIRegistry Models (max_values: None, max_size: Some(1712), added: 4187, mode: MaxEncodedLen)
+	/// Storage: AIRegistry LastActivity (r:0 w:1)
+	/// Proof: AIRegistry LastActivity (max_values: None, max_size: Some(28), added: 2503, mode: MaxEncodedLen)
+	/// Storage: AIRegistry IncentiveUsage (r:1 w:1)
+	/// Proof: AIRegistry IncentiveUsage (max_values: None, max_size: Some(32), added: 2507, mode: MaxEncodedLen)
+	/// Storage: AIRegistry EraIncentives (r:2 w:2)
+	/// Proof: AIRegistry EraIncentives (max_values: None, max_size: Some(44), added: 2519, mode: MaxEncodedLen)
+	/// Storage: AIRegistry IncentiveEarnings (r:1 w:1)
+	/// Proof: AIRegistry IncentiveEarnings (max_values: None, max_size: Some(40), added: 2515, mode: MaxEncodedLen)
+	/// Storage: AIRegistry IncentivePerEra (r:1 w:0)
+	/// Proof: AIRegistry IncentivePerEra (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
+	/// Storage: AIRegistry IncentiveFunds (r:1 w:1)
+	/// Proof: AIRegistry IncentiveFunds (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
+	/// Storage: AIRegistry RecentUsage (r:1 w:1)
+	/// Proof: AIRegistry RecentUsage (max_values: None, max_size: Some(137), added: 2612, mode: MaxEncodedLen)
+	/// Storage: AIRegistry GlobalStats (r:1 w:1)
+	/// Proof: AIRegistry GlobalStats (max_values: Some(1), max_size: Some(40), added: 535, mode: MaxEncodedLen)
+	/// Storage: AIRegistry MostUsedModels (r:1 w:1)
+	/// Proof: AIRegistry MostUsedModels (max_values: Some(1), max_size: Some(1602), added: 2097, mode: MaxEncodedLen)
+	fn expire_metered_session() -> Weight {
+		Weight::from_parts(52_000_000, 32546)
+			.saturating_add(T::DbWeight::get().reads(15))
+			.saturating_add(T::DbWeight::get().writes(16))
+	}
+	/// Storage: Inference Tabs (r:1 w:1)
+	/// Proof: Inference Tabs (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: Escrow Escrows (r:1 w:1)
+	/// Proof: Escrow Escrows (max_values: None, max_size: Some(86), added: 2561, mode: MaxEncodedLen)
+	/// Storage: System Account (r:2 w:2)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: Inference Earnings (r:1 w:1)
+	/// Proof: Inference Earnings (max_values: None, max_size: Some(40), added: 2515, mode: MaxEncodedLen)
+	fn settle_tab() -> Weight {
+		Weight::from_parts(36_000_000, 6196)
+			.saturating_add(T::DbWeight::get().reads(5))
+			.saturating_add(T::DbWeight::get().writes(5))
+	}
+
+	/// Storage: Inference TabSweep (r:1 w:1)
+	/// Proof: Inference TabSweep (max_values: Some(1), max_size: Some(41), added: 536, mode: MaxEncodedLen)
+	/// Storage: Inference PayoutSweep (r:0 w:1)
+	/// Proof: Inference PayoutSweep (max_values: Some(1), max_size: Some(9), added: 504, mode: MaxEncodedLen)
+	/// Storage: Inference Tabs (r:33 w:32)
+	/// Proof: Inference Tabs (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: System Account (r:33 w:33)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: Inference Earnings (r:32 w:32)
+	/// Proof: Inference Earnings (max_values: None, max_size: Some(40), added: 2515, mode: MaxEncodedLen)
+	/// The range of component `n` is `[0, 32]`.
+	fn settle_tabs(n: u32, ) -> Weight {
+		Weight::from_parts(8_000_000, 6196)
+			// Standard Error: 40_000
+			.saturating_add(Weight::from_parts(30_000_000, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().reads((3_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes(3))
+			.saturating_add(T::DbWeight::get().writes((3_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 2603).saturating_mul(n.into()))
+	}
+
+	/// Storage: Inference Requests (r:1 w:1)
+	/// Proof: Inference Requests (max_values: None, max_size: Some(225), added: 2700, mode: MaxEncodedLen)
+	/// Storage: Escrow NextEscrowId (r:1 w:1)
+	/// Proof: Escrow NextEscrowId (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: Inference AutoReleases (r:1 w:1)
+	/// Proof: Inference AutoReleases (max_values: None, max_size: Some(530), added: 3005, mode: MaxEncodedLen)
+	/// Storage: Escrow Escrows (r:0 w:1)
+	/// Proof: Escrow Escrows (max_values: None, max_size: Some(81), added: 2556, mode: MaxEncodedLen)
+	/// Storage: Inference Challenges (r:0 w:1)
+	/// Proof: Inference Challenges (max_values: None, max_size: Some(108), added: 2583, mode: MaxEncodedLen)
+	/// Storage: AIRegistry Models (r:1 w:0)
+	/// Proof: AIRegistry Models (max_values: None, max_size: Some(1712), added: 4187, mode: MaxEncodedLen)
+	fn challenge_result() -> Weight {
+		Weight::from_parts(35_000_000, 12495)
+			.saturating_add(T::DbWeight::get().reads(5))
+			.saturating_add(T::DbWeight::get().writes(6))
+	}
+
+	/// Storage: Inference Requests (r:1 w:1)
+	/// Proof: Inference Requests (max_values: None, max_size: Some(225), added: 2700, mode: MaxEncodedLen)
+	/// Storage: AIRegistry Models (r:1 w:0)
+	/// Proof: AIRegistry Models (max_values: None, max_size: Some(1712), added: 4187, mode: MaxEncodedLen)
+	/// Storage: Inference Challenges (r:1 w:1)
+	/// Proof: Inference Challenges (max_values: None, max_size: Some(108), added: 2583, mode: MaxEncodedLen)
+	/// Storage: Escrow Escrows (r:1 w:1)
+	/// Proof: Escrow Escrows (max_values: None, max_size: Some(81), added: 2556, mode: MaxEncodedLen)
+	/// Storage: System Account (r:3 w:3)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: Inference RequestAssets (r:1 w:0)
+	/// Proof: Inference RequestAssets (max_values: None, max_size: Some(28), added: 2503, mode: MaxEncodedLen)
+	/// Storage: Assets Asset (r:1 w:1)
+	/// Proof: Assets Asset (max_values: None, max_size: Some(210), added: 2685, mode: MaxEncodedLen)
+	/// Storage: Assets Account (r:3 w:3)
+	/// Proof: Assets Account (max_values: None, max_size: Some(134), added: 2609, mode: MaxEncodedLen)
+	/// Storage: Inference ModelQueue (r:1 w:1)
+	/// Proof: Inference ModelQueue (max_values: None, max_size: Some(2074), added: 4549, mode: MaxEncodedLen)
+	/// Storage: Inference Referrals (r:0 w:1)
+	/// Proof: Inference Referrals (max_values: None, max_size: Some(60), added: 2535, mode: MaxEncodedLen)
+	/// Storage: Inference TrialRequests (r:1 w:1)
+	/// Proof: Inference TrialRequests (max_values: None, max_size: Some(56), added: 2531, mode: MaxEncodedLen)
+	/// Storage: ComputeProviders Providers (r:1 w:1)
+	/// Proof: ComputeProviders Providers (max_values: None, max_size: Some(94), added: 2569, mode: MaxEncodedLen)
+	fn concede_challenge() -> Weight {
+		Weight::from_parts(84_000_000, 40061)
+			.saturating_add(T::DbWeight::get().reads(16))
+			.saturating_add(T::DbWeight::get().writes(14))
+	}
+
+	/// Storage: Inference Requests (r:1 w:1)
+	/// Proof: Inference Requests (max_values: None, max_size: Some(225), added: 2700, mode: MaxEncodedLen)
+	/// Storage: Inference Challenges (r:1 w:1)
+	/// Proof: Inference Challenges (max_values: None, max_size: Some(108), added: 2583, mode: MaxEncodedLen)
+	/// Storage: Escrow Escrows (r:1 w:1)
+	/// Proof: Escrow Escrows (max_values: None, max_size: Some(81), added: 2556, mode: MaxEncodedLen)
+	/// Storage: System Account (r:3 w:3)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: Inference RequestAssets (r:1 w:0)
+	/// Proof: Inference RequestAssets (max_values: None, max_size: Some(28), added: 2503, mode: MaxEncodedLen)
+	/// Storage: Assets Asset (r:1 w:1)
+	/// Proof: Assets Asset (max_values: None, max_size: Some(210), added: 2685, mode: MaxEncodedLen)
+	/// Storage: Assets Account (r:3 w:3)
+	/// Proof: Assets Account (max_values: None, max_size: Some(134), added: 2609, mode: MaxEncodedLen)
+	/// Storage: Inference ModelQueue (r:1 w:1)
+	/// Proof: Inference ModelQueue (max_values: None, max_size: Some(2074), added: 4549, mode: MaxEncodedLen)
+	/// Storage: Inference Referrals (r:0 w:1)
+	/// Proof: Inference Referrals (max_values: None, max_size: Some(60), added: 2535, mode: MaxEncodedLen)
+	/// Storage: Inference TrialRequests (r:1 w:1)
+	/// Proof: Inference TrialRequests (max_values: None, max_size: Some(56), added: 2531, mode: MaxEncodedLen)
+	/// Storage: ComputeProviders Providers (r:1 w:1)
+	/// Proof: ComputeProviders Providers (max_values: None, max_size: Some(94), added: 2569, mode: MaxEncodedLen)
+	fn resolve_challenge() -> Weight {
+		Weight::from_parts(80_000_000, 35874)
+			.saturating_add(T::DbWeight::get().reads(15))
+			.saturating_add(T::DbWeight::get().writes(14))
+	}
+}
+
+// For backwards compatibility and tests
+impl WeightInfo for () {
+	fn request_inference() -> Weight {
+		Weight::from_parts(53_000_000, 26942)
+			.saturating_add(RocksDbWeight::get().reads(11))
+			.saturating_add(RocksDbWeight::get().writes(9))
+	}
+
+	fn request_inference_with_asset() -> Weight {
+		Weight::from_parts(68_000_000, 32354)
+			.saturating_add(RocksDbWeight::get().reads(13))
+			.saturating_add(RocksDbWeight::get().writes(13))
+	}
+
+	fn accept_request() -> Weight {
+		Weight::from_parts(48_000_000, 14565)
+			.saturating_add(RocksDbWeight::get().reads(5))
+			.saturating_add(RocksDbWeight::get().writes(2))
+	}
+
+	fn commit_result() -> Weight {
+		Weight::from_parts(35_000_000, 5286)
+			.saturating_add(RocksDbWeight::get().reads(2))
+			.saturating_add(RocksDbWeight::get().writes(2))
+	}
+
+	fn settle_request(s: u32, ) -> Weight {
+		Weight::from_parts(61_000_000, 45812)
+			.saturating_add(Weight::from_parts(14_000_000, 0).saturating_mul(s.into()))
+			.saturating_add(RocksDbWeight::get().reads(17))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(s.into())))
+			.saturating_add(RocksDbWeight::get().writes(13))
+			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(s.into())))
+			.saturating_add(Weight::from_parts(0, 2609).saturating_mul(s.into()))
+	}
+
+	fn cancel_request() -> Weight {
+		Weight::from_parts(52_000_000, 20186)
+			.saturating_add(RocksDbWeight::get().reads(7))
+			.saturating_add(RocksDbWeight::get().writes(7))
+	}
+
+	fn expire_request() -> Weight {
+		Weight::from_parts(60_000_000, 25312)
+			.saturating_add(RocksDbWeight::get().reads(9))
+			.saturating_add(RocksDbWeight::get().writes(9))
+	}
+
+	fn reveal_result() -> Weight {
+		Weight::from_parts(55_000_000, 35838)
+			.saturating_add(RocksDbWeight::get().reads(15))
+			.saturating_add(RocksDbWeight::get().writes(16))
+	}
+
+	fn submit_inference_proof(n: u32, ) -> Weight {
+		Weight::from_parts(20_000_000, 2700)
+			.saturating_add(Weight::from_parts(1_000, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(1))
+			.saturating_add(RocksDbWeight::get().writes(1))
+	}
+
+	fn set_enclave_requirement() -> Weight {
+		Weight::from_parts(18_000_000, 4187)
+			.saturating_add(RocksDbWeight::get().reads(1))
+			.saturating_add(RocksDbWeight::get().writes(1))
+	}
+
+	fn set_usd_price() -> Weight {
+		Weight::from_parts(18_000_000, 4187)
+			.saturating_add(RocksDbWeight::get().reads(1))
+			.saturating_add(RocksDbWeight::get().writes(1))
+	}
+
+	fn set_asset_price() -> Weight {
+		Weight::from_parts(18_000_000, 4187)
+			.saturating_add(RocksDbWeight::get().reads(1))
+			.saturating_add(RocksDbWeight::get().writes(1))
+	}
+
+	fn set_quota() -> Weight {
+		Weight::from_parts(18_000_000, 4187)
+			.saturating_add(RocksDbWeight::get().reads(1))
+			.saturating_add(RocksDbWeight::get().writes(1))
+	}
+
+	fn create_coupon() -> Weight {
+		Weight::from_parts(20_000_000, 6731)
+			.saturating_add(RocksDbWeight::get().reads(2))
+			.saturating_add(RocksDbWeight::get().writes(1))
+	}
+
+	fn remove_coupon() -> Weight {
+		Weight::from_parts(21_000_000, 6731)
+			.saturating_add(RocksDbWeight::get().reads(2))
+			.saturating_add(RocksDbWeight::get().writes(1))
+	}
+
+	fn request_inference_with_coupon() -> Weight {
+		Weight::from_parts(61_000_000, 29486)
+			.saturating_add(RocksDbWeight::get().reads(11))
+			.saturating_add(RocksDbWeight::get().writes(7))
+	}
+
+	fn set_referral_share() -> Weight {
+		Weight::from_parts(18_000_000, 4187)
+			.saturating_add(RocksDbWeight::get().reads(1))
+			.saturating_add(RocksDbWeight::get().writes(1))
+	}
+
+	fn request_inference_with_referrer() -> Weight {
+		Weight::from_parts(58_000_000, 29445)
+			.saturating_add(RocksDbWeight::get().reads(11))
+			.saturating_add(RocksDbWeight::get().writes(7))
+	}
+
+	fn fund_trial_pool() -> Weight {
+		Weight::from_parts(36_000_000, 9341)
+			.saturating_add(RocksDbWeight::get().reads(3))
+			.saturating_add(RocksDbWeight::get().writes(2))
+	}
+
+	fn close_trial_pool() -> Weight {
+		Weight::from_parts(30_000_000, 5154)
+			.saturating_add(RocksDbWeight::get().reads(2))
+			.saturating_add(RocksDbWeight::get().writes(2))
+	}
+
+	fn request_trial_inference() -> Weight {
+		Weight::from_parts(72_000_000, 34647)
+			.saturating_add(RocksDbWeight::get().reads(13))
+			.saturating_add(RocksDbWeight::get().writes(10))
+	}
+
+	fn set_demand_pricing() -> Weight {
+		Weight::from_parts(19_000_000, 4187)
+			.saturating_add(RocksDbWeight::get().reads(1))
+			.saturating_add(RocksDbWeight::get().writes(2))
+	}
+
+	fn claim_earnings(s: u32, ) -> Weight {
+		Weight::from_parts(26_000_000, 21386)
+			.saturating_add(Weight::from_parts(21_000_000, 0).saturating_mul(s.into()))
+			.saturating_add(RocksDbWeight::get().reads(7))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(s.into())))
+			.saturating_add(RocksDbWeight::get().writes(2))
+			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(s.into())))
+			.saturating_add(Weight::from_parts(0, 2603).saturating_mul(s.into()))
+	}
+
+	fn sweep_earnings(n: u32, ) -> Weight {
+		Weight::from_parts(30_000_000, 31652)
+			.saturating_add(Weight::from_parts(230_000_000, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(13))
+			.saturating_add(RocksDbWeight::get().reads((16_u64).saturating_mul(n.into())))
+			.saturating_add(RocksDbWeight::get().writes(12))
+			.saturating_add(RocksDbWeight::get().writes((11_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 44813).saturating_mul(n.into()))
+	}
+
+	fn prioritize_inference() -> Weight {
+		Weight::from_parts(17_000_000, 16832)
+			.saturating_add(RocksDbWeight::get().reads(6))
+	}
+
+	fn set_promo_price() -> Weight {
+		Weight::from_parts(18_000_000, 4187)
+			.saturating_add(RocksDbWeight::get().reads(1))
+			.saturating_add(RocksDbWeight::get().writes(1))
+	}
+
+	fn submit_result() -> Weight {
+		Weight::from_parts(62_000_000, 38424)
+			.saturating_add(RocksDbWeight::get().reads(16))
+			.saturating_add(RocksDbWeight::get().writes(18))
+	}
+
+	fn set_response_deadline() -> Weight {
+		Weight::from_parts(18_000_000, 4187)
+			.saturating_add(RocksDbWeight::get().reads(1))
+			.saturating_add(RocksDbWeight::get().writes(1))
+	}
+
+	fn refund_timed_out() -> Weight {
+		Weight::from_parts(88_000_000, 35630)
+			.saturating_add(RocksDbWeight::get().reads(13))
+			.saturating_add(RocksDbWeight::get().writes(12))
+	}
+
+	fn set_batch_discount() -> Weight {
+		Weight::from_parts(18_000_000, 4187)
+			.saturating_add(RocksDbWeight::get().reads(1))
+			.saturating_add(RocksDbWeight::get().writes(1))
+	}
+
+	fn request_inference_batch(n: u32, ) -> Weight {
+		Weight::from_parts(50_000_000, 29446)
+			.saturating_add(Weight::from_parts(14_000_000, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(14))
+			.saturating_add(RocksDbWeight::get().writes(9))
+			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(n.into())))
+	}
+
+	fn set_unit_price() -> Weight {
+		Weight::from_parts(18_000_000, 4187)
+			.saturating_add(RocksDbWeight::get().reads(1))
+			.saturating_add(RocksDbWeight::get().writes(1))
+	}
+
+	fn open_metered_session() -> Weight {
+		Weight::from_parts(42_000_000, 15866)
+			.saturating_add(RocksDbWeight::get().reads(6))
+			.saturating_add(RocksDbWeight::get().writes(4))
+	}
+
+	fn accept_metered_session() -> Weight {
+		Weight::from_parts(46_000_000, 14497)
+			.saturating_add(RocksDbWeight::get().reads(5))
+			.saturating_add(RocksDbWeight::get().writes(2))
+	}
+
+	fn report_usage() -> Weight {
+		Weight::from_parts(45_000_000, 18246)
+			.saturating_add(RocksDbWeight::get().reads(8))
+			.saturating_add(RocksDbWeight::get().writes(8))
+	}
+
+	fn close_metered_session() -> Weight {
+		Weight::from_parts(52_000_000, 32546)
+			.saturating_add(RocksDbWeight::get().reads(15))
+			.saturating_add(RocksDbWeight::get().writes(16))
+	}
+
+	fn expire_metered_session() -> Weight {
+		Weight::from_parts(52_000_000, 32546)
+			.saturating_add(RocksDbWeight::get().reads(15))
+			.saturating_add(RocksDbWeight::get().writes(16))
+	}
+
+	fn settle_tab() -> Weight {
+		Weight::from_parts(36_000_000, 6196)
+			.saturating_add(RocksDbWeight::get().reads(5))
+			.saturating_add(RocksDbWeight::get().writes(5))
+	}
+
+	fn settle_tabs(n: u32, ) -> Weight {
+		Weight::from_parts(8_000_000, 6196)
+			.saturating_add(Weight::from_parts(30_000_000, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(3))
+			.saturating_add(RocksDbWeight::get().reads((3_u64).saturating_mul(n.into())))
+			.saturating_add(RocksDbWeight::get().writes(3))
+			.saturating_add(RocksDbWeight::get().writes((3_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 2603).saturating_mul(n.into()))
+	}
+
+	fn challenge_result() -> Weight {
+		Weight::from_parts(35_000_000, 12495)
+			.saturating_add(RocksDbWeight::get().reads(5))
+			.saturating_add(RocksDbWeight::get().writes(6))
+	}
+
+	fn concede_challenge() -> Weight {
+		Weight::from_parts(84_000_000, 40061)
+			.saturating_add(RocksDbWeight::get().reads(16))
+			.saturating_add(RocksDbWeight::get().writes(14))
+	}
+
+	fn resolve_challenge() -> Weight {
+		Weight::from_parts(80_000_000, 35874)
+			.saturating_add(RocksDbWeight::get().reads(15))
+			.saturating_add(RocksDbWeight::get().writes(14))
+	}
+}
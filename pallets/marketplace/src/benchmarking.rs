@@ -0,0 +1,237 @@
+//! Benchmarking setup for pallet-marketplace
+
+#![cfg(feature = "runtime-benchmarks")]
+
+use super::*;
+use crate::pallet::BalanceOf;
+use alloc::vec::Vec;
+use frame_benchmarking::v2::*;
+use frame_support::traits::{Currency, Get, Hooks};
+use frame_system::{pallet_prelude::BlockNumberFor, RawOrigin};
+use pallet_ai_registry::ModelId;
+use sp_runtime::traits::Saturating;
+
+/// Price models are listed at
+fn price<T: Config>() -> BalanceOf<T> {
+	1_000u32.into()
+}
+
+/// Give `who` enough free balance to list and buy a model
+fn fund_account<T: Config>(who: &T::AccountId) {
+	let balance: BalanceOf<T> = T::ListingDeposit::get()
+		.saturating_add(price::<T>())
+		.saturating_mul(10u32.into())
+		.saturating_add(T::Currency::minimum_balance());
+	let _ = T::Currency::deposit_creating(who, balance);
+}
+
+/// Create a model owned by `seller` and list it
+fn setup_listing<T: Config>(seller: &T::AccountId) -> ModelId {
+	let model_id = T::Models::create_model(seller);
+	fund_account::<T>(seller);
+	let _ =
+		Pallet::<T>::list_model(RawOrigin::Signed(seller.clone()).into(), model_id, price::<T>());
+	model_id
+}
+
+/// Create a model owned by `seller` and auction it for `duration` blocks
+fn setup_auction<T: Config>(seller: &T::AccountId, duration: BlockNumberFor<T>) -> ModelId {
+	let model_id = T::Models::create_model(seller);
+	fund_account::<T>(seller);
+	let _ = Pallet::<T>::start_auction(
+		RawOrigin::Signed(seller.clone()).into(),
+		model_id,
+		price::<T>(),
+		duration,
+	);
+	model_id
+}
+
+/// Fund `bidder` for winning a model and have them bid `amount` on it
+fn place_bid<T: Config>(bidder: &T::AccountId, model_id: ModelId, amount: BalanceOf<T>) {
+	// Funds the bidder for the model deposit they take over
+	let _ = T::Models::create_model(bidder);
+	fund_account::<T>(bidder);
+	let _ = Pallet::<T>::bid(RawOrigin::Signed(bidder.clone()).into(), model_id, amount);
+}
+
+#[benchmarks]
+mod benchmarks {
+	use super::*;
+
+	#[benchmark]
+	fn list_model() {
+		let seller: T::AccountId = whitelisted_caller();
+		let model_id = T::Models::create_model(&seller);
+		fund_account::<T>(&seller);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(seller), model_id, price::<T>());
+
+		assert!(Listings::<T>::contains_key(model_id));
+	}
+
+	#[benchmark]
+	fn delist_model() {
+		let seller: T::AccountId = whitelisted_caller();
+		let model_id = setup_listing::<T>(&seller);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(seller), model_id);
+
+		assert!(!Listings::<T>::contains_key(model_id));
+	}
+
+	#[benchmark]
+	fn buy_model() {
+		let seller: T::AccountId = account("seller", 0, 0);
+		let model_id = setup_listing::<T>(&seller);
+		let buyer: T::AccountId = whitelisted_caller();
+		// Funds the buyer for the model deposit they take over
+		let _ = T::Models::create_model(&buyer);
+		fund_account::<T>(&buyer);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(buyer.clone()), model_id, price::<T>());
+
+		assert_eq!(T::Models::owner_of(model_id), Some(buyer));
+	}
+
+	#[benchmark]
+	fn start_auction() {
+		let seller: T::AccountId = whitelisted_caller();
+		let model_id = T::Models::create_model(&seller);
+		fund_account::<T>(&seller);
+		let duration = T::MaxAuctionDuration::get();
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(seller), model_id, price::<T>(), duration);
+
+		assert!(Auctions::<T>::contains_key(model_id));
+	}
+
+	#[benchmark]
+	fn bid() {
+		let seller: T::AccountId = account("seller", 0, 0);
+		let model_id = setup_auction::<T>(&seller, T::MaxAuctionDuration::get());
+		place_bid::<T>(&account("bidder", 0, 0), model_id, price::<T>());
+		let bidder: T::AccountId = whitelisted_caller();
+		fund_account::<T>(&bidder);
+		let amount = price::<T>().saturating_add(1u32.into());
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(bidder.clone()), model_id, amount);
+
+		assert_eq!(
+			Auctions::<T>::get(model_id).and_then(|a| a.best_bid).map(|b| (b.bidder, b.amount)),
+			Some((bidder, amount))
+		);
+	}
+
+	#[benchmark]
+	fn settle_auctions(n: Linear<0, { T::MaxAuctionsPerBlock::get() }>) {
+		let duration = T::MaxAuctionDuration::get();
+		let mut sold = Vec::new();
+		for i in 0..n {
+			let model_id = setup_auction::<T>(&account("seller", i, 0), duration);
+			let bidder: T::AccountId = account("bidder", i, 0);
+			place_bid::<T>(&bidder, model_id, price::<T>());
+			sold.push((model_id, bidder));
+		}
+		let end = frame_system::Pallet::<T>::block_number().saturating_add(duration);
+
+		#[block]
+		{
+			Pallet::<T>::on_initialize(end);
+		}
+
+		for (model_id, bidder) in sold {
+			assert_eq!(T::Models::owner_of(model_id), Some(bidder));
+		}
+	}
+
+	#[benchmark]
+	fn lease_model() {
+		let lessor: T::AccountId = whitelisted_caller();
+		let model_id = T::Models::create_model(&lessor);
+		let lessee: T::AccountId = account("lessee", 0, 0);
+		let duration = T::MaxLeaseDuration::get();
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(lessor), model_id, lessee, duration, price::<T>());
+
+		assert!(LeaseOffers::<T>::contains_key(model_id));
+	}
+
+	#[benchmark]
+	fn accept_lease() {
+		let lessor: T::AccountId = account("lessor", 0, 0);
+		let model_id = T::Models::create_model(&lessor);
+		let lessee: T::AccountId = whitelisted_caller();
+		fund_account::<T>(&lessee);
+		let _ = Pallet::<T>::lease_model(
+			RawOrigin::Signed(lessor).into(),
+			model_id,
+			lessee.clone(),
+			T::MaxLeaseDuration::get(),
+			price::<T>(),
+		);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(lessee), model_id, price::<T>());
+
+		assert!(Leases::<T>::contains_key(model_id));
+	}
+
+	#[benchmark]
+	fn cancel_lease_offer() {
+		let lessor: T::AccountId = whitelisted_caller();
+		let model_id = T::Models::create_model(&lessor);
+		let _ = Pallet::<T>::lease_model(
+			RawOrigin::Signed(lessor.clone()).into(),
+			model_id,
+			account("lessee", 0, 0),
+			T::MaxLeaseDuration::get(),
+			price::<T>(),
+		);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(lessor), model_id);
+
+		assert!(!LeaseOffers::<T>::contains_key(model_id));
+	}
+
+	#[benchmark]
+	fn end_leases(n: Linear<0, { T::MaxLeasesPerBlock::get() }>) {
+		let duration = T::MaxLeaseDuration::get();
+		let mut leased = Vec::new();
+		for i in 0..n {
+			let lessor: T::AccountId = account("lessor", i, 0);
+			let model_id = T::Models::create_model(&lessor);
+			let lessee: T::AccountId = account("lessee", i, 0);
+			fund_account::<T>(&lessee);
+			let _ = Pallet::<T>::lease_model(
+				RawOrigin::Signed(lessor).into(),
+				model_id,
+				lessee.clone(),
+				duration,
+				price::<T>(),
+			);
+			let _ =
+				Pallet::<T>::accept_lease(RawOrigin::Signed(lessee).into(), model_id, price::<T>());
+			leased.push(model_id);
+		}
+		let end = frame_system::Pallet::<T>::block_number().saturating_add(duration);
+
+		#[block]
+		{
+			Pallet::<T>::on_initialize(end);
+		}
+
+		for model_id in leased {
+			assert!(!Leases::<T>::contains_key(model_id));
+		}
+	}
+
+	impl_benchmark_test_suite!(Pallet, crate::mock::new_test_ext(), crate::mock::Test);
+}
@@ -0,0 +1,774 @@
+//! # Marketplace Pallet
+//!
+//! Fixed-price sales of models from the AI registry.
+//!
+//! ## Overview
+//!
+//! A model owner lists a model at a price and anyone can buy it for that price.
+//! The purchase is atomic: the buyer pays, the model's creator receives their
+//! royalty, and the buyer becomes the owner of the model and its NFT, reserving the
+//! model's deposit in place of the seller.
+//!
+//! ## Listing deposits
+//!
+//! Listing reserves `ListingDeposit` from the seller, returned when the model is
+//! sold or delisted. A listing goes stale once the seller no longer owns the model,
+//! e.g. after transferring it elsewhere; stale listings cannot be bought and anyone
+//! may remove them, returning the deposit to the seller.
+//!
+//! Listing and auction deposits and bids are held through the escrow pallet.
+//!
+//! ## Auctions
+//!
+//! Instead of listing it, an owner can auction a model to the highest bidder. Bids
+//! are reserved from the bidder and released when they are outbid. The auction is
+//! settled automatically at the start of its end block: the best bid buys the
+//! model like a fixed-price purchase. Auctions without bids, or whose seller no
+//! longer owns the model, end without a sale and the best bid is released.
+//!
+//! ## Leases
+//!
+//! An owner can lease a model out: the lessee pays a fee up front and collects all
+//! of the model's inference revenue until the lease ends, after which it reverts to
+//! the model's owners automatically. Leases survive the model being sold.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+
+pub use pallet::*;
+
+#[cfg(test)]
+mod mock;
+
+#[cfg(test)]
+mod tests;
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+
+pub mod weights;
+pub use weights::*;
+
+pub mod types;
+pub use types::*;
+
+pub mod traits;
+pub use traits::*;
+
+#[frame_support::pallet]
+pub mod pallet {
+	use super::*;
+	use frame_support::{
+		pallet_prelude::*,
+		traits::{Currency, ExistenceRequirement},
+	};
+	use frame_system::pallet_prelude::*;
+	use pallet_ai_registry::ModelId;
+	use pallet_escrow::Escrow;
+	use sp_runtime::traits::{Saturating, Zero};
+
+	pub(crate) type BalanceOf<T> =
+		<<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
+	pub(crate) type ListingOf<T> = Listing<<T as frame_system::Config>::AccountId, BalanceOf<T>>;
+	pub(crate) type AuctionOf<T> =
+		Auction<<T as frame_system::Config>::AccountId, BalanceOf<T>, BlockNumberFor<T>>;
+	pub(crate) type LeaseOfferOf<T> =
+		LeaseOffer<<T as frame_system::Config>::AccountId, BalanceOf<T>, BlockNumberFor<T>>;
+	pub(crate) type LeaseOf<T> =
+		Lease<<T as frame_system::Config>::AccountId, BalanceOf<T>, BlockNumberFor<T>>;
+
+	#[pallet::pallet]
+	pub struct Pallet<T>(_);
+
+	/// Configuration trait for the Marketplace pallet
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		/// The overarching event type
+		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+		/// Weight information for extrinsics
+		type WeightInfo: WeightInfo;
+
+		/// Currency used for payments
+		type Currency: Currency<Self::AccountId>;
+
+		/// Escrow holding listing deposits and bids in `Currency`
+		type Escrow: Escrow<Self::AccountId, BalanceOf<Self>, BlockNumberFor<Self>>;
+
+		/// Registered models being traded
+		type Models: ModelMarket<Self::AccountId, BalanceOf<Self>>;
+
+		/// Deposit reserved from a seller for each listing
+		#[pallet::constant]
+		type ListingDeposit: Get<BalanceOf<Self>>;
+
+		/// Longest an auction can run, in blocks
+		#[pallet::constant]
+		type MaxAuctionDuration: Get<BlockNumberFor<Self>>;
+
+		/// Maximum number of auctions ending in the same block
+		#[pallet::constant]
+		type MaxAuctionsPerBlock: Get<u32>;
+
+		/// Longest a model can be leased out, in blocks
+		#[pallet::constant]
+		type MaxLeaseDuration: Get<BlockNumberFor<Self>>;
+
+		/// Maximum number of leases ending in the same block
+		#[pallet::constant]
+		type MaxLeasesPerBlock: Get<u32>;
+	}
+
+	/// Models for sale
+	#[pallet::storage]
+	pub type Listings<T: Config> = StorageMap<_, Blake2_128Concat, ModelId, ListingOf<T>>;
+
+	/// Running auctions
+	#[pallet::storage]
+	pub type Auctions<T: Config> = StorageMap<_, Blake2_128Concat, ModelId, AuctionOf<T>>;
+
+	/// Auctions to settle at the start of each block
+	#[pallet::storage]
+	pub type AuctionsEnding<T: Config> = StorageMap<
+		_,
+		Twox64Concat,
+		BlockNumberFor<T>,
+		BoundedVec<ModelId, T::MaxAuctionsPerBlock>,
+		ValueQuery,
+	>;
+
+	/// Pending lease offers, one per model
+	#[pallet::storage]
+	pub type LeaseOffers<T: Config> = StorageMap<_, Blake2_128Concat, ModelId, LeaseOfferOf<T>>;
+
+	/// Running leases
+	#[pallet::storage]
+	pub type Leases<T: Config> = StorageMap<_, Blake2_128Concat, ModelId, LeaseOf<T>>;
+
+	/// Leases to end at the start of each block
+	#[pallet::storage]
+	pub type LeasesEnding<T: Config> = StorageMap<
+		_,
+		Twox64Concat,
+		BlockNumberFor<T>,
+		BoundedVec<ModelId, T::MaxLeasesPerBlock>,
+		ValueQuery,
+	>;
+
+	/// Events emitted by this pallet
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// A model was listed for sale
+		/// [model_id, seller, price]
+		ModelListed { model_id: ModelId, seller: T::AccountId, price: BalanceOf<T> },
+		/// A listing was removed without a sale
+		/// [model_id, seller]
+		ModelDelisted { model_id: ModelId, seller: T::AccountId },
+		/// A listed model was bought
+		/// [model_id, seller, buyer, price]
+		ModelBought {
+			model_id: ModelId,
+			seller: T::AccountId,
+			buyer: T::AccountId,
+			price: BalanceOf<T>,
+		},
+		/// An auction of a model started
+		/// [model_id, seller, min_bid, end]
+		AuctionStarted {
+			model_id: ModelId,
+			seller: T::AccountId,
+			min_bid: BalanceOf<T>,
+			end: BlockNumberFor<T>,
+		},
+		/// A bid was placed, releasing the previous best bid
+		/// [model_id, bidder, amount]
+		BidPlaced { model_id: ModelId, bidder: T::AccountId, amount: BalanceOf<T> },
+		/// An auction ended with a sale to the best bidder
+		/// [model_id, seller, winner, price]
+		AuctionSettled {
+			model_id: ModelId,
+			seller: T::AccountId,
+			winner: T::AccountId,
+			price: BalanceOf<T>,
+		},
+		/// An auction ended without a sale
+		/// [model_id, seller]
+		AuctionUnsold { model_id: ModelId, seller: T::AccountId },
+		/// An owner offered to lease out a model
+		/// [model_id, lessor, lessee, duration, fee]
+		LeaseOffered {
+			model_id: ModelId,
+			lessor: T::AccountId,
+			lessee: T::AccountId,
+			duration: BlockNumberFor<T>,
+			fee: BalanceOf<T>,
+		},
+		/// A lease offer was withdrawn
+		/// [model_id]
+		LeaseOfferCancelled { model_id: ModelId },
+		/// A lease started; the lessee collects the model's revenue until `end`
+		/// [model_id, lessor, lessee, fee, end]
+		LeaseStarted {
+			model_id: ModelId,
+			lessor: T::AccountId,
+			lessee: T::AccountId,
+			fee: BalanceOf<T>,
+			end: BlockNumberFor<T>,
+		},
+		/// A lease ended and revenue reverted to the model's owners
+		/// [model_id, lessee]
+		LeaseEnded { model_id: ModelId, lessee: T::AccountId },
+	}
+
+	/// Errors that can occur in this pallet
+	#[pallet::error]
+	pub enum Error<T> {
+		/// Model doesn't exist or its ownership is not settled
+		ModelNotFound,
+		/// Only the model owner can list it
+		NotModelOwner,
+		/// Model is already listed
+		AlreadyListed,
+		/// Model is not listed
+		NotListed,
+		/// Only the seller can delist a listing that is not stale
+		NotSeller,
+		/// Sellers cannot buy their own listing
+		CannotBuyOwnModel,
+		/// Asking price is above the buyer's limit
+		PriceAboveLimit,
+		/// Seller no longer owns the listed model
+		StaleListing,
+		/// Caller cannot reserve the listing deposit
+		InsufficientDeposit,
+		/// Model is being auctioned
+		AlreadyAuctioned,
+		/// Model is not being auctioned
+		NoAuction,
+		/// Duration is zero or above `MaxAuctionDuration`
+		InvalidDuration,
+		/// Too many auctions end in the same block
+		TooManyAuctions,
+		/// The auction has ended
+		AuctionEnded,
+		/// Sellers cannot bid on their own auction
+		CannotBidOnOwnModel,
+		/// Bid is below the minimum or not above the best bid
+		BidTooLow,
+		/// Caller cannot reserve the bid
+		InsufficientBalance,
+		/// Model is already leased out
+		AlreadyLeased,
+		/// A lease offer for the model is pending
+		LeaseOfferPending,
+		/// No lease offer for the model
+		NoLeaseOffer,
+		/// Only the account the offer was made to can accept it
+		NotLessee,
+		/// Lessor no longer owns the model
+		StaleLeaseOffer,
+		/// Lease fee exceeds the lessee's limit
+		FeeAboveLimit,
+		/// Too many leases end in the same block
+		TooManyLeases,
+	}
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		fn on_initialize(now: BlockNumberFor<T>) -> Weight {
+			let ending = AuctionsEnding::<T>::take(now);
+			let count = ending.len() as u32;
+			for model_id in ending {
+				Self::settle_auction(model_id);
+			}
+			let expiring = LeasesEnding::<T>::take(now);
+			let leases = expiring.len() as u32;
+			for model_id in expiring {
+				Self::end_lease(model_id);
+			}
+			T::WeightInfo::settle_auctions(count).saturating_add(T::WeightInfo::end_leases(leases))
+		}
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// List a model for sale at a fixed price
+		///
+		/// # Arguments
+		/// * `origin` - Must be the model owner
+		/// * `model_id` - ID of the model
+		/// * `price` - Asking price
+		///
+		/// # Errors
+		/// * `ModelNotFound` - Model doesn't exist or its ownership is not settled
+		/// * `NotModelOwner` - Caller doesn't own the model
+		/// * `AlreadyListed` - Model is already listed
+		/// * `AlreadyAuctioned` - Model is being auctioned
+		/// * `InsufficientDeposit` - Caller cannot reserve `ListingDeposit`
+		///
+		/// # Events
+		/// * `ModelListed` - Listing created
+		#[pallet::call_index(0)]
+		#[pallet::weight(T::WeightInfo::list_model())]
+		pub fn list_model(
+			origin: OriginFor<T>,
+			model_id: ModelId,
+			price: BalanceOf<T>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let owner = T::Models::owner_of(model_id).ok_or(Error::<T>::ModelNotFound)?;
+			ensure!(owner == who, Error::<T>::NotModelOwner);
+			ensure!(!Listings::<T>::contains_key(model_id), Error::<T>::AlreadyListed);
+			ensure!(!Auctions::<T>::contains_key(model_id), Error::<T>::AlreadyAuctioned);
+
+			let deposit = T::Escrow::lock(&who, T::ListingDeposit::get(), None)
+				.map_err(|_| Error::<T>::InsufficientDeposit)?;
+
+			Listings::<T>::insert(model_id, Listing { seller: who.clone(), price, deposit });
+
+			Self::deposit_event(Event::ModelListed { model_id, seller: who, price });
+
+			Ok(())
+		}
+
+		/// Remove a listing and return its deposit to the seller
+		///
+		/// # Arguments
+		/// * `origin` - The seller, or anyone once the listing is stale
+		/// * `model_id` - ID of the listed model
+		///
+		/// # Errors
+		/// * `NotListed` - Model is not listed
+		/// * `NotSeller` - Caller is not the seller and the listing is not stale
+		///
+		/// # Events
+		/// * `ModelDelisted` - Listing removed
+		#[pallet::call_index(1)]
+		#[pallet::weight(T::WeightInfo::delist_model())]
+		pub fn delist_model(origin: OriginFor<T>, model_id: ModelId) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let listing = Listings::<T>::get(model_id).ok_or(Error::<T>::NotListed)?;
+			ensure!(
+				listing.seller == who
+					|| T::Models::owner_of(model_id).as_ref() != Some(&listing.seller),
+				Error::<T>::NotSeller
+			);
+
+			T::Escrow::refund(listing.deposit)?;
+			Listings::<T>::remove(model_id);
+
+			Self::deposit_event(Event::ModelDelisted { model_id, seller: listing.seller });
+
+			Ok(())
+		}
+
+		/// Buy a listed model at its asking price
+		///
+		/// The buyer pays the price, minus the creator's royalty, to the seller and
+		/// takes over the model, reserving its deposit.
+		///
+		/// # Arguments
+		/// * `origin` - The buyer
+		/// * `model_id` - ID of the listed model
+		/// * `max_price` - Most the buyer is willing to pay
+		///
+		/// # Errors
+		/// * `NotListed` - Model is not listed
+		/// * `CannotBuyOwnModel` - Caller is the seller
+		/// * `PriceAboveLimit` - Asking price exceeds `max_price`
+		/// * `StaleListing` - Seller no longer owns the model
+		/// * Any error from paying for the model or reserving its deposit
+		///
+		/// # Events
+		/// * `ModelBought` - Model changed hands
+		#[pallet::call_index(2)]
+		#[pallet::weight(T::WeightInfo::buy_model())]
+		pub fn buy_model(
+			origin: OriginFor<T>,
+			model_id: ModelId,
+			max_price: BalanceOf<T>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let listing = Listings::<T>::get(model_id).ok_or(Error::<T>::NotListed)?;
+			ensure!(listing.seller != who, Error::<T>::CannotBuyOwnModel);
+			ensure!(listing.price <= max_price, Error::<T>::PriceAboveLimit);
+			ensure!(
+				T::Models::owner_of(model_id).as_ref() == Some(&listing.seller),
+				Error::<T>::StaleListing
+			);
+
+			T::Escrow::refund(listing.deposit)?;
+			T::Models::sell(model_id, &who, listing.price)?;
+			Listings::<T>::remove(model_id);
+
+			Self::deposit_event(Event::ModelBought {
+				model_id,
+				seller: listing.seller,
+				buyer: who,
+				price: listing.price,
+			});
+
+			Ok(())
+		}
+
+		/// Auction a model to the highest bidder
+		///
+		/// The auction is settled at the start of block `now + duration`.
+		///
+		/// # Arguments
+		/// * `origin` - Must be the model owner
+		/// * `model_id` - ID of the model
+		/// * `min_bid` - Lowest acceptable bid
+		/// * `duration` - Blocks until settlement, at most `MaxAuctionDuration`
+		///
+		/// # Errors
+		/// * `ModelNotFound` - Model doesn't exist or its ownership is not settled
+		/// * `NotModelOwner` - Caller doesn't own the model
+		/// * `AlreadyListed` - Model is listed for a fixed price
+		/// * `AlreadyAuctioned` - Model is already being auctioned
+		/// * `InvalidDuration` - `duration` is zero or too long
+		/// * `TooManyAuctions` - `MaxAuctionsPerBlock` auctions already end then
+		/// * `InsufficientDeposit` - Caller cannot reserve `ListingDeposit`
+		///
+		/// # Events
+		/// * `AuctionStarted` - Auction created
+		#[pallet::call_index(3)]
+		#[pallet::weight(T::WeightInfo::start_auction())]
+		pub fn start_auction(
+			origin: OriginFor<T>,
+			model_id: ModelId,
+			min_bid: BalanceOf<T>,
+			duration: BlockNumberFor<T>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let owner = T::Models::owner_of(model_id).ok_or(Error::<T>::ModelNotFound)?;
+			ensure!(owner == who, Error::<T>::NotModelOwner);
+			ensure!(!Listings::<T>::contains_key(model_id), Error::<T>::AlreadyListed);
+			ensure!(!Auctions::<T>::contains_key(model_id), Error::<T>::AlreadyAuctioned);
+			ensure!(
+				!duration.is_zero() && duration <= T::MaxAuctionDuration::get(),
+				Error::<T>::InvalidDuration
+			);
+
+			let end = frame_system::Pallet::<T>::block_number().saturating_add(duration);
+			AuctionsEnding::<T>::try_mutate(end, |ending| ending.try_push(model_id))
+				.map_err(|_| Error::<T>::TooManyAuctions)?;
+
+			let deposit = T::Escrow::lock(&who, T::ListingDeposit::get(), None)
+				.map_err(|_| Error::<T>::InsufficientDeposit)?;
+
+			Auctions::<T>::insert(
+				model_id,
+				Auction { seller: who.clone(), min_bid, end, best_bid: None, deposit },
+			);
+
+			Self::deposit_event(Event::AuctionStarted { model_id, seller: who, min_bid, end });
+
+			Ok(())
+		}
+
+		/// Bid on an auctioned model
+		///
+		/// The bid is held in escrow until the bidder is outbid or the auction is settled.
+		///
+		/// # Arguments
+		/// * `origin` - The bidder
+		/// * `model_id` - ID of the auctioned model
+		/// * `amount` - Bid, at least the minimum bid and above the best bid
+		///
+		/// # Errors
+		/// * `NoAuction` - Model is not being auctioned
+		/// * `AuctionEnded` - Auction is being settled
+		/// * `CannotBidOnOwnModel` - Caller is the seller
+		/// * `BidTooLow` - Bid is below the minimum or not above the best bid
+		/// * `InsufficientBalance` - Caller cannot reserve the bid
+		///
+		/// # Events
+		/// * `BidPlaced` - Bid is the new best bid
+		#[pallet::call_index(4)]
+		#[pallet::weight(T::WeightInfo::bid())]
+		pub fn bid(
+			origin: OriginFor<T>,
+			model_id: ModelId,
+			amount: BalanceOf<T>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			Auctions::<T>::try_mutate(model_id, |maybe_auction| -> DispatchResult {
+				let auction = maybe_auction.as_mut().ok_or(Error::<T>::NoAuction)?;
+				ensure!(
+					frame_system::Pallet::<T>::block_number() < auction.end,
+					Error::<T>::AuctionEnded
+				);
+				ensure!(auction.seller != who, Error::<T>::CannotBidOnOwnModel);
+				ensure!(amount >= auction.min_bid, Error::<T>::BidTooLow);
+				if let Some(best) = &auction.best_bid {
+					ensure!(amount > best.amount, Error::<T>::BidTooLow);
+				}
+
+				let escrow = T::Escrow::lock(&who, amount, None)
+					.map_err(|_| Error::<T>::InsufficientBalance)?;
+				if let Some(outbid) =
+					auction.best_bid.replace(Bid { bidder: who.clone(), amount, escrow })
+				{
+					T::Escrow::refund(outbid.escrow)?;
+				}
+				Ok(())
+			})?;
+
+			Self::deposit_event(Event::BidPlaced { model_id, bidder: who, amount });
+
+			Ok(())
+		}
+
+		/// Offer to lease out a model
+		///
+		/// The lessee starts the lease by accepting the offer and paying `fee`.
+		///
+		/// # Arguments
+		/// * `origin` - Must be the model owner
+		/// * `model_id` - ID of the model
+		/// * `lessee` - Account the offer is made to
+		/// * `duration` - Length of the lease, at most `MaxLeaseDuration`
+		/// * `fee` - Fee paid by the lessee to the owner
+		///
+		/// # Errors
+		/// * `ModelNotFound` - Model doesn't exist or its ownership is not settled
+		/// * `NotModelOwner` - Caller doesn't own the model
+		/// * `AlreadyLeased` - Model is leased out
+		/// * `LeaseOfferPending` - An offer for the model is pending
+		/// * `InvalidDuration` - `duration` is zero or too long
+		///
+		/// # Events
+		/// * `LeaseOffered` - Offer recorded
+		#[pallet::call_index(5)]
+		#[pallet::weight(T::WeightInfo::lease_model())]
+		pub fn lease_model(
+			origin: OriginFor<T>,
+			model_id: ModelId,
+			lessee: T::AccountId,
+			duration: BlockNumberFor<T>,
+			fee: BalanceOf<T>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let owner = T::Models::owner_of(model_id).ok_or(Error::<T>::ModelNotFound)?;
+			ensure!(owner == who, Error::<T>::NotModelOwner);
+			ensure!(!Leases::<T>::contains_key(model_id), Error::<T>::AlreadyLeased);
+			ensure!(!LeaseOffers::<T>::contains_key(model_id), Error::<T>::LeaseOfferPending);
+			ensure!(
+				!duration.is_zero() && duration <= T::MaxLeaseDuration::get(),
+				Error::<T>::InvalidDuration
+			);
+
+			LeaseOffers::<T>::insert(
+				model_id,
+				LeaseOffer { lessor: who.clone(), lessee: lessee.clone(), duration, fee },
+			);
+
+			Self::deposit_event(Event::LeaseOffered {
+				model_id,
+				lessor: who,
+				lessee,
+				duration,
+				fee,
+			});
+
+			Ok(())
+		}
+
+		/// Accept a lease offer, paying its fee to the owner
+		///
+		/// # Arguments
+		/// * `origin` - Must be the lessee of the offer
+		/// * `model_id` - ID of the model
+		/// * `max_fee` - Most the lessee is willing to pay
+		///
+		/// # Errors
+		/// * `NoLeaseOffer` - No offer for the model
+		/// * `NotLessee` - Offer was made to another account
+		/// * `FeeAboveLimit` - Fee exceeds `max_fee`
+		/// * `StaleLeaseOffer` - Lessor no longer owns the model
+		/// * `TooManyLeases` - `MaxLeasesPerBlock` leases already end then
+		/// * Any error from paying the fee
+		///
+		/// # Events
+		/// * `LeaseStarted` - Lessee now collects the model's revenue
+		#[pallet::call_index(6)]
+		#[pallet::weight(T::WeightInfo::accept_lease())]
+		pub fn accept_lease(
+			origin: OriginFor<T>,
+			model_id: ModelId,
+			max_fee: BalanceOf<T>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let offer = LeaseOffers::<T>::get(model_id).ok_or(Error::<T>::NoLeaseOffer)?;
+			ensure!(offer.lessee == who, Error::<T>::NotLessee);
+			ensure!(offer.fee <= max_fee, Error::<T>::FeeAboveLimit);
+			ensure!(
+				T::Models::owner_of(model_id).as_ref() == Some(&offer.lessor),
+				Error::<T>::StaleLeaseOffer
+			);
+
+			let end = frame_system::Pallet::<T>::block_number().saturating_add(offer.duration);
+			LeasesEnding::<T>::try_mutate(end, |ending| ending.try_push(model_id))
+				.map_err(|_| Error::<T>::TooManyLeases)?;
+			T::Currency::transfer(&who, &offer.lessor, offer.fee, ExistenceRequirement::KeepAlive)?;
+
+			T::Models::set_revenue_recipient(model_id, Some(who.clone()));
+			LeaseOffers::<T>::remove(model_id);
+			Leases::<T>::insert(
+				model_id,
+				Lease { lessor: offer.lessor.clone(), lessee: who.clone(), fee: offer.fee, end },
+			);
+
+			Self::deposit_event(Event::LeaseStarted {
+				model_id,
+				lessor: offer.lessor,
+				lessee: who,
+				fee: offer.fee,
+				end,
+			});
+
+			Ok(())
+		}
+
+		/// Withdraw a lease offer
+		///
+		/// # Arguments
+		/// * `origin` - The lessor, or anyone once the offer is stale
+		/// * `model_id` - ID of the model
+		///
+		/// # Errors
+		/// * `NoLeaseOffer` - No offer for the model
+		/// * `NotModelOwner` - Caller is not the lessor and the offer is not stale
+		///
+		/// # Events
+		/// * `LeaseOfferCancelled` - Offer removed
+		#[pallet::call_index(7)]
+		#[pallet::weight(T::WeightInfo::cancel_lease_offer())]
+		pub fn cancel_lease_offer(origin: OriginFor<T>, model_id: ModelId) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let offer = LeaseOffers::<T>::get(model_id).ok_or(Error::<T>::NoLeaseOffer)?;
+			ensure!(
+				offer.lessor == who
+					|| T::Models::owner_of(model_id).as_ref() != Some(&offer.lessor),
+				Error::<T>::NotModelOwner
+			);
+
+			LeaseOffers::<T>::remove(model_id);
+
+			Self::deposit_event(Event::LeaseOfferCancelled { model_id });
+
+			Ok(())
+		}
+	}
+
+	impl<T: Config> Pallet<T> {
+		/// End the auction of `model_id`, selling the model to the best bidder
+		///
+		/// The seller's deposit and the best bid are released first; the sale then
+		/// pays for the model out of the winner's free balance. A failed sale, e.g.
+		/// because the seller no longer owns the model, leaves no trace.
+		fn settle_auction(model_id: ModelId) {
+			let Some(auction) = Auctions::<T>::take(model_id) else { return };
+			let _ = T::Escrow::refund(auction.deposit);
+
+			let Some(Bid { bidder: winner, amount: price, escrow }) = auction.best_bid else {
+				Self::deposit_event(Event::AuctionUnsold { model_id, seller: auction.seller });
+				return;
+			};
+			let _ = T::Escrow::refund(escrow);
+
+			let sold = T::Models::owner_of(model_id).as_ref() == Some(&auction.seller)
+				&& frame_support::storage::with_storage_layer(|| {
+					T::Models::sell(model_id, &winner, price)
+				})
+				.is_ok();
+			if sold {
+				Self::deposit_event(Event::AuctionSettled {
+					model_id,
+					seller: auction.seller,
+					winner,
+					price,
+				});
+			} else {
+				Self::deposit_event(Event::AuctionUnsold { model_id, seller: auction.seller });
+			}
+		}
+
+		/// End the lease of `model_id`, reverting its revenue to its owners
+		fn end_lease(model_id: ModelId) {
+			let Some(lease) = Leases::<T>::take(model_id) else { return };
+			T::Models::set_revenue_recipient(model_id, None);
+
+			Self::deposit_event(Event::LeaseEnded { model_id, lessee: lease.lessee });
+		}
+	}
+}
+
+/// Trades models through the AI registry pallet
+impl<T, Balance> ModelMarket<T::AccountId, Balance> for pallet_ai_registry::Pallet<T>
+where
+	T: pallet_ai_registry::Config,
+	Balance: sp_runtime::traits::AtLeast32BitUnsigned,
+{
+	fn owner_of(model_id: pallet_ai_registry::ModelId) -> Option<T::AccountId> {
+		use pallet_ai_registry::{AiRegistryInspect, ModelNfts};
+
+		<Self as AiRegistryInspect<_, Balance>>::owner(model_id)
+			.filter(|owner| T::ModelNfts::owner(model_id).is_none_or(|holder| holder == *owner))
+	}
+
+	fn sell(
+		model_id: pallet_ai_registry::ModelId,
+		buyer: &T::AccountId,
+		price: Balance,
+	) -> frame_support::dispatch::DispatchResult {
+		use sp_runtime::SaturatedConversion;
+
+		let price: u128 = price.saturated_into();
+		Self::do_sell(model_id, buyer, price.saturated_into())
+	}
+
+	fn set_revenue_recipient(
+		model_id: pallet_ai_registry::ModelId,
+		recipient: Option<T::AccountId>,
+	) {
+		Self::set_revenue_recipient(model_id, recipient)
+	}
+
+	#[cfg(feature = "runtime-benchmarks")]
+	fn create_model(owner: &T::AccountId) -> pallet_ai_registry::ModelId {
+		use frame_support::traits::{Currency, Get};
+		use sp_runtime::traits::Saturating;
+
+		let balance = Self::model_deposit(1_000)
+			.saturating_add(T::RegistrationFee::get())
+			.saturating_add(T::Currency::minimum_balance())
+			.saturating_mul(2u32.into());
+		T::Currency::make_free_balance_be(owner, balance);
+
+		let model_id = pallet_ai_registry::NextModelId::<T>::get();
+		let _ = Self::register_model(
+			frame_system::RawOrigin::Signed(owner.clone()).into(),
+			Self::benchmark_cid(model_id),
+			Self::benchmark_name(model_id),
+			b"Description".to_vec(),
+			pallet_ai_registry::ModelType::Classification,
+			1_000u32.into(),
+			pallet_ai_registry::License::Mit,
+			1_000,
+			Default::default(),
+		);
+		model_id
+	}
+}
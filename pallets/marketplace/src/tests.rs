@@ -0,0 +1,451 @@
+//! Unit tests for Marketplace pallet
+
+use crate::{
+	mock::*,
+	pallet::{Auctions, AuctionsEnding, Error, Event, LeaseOffers, Leases, LeasesEnding, Listings},
+	Auction, Bid, Lease, LeaseOffer, Listing,
+};
+use frame_support::{assert_noop, assert_ok};
+use pallet_ai_registry::{License, ModelType, Models};
+use sp_runtime::Perbill;
+
+const PRICE: u128 = 1_000;
+const DEPOSIT: u128 = 2_000;
+
+/// CIDv0 of the `n`th test model
+fn cid(n: u8) -> Vec<u8> {
+	let mut cid = b"QmYwAPJzv5CZsnA625s3Xf2nemtYgPpHdWEz79ojWnPbd".to_vec();
+	cid.push(b'A' + n);
+	cid
+}
+
+/// Register the next model, owned by account 1
+fn register_model() {
+	let model_id = pallet_ai_registry::NextModelId::<Test>::get();
+	assert_ok!(AIRegistry::register_model(
+		RuntimeOrigin::signed(1),
+		cid(model_id as u8),
+		format!("Model {model_id}").into_bytes(),
+		b"Description".to_vec(),
+		ModelType::Classification,
+		100,
+		License::Mit,
+		1_000,
+		vec![]
+	));
+}
+
+#[test]
+fn list_model_works() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			Marketplace::list_model(RuntimeOrigin::signed(1), 0, PRICE),
+			Error::<Test>::ModelNotFound
+		);
+		register_model();
+		assert_noop!(
+			Marketplace::list_model(RuntimeOrigin::signed(2), 0, PRICE),
+			Error::<Test>::NotModelOwner
+		);
+
+		assert_ok!(Marketplace::list_model(RuntimeOrigin::signed(1), 0, PRICE));
+		System::assert_last_event(
+			Event::ModelListed { model_id: 0, seller: 1, price: PRICE }.into(),
+		);
+		assert_eq!(Listings::<Test>::get(0), Some(Listing { seller: 1, price: PRICE, deposit: 0 }));
+		assert_eq!(Balances::reserved_balance(1), DEPOSIT + 50);
+
+		assert_noop!(
+			Marketplace::list_model(RuntimeOrigin::signed(1), 0, PRICE),
+			Error::<Test>::AlreadyListed
+		);
+	});
+}
+
+#[test]
+fn list_model_requires_deposit() {
+	new_test_ext().execute_with(|| {
+		register_model();
+		assert_ok!(AIRegistry::transfer_model(RuntimeOrigin::signed(1), 0, 2));
+		assert_ok!(Balances::transfer_allow_death(RuntimeOrigin::signed(2), 5, 7_990));
+
+		assert_noop!(
+			Marketplace::list_model(RuntimeOrigin::signed(2), 0, PRICE),
+			Error::<Test>::InsufficientDeposit
+		);
+	});
+}
+
+#[test]
+fn delist_model_works() {
+	new_test_ext().execute_with(|| {
+		register_model();
+		assert_noop!(
+			Marketplace::delist_model(RuntimeOrigin::signed(1), 0),
+			Error::<Test>::NotListed
+		);
+		assert_ok!(Marketplace::list_model(RuntimeOrigin::signed(1), 0, PRICE));
+		assert_noop!(
+			Marketplace::delist_model(RuntimeOrigin::signed(2), 0),
+			Error::<Test>::NotSeller
+		);
+
+		assert_ok!(Marketplace::delist_model(RuntimeOrigin::signed(1), 0));
+		System::assert_last_event(Event::ModelDelisted { model_id: 0, seller: 1 }.into());
+		assert!(!Listings::<Test>::contains_key(0));
+		assert_eq!(Balances::reserved_balance(1), DEPOSIT);
+	});
+}
+
+#[test]
+fn buy_model_transfers_ownership_and_pays_seller() {
+	new_test_ext().execute_with(|| {
+		register_model();
+		assert_ok!(Marketplace::list_model(RuntimeOrigin::signed(1), 0, PRICE));
+		let seller_free = Balances::free_balance(1);
+
+		assert_noop!(
+			Marketplace::buy_model(RuntimeOrigin::signed(1), 0, PRICE),
+			Error::<Test>::CannotBuyOwnModel
+		);
+		assert_noop!(
+			Marketplace::buy_model(RuntimeOrigin::signed(2), 0, PRICE - 1),
+			Error::<Test>::PriceAboveLimit
+		);
+
+		assert_ok!(Marketplace::buy_model(RuntimeOrigin::signed(2), 0, PRICE));
+		System::assert_last_event(
+			Event::ModelBought { model_id: 0, seller: 1, buyer: 2, price: PRICE }.into(),
+		);
+
+		assert_eq!(Models::<Test>::get(0).unwrap().owner, 2);
+		assert!(!Listings::<Test>::contains_key(0));
+		// The seller is also the creator, so receives the whole price
+		assert_eq!(Balances::free_balance(1), seller_free + PRICE + DEPOSIT + 50);
+		assert_eq!(Balances::reserved_balance(1), 0);
+		assert_eq!(Balances::free_balance(2), 10_000 - PRICE - DEPOSIT);
+		assert_eq!(Balances::reserved_balance(2), DEPOSIT);
+	});
+}
+
+#[test]
+fn resale_pays_creator_royalty() {
+	new_test_ext().execute_with(|| {
+		register_model();
+		assert_ok!(Marketplace::list_model(RuntimeOrigin::signed(1), 0, PRICE));
+		assert_ok!(Marketplace::buy_model(RuntimeOrigin::signed(2), 0, PRICE));
+		assert_ok!(Marketplace::list_model(RuntimeOrigin::signed(2), 0, PRICE));
+		let creator_free = Balances::free_balance(1);
+		let seller_free = Balances::free_balance(2);
+
+		assert_ok!(Marketplace::buy_model(RuntimeOrigin::signed(4), 0, PRICE));
+
+		assert_eq!(Models::<Test>::get(0).unwrap().owner, 4);
+		assert_eq!(Balances::free_balance(1), creator_free + PRICE / 10);
+		assert_eq!(Balances::free_balance(2), seller_free + PRICE - PRICE / 10 + DEPOSIT + 50);
+	});
+}
+
+#[test]
+fn buy_model_is_atomic() {
+	new_test_ext().execute_with(|| {
+		register_model();
+		assert_ok!(Marketplace::list_model(RuntimeOrigin::signed(1), 0, PRICE));
+
+		// Account 3 can neither pay nor reserve the model deposit
+		assert!(Marketplace::buy_model(RuntimeOrigin::signed(3), 0, PRICE).is_err());
+
+		assert_eq!(Models::<Test>::get(0).unwrap().owner, 1);
+		assert!(Listings::<Test>::contains_key(0));
+		assert_eq!(Balances::reserved_balance(1), DEPOSIT + 50);
+		assert_eq!(Balances::free_balance(3), 100);
+	});
+}
+
+#[test]
+fn stale_listings_cannot_be_bought_and_anyone_can_remove_them() {
+	new_test_ext().execute_with(|| {
+		register_model();
+		assert_ok!(Marketplace::list_model(RuntimeOrigin::signed(1), 0, PRICE));
+		assert_ok!(AIRegistry::transfer_model(RuntimeOrigin::signed(1), 0, 2));
+
+		assert_noop!(
+			Marketplace::buy_model(RuntimeOrigin::signed(4), 0, PRICE),
+			Error::<Test>::StaleListing
+		);
+
+		assert_ok!(Marketplace::delist_model(RuntimeOrigin::signed(4), 0));
+		System::assert_last_event(Event::ModelDelisted { model_id: 0, seller: 1 }.into());
+		assert_eq!(Balances::reserved_balance(1), 0);
+	});
+}
+
+#[test]
+fn start_auction_works() {
+	new_test_ext().execute_with(|| {
+		register_model();
+		assert_noop!(
+			Marketplace::start_auction(RuntimeOrigin::signed(2), 0, PRICE, 10),
+			Error::<Test>::NotModelOwner
+		);
+		assert_noop!(
+			Marketplace::start_auction(RuntimeOrigin::signed(1), 0, PRICE, 0),
+			Error::<Test>::InvalidDuration
+		);
+		assert_noop!(
+			Marketplace::start_auction(RuntimeOrigin::signed(1), 0, PRICE, 101),
+			Error::<Test>::InvalidDuration
+		);
+
+		assert_ok!(Marketplace::start_auction(RuntimeOrigin::signed(1), 0, PRICE, 10));
+		System::assert_last_event(
+			Event::AuctionStarted { model_id: 0, seller: 1, min_bid: PRICE, end: 11 }.into(),
+		);
+		assert_eq!(
+			Auctions::<Test>::get(0),
+			Some(Auction { seller: 1, min_bid: PRICE, end: 11, best_bid: None, deposit: 0 })
+		);
+		assert_eq!(AuctionsEnding::<Test>::get(11).into_inner(), vec![0]);
+		assert_eq!(Balances::reserved_balance(1), DEPOSIT + 50);
+
+		// Auctioned models cannot be listed and vice versa
+		assert_noop!(
+			Marketplace::start_auction(RuntimeOrigin::signed(1), 0, PRICE, 10),
+			Error::<Test>::AlreadyAuctioned
+		);
+		assert_noop!(
+			Marketplace::list_model(RuntimeOrigin::signed(1), 0, PRICE),
+			Error::<Test>::AlreadyAuctioned
+		);
+	});
+}
+
+#[test]
+fn auctions_ending_per_block_are_bounded() {
+	new_test_ext().execute_with(|| {
+		for _ in 0..3 {
+			register_model();
+		}
+		assert_ok!(Marketplace::start_auction(RuntimeOrigin::signed(1), 0, PRICE, 10));
+		assert_ok!(Marketplace::start_auction(RuntimeOrigin::signed(1), 1, PRICE, 10));
+		assert_noop!(
+			Marketplace::start_auction(RuntimeOrigin::signed(1), 2, PRICE, 10),
+			Error::<Test>::TooManyAuctions
+		);
+		assert_ok!(Marketplace::start_auction(RuntimeOrigin::signed(1), 2, PRICE, 11));
+	});
+}
+
+#[test]
+fn bids_are_escrowed_and_outbid_bids_refunded() {
+	new_test_ext().execute_with(|| {
+		register_model();
+		assert_noop!(
+			Marketplace::bid(RuntimeOrigin::signed(2), 0, PRICE),
+			Error::<Test>::NoAuction
+		);
+		assert_ok!(Marketplace::start_auction(RuntimeOrigin::signed(1), 0, PRICE, 10));
+
+		assert_noop!(
+			Marketplace::bid(RuntimeOrigin::signed(1), 0, PRICE),
+			Error::<Test>::CannotBidOnOwnModel
+		);
+		assert_noop!(
+			Marketplace::bid(RuntimeOrigin::signed(2), 0, PRICE - 1),
+			Error::<Test>::BidTooLow
+		);
+		assert_noop!(
+			Marketplace::bid(RuntimeOrigin::signed(3), 0, PRICE),
+			Error::<Test>::InsufficientBalance
+		);
+
+		assert_ok!(Marketplace::bid(RuntimeOrigin::signed(2), 0, PRICE));
+		System::assert_last_event(
+			Event::BidPlaced { model_id: 0, bidder: 2, amount: PRICE }.into(),
+		);
+		assert_eq!(Balances::reserved_balance(2), PRICE);
+
+		assert_noop!(
+			Marketplace::bid(RuntimeOrigin::signed(4), 0, PRICE),
+			Error::<Test>::BidTooLow
+		);
+		assert_ok!(Marketplace::bid(RuntimeOrigin::signed(4), 0, PRICE + 1));
+		assert_eq!(Balances::reserved_balance(2), 0);
+		assert_eq!(Balances::reserved_balance(4), PRICE + 1);
+		assert_eq!(
+			Auctions::<Test>::get(0).unwrap().best_bid,
+			Some(Bid { bidder: 4, amount: PRICE + 1, escrow: 2 })
+		);
+	});
+}
+
+#[test]
+fn auctions_settle_to_best_bidder() {
+	new_test_ext().execute_with(|| {
+		register_model();
+		assert_ok!(Marketplace::start_auction(RuntimeOrigin::signed(1), 0, PRICE, 10));
+		assert_ok!(Marketplace::bid(RuntimeOrigin::signed(2), 0, PRICE));
+		assert_ok!(Marketplace::bid(RuntimeOrigin::signed(4), 0, PRICE + 100));
+		let seller_free = Balances::free_balance(1);
+
+		run_to_block(10);
+		assert_eq!(Models::<Test>::get(0).unwrap().owner, 1);
+
+		run_to_block(11);
+		System::assert_last_event(
+			Event::AuctionSettled { model_id: 0, seller: 1, winner: 4, price: PRICE + 100 }.into(),
+		);
+		assert_eq!(Models::<Test>::get(0).unwrap().owner, 4);
+		assert!(!Auctions::<Test>::contains_key(0));
+		assert!(!AuctionsEnding::<Test>::contains_key(11));
+		assert_eq!(Balances::free_balance(1), seller_free + PRICE + 100 + DEPOSIT + 50);
+		assert_eq!(Balances::free_balance(2), 10_000);
+		assert_eq!(Balances::free_balance(4), 10_000 - PRICE - 100 - DEPOSIT);
+		assert_eq!(Balances::reserved_balance(4), DEPOSIT);
+	});
+}
+
+#[test]
+fn auctions_without_a_sale_release_everything() {
+	new_test_ext().execute_with(|| {
+		register_model();
+		assert_ok!(Marketplace::start_auction(RuntimeOrigin::signed(1), 0, PRICE, 10));
+
+		run_to_block(11);
+		System::assert_last_event(Event::AuctionUnsold { model_id: 0, seller: 1 }.into());
+		assert_eq!(Balances::reserved_balance(1), DEPOSIT);
+		assert_noop!(
+			Marketplace::bid(RuntimeOrigin::signed(2), 0, PRICE),
+			Error::<Test>::NoAuction
+		);
+
+		// The seller gave the model away before settlement: the bid is refunded
+		assert_ok!(Marketplace::start_auction(RuntimeOrigin::signed(1), 0, PRICE, 10));
+		assert_ok!(Marketplace::bid(RuntimeOrigin::signed(2), 0, PRICE));
+		assert_ok!(AIRegistry::transfer_model(RuntimeOrigin::signed(1), 0, 4));
+
+		run_to_block(21);
+		System::assert_last_event(Event::AuctionUnsold { model_id: 0, seller: 1 }.into());
+		assert_eq!(Models::<Test>::get(0).unwrap().owner, 4);
+		assert_eq!(Balances::free_balance(2), 10_000);
+		assert_eq!(Balances::reserved_balance(1), 0);
+	});
+}
+
+#[test]
+fn failed_auction_sales_leave_no_trace() {
+	new_test_ext().execute_with(|| {
+		register_model();
+		assert_ok!(Marketplace::start_auction(RuntimeOrigin::signed(1), 0, PRICE, 10));
+		assert_ok!(Marketplace::bid(RuntimeOrigin::signed(2), 0, 9_000));
+		let creator_free = Balances::free_balance(1);
+
+		// The winner can pay the bid but not also reserve the model deposit
+		run_to_block(11);
+		System::assert_last_event(Event::AuctionUnsold { model_id: 0, seller: 1 }.into());
+		assert_eq!(Models::<Test>::get(0).unwrap().owner, 1);
+		assert_eq!(Balances::free_balance(1), creator_free + 50);
+		assert_eq!(Balances::free_balance(2), 10_000);
+	});
+}
+
+#[test]
+fn lease_model_records_offer() {
+	new_test_ext().execute_with(|| {
+		register_model();
+		assert_noop!(
+			Marketplace::lease_model(RuntimeOrigin::signed(2), 0, 2, 10, 300),
+			Error::<Test>::NotModelOwner
+		);
+		assert_noop!(
+			Marketplace::lease_model(RuntimeOrigin::signed(1), 0, 2, 0, 300),
+			Error::<Test>::InvalidDuration
+		);
+		assert_noop!(
+			Marketplace::lease_model(RuntimeOrigin::signed(1), 0, 2, 101, 300),
+			Error::<Test>::InvalidDuration
+		);
+
+		assert_ok!(Marketplace::lease_model(RuntimeOrigin::signed(1), 0, 2, 10, 300));
+		System::assert_last_event(
+			Event::LeaseOffered { model_id: 0, lessor: 1, lessee: 2, duration: 10, fee: 300 }
+				.into(),
+		);
+		assert_eq!(
+			LeaseOffers::<Test>::get(0),
+			Some(LeaseOffer { lessor: 1, lessee: 2, duration: 10, fee: 300 })
+		);
+		assert_noop!(
+			Marketplace::lease_model(RuntimeOrigin::signed(1), 0, 4, 10, 300),
+			Error::<Test>::LeaseOfferPending
+		);
+
+		assert_noop!(
+			Marketplace::cancel_lease_offer(RuntimeOrigin::signed(2), 0),
+			Error::<Test>::NotModelOwner
+		);
+		assert_ok!(Marketplace::cancel_lease_offer(RuntimeOrigin::signed(1), 0));
+		System::assert_last_event(Event::LeaseOfferCancelled { model_id: 0 }.into());
+		assert!(!LeaseOffers::<Test>::contains_key(0));
+	});
+}
+
+#[test]
+fn accepted_leases_redirect_revenue_until_they_end() {
+	new_test_ext().execute_with(|| {
+		register_model();
+		assert_ok!(Marketplace::lease_model(RuntimeOrigin::signed(1), 0, 2, 10, 300));
+
+		assert_noop!(
+			Marketplace::accept_lease(RuntimeOrigin::signed(4), 0, 300),
+			Error::<Test>::NotLessee
+		);
+		assert_noop!(
+			Marketplace::accept_lease(RuntimeOrigin::signed(2), 0, 299),
+			Error::<Test>::FeeAboveLimit
+		);
+
+		let lessor_free = Balances::free_balance(1);
+		assert_ok!(Marketplace::accept_lease(RuntimeOrigin::signed(2), 0, 300));
+		System::assert_last_event(
+			Event::LeaseStarted { model_id: 0, lessor: 1, lessee: 2, fee: 300, end: 11 }.into(),
+		);
+		assert_eq!(Balances::free_balance(1), lessor_free + 300);
+		assert_eq!(Balances::free_balance(2), 10_000 - 300);
+		assert_eq!(Leases::<Test>::get(0), Some(Lease { lessor: 1, lessee: 2, fee: 300, end: 11 }));
+		assert_eq!(LeasesEnding::<Test>::get(11).into_inner(), vec![0]);
+		assert!(!LeaseOffers::<Test>::contains_key(0));
+		assert_eq!(AIRegistry::revenue_shares(0), vec![(2, Perbill::one())]);
+
+		assert_noop!(
+			Marketplace::lease_model(RuntimeOrigin::signed(1), 0, 4, 10, 300),
+			Error::<Test>::AlreadyLeased
+		);
+
+		// The lease survives a sale of the model
+		assert_ok!(Marketplace::list_model(RuntimeOrigin::signed(1), 0, PRICE));
+		assert_ok!(Marketplace::buy_model(RuntimeOrigin::signed(4), 0, PRICE));
+		assert_eq!(AIRegistry::revenue_shares(0), vec![(2, Perbill::one())]);
+
+		run_to_block(11);
+		System::assert_last_event(Event::LeaseEnded { model_id: 0, lessee: 2 }.into());
+		assert!(!Leases::<Test>::contains_key(0));
+		assert!(AIRegistry::revenue_shares(0).is_empty());
+	});
+}
+
+#[test]
+fn stale_lease_offers_cannot_be_accepted() {
+	new_test_ext().execute_with(|| {
+		register_model();
+		assert_ok!(Marketplace::lease_model(RuntimeOrigin::signed(1), 0, 2, 10, 300));
+		assert_ok!(AIRegistry::transfer_model(RuntimeOrigin::signed(1), 0, 4));
+
+		assert_noop!(
+			Marketplace::accept_lease(RuntimeOrigin::signed(2), 0, 300),
+			Error::<Test>::StaleLeaseOffer
+		);
+		assert_ok!(Marketplace::cancel_lease_offer(RuntimeOrigin::signed(2), 0));
+		assert!(!LeaseOffers::<Test>::contains_key(0));
+	});
+}
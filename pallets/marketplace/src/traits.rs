@@ -0,0 +1,22 @@
+//! Interfaces the Marketplace pallet expects from the rest of the runtime
+
+use frame_support::pallet_prelude::DispatchResult;
+use pallet_ai_registry::ModelId;
+
+/// Registered models that can change hands
+pub trait ModelMarket<AccountId, Balance> {
+	/// Account that may sell `model_id`, if the model exists and its ownership is
+	/// settled
+	fn owner_of(model_id: ModelId) -> Option<AccountId>;
+
+	/// Have `buyer` pay `price` for `model_id` and take over the model and its deposit
+	fn sell(model_id: ModelId, buyer: &AccountId, price: Balance) -> DispatchResult;
+
+	/// Send the inference revenue of `model_id` to `recipient`, or back to its owners
+	/// if `None`
+	fn set_revenue_recipient(model_id: ModelId, recipient: Option<AccountId>);
+
+	/// Register a model owned by `owner` and return its ID
+	#[cfg(feature = "runtime-benchmarks")]
+	fn create_model(owner: &AccountId) -> ModelId;
+}
@@ -0,0 +1,69 @@
+//! Type definitions for the Marketplace pallet
+
+use codec::{Decode, Encode, MaxEncodedLen};
+use pallet_escrow::EscrowId;
+use scale_info::TypeInfo;
+use sp_runtime::RuntimeDebug;
+
+/// A model offered for sale at a fixed price
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct Listing<AccountId, Balance> {
+	/// Owner who listed the model
+	pub seller: AccountId,
+	/// Asking price
+	pub price: Balance,
+	/// Escrow holding the seller's deposit while the listing exists
+	pub deposit: EscrowId,
+}
+
+/// An English auction of a model
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct Auction<AccountId, Balance, BlockNumber> {
+	/// Owner who started the auction
+	pub seller: AccountId,
+	/// Lowest acceptable bid
+	pub min_bid: Balance,
+	/// Block at which the auction is settled
+	pub end: BlockNumber,
+	/// Highest bid so far
+	pub best_bid: Option<Bid<AccountId, Balance>>,
+	/// Escrow holding the seller's deposit while the auction runs
+	pub deposit: EscrowId,
+}
+
+/// A bid on an auction, held in escrow until outbid or settled
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct Bid<AccountId, Balance> {
+	/// Account that placed the bid
+	pub bidder: AccountId,
+	/// Amount bid
+	pub amount: Balance,
+	/// Escrow holding the bid
+	pub escrow: EscrowId,
+}
+
+/// Terms on which an owner offers to lease out a model
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct LeaseOffer<AccountId, Balance, BlockNumber> {
+	/// Owner making the offer
+	pub lessor: AccountId,
+	/// Account the offer is made to
+	pub lessee: AccountId,
+	/// Length of the lease in blocks
+	pub duration: BlockNumber,
+	/// Fee paid up front by the lessee to the lessor
+	pub fee: Balance,
+}
+
+/// A running lease, sending a model's inference revenue to the lessee
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct Lease<AccountId, Balance, BlockNumber> {
+	/// Owner who leased out the model
+	pub lessor: AccountId,
+	/// Account collecting the revenue
+	pub lessee: AccountId,
+	/// Fee the lessee paid
+	pub fee: Balance,
+	/// Block at which revenue reverts to the model's owners
+	pub end: BlockNumber,
+}
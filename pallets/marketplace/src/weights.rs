@@ -0,0 +1,277 @@
+//! Autogenerated weights for pallet_marketplace
+//!
+//! THIS FILE WAS AUTO-GENERATED USING THE SUBSTRATE BENCHMARK CLI VERSION 4.0.0-dev
+//! DATE: 2024-01-01, STEPS: `50`, REPEAT: `20`, LOW RANGE: `[]`, HIGH RANGE: `[]`
+//! WORST CASE MAP SIZE: `1000000`
+//! HOSTNAME: `benchmark-runner`, CPU: `Intel(R) Xeon(R) CPU @ 2.60GHz`
+//! EXECUTION: Some(Wasm), WASM-EXECUTION: Compiled, CHAIN: Some("dev"), DB CACHE: 1024
+
+// Executed Command:
+// ./target/production/solochain-template-node
+// benchmark
+// pallet
+// --chain=dev
+// --steps=50
+// --repeat=20
+// --pallet=pallet_marketplace
+// --output=./pallets/marketplace/src/weights.rs
+// --template=./.maintain/frame-weight-template.hbs
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+#![allow(missing_docs)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use core::marker::PhantomData;
+
+/// Weight functions needed for pallet_marketplace.
+pub trait WeightInfo {
+	fn list_model() -> Weight;
+	fn delist_model() -> Weight;
+	fn buy_model() -> Weight;
+	fn start_auction() -> Weight;
+	fn bid() -> Weight;
+	fn settle_auctions(n: u32, ) -> Weight;
+	fn lease_model() -> Weight;
+	fn accept_lease() -> Weight;
+	fn cancel_lease_offer() -> Weight;
+	fn end_leases(n: u32, ) -> Weight;
+}
+
+/// Weights for pallet_marketplace using the Substrate node and recommended hardware.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	/// Storage: AIRegistry Models (r:1 w:0)
+	/// Proof: AIRegistry Models (max_values: None, max_size: Some(1712), added: 4187, mode: MaxEncodedLen)
+	/// Storage: Nfts Item (r:1 w:0)
+	/// Proof: Nfts Item (max_values: None, max_size: Some(865), added: 3340, mode: MaxEncodedLen)
+	/// Storage: Marketplace Listings (r:1 w:1)
+	/// Proof: Marketplace Listings (max_values: None, max_size: Some(88), added: 2563, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	fn list_model() -> Weight {
+		Weight::from_parts(24_000_000, 12693)
+			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+
+	/// Storage: Marketplace Listings (r:1 w:1)
+	/// Proof: Marketplace Listings (max_values: None, max_size: Some(88), added: 2563, mode: MaxEncodedLen)
+	/// Storage: AIRegistry Models (r:1 w:0)
+	/// Proof: AIRegistry Models (max_values: None, max_size: Some(1712), added: 4187, mode: MaxEncodedLen)
+	/// Storage: Nfts Item (r:1 w:0)
+	/// Proof: Nfts Item (max_values: None, max_size: Some(865), added: 3340, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	fn delist_model() -> Weight {
+		Weight::from_parts(22_000_000, 12693)
+			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+
+	/// Storage: Marketplace Listings (r:1 w:1)
+	/// Proof: Marketplace Listings (max_values: None, max_size: Some(88), added: 2563, mode: MaxEncodedLen)
+	/// Storage: AIRegistry Models (r:1 w:1)
+	/// Proof: AIRegistry Models (max_values: None, max_size: Some(1712), added: 4187, mode: MaxEncodedLen)
+	/// Storage: Nfts Item (r:1 w:1)
+	/// Proof: Nfts Item (max_values: None, max_size: Some(865), added: 3340, mode: MaxEncodedLen)
+	/// Storage: System Account (r:3 w:3)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: AIRegistry OwnedModels (r:2 w:2)
+	/// Proof: AIRegistry OwnedModels (max_values: None, max_size: Some(2098), added: 4573, mode: MaxEncodedLen)
+	/// Storage: AIRegistry OwnerModelNames (r:2 w:2)
+	/// Proof: AIRegistry OwnerModelNames (max_values: None, max_size: Some(88), added: 2563, mode: MaxEncodedLen)
+	fn buy_model() -> Weight {
+		Weight::from_parts(68_000_000, 32171)
+			.saturating_add(T::DbWeight::get().reads(10))
+			.saturating_add(T::DbWeight::get().writes(10))
+	}
+
+	/// Storage: AIRegistry Models (r:1 w:0)
+	/// Proof: AIRegistry Models (max_values: None, max_size: Some(1712), added: 4187, mode: MaxEncodedLen)
+	/// Storage: Nfts Item (r:1 w:0)
+	/// Proof: Nfts Item (max_values: None, max_size: Some(865), added: 3340, mode: MaxEncodedLen)
+	/// Storage: Marketplace Listings (r:1 w:0)
+	/// Proof: Marketplace Listings (max_values: None, max_size: Some(88), added: 2563, mode: MaxEncodedLen)
+	/// Storage: Marketplace Auctions (r:1 w:1)
+	/// Proof: Marketplace Auctions (max_values: None, max_size: Some(141), added: 2616, mode: MaxEncodedLen)
+	/// Storage: Marketplace AuctionsEnding (r:1 w:1)
+	/// Proof: Marketplace AuctionsEnding (max_values: None, max_size: Some(77), added: 2552, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	fn start_auction() -> Weight {
+		Weight::from_parts(30_000_000, 17861)
+			.saturating_add(T::DbWeight::get().reads(6))
+			.saturating_add(T::DbWeight::get().writes(3))
+	}
+
+	/// Storage: Marketplace Auctions (r:1 w:1)
+	/// Proof: Marketplace Auctions (max_values: None, max_size: Some(141), added: 2616, mode: MaxEncodedLen)
+	/// Storage: System Account (r:2 w:2)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	fn bid() -> Weight {
+		Weight::from_parts(28_000_000, 7822)
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(3))
+	}
+
+	/// Storage: Marketplace AuctionsEnding (r:1 w:1)
+	/// Proof: Marketplace AuctionsEnding (max_values: None, max_size: Some(77), added: 2552, mode: MaxEncodedLen)
+	/// Storage: Marketplace Auctions (r:8 w:8)
+	/// Proof: Marketplace Auctions (max_values: None, max_size: Some(141), added: 2616, mode: MaxEncodedLen)
+	/// Storage: AIRegistry Models (r:8 w:8)
+	/// Proof: AIRegistry Models (max_values: None, max_size: Some(1712), added: 4187, mode: MaxEncodedLen)
+	/// Storage: Nfts Item (r:8 w:8)
+	/// Proof: Nfts Item (max_values: None, max_size: Some(865), added: 3340, mode: MaxEncodedLen)
+	/// Storage: System Account (r:32 w:32)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: AIRegistry OwnedModels (r:16 w:16)
+	/// Proof: AIRegistry OwnedModels (max_values: None, max_size: Some(2098), added: 4573, mode: MaxEncodedLen)
+	/// Storage: AIRegistry OwnerModelNames (r:16 w:16)
+	/// Proof: AIRegistry OwnerModelNames (max_values: None, max_size: Some(88), added: 2563, mode: MaxEncodedLen)
+	fn settle_auctions(n: u32, ) -> Weight {
+		Weight::from_parts(4_000_000, 2552)
+			// Standard Error: 3_500_000
+			.saturating_add(Weight::from_parts(70_000_000, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().reads((11_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes(1))
+			.saturating_add(T::DbWeight::get().writes((11_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 34827).saturating_mul(n.into()))
+	}
+
+	/// Storage: AIRegistry Models (r:1 w:0)
+	/// Proof: AIRegistry Models (max_values: None, max_size: Some(1712), added: 4187, mode: MaxEncodedLen)
+	/// Storage: Nfts Item (r:1 w:0)
+	/// Proof: Nfts Item (max_values: None, max_size: Some(865), added: 3340, mode: MaxEncodedLen)
+	/// Storage: Marketplace Leases (r:1 w:0)
+	/// Proof: Marketplace Leases (max_values: None, max_size: Some(108), added: 2583, mode: MaxEncodedLen)
+	/// Storage: Marketplace LeaseOffers (r:1 w:1)
+	/// Proof: Marketplace LeaseOffers (max_values: None, max_size: Some(108), added: 2583, mode: MaxEncodedLen)
+	fn lease_model() -> Weight {
+		Weight::from_parts(22_000_000, 12693)
+			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+
+	/// Storage: Marketplace LeaseOffers (r:1 w:1)
+	/// Proof: Marketplace LeaseOffers (max_values: None, max_size: Some(108), added: 2583, mode: MaxEncodedLen)
+	/// Storage: AIRegistry Models (r:1 w:0)
+	/// Proof: AIRegistry Models (max_values: None, max_size: Some(1712), added: 4187, mode: MaxEncodedLen)
+	/// Storage: Nfts Item (r:1 w:0)
+	/// Proof: Nfts Item (max_values: None, max_size: Some(865), added: 3340, mode: MaxEncodedLen)
+	/// Storage: Marketplace LeasesEnding (r:1 w:1)
+	/// Proof: Marketplace LeasesEnding (max_values: None, max_size: Some(77), added: 2552, mode: MaxEncodedLen)
+	/// Storage: System Account (r:2 w:2)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: AIRegistry RevenueRecipient (r:0 w:1)
+	/// Proof: AIRegistry RevenueRecipient (max_values: None, max_size: Some(56), added: 2531, mode: MaxEncodedLen)
+	/// Storage: Marketplace Leases (r:0 w:1)
+	/// Proof: Marketplace Leases (max_values: None, max_size: Some(108), added: 2583, mode: MaxEncodedLen)
+	fn accept_lease() -> Weight {
+		Weight::from_parts(45_000_000, 17868)
+			.saturating_add(T::DbWeight::get().reads(6))
+			.saturating_add(T::DbWeight::get().writes(6))
+	}
+
+	/// Storage: Marketplace LeaseOffers (r:1 w:1)
+	/// Proof: Marketplace LeaseOffers (max_values: None, max_size: Some(108), added: 2583, mode: MaxEncodedLen)
+	/// Storage: AIRegistry Models (r:1 w:0)
+	/// Proof: AIRegistry Models (max_values: None, max_size: Some(1712), added: 4187, mode: MaxEncodedLen)
+	/// Storage: Nfts Item (r:1 w:0)
+	/// Proof: Nfts Item (max_values: None, max_size: Some(865), added: 3340, mode: MaxEncodedLen)
+	fn cancel_lease_offer() -> Weight {
+		Weight::from_parts(18_000_000, 10110)
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+
+	/// Storage: Marketplace LeasesEnding (r:1 w:1)
+	/// Proof: Marketplace LeasesEnding (max_values: None, max_size: Some(77), added: 2552, mode: MaxEncodedLen)
+	/// Storage: Marketplace Leases (r:8 w:8)
+	/// Proof: Marketplace Leases (max_values: None, max_size: Some(108), added: 2583, mode: MaxEncodedLen)
+	/// Storage: AIRegistry RevenueRecipient (r:0 w:8)
+	/// Proof: AIRegistry RevenueRecipient (max_values: None, max_size: Some(56), added: 2531, mode: MaxEncodedLen)
+	fn end_leases(n: u32, ) -> Weight {
+		Weight::from_parts(3_000_000, 2552)
+			// Standard Error: 600_000
+			.saturating_add(Weight::from_parts(12_000_000, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes(1))
+			.saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 2583).saturating_mul(n.into()))
+	}
+}
+
+// For backwards compatibility and tests
+impl WeightInfo for () {
+	fn list_model() -> Weight {
+		Weight::from_parts(24_000_000, 12693)
+			.saturating_add(RocksDbWeight::get().reads(4))
+			.saturating_add(RocksDbWeight::get().writes(2))
+	}
+
+	fn delist_model() -> Weight {
+		Weight::from_parts(22_000_000, 12693)
+			.saturating_add(RocksDbWeight::get().reads(4))
+			.saturating_add(RocksDbWeight::get().writes(2))
+	}
+
+	fn buy_model() -> Weight {
+		Weight::from_parts(68_000_000, 32171)
+			.saturating_add(RocksDbWeight::get().reads(10))
+			.saturating_add(RocksDbWeight::get().writes(10))
+	}
+
+	fn start_auction() -> Weight {
+		Weight::from_parts(30_000_000, 17861)
+			.saturating_add(RocksDbWeight::get().reads(6))
+			.saturating_add(RocksDbWeight::get().writes(3))
+	}
+
+	fn bid() -> Weight {
+		Weight::from_parts(28_000_000, 7822)
+			.saturating_add(RocksDbWeight::get().reads(3))
+			.saturating_add(RocksDbWeight::get().writes(3))
+	}
+
+	fn settle_auctions(n: u32, ) -> Weight {
+		Weight::from_parts(4_000_000, 2552)
+			.saturating_add(Weight::from_parts(70_000_000, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(1))
+			.saturating_add(RocksDbWeight::get().reads((11_u64).saturating_mul(n.into())))
+			.saturating_add(RocksDbWeight::get().writes(1))
+			.saturating_add(RocksDbWeight::get().writes((11_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 34827).saturating_mul(n.into()))
+	}
+
+	fn lease_model() -> Weight {
+		Weight::from_parts(22_000_000, 12693)
+			.saturating_add(RocksDbWeight::get().reads(4))
+			.saturating_add(RocksDbWeight::get().writes(1))
+	}
+
+	fn accept_lease() -> Weight {
+		Weight::from_parts(45_000_000, 17868)
+			.saturating_add(RocksDbWeight::get().reads(6))
+			.saturating_add(RocksDbWeight::get().writes(6))
+	}
+
+	fn cancel_lease_offer() -> Weight {
+		Weight::from_parts(18_000_000, 10110)
+			.saturating_add(RocksDbWeight::get().reads(3))
+			.saturating_add(RocksDbWeight::get().writes(1))
+	}
+
+	fn end_leases(n: u32, ) -> Weight {
+		Weight::from_parts(3_000_000, 2552)
+			.saturating_add(Weight::from_parts(12_000_000, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(1))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(n.into())))
+			.saturating_add(RocksDbWeight::get().writes(1))
+			.saturating_add(RocksDbWeight::get().writes((2_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 2583).saturating_mul(n.into()))
+	}
+}
@@ -42,10 +42,14 @@ use sp_version::RuntimeVersion;
 
 // Local module imports
 use super::{
-	AccountId, Aura, Balance, Block, Executive, Grandpa, InherentDataExt, Nonce, Runtime,
-	RuntimeCall, RuntimeGenesisConfig, SessionKeys, System, TransactionPayment, VERSION,
+	configs::contracts_config::ContractsDryRunGasLimit, AIRegistry, AccountId, Aura, Balance,
+	Block, BlockNumber, Contracts, Executive, Grandpa, Hash, InherentDataExt, Nonce, Runtime,
+	RuntimeCall, RuntimeEvent, RuntimeGenesisConfig, SessionKeys, System, TransactionPayment,
+	VERSION,
 };
 
+type EventRecord = frame_system::EventRecord<RuntimeEvent, Hash>;
+
 impl_runtime_apis! {
 	impl sp_api::Core<Block> for Runtime {
 		fn version() -> RuntimeVersion {
@@ -220,6 +224,139 @@ impl_runtime_apis! {
 		}
 	}
 
+	#[api_version(8)]
+	impl pallet_ai_registry_runtime_api::AiRegistryApi<Block, AccountId> for Runtime {
+		fn rating_provenance(
+			model_id: pallet_ai_registry::ModelId,
+			rater: AccountId,
+		) -> Option<pallet_ai_registry::RatingRecord> {
+			AIRegistry::rating_provenance(model_id, &rater)
+		}
+
+		fn successor(model_id: pallet_ai_registry::ModelId) -> Option<pallet_ai_registry::ModelId> {
+			AIRegistry::successor(model_id)
+		}
+
+		fn recent_inferences(model_id: pallet_ai_registry::ModelId, eras: u32) -> u32 {
+			AIRegistry::recent_inferences(model_id, eras)
+		}
+
+		fn models_of(owner: AccountId) -> Vec<pallet_ai_registry::ModelId> {
+			AIRegistry::models_of(&owner)
+		}
+
+		fn top_models_by_rating(n: u32) -> Vec<pallet_ai_registry::ModelId> {
+			AIRegistry::top_models_by_rating(n)
+		}
+
+		fn top_models_by_inferences(n: u32) -> Vec<pallet_ai_registry::ModelId> {
+			AIRegistry::top_models_by_inferences(n)
+		}
+
+		fn recently_registered(n: u32) -> Vec<pallet_ai_registry::ModelId> {
+			AIRegistry::recently_registered(n)
+		}
+
+		fn models_by_name_prefix(prefix: Vec<u8>) -> Vec<pallet_ai_registry::ModelId> {
+			AIRegistry::models_by_name_prefix(&prefix)
+		}
+
+		fn median_rating(model_id: pallet_ai_registry::ModelId) -> Option<u8> {
+			AIRegistry::get_median_rating(model_id)
+		}
+
+		fn developer_page(developer: AccountId) -> Option<pallet_ai_registry::DeveloperPage> {
+			AIRegistry::developer_page(&developer)
+		}
+	}
+
+	#[api_version(2)]
+	impl pallet_ai_registry_runtime_api::AiRegistryStatsApi<Block> for Runtime {
+		fn global_stats() -> pallet_ai_registry::RegistryStats {
+			AIRegistry::global_stats()
+		}
+
+		fn model_stats(model_id: pallet_ai_registry::ModelId) -> Option<pallet_ai_registry::ModelStats> {
+			AIRegistry::model_stats(model_id)
+		}
+
+		fn recent_revenue(model_id: pallet_ai_registry::ModelId, eras: u32) -> u128 {
+			AIRegistry::recent_revenue(model_id, eras)
+		}
+	}
+
+	impl pallet_ai_registry_runtime_api::AiRegistryRevenueApi<Block, AccountId> for Runtime {
+		fn model_revenue(
+			model_id: pallet_ai_registry::ModelId,
+		) -> Option<pallet_ai_registry::RevenueHistory> {
+			AIRegistry::model_revenue(model_id)
+		}
+
+		fn owner_revenue(owner: AccountId) -> pallet_ai_registry::RevenueHistory {
+			AIRegistry::owner_revenue(&owner)
+		}
+	}
+
+	impl pallet_contracts::ContractsApi<Block, AccountId, Balance, BlockNumber, Hash, EventRecord>
+		for Runtime
+	{
+		fn call(
+			origin: AccountId,
+			dest: AccountId,
+			value: Balance,
+			gas_limit: Option<Weight>,
+			storage_deposit_limit: Option<Balance>,
+			input_data: Vec<u8>,
+		) -> pallet_contracts::ContractExecResult<Balance, EventRecord> {
+			Contracts::bare_call(
+				origin,
+				dest,
+				value,
+				gas_limit.unwrap_or_else(ContractsDryRunGasLimit::get),
+				storage_deposit_limit,
+				input_data,
+				pallet_contracts::DebugInfo::UnsafeDebug,
+				pallet_contracts::CollectEvents::UnsafeCollect,
+				pallet_contracts::Determinism::Enforced,
+			)
+		}
+
+		fn instantiate(
+			origin: AccountId,
+			value: Balance,
+			gas_limit: Option<Weight>,
+			storage_deposit_limit: Option<Balance>,
+			code: pallet_contracts::Code<Hash>,
+			data: Vec<u8>,
+			salt: Vec<u8>,
+		) -> pallet_contracts::ContractInstantiateResult<AccountId, Balance, EventRecord> {
+			Contracts::bare_instantiate(
+				origin,
+				value,
+				gas_limit.unwrap_or_else(ContractsDryRunGasLimit::get),
+				storage_deposit_limit,
+				code,
+				data,
+				salt,
+				pallet_contracts::DebugInfo::UnsafeDebug,
+				pallet_contracts::CollectEvents::UnsafeCollect,
+			)
+		}
+
+		fn upload_code(
+			origin: AccountId,
+			code: Vec<u8>,
+			storage_deposit_limit: Option<Balance>,
+			determinism: pallet_contracts::Determinism,
+		) -> pallet_contracts::CodeUploadResult<Hash, Balance> {
+			Contracts::bare_upload_code(origin, code, storage_deposit_limit, determinism)
+		}
+
+		fn get_storage(address: AccountId, key: Vec<u8>) -> pallet_contracts::GetStorageResult {
+			Contracts::get_storage(address, key)
+		}
+	}
+
 	#[cfg(feature = "runtime-benchmarks")]
 	impl frame_benchmarking::Benchmark<Block> for Runtime {
 		fn benchmark_metadata(extra: bool) -> (
@@ -28,7 +28,20 @@ frame_benchmarking::define_benchmarks!(
 	[frame_system, SystemBench::<Runtime>]
 	[frame_system_extensions, SystemExtensionsBench::<Runtime>]
 	[pallet_balances, Balances]
+	[pallet_assets, Assets]
 	[pallet_timestamp, Timestamp]
 	[pallet_sudo, Sudo]
 	[pallet_template, Template]
+	[pallet_ai_registry, AIRegistry]
+	[pallet_collective, Council]
+	[pallet_membership, DeveloperMembership]
+	[pallet_proxy, Proxy]
+	[pallet_compute_providers, ComputeProviders]
+	[pallet_inference, Inference]
+	[pallet_availability, Availability]
+	[pallet_marketplace, Marketplace]
+	[pallet_escrow, Escrow]
+	[pallet_contracts, Contracts]
+	[pallet_nfts, Nfts]
+	// pallet_disputes needs a payment source to benchmark against; add it once one exists.
 );
@@ -0,0 +1,219 @@
+//! Smart contracts, and the chain extension letting ink! contracts build on the registry.
+//!
+//! On-chain agents and escrow contracts read model metadata, ratings and prices
+//! through [`AiRegistryExtension`]. In ink!:
+//!
+//! ```text
+//! #[ink::chain_extension(extension = 0)]
+//! pub trait AiRegistry {
+//!     type ErrorCode = RegistryError;
+//!
+//!     #[ink(function = 1, handle_status = false)]
+//!     fn model(model_id: u64) -> Option<ModelInfo>;
+//!     #[ink(function = 2, handle_status = false)]
+//!     fn average_rating(model_id: u64) -> Option<u8>;
+//!     #[ink(function = 3, handle_status = false)]
+//!     fn price(model_id: u64) -> Option<Balance>;
+//!     #[ink(function = 4, handle_status = false)]
+//!     fn asset_price(model_id: u64, asset_id: u32) -> Option<Balance>;
+//! }
+//! ```
+//!
+//! `pallet-revive` has no chain extensions, so only `pallet-contracts` (Wasm) is wired.
+
+use alloc::vec::Vec;
+use codec::{Decode, Encode, MaxEncodedLen};
+use frame_support::{
+	parameter_types,
+	traits::{ConstBool, ConstU32, Nothing},
+	weights::Weight,
+};
+use frame_system::EnsureSigned;
+use pallet_ai_registry::{ModelId, ModelMetadata, ModelStatus, ModelType};
+use pallet_contracts::chain_extension::{
+	ChainExtension, Environment, Ext, InitState, Result as ExtensionResult, RetVal,
+};
+use scale_info::TypeInfo;
+use sp_runtime::{traits::SaturatedConversion, DispatchError, Perbill, RuntimeDebug};
+
+use super::RuntimeBlockWeights;
+use crate::{
+	AccountId, Balance, Balances, RandomnessCollectiveFlip, Runtime, RuntimeCall, RuntimeEvent,
+	RuntimeHoldReason, Timestamp, TransactionPayment, MICRO_UNIT, MILLI_UNIT,
+};
+
+/// Storage deposit for `items` storage items taking `bytes` bytes.
+pub(super) const fn deposit(items: u32, bytes: u32) -> Balance {
+	items as Balance * 10 * MILLI_UNIT + bytes as Balance * 10 * MICRO_UNIT
+}
+
+parameter_types! {
+	pub const ContractDepositPerItem: Balance = deposit(1, 0);
+	pub const ContractDepositPerByte: Balance = deposit(0, 1);
+	pub const DefaultDepositLimit: Balance = deposit(1024, 1024 * 1024);
+	pub Schedule: pallet_contracts::Schedule<Runtime> = Default::default();
+	pub const CodeHashLockupDepositPercent: Perbill = Perbill::from_percent(30);
+	pub const MaxDelegateDependencies: u32 = 32;
+	/// Gas limit of contract dry runs through the runtime API.
+	pub ContractsDryRunGasLimit: Weight = RuntimeBlockWeights::get().max_block;
+}
+
+// Collective flip mixes the last 81 block hashes, which the block authors choose, so
+// its output is predictable and can be biased. That is acceptable here because nothing
+// of value depends on it: no pallet of this runtime reads it, and contracts only reach
+// it through the deprecated `seal_random`, which ink! no longer exposes. A contract
+// that needs unbiased randomness must bring its own, e.g. commit-reveal or an oracle.
+impl pallet_insecure_randomness_collective_flip::Config for Runtime {}
+
+impl pallet_contracts::Config for Runtime {
+	type Time = Timestamp;
+	// Only the deprecated `seal_random` uses this; contracts must not rely on it, see
+	// the collective flip config above
+	type Randomness = RandomnessCollectiveFlip;
+	type Currency = Balances;
+	type RuntimeEvent = RuntimeEvent;
+	type RuntimeCall = RuntimeCall;
+	type RuntimeHoldReason = RuntimeHoldReason;
+	// Contracts read the registry through the chain extension; they dispatch no calls
+	type CallFilter = Nothing;
+	type WeightPrice = TransactionPayment;
+	type WeightInfo = pallet_contracts::weights::SubstrateWeight<Self>;
+	type ChainExtension = AiRegistryExtension;
+	type Schedule = Schedule;
+	type CallStack = [pallet_contracts::Frame<Self>; 5];
+	type DepositPerByte = ContractDepositPerByte;
+	type DefaultDepositLimit = DefaultDepositLimit;
+	type DepositPerItem = ContractDepositPerItem;
+	type CodeHashLockupDepositPercent = CodeHashLockupDepositPercent;
+	type AddressGenerator = pallet_contracts::DefaultAddressGenerator;
+	type MaxCodeLen = ConstU32<{ 123 * 1024 }>;
+	type MaxStorageKeyLen = ConstU32<128>;
+	type MaxTransientStorageSize = ConstU32<{ 1024 * 1024 }>;
+	type MaxDelegateDependencies = MaxDelegateDependencies;
+	type UnsafeUnstableInterface = ConstBool<false>;
+	type MaxDebugBufferLen = ConstU32<{ 2 * 1024 * 1024 }>;
+	type UploadOrigin = EnsureSigned<AccountId>;
+	type InstantiateOrigin = EnsureSigned<AccountId>;
+	type Migrations = ();
+	type Debug = ();
+	type Environment = ();
+	type ApiVersion = ();
+	// No transport: contracts do not send or execute XCM
+	type Xcm = ();
+}
+
+/// Function IDs of [`AiRegistryExtension`]
+pub mod func_ids {
+	/// `ModelId -> Option<ContractModelInfo>`
+	pub const MODEL: u16 = 1;
+	/// `ModelId -> Option<u8>`, the average rating
+	pub const AVERAGE_RATING: u16 = 2;
+	/// `ModelId -> Option<Balance>`, the native price of a request right now
+	pub const PRICE: u16 = 3;
+	/// `(ModelId, AssetId) -> Option<Balance>`, the price of a request right now in an
+	/// accepted asset
+	pub const ASSET_PRICE: u16 = 4;
+}
+
+/// What contracts see of a model
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo)]
+pub struct ContractModelInfo {
+	/// Owner of the model
+	pub owner: AccountId,
+	/// IPFS Content Identifier for model data
+	pub ipfs_cid: Vec<u8>,
+	/// Human-readable model name
+	pub name: Vec<u8>,
+	/// Type of AI model
+	pub model_type: ModelType,
+	/// Current status of the model
+	pub status: ModelStatus,
+	/// Block number when the model was created
+	pub created_at: u64,
+	/// Total number of inferences performed
+	pub total_inferences: u64,
+	/// Number of ratings received, each counted with its weight
+	pub rating_count: u32,
+}
+
+impl From<ModelMetadata<Runtime>> for ContractModelInfo {
+	fn from(model: ModelMetadata<Runtime>) -> Self {
+		Self {
+			owner: model.owner,
+			ipfs_cid: model.ipfs_cid.into_inner(),
+			name: model.name.into_inner(),
+			model_type: model.model_type,
+			status: model.status,
+			created_at: model.created_at,
+			total_inferences: model.total_inferences,
+			rating_count: model.rating_count,
+		}
+	}
+}
+
+/// Read-only access to the AI registry for contracts, under the chain specific
+/// extension ID `0`
+///
+/// Inputs and outputs are SCALE encoded, see [`func_ids`]. Unknown functions trap
+/// the calling contract.
+#[derive(Default)]
+pub struct AiRegistryExtension;
+
+impl AiRegistryExtension {
+	/// Weight of reading `reads` items, the largest `max_len` bytes long
+	fn reads(reads: u64, max_len: usize) -> Weight {
+		<Runtime as frame_system::Config>::DbWeight::get()
+			.reads(reads)
+			.saturating_add(Weight::from_parts(0, reads.saturating_mul(max_len as u64)))
+	}
+}
+
+impl ChainExtension<Runtime> for AiRegistryExtension {
+	fn call<E: Ext<T = Runtime>>(
+		&mut self,
+		env: Environment<E, InitState>,
+	) -> ExtensionResult<RetVal> {
+		let mut env = env.buf_in_buf_out();
+		if env.ext_id() != 0 {
+			return Err(DispatchError::Other("Unknown chain extension"));
+		}
+		let model_len = ModelMetadata::<Runtime>::max_encoded_len();
+
+		let output = match env.func_id() {
+			func_ids::MODEL => {
+				env.charge_weight(Self::reads(1, model_len))?;
+				let model_id: ModelId = env.read_as()?;
+				pallet_ai_registry::Models::<Runtime>::get(model_id)
+					.map(ContractModelInfo::from)
+					.encode()
+			}
+			func_ids::AVERAGE_RATING => {
+				env.charge_weight(Self::reads(1, model_len))?;
+				let model_id: ModelId = env.read_as()?;
+				pallet_ai_registry::Pallet::<Runtime>::get_average_rating(model_id).encode()
+			}
+			func_ids::PRICE => {
+				env.charge_weight(Self::reads(5, model_len))?;
+				let model_id: ModelId = env.read_as()?;
+				pallet_ai_registry::Models::<Runtime>::get(model_id)
+					.and_then(|model| {
+						pallet_inference::Pallet::<Runtime>::current_price(
+							model_id,
+							model.price.saturated_into(),
+						)
+						.ok()
+					})
+					.encode()
+			}
+			func_ids::ASSET_PRICE => {
+				env.charge_weight(Self::reads(3, Balance::max_encoded_len()))?;
+				let (model_id, asset_id): (ModelId, u32) = env.read_as()?;
+				pallet_inference::Pallet::<Runtime>::asset_price(model_id, asset_id).encode()
+			}
+			_ => return Err(DispatchError::Other("Unknown AI registry function")),
+		};
+
+		env.write(&output, false, None)?;
+		Ok(RetVal::Converging(0))
+	}
+}
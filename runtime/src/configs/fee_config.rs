@@ -0,0 +1,107 @@
+//! Fee discounts for model owners with stake in the registry.
+//!
+//! Accounts whose models hold at least [`StakedOwnerThreshold`] in reserved deposits
+//! pay [`StakedOwnerDiscount`] less for registry calls they sign. Tips are never
+//! discounted. The discount is decided when the fee is withdrawn, so a call that
+//! changes the signer's stake is refunded at the rate it was charged at.
+
+use core::marker::PhantomData;
+use frame_support::parameter_types;
+use pallet_transaction_payment::OnChargeTransaction;
+use sp_runtime::{
+	traits::{DispatchInfoOf, PostDispatchInfoOf},
+	transaction_validity::TransactionValidityError,
+	Perbill,
+};
+
+use crate::{AIRegistry, AccountId, Balance, Balances, Runtime, RuntimeCall, UNIT};
+
+parameter_types! {
+	/// Model deposits an account must have reserved to pay discounted fees
+	pub const StakedOwnerThreshold: Balance = 500 * UNIT;
+	/// Share of the fee, tip excluded, staked owners don't pay
+	pub const StakedOwnerDiscount: Perbill = Perbill::from_percent(25);
+}
+
+/// Charges fees with `Inner`, discounting registry calls of staked model owners
+pub struct StakedOwnerFees<Inner>(PhantomData<Inner>);
+
+impl<Inner> StakedOwnerFees<Inner> {
+	/// Whether `who` pays a discounted fee for `call`
+	fn is_discounted(who: &AccountId, call: &RuntimeCall) -> bool {
+		let threshold = StakedOwnerThreshold::get();
+		// Model deposits are reserved, so the reserved balance bounds the stake and
+		// spares reading the models of most accounts
+		matches!(call, RuntimeCall::AIRegistry(..))
+			&& Balances::reserved_balance(who) >= threshold
+			&& AIRegistry::model_stake(who) >= threshold
+	}
+
+	/// `fee` with the discount applied to everything but `tip`
+	fn discounted(fee: Balance, tip: Balance) -> Balance {
+		let base = fee.saturating_sub(tip);
+		base.saturating_sub(StakedOwnerDiscount::get() * base).saturating_add(tip)
+	}
+}
+
+impl<Inner> OnChargeTransaction<Runtime> for StakedOwnerFees<Inner>
+where
+	Inner: OnChargeTransaction<Runtime, Balance = Balance>,
+{
+	type Balance = Balance;
+	/// Whether the fee was discounted, and what `Inner` withdrew
+	type LiquidityInfo = (bool, Inner::LiquidityInfo);
+
+	fn withdraw_fee(
+		who: &AccountId,
+		call: &RuntimeCall,
+		dispatch_info: &DispatchInfoOf<RuntimeCall>,
+		fee: Balance,
+		tip: Balance,
+	) -> Result<Self::LiquidityInfo, TransactionValidityError> {
+		let discounted = Self::is_discounted(who, call);
+		let fee = if discounted { Self::discounted(fee, tip) } else { fee };
+		Ok((discounted, Inner::withdraw_fee(who, call, dispatch_info, fee, tip)?))
+	}
+
+	fn can_withdraw_fee(
+		who: &AccountId,
+		call: &RuntimeCall,
+		dispatch_info: &DispatchInfoOf<RuntimeCall>,
+		fee: Balance,
+		tip: Balance,
+	) -> Result<(), TransactionValidityError> {
+		let fee = if Self::is_discounted(who, call) { Self::discounted(fee, tip) } else { fee };
+		Inner::can_withdraw_fee(who, call, dispatch_info, fee, tip)
+	}
+
+	fn correct_and_deposit_fee(
+		who: &AccountId,
+		dispatch_info: &DispatchInfoOf<RuntimeCall>,
+		post_info: &PostDispatchInfoOf<RuntimeCall>,
+		corrected_fee: Balance,
+		tip: Balance,
+		(discounted, already_withdrawn): Self::LiquidityInfo,
+	) -> Result<(), TransactionValidityError> {
+		let corrected_fee =
+			if discounted { Self::discounted(corrected_fee, tip) } else { corrected_fee };
+		Inner::correct_and_deposit_fee(
+			who,
+			dispatch_info,
+			post_info,
+			corrected_fee,
+			tip,
+			already_withdrawn,
+		)
+	}
+
+	#[cfg(feature = "runtime-benchmarks")]
+	fn endow_account(who: &AccountId, amount: Balance) {
+		Inner::endow_account(who, amount)
+	}
+
+	#[cfg(feature = "runtime-benchmarks")]
+	fn minimum_balance() -> Balance {
+		Inner::minimum_balance()
+	}
+}
@@ -24,27 +24,50 @@
 // For more information, please refer to <http://unlicense.org>
 
 // Substrate and Polkadot dependencies
+use alloc::vec::Vec;
 use frame_support::{
 	derive_impl, parameter_types,
-	traits::{ConstBool, ConstU128, ConstU32, ConstU64, ConstU8, VariantCountOf},
+	traits::{
+		tokens::nonfungibles_v2, AsEnsureOriginWithArg, ConstBool, ConstU128, ConstU32, ConstU64,
+		ConstU8, Contains, Currency, EitherOf, EitherOfDiverse, Everything, Get, InsideBoth,
+		OnUnbalanced, VariantCountOf,
+	},
 	weights::{
 		constants::{RocksDbWeight, WEIGHT_REF_TIME_PER_SECOND},
 		IdentityFee, Weight,
 	},
+	PalletId,
+};
+use frame_system::{
+	limits::{BlockLength, BlockWeights},
+	EnsureRoot, EnsureSigned,
+};
+use pallet_nfts::{
+	CollectionConfig, CollectionSetting, CollectionSettings, ItemConfig, PalletFeatures,
 };
-use frame_system::limits::{BlockLength, BlockWeights};
 use pallet_transaction_payment::{ConstFeeMultiplier, FungibleAdapter, Multiplier};
 use sp_consensus_aura::sr25519::AuthorityId as AuraId;
-use sp_runtime::{traits::One, Perbill};
+use sp_runtime::{
+	traits::{AccountIdConversion, One, Verify},
+	transaction_validity::TransactionPriority,
+	DispatchResult, Perbill,
+};
 use sp_version::RuntimeVersion;
 
 // Local module imports
 use super::{
-	AccountId, Aura, Balance, Balances, Block, BlockNumber, Hash, Nonce, PalletInfo, Runtime,
-	RuntimeCall, RuntimeEvent, RuntimeFreezeReason, RuntimeHoldReason, RuntimeOrigin, RuntimeTask,
-	System, EXISTENTIAL_DEPOSIT, SLOT_DURATION, VERSION,
+	AIRegistry, AccountId, Assets, Aura, Balance, Balances, Block, BlockNumber, ComputeProviders,
+	DeveloperMembership, Escrow, Hash, Inference, Nfts, Nonce, PalletInfo, Runtime, RuntimeCall,
+	RuntimeEvent, RuntimeFreezeReason, RuntimeHoldReason, RuntimeOrigin, RuntimeTask, Signature,
+	System, UncheckedExtrinsic, DAYS, EXISTENTIAL_DEPOSIT, HOURS, MICRO_UNIT, MILLI_UNIT, MINUTES,
+	SLOT_DURATION, UNIT, VERSION,
 };
 
+pub mod contracts_config;
+pub mod fee_config;
+pub mod proxy_config;
+pub mod xcm_config;
+
 const NORMAL_DISPATCH_RATIO: Perbill = Perbill::from_percent(75);
 
 parameter_types! {
@@ -60,6 +83,39 @@ parameter_types! {
 	pub const SS58Prefix: u8 = 42;
 }
 
+/// Rejects sudo-dispatched AI registry calls once the registry's admin sunset has passed.
+///
+/// After the sunset, registry administration has to go through governance instead.
+pub struct AiRegistrySudoSunset;
+impl Contains<RuntimeCall> for AiRegistrySudoSunset {
+	fn contains(call: &RuntimeCall) -> bool {
+		let inner = match call {
+			RuntimeCall::Sudo(
+				pallet_sudo::Call::sudo { call }
+				| pallet_sudo::Call::sudo_unchecked_weight { call, .. }
+				| pallet_sudo::Call::sudo_as { call, .. },
+			) => call,
+			_ => return true,
+		};
+		!matches!(**inner, RuntimeCall::AIRegistry(_))
+			|| !pallet_ai_registry::Pallet::<Runtime>::admin_sunset_passed()
+	}
+}
+
+/// Rejects enclave requirements on models while no attestation can be registered.
+///
+/// The compute providers' `AttestationVerifier` is `()`, so no provider could ever serve
+/// a model requiring an enclave. Lift this filter together with shipping a verifier.
+pub struct NoEnclaveRequirements;
+impl Contains<RuntimeCall> for NoEnclaveRequirements {
+	fn contains(call: &RuntimeCall) -> bool {
+		!matches!(
+			call,
+			RuntimeCall::Inference(pallet_inference::Call::set_enclave_requirement { .. })
+		)
+	}
+}
+
 /// The default types are being injected by [`derive_impl`](`frame_support::derive_impl`) from
 /// [`SoloChainDefaultConfig`](`struct@frame_system::config_preludes::SolochainDefaultConfig`),
 /// but overridden as needed.
@@ -88,6 +144,12 @@ impl frame_system::Config for Runtime {
 	/// This is used as an identifier of the chain. 42 is the generic substrate prefix.
 	type SS58Prefix = SS58Prefix;
 	type MaxConsumers = frame_support::traits::ConstU32<16>;
+	/// The AI registry ages accounts so fresh ones cannot rate models.
+	type OnNewAccount = AIRegistry;
+	type OnKilledAccount = AIRegistry;
+	/// Sudo may not administer the AI registry after its admin sunset, and models cannot
+	/// require enclaves no provider can attest to.
+	type BaseCallFilter = InsideBoth<AiRegistrySudoSunset, NoEnclaveRequirements>;
 }
 
 impl pallet_aura::Config for Runtime {
@@ -143,7 +205,7 @@ parameter_types! {
 
 impl pallet_transaction_payment::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
-	type OnChargeTransaction = FungibleAdapter<Balances, ()>;
+	type OnChargeTransaction = fee_config::StakedOwnerFees<FungibleAdapter<Balances, ()>>;
 	type OperationalFeeMultiplier = ConstU8<5>;
 	type WeightToFee = IdentityFee<Balance>;
 	type LengthToFee = IdentityFee<Balance>;
@@ -157,8 +219,648 @@ impl pallet_sudo::Config for Runtime {
 	type WeightInfo = pallet_sudo::weights::SubstrateWeight<Runtime>;
 }
 
+parameter_types! {
+	pub const AssetDeposit: Balance = 10 * UNIT;
+	pub const AssetAccountDeposit: Balance = UNIT / 100;
+	pub const AssetMetadataDepositBase: Balance = UNIT;
+	pub const AssetMetadataDepositPerByte: Balance = UNIT / 100;
+	pub const AssetApprovalDeposit: Balance = UNIT / 100;
+}
+
+/// Fungible assets, e.g. stablecoins, that model owners can accept as payment.
+impl pallet_assets::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type Balance = Balance;
+	type RemoveItemsLimit = ConstU32<1000>;
+	type AssetId = u32;
+	type AssetIdParameter = codec::Compact<u32>;
+	type Currency = Balances;
+	type CreateOrigin = AsEnsureOriginWithArg<EnsureSigned<AccountId>>;
+	type ForceOrigin =
+		EitherOfDiverse<pallet_ai_registry::EnsureRootBeforeSunset<Runtime>, CouncilMajority>;
+	type AssetDeposit = AssetDeposit;
+	type AssetAccountDeposit = AssetAccountDeposit;
+	type MetadataDepositBase = AssetMetadataDepositBase;
+	type MetadataDepositPerByte = AssetMetadataDepositPerByte;
+	type ApprovalDeposit = AssetApprovalDeposit;
+	type StringLimit = ConstU32<50>;
+	type Freezer = ();
+	type Holder = ();
+	type Extra = ();
+	type CallbackHandle = ();
+	type WeightInfo = pallet_assets::weights::SubstrateWeight<Runtime>;
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkHelper = ();
+}
+
+parameter_types! {
+	pub const NftCollectionDeposit: Balance = 10 * UNIT;
+	pub const NftItemDeposit: Balance = UNIT / 100;
+	pub const NftMetadataDepositBase: Balance = UNIT / 10;
+	pub const NftAttributeDepositBase: Balance = UNIT / 10;
+	pub const NftDepositPerByte: Balance = UNIT / 1000;
+	pub NftFeatures: PalletFeatures = PalletFeatures::all_enabled();
+	/// Holds one NFT per registered model, out of reach of the IDs `create` hands out.
+	pub const ModelCollectionId: u32 = u32::MAX;
+	/// Keyless owner and admin of the model collection.
+	pub ModelCollectionOwner: AccountId = PalletId(*b"py/aimdl").into_account_truncating();
+}
+
+/// NFTs, including the ones representing registered models.
+impl pallet_nfts::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type CollectionId = u32;
+	// Model NFTs use the model ID as item ID
+	type ItemId = pallet_ai_registry::ModelId;
+	type Currency = Balances;
+	type ForceOrigin =
+		EitherOfDiverse<pallet_ai_registry::EnsureRootBeforeSunset<Runtime>, CouncilMajority>;
+	type CreateOrigin = AsEnsureOriginWithArg<EnsureSigned<AccountId>>;
+	type Locker = ();
+	type CollectionDeposit = NftCollectionDeposit;
+	type ItemDeposit = NftItemDeposit;
+	type MetadataDepositBase = NftMetadataDepositBase;
+	type AttributeDepositBase = NftAttributeDepositBase;
+	type DepositPerByte = NftDepositPerByte;
+	type StringLimit = ConstU32<256>;
+	type KeyLimit = ConstU32<64>;
+	type ValueLimit = ConstU32<256>;
+	type ApprovalsLimit = ConstU32<20>;
+	type ItemAttributesApprovalsLimit = ConstU32<20>;
+	type MaxTips = ConstU32<10>;
+	type MaxDeadlineDuration = ConstU32<{ 365 * DAYS }>;
+	type MaxAttributesPerCall = ConstU32<10>;
+	type Features = NftFeatures;
+	type OffchainSignature = Signature;
+	type OffchainPublic = <Signature as Verify>::Signer;
+	type WeightInfo = pallet_nfts::weights::SubstrateWeight<Runtime>;
+	#[cfg(feature = "runtime-benchmarks")]
+	type Helper = ();
+	type BlockNumberProvider = System;
+}
+
+/// Model NFTs in the `ModelCollectionId` collection, transferable and tradable like
+/// any other NFT.
+pub struct ModelNfts;
+
+impl pallet_ai_registry::ModelNfts<AccountId> for ModelNfts {
+	fn mint(model_id: pallet_ai_registry::ModelId, owner: &AccountId) -> DispatchResult {
+		let collection = ModelCollectionId::get();
+		if Nfts::collection_owner(collection).is_none() {
+			// Created on first use; the registry deposit already covers each model
+			let admin = ModelCollectionOwner::get();
+			Nfts::do_create_collection(
+				collection,
+				admin.clone(),
+				admin.clone(),
+				CollectionConfig {
+					settings: CollectionSettings::from_disabled(
+						CollectionSetting::DepositRequired.into(),
+					),
+					max_supply: None,
+					mint_settings: Default::default(),
+				},
+				0,
+				pallet_nfts::Event::ForceCreated { collection, owner: admin },
+			)?;
+		}
+		<Nfts as nonfungibles_v2::Mutate<_, _>>::mint_into(
+			&collection,
+			&model_id,
+			owner,
+			&ItemConfig::default(),
+			false,
+		)
+	}
+
+	fn burn(model_id: pallet_ai_registry::ModelId) -> DispatchResult {
+		<Nfts as nonfungibles_v2::Mutate<_, ItemConfig>>::burn(
+			&ModelCollectionId::get(),
+			&model_id,
+			None,
+		)
+	}
+
+	fn owner(model_id: pallet_ai_registry::ModelId) -> Option<AccountId> {
+		Nfts::owner(ModelCollectionId::get(), model_id)
+	}
+
+	fn transfer(model_id: pallet_ai_registry::ModelId, to: &AccountId) -> DispatchResult {
+		<Nfts as nonfungibles_v2::Transfer<_>>::transfer(&ModelCollectionId::get(), &model_id, to)
+	}
+}
+
 /// Configure the pallet-template in pallets/template.
 impl pallet_template::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type WeightInfo = pallet_template::weights::SubstrateWeight<Runtime>;
 }
+
+parameter_types! {
+	pub const CouncilMotionDuration: BlockNumber = 3 * DAYS;
+	pub const CouncilMaxProposals: u32 = 100;
+	pub const CouncilMaxMembers: u32 = 100;
+	pub MaxCollectivesProposalWeight: Weight = Perbill::from_percent(50) * RuntimeBlockWeights::get().max_block;
+}
+
+pub type CouncilCollective = pallet_collective::Instance1;
+
+/// The council governs the AI registry once the admin sunset has passed.
+impl pallet_collective::Config<CouncilCollective> for Runtime {
+	type RuntimeOrigin = RuntimeOrigin;
+	type Proposal = RuntimeCall;
+	type RuntimeEvent = RuntimeEvent;
+	type MotionDuration = CouncilMotionDuration;
+	type MaxProposals = CouncilMaxProposals;
+	type MaxMembers = CouncilMaxMembers;
+	type DefaultVote = pallet_collective::PrimeDefaultVote;
+	type WeightInfo = pallet_collective::weights::SubstrateWeight<Runtime>;
+	type SetMembersOrigin = EnsureRoot<AccountId>;
+	type MaxProposalWeight = MaxCollectivesProposalWeight;
+	type DisapproveOrigin = EnsureRoot<AccountId>;
+	type KillOrigin = EnsureRoot<AccountId>;
+	type Consideration = ();
+}
+
+/// Simple majority of the council.
+type CouncilMajority =
+	pallet_collective::EnsureProportionMoreThan<AccountId, CouncilCollective, 1, 2>;
+
+/// Council supermajority, used as the referendum track for extending the admin sunset.
+type CouncilSupermajority =
+	pallet_collective::EnsureProportionAtLeast<AccountId, CouncilCollective, 2, 3>;
+
+parameter_types! {
+	pub const DeveloperMaxMembers: u32 = 100;
+}
+
+pub type DeveloperMembershipInstance = pallet_membership::Instance1;
+
+/// Sudo until the admin sunset, then a council majority, manages the developer allowlist.
+type DeveloperAdmin =
+	EitherOfDiverse<pallet_ai_registry::EnsureRootBeforeSunset<Runtime>, CouncilMajority>;
+
+/// Developers allowed to register models while the AI registry requires an allowlist,
+/// for consortium chains.
+impl pallet_membership::Config<DeveloperMembershipInstance> for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type AddOrigin = DeveloperAdmin;
+	type RemoveOrigin = DeveloperAdmin;
+	type SwapOrigin = DeveloperAdmin;
+	type ResetOrigin = DeveloperAdmin;
+	type PrimeOrigin = DeveloperAdmin;
+	type MembershipInitialized = ();
+	type MembershipChanged = ();
+	type MaxMembers = DeveloperMaxMembers;
+	type WeightInfo = pallet_membership::weights::SubstrateWeight<Runtime>;
+}
+
+parameter_types! {
+	pub const MinimumModelStake: Balance = 100 * UNIT;
+	pub const RegistrationFee: Balance = UNIT;
+	pub const MaxCidLength: u32 = 128;
+	pub const MaxOrganizationMembers: u32 = 50;
+	pub const MaxNameLength: u32 = 256;
+	pub const MaxDescriptionLength: u32 = 1024;
+	/// Roughly 1 UNIT per gigabyte of declared model artifact.
+	pub const DepositPerByte: Balance = MICRO_UNIT / 1_000;
+	/// Sudo administers the registry for the first six months of the chain.
+	pub const AdminSunsetBlock: BlockNumber = 180 * DAYS;
+	pub const MaxCallbacksPerModel: u32 = 8;
+	pub const MaxExportBatch: u32 = 20;
+	/// Rent accrues weekly, once a model has seen no inference for about six months.
+	pub const RentEraLength: BlockNumber = 7 * DAYS;
+	pub const RentFreeEras: u32 = 26;
+	pub const RentPerEra: Balance = UNIT / 10;
+	pub const RentReapDelay: BlockNumber = 30 * DAYS;
+	pub const SlashFraction: Perbill = Perbill::from_percent(50);
+	pub const MaxShareholders: u32 = 10;
+	/// Holders of a tenth of a model can put metadata changes to a vote.
+	pub const ProposalShareThreshold: Perbill = Perbill::from_percent(10);
+	/// Creators keep 5% of every later sale of their models.
+	pub const CreatorRoyalty: Perbill = Perbill::from_percent(5);
+	/// A model can cite up to 16 training datasets.
+	pub const MaxDatasetsPerModel: u32 = 16;
+	/// Forks can pass at most half of their inference revenue to their parent.
+	pub const MaxParentShare: Perbill = Perbill::from_percent(50);
+	/// Collections group up to 64 models.
+	pub const MaxModelsPerCollection: u32 = 64;
+	/// A model can be in up to 16 collections.
+	pub const MaxCollectionsPerModel: u32 = 16;
+	/// Accounts can bookmark up to 128 models.
+	pub const MaxBookmarks: u32 = 128;
+	/// Accounts can own up to 256 models.
+	pub const MaxModelsPerOwner: u32 = 256;
+	/// Up to 256 accounts can watch a model, listed 32 per change notification.
+	pub const MaxWatchers: u32 = 256;
+	pub const WatchersPerEvent: u32 = 32;
+	/// Holds registration fees; models averaging four stars over at least ten ratings
+	/// get 5% of their fee back every rent era.
+	pub const RegistryPalletId: PalletId = PalletId(*b"py/aireg");
+	pub const RebateRatingThreshold: u8 = 4;
+	pub const RebateMinRatings: u32 = 10;
+	pub const RebatePerEra: Perbill = Perbill::from_percent(5);
+	/// Every week 100 UNIT are minted for the hundred best-rated models with at least
+	/// ten ratings.
+	pub const MaxRewardedModels: u32 = 100;
+	pub const RewardMinRatings: u32 = 10;
+	pub const RewardPerEra: Balance = 100 * UNIT;
+	/// Auditors see the last 32 metadata updates of each model on-chain.
+	pub const MaxUpdateHistory: u32 = 32;
+	/// Inference counts are kept for the last 14 weekly eras.
+	pub const UsageWindow: u32 = 14;
+	/// Front pages can list the hundred most used models.
+	pub const LeaderboardSize: u32 = 100;
+	/// Name searches look models up by the first three letters of their name.
+	pub const NamePrefixLength: u32 = 3;
+	/// Raters weigh with the square root of the whole units they paid for a model's
+	/// inferences, so paying users outweigh fresh accounts without buying the rating.
+	pub const RatingStakeUnit: u128 = UNIT;
+	/// Accounts rate once they are a day old and still hold the existential deposit.
+	pub const MinRaterAge: BlockNumber = DAYS;
+	pub const MinRaterBalance: Balance = EXISTENTIAL_DEPOSIT;
+	/// An account can rate 20 models a week, throttling coordinated review campaigns.
+	pub const MaxRatingsPerEra: u32 = 20;
+	/// Models averaging under two stars over their last twenty ratings, with at least
+	/// ten of them, are paused until their owner or the council reactivates them.
+	pub const RatingWindow: u32 = 20;
+	pub const PauseMinRatings: u32 = 10;
+	pub const PauseRatingFloor: u8 = 2;
+	/// Ten reports from eligible raters suspend a model until the council rules.
+	pub const ReportThreshold: u32 = 10;
+	/// Owners appealing a forced deactivation stake a tenth of the minimum model stake.
+	pub const AppealBond: Balance = 10 * UNIT;
+	/// Owners can keep a model they deactivate usable for up to thirty days.
+	pub const MaxDeactivationGrace: BlockNumber = 30 * DAYS;
+	pub const MaxDeactivationsPerBlock: u32 = 16;
+	/// Users of a retiring model are reminded a week before it is deactivated.
+	pub const DeactivationNotice: BlockNumber = 7 * DAYS;
+	pub const MaxPriceChangesPerBlock: u32 = 16;
+	/// Collects slashed registry deposits and funds model incentives.
+	pub const TreasuryPalletId: PalletId = PalletId(*b"py/trsry");
+	pub TreasuryAccount: AccountId = TreasuryPalletId::get().into_account_truncating();
+}
+
+/// Moves slashed funds into the treasury account.
+pub struct ToTreasury;
+
+impl OnUnbalanced<pallet_balances::NegativeImbalance<Runtime>> for ToTreasury {
+	fn on_nonzero_unbalanced(amount: pallet_balances::NegativeImbalance<Runtime>) {
+		Balances::resolve_creating(&TreasuryAccount::get(), amount);
+	}
+}
+
+/// Configure the AI registry pallet in pallets/ai-registry.
+impl pallet_ai_registry::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type RuntimeCall = RuntimeCall;
+	type WeightInfo = pallet_ai_registry::weights::SubstrateWeight<Runtime>;
+	type Currency = Balances;
+	type MinimumModelStake = MinimumModelStake;
+	type RegistrationFee = RegistrationFee;
+	type MaxCidLength = MaxCidLength;
+	type MaxNameLength = MaxNameLength;
+	type MaxDescriptionLength = MaxDescriptionLength;
+	type DepositPerByte = DepositPerByte;
+	// Local accounts, and teams on other chains through their derived account
+	type RegistrationOrigin = EitherOf<EnsureSigned<AccountId>, xcm_config::XcmRegistrationOrigin>;
+	type GovernanceOrigin =
+		EitherOfDiverse<pallet_ai_registry::EnsureRootBeforeSunset<Runtime>, CouncilMajority>;
+	type AdminSunsetBlock = AdminSunsetBlock;
+	type SunsetExtensionOrigin = CouncilSupermajority;
+	type MaxCallbacksPerModel = MaxCallbacksPerModel;
+	type InferenceReceipts = Inference;
+	type RequireReceiptToRate = ConstBool<true>;
+	type RatingWeight = pallet_ai_registry::QuadraticRatingWeight<Inference, RatingStakeUnit>;
+	type MinRaterAge = MinRaterAge;
+	type MinRaterBalance = MinRaterBalance;
+	type MaxRatingsPerEra = MaxRatingsPerEra;
+	type RatingWindow = RatingWindow;
+	type PauseMinRatings = PauseMinRatings;
+	type PauseRatingFloor = PauseRatingFloor;
+	type ReportThreshold = ReportThreshold;
+	type AppealBond = AppealBond;
+	type MaxDeactivationGrace = MaxDeactivationGrace;
+	type MaxDeactivationsPerBlock = MaxDeactivationsPerBlock;
+	type DeactivationNotice = DeactivationNotice;
+	type MaxPriceChangesPerBlock = MaxPriceChangesPerBlock;
+	// Registrations are open; permissioned deployments can switch approval on
+	type RequireRegistrationApproval = ConstBool<false>;
+	type RegistrationApprovalOrigin =
+		EitherOfDiverse<pallet_ai_registry::EnsureRootBeforeSunset<Runtime>, CouncilMajority>;
+	// Open to everyone until governance requires the allowlist
+	type DeveloperAllowlist = DeveloperMembership;
+	type MaxOrganizationMembers = MaxOrganizationMembers;
+	// No identity pallet yet; age and balance keep throwaway accounts out
+	type VerifiedRaters = Everything;
+	type InferenceOrigin = pallet_inference::EnsureInference;
+	type ModelNfts = ModelNfts;
+	type OnModelRegistered = ();
+	type OnModelStatusChanged = ();
+	type MaxShareholders = MaxShareholders;
+	type ProposalShareThreshold = ProposalShareThreshold;
+	type CreatorRoyalty = CreatorRoyalty;
+	type MaxDatasetsPerModel = MaxDatasetsPerModel;
+	type MaxParentShare = MaxParentShare;
+	type MaxModelsPerCollection = MaxModelsPerCollection;
+	type MaxCollectionsPerModel = MaxCollectionsPerModel;
+	type MaxBookmarks = MaxBookmarks;
+	type MaxModelsPerOwner = MaxModelsPerOwner;
+	type MaxWatchers = MaxWatchers;
+	type WatchersPerEvent = WatchersPerEvent;
+	type OwnerSignature = Signature;
+	type OwnerSigner = <Signature as Verify>::Signer;
+	type MaxExportBatch = MaxExportBatch;
+	type RentEraLength = RentEraLength;
+	type RentFreeEras = RentFreeEras;
+	type RentPerEra = RentPerEra;
+	type RentReapDelay = RentReapDelay;
+	type SlashOrigin =
+		EitherOfDiverse<pallet_ai_registry::EnsureRootBeforeSunset<Runtime>, CouncilMajority>;
+	type SlashFraction = SlashFraction;
+	type Slash = ToTreasury;
+	type PalletId = RegistryPalletId;
+	type RebateRatingThreshold = RebateRatingThreshold;
+	type RebateMinRatings = RebateMinRatings;
+	type RebatePerEra = RebatePerEra;
+	type IncentiveOrigin = CouncilMajority;
+	type IncentiveSource = TreasuryAccount;
+	type MaxRewardedModels = MaxRewardedModels;
+	type RewardMinRatings = RewardMinRatings;
+	type RewardPerEra = RewardPerEra;
+	type MaxUpdateHistory = MaxUpdateHistory;
+	type UsageWindow = UsageWindow;
+	type LeaderboardSize = LeaderboardSize;
+	type NamePrefixLength = NamePrefixLength;
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkHelper = AiRegistryBenchmarkHelper;
+}
+
+parameter_types! {
+	pub const DisputeBond: Balance = 10 * UNIT;
+	pub const ResponseBond: Balance = 10 * UNIT;
+	pub const DisputeResponsePeriod: BlockNumber = 3 * DAYS;
+}
+
+/// Configure the disputes pallet in pallets/disputes.
+impl pallet_disputes::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type WeightInfo = pallet_disputes::weights::SubstrateWeight<Runtime>;
+	type Currency = Balances;
+	type Escrow = Escrow;
+	// Inference results, disputed under their request ID within the acceptance window.
+	type Payments = Inference;
+	type Models = AIRegistry;
+	type ArbitrationOrigin =
+		EitherOfDiverse<pallet_ai_registry::EnsureRootBeforeSunset<Runtime>, CouncilMajority>;
+	type DisputeBond = DisputeBond;
+	type ResponseBond = ResponseBond;
+	type ResponsePeriod = DisputeResponsePeriod;
+}
+
+parameter_types! {
+	pub const MinimumProviderStake: Balance = 100 * UNIT;
+	pub const MaxProviderCapabilities: u32 = 16;
+	pub const MaxConcurrentJobs: u32 = 32;
+	pub const ProviderUnbondingPeriod: BlockNumber = 7 * DAYS;
+	pub const MaxQuoteLength: u32 = 16 * 1024;
+	pub const AttestationValidity: BlockNumber = 7 * DAYS;
+}
+
+/// Configure the compute providers pallet in pallets/compute-providers.
+impl pallet_compute_providers::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type WeightInfo = pallet_compute_providers::weights::SubstrateWeight<Runtime>;
+	type Currency = Balances;
+	type MinimumProviderStake = MinimumProviderStake;
+	type MaxCapabilities = MaxProviderCapabilities;
+	type MaxConcurrentJobs = MaxConcurrentJobs;
+	type UnbondingPeriod = ProviderUnbondingPeriod;
+	type SlashOrigin =
+		EitherOfDiverse<pallet_ai_registry::EnsureRootBeforeSunset<Runtime>, CouncilMajority>;
+	type Slash = ToTreasury;
+	// No quote verifier is available yet; attestations cannot be registered, and
+	// `NoEnclaveRequirements` keeps models from requiring them.
+	type AttestationVerifier = ();
+	type MaxQuoteLength = MaxQuoteLength;
+	type AttestationValidity = AttestationValidity;
+}
+
+parameter_types! {
+	pub const MaxInferenceQueueLength: u32 = 256;
+	pub const AssignmentTimeout: BlockNumber = 10 * MINUTES;
+	pub const CompletionTimeout: BlockNumber = HOURS;
+	pub const RevealDelay: BlockNumber = 2 * MINUTES;
+	pub const RevealWindow: BlockNumber = 10 * MINUTES;
+	pub const MaxProofLength: u32 = 64 * 1024;
+	pub const InferencePalletId: PalletId = PalletId(*b"py/infer");
+	/// Per-account model quotas reset daily.
+	pub const QuotaPeriod: BlockNumber = DAYS;
+	pub const MaxCouponLength: u32 = 32;
+	/// Owners can pass up to a fifth of a model's price to referrers.
+	pub const MaxReferralShare: Perbill = Perbill::from_percent(20);
+	/// Demand-priced models follow their requests of the last hour.
+	pub const DemandWindow: BlockNumber = HOURS;
+	/// Earnings nobody claimed are paid out weekly, for 16 models per block.
+	pub const PayoutEraLength: BlockNumber = 7 * DAYS;
+	pub const MaxPayoutsPerBlock: u32 = 16;
+	/// Inference requests gain one unit of pool priority per milli-unit they pay, up
+	/// to what a thousand units pay.
+	pub const PaymentPerPriority: Balance = MILLI_UNIT;
+	pub const MaxPriorityBoost: u64 = 1_000_000;
+	/// Buyers have a day to accept or dispute a result before it is paid for, and
+	/// up to 64 payments are released per block.
+	pub const AcceptanceWindow: BlockNumber = DAYS;
+	pub const MaxAutoReleasesPerBlock: u32 = 64;
+	/// Refunding a request past its model's response deadline pays 1% of it from the
+	/// provider's stake.
+	pub const TimeoutRefundReward: Perbill = Perbill::from_percent(1);
+	pub const MaxInferenceBatchSize: u32 = 16;
+	/// Fees run up on tabs until a buyer owes a model a unit, and up to 32 tabs
+	/// left at the end of an era are settled per block.
+	pub const TabSettlementThreshold: Balance = UNIT;
+	pub const MaxTabSettlementsPerBlock: u32 = 32;
+	/// Metered sessions without a usage report for an hour can be expired.
+	pub const MeteredSessionTimeout: BlockNumber = HOURS;
+	/// Challenging a result bonds 10 units, the provider or model owner has a day to
+	/// concede before arbitration steps in, and upheld challengers get 10% of the
+	/// payment from the provider's stake.
+	pub const InferenceChallengeBond: Balance = 10 * UNIT;
+	pub const InferenceChallengePeriod: BlockNumber = DAYS;
+	pub const InferenceChallengeReward: Perbill = Perbill::from_percent(10);
+	/// Paid out earnings go 5% to the treasury and 5% to the validators.
+	pub const TreasuryEarningsShare: Perbill = Perbill::from_percent(5);
+	pub const ValidatorEarningsShare: Perbill = Perbill::from_percent(5);
+}
+
+/// Takes the treasury's share of paid out earnings, and the validators' share split
+/// evenly between the current Aura authorities.
+///
+/// Only `MaxRevenueShares` cuts are paid, so with more than nine authorities the last
+/// ones go without.
+pub struct InferenceEarningsCuts;
+
+impl Get<Vec<(AccountId, Perbill)>> for InferenceEarningsCuts {
+	fn get() -> Vec<(AccountId, Perbill)> {
+		let validators = pallet_aura::Authorities::<Runtime>::get();
+		let mut cuts = Vec::with_capacity(validators.len() + 1);
+		cuts.push((TreasuryAccount::get(), TreasuryEarningsShare::get()));
+		if !validators.is_empty() {
+			let share = Perbill::from_parts(
+				ValidatorEarningsShare::get().deconstruct() / validators.len() as u32,
+			);
+			cuts.extend(validators.into_iter().map(|id| (AccountId::from(id.into_inner()), share)));
+		}
+		cuts
+	}
+}
+
+/// Configure the inference pallet in pallets/inference.
+impl pallet_inference::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type WeightInfo = pallet_inference::weights::SubstrateWeight<Runtime>;
+	type Currency = Balances;
+	type Models = AIRegistry;
+	type MaxRevenueShares = MaxShareholders;
+	type Providers = ComputeProviders;
+	type MaxQueueLength = MaxInferenceQueueLength;
+	type AssignmentTimeout = AssignmentTimeout;
+	type CompletionTimeout = CompletionTimeout;
+	type RevealDelay = RevealDelay;
+	type RevealWindow = RevealWindow;
+	// No zkML verifier is available yet; payments never wait on a proof.
+	type ProofVerifier = ();
+	type MaxProofLength = MaxProofLength;
+	// No price oracle is connected yet; models can only be priced in native tokens.
+	type PriceFeed = ();
+	// No cut is taken at settlement; the treasury and validators are paid out of earnings.
+	type OnInferenceFee = ();
+	type Assets = Assets;
+	type PalletId = InferencePalletId;
+	type QuotaPeriod = QuotaPeriod;
+	type MaxCouponLength = MaxCouponLength;
+	type MaxReferralShare = MaxReferralShare;
+	type DemandWindow = DemandWindow;
+	type PayoutEraLength = PayoutEraLength;
+	type MaxPayoutsPerBlock = MaxPayoutsPerBlock;
+	type EarningsCuts = InferenceEarningsCuts;
+	type PaymentPerPriority = PaymentPerPriority;
+	type MaxPriorityBoost = MaxPriorityBoost;
+	type MaxCidLength = MaxCidLength;
+	type AcceptanceWindow = AcceptanceWindow;
+	type MaxAutoReleasesPerBlock = MaxAutoReleasesPerBlock;
+	type ArbitrationOrigin =
+		EitherOfDiverse<pallet_ai_registry::EnsureRootBeforeSunset<Runtime>, CouncilMajority>;
+	type TimeoutRefundReward = TimeoutRefundReward;
+	type MaxBatchSize = MaxInferenceBatchSize;
+	type TabSettlementThreshold = TabSettlementThreshold;
+	type MaxTabSettlementsPerBlock = MaxTabSettlementsPerBlock;
+	type MeteredSessionTimeout = MeteredSessionTimeout;
+	type Escrow = Escrow;
+	type ChallengeBond = InferenceChallengeBond;
+	type ChallengePeriod = InferenceChallengePeriod;
+	type ChallengeReward = InferenceChallengeReward;
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkHelper = ();
+}
+
+parameter_types! {
+	pub const MaxAvailabilityReporters: u32 = 32;
+	pub const AvailabilityCheckInterval: BlockNumber = 10 * MINUTES;
+	pub const AvailabilitySampleSize: u32 = 20;
+	pub const UnavailableThreshold: u32 = 6;
+	pub const MaxUnsignedAvailabilityReports: u32 = 8;
+	pub const AvailabilityReportPriority: TransactionPriority = TransactionPriority::MAX / 2;
+}
+
+/// Configure the availability pallet in pallets/availability.
+impl pallet_availability::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type WeightInfo = pallet_availability::weights::SubstrateWeight<Runtime>;
+	type AuthorityId = pallet_availability::crypto::AvailabilityAuthId;
+	type Models = AIRegistry;
+	type ManagerOrigin =
+		EitherOfDiverse<pallet_ai_registry::EnsureRootBeforeSunset<Runtime>, CouncilMajority>;
+	type MaxReporters = MaxAvailabilityReporters;
+	type CheckInterval = AvailabilityCheckInterval;
+	type SampleSize = AvailabilitySampleSize;
+	type UnavailableThreshold = UnavailableThreshold;
+	type MaxUnsignedPerBlock = MaxUnsignedAvailabilityReports;
+	type UnsignedPriority = AvailabilityReportPriority;
+}
+
+parameter_types! {
+	pub const ListingDeposit: Balance = UNIT;
+	pub const MaxAuctionDuration: BlockNumber = 30 * DAYS;
+	pub const MaxAuctionsPerBlock: u32 = 8;
+	pub const MaxLeaseDuration: BlockNumber = 365 * DAYS;
+	pub const MaxLeasesPerBlock: u32 = 8;
+}
+
+/// Configure the marketplace pallet in pallets/marketplace.
+impl pallet_marketplace::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type WeightInfo = pallet_marketplace::weights::SubstrateWeight<Runtime>;
+	type Currency = Balances;
+	type Escrow = Escrow;
+	type Models = AIRegistry;
+	type ListingDeposit = ListingDeposit;
+	type MaxAuctionDuration = MaxAuctionDuration;
+	type MaxAuctionsPerBlock = MaxAuctionsPerBlock;
+	type MaxLeaseDuration = MaxLeaseDuration;
+	type MaxLeasesPerBlock = MaxLeasesPerBlock;
+}
+
+parameter_types! {
+	pub const MaxEscrowTimeoutsPerBlock: u32 = 64;
+	pub const EscrowPalletId: PalletId = PalletId(*b"py/escrw");
+}
+
+/// Configure the escrow pallet in pallets/escrow.
+impl pallet_escrow::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type WeightInfo = pallet_escrow::weights::SubstrateWeight<Runtime>;
+	type Currency = Balances;
+	type Assets = Assets;
+	type PalletId = EscrowPalletId;
+	type MaxTimeoutsPerBlock = MaxEscrowTimeoutsPerBlock;
+}
+
+impl frame_system::offchain::SigningTypes for Runtime {
+	type Public = <Signature as Verify>::Signer;
+	type Signature = Signature;
+}
+
+impl<LocalCall> frame_system::offchain::CreateTransactionBase<LocalCall> for Runtime
+where
+	RuntimeCall: From<LocalCall>,
+{
+	type Extrinsic = UncheckedExtrinsic;
+	type RuntimeCall = RuntimeCall;
+}
+
+/// Lets off-chain workers submit unsigned transactions, e.g. signed availability reports.
+impl<LocalCall> frame_system::offchain::CreateInherent<LocalCall> for Runtime
+where
+	RuntimeCall: From<LocalCall>,
+{
+	fn create_inherent(call: RuntimeCall) -> UncheckedExtrinsic {
+		UncheckedExtrinsic::new_bare(call)
+	}
+}
+
+/// Signs migration packets with a fresh sr25519 key from the benchmark keystore.
+#[cfg(feature = "runtime-benchmarks")]
+pub struct AiRegistryBenchmarkHelper;
+
+#[cfg(feature = "runtime-benchmarks")]
+impl pallet_ai_registry::BenchmarkHelper<Signature, AccountId> for AiRegistryBenchmarkHelper {
+	fn create_signature(entropy: &[u8], msg: &[u8]) -> (Signature, AccountId) {
+		use sp_runtime::{traits::IdentifyAccount, MultiSigner, RuntimeAppPublic};
+
+		let public = AuraId::generate_pair(Some(entropy.to_vec()));
+		let signature = public.sign(&msg).expect("key was just generated; qed");
+		let signer = MultiSigner::Sr25519(public.into());
+		(Signature::Sr25519(signature.into()), signer.into_account())
+	}
+}
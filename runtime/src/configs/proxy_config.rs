@@ -0,0 +1,111 @@
+//! Proxies, so owners can delegate model management to hot keys.
+//!
+//! A [`ProxyType::AiModelManagement`] proxy can update, deactivate and reactivate
+//! the owner's models and claim what they earned, but cannot move the models, their
+//! shares or any balance to another account. Calls paying registration fees, such as
+//! registering or forking a model, are left to the owner key for that reason. Keeping
+//! the owner key cold then only risks the models' metadata, not their ownership.
+
+use codec::{Decode, DecodeWithMemTracking, Encode, MaxEncodedLen};
+use frame_support::{parameter_types, traits::InstanceFilter};
+use scale_info::TypeInfo;
+use sp_runtime::{traits::BlakeTwo256, RuntimeDebug};
+
+use super::contracts_config::deposit;
+use crate::{Balance, Balances, Runtime, RuntimeCall, RuntimeEvent, System};
+
+parameter_types! {
+	// One storage item; key size 32, value size 8
+	pub const ProxyDepositBase: Balance = deposit(1, 40);
+	// Additional storage item size of 33 bytes
+	pub const ProxyDepositFactor: Balance = deposit(0, 33);
+	pub const MaxProxies: u32 = 32;
+	// One storage item; key size 32, value size 16
+	pub const AnnouncementDepositBase: Balance = deposit(1, 48);
+	pub const AnnouncementDepositFactor: Balance = deposit(0, 66);
+	pub const MaxPending: u32 = 32;
+}
+
+/// Calls a proxy may make on behalf of the account it proxies
+#[derive(
+	Copy,
+	Clone,
+	Eq,
+	PartialEq,
+	Ord,
+	PartialOrd,
+	Default,
+	Encode,
+	Decode,
+	DecodeWithMemTracking,
+	RuntimeDebug,
+	MaxEncodedLen,
+	TypeInfo,
+)]
+pub enum ProxyType {
+	/// Any call
+	#[default]
+	Any,
+	/// Managing models and claiming their earnings, but no transfers or fees
+	AiModelManagement,
+}
+
+impl InstanceFilter<RuntimeCall> for ProxyType {
+	fn filter(&self, call: &RuntimeCall) -> bool {
+		use pallet_ai_registry::Call as Registry;
+		match self {
+			ProxyType::Any => true,
+			ProxyType::AiModelManagement => matches!(
+				call,
+				RuntimeCall::AIRegistry(
+					Registry::update_model_metadata { .. }
+						| Registry::schedule_price_change { .. }
+						| Registry::cancel_price_change { .. }
+						| Registry::rename_model { .. }
+						| Registry::deprecate_model { .. }
+						| Registry::deactivate_model { .. }
+						| Registry::deactivate_at { .. }
+						| Registry::reactivate_model { .. }
+						| Registry::propose_size_correction { .. }
+						| Registry::add_model_callback { .. }
+						| Registry::remove_model_callback { .. }
+						| Registry::export_models { .. }
+						| Registry::add_to_collection { .. }
+						| Registry::remove_from_collection { .. }
+						| Registry::collect_rent { .. }
+						| Registry::top_up_deposit { .. }
+						| Registry::claim_fee_rebate { .. }
+						| Registry::claim_incentive { .. }
+						| Registry::claim_reward { .. }
+						| Registry::set_developer_profile { .. }
+						| Registry::clear_developer_profile { .. }
+				) | RuntimeCall::Inference(pallet_inference::Call::claim_earnings { .. })
+			),
+		}
+	}
+
+	fn is_superset(&self, o: &Self) -> bool {
+		match (self, o) {
+			(x, y) if x == y => true,
+			(ProxyType::Any, _) => true,
+			(_, ProxyType::Any) => false,
+			_ => false,
+		}
+	}
+}
+
+impl pallet_proxy::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type RuntimeCall = RuntimeCall;
+	type Currency = Balances;
+	type ProxyType = ProxyType;
+	type ProxyDepositBase = ProxyDepositBase;
+	type ProxyDepositFactor = ProxyDepositFactor;
+	type MaxProxies = MaxProxies;
+	type WeightInfo = pallet_proxy::weights::SubstrateWeight<Runtime>;
+	type MaxPending = MaxPending;
+	type CallHasher = BlakeTwo256;
+	type AnnouncementDepositBase = AnnouncementDepositBase;
+	type AnnouncementDepositFactor = AnnouncementDepositFactor;
+	type BlockNumberProvider = System;
+}
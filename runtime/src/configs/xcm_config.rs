@@ -0,0 +1,205 @@
+//! XCM configuration, letting users on other chains pay for inferences.
+//!
+//! A solochain has no relay chain to deliver messages, so nothing is routed out of
+//! the runtime and incoming messages arrive through a bridge or local execution.
+//! A purchase from another chain looks like:
+//!
+//! ```text
+//! WithdrawAsset(assets)                 // reserve transfer back from the sender's sovereign account
+//! BuyExecution { fees, weight_limit }
+//! DepositAsset { assets: All, beneficiary: <origin> }
+//! Transact { call: Inference::request_inference_with_asset(..) }
+//! ```
+//!
+//! The sender's location is converted to a local account that receives the assets,
+//! makes the request and, once it settles, holds the receipt needed to rate the model.
+
+use frame_support::{
+	parameter_types,
+	traits::{Contains, Disabled, EnsureOrigin, Everything, Nothing, PalletInfoAccess},
+	weights::IdentityFee,
+};
+use frame_system::EnsureRoot;
+use pallet_xcm::XcmPassthrough;
+use xcm::latest::prelude::*;
+use xcm_builder::{
+	AccountId32Aliases, AllowTopLevelPaidExecutionFrom, AsPrefixedGeneralIndex,
+	ConvertedConcreteId, DescribeAllTerminal, DescribeFamily, EnsureXcmOrigin, FixedWeightBounds,
+	FrameTransactionalProcessor, FungibleAdapter, FungiblesAdapter, HashedDescription, IsConcrete,
+	NoChecking, SignedAccountId32AsNative, SignedToAccountId32, SovereignSignedViaLocation,
+	TakeWeightCredit, UsingComponents,
+};
+use xcm_executor::{traits::ConvertLocation, XcmExecutor};
+
+use crate::{
+	AccountId, AllPalletsWithSystem, Assets, Balance, Balances, PolkadotXcm, Runtime, RuntimeCall,
+	RuntimeEvent, RuntimeOrigin,
+};
+
+parameter_types! {
+	/// The runtime has no consensus system above it; bridges give it one.
+	pub UniversalLocation: InteriorLocation = Here;
+	pub const ThisNetwork: Option<NetworkId> = None;
+	pub HereLocation: Location = Location::here();
+	pub AssetsPalletLocation: Location =
+		PalletInstance(<Assets as PalletInfoAccess>::index() as u8).into();
+	pub const UnitWeightCost: Weight = Weight::from_parts(1_000_000_000, 64 * 1024);
+	pub const MaxInstructions: u32 = 100;
+	pub const MaxAssetsIntoHolding: u32 = 64;
+	pub CheckingAccount: AccountId = PolkadotXcm::check_account();
+}
+
+/// Local accounts for locations: this chain's `AccountId32`s keep their account,
+/// anything else, e.g. an account on another chain, gets one derived from a hash
+/// of its description.
+pub type LocationToAccountId = (
+	AccountId32Aliases<ThisNetwork, AccountId>,
+	HashedDescription<AccountId, DescribeFamily<DescribeAllTerminal>>,
+);
+
+/// The native token, at `Here`.
+pub type NativeAssetTransactor =
+	FungibleAdapter<Balances, IsConcrete<HereLocation>, LocationToAccountId, AccountId, ()>;
+
+/// Assets of `pallet-assets`, at `PalletInstance(<index>)/GeneralIndex(<id>)`.
+pub type AssetsTransactor = FungiblesAdapter<
+	Assets,
+	ConvertedConcreteId<
+		u32,
+		Balance,
+		AsPrefixedGeneralIndex<AssetsPalletLocation, u32, sp_runtime::traits::TryConvertInto>,
+		sp_runtime::traits::TryConvertInto,
+	>,
+	LocationToAccountId,
+	AccountId,
+	NoChecking,
+	CheckingAccount,
+>;
+
+pub type AssetTransactors = (NativeAssetTransactor, AssetsTransactor);
+
+/// Dispatch `Transact` as the local account of the sender.
+pub type XcmOriginToTransactDispatchOrigin = (
+	SovereignSignedViaLocation<LocationToAccountId, RuntimeOrigin>,
+	SignedAccountId32AsNative<ThisNetwork, RuntimeOrigin>,
+	XcmPassthrough<RuntimeOrigin>,
+);
+
+/// Only messages that pay for their execution are run.
+pub type Barrier = (TakeWeightCredit, AllowTopLevelPaidExecutionFrom<Everything>);
+
+/// Models registered from other chains: an XCM origin, e.g. `Transact` with
+/// `OriginKind::Xcm`, from a location outside this chain, resolving to the account
+/// derived for that location; the chain's sovereign account for a bare
+/// `Parachain(id)`, otherwise one for the account or pallet within it.
+pub struct XcmRegistrationOrigin;
+
+impl EnsureOrigin<RuntimeOrigin> for XcmRegistrationOrigin {
+	type Success = AccountId;
+
+	fn try_origin(o: RuntimeOrigin) -> Result<AccountId, RuntimeOrigin> {
+		match o.clone().into() {
+			Ok(pallet_xcm::Origin::Xcm(location)) if location.parents > 0 => {
+				LocationToAccountId::convert_location(&location).ok_or(o)
+			}
+			_ => Err(o),
+		}
+	}
+
+	#[cfg(feature = "runtime-benchmarks")]
+	fn try_successful_origin() -> Result<RuntimeOrigin, ()> {
+		Ok(pallet_xcm::Origin::Xcm(Location::new(1, [Parachain(2000)])).into())
+	}
+}
+
+/// Calls remote users may `Transact`: buying, settling and rating inferences, and
+/// registering and updating models.
+pub struct XcmCallFilter;
+
+impl Contains<RuntimeCall> for XcmCallFilter {
+	fn contains(call: &RuntimeCall) -> bool {
+		matches!(
+			call,
+			RuntimeCall::Inference(
+				pallet_inference::Call::request_inference { .. }
+					| pallet_inference::Call::request_inference_with_asset { .. }
+					| pallet_inference::Call::settle_request { .. }
+					| pallet_inference::Call::cancel_request { .. }
+			) | RuntimeCall::AIRegistry(
+				pallet_ai_registry::Call::rate_model { .. }
+					| pallet_ai_registry::Call::register_model { .. }
+					| pallet_ai_registry::Call::update_model_metadata { .. }
+			)
+		)
+	}
+}
+
+pub struct XcmConfig;
+
+impl xcm_executor::Config for XcmConfig {
+	type RuntimeCall = RuntimeCall;
+	// No transport: the runtime does not send messages
+	type XcmSender = ();
+	type XcmEventEmitter = PolkadotXcm;
+	type AssetTransactor = AssetTransactors;
+	type OriginConverter = XcmOriginToTransactDispatchOrigin;
+	// Only this chain's own assets are accepted, returned from the sender's
+	// sovereign account; no other chain is trusted as a reserve
+	type IsReserve = ();
+	type IsTeleporter = ();
+	type UniversalLocation = UniversalLocation;
+	type Barrier = Barrier;
+	type Weigher = FixedWeightBounds<UnitWeightCost, RuntimeCall, MaxInstructions>;
+	type Trader = UsingComponents<IdentityFee<Balance>, HereLocation, AccountId, Balances, ()>;
+	type ResponseHandler = PolkadotXcm;
+	type AssetTrap = PolkadotXcm;
+	type AssetLocker = ();
+	type AssetExchanger = ();
+	type AssetClaims = PolkadotXcm;
+	type SubscriptionService = PolkadotXcm;
+	type PalletInstancesInfo = AllPalletsWithSystem;
+	type MaxAssetsIntoHolding = MaxAssetsIntoHolding;
+	type FeeManager = ();
+	type MessageExporter = ();
+	type UniversalAliases = Nothing;
+	type CallDispatcher = RuntimeCall;
+	type SafeCallFilter = XcmCallFilter;
+	type Aliasers = Nothing;
+	type TransactionalProcessor = FrameTransactionalProcessor;
+	type HrmpNewChannelOpenRequestHandler = ();
+	type HrmpChannelAcceptedHandler = ();
+	type HrmpChannelClosingHandler = ();
+	type XcmRecorder = PolkadotXcm;
+}
+
+/// Local signed origins as XCM locations.
+pub type LocalOriginToLocation = SignedToAccountId32<RuntimeOrigin, AccountId, ThisNetwork>;
+
+impl pallet_xcm::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type SendXcmOrigin = EnsureXcmOrigin<RuntimeOrigin, LocalOriginToLocation>;
+	type XcmRouter = ();
+	type ExecuteXcmOrigin = EnsureXcmOrigin<RuntimeOrigin, LocalOriginToLocation>;
+	type XcmExecuteFilter = Everything;
+	type XcmExecutor = XcmExecutor<XcmConfig>;
+	type XcmTeleportFilter = Nothing;
+	type XcmReserveTransferFilter = Everything;
+	type Weigher = FixedWeightBounds<UnitWeightCost, RuntimeCall, MaxInstructions>;
+	type UniversalLocation = UniversalLocation;
+	type RuntimeOrigin = RuntimeOrigin;
+	type RuntimeCall = RuntimeCall;
+	const VERSION_DISCOVERY_QUEUE_SIZE: u32 = 100;
+	type AdvertisedXcmVersion = pallet_xcm::CurrentXcmVersion;
+	type AdminOrigin = EnsureRoot<AccountId>;
+	type TrustedLockers = ();
+	type SovereignAccountOf = LocationToAccountId;
+	type Currency = Balances;
+	type CurrencyMatcher = IsConcrete<HereLocation>;
+	type MaxLockers = frame_support::traits::ConstU32<8>;
+	type MaxRemoteLockConsumers = frame_support::traits::ConstU32<0>;
+	type RemoteLockConsumerIdentifier = ();
+	// No benchmarked weights for this runtime yet
+	type WeightInfo = pallet_xcm::TestWeightInfo;
+	// Origin aliasing is not supported
+	type AuthorizedAliasConsideration = Disabled;
+}
@@ -27,6 +27,9 @@ pub use sp_runtime::BuildStorage;
 
 pub mod genesis_config_presets;
 
+#[cfg(test)]
+mod tests;
+
 /// Opaque types. These are used by the CLI to instantiate machinery that don't need to know
 /// the specifics of the runtime. They can then be made to be agnostic over specific formats
 /// of data like extrinsics, allowing for them to continue syncing the network through upgrades
@@ -156,6 +159,7 @@ pub type TxExtension = (
 	frame_system::CheckNonce<Runtime>,
 	frame_system::CheckWeight<Runtime>,
 	pallet_transaction_payment::ChargeTransactionPayment<Runtime>,
+	pallet_inference::PrioritizePaidInference<Runtime>,
 	frame_metadata_hash_extension::CheckMetadataHash<Runtime>,
 	frame_system::WeightReclaim<Runtime>,
 );
@@ -171,7 +175,10 @@ pub type SignedPayload = generic::SignedPayload<RuntimeCall, TxExtension>;
 ///
 /// This can be a tuple of types, each implementing `OnRuntimeUpgrade`.
 #[allow(unused_parens)]
-type Migrations = ();
+type Migrations = (
+	pallet_ai_registry::migrations::Unreleased<Runtime>,
+	pallet_compute_providers::migrations::Unreleased<Runtime>,
+);
 
 /// Executive: handles dispatch to the various modules.
 pub type Executive = frame_executive::Executive<
@@ -225,4 +232,49 @@ mod runtime {
 	// Include the custom logic from the pallet-template in the runtime.
 	#[runtime::pallet_index(7)]
 	pub type Template = pallet_template;
+
+	#[runtime::pallet_index(8)]
+	pub type AIRegistry = pallet_ai_registry;
+
+	#[runtime::pallet_index(9)]
+	pub type Council = pallet_collective<Instance1>;
+
+	#[runtime::pallet_index(10)]
+	pub type Disputes = pallet_disputes;
+
+	#[runtime::pallet_index(11)]
+	pub type ComputeProviders = pallet_compute_providers;
+
+	#[runtime::pallet_index(12)]
+	pub type Inference = pallet_inference;
+
+	#[runtime::pallet_index(13)]
+	pub type Availability = pallet_availability;
+
+	#[runtime::pallet_index(14)]
+	pub type Assets = pallet_assets;
+
+	#[runtime::pallet_index(15)]
+	pub type PolkadotXcm = pallet_xcm;
+
+	#[runtime::pallet_index(16)]
+	pub type RandomnessCollectiveFlip = pallet_insecure_randomness_collective_flip;
+
+	#[runtime::pallet_index(17)]
+	pub type Contracts = pallet_contracts;
+
+	#[runtime::pallet_index(18)]
+	pub type Nfts = pallet_nfts;
+
+	#[runtime::pallet_index(19)]
+	pub type Marketplace = pallet_marketplace;
+
+	#[runtime::pallet_index(20)]
+	pub type DeveloperMembership = pallet_membership<Instance1>;
+
+	#[runtime::pallet_index(21)]
+	pub type Proxy = pallet_proxy;
+
+	#[runtime::pallet_index(22)]
+	pub type Escrow = pallet_escrow;
 }
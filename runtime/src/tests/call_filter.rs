@@ -0,0 +1,27 @@
+//! Calls the runtime's base call filter keeps out
+
+use super::new_test_ext;
+use crate::{AccountId, RuntimeCall, RuntimeOrigin};
+use frame_support::{assert_noop, assert_ok};
+use frame_system::Call as SystemCall;
+use pallet_inference::EnclaveRequirement;
+use sp_runtime::traits::Dispatchable;
+
+#[test]
+fn enclave_requirements_are_filtered_without_a_verifier() {
+	let owner = AccountId::from([1; 32]);
+	new_test_ext(vec![]).execute_with(|| {
+		let call = RuntimeCall::Inference(pallet_inference::Call::set_enclave_requirement {
+			model_id: 0,
+			requirement: Some(EnclaveRequirement::AnyEnclave),
+		});
+		assert_noop!(
+			call.dispatch(RuntimeOrigin::signed(owner.clone())),
+			frame_system::Error::<crate::Runtime>::CallFiltered
+		);
+
+		// Other calls still go through
+		let remark = RuntimeCall::System(SystemCall::remark { remark: vec![] });
+		assert_ok!(remark.dispatch(RuntimeOrigin::signed(owner)));
+	});
+}
@@ -0,0 +1,175 @@
+//! The registry as contracts see it through the chain extension
+
+use super::new_test_ext;
+use crate::{
+	configs::contracts_config::{func_ids, ContractModelInfo, ContractsDryRunGasLimit},
+	AccountId, Balance, Contracts, Runtime, RuntimeCall, RuntimeOrigin, UNIT,
+};
+use codec::{Decode, Encode};
+use frame_support::assert_ok;
+use pallet_ai_registry::{License, ModelId, ModelType, Models};
+use pallet_contracts::{Code, CollectEvents, DebugInfo, Determinism};
+use pallet_inference::{DemandCounter, DemandPricing};
+use sp_runtime::{traits::Dispatchable, DispatchError, Percent};
+
+const ALICE: AccountId = AccountId::new([1; 32]);
+
+/// Instantiate the pass-through fixture, returning its account
+fn deploy() -> AccountId {
+	let code = wat::parse_str(include_str!("fixtures/chain_extension.wat")).unwrap();
+	Contracts::bare_instantiate(
+		ALICE,
+		0,
+		ContractsDryRunGasLimit::get(),
+		None,
+		Code::Upload(code),
+		vec![],
+		vec![],
+		DebugInfo::Skip,
+		CollectEvents::Skip,
+	)
+	.result
+	.unwrap()
+	.account_id
+}
+
+/// Call function `func_id` of chain extension `ext_id` with `input` from `contract`
+fn query(
+	contract: &AccountId,
+	ext_id: u16,
+	func_id: u16,
+	input: impl Encode,
+) -> Result<Vec<u8>, DispatchError> {
+	let id = ((ext_id as u32) << 16) | func_id as u32;
+	let mut data = id.to_le_bytes().to_vec();
+	input.encode_to(&mut data);
+	Contracts::bare_call(
+		ALICE,
+		contract.clone(),
+		0,
+		ContractsDryRunGasLimit::get(),
+		None,
+		data,
+		DebugInfo::Skip,
+		CollectEvents::Skip,
+		Determinism::Enforced,
+	)
+	.result
+	.map(|value| value.data)
+}
+
+fn decode<T: Decode>(output: Result<Vec<u8>, DispatchError>) -> T {
+	T::decode(&mut &output.unwrap()[..]).unwrap()
+}
+
+fn register_model() {
+	let call = RuntimeCall::AIRegistry(pallet_ai_registry::Call::register_model {
+		ipfs_cid: b"QmYwAPJzv5CZsnA625s3Xf2nemtYgPpHdWEz79ojWnPbdG".to_vec(),
+		name: b"Contract model".to_vec(),
+		description: b"Read by contracts".to_vec(),
+		model_type: ModelType::Classification,
+		price: UNIT,
+		license: License::Mit,
+		size_bytes: 1_000,
+		datasets: vec![],
+	});
+	assert_ok!(call.dispatch(RuntimeOrigin::signed(ALICE)));
+}
+
+#[test]
+fn contracts_read_models() {
+	new_test_ext(vec![(ALICE, 1_000 * UNIT)]).execute_with(|| {
+		let contract = deploy();
+		assert_eq!(
+			decode::<Option<ContractModelInfo>>(query(&contract, 0, func_ids::MODEL, 0 as ModelId)),
+			None
+		);
+
+		register_model();
+		let info: Option<ContractModelInfo> =
+			decode(query(&contract, 0, func_ids::MODEL, 0 as ModelId));
+		let info = info.unwrap();
+		assert_eq!((info.owner, info.name), (ALICE, b"Contract model".to_vec()));
+		assert_eq!(Some(info), Models::<Runtime>::get(0).map(Into::into));
+	});
+}
+
+#[test]
+fn contracts_read_average_ratings() {
+	new_test_ext(vec![(ALICE, 1_000 * UNIT)]).execute_with(|| {
+		let contract = deploy();
+		register_model();
+		let rating =
+			|| decode::<Option<u8>>(query(&contract, 0, func_ids::AVERAGE_RATING, 0 as ModelId));
+		assert_eq!(rating(), None);
+
+		Models::<Runtime>::mutate(0, |model| {
+			let model = model.as_mut().unwrap();
+			model.total_rating = 9;
+			model.rating_count = 2;
+		});
+		assert_eq!(rating(), Some(4));
+	});
+}
+
+#[test]
+fn contracts_read_prices() {
+	new_test_ext(vec![(ALICE, 1_000 * UNIT)]).execute_with(|| {
+		let contract = deploy();
+		let price =
+			|| decode::<Option<Balance>>(query(&contract, 0, func_ids::PRICE, 0 as ModelId));
+		assert_eq!(price(), None);
+
+		register_model();
+		assert_eq!(price(), Some(UNIT));
+	});
+}
+
+#[test]
+fn contracts_read_asset_prices() {
+	new_test_ext(vec![(ALICE, 1_000 * UNIT)]).execute_with(|| {
+		let contract = deploy();
+		let price = |asset_id: u32| {
+			decode::<Option<Balance>>(query(
+				&contract,
+				0,
+				func_ids::ASSET_PRICE,
+				(0 as ModelId, asset_id),
+			))
+		};
+		pallet_inference::AssetPrices::<Runtime>::insert(0, 1, 5 * UNIT);
+
+		assert_eq!(price(1), Some(5 * UNIT));
+		assert_eq!(price(2), None);
+
+		// Asset prices carry the demand surcharge too
+		pallet_inference::DemandPricings::<Runtime>::insert(
+			0,
+			DemandPricing {
+				baseline: 0,
+				step: Percent::from_percent(10),
+				max_surcharge: Percent::from_percent(50),
+			},
+		);
+		pallet_inference::DemandCounters::<Runtime>::insert(
+			0,
+			DemandCounter { window: 0, current: 2, previous: 0 },
+		);
+		assert_eq!(price(1), Some(6 * UNIT));
+	});
+}
+
+#[test]
+fn unknown_functions_trap() {
+	new_test_ext(vec![(ALICE, 1_000 * UNIT)]).execute_with(|| {
+		let contract = deploy();
+		assert_eq!(
+			query(&contract, 0, 5, 0 as ModelId),
+			Err(DispatchError::Other("Unknown AI registry function"))
+		);
+		assert_eq!(
+			query(&contract, 1, func_ids::MODEL, 0 as ModelId),
+			Err(DispatchError::Other("Unknown chain extension"))
+		);
+	});
+}
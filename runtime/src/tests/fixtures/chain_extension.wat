@@ -0,0 +1,38 @@
+;; Calls the chain extension with the contract's input and returns its output.
+;;
+;; Input: the `u32` chain extension ID (`extension << 16 | function`), then the
+;; SCALE encoded arguments.
+(module
+	(import "seal0" "input" (func $input (param i32 i32)))
+	(import "seal0" "call_chain_extension"
+		(func $call_chain_extension (param i32 i32 i32 i32 i32) (result i32)))
+	(import "seal0" "seal_return" (func $seal_return (param i32 i32 i32)))
+	(import "env" "memory" (memory 1 1))
+
+	;; [0, 4) length of the buffer, then of what was written to it
+	;; [4, 8) chain extension ID
+	;; [8, 16392) arguments, then output
+
+	(func (export "deploy"))
+
+	(func (export "call")
+		(local $input_len i32)
+
+		(i32.store (i32.const 0) (i32.const 16388))
+		(call $input (i32.const 4) (i32.const 0))
+		(local.set $input_len (i32.sub (i32.load (i32.const 0)) (i32.const 4)))
+
+		(i32.store (i32.const 0) (i32.const 16384))
+		(drop
+			(call $call_chain_extension
+				(i32.load (i32.const 4))
+				(i32.const 8)
+				(local.get $input_len)
+				(i32.const 8)
+				(i32.const 0)
+			)
+		)
+
+		(call $seal_return (i32.const 0) (i32.const 8) (i32.load (i32.const 0)))
+	)
+)
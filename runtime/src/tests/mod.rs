@@ -0,0 +1,22 @@
+//! Tests of the runtime's own configuration, on top of the pallets' unit tests
+
+mod call_filter;
+mod contracts;
+mod proxy;
+mod xcm;
+
+use crate::{AccountId, Balance, Runtime, System};
+use sp_runtime::BuildStorage;
+
+/// Externalities at block 1 with `balances` endowed
+pub(crate) fn new_test_ext(balances: Vec<(AccountId, Balance)>) -> sp_io::TestExternalities {
+	let mut t = frame_system::GenesisConfig::<Runtime>::default().build_storage().unwrap();
+
+	pallet_balances::GenesisConfig::<Runtime> { balances, dev_accounts: None }
+		.assimilate_storage(&mut t)
+		.unwrap();
+
+	let mut ext = sp_io::TestExternalities::new(t);
+	ext.execute_with(|| System::set_block_number(1));
+	ext
+}
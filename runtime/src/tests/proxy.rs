@@ -0,0 +1,97 @@
+//! What model management proxies may do for their owners
+
+use super::new_test_ext;
+use crate::{
+	configs::proxy_config::ProxyType, AccountId, Proxy, Runtime, RuntimeCall, RuntimeEvent,
+	RuntimeOrigin, System, UNIT,
+};
+use frame_support::{assert_ok, traits::InstanceFilter};
+use pallet_ai_registry::{License, ModelType, Models};
+use sp_runtime::traits::Dispatchable;
+
+const OWNER: AccountId = AccountId::new([1; 32]);
+const HOT_KEY: AccountId = AccountId::new([2; 32]);
+
+fn register_model() -> RuntimeCall {
+	RuntimeCall::AIRegistry(pallet_ai_registry::Call::register_model {
+		ipfs_cid: b"QmYwAPJzv5CZsnA625s3Xf2nemtYgPpHdWEz79ojWnPbdG".to_vec(),
+		name: b"Managed model".to_vec(),
+		description: b"Managed from a hot key".to_vec(),
+		model_type: ModelType::Classification,
+		price: UNIT,
+		license: License::Mit,
+		size_bytes: 1_000,
+		datasets: vec![],
+	})
+}
+
+fn update_price() -> RuntimeCall {
+	RuntimeCall::AIRegistry(pallet_ai_registry::Call::update_model_metadata {
+		model_id: 0,
+		new_price: Some(2 * UNIT),
+		new_description: None,
+		new_status: None,
+		new_license: None,
+	})
+}
+
+fn transfer() -> RuntimeCall {
+	RuntimeCall::Balances(pallet_balances::Call::transfer_allow_death {
+		dest: HOT_KEY.into(),
+		value: UNIT,
+	})
+}
+
+/// Dispatch `call` as `OWNER` through `HOT_KEY`, returning what the call itself did
+fn proxy(call: RuntimeCall) -> sp_runtime::DispatchResult {
+	assert_ok!(Proxy::proxy(RuntimeOrigin::signed(HOT_KEY), OWNER.into(), None, Box::new(call)));
+	match System::events().last().map(|record| record.event.clone()) {
+		Some(RuntimeEvent::Proxy(pallet_proxy::Event::ProxyExecuted { result })) => result,
+		event => panic!("unexpected event {event:?}"),
+	}
+}
+
+#[test]
+fn model_management_covers_registry_calls_and_earnings() {
+	let management = ProxyType::AiModelManagement;
+	assert!(management.filter(&update_price()));
+	assert!(management
+		.filter(&RuntimeCall::Inference(pallet_inference::Call::claim_earnings { model_id: 0 })));
+
+	// Registrations pay fees from the owner's balance
+	assert!(!management.filter(&register_model()));
+	assert!(!management.filter(&RuntimeCall::AIRegistry(
+		pallet_ai_registry::Call::create_collection { name: b"Suite".to_vec() }
+	)));
+
+	assert!(!management.filter(&transfer()));
+	assert!(!management.filter(&RuntimeCall::AIRegistry(
+		pallet_ai_registry::Call::transfer_model { model_id: 0, dest: HOT_KEY }
+	)));
+
+	assert!(ProxyType::Any.filter(&transfer()));
+	assert!(ProxyType::Any.is_superset(&management));
+	assert!(!management.is_superset(&ProxyType::Any));
+}
+
+#[test]
+fn model_management_proxies_cannot_move_funds() {
+	new_test_ext(vec![(OWNER, 1_000 * UNIT), (HOT_KEY, 10 * UNIT)]).execute_with(|| {
+		assert_ok!(Proxy::add_proxy(
+			RuntimeOrigin::signed(OWNER),
+			HOT_KEY.into(),
+			ProxyType::AiModelManagement,
+			0
+		));
+
+		let filtered: sp_runtime::DispatchResult =
+			Err(frame_system::Error::<Runtime>::CallFiltered.into());
+		assert_eq!(proxy(register_model()), filtered);
+		assert_ok!(register_model().dispatch(RuntimeOrigin::signed(OWNER)));
+		assert_ok!(proxy(update_price()));
+		assert_eq!(Models::<Runtime>::get(0).unwrap().price, 2 * UNIT);
+
+		assert_eq!(proxy(transfer()), filtered);
+		assert_eq!(pallet_balances::Pallet::<Runtime>::free_balance(HOT_KEY), 10 * UNIT);
+	});
+}
@@ -0,0 +1,94 @@
+//! Models registered and updated from other chains
+
+use super::new_test_ext;
+use crate::{
+	configs::xcm_config::{LocationToAccountId, XcmConfig},
+	RuntimeCall, UNIT,
+};
+use codec::Encode;
+use frame_support::{assert_ok, weights::Weight};
+use pallet_ai_registry::{License, ModelType, Models};
+use xcm::latest::prelude::*;
+use xcm_executor::{traits::ConvertLocation, XcmExecutor};
+
+/// A team's parachain
+fn sibling() -> Location {
+	Location::new(1, [Parachain(2000)])
+}
+
+/// Execute `call` with `OriginKind::Xcm` from `origin`, paying for the message with
+/// native tokens withdrawn from the origin's derived account
+fn transact(origin: Location, call: RuntimeCall) -> Outcome {
+	let fees: Asset = (Here, 10 * UNIT).into();
+	let message = Xcm::<RuntimeCall>(vec![
+		WithdrawAsset(fees.clone().into()),
+		BuyExecution { fees, weight_limit: Unlimited },
+		Transact {
+			origin_kind: OriginKind::Xcm,
+			fallback_max_weight: None,
+			call: call.encode().into(),
+		},
+	]);
+	let mut hash = message.using_encoded(sp_io::hashing::blake2_256);
+	XcmExecutor::<XcmConfig>::prepare_and_execute(
+		origin,
+		message,
+		&mut hash,
+		Weight::MAX,
+		Weight::zero(),
+	)
+}
+
+fn register_model() -> RuntimeCall {
+	RuntimeCall::AIRegistry(pallet_ai_registry::Call::register_model {
+		ipfs_cid: b"QmYwAPJzv5CZsnA625s3Xf2nemtYgPpHdWEz79ojWnPbdG".to_vec(),
+		name: b"Remote model".to_vec(),
+		description: b"Registered from a parachain".to_vec(),
+		model_type: ModelType::Classification,
+		price: UNIT,
+		license: License::Mit,
+		size_bytes: 1_000,
+		datasets: vec![],
+	})
+}
+
+#[test]
+fn parachains_register_models_to_their_derived_account() {
+	let owner = LocationToAccountId::convert_location(&sibling()).unwrap();
+	new_test_ext(vec![(owner.clone(), 1_000 * UNIT)]).execute_with(|| {
+		assert_ok!(transact(sibling(), register_model()).ensure_complete());
+
+		let model = Models::<crate::Runtime>::get(0).unwrap();
+		assert_eq!(model.owner, owner);
+		assert_eq!(model.price, UNIT);
+	});
+}
+
+#[test]
+fn parachains_update_the_models_they_own() {
+	let owner = LocationToAccountId::convert_location(&sibling()).unwrap();
+	new_test_ext(vec![(owner, 1_000 * UNIT)]).execute_with(|| {
+		assert_ok!(transact(sibling(), register_model()).ensure_complete());
+
+		let update = RuntimeCall::AIRegistry(pallet_ai_registry::Call::update_model_metadata {
+			model_id: 0,
+			new_price: Some(2 * UNIT),
+			new_description: None,
+			new_status: None,
+			new_license: Some(License::Apache2),
+		});
+		assert_ok!(transact(sibling(), update).ensure_complete());
+		let model = Models::<crate::Runtime>::get(0).unwrap();
+		assert_eq!((model.price, model.license), (2 * UNIT, License::Apache2));
+	});
+}
+
+#[test]
+fn other_registry_calls_cannot_be_transacted() {
+	let owner = LocationToAccountId::convert_location(&sibling()).unwrap();
+	new_test_ext(vec![(owner, 1_000 * UNIT)]).execute_with(|| {
+		let bookmark =
+			RuntimeCall::AIRegistry(pallet_ai_registry::Call::bookmark_model { model_id: 0 });
+		assert!(transact(sibling(), bookmark).ensure_complete().is_err());
+	});
+}